@@ -438,7 +438,13 @@ impl pallet_did_lookup::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = ConstU128<UNIT>;
 	type DidIdentifier = DidIdentifier;
+	type Eip712ChainId = ConstU64<0>;
 	type EnsureOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
+	type MaxAssociationsPerBatch = ConstU32<50>;
+	type MaxLabelLength = ConstU32<32>;
+	type MaxLinkedAccounts = ConstU32<50>;
+	type MetadataByteDeposit = ConstU128<MILLIUNIT>;
+	type NameProvider = runtime_common::did::LinkedWeb3Names<Runtime>;
 	type OriginSuccess = DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -455,6 +461,7 @@ impl pallet_web3_names::Config for Runtime {
 	type Deposit = ConstU128<UNIT>;
 	type MaxNameLength = ConstU32<32>;
 	type MinNameLength = ConstU32<3>;
+	type NameChangeHook = runtime_common::did::LinkedWeb3Names<Runtime>;
 	type OriginSuccess = DidRawOrigin<AccountId, DidIdentifier>;
 	type OwnerOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
 	type RuntimeEvent = RuntimeEvent;
@@ -659,9 +666,9 @@ impl_runtime_apis! {
 					did::Did::<Runtime>::get(&owner_info.owner).map(|details| (owner_info, details))
 				})
 				.map(|(owner_info, details)| {
-					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(
+					let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(
 						&owner_info.owner,
-					).collect();
+					).map(Into::into).collect();
 					let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
 
 					kilt_runtime_api_did::RawDidLinkedInfo{
@@ -702,7 +709,7 @@ impl_runtime_apis! {
 				})
 				.map(|(connection_record, details)| {
 					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did).map(Into::into);
-					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&connection_record.did).collect();
+					let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(&connection_record.did).map(Into::into).collect();
 					let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&connection_record.did).map(|e| From::from(e.1)).collect();
 
 					kilt_runtime_api_did::RawDidLinkedInfo {
@@ -740,7 +747,7 @@ impl_runtime_apis! {
 		> {
 			let details = did::Did::<Runtime>::get(&did)?;
 			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
-			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
+			let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(&did).map(Into::into).collect();
 			let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
 
 			Some(kilt_runtime_api_did::RawDidLinkedInfo {
@@ -776,6 +783,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl kilt_runtime_api_did::Web3NameLookup<Block, LinkableAccountId> for Runtime {
+		fn batch_query_web3_name_by_account(accounts: Vec<LinkableAccountId>) -> Vec<Option<Vec<u8>>> {
+			accounts.iter().map(|account| runtime_common::did::web3_name_by_account::<Runtime>(account).map(Into::into)).collect()
+		}
+	}
+
 	impl kilt_runtime_api_dip_provider::DipProvider<Block, runtime_api::DipProofRequest, CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, runtime_api::DipProofError> for Runtime {
 		fn generate_proof(request: runtime_api::DipProofRequest) -> Result<CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, runtime_api::DipProofError> {
 			let identity_details = IdentityProviderOf::<Runtime>::retrieve(&request.identifier).map_err(runtime_api::DipProofError::IdentityProvider)?;
@@ -0,0 +1,341 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use asset_hub_rococo_emulated_chain::AssetHubRococoParaPallet;
+use emulated_integration_tests_common::accounts::{ALICE, BOB};
+use frame_support::{
+	assert_ok,
+	dispatch::RawOrigin,
+	traits::{
+		fungible,
+		fungibles::{Create, Inspect, Mutate},
+	},
+};
+use pallet_bonded_coins::{
+	curves::{polynomial::PolynomialParameters, Curve},
+	PoolDetailsOf, PoolStatus, Pools,
+};
+use parity_scale_codec::Encode;
+use peregrine_runtime::{
+	xcm::LocationToAccountIdWithRemoteAccountsConverter, BondedCurrencies, BondedFungibles, Fungibles,
+	Runtime as PeregrineRuntime,
+};
+use runtime_common::{bonded_coins::FixedPoint, constants::KILT, AccountId, Balance};
+use sp_core::sr25519;
+use xcm::{
+	lts::prelude::{
+		Instruction::{BuyExecution, Transact, WithdrawAsset},
+		Junction::{AccountId32, GeneralIndex, PalletInstance, Parachain},
+		Junctions, Location, OriginKind, ParentThen, Weight, WeightLimit, Xcm,
+	},
+	DoubleEncoded, VersionedLocation, VersionedXcm,
+};
+use xcm_emulator::{assert_expected_events, Chain, Network, Parachain as _, TestExt};
+use xcm_executor::traits::ConvertLocation;
+
+use crate::{
+	mock::network::{AssetHub, MockNetwork, Peregrine, Rococo},
+	utils::get_account_id_from_seed,
+};
+
+/// ID of USDT in the assets pallet of Asset Hub.
+const USDT_ID: u32 = 1984;
+/// ID of the bonded currency issued by the test pool.
+const BONDED_CURRENCY_ID: u32 = 0;
+const USDT_MIN_BALANCE: Balance = 1_000;
+const USDT_BALANCE: Balance = 1_000_000_000_000;
+
+fn usdt_location() -> Location {
+	Location::new(
+		1,
+		[
+			Parachain(AssetHub::para_id().into()),
+			PalletInstance(50),
+			GeneralIndex(USDT_ID.into()),
+		],
+	)
+}
+
+fn get_sibling_destination_peregrine() -> VersionedLocation {
+	ParentThen(Junctions::X1([Parachain(Peregrine::para_id().into())].into())).into()
+}
+
+fn pool_id() -> AccountId {
+	AccountId::new([1u8; 32])
+}
+
+/// Registers USDT as a foreign asset and creates a pool with USDT as
+/// collateral, which issues one bonded currency at a constant price.
+fn create_usdt_pool(owner: AccountId) {
+	assert_ok!(<Fungibles as Create<AccountId>>::create(
+		usdt_location(),
+		owner.clone(),
+		true,
+		USDT_MIN_BALANCE
+	));
+	assert_ok!(<BondedFungibles as Create<AccountId>>::create(
+		BONDED_CURRENCY_ID,
+		pool_id(),
+		false,
+		1
+	));
+
+	let curve = Curve::Polynomial(PolynomialParameters {
+		m: FixedPoint::from_num(0),
+		n: FixedPoint::from_num(0),
+		o: FixedPoint::from_num(1),
+	});
+	let mut pool_details = PoolDetailsOf::<PeregrineRuntime>::new(
+		owner,
+		curve,
+		usdt_location(),
+		vec![BONDED_CURRENCY_ID]
+			.try_into()
+			.expect("One currency is within bounds"),
+		true,
+		false,
+		0,
+		1,
+		0,
+	);
+	pool_details.state = PoolStatus::Active;
+	Pools::<PeregrineRuntime>::insert(pool_id(), pool_details);
+}
+
+/// Returns the local account of an account on Asset Hub.
+fn get_remote_account_on_peregrine(account: AccountId) -> AccountId {
+	let location = Location::new(
+		1,
+		[
+			Parachain(AssetHub::para_id().into()),
+			AccountId32 {
+				network: None,
+				id: account.into(),
+			},
+		],
+	);
+	LocationToAccountIdWithRemoteAccountsConverter::convert_location(&location)
+		.expect("Accounts on sibling chains can be converted")
+}
+
+fn get_xcm_message_mint_into(
+	beneficiary: AccountId,
+	amount_to_mint: Balance,
+	fee_balance: Balance,
+) -> VersionedXcm<()> {
+	let call: DoubleEncoded<()> =
+		<Peregrine as Chain>::RuntimeCall::BondedCurrencies(pallet_bonded_coins::Call::mint_into {
+			pool_id: pool_id(),
+			currency_idx: 0,
+			beneficiary: beneficiary.into(),
+			amount_to_mint,
+			max_cost: USDT_BALANCE / 2,
+			currency_count: 1,
+		})
+		.encode()
+		.into();
+
+	VersionedXcm::from(Xcm(vec![
+		WithdrawAsset((usdt_location(), fee_balance).into()),
+		BuyExecution {
+			fees: (usdt_location(), fee_balance).into(),
+			weight_limit: WeightLimit::Unlimited,
+		},
+		Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			require_weight_at_most: Weight::from_parts(10_000_000_000, 200_000),
+			call,
+		},
+	]))
+}
+
+#[test]
+fn test_mint_into_from_asset_hub_account_successful() {
+	MockNetwork::reset();
+
+	let alice_account_id = get_account_id_from_seed::<sr25519::Public>(ALICE);
+	let remote_account = get_remote_account_on_peregrine(alice_account_id.clone());
+	let amount_to_mint = 1_000;
+
+	Peregrine::execute_with(|| {
+		create_usdt_pool(alice_account_id.clone());
+		assert_ok!(<Fungibles as Mutate<AccountId>>::mint_into(
+			usdt_location(),
+			&remote_account,
+			USDT_BALANCE
+		));
+		// The remote account needs a provider reference to hold the non-sufficient
+		// bonded currency.
+		<peregrine_runtime::Balances as fungible::Mutate<AccountId>>::set_balance(&remote_account, KILT);
+	});
+
+	AssetHub::execute_with(|| {
+		assert_ok!(<AssetHub as AssetHubRococoParaPallet>::PolkadotXcm::send(
+			<AssetHub as Chain>::RuntimeOrigin::signed(alice_account_id),
+			Box::new(get_sibling_destination_peregrine()),
+			Box::new(get_xcm_message_mint_into(
+				remote_account.clone(),
+				amount_to_mint,
+				USDT_BALANCE / 2
+			))
+		));
+
+		type RuntimeEvent = <AssetHub as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHub,
+			vec![
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Sent { .. }) => {},
+			]
+		);
+	});
+
+	Peregrine::execute_with(|| {
+		type PeregrineRuntimeEvent = <Peregrine as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			Peregrine,
+			vec![
+				PeregrineRuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed { success: true, .. }) => {},
+			]
+		);
+
+		assert_eq!(
+			<BondedFungibles as Inspect<AccountId>>::balance(BONDED_CURRENCY_ID, &remote_account),
+			amount_to_mint
+		);
+		// The collateral is taken from the remote account and backs the minted
+		// currency in the pool account.
+		let pool_collateral = <Fungibles as Inspect<AccountId>>::balance(usdt_location(), &pool_id());
+		assert!(pool_collateral >= amount_to_mint);
+		// Both the XCM fees and the collateral were paid in USDT.
+		assert!(<Fungibles as Inspect<AccountId>>::balance(usdt_location(), &remote_account) < USDT_BALANCE / 2);
+	});
+
+	Rococo::execute_with(|| {
+		assert_eq!(Rococo::events().len(), 0);
+	});
+}
+
+#[test]
+fn test_burn_into_remote_to_asset_hub_successful() {
+	MockNetwork::reset();
+
+	let alice_account_id = get_account_id_from_seed::<sr25519::Public>(ALICE);
+	let bob_account_id = get_account_id_from_seed::<sr25519::Public>(BOB);
+	let peregrine_sovereign_account =
+		AssetHub::sovereign_account_id_of(AssetHub::sibling_location_of(Peregrine::para_id()));
+	let amount_to_mint = 10_000_000_000;
+
+	Peregrine::execute_with(|| {
+		create_usdt_pool(alice_account_id.clone());
+		assert_ok!(<Fungibles as Mutate<AccountId>>::mint_into(
+			usdt_location(),
+			&alice_account_id,
+			USDT_BALANCE
+		));
+		assert_ok!(BondedCurrencies::mint_into(
+			RawOrigin::Signed(alice_account_id.clone()).into(),
+			pool_id(),
+			0,
+			alice_account_id.clone().into(),
+			amount_to_mint,
+			USDT_BALANCE,
+			1
+		));
+	});
+
+	// The collateral of the pool is held by the sovereign account of Peregrine
+	// on its reserve chain.
+	AssetHub::execute_with(|| {
+		type Assets = <AssetHub as AssetHubRococoParaPallet>::Assets;
+
+		if !<Assets as Inspect<AccountId>>::asset_exists(USDT_ID) {
+			assert_ok!(<Assets as Create<AccountId>>::create(
+				USDT_ID,
+				alice_account_id.clone(),
+				true,
+				USDT_MIN_BALANCE
+			));
+		}
+		assert_ok!(<Assets as Mutate<AccountId>>::mint_into(
+			USDT_ID,
+			&peregrine_sovereign_account,
+			USDT_BALANCE
+		));
+	});
+
+	let beneficiary: VersionedLocation = Location::new(
+		0,
+		[AccountId32 {
+			network: None,
+			id: bob_account_id.clone().into(),
+		}],
+	)
+	.into();
+
+	Peregrine::execute_with(|| {
+		assert_ok!(BondedCurrencies::burn_into_remote(
+			RawOrigin::Signed(alice_account_id.clone()).into(),
+			pool_id(),
+			0,
+			Box::new(beneficiary.clone()),
+			amount_to_mint,
+			1,
+			1
+		));
+
+		type PeregrineRuntimeEvent = <Peregrine as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			Peregrine,
+			vec![
+				PeregrineRuntimeEvent::PolkadotXcm(pallet_xcm::Event::Sent { .. }) => {},
+				PeregrineRuntimeEvent::BondedCurrencies(pallet_bonded_coins::Event::CollateralSentRemote { who, beneficiary: sent_to, .. }) => {
+					who: who == &alice_account_id,
+					sent_to: sent_to == &beneficiary,
+				},
+			]
+		);
+
+		assert_eq!(
+			<BondedFungibles as Inspect<AccountId>>::balance(BONDED_CURRENCY_ID, &alice_account_id),
+			0
+		);
+	});
+
+	Rococo::execute_with(|| {
+		assert_eq!(Rococo::events().len(), 0);
+	});
+
+	AssetHub::execute_with(|| {
+		type RuntimeEvent = <AssetHub as Chain>::RuntimeEvent;
+		type Assets = <AssetHub as AssetHubRococoParaPallet>::Assets;
+
+		assert_expected_events!(
+			AssetHub,
+			vec![
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed { success: true, .. }) => {},
+			]
+		);
+
+		// The beneficiary receives the collateral minus the XCM fees on Asset Hub.
+		let received = <Assets as Inspect<AccountId>>::balance(USDT_ID, &bob_account_id);
+		assert!(received > 0);
+		assert!(received <= amount_to_mint);
+	});
+}
@@ -16,6 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
+mod bonded_coins;
 mod did_pallets;
 mod reserve_transfers;
 mod sudo;
@@ -34,6 +34,10 @@ use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
 use runtime_common::{opaque::Block, AccountId, Balance, Nonce};
 
+use crate::rpc::did::{Did, DidApiServer};
+
+mod did;
+
 /// A type representing all RPC extensions.
 pub(crate) type RpcExtension = jsonrpsee::RpcModule<()>;
 
@@ -64,7 +68,8 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
 		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
 		+ BlockBuilder<Block>
-		+ pallet_ismp_runtime_api::IsmpRuntimeApi<Block, sp_core::H256>,
+		+ pallet_ismp_runtime_api::IsmpRuntimeApi<Block, sp_core::H256>
+		+ kilt_runtime_api_did::Web3NameLookup<Block, pallet_did_lookup::linkable_account::LinkableAccountId>,
 
 	P: TransactionPool + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
@@ -78,6 +83,7 @@ where
 
 	module.merge(System::new(Arc::clone(&client), pool).into_rpc())?;
 	module.merge(TransactionPayment::new(Arc::clone(&client)).into_rpc())?;
+	module.merge(Did::new(Arc::clone(&client)).into_rpc())?;
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
 	// to call into the runtime.
@@ -0,0 +1,92 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! RPC methods to resolve DID related information.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use kilt_runtime_api_did::Web3NameLookup;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// DID RPC methods.
+#[rpc(server)]
+pub trait DidApi<BlockHash> {
+	/// Resolve each of the given accounts to the web3name of the DID it is
+	/// linked to, if any.
+	#[method(name = "did_queryWeb3NamesByAccount")]
+	fn query_web3_names_by_account(
+		&self,
+		accounts: Vec<LinkableAccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Option<String>>>;
+}
+
+/// Implementation of the DID RPC methods.
+pub struct Did<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Did<C, Block> {
+	/// Create a new instance of the DID RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block> DidApiServer<<Block as BlockT>::Hash> for Did<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: Web3NameLookup<Block, LinkableAccountId>,
+{
+	fn query_web3_names_by_account(
+		&self,
+		accounts: Vec<LinkableAccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Option<String>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let names = self
+			.client
+			.runtime_api()
+			.batch_query_web3_name_by_account(at_hash, accounts)
+			.map_err(|e| -> ErrorObjectOwned {
+				ErrorObject::owned(RUNTIME_ERROR, "Unable to query web3names.", Some(e.to_string()))
+			})?;
+
+		Ok(names
+			.into_iter()
+			.map(|name| name.map(|name| String::from_utf8_lossy(&name).into_owned()))
+			.collect())
+	}
+}
@@ -44,6 +44,7 @@ use sp_runtime::traits::BlakeTwo256;
 use std::{sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
 
+use pallet_did_lookup::linkable_account::LinkableAccountId;
 use runtime_common::{AccountId, AuthorityId, Balance, BlockNumber, Hash, Nonce};
 
 pub const AUTHORING_DURATION: u64 = 1500;
@@ -211,7 +212,8 @@ where
 		+ sp_consensus_aura::AuraApi<Block, AuthorityId>
 		+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
 		+ pallet_ismp_runtime_api::IsmpRuntimeApi<Block, sp_core::H256>
-		+ ismp_parachain_runtime_api::IsmpParachainApi<Block>,
+		+ ismp_parachain_runtime_api::IsmpParachainApi<Block>
+		+ kilt_runtime_api_did::Web3NameLookup<Block, LinkableAccountId>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_state_machine::Backend<BlakeTwo256>,
 	RB: FnOnce(
 			Arc<TFullClient<Block, RuntimeApi, WasmExecutor<HostFunctions>>>,
@@ -435,7 +437,8 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
 		+ pallet_ismp_runtime_api::IsmpRuntimeApi<Block, sp_core::H256>
-		+ ismp_parachain_runtime_api::IsmpParachainApi<Block>,
+		+ ismp_parachain_runtime_api::IsmpParachainApi<Block>
+		+ kilt_runtime_api_did::Web3NameLookup<Block, LinkableAccountId>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>: sp_state_machine::Backend<BlakeTwo256>,
 {
 	start_node_impl::<API, _, _>(
@@ -16,6 +16,8 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
+use frame_support::weights::Weight;
+
 use crate::{Config, DidIdentifierOf};
 
 /// Runtime logic evaluated by the DID pallet upon deleting an existing DID.
@@ -23,10 +25,18 @@ pub trait DidDeletionHook<T>
 where
 	T: Config,
 {
-	/// Return whether the DID can be deleted (`Ok(())`), or not. In case of
-	/// error, the consumed weight (less than or equal to `MAX_WEIGHT`) is
-	/// returned.
+	/// Return whether the DID can be deleted or not.
 	fn can_delete(did: &DidIdentifierOf<T>) -> bool;
+
+	/// Clean up the runtime state referring to the DID after it has been
+	/// deleted.
+	fn on_deleted(_did: &DidIdentifierOf<T>) {}
+
+	/// Return the maximum weight consumed by `on_deleted`, which is charged
+	/// by the calls deleting a DID on top of their own weight.
+	fn on_deleted_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 impl<T> DidDeletionHook<T> for ()
@@ -0,0 +1,113 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+/// Constant-Product AMM Module
+///
+/// This module provides the arithmetic of the constant-product AMM that a pool
+/// migrates its liquidity into upon graduation. The AMM keeps the product of
+/// its reserves constant on swaps:
+/// ```text
+/// x * y = k
+/// ```
+/// Where:
+/// - `x` is the collateral reserve,
+/// - `y` is the bonded currency reserve.
+///
+/// ### Swaps
+/// Swapping `dx` into the AMM (after deducting the swap fee) returns
+/// ```text
+/// dy = y * dx / (x + dx)
+/// ```
+/// The swap fee remains in the reserves and thus accrues to liquidity
+/// providers.
+///
+/// ### Optimization for Numerical Stability
+/// All calculations are performed on `U256`, which can hold the product of any
+/// two `u128` balances without overflowing. Results are rounded in favour of
+/// the AMM.
+use sp_arithmetic::ArithmeticError;
+use sp_core::U256;
+use sp_runtime::traits::CheckedConversion;
+
+use crate::types::Round;
+
+fn to_u256<Balance: TryInto<U256>>(balance: Balance) -> Result<U256, ArithmeticError> {
+	balance.checked_into().ok_or(ArithmeticError::Overflow)
+}
+
+fn from_u256<Balance: TryFrom<U256>>(value: U256) -> Result<Balance, ArithmeticError> {
+	value.checked_into().ok_or(ArithmeticError::Overflow)
+}
+
+/// Calculates `a * b / c`, rounded in the given direction.
+///
+/// # Errors
+/// - `ArithmeticError::DivisionByZero`: If `c` is zero.
+/// - `ArithmeticError::Overflow`: If a value or the result cannot be
+///   converted.
+pub fn mul_div<A, B, C, Out>(a: A, b: B, c: C, round_kind: Round) -> Result<Out, ArithmeticError>
+where
+	A: TryInto<U256>,
+	B: TryInto<U256>,
+	C: TryInto<U256>,
+	Out: TryFrom<U256>,
+{
+	let numerator = to_u256(a)?.checked_mul(to_u256(b)?).ok_or(ArithmeticError::Overflow)?;
+	let denominator = to_u256(c)?;
+
+	let quotient = numerator
+		.checked_div(denominator)
+		.ok_or(ArithmeticError::DivisionByZero)?;
+
+	let remainder = numerator
+		.checked_rem(denominator)
+		.ok_or(ArithmeticError::DivisionByZero)?;
+
+	let result = if round_kind == Round::Up && !remainder.is_zero() {
+		quotient.checked_add(U256::one()).ok_or(ArithmeticError::Overflow)?
+	} else {
+		quotient
+	};
+
+	from_u256(result)
+}
+
+/// Calculates the output of swapping `amount_in` into the AMM, rounded down.
+/// The swap fee must already be deducted from `amount_in`.
+///
+/// # Parameters
+/// - `amount_in`: The amount paid into the AMM, excluding the swap fee.
+/// - `reserve_in`: The reserve of the currency paid into the AMM.
+/// - `reserve_out`: The reserve of the currency paid out by the AMM.
+///
+/// # Errors
+/// - `ArithmeticError::DivisionByZero`: If both `amount_in` and `reserve_in`
+///   are zero.
+/// - `ArithmeticError::Overflow`: If a value cannot be converted.
+pub fn get_amount_out<In, Out>(amount_in: In, reserve_in: In, reserve_out: Out) -> Result<Out, ArithmeticError>
+where
+	In: TryInto<U256>,
+	Out: TryInto<U256> + TryFrom<U256>,
+{
+	let amount_in = to_u256(amount_in)?;
+	let reserve_in_post = to_u256(reserve_in)?
+		.checked_add(amount_in)
+		.ok_or(ArithmeticError::Overflow)?;
+
+	mul_div(reserve_out, amount_in, reserve_in_post, Round::Down)
+}
@@ -17,24 +17,33 @@
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 use frame_benchmarking::v2::*;
 use frame_support::traits::fungibles::roles::Inspect as InspectRoles;
+use parity_scale_codec::Decode;
 use scale_info::prelude::format;
 use sp_core::U256;
+use sp_runtime::traits::TrailingZeroInput;
 use sp_std::{
+	boxed::Box,
 	ops::{AddAssign, BitOrAssign, ShlAssign},
+	vec,
 	vec::Vec,
 };
 use substrate_fixed::traits::{Fixed, FixedSigned, FixedUnsigned, ToFixed};
 
 use crate::{
 	curves::{
+		exponential::{ExponentialParameters, ExponentialParametersInput},
 		lmsr::{LMSRParameters, LMSRParametersInput},
+		piecewise_linear::{
+			ControlPoint, PiecewiseLinearParameters, PiecewiseLinearParametersInput, MAX_CONTROL_POINTS,
+		},
 		polynomial::PolynomialParameters,
 		square_root::{SquareRootParameters, SquareRootParametersInput},
 		Curve, CurveInput,
 	},
-	types::BondedCurrenciesSettings,
+	traits::VerifyIssuer,
+	types::{BondedCurrenciesSettings, PoolFees},
 	Call, CollateralAssetIdOf, CollateralBalanceOf, Config, CurveParameterTypeOf, FungiblesAssetIdOf,
-	FungiblesBalanceOf, Pallet,
+	FungiblesBalanceOf, Pallet, RemoteBeneficiaryOf,
 };
 
 /// Helper trait to calculate asset ids for collateral and bonded assets used in
@@ -47,6 +56,14 @@ pub trait BenchmarkHelper<T: Config> {
 	fn calculate_bonded_asset_id(seed: u32) -> FungiblesAssetIdOf<T>;
 
 	fn set_native_balance(account: &T::AccountId, amount: u128);
+
+	/// Calculate the asset id for a collateral asset which can be sent to
+	/// another chain via `RemoteCollaterals`.
+	fn calculate_remote_collateral_asset_id(seed: u32) -> CollateralAssetIdOf<T>;
+
+	/// Prepare sending collateral to another chain, e.g., by opening a
+	/// channel, and return a beneficiary on that chain.
+	fn prepare_remote_transfer(seed: u32) -> RemoteBeneficiaryOf<T>;
 }
 
 impl<T> BenchmarkHelper<T> for ()
@@ -64,6 +81,15 @@ where
 	}
 
 	fn set_native_balance(_account: &<T>::AccountId, _amount: u128) {}
+
+	fn calculate_remote_collateral_asset_id(seed: u32) -> CollateralAssetIdOf<T> {
+		seed.into()
+	}
+
+	fn prepare_remote_transfer(_seed: u32) -> RemoteBeneficiaryOf<T> {
+		RemoteBeneficiaryOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+			.expect("Beneficiary should be decodable from zeroes")
+	}
 }
 
 fn get_2nd_order_polynomial_curve<Float: FixedSigned>() -> Curve<Float> {
@@ -95,6 +121,49 @@ fn get_lmsr_curve_input<Float: FixedUnsigned>() -> CurveInput<Float> {
 	CurveInput::Lmsr(LMSRParametersInput { m })
 }
 
+fn get_piecewise_linear_curve<Float: FixedSigned>() -> Curve<Float> {
+	// Use the maximum number of control points to benchmark the worst case.
+	let points = (0..MAX_CONTROL_POINTS)
+		.map(|i| ControlPoint {
+			supply: Float::from_num(i),
+			price: Float::from_num(i.saturating_add(1)),
+		})
+		.collect::<Vec<_>>();
+	Curve::PiecewiseLinear(PiecewiseLinearParameters {
+		points: points
+			.try_into()
+			.expect("Number of control points should not exceed the maximum"),
+	})
+}
+
+fn get_piecewise_linear_curve_input<Float: FixedUnsigned>() -> CurveInput<Float> {
+	let points = (0..MAX_CONTROL_POINTS)
+		.map(|i| ControlPoint {
+			supply: Float::from_num(i),
+			price: Float::from_num(i.saturating_add(1)),
+		})
+		.collect::<Vec<_>>();
+	CurveInput::PiecewiseLinear(PiecewiseLinearParametersInput {
+		points: points
+			.try_into()
+			.expect("Number of control points should not exceed the maximum"),
+	})
+}
+
+fn get_exponential_curve<Float: FixedSigned>() -> Curve<Float> {
+	let m = Float::from_num(1);
+	let k = Float::from_num(0.1);
+	let cap = Float::from_num(2);
+	Curve::Exponential(ExponentialParameters { m, k, cap })
+}
+
+fn get_exponential_curve_input<Float: FixedUnsigned>() -> CurveInput<Float> {
+	let m = Float::from_num(1);
+	let k = Float::from_num(0.1);
+	let cap = Float::from_num(2);
+	CurveInput::Exponential(ExponentialParametersInput { m, k, cap })
+}
+
 #[benchmarks(where
 	<CurveParameterTypeOf<T> as Fixed>::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign + TryFrom<U256> + TryInto<U256>,
 	CollateralBalanceOf<T>: Into<U256> + TryFrom<U256>,
@@ -110,6 +179,7 @@ mod benchmarks {
 		fungibles::{Create, Destroy, Inspect as InspectFungibles, Mutate as MutateFungibles},
 		AccountTouch, EnsureOrigin, Get, OriginTrait,
 	};
+	use frame_system::pallet_prelude::BlockNumberFor;
 	use sp_runtime::{traits::Zero, BoundedVec, SaturatedConversion};
 	use sp_std::ops::Mul;
 
@@ -117,7 +187,10 @@ mod benchmarks {
 		curves::Curve,
 		mock::*,
 		types::{Locks, PoolManagingTeam, PoolStatus},
-		AccountIdLookupOf, AccountIdOf, CollateralAssetIdOf, CurveParameterInputOf, PoolDetailsOf, Pools, TokenMetaOf,
+		AccountIdLookupOf, AccountIdOf, AccruedFees, AmmPoolOf, AmmPools, CollateralAssetIdOf, CurveParameterInputOf,
+		GraduationSettingsOf, LiquidityShares, Metadata, PoolDetailsOf, PoolFeeSettings, PoolGraduationSettings,
+		PoolIssuerOf, PoolIssuers, PoolMetadataOf, PoolResolvers, PoolVestingSettings, Pools, PriceAccumulators,
+		ResolvedOutcomes, TokenMetaOf, VestingScheduleOf, VestingSchedules, VestingSettingsOf,
 	};
 
 	use super::*;
@@ -147,6 +220,13 @@ mod benchmarks {
 		collateral_id
 	}
 
+	fn in_smallest_units(amount: u128, denomination: u8) -> u128 {
+		10u128
+			.checked_pow(denomination.into())
+			.and_then(|scale| scale.checked_mul(amount))
+			.expect("Amount in smallest units should not overflow")
+	}
+
 	fn set_collateral_balance<T: Config>(asset_id: CollateralAssetIdOf<T>, who: &AccountIdOf<T>, amount: u128)
 	where
 		T::Collaterals: MutateFungibles<T::AccountId>,
@@ -244,6 +324,89 @@ mod benchmarks {
 		pool_id
 	}
 
+	fn set_max_pool_fees<T: Config>(pool_id: &T::PoolId, collateral_id: CollateralAssetIdOf<T>)
+	where
+		T::Collaterals: MutateFungibles<T::AccountId>,
+	{
+		PoolFeeSettings::<T>::insert(
+			pool_id,
+			PoolFees {
+				mint: T::MaxPoolFee::get(),
+				burn: T::MaxPoolFee::get(),
+			},
+		);
+
+		// The protocol fee destination must be able to receive the protocol fee.
+		let protocol_fee_destination = T::ProtocolFeeDestination::get();
+		make_free_for_deposit::<T>(&protocol_fee_destination);
+		set_collateral_balance::<T>(collateral_id, &protocol_fee_destination, 10000u128);
+	}
+
+	fn set_max_vesting_schedules<T: Config>(
+		pool_id: &T::PoolId,
+		asset_id: FungiblesAssetIdOf<T>,
+		who: &AccountIdOf<T>,
+	) {
+		// Every mint vests.
+		PoolVestingSettings::<T>::insert(
+			pool_id,
+			VestingSettingsOf::<T> {
+				cliff: 10u32.into(),
+				duration: 100u32.into(),
+				threshold: Some(Zero::zero()),
+			},
+		);
+
+		// Leave room for exactly one more schedule.
+		let schedules: Vec<VestingScheduleOf<T>> = (1..T::MaxVestingSchedules::get())
+			.map(|_| VestingScheduleOf::<T> {
+				locked: Zero::zero(),
+				start: Zero::zero(),
+				duration: 100u32.into(),
+			})
+			.collect();
+		VestingSchedules::<T>::insert(asset_id, who, BoundedVec::truncate_from(schedules));
+	}
+
+	fn create_graduated_pool<T: Config>(
+		manager: &AccountIdOf<T>,
+	) -> (T::PoolId, CollateralAssetIdOf<T>, FungiblesAssetIdOf<T>)
+	where
+		<CurveParameterTypeOf<T> as Fixed>::Bits:
+			Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign + TryFrom<U256> + TryInto<U256>,
+		CollateralBalanceOf<T>: Into<U256> + TryFrom<U256>,
+		FungiblesBalanceOf<T>: Into<U256> + TryFrom<U256>,
+		T::Collaterals: Create<T::AccountId> + MutateFungibles<T::AccountId>,
+	{
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(1, false);
+		let bonded_id = bonded_currencies[0].clone();
+
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(
+			curve,
+			bonded_currencies,
+			Some(manager.clone()),
+			Some(PoolStatus::Graduated),
+			None,
+		);
+
+		let pool_account = pool_id.clone().into();
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, manager).expect("Touching should work");
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 1_000_000u128);
+
+		AmmPools::<T>::insert(
+			&pool_id,
+			AmmPoolOf::<T> {
+				collateral_reserve: 1_000_000u128.saturated_into(),
+				bonded_reserve: 1_000_000u128.saturated_into(),
+				total_shares: 1_000_000u128.saturated_into(),
+			},
+		);
+
+		(pool_id, collateral_id, bonded_id)
+	}
+
 	fn generate_token_metadata<T: Config>(c: u32) -> BoundedVec<TokenMetaOf<T>, T::MaxCurrenciesPerPool> {
 		let mut token_meta = Vec::new();
 		for i in 1..=c {
@@ -282,6 +445,7 @@ mod benchmarks {
 				transferable: true,
 				min_operation_balance: 1u128.saturated_into(),
 			},
+			PoolFees::default(),
 		);
 
 		// Verify
@@ -323,6 +487,7 @@ mod benchmarks {
 				transferable: true,
 				min_operation_balance: 1u128.saturated_into(),
 			},
+			PoolFees::default(),
 		);
 
 		// Verify
@@ -363,6 +528,7 @@ mod benchmarks {
 				transferable: true,
 				min_operation_balance: 1u128.saturated_into(),
 			},
+			PoolFees::default(),
 		);
 
 		// Verify
@@ -376,6 +542,88 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn create_pool_piecewise_linear(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let collateral_id = create_default_collateral_asset::<T>();
+
+		let curve = get_piecewise_linear_curve_input::<CurveParameterInputOf<T>>();
+		let currencies = generate_token_metadata::<T>(c);
+
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		#[extrinsic_call]
+		create_pool(
+			origin as T::RuntimeOrigin,
+			curve,
+			collateral_id,
+			currencies,
+			BondedCurrenciesSettings {
+				denomination: 10,
+				allow_reset_team: true,
+				transferable: true,
+				min_operation_balance: 1u128.saturated_into(),
+			},
+			PoolFees::default(),
+		);
+
+		// Verify
+		let (id, pool) = Pools::<T>::iter().next().expect("Pool should exist");
+		let expected_pool_id: T::PoolId = calculate_pool_id(&pool.bonded_currencies.into_inner());
+		match pool.curve {
+			Curve::PiecewiseLinear(_) => {
+				assert_eq!(id, expected_pool_id);
+			}
+			_ => panic!("pool.curve is not a PiecewiseLinear curve!"),
+		}
+	}
+
+	#[benchmark]
+	fn create_pool_exponential(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let collateral_id = create_default_collateral_asset::<T>();
+
+		let curve = get_exponential_curve_input::<CurveParameterInputOf<T>>();
+		let currencies = generate_token_metadata::<T>(c);
+
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		#[extrinsic_call]
+		create_pool(
+			origin as T::RuntimeOrigin,
+			curve,
+			collateral_id,
+			currencies,
+			BondedCurrenciesSettings {
+				denomination: 10,
+				allow_reset_team: true,
+				transferable: true,
+				min_operation_balance: 1u128.saturated_into(),
+			},
+			PoolFees::default(),
+		);
+
+		// Verify
+		let (id, pool) = Pools::<T>::iter().next().expect("Pool should exist");
+		let expected_pool_id: T::PoolId = calculate_pool_id(&pool.bonded_currencies.into_inner());
+		match pool.curve {
+			Curve::Exponential(_) => {
+				assert_eq!(id, expected_pool_id);
+			}
+			_ => panic!("pool.curve is not an Exponential curve!"),
+		}
+	}
+
 	#[benchmark]
 	fn reset_team(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
 		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
@@ -387,7 +635,7 @@ mod benchmarks {
 
 		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
 
-		let curve = get_linear_bonding_curve::<CurveParameterTypeOf<T>>();
+		let curve = get_2nd_order_polynomial_curve::<CurveParameterTypeOf<T>>();
 		let pool_id = create_pool::<T>(curve, bonded_currencies.clone(), Some(account_origin), None, None);
 
 		// Although these would rarely happen in practice, for benchmarking we assume
@@ -433,7 +681,7 @@ mod benchmarks {
 		let bonded_coin_id = T::BenchmarkHelper::calculate_bonded_asset_id(0);
 		create_bonded_asset::<T>(bonded_coin_id.clone());
 
-		let curve = get_linear_bonding_curve::<CurveParameterTypeOf<T>>();
+		let curve = get_2nd_order_polynomial_curve::<CurveParameterTypeOf<T>>();
 		let pool_id = create_pool::<T>(curve, [bonded_coin_id].to_vec(), Some(account_origin), None, None);
 
 		#[extrinsic_call]
@@ -455,7 +703,7 @@ mod benchmarks {
 		let bonded_coin_id = T::BenchmarkHelper::calculate_bonded_asset_id(0);
 		create_bonded_asset::<T>(bonded_coin_id.clone());
 
-		let curve = get_linear_bonding_curve::<CurveParameterTypeOf<T>>();
+		let curve = get_2nd_order_polynomial_curve::<CurveParameterTypeOf<T>>();
 		let pool_id = create_pool::<T>(curve, [bonded_coin_id].to_vec(), Some(account_origin), None, None);
 
 		let locks = Locks::default();
@@ -479,7 +727,7 @@ mod benchmarks {
 		let bonded_coin_id = T::BenchmarkHelper::calculate_bonded_asset_id(0);
 		create_bonded_asset::<T>(bonded_coin_id.clone());
 
-		let curve = get_linear_bonding_curve::<CurveParameterTypeOf<T>>();
+		let curve = get_2nd_order_polynomial_curve::<CurveParameterTypeOf<T>>();
 		let pool_id = create_pool::<T>(
 			curve,
 			[bonded_coin_id].to_vec(),
@@ -511,7 +759,10 @@ mod benchmarks {
 
 		let pool_id = create_pool::<T>(curve, bonded_currencies.clone(), None, None, Some(0));
 
-		T::Collaterals::touch(collateral_id, &pool_id.clone().into(), &account_origin).expect("Touching should work");
+		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
+			.expect("Touching should work");
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, bonded_currencies[0].clone(), &account_origin);
 
 		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
 		let amount_to_mint = 10u128.saturated_into();
@@ -553,7 +804,10 @@ mod benchmarks {
 
 		let pool_id = create_pool::<T>(curve, bonded_currencies.clone(), None, None, Some(0));
 
-		T::Collaterals::touch(collateral_id, &pool_id.clone().into(), &account_origin).expect("Touching should work");
+		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
+			.expect("Touching should work");
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, bonded_currencies[0].clone(), &account_origin);
 
 		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
 		let amount_to_mint = 10u128.saturated_into();
@@ -596,7 +850,98 @@ mod benchmarks {
 
 		let pool_id = create_pool::<T>(curve, bonded_currencies.clone(), None, None, Some(0));
 
-		T::Collaterals::touch(collateral_id, &pool_id.clone().into(), &account_origin).expect("Touching should work");
+		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
+			.expect("Touching should work");
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, bonded_currencies[0].clone(), &account_origin);
+
+		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
+		let amount_to_mint = 10u128.saturated_into();
+		let max_costs = 100000u128.saturated_into();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		mint_into(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			beneficiary,
+			amount_to_mint,
+			max_costs,
+			max_currencies,
+		);
+
+		// Verify
+		let target_asset_id = bonded_currencies[0].clone();
+		let balance = T::Fungibles::balance(target_asset_id, &account_origin);
+		assert_eq!(balance, amount_to_mint.saturated_into());
+	}
+
+	#[benchmark]
+	fn mint_into_piecewise_linear(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let collateral_id = create_default_collateral_asset::<T>();
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+		set_collateral_balance::<T>(collateral_id.clone(), &account_origin, 10000u128);
+
+		let curve = get_piecewise_linear_curve::<CurveParameterTypeOf<T>>();
+
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+
+		let pool_id = create_pool::<T>(curve, bonded_currencies.clone(), None, None, Some(0));
+
+		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
+			.expect("Touching should work");
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, bonded_currencies[0].clone(), &account_origin);
+
+		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
+		let amount_to_mint = 10u128.saturated_into();
+		let max_costs = 100000u128.saturated_into();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		mint_into(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			beneficiary,
+			amount_to_mint,
+			max_costs,
+			max_currencies,
+		);
+
+		// Verify
+		let target_asset_id = bonded_currencies[0].clone();
+		let balance = T::Fungibles::balance(target_asset_id, &account_origin);
+		assert_eq!(balance, amount_to_mint.saturated_into());
+	}
+
+	#[benchmark]
+	fn mint_into_exponential(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let collateral_id = create_default_collateral_asset::<T>();
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+		set_collateral_balance::<T>(collateral_id.clone(), &account_origin, 10000u128);
+
+		let curve = get_exponential_curve::<CurveParameterTypeOf<T>>();
+
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+
+		let pool_id = create_pool::<T>(curve, bonded_currencies.clone(), None, None, Some(0));
+
+		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
+			.expect("Touching should work");
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, bonded_currencies[0].clone(), &account_origin);
 
 		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
 		let amount_to_mint = 10u128.saturated_into();
@@ -643,7 +988,8 @@ mod benchmarks {
 
 		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
 
-		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
 
 		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
 		let amount_to_burn = 10u128.saturated_into();
@@ -690,7 +1036,8 @@ mod benchmarks {
 
 		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
 
-		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
 
 		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
 		let amount_to_burn = 10u128.saturated_into();
@@ -737,7 +1084,8 @@ mod benchmarks {
 
 		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
 
-		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
 
 		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
 		let amount_to_burn = 10u128.saturated_into();
@@ -763,126 +1111,902 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn start_destroy(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
-		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+	fn burn_into_piecewise_linear(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
 		let account_origin = origin
 			.clone()
 			.into_signer()
 			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
 
+		let collateral_id = create_default_collateral_asset::<T>();
 		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
-		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
-
-		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin), None, None);
-		let pool_id_clone = pool_id.clone();
-		let max_currencies = T::MaxCurrenciesPerPool::get();
-
-		#[extrinsic_call]
-		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+		let target_asset_id = bonded_currencies[0].clone();
 
-		// Verify
+		let start_balance = 100u128;
+		set_fungible_balance::<T>(target_asset_id.clone(), &account_origin, start_balance);
 
-		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
-		assert_eq!(pool_details.state, PoolStatus::Destroying);
-	}
+		let curve = get_piecewise_linear_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, Some(0));
+		let pool_account = pool_id.clone().into();
 
-	#[benchmark]
-	fn force_start_destroy(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
-		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
-		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
 
-		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, None);
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
 
-		let origin = T::ForceOrigin::try_successful_origin().expect("creating origin should not fail");
-		let pool_id_clone = pool_id.clone();
+		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
+		let amount_to_burn = 10u128.saturated_into();
+		let min_return = 0u128.saturated_into();
 		let max_currencies = T::MaxCurrenciesPerPool::get();
 
 		#[extrinsic_call]
-		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
-
-		// Verify
-
-		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
+		burn_into(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			beneficiary,
+			amount_to_burn,
+			min_return,
+			max_currencies,
+		);
+
+		let balance = T::Fungibles::balance(target_asset_id, &account_origin);
+		assert_eq!(
+			balance,
+			(start_balance - amount_to_burn.saturated_into::<u128>()).saturated_into()
+		);
+	}
+
+	#[benchmark]
+	fn burn_into_exponential(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let target_asset_id = bonded_currencies[0].clone();
+
+		let start_balance = 100u128;
+		set_fungible_balance::<T>(target_asset_id.clone(), &account_origin, start_balance);
+
+		let curve = get_exponential_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, Some(0));
+		let pool_account = pool_id.clone().into();
+
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
+
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+
+		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
+		let amount_to_burn = 10u128.saturated_into();
+		let min_return = 0u128.saturated_into();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		burn_into(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			beneficiary,
+			amount_to_burn,
+			min_return,
+			max_currencies,
+		);
+
+		let balance = T::Fungibles::balance(target_asset_id, &account_origin);
+		assert_eq!(
+			balance,
+			(start_balance - amount_to_burn.saturated_into::<u128>()).saturated_into()
+		);
+	}
+
+	#[benchmark]
+	fn swap_within_pool_polynomial(c: Linear<2, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let from_asset_id = bonded_currencies[0].clone();
+		let to_asset_id = bonded_currencies[1].clone();
+
+		// The search for the swap outcome resolves the amount out down to a single
+		// unit, so the maximum denomination maximizes the number of curve evaluations.
+		let denomination = T::MaxDenomination::get();
+		let start_balance = in_smallest_units(100, denomination);
+		set_fungible_balance::<T>(from_asset_id.clone(), &account_origin, start_balance);
+
+		let curve = get_2nd_order_polynomial_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, Some(denomination));
+		let pool_account = pool_id.clone().into();
+
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
+
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, to_asset_id.clone(), &account_origin);
+
+		let amount_in = in_smallest_units(10, denomination).saturated_into();
+		let min_out = 0u128.saturated_into();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		swap_within_pool(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			1,
+			amount_in,
+			min_out,
+			max_currencies,
+		);
+
+		// Verify
+		let balance = T::Fungibles::balance(from_asset_id, &account_origin);
+		assert_eq!(
+			balance,
+			(start_balance - amount_in.saturated_into::<u128>()).saturated_into()
+		);
+		assert!(!T::Fungibles::balance(to_asset_id, &account_origin).is_zero());
+	}
+
+	#[benchmark]
+	fn swap_within_pool_square_root(c: Linear<2, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let from_asset_id = bonded_currencies[0].clone();
+		let to_asset_id = bonded_currencies[1].clone();
+
+		// The search for the swap outcome resolves the amount out down to a single
+		// unit, so the maximum denomination maximizes the number of curve evaluations.
+		let denomination = T::MaxDenomination::get();
+		let start_balance = in_smallest_units(100, denomination);
+		set_fungible_balance::<T>(from_asset_id.clone(), &account_origin, start_balance);
+
+		let curve = get_square_root_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, Some(denomination));
+		let pool_account = pool_id.clone().into();
+
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
+
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, to_asset_id.clone(), &account_origin);
+
+		let amount_in = in_smallest_units(10, denomination).saturated_into();
+		let min_out = 0u128.saturated_into();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		swap_within_pool(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			1,
+			amount_in,
+			min_out,
+			max_currencies,
+		);
+
+		// Verify
+		let balance = T::Fungibles::balance(from_asset_id, &account_origin);
+		assert_eq!(
+			balance,
+			(start_balance - amount_in.saturated_into::<u128>()).saturated_into()
+		);
+		assert!(!T::Fungibles::balance(to_asset_id, &account_origin).is_zero());
+	}
+
+	#[benchmark]
+	fn swap_within_pool_lmsr(c: Linear<2, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let from_asset_id = bonded_currencies[0].clone();
+		let to_asset_id = bonded_currencies[1].clone();
+
+		// The search for the swap outcome resolves the amount out down to a single
+		// unit, so the maximum denomination maximizes the number of curve evaluations.
+		let denomination = T::MaxDenomination::get();
+		let start_balance = in_smallest_units(100, denomination);
+		set_fungible_balance::<T>(from_asset_id.clone(), &account_origin, start_balance);
+
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, Some(denomination));
+		let pool_account = pool_id.clone().into();
+
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
+
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, to_asset_id.clone(), &account_origin);
+
+		let amount_in = in_smallest_units(10, denomination).saturated_into();
+		let min_out = 0u128.saturated_into();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		swap_within_pool(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			1,
+			amount_in,
+			min_out,
+			max_currencies,
+		);
+
+		// Verify
+		let balance = T::Fungibles::balance(from_asset_id, &account_origin);
+		assert_eq!(
+			balance,
+			(start_balance - amount_in.saturated_into::<u128>()).saturated_into()
+		);
+		assert!(!T::Fungibles::balance(to_asset_id, &account_origin).is_zero());
+	}
+
+	#[benchmark]
+	fn swap_within_pool_piecewise_linear(c: Linear<2, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let from_asset_id = bonded_currencies[0].clone();
+		let to_asset_id = bonded_currencies[1].clone();
+
+		// The search for the swap outcome resolves the amount out down to a single
+		// unit, so the maximum denomination maximizes the number of curve evaluations.
+		let denomination = T::MaxDenomination::get();
+		let start_balance = in_smallest_units(100, denomination);
+		set_fungible_balance::<T>(from_asset_id.clone(), &account_origin, start_balance);
+
+		let curve = get_piecewise_linear_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, Some(denomination));
+		let pool_account = pool_id.clone().into();
+
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
+
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, to_asset_id.clone(), &account_origin);
+
+		let amount_in = in_smallest_units(10, denomination).saturated_into();
+		let min_out = 0u128.saturated_into();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		swap_within_pool(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			1,
+			amount_in,
+			min_out,
+			max_currencies,
+		);
+
+		// Verify
+		let balance = T::Fungibles::balance(from_asset_id, &account_origin);
+		assert_eq!(
+			balance,
+			(start_balance - amount_in.saturated_into::<u128>()).saturated_into()
+		);
+		assert!(!T::Fungibles::balance(to_asset_id, &account_origin).is_zero());
+	}
+
+	#[benchmark]
+	fn swap_within_pool_exponential(c: Linear<2, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let from_asset_id = bonded_currencies[0].clone();
+		let to_asset_id = bonded_currencies[1].clone();
+
+		// The search for the swap outcome resolves the amount out down to a single
+		// unit, so the maximum denomination maximizes the number of curve evaluations.
+		let denomination = T::MaxDenomination::get();
+		let start_balance = in_smallest_units(100, denomination);
+		set_fungible_balance::<T>(from_asset_id.clone(), &account_origin, start_balance);
+
+		let curve = get_exponential_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, Some(denomination));
+		let pool_account = pool_id.clone().into();
+
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
+
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+		set_max_vesting_schedules::<T>(&pool_id, to_asset_id.clone(), &account_origin);
+
+		let amount_in = in_smallest_units(10, denomination).saturated_into();
+		let min_out = 0u128.saturated_into();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		swap_within_pool(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			1,
+			amount_in,
+			min_out,
+			max_currencies,
+		);
+
+		// Verify
+		let balance = T::Fungibles::balance(from_asset_id, &account_origin);
+		assert_eq!(
+			balance,
+			(start_balance - amount_in.saturated_into::<u128>()).saturated_into()
+		);
+		assert!(!T::Fungibles::balance(to_asset_id, &account_origin).is_zero());
+	}
+
+	#[benchmark]
+	fn start_destroy(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+
+		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin), None, None);
+		let pool_id_clone = pool_id.clone();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+
+		// Verify
+
+		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
 		assert_eq!(pool_details.state, PoolStatus::Destroying);
 	}
 
 	#[benchmark]
-	fn finish_destroy(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
-		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, true);
-		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+	fn force_start_destroy(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, None);
+
+		let origin = T::ForceOrigin::try_successful_origin().expect("creating origin should not fail");
+		let pool_id_clone = pool_id.clone();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+
+		// Verify
+
+		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
+		assert_eq!(pool_details.state, PoolStatus::Destroying);
+	}
+
+	#[benchmark]
+	fn finish_destroy(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, true);
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		let pool_id = create_pool::<T>(
+			curve,
+			bonded_currencies.clone(),
+			None,
+			Some(PoolStatus::Destroying),
+			None,
+		);
+
+		make_free_for_deposit::<T>(&owner);
+
+		let hold_reason = Pallet::<T>::calculate_hold_reason(&pool_id).expect("Generating HoldReason should not fail");
+
+		T::DepositCurrency::hold(
+			&hold_reason,
+			&owner,
+			Pallet::<T>::calculate_pool_deposit(bonded_currencies.len()),
+		)
+		.expect("Generating Hold should not fail");
+
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let pool_id_clone = pool_id.clone();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+
+		// Verify
+		let pool_details = Pools::<T>::get(&pool_id);
+		assert!(pool_details.is_none());
+	}
+
+	#[benchmark]
+	fn start_refund(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let target_asset_id = bonded_currencies[0].clone();
+
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+
+		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin.clone()), None, None);
+
+		let pool_account = pool_id.clone().into();
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
+			.expect("Touching should work");
+		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
+
+		let holder: T::AccountId = account("holder", 0, 1);
+		T::Fungibles::touch(target_asset_id.clone(), &holder, &account_origin).expect("Touching should work");
+		set_fungible_balance::<T>(target_asset_id, &holder, 10000u128);
+
+		let pool_id_clone = pool_id.clone();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+
+		// Verify
+
+		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
+		assert_eq!(pool_details.state, PoolStatus::Refunding);
+	}
+
+	#[benchmark]
+	fn force_start_refund(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let target_asset_id = bonded_currencies[0].clone();
+
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, None);
+
+		let pool_account = pool_id.clone().into();
+
+		// give the pool account some funds.
+		make_free_for_deposit::<T>(&pool_account);
+		let collateral_id = create_default_collateral_asset::<T>();
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &pool_account).expect("Touching should work");
+		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
+
+		let holder: T::AccountId = account("holder", 0, 0);
+		T::Fungibles::touch(target_asset_id.clone(), &holder, &pool_account).expect("Touching should work");
+		set_fungible_balance::<T>(target_asset_id, &holder, 10000u128);
+
+		let origin = T::ForceOrigin::try_successful_origin().expect("creating origin should not fail");
+		let pool_id_clone = pool_id.clone();
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+
+		// Verify
+		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
+		assert_eq!(pool_details.state, PoolStatus::Refunding);
+	}
+
+	#[benchmark]
+	fn refund_account(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let target_asset_id = bonded_currencies[0].clone();
+
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, Some(PoolStatus::Refunding), None);
+
+		let pool_account = pool_id.clone().into();
+		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
+			.expect("Touching should work");
+		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
+
+		set_fungible_balance::<T>(target_asset_id.clone(), &account_origin, 100000u128);
+
+		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
+		let max_currencies = T::MaxCurrenciesPerPool::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id, beneficiary, 0, max_currencies);
+
+		// Verify
+		let balance = T::Fungibles::balance(target_asset_id, &account_origin);
+		assert_eq!(balance, Zero::zero());
+	}
+
+	#[benchmark]
+	fn claim_fees() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(1, false);
+
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin.clone()), None, None);
+
+		let pool_account = pool_id.clone().into();
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+
+		let accrued_fees: CollateralBalanceOf<T> = 1000u128.saturated_into();
+		AccruedFees::<T>::insert(&pool_id, accrued_fees);
+
+		let beneficiary_account: T::AccountId = account("beneficiary", 0, 0);
+		make_free_for_deposit::<T>(&beneficiary_account);
+		let beneficiary = AccountIdLookupOf::<T>::from(beneficiary_account.clone());
+
+		let pool_id_clone = pool_id.clone();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, beneficiary);
+
+		// Verify
+		assert_eq!(
+			T::Collaterals::balance(collateral_id, &beneficiary_account),
+			accrued_fees
+		);
+		assert!(AccruedFees::<T>::get(&pool_id).is_zero());
+	}
+
+	#[benchmark]
+	fn set_graduation() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(1, false);
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin), None, None);
+
+		let settings = GraduationSettingsOf::<T> {
+			collateral_threshold: 1000u128.saturated_into(),
+		};
+
+		let pool_id_clone = pool_id.clone();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, Some(settings.clone()));
+
+		// Verify
+		assert_eq!(PoolGraduationSettings::<T>::get(&pool_id), Some(settings));
+	}
+
+	#[benchmark]
+	fn graduate() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+
+		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(1, false);
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin.clone()), None, None);
+
+		let pool_account = pool_id.clone().into();
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
+		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
+
+		PoolGraduationSettings::<T>::insert(
+			&pool_id,
+			GraduationSettingsOf::<T> {
+				collateral_threshold: 1000u128.saturated_into(),
+			},
+		);
+
+		let pool_id_clone = pool_id.clone();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone);
+
+		// Verify
+		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
+		assert_eq!(pool_details.state, PoolStatus::Graduated);
+		assert!(AmmPools::<T>::contains_key(&pool_id));
+	}
+
+	#[benchmark]
+	fn swap_collateral_for_bonded() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
 
-		let owner: T::AccountId = account("owner", 0, 0);
-		let pool_id = create_pool::<T>(
-			curve,
-			bonded_currencies.clone(),
-			None,
-			Some(PoolStatus::Destroying),
-			None,
+		let (pool_id, collateral_id, bonded_id) = create_graduated_pool::<T>(&account_origin);
+		set_collateral_balance::<T>(collateral_id, &account_origin, 10000u128);
+		set_max_vesting_schedules::<T>(&pool_id, bonded_id.clone(), &account_origin);
+
+		let amount_in: CollateralBalanceOf<T> = 1000u128.saturated_into();
+		let min_out: FungiblesBalanceOf<T> = 1u128.saturated_into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id, amount_in, min_out);
+
+		// Verify
+		assert!(T::Fungibles::balance(bonded_id, &account_origin) >= min_out);
+	}
+
+	#[benchmark]
+	fn swap_bonded_for_collateral() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let (pool_id, collateral_id, bonded_id) = create_graduated_pool::<T>(&account_origin);
+		set_collateral_balance::<T>(collateral_id.clone(), &account_origin, 10000u128);
+		set_fungible_balance::<T>(bonded_id.clone(), &account_origin, 10000u128);
+
+		let amount_in: FungiblesBalanceOf<T> = 1000u128.saturated_into();
+		let min_out: CollateralBalanceOf<T> = 1u128.saturated_into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id, amount_in, min_out);
+
+		// Verify
+		assert_eq!(
+			T::Fungibles::balance(bonded_id, &account_origin),
+			9000u128.saturated_into()
 		);
+		assert!(T::Collaterals::balance(collateral_id, &account_origin) > 10000u128.saturated_into());
+	}
 
-		make_free_for_deposit::<T>(&owner);
+	#[benchmark]
+	fn add_liquidity() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
 
-		let hold_reason = Pallet::<T>::calculate_hold_reason(&pool_id).expect("Generating HoldReason should not fail");
+		let (pool_id, collateral_id, bonded_id) = create_graduated_pool::<T>(&account_origin);
+		set_collateral_balance::<T>(collateral_id, &account_origin, 10000u128);
+		set_fungible_balance::<T>(bonded_id, &account_origin, 10000u128);
 
-		T::DepositCurrency::hold(
-			&hold_reason,
-			&owner,
-			Pallet::<T>::calculate_pool_deposit(bonded_currencies.len()),
-		)
-		.expect("Generating Hold should not fail");
+		let collateral_amount: CollateralBalanceOf<T> = 1000u128.saturated_into();
+		let max_bonded: FungiblesBalanceOf<T> = 1000u128.saturated_into();
+
+		let pool_id_clone = pool_id.clone();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, collateral_amount, max_bonded);
+
+		// Verify
+		assert_eq!(
+			LiquidityShares::<T>::get(&pool_id, &account_origin),
+			1000u128.saturated_into()
+		);
+	}
 
+	#[benchmark]
+	fn remove_liquidity() {
 		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let (pool_id, collateral_id, bonded_id) = create_graduated_pool::<T>(&account_origin);
+		set_collateral_balance::<T>(collateral_id, &account_origin, 10000u128);
+
+		let shares: CollateralBalanceOf<T> = 1000u128.saturated_into();
+		LiquidityShares::<T>::insert(&pool_id, &account_origin, shares);
+
 		let pool_id_clone = pool_id.clone();
-		let max_currencies = T::MaxCurrenciesPerPool::get();
 
 		#[extrinsic_call]
-		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+		_(
+			origin as T::RuntimeOrigin,
+			pool_id_clone,
+			shares,
+			Zero::zero(),
+			Zero::zero(),
+		);
 
 		// Verify
-		let pool_details = Pools::<T>::get(&pool_id);
-		assert!(pool_details.is_none());
+		assert!(!LiquidityShares::<T>::contains_key(&pool_id, &account_origin));
+		assert_eq!(
+			T::Fungibles::balance(bonded_id, &account_origin),
+			1000u128.saturated_into()
+		);
 	}
 
 	#[benchmark]
-	fn start_refund(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+	fn set_vesting() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(1, false);
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin), None, None);
+
+		let settings = VestingSettingsOf::<T> {
+			cliff: 10u32.into(),
+			duration: 100u32.into(),
+			threshold: Some(1000u128.saturated_into()),
+		};
+
+		let pool_id_clone = pool_id.clone();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, Some(settings.clone()));
+
+		// Verify
+		assert_eq!(PoolVestingSettings::<T>::get(&pool_id), Some(settings));
+	}
+
+	#[benchmark]
+	fn claim_vested() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(1, false);
+		let bonded_id = bonded_currencies[0].clone();
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, None);
+
+		// All schedules vest partially, so that every schedule is updated.
+		let max_schedules = T::MaxVestingSchedules::get();
+		set_fungible_balance::<T>(
+			bonded_id.clone(),
+			&account_origin,
+			1000u128.saturating_mul(max_schedules.into()),
+		);
+		let schedules: Vec<VestingScheduleOf<T>> = (0..max_schedules)
+			.map(|_| VestingScheduleOf::<T> {
+				locked: 1000u128.saturated_into(),
+				start: Zero::zero(),
+				duration: 100u32.into(),
+			})
+			.collect();
+		VestingSchedules::<T>::insert(&bonded_id, &account_origin, BoundedVec::truncate_from(schedules));
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(50u32));
+
+		let account = AccountIdLookupOf::<T>::from(account_origin.clone());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id, account, 0);
+
+		// Verify
+		let locked = VestingSchedules::<T>::get(&bonded_id, &account_origin)
+			.iter()
+			.fold(0u128, |sum, schedule| {
+				sum.saturating_add(schedule.locked.saturated_into())
+			});
+		assert_eq!(locked, 500u128.saturating_mul(max_schedules.into()));
+	}
+
+	#[benchmark]
+	fn set_resolver() {
 		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
 		let account_origin = origin
 			.clone()
 			.into_signer()
 			.expect("generating account_id from origin should not fail");
 
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(2, false);
+		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin), None, None);
+
+		let resolver: T::AccountId = account("resolver", 0, 0);
+		let resolver_lookup = AccountIdLookupOf::<T>::from(resolver.clone());
+		let pool_id_clone = pool_id.clone();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, resolver_lookup);
+
+		// Verify
+		assert_eq!(PoolResolvers::<T>::get(&pool_id), Some(resolver));
+	}
+
+	#[benchmark]
+	fn resolve(c: Linear<2, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::ResolveOrigin::try_successful_origin().expect("creating origin should not fail");
+		let resolver = T::ResolveOrigin::ensure_origin(origin.clone()).expect("resolving origin should not fail");
+
 		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
 		let target_asset_id = bonded_currencies[0].clone();
 
 		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
 
-		let pool_id = create_pool::<T>(curve, bonded_currencies, Some(account_origin.clone()), None, None);
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, None);
+		PoolResolvers::<T>::insert(&pool_id, resolver);
 
 		let pool_account = pool_id.clone().into();
 
+		// give the pool account some funds.
+		make_free_for_deposit::<T>(&pool_account);
 		let collateral_id = create_default_collateral_asset::<T>();
-		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
-			.expect("Touching should work");
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &pool_account).expect("Touching should work");
 		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
 
-		let holder: T::AccountId = account("holder", 0, 1);
-		T::Fungibles::touch(target_asset_id.clone(), &holder, &account_origin).expect("Touching should work");
+		let holder: T::AccountId = account("holder", 0, 0);
+		T::Fungibles::touch(target_asset_id.clone(), &holder, &pool_account).expect("Touching should work");
 		set_fungible_balance::<T>(target_asset_id, &holder, 10000u128);
 
 		let pool_id_clone = pool_id.clone();
 		let max_currencies = T::MaxCurrenciesPerPool::get();
 
 		#[extrinsic_call]
-		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+		_(origin as T::RuntimeOrigin, pool_id_clone, 0, max_currencies);
 
 		// Verify
-
 		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
 		assert_eq!(pool_details.state, PoolStatus::Refunding);
+		assert_eq!(ResolvedOutcomes::<T>::get(&pool_id), Some(0));
 	}
 
 	#[benchmark]
-	fn force_start_refund(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+	fn force_resolve(c: Linear<2, { T::MaxCurrenciesPerPool::get() }>) {
 		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
 		let target_asset_id = bonded_currencies[0].clone();
 
@@ -907,15 +2031,16 @@ mod benchmarks {
 		let max_currencies = T::MaxCurrenciesPerPool::get();
 
 		#[extrinsic_call]
-		_(origin as T::RuntimeOrigin, pool_id_clone, max_currencies);
+		_(origin as T::RuntimeOrigin, pool_id_clone, 0, max_currencies);
 
 		// Verify
 		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
 		assert_eq!(pool_details.state, PoolStatus::Refunding);
+		assert_eq!(ResolvedOutcomes::<T>::get(&pool_id), Some(0));
 	}
 
 	#[benchmark]
-	fn refund_account(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+	fn set_metadata() {
 		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
 		let account_origin = origin
 			.clone()
@@ -923,30 +2048,162 @@ mod benchmarks {
 			.expect("generating account_id from origin should not fail");
 		make_free_for_deposit::<T>(&account_origin);
 
-		let collateral_id = create_default_collateral_asset::<T>();
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(1, false);
+		let curve = get_2nd_order_polynomial_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, None);
+		Pools::<T>::mutate(&pool_id, |details| {
+			if let Some(details) = details {
+				details.owner = account_origin;
+			}
+		});
+
+		// worst case: metadata of maximum length is added, which requires a deposit
+		let metadata = PoolMetadataOf::<T> {
+			description: Some(BoundedVec::truncate_from(vec![
+				0u8;
+				T::MaxDescriptionLength::get()
+					.saturated_into()
+			])),
+			uri: Some(BoundedVec::truncate_from(vec![
+				0u8;
+				T::MaxUriLength::get().saturated_into()
+			])),
+			logo_hash: Some(T::Hash::default()),
+		};
+		let pool_id_clone = pool_id.clone();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, Some(metadata.clone()));
+
+		// Verify
+		assert_eq!(Metadata::<T>::get(&pool_id), Some(metadata));
+	}
+
+	#[benchmark]
+	fn set_issuer() {
+		let origin = T::DefaultOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(1, false);
+		let curve = get_2nd_order_polynomial_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, None);
+		Pools::<T>::mutate(&pool_id, |details| {
+			if let Some(details) = details {
+				details.owner = account_origin.clone();
+			}
+		});
+
+		let (identifier, credential_id) = T::IssuerVerification::create_verified_issuer(&account_origin, &pool_id);
+		let issuer = PoolIssuerOf::<T> {
+			identifier,
+			credential_id,
+		};
+		let pool_id_clone = pool_id.clone();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, pool_id_clone, Some(issuer.clone()));
+
+		// Verify
+		assert_eq!(PoolIssuers::<T>::get(&pool_id), Some(issuer));
+	}
+
+	#[benchmark]
+	fn update_price_accumulator(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let holder: T::AccountId = account("holder", 0, 0);
+
+		create_default_collateral_asset::<T>();
 		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
-		let target_asset_id = bonded_currencies[0].clone();
+		for asset_id in bonded_currencies.iter() {
+			set_fungible_balance::<T>(asset_id.clone(), &holder, 100u128);
+		}
 
 		let curve = get_lmsr_curve::<CurveParameterTypeOf<T>>();
-		let pool_id = create_pool::<T>(curve, bonded_currencies, None, Some(PoolStatus::Refunding), None);
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, None);
+		let pool_details = Pools::<T>::get(&pool_id).expect("Pool should exist");
+
+		let update = || {
+			Pallet::<T>::update_price_accumulator(
+				&pool_id,
+				&pool_details.curve,
+				&pool_details.bonded_currencies,
+				pool_details.currencies_settings.denomination,
+				pool_details.collateral.clone(),
+			)
+		};
+
+		// worst case: an accumulator exists and a new observation is recorded
+		update();
+		let next_period = frame_system::Pallet::<T>::block_number().saturating_add(T::PriceObservationPeriod::get());
+		frame_system::Pallet::<T>::set_block_number(next_period);
+
+		#[block]
+		{
+			update();
+		}
+
+		// Verify
+		let accumulator = PriceAccumulators::<T>::get(&pool_id).expect("Accumulator should exist");
+		assert_eq!(accumulator.last_update, next_period);
+	}
+
+	#[benchmark]
+	fn burn_into_remote(c: Linear<1, { T::MaxCurrenciesPerPool::get() }>) {
+		let origin = T::PoolCreateOrigin::try_successful_origin().expect("creating origin should not fail");
+		let account_origin = origin
+			.clone()
+			.into_signer()
+			.expect("generating account_id from origin should not fail");
+		make_free_for_deposit::<T>(&account_origin);
+
+		let collateral_id = T::BenchmarkHelper::calculate_remote_collateral_asset_id(0);
+		create_collateral_asset::<T>(collateral_id.clone());
+		let bonded_currencies = create_bonded_currencies_in_range::<T>(c, false);
+		let target_asset_id = bonded_currencies[0].clone();
 
+		let start_balance = 100u128;
+		set_fungible_balance::<T>(target_asset_id.clone(), &account_origin, start_balance);
+
+		let curve = get_2nd_order_polynomial_curve::<CurveParameterTypeOf<T>>();
+		let pool_id = create_pool::<T>(curve, bonded_currencies, None, None, Some(0));
+		Pools::<T>::mutate(&pool_id, |details| {
+			if let Some(details) = details {
+				details.collateral = collateral_id.clone();
+			}
+		});
 		let pool_account = pool_id.clone().into();
-		T::Collaterals::touch(collateral_id.clone(), &pool_id.clone().into(), &account_origin)
-			.expect("Touching should work");
-		set_collateral_balance::<T>(collateral_id, &pool_account, 10000u128);
 
-		set_fungible_balance::<T>(target_asset_id.clone(), &account_origin, 100000u128);
+		T::Collaterals::touch(collateral_id.clone(), &pool_account, &account_origin).expect("Touching should work");
 
-		let beneficiary = AccountIdLookupOf::<T>::from(account_origin.clone());
+		set_collateral_balance::<T>(collateral_id.clone(), &pool_account, 10000u128);
+		set_max_pool_fees::<T>(&pool_id, collateral_id);
+
+		let beneficiary = T::BenchmarkHelper::prepare_remote_transfer(0);
+		let amount_to_burn = 10u128.saturated_into();
+		let min_return = 0u128.saturated_into();
 		let max_currencies = T::MaxCurrenciesPerPool::get();
 
 		#[extrinsic_call]
-		_(origin as T::RuntimeOrigin, pool_id, beneficiary, 0, max_currencies);
+		_(
+			origin as T::RuntimeOrigin,
+			pool_id,
+			0,
+			Box::new(beneficiary),
+			amount_to_burn,
+			min_return,
+			max_currencies,
+		);
 
-		// Verify
 		let balance = T::Fungibles::balance(target_asset_id, &account_origin);
-		assert_eq!(balance, Zero::zero());
+		assert_eq!(
+			balance,
+			(start_balance - amount_to_burn.saturated_into::<u128>()).saturated_into()
+		);
 	}
+
 	#[cfg(test)]
 	mod benchmark_tests {
 		use crate::Pallet;
@@ -0,0 +1,209 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+/// Bounded Exponential Bonding Curve Implementation.
+///
+/// This module provides an implementation of an exponential bonding curve
+/// whose price is capped at a maximum value.
+///
+/// ### Cost Function
+/// The cost function is defined as:
+/// ```text
+/// c(s) = min(m * e^(k * s), cap)
+/// ```
+/// Where:
+/// - `s` is the supply of assets,
+/// - `m` is the price at supply `0`,
+/// - `k` is the growth rate of the price,
+/// - `cap` is the maximum price.
+///
+/// The price reaches the cap at the supply
+/// ```text
+/// s_cap = ln(cap / m) / k
+/// ```
+///
+/// ### Antiderivative
+/// Below `s_cap`, the indefinite integral of the cost function is:
+/// ```text
+/// C(s) = (m / k) * e^(k * s) = c(s) / k
+/// ```
+/// Above `s_cap`, the cost grows linearly with `cap`.
+///
+/// To calculate the incremental cost of a transaction, use the formula:
+/// ```text
+/// Incremental Cost = C(s) - C(s*)
+/// ```
+/// Here, `s*` represents the initial supply before the transaction, and `s` is
+/// the supply after the transaction.
+///
+/// ### Optimization for Numerical Stability
+/// The exponential term is never evaluated beyond `s_cap`, which bounds it by
+/// `cap / m` and prevents the overflow an unbounded exponential would run into
+/// for large supplies.
+use frame_support::ensure;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_arithmetic::ArithmeticError;
+use sp_std::ops::{AddAssign, BitOrAssign, ShlAssign};
+use substrate_fixed::{
+	traits::{Fixed, FixedSigned, FixedUnsigned, ToFixed},
+	transcendental::{exp, ln},
+};
+
+use super::{calculate_accumulated_passive_issuance, BondingFunction};
+use crate::{PassiveSupply, Precision};
+
+/// A struct representing the unchecked input parameters for a bounded
+/// exponential bonding curve. This struct is used to convert the input
+/// parameters to the correct fixed-point type.
+///
+/// ### Example
+///
+/// For a cost function `c(s) = min(0.5 * e^(0.1 * s), 10)` the input
+/// parameters would be:
+/// ```rust, ignore
+/// ExponentialParametersInput {
+///    m: 0.5,
+///    k: 0.1,
+///    cap: 10,
+/// }
+/// ```
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ExponentialParametersInput<Coefficient> {
+	/// The price at supply `0`.
+	pub m: Coefficient,
+	/// The growth rate of the price.
+	pub k: Coefficient,
+	/// The maximum price.
+	pub cap: Coefficient,
+}
+
+/// A struct representing the validated parameters for a bounded exponential
+/// bonding curve. This struct is used to store the parameters for a bounded
+/// exponential bonding curve and to perform calculations using it.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ExponentialParameters<Coefficient> {
+	/// The price at supply `0`. This value must be greater than zero.
+	pub m: Coefficient,
+	/// The growth rate of the price. This value must be greater than zero.
+	pub k: Coefficient,
+	/// The maximum price. This value must not be smaller than `m`.
+	pub cap: Coefficient,
+}
+
+/// Implementation of the TryFrom trait for `ExponentialParametersInput` to
+/// convert the input parameters to the correct fixed-point type. The TryFrom
+/// implementation for `ExponentialParameters` will fail if the conversion to
+/// the fixed-point type fails, if `m` or `k` are zero or if `cap` is smaller
+/// than `m`.
+impl<I: FixedUnsigned, C: FixedSigned> TryFrom<ExponentialParametersInput<I>> for ExponentialParameters<C> {
+	type Error = ();
+	fn try_from(value: ExponentialParametersInput<I>) -> Result<Self, Self::Error> {
+		let m = C::checked_from_fixed(value.m).ok_or(())?;
+		let k = C::checked_from_fixed(value.k).ok_or(())?;
+		let cap = C::checked_from_fixed(value.cap).ok_or(())?;
+
+		ensure!(m > C::from_num(0u8), ());
+		ensure!(k > C::from_num(0u8), ());
+		ensure!(cap >= m, ());
+
+		Ok(ExponentialParameters { m, k, cap })
+	}
+}
+
+impl<Coefficient> ExponentialParameters<Coefficient>
+where
+	Coefficient: FixedSigned + PartialOrd<Precision> + From<Precision>,
+	<Coefficient as Fixed>::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+	/// Calculate the supply at which the price reaches the cap.
+	fn cap_supply(&self) -> Result<Coefficient, ArithmeticError> {
+		let ratio = self.cap.checked_div(self.m).ok_or(ArithmeticError::DivisionByZero)?;
+
+		ln::<Coefficient, Coefficient>(ratio)
+			.map_err(|_| ArithmeticError::Underflow)?
+			.checked_div(self.k)
+			.ok_or(ArithmeticError::DivisionByZero)
+	}
+
+	/// Calculate the uncapped price `m * e^(k * s)` at the given supply.
+	fn uncapped_price(&self, supply: Coefficient) -> Result<Coefficient, ArithmeticError> {
+		let exponent = self.k.checked_mul(supply).ok_or(ArithmeticError::Overflow)?;
+
+		exp::<Coefficient, Coefficient>(exponent)
+			.map_err(|_| ArithmeticError::Overflow)?
+			.checked_mul(self.m)
+			.ok_or(ArithmeticError::Overflow)
+	}
+}
+
+impl<Coefficient> BondingFunction<Coefficient> for ExponentialParameters<Coefficient>
+where
+	Coefficient: FixedSigned + PartialOrd<Precision> + From<Precision>,
+	<Coefficient as Fixed>::Bits: Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign,
+{
+	/// Calculate the cost of purchasing/selling assets using the bounded
+	/// exponential bonding curve.
+	fn calculate_costs(
+		&self,
+		low_without_passive: Coefficient,
+		high_without_passive: Coefficient,
+		passive_supply: PassiveSupply<Coefficient>,
+	) -> Result<Coefficient, ArithmeticError> {
+		let accumulated_passive_issuance = calculate_accumulated_passive_issuance(&passive_supply);
+
+		// reassign high and low to include the accumulated passive issuance
+		let high = high_without_passive
+			.checked_add(accumulated_passive_issuance)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let low = low_without_passive
+			.checked_add(accumulated_passive_issuance)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let cap_supply = self.cap_supply()?;
+
+		// Calculate (c(high) - c(low)) / k for the uncapped part
+		let exponential_low = low.min(cap_supply);
+		let exponential_high = high.min(cap_supply);
+
+		let exponential_costs = if exponential_low < exponential_high {
+			self.uncapped_price(exponential_high)?
+				.checked_sub(self.uncapped_price(exponential_low)?)
+				.ok_or(ArithmeticError::Underflow)?
+				.checked_div(self.k)
+				.ok_or(ArithmeticError::DivisionByZero)?
+		} else {
+			Coefficient::from_num(0u8)
+		};
+
+		// Calculate cap * (high - low) for the capped part
+		let capped_low = low.max(cap_supply);
+
+		let capped_costs = if capped_low < high {
+			let delta_x = high.checked_sub(capped_low).ok_or(ArithmeticError::Underflow)?;
+			self.cap.checked_mul(delta_x).ok_or(ArithmeticError::Overflow)?
+		} else {
+			Coefficient::from_num(0u8)
+		};
+
+		exponential_costs
+			.checked_add(capped_costs)
+			.ok_or(ArithmeticError::Overflow)
+	}
+}
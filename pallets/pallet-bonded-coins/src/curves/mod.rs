@@ -23,7 +23,11 @@
 /// - Polynomial
 /// - SquareRoot
 /// - LMSR (Logarithmic Market Scoring Rule)
+/// - PiecewiseLinear
+/// - Exponential (bounded by a price cap)
+pub mod exponential;
 pub mod lmsr;
+pub mod piecewise_linear;
 pub mod polynomial;
 pub mod square_root;
 
@@ -37,7 +41,9 @@ use substrate_fixed::traits::{Fixed, FixedSigned, ToFixed};
 
 use crate::{
 	curves::{
+		exponential::{ExponentialParameters, ExponentialParametersInput},
 		lmsr::{LMSRParameters, LMSRParametersInput},
+		piecewise_linear::{PiecewiseLinearParameters, PiecewiseLinearParametersInput},
 		polynomial::{PolynomialParameters, PolynomialParametersInput},
 		square_root::{SquareRootParameters, SquareRootParametersInput},
 	},
@@ -52,6 +58,8 @@ pub enum Curve<Coefficient> {
 	Polynomial(PolynomialParameters<Coefficient>),
 	SquareRoot(SquareRootParameters<Coefficient>),
 	Lmsr(LMSRParameters<Coefficient>),
+	PiecewiseLinear(PiecewiseLinearParameters<Coefficient>),
+	Exponential(ExponentialParameters<Coefficient>),
 }
 
 /// An enum representing input parameters for different types of curves.
@@ -61,6 +69,8 @@ pub enum CurveInput<Coefficient> {
 	Polynomial(PolynomialParametersInput<Coefficient>),
 	SquareRoot(SquareRootParametersInput<Coefficient>),
 	Lmsr(LMSRParametersInput<Coefficient>),
+	PiecewiseLinear(PiecewiseLinearParametersInput<Coefficient>),
+	Exponential(ExponentialParametersInput<Coefficient>),
 }
 
 /// Implementation of the TryFrom trait for `CurveInput` to convert the input
@@ -74,6 +84,8 @@ where
 	LMSRParameters<C>: TryFrom<LMSRParametersInput<I>>,
 	PolynomialParameters<C>: TryFrom<PolynomialParametersInput<I>>,
 	SquareRootParameters<C>: TryFrom<SquareRootParametersInput<I>>,
+	PiecewiseLinearParameters<C>: TryFrom<PiecewiseLinearParametersInput<I>>,
+	ExponentialParameters<C>: TryFrom<ExponentialParametersInput<I>>,
 {
 	type Error = ();
 	fn try_from(value: CurveInput<I>) -> Result<Self, Self::Error> {
@@ -90,6 +102,14 @@ where
 				let checked_param = SquareRootParameters::<C>::try_from(params).map_err(|_| ())?;
 				Ok(Curve::SquareRoot(checked_param))
 			}
+			CurveInput::PiecewiseLinear(params) => {
+				let checked_param = PiecewiseLinearParameters::<C>::try_from(params).map_err(|_| ())?;
+				Ok(Curve::PiecewiseLinear(checked_param))
+			}
+			CurveInput::Exponential(params) => {
+				let checked_param = ExponentialParameters::<C>::try_from(params).map_err(|_| ())?;
+				Ok(Curve::Exponential(checked_param))
+			}
 		}
 	}
 }
@@ -104,6 +124,8 @@ where
 			Curve::Polynomial(params) => params,
 			Curve::SquareRoot(params) => params,
 			Curve::Lmsr(params) => params,
+			Curve::PiecewiseLinear(params) => params,
+			Curve::Exponential(params) => params,
 		}
 	}
 }
@@ -0,0 +1,244 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+/// Piecewise-Linear Bonding Curve Implementation.
+///
+/// This module provides an implementation of a bonding curve whose price is
+/// defined by a list of control points `(s_i, p_i)`.
+///
+/// ### Cost Function
+/// Between two neighbouring control points the price is interpolated
+/// linearly:
+/// ```text
+/// c(s) = p_i + (p_(i+1) - p_i) * (s - s_i) / (s_(i+1) - s_i),   s_i <= s < s_(i+1)
+/// ```
+/// The first control point must be located at supply `0`. Beyond the last
+/// control point the price stays constant at the price of the last point,
+/// which allows expressing capped curves (e.g., a capped linear curve is
+/// defined by two control points).
+///
+/// ### Incremental Cost
+/// The cost of a transaction is the integral of `c(s)` between the supply
+/// before (`s*`) and after (`s`) the transaction. Since `c(s)` is linear on
+/// each segment, the integral over the part of a segment between `a` and `b`
+/// is computed exactly with the trapezoidal rule:
+/// ```text
+/// Incremental Cost = Σ (c(a) + c(b)) / 2 * (b - a)
+/// ```
+///
+/// ### Optimization for Numerical Stability
+/// Only the segments overlapping the transaction bounds are integrated, so
+/// the magnitude of intermediate values is bounded by the transaction size
+/// rather than by the total supply. The mean price of a segment is computed
+/// as `c(a) + (c(b) - c(a)) / 2` to avoid overflowing on the sum of both
+/// prices.
+use frame_support::{ensure, traits::ConstU32, BoundedVec};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_arithmetic::ArithmeticError;
+use sp_std::vec::Vec;
+use substrate_fixed::traits::{FixedSigned, FixedUnsigned};
+
+use super::{calculate_accumulated_passive_issuance, BondingFunction};
+use crate::PassiveSupply;
+
+/// The maximum number of control points of a piecewise-linear curve.
+pub const MAX_CONTROL_POINTS: u32 = 8;
+
+/// The list of control points defining a piecewise-linear curve.
+pub type ControlPoints<Coefficient> = BoundedVec<ControlPoint<Coefficient>, ConstU32<MAX_CONTROL_POINTS>>;
+
+/// A single control point of a piecewise-linear curve.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ControlPoint<Coefficient> {
+	/// The supply at which the control point is located.
+	pub supply: Coefficient,
+	/// The price at the given supply.
+	pub price: Coefficient,
+}
+
+/// A struct representing the unchecked input parameters for a
+/// piecewise-linear bonding curve. This struct is used to convert the input
+/// parameters to the correct fixed-point type.
+///
+/// ### Example
+///
+/// For a linear price function `c(s) = 2 * s + 1`, which is capped at a price
+/// of `21` once a supply of `10` is reached, the input parameters would be:
+/// ```rust, ignore
+/// PiecewiseLinearParametersInput {
+///    points: [
+///       ControlPoint { supply: 0, price: 1 },
+///       ControlPoint { supply: 10, price: 21 },
+///    ],
+/// }
+/// ```
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PiecewiseLinearParametersInput<Coefficient> {
+	/// The control points, ordered by supply.
+	pub points: ControlPoints<Coefficient>,
+}
+
+/// A struct representing the validated parameters for a piecewise-linear
+/// bonding curve. This struct is used to store the parameters for a
+/// piecewise-linear bonding curve and to perform calculations using it.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PiecewiseLinearParameters<Coefficient> {
+	/// The control points, ordered by strictly increasing supply. The first
+	/// control point is located at supply `0`.
+	pub points: ControlPoints<Coefficient>,
+}
+
+/// Implementation of the TryFrom trait for `PiecewiseLinearParametersInput`
+/// to convert the input parameters to the correct fixed-point type. The
+/// TryFrom implementation for `PiecewiseLinearParameters` will fail if the
+/// conversion to the fixed-point type fails, if no control points are
+/// provided, if the first control point is not located at supply `0` or if
+/// the supplies of the control points are not strictly increasing.
+impl<I: FixedUnsigned, C: FixedSigned> TryFrom<PiecewiseLinearParametersInput<I>> for PiecewiseLinearParameters<C> {
+	type Error = ();
+	fn try_from(value: PiecewiseLinearParametersInput<I>) -> Result<Self, Self::Error> {
+		let points = value
+			.points
+			.into_iter()
+			.map(|point| {
+				Ok(ControlPoint {
+					supply: C::checked_from_fixed(point.supply).ok_or(())?,
+					price: C::checked_from_fixed(point.price).ok_or(())?,
+				})
+			})
+			.collect::<Result<Vec<_>, ()>>()?;
+
+		let first = points.first().ok_or(())?;
+		ensure!(first.supply == C::from_num(0u8), ());
+		ensure!(
+			points
+				.windows(2)
+				.all(|segment| matches!(segment, [start, end] if start.supply < end.supply)),
+			()
+		);
+
+		Ok(PiecewiseLinearParameters {
+			points: points.try_into().map_err(|_| ())?,
+		})
+	}
+}
+
+impl<Coefficient: FixedSigned> PiecewiseLinearParameters<Coefficient> {
+	/// Linearly interpolates the price at `supply`, which must be located
+	/// within the segment between `start` and `end`.
+	fn interpolate(
+		start: &ControlPoint<Coefficient>,
+		end: &ControlPoint<Coefficient>,
+		supply: Coefficient,
+	) -> Result<Coefficient, ArithmeticError> {
+		let width = end.supply.checked_sub(start.supply).ok_or(ArithmeticError::Underflow)?;
+		let offset = supply.checked_sub(start.supply).ok_or(ArithmeticError::Underflow)?;
+		let ratio = offset.checked_div(width).ok_or(ArithmeticError::DivisionByZero)?;
+
+		// The rise is negative for segments with a decreasing price.
+		let rise = end.price.checked_sub(start.price).ok_or(ArithmeticError::Overflow)?;
+
+		rise.checked_mul(ratio)
+			.ok_or(ArithmeticError::Overflow)?
+			.checked_add(start.price)
+			.ok_or(ArithmeticError::Overflow)
+	}
+
+	/// Calculates the area below a linear price function over an interval of
+	/// the given `width`, with `price_from` at the start and `price_to` at the
+	/// end of the interval.
+	fn trapezoid(
+		width: Coefficient,
+		price_from: Coefficient,
+		price_to: Coefficient,
+	) -> Result<Coefficient, ArithmeticError> {
+		let half_rise = price_to
+			.checked_sub(price_from)
+			.ok_or(ArithmeticError::Overflow)?
+			.checked_div(Coefficient::from_num(2u8))
+			.ok_or(ArithmeticError::DivisionByZero)?;
+
+		let mean_price = price_from.checked_add(half_rise).ok_or(ArithmeticError::Overflow)?;
+
+		mean_price.checked_mul(width).ok_or(ArithmeticError::Overflow)
+	}
+}
+
+impl<Coefficient> BondingFunction<Coefficient> for PiecewiseLinearParameters<Coefficient>
+where
+	Coefficient: FixedSigned,
+{
+	/// Calculate the cost of purchasing/selling assets using the
+	/// piecewise-linear bonding curve.
+	fn calculate_costs(
+		&self,
+		low_without_passive: Coefficient,
+		high_without_passive: Coefficient,
+		passive_supply: PassiveSupply<Coefficient>,
+	) -> Result<Coefficient, ArithmeticError> {
+		let accumulated_passive_issuance = calculate_accumulated_passive_issuance(&passive_supply);
+
+		// reassign high and low to include the accumulated passive issuance
+		let high = high_without_passive
+			.checked_add(accumulated_passive_issuance)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let low = low_without_passive
+			.checked_add(accumulated_passive_issuance)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		// Integrate over the parts of all segments between low and high.
+		let segment_costs = self
+			.points
+			.windows(2)
+			.try_fold(Coefficient::from_num(0u8), |acc, segment| {
+				let [start, end] = segment else {
+					return Ok(acc);
+				};
+				let from = low.max(start.supply);
+				let to = high.min(end.supply);
+
+				if from >= to {
+					return Ok(acc);
+				}
+
+				let price_from = Self::interpolate(start, end, from)?;
+				let price_to = Self::interpolate(start, end, to)?;
+				let width = to.checked_sub(from).ok_or(ArithmeticError::Underflow)?;
+				let area = Self::trapezoid(width, price_from, price_to)?;
+
+				acc.checked_add(area).ok_or(ArithmeticError::Overflow)
+			})?;
+
+		// Beyond the last control point, the price stays constant.
+		let Some(last) = self.points.last() else {
+			return Ok(segment_costs);
+		};
+
+		let from = low.max(last.supply);
+		if from >= high {
+			return Ok(segment_costs);
+		}
+
+		let width = high.checked_sub(from).ok_or(ArithmeticError::Underflow)?;
+		let tail_costs = last.price.checked_mul(width).ok_or(ArithmeticError::Overflow)?;
+
+		segment_costs.checked_add(tail_costs).ok_or(ArithmeticError::Overflow)
+	}
+}
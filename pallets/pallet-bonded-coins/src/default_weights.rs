@@ -49,6 +49,8 @@ pub trait WeightInfo {
 	fn create_pool_polynomial(c: u32, ) -> Weight;
 	fn create_pool_square_root(c: u32, ) -> Weight;
 	fn create_pool_lmsr(c: u32, ) -> Weight;
+	fn create_pool_piecewise_linear(c: u32, ) -> Weight;
+	fn create_pool_exponential(c: u32, ) -> Weight;
 	fn reset_team() -> Weight;
 	fn reset_manager() -> Weight;
 	fn set_lock() -> Weight;
@@ -56,15 +58,40 @@ pub trait WeightInfo {
 	fn mint_into_polynomial(c: u32, ) -> Weight;
 	fn mint_into_square_root(c: u32, ) -> Weight;
 	fn mint_into_lmsr(c: u32, ) -> Weight;
+	fn mint_into_piecewise_linear(c: u32, ) -> Weight;
+	fn mint_into_exponential(c: u32, ) -> Weight;
 	fn burn_into_polynomial(c: u32, ) -> Weight;
 	fn burn_into_square_root(c: u32, ) -> Weight;
 	fn burn_into_lmsr(c: u32, ) -> Weight;
+	fn burn_into_piecewise_linear(c: u32, ) -> Weight;
+	fn burn_into_exponential(c: u32, ) -> Weight;
+	fn swap_within_pool_polynomial(c: u32, ) -> Weight;
+	fn swap_within_pool_square_root(c: u32, ) -> Weight;
+	fn swap_within_pool_lmsr(c: u32, ) -> Weight;
+	fn swap_within_pool_piecewise_linear(c: u32, ) -> Weight;
+	fn swap_within_pool_exponential(c: u32, ) -> Weight;
 	fn start_destroy(c: u32, ) -> Weight;
 	fn force_start_destroy(c: u32, ) -> Weight;
 	fn finish_destroy(c: u32, ) -> Weight;
 	fn start_refund(c: u32, ) -> Weight;
 	fn force_start_refund(c: u32, ) -> Weight;
 	fn refund_account(c: u32, ) -> Weight;
+	fn claim_fees() -> Weight;
+	fn set_graduation() -> Weight;
+	fn graduate() -> Weight;
+	fn swap_collateral_for_bonded() -> Weight;
+	fn swap_bonded_for_collateral() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn set_vesting() -> Weight;
+	fn claim_vested() -> Weight;
+	fn set_resolver() -> Weight;
+	fn resolve(c: u32, ) -> Weight;
+	fn force_resolve(c: u32, ) -> Weight;
+	fn set_metadata() -> Weight;
+	fn set_issuer() -> Weight;
+	fn update_price_accumulator(c: u32, ) -> Weight;
+	fn burn_into_remote(c: u32, ) -> Weight;
 }
 
 /// Weights for pallet_bonded_coins using the Substrate node and recommended hardware.
@@ -83,7 +110,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCoins::Pools` (r:0 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn create_pool_polynomial(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -112,7 +139,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCoins::Pools` (r:0 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn create_pool_square_root(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -141,7 +168,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCoins::Pools` (r:0 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn create_pool_lmsr(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -157,54 +184,116 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
+	/// Storage: `BondedCoins::NextAssetId` (r:1 w:1)
+	/// Proof: `BondedCoins::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:51)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::Pools` (r:0 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn create_pool_piecewise_linear(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `3676 + c * (2685 ±0)`
+		// Minimum execution time: 1_045_845 nanoseconds.
+		Weight::from_parts(936_864_303, 3676)
+			// Standard Error: 116_249
+			.saturating_add(Weight::from_parts(139_836_712, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::NextAssetId` (r:1 w:1)
+	/// Proof: `BondedCoins::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:51)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::Pools` (r:0 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn create_pool_exponential(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `3676 + c * (2685 ±0)`
+		// Minimum execution time: 1_016_793 nanoseconds.
+		Weight::from_parts(910_840_295, 3676)
+			// Standard Error: 116_249
+			.saturating_add(Weight::from_parts(139_836_712, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:1 w:1)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	fn reset_team() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `619`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 233_932 nanoseconds.
-		Weight::from_parts(236_466_000, 3830)
+		Weight::from_parts(236_466_000, 4039)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	fn reset_manager() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `339`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 138_674 nanoseconds.
-		Weight::from_parts(139_617_000, 3830)
+		Weight::from_parts(139_617_000, 4039)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	fn set_lock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `339`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 136_868 nanoseconds.
-		Weight::from_parts(138_628_000, 3830)
+		Weight::from_parts(138_628_000, 4039)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `342`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 134_994 nanoseconds.
-		Weight::from_parts(136_078_000, 3830)
+		Weight::from_parts(136_078_000, 4039)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -213,6 +302,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn mint_into_polynomial(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -222,13 +315,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(608_351_405, 6208)
 			// Standard Error: 47_025
 			.saturating_add(Weight::from_parts(32_911_729, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -237,6 +334,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn mint_into_square_root(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -246,13 +347,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(639_889_739, 6208)
 			// Standard Error: 51_003
 			.saturating_add(Weight::from_parts(32_847_297, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -261,6 +366,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn mint_into_lmsr(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -270,13 +379,81 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(605_811_044, 6208)
 			// Standard Error: 68_768
 			.saturating_add(Weight::from_parts(45_571_290, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:2 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn mint_into_piecewise_linear(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 683_105 nanoseconds.
+		Weight::from_parts(654_275_927, 6208)
+			// Standard Error: 68_768
+			.saturating_add(Weight::from_parts(45_571_290, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:2 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn mint_into_exponential(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 664_130 nanoseconds.
+		Weight::from_parts(636_101_596, 6208)
+			// Standard Error: 68_768
+			.saturating_add(Weight::from_parts(45_571_290, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -285,6 +462,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn burn_into_polynomial(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -294,13 +473,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(1_231_031_276, 8817)
 			// Standard Error: 97_453
 			.saturating_add(Weight::from_parts(30_883_086, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -309,6 +492,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn burn_into_square_root(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -318,13 +503,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(1_269_726_586, 8817)
 			// Standard Error: 81_512
 			.saturating_add(Weight::from_parts(31_048_333, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -333,6 +522,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn burn_into_lmsr(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -342,13 +533,233 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(1_227_113_068, 8817)
 			// Standard Error: 122_714
 			.saturating_add(Weight::from_parts(63_197_028, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn burn_into_piecewise_linear(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `920 + c * (221 ±0)`
+		//  Estimated: `8817 + c * (2685 ±0)`
+		// Minimum execution time: 1_338_923 nanoseconds.
+		Weight::from_parts(1_325_282_113, 8817)
+			// Standard Error: 122_714
+			.saturating_add(Weight::from_parts(63_197_028, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn burn_into_exponential(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `920 + c * (221 ±0)`
+		//  Estimated: `8817 + c * (2685 ±0)`
+		// Minimum execution time: 1_301_731 nanoseconds.
+		Weight::from_parts(1_288_468_721, 8817)
+			// Standard Error: 122_714
+			.saturating_add(Weight::from_parts(63_197_028, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_polynomial(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `855 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_143_810 nanoseconds.
+		Weight::from_parts(3_041_757_025, 6208)
+			// Standard Error: 235_125
+			.saturating_add(Weight::from_parts(164_558_645, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_square_root(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `839 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_318_640 nanoseconds.
+		Weight::from_parts(3_199_448_695, 6208)
+			// Standard Error: 255_015
+			.saturating_add(Weight::from_parts(164_236_485, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_lmsr(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_162_525 nanoseconds.
+		Weight::from_parts(3_029_055_220, 6208)
+			// Standard Error: 343_840
+			.saturating_add(Weight::from_parts(227_856_450, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_piecewise_linear(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_415_525 nanoseconds.
+		Weight::from_parts(3_271_379_635, 6208)
+			// Standard Error: 343_840
+			.saturating_add(Weight::from_parts(227_856_450, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_exponential(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_320_650 nanoseconds.
+		Weight::from_parts(3_180_507_980, 6208)
+			// Standard Error: 343_840
+			.saturating_add(Weight::from_parts(227_856_450, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:50 w:50)
@@ -357,9 +768,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn start_destroy(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `373 + c * (221 ±0)`
-		//  Estimated: `3830 + c * (2685 ±0)`
+		//  Estimated: `4039 + c * (2685 ±0)`
 		// Minimum execution time: 289_514 nanoseconds.
-		Weight::from_parts(237_013_036, 3830)
+		Weight::from_parts(237_013_036, 4039)
 			// Standard Error: 81_274
 			.saturating_add(Weight::from_parts(79_952_518, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
@@ -369,16 +780,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn force_start_destroy(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `341 + c * (221 ±0)`
-		//  Estimated: `3830 + c * (2685 ±0)`
+		//  Estimated: `4039 + c * (2685 ±0)`
 		// Minimum execution time: 264_075 nanoseconds.
-		Weight::from_parts(219_016_367, 3830)
+		Weight::from_parts(219_016_367, 4039)
 			// Standard Error: 102_148
 			.saturating_add(Weight::from_parts(79_147_583, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
@@ -388,7 +799,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:50 w:0)
@@ -403,9 +814,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn finish_destroy(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `504 + c * (221 ±0)`
-		//  Estimated: `3830 + c * (2685 ±0)`
+		//  Estimated: `4039 + c * (2685 ±0)`
 		// Minimum execution time: 723_132 nanoseconds.
-		Weight::from_parts(655_543_968, 3830)
+		Weight::from_parts(655_543_968, 4039)
 			// Standard Error: 157_030
 			.saturating_add(Weight::from_parts(102_388_883, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
@@ -415,7 +826,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:1 w:0)
@@ -424,16 +835,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn start_refund(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1005 + c * (11 ±0)`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 250_787 nanoseconds.
-		Weight::from_parts(264_418_963, 3830)
+		Weight::from_parts(264_418_963, 4039)
 			// Standard Error: 10_398
 			.saturating_add(Weight::from_parts(128_444, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:1 w:0)
@@ -442,22 +853,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_start_refund(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `940 + c * (11 ±0)`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 251_032 nanoseconds.
-		Weight::from_parts(265_248_643, 3830)
+		Weight::from_parts(265_248_643, 4039)
 			// Standard Error: 14_079
 			.saturating_add(Weight::from_parts(80_561, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::ResolvedOutcomes` (r:1 w:0)
+	/// Proof: `BondedCoins::ResolvedOutcomes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn refund_account(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -467,58 +882,325 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(1_343_165_422, 8817)
 			// Standard Error: 90_432
 			.saturating_add(Weight::from_parts(31_315_327, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 			.saturating_add(Weight::from_parts(0 ,2685).saturating_mul(c.into()))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
-	/// Storage: `BondedCoins::NextAssetId` (r:1 w:1)
-	/// Proof: `BondedCoins::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:1 w:1)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1 w:1)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
-	/// Storage: `BondedCurrencies::Account` (r:1 w:1)
-	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
-	/// Storage: `BondedCurrencies::Asset` (r:51 w:51)
-	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
-	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
-	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
-	/// Storage: `BondedCoins::Pools` (r:0 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
-	/// The range of component `c` is `[1, 50]`.
-	fn create_pool_polynomial(c: u32, ) -> Weight {
+	fn claim_fees() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `534`
-		//  Estimated: `3676 + c * (2685 ±0)`
-		// Minimum execution time: 972_383 nanoseconds.
-		Weight::from_parts(890_138_970, 3676)
-			// Standard Error: 148_237
-			.saturating_add(Weight::from_parts(139_407_230, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
-			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+		//  Measured:  `734`
+		//  Estimated: `6208`
+		// Minimum execution time: 264_391 nanoseconds.
+		Weight::from_parts(268_712_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-	/// Storage: `BondedCoins::NextAssetId` (r:1 w:1)
-	/// Proof: `BondedCoins::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1 w:1)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
-	/// Storage: `BondedCurrencies::Account` (r:1 w:1)
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolGraduationSettings` (r:0 w:1)
+	/// Proof: `BondedCoins::PoolGraduationSettings` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn set_graduation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339`
+		//  Estimated: `4039`
+		// Minimum execution time: 135_912 nanoseconds.
+		Weight::from_parts(137_404_000, 4039)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolGraduationSettings` (r:1 w:1)
+	/// Proof: `BondedCoins::PoolGraduationSettings` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:0)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:0 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn graduate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `4039`
+		// Minimum execution time: 172_318 nanoseconds.
+		Weight::from_parts(175_066_000, 4039)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:1 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn swap_collateral_for_bonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `934`
+		//  Estimated: `8817`
+		// Minimum execution time: 301_427 nanoseconds.
+		Weight::from_parts(305_118_000, 8817)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:1 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn swap_bonded_for_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1037`
+		//  Estimated: `8817`
+		// Minimum execution time: 312_806 nanoseconds.
+		Weight::from_parts(316_247_000, 8817)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:1 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::LiquidityShares` (r:1 w:1)
+	/// Proof: `BondedCoins::LiquidityShares` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn add_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1037`
+		//  Estimated: `8817`
+		// Minimum execution time: 338_215 nanoseconds.
+		Weight::from_parts(342_539_000, 8817)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:1 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::LiquidityShares` (r:1 w:1)
+	/// Proof: `BondedCoins::LiquidityShares` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn remove_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1009`
+		//  Estimated: `8817`
+		// Minimum execution time: 329_644 nanoseconds.
+		Weight::from_parts(333_870_000, 8817)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:0 w:1)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn set_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339`
+		//  Estimated: `4039`
+		// Minimum execution time: 134_208 nanoseconds.
+		Weight::from_parts(136_117_000, 4039)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	fn claim_vested() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `4039`
+		// Minimum execution time: 158_934 nanoseconds.
+		Weight::from_parts(161_502_000, 4039)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolResolvers` (r:0 w:1)
+	/// Proof: `BondedCoins::PoolResolvers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn set_resolver() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339`
+		//  Estimated: `4039`
+		// Minimum execution time: 134_871 nanoseconds.
+		Weight::from_parts(136_592_000, 4039)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolResolvers` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolResolvers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::ResolvedOutcomes` (r:0 w:1)
+	/// Proof: `BondedCoins::ResolvedOutcomes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn resolve(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1005 + c * (11 ±0)`
+		//  Estimated: `4039`
+		// Minimum execution time: 265_834 nanoseconds.
+		Weight::from_parts(280_284_100, 4039)
+			// Standard Error: 10_398
+			.saturating_add(Weight::from_parts(128_444, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::ResolvedOutcomes` (r:0 w:1)
+	/// Proof: `BondedCoins::ResolvedOutcomes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn force_resolve(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `940 + c * (11 ±0)`
+		//  Estimated: `4039`
+		// Minimum execution time: 261_073 nanoseconds.
+		Weight::from_parts(275_858_588, 4039)
+			// Standard Error: 14_079
+			.saturating_add(Weight::from_parts(80_561, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::Metadata` (r:1 w:1)
+	/// Proof: `BondedCoins::Metadata` (`max_values`: None, `max_size`: Some(463), added: 2938, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `4039`
+		// Minimum execution time: 231_407 nanoseconds.
+		Weight::from_parts(236_862_000, 4039)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolIssuers` (r:1 w:1)
+	/// Proof: `BondedCoins::PoolIssuers` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn set_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `4039`
+		// Minimum execution time: 224_916 nanoseconds.
+		Weight::from_parts(229_735_000, 4039)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. The curve
+	/// is evaluated once per currency, each time as costly as minting into an
+	/// LMSR pool.
+	/// Storage: `BondedCoins::PriceAccumulators` (r:1 w:1)
+	/// Storage: `BondedCoins::PriceObservations` (r:1 w:1)
+	fn update_price_accumulator(c: u32, ) -> Weight {
+		Weight::from_parts(Self::mint_into_lmsr(c).ref_time().saturating_mul(c.into()), 9061)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Burning
+	/// is as costly as for a polynomial pool, and sending the collateral
+	/// withdraws it from the caller and deposits it into the account of the
+	/// reserve.
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	fn burn_into_remote(c: u32, ) -> Weight {
+		Self::burn_into_polynomial(c)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `BondedCoins::NextAssetId` (r:1 w:1)
+	/// Proof: `BondedCoins::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:1)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:51)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCoins::Pools` (r:0 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn create_pool_polynomial(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `3676 + c * (2685 ±0)`
+		// Minimum execution time: 972_383 nanoseconds.
+		Weight::from_parts(890_138_970, 3676)
+			// Standard Error: 148_237
+			.saturating_add(Weight::from_parts(139_407_230, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::NextAssetId` (r:1 w:1)
+	/// Proof: `BondedCoins::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:51)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::Pools` (r:0 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn create_pool_square_root(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -547,7 +1229,7 @@ impl WeightInfo for () {
 	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCoins::Pools` (r:0 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn create_pool_lmsr(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -563,54 +1245,116 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
+	/// Storage: `BondedCoins::NextAssetId` (r:1 w:1)
+	/// Proof: `BondedCoins::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:51)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::Pools` (r:0 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn create_pool_piecewise_linear(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `3676 + c * (2685 ±0)`
+		// Minimum execution time: 1_045_845 nanoseconds.
+		Weight::from_parts(936_864_303, 3676)
+			// Standard Error: 116_249
+			.saturating_add(Weight::from_parts(139_836_712, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::NextAssetId` (r:1 w:1)
+	/// Proof: `BondedCoins::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:51)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:50 w:50)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::Pools` (r:0 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn create_pool_exponential(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `3676 + c * (2685 ±0)`
+		// Minimum execution time: 1_016_793 nanoseconds.
+		Weight::from_parts(910_840_295, 3676)
+			// Standard Error: 116_249
+			.saturating_add(Weight::from_parts(139_836_712, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:1 w:1)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	fn reset_team() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `619`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 233_932 nanoseconds.
-		Weight::from_parts(236_466_000, 3830)
+		Weight::from_parts(236_466_000, 4039)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	fn reset_manager() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `339`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 138_674 nanoseconds.
-		Weight::from_parts(139_617_000, 3830)
+		Weight::from_parts(139_617_000, 4039)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	fn set_lock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `339`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 136_868 nanoseconds.
-		Weight::from_parts(138_628_000, 3830)
+		Weight::from_parts(138_628_000, 4039)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `342`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 134_994 nanoseconds.
-		Weight::from_parts(136_078_000, 3830)
+		Weight::from_parts(136_078_000, 4039)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -619,6 +1363,10 @@ impl WeightInfo for () {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn mint_into_polynomial(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -628,13 +1376,17 @@ impl WeightInfo for () {
 		Weight::from_parts(608_351_405, 6208)
 			// Standard Error: 47_025
 			.saturating_add(Weight::from_parts(32_911_729, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -643,6 +1395,10 @@ impl WeightInfo for () {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn mint_into_square_root(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -652,13 +1408,17 @@ impl WeightInfo for () {
 		Weight::from_parts(639_889_739, 6208)
 			// Standard Error: 51_003
 			.saturating_add(Weight::from_parts(32_847_297, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -667,6 +1427,10 @@ impl WeightInfo for () {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn mint_into_lmsr(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -676,13 +1440,81 @@ impl WeightInfo for () {
 		Weight::from_parts(605_811_044, 6208)
 			// Standard Error: 68_768
 			.saturating_add(Weight::from_parts(45_571_290, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:2 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn mint_into_piecewise_linear(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 683_105 nanoseconds.
+		Weight::from_parts(654_275_927, 6208)
+			// Standard Error: 68_768
+			.saturating_add(Weight::from_parts(45_571_290, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:1)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:2 w:1)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn mint_into_exponential(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 664_130 nanoseconds.
+		Weight::from_parts(636_101_596, 6208)
+			// Standard Error: 68_768
+			.saturating_add(Weight::from_parts(45_571_290, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -691,6 +1523,8 @@ impl WeightInfo for () {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn burn_into_polynomial(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -700,13 +1534,17 @@ impl WeightInfo for () {
 		Weight::from_parts(1_231_031_276, 8817)
 			// Standard Error: 97_453
 			.saturating_add(Weight::from_parts(30_883_086, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -715,6 +1553,8 @@ impl WeightInfo for () {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn burn_into_square_root(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -724,13 +1564,17 @@ impl WeightInfo for () {
 		Weight::from_parts(1_269_726_586, 8817)
 			// Standard Error: 81_512
 			.saturating_add(Weight::from_parts(31_048_333, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
@@ -739,6 +1583,8 @@ impl WeightInfo for () {
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn burn_into_lmsr(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -748,13 +1594,233 @@ impl WeightInfo for () {
 		Weight::from_parts(1_227_113_068, 8817)
 			// Standard Error: 122_714
 			.saturating_add(Weight::from_parts(63_197_028, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn burn_into_piecewise_linear(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `920 + c * (221 ±0)`
+		//  Estimated: `8817 + c * (2685 ±0)`
+		// Minimum execution time: 1_338_923 nanoseconds.
+		Weight::from_parts(1_325_282_113, 8817)
+			// Standard Error: 122_714
+			.saturating_add(Weight::from_parts(63_197_028, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:0)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 50]`.
+	fn burn_into_exponential(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `920 + c * (221 ±0)`
+		//  Estimated: `8817 + c * (2685 ±0)`
+		// Minimum execution time: 1_301_731 nanoseconds.
+		Weight::from_parts(1_288_468_721, 8817)
+			// Standard Error: 122_714
+			.saturating_add(Weight::from_parts(63_197_028, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_polynomial(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `855 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_143_810 nanoseconds.
+		Weight::from_parts(3_041_757_025, 6208)
+			// Standard Error: 235_125
+			.saturating_add(Weight::from_parts(164_558_645, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_square_root(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `839 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_318_640 nanoseconds.
+		Weight::from_parts(3_199_448_695, 6208)
+			// Standard Error: 255_015
+			.saturating_add(Weight::from_parts(164_236_485, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_lmsr(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_162_525 nanoseconds.
+		Weight::from_parts(3_029_055_220, 6208)
+			// Standard Error: 343_840
+			.saturating_add(Weight::from_parts(227_856_450, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_piecewise_linear(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_415_525 nanoseconds.
+		Weight::from_parts(3_271_379_635, 6208)
+			// Standard Error: 343_840
+			.saturating_add(Weight::from_parts(227_856_450, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolFeeSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolFeeSettings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Metadata` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:4 w:4)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn swap_within_pool_exponential(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `823 + c * (221 ±0)`
+		//  Estimated: `6208 + c * (2685 ±0)`
+		// Minimum execution time: 3_320_650 nanoseconds.
+		Weight::from_parts(3_180_507_980, 6208)
+			// Standard Error: 343_840
+			.saturating_add(Weight::from_parts(227_856_450, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:50 w:50)
@@ -763,9 +1829,9 @@ impl WeightInfo for () {
 	fn start_destroy(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `373 + c * (221 ±0)`
-		//  Estimated: `3830 + c * (2685 ±0)`
+		//  Estimated: `4039 + c * (2685 ±0)`
 		// Minimum execution time: 289_514 nanoseconds.
-		Weight::from_parts(237_013_036, 3830)
+		Weight::from_parts(237_013_036, 4039)
 			// Standard Error: 81_274
 			.saturating_add(Weight::from_parts(79_952_518, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
@@ -775,16 +1841,16 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn force_start_destroy(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `341 + c * (221 ±0)`
-		//  Estimated: `3830 + c * (2685 ±0)`
+		//  Estimated: `4039 + c * (2685 ±0)`
 		// Minimum execution time: 264_075 nanoseconds.
-		Weight::from_parts(219_016_367, 3830)
+		Weight::from_parts(219_016_367, 4039)
 			// Standard Error: 102_148
 			.saturating_add(Weight::from_parts(79_147_583, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
@@ -794,7 +1860,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:50 w:50)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Metadata` (r:50 w:0)
@@ -809,9 +1875,9 @@ impl WeightInfo for () {
 	fn finish_destroy(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `504 + c * (221 ±0)`
-		//  Estimated: `3830 + c * (2685 ±0)`
+		//  Estimated: `4039 + c * (2685 ±0)`
 		// Minimum execution time: 723_132 nanoseconds.
-		Weight::from_parts(655_543_968, 3830)
+		Weight::from_parts(655_543_968, 4039)
 			// Standard Error: 157_030
 			.saturating_add(Weight::from_parts(102_388_883, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
@@ -821,7 +1887,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:1 w:0)
@@ -830,16 +1896,16 @@ impl WeightInfo for () {
 	fn start_refund(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1005 + c * (11 ±0)`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 250_787 nanoseconds.
-		Weight::from_parts(264_418_963, 3830)
+		Weight::from_parts(264_418_963, 4039)
 			// Standard Error: 10_398
 			.saturating_add(Weight::from_parts(128_444, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:1)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:1 w:0)
@@ -848,22 +1914,26 @@ impl WeightInfo for () {
 	fn force_start_refund(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `940 + c * (11 ±0)`
-		//  Estimated: `3830`
+		//  Estimated: `4039`
 		// Minimum execution time: 251_032 nanoseconds.
-		Weight::from_parts(265_248_643, 3830)
+		Weight::from_parts(265_248_643, 4039)
 			// Standard Error: 14_079
 			.saturating_add(Weight::from_parts(80_561, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `BondedCoins::Pools` (r:1 w:0)
-	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(365), added: 2840, mode: `MaxEncodedLen`)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
 	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `BondedCurrencies::Asset` (r:51 w:2)
 	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::ResolvedOutcomes` (r:1 w:0)
+	/// Proof: `BondedCoins::ResolvedOutcomes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 50]`.
 	fn refund_account(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -873,9 +1943,276 @@ impl WeightInfo for () {
 		Weight::from_parts(1_343_165_422, 8817)
 			// Standard Error: 90_432
 			.saturating_add(Weight::from_parts(31_315_327, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 			.saturating_add(Weight::from_parts(0, 2685).saturating_mul(c.into()))
 	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:1)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:1 w:1)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn claim_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `734`
+		//  Estimated: `6208`
+		// Minimum execution time: 264_391 nanoseconds.
+		Weight::from_parts(268_712_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolGraduationSettings` (r:0 w:1)
+	/// Proof: `BondedCoins::PoolGraduationSettings` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn set_graduation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339`
+		//  Estimated: `4039`
+		// Minimum execution time: 135_912 nanoseconds.
+		Weight::from_parts(137_404_000, 4039)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolGraduationSettings` (r:1 w:1)
+	/// Proof: `BondedCoins::PoolGraduationSettings` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AccruedFees` (r:1 w:0)
+	/// Proof: `BondedCoins::AccruedFees` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:0 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn graduate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `4039`
+		// Minimum execution time: 172_318 nanoseconds.
+		Weight::from_parts(175_066_000, 4039)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:1 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn swap_collateral_for_bonded() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `934`
+		//  Estimated: `8817`
+		// Minimum execution time: 301_427 nanoseconds.
+		Weight::from_parts(305_118_000, 8817)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:1 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn swap_bonded_for_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1037`
+		//  Estimated: `8817`
+		// Minimum execution time: 312_806 nanoseconds.
+		Weight::from_parts(316_247_000, 8817)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:1 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::LiquidityShares` (r:1 w:1)
+	/// Proof: `BondedCoins::LiquidityShares` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn add_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1037`
+		//  Estimated: `8817`
+		// Minimum execution time: 338_215 nanoseconds.
+		Weight::from_parts(342_539_000, 8817)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::AmmPools` (r:1 w:1)
+	/// Proof: `BondedCoins::AmmPools` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:2 w:2)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:3 w:3)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::LiquidityShares` (r:1 w:1)
+	/// Proof: `BondedCoins::LiquidityShares` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn remove_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1009`
+		//  Estimated: `8817`
+		// Minimum execution time: 329_644 nanoseconds.
+		Weight::from_parts(333_870_000, 8817)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolVestingSettings` (r:0 w:1)
+	/// Proof: `BondedCoins::PoolVestingSettings` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn set_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339`
+		//  Estimated: `4039`
+		// Minimum execution time: 134_208 nanoseconds.
+		Weight::from_parts(136_117_000, 4039)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::VestingSchedules` (r:1 w:1)
+	/// Proof: `BondedCoins::VestingSchedules` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	fn claim_vested() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `4039`
+		// Minimum execution time: 158_934 nanoseconds.
+		Weight::from_parts(161_502_000, 4039)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:0)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolResolvers` (r:0 w:1)
+	/// Proof: `BondedCoins::PoolResolvers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn set_resolver() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339`
+		//  Estimated: `4039`
+		// Minimum execution time: 134_871 nanoseconds.
+		Weight::from_parts(136_592_000, 4039)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolResolvers` (r:1 w:0)
+	/// Proof: `BondedCoins::PoolResolvers` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::ResolvedOutcomes` (r:0 w:1)
+	/// Proof: `BondedCoins::ResolvedOutcomes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn resolve(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1005 + c * (11 ±0)`
+		//  Estimated: `4039`
+		// Minimum execution time: 265_834 nanoseconds.
+		Weight::from_parts(280_284_100, 4039)
+			// Standard Error: 10_398
+			.saturating_add(Weight::from_parts(128_444, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Account` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCurrencies::Asset` (r:1 w:0)
+	/// Proof: `BondedCurrencies::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::ResolvedOutcomes` (r:0 w:1)
+	/// Proof: `BondedCoins::ResolvedOutcomes` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[2, 50]`.
+	fn force_resolve(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `940 + c * (11 ±0)`
+		//  Estimated: `4039`
+		// Minimum execution time: 261_073 nanoseconds.
+		Weight::from_parts(275_858_588, 4039)
+			// Standard Error: 14_079
+			.saturating_add(Weight::from_parts(80_561, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::Metadata` (r:1 w:1)
+	/// Proof: `BondedCoins::Metadata` (`max_values`: None, `max_size`: Some(463), added: 2938, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `4039`
+		// Minimum execution time: 231_407 nanoseconds.
+		Weight::from_parts(236_862_000, 4039)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `BondedCoins::Pools` (r:1 w:1)
+	/// Proof: `BondedCoins::Pools` (`max_values`: None, `max_size`: Some(574), added: 3049, mode: `MaxEncodedLen`)
+	/// Storage: `BondedCoins::PoolIssuers` (r:1 w:1)
+	/// Proof: `BondedCoins::PoolIssuers` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	fn set_issuer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `4039`
+		// Minimum execution time: 224_916 nanoseconds.
+		Weight::from_parts(229_735_000, 4039)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. The curve
+	/// is evaluated once per currency, each time as costly as minting into an
+	/// LMSR pool.
+	/// Storage: `BondedCoins::PriceAccumulators` (r:1 w:1)
+	/// Storage: `BondedCoins::PriceObservations` (r:1 w:1)
+	fn update_price_accumulator(c: u32, ) -> Weight {
+		Weight::from_parts(Self::mint_into_lmsr(c).ref_time().saturating_mul(c.into()), 9061)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Burning
+	/// is as costly as for a polynomial pool, and sending the collateral
+	/// withdraws it from the caller and deposits it into the account of the
+	/// reserve.
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	fn burn_into_remote(c: u32, ) -> Weight {
+		Self::burn_into_polynomial(c)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -31,6 +31,7 @@ mod tests;
 #[cfg(any(feature = "try-runtime", test))]
 mod try_state;
 
+pub mod amm;
 pub mod curves;
 mod default_weights;
 pub mod traits;
@@ -38,7 +39,10 @@ mod types;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::BenchmarkHelper;
 
-pub use types::{BondedCurrenciesSettings, Locks, PoolStatus, Round};
+pub use types::{
+	AmmPool, BondedCurrenciesSettings, GraduationSettings, Locks, PoolFees, PoolIssuer, PoolMetadata, PoolStatus,
+	PriceAccumulator, PriceObservation, Round, VestingSchedule, VestingSettings,
+};
 
 pub use default_weights::WeightInfo;
 
@@ -74,13 +78,15 @@ pub mod pallet {
 		Hashable, Parameter,
 	};
 	use frame_system::pallet_prelude::*;
+	use pallet_assets::FrozenBalance;
 	use sp_arithmetic::ArithmeticError;
 	use sp_core::U256;
 	use sp_runtime::{
 		traits::{
-			Bounded, CheckedConversion, SaturatedConversion, Saturating, StaticLookup, UniqueSaturatedInto, Zero,
+			Bounded, CheckedAdd, CheckedConversion, CheckedDiv, CheckedMul, CheckedSub, One, SaturatedConversion,
+			Saturating, StaticLookup, UniqueSaturatedInto, Zero,
 		},
-		BoundedVec, DispatchError, TokenError,
+		BoundedVec, DispatchError, Perbill, TokenError,
 	};
 	use sp_std::{
 		collections::btree_set::BTreeSet,
@@ -95,9 +101,14 @@ pub mod pallet {
 	};
 
 	use crate::{
+		amm,
 		curves::{balance_to_fixed, fixed_to_balance, BondingFunction, Curve, CurveInput},
-		traits::{FreezeAccounts, NextAssetIds, ResetTeam},
-		types::{BondedCurrenciesSettings, Locks, PoolDetails, PoolManagingTeam, PoolStatus, Round, TokenMeta},
+		traits::{BondedPriceOracle, FreezeAccounts, NextAssetIds, RemoteCollateralTransfer, ResetTeam, VerifyIssuer},
+		types::{
+			AmmPool, BondedCurrenciesSettings, GraduationSettings, Locks, PoolDetails, PoolFees, PoolIssuer,
+			PoolManagingTeam, PoolMetadata, PoolStatus, PriceAccumulator, PriceObservation, Round, TokenMeta,
+			VestingSchedule, VestingSettings,
+		},
 		WeightInfo,
 	};
 
@@ -143,6 +154,44 @@ pub mod pallet {
 		BondedCurrenciesSettingsOf<T>,
 	>;
 
+	pub type GraduationSettingsOf<T> = GraduationSettings<CollateralBalanceOf<T>>;
+
+	pub type AmmPoolOf<T> = AmmPool<CollateralBalanceOf<T>, FungiblesBalanceOf<T>>;
+
+	pub type VestingSettingsOf<T> = VestingSettings<BlockNumberFor<T>, FungiblesBalanceOf<T>>;
+
+	pub type VestingScheduleOf<T> = VestingSchedule<BlockNumberFor<T>, FungiblesBalanceOf<T>>;
+
+	pub(crate) type VestingSchedulesOf<T> = BoundedVec<VestingScheduleOf<T>, <T as Config>::MaxVestingSchedules>;
+
+	pub(crate) type SpotPricesOf<T> = BoundedVec<CollateralBalanceOf<T>, <T as Config>::MaxCurrenciesPerPool>;
+
+	pub(crate) type CumulativePricesOf<T> = BoundedVec<U256, <T as Config>::MaxCurrenciesPerPool>;
+
+	pub type PriceAccumulatorOf<T> = PriceAccumulator<BlockNumberFor<T>, SpotPricesOf<T>, CumulativePricesOf<T>>;
+
+	pub type PriceObservationOf<T> = PriceObservation<BlockNumberFor<T>, CumulativePricesOf<T>>;
+
+	pub type RemoteBeneficiaryOf<T> = <<T as Config>::RemoteCollaterals as RemoteCollateralTransfer<
+		AccountIdOf<T>,
+		CollateralAssetIdOf<T>,
+		CollateralBalanceOf<T>,
+	>>::Beneficiary;
+
+	pub type DescriptionOf<T> = BoundedVec<u8, <T as Config>::MaxDescriptionLength>;
+
+	pub type UriOf<T> = BoundedVec<u8, <T as Config>::MaxUriLength>;
+
+	pub type PoolMetadataOf<T> = PoolMetadata<DescriptionOf<T>, UriOf<T>, <T as frame_system::Config>::Hash>;
+
+	pub type IssuerIdentifierOf<T> =
+		<<T as Config>::IssuerVerification as VerifyIssuer<AccountIdOf<T>, <T as Config>::PoolId>>::Identifier;
+
+	pub type IssuerCredentialIdOf<T> =
+		<<T as Config>::IssuerVerification as VerifyIssuer<AccountIdOf<T>, <T as Config>::PoolId>>::CredentialId;
+
+	pub type PoolIssuerOf<T> = PoolIssuer<IssuerIdentifierOf<T>, IssuerCredentialIdOf<T>>;
+
 	/// Minimum required amount of integer and fractional bits to perform ln,
 	/// sqrt, and exp operations
 	pub(crate) type Precision = I9F23;
@@ -154,6 +203,11 @@ pub mod pallet {
 
 	pub(crate) const LOG_TARGET: &str = "runtime::pallet-bonded-coins";
 
+	/// Maximum number of curve evaluations in each phase of the search for
+	/// the outcome of a swap within a pool. Balances have at most 128 bits,
+	/// so neither doubling nor bisecting can take more steps.
+	pub(crate) const MAX_SWAP_SEARCH_STEPS: u32 = 128;
+
 	/// Configure the pallet by specifying the parameters and types on which it
 	/// depends.
 	#[pallet::config]
@@ -199,6 +253,66 @@ pub mod pallet {
 		#[pallet::constant]
 		type BaseDeposit: Get<DepositBalanceOf<Self>>;
 
+		/// The maximum fee a pool can charge on minting or burning.
+		#[pallet::constant]
+		type MaxPoolFee: Get<Perbill>;
+
+		/// The share of the collateral of each mint and burn operation which is
+		/// charged as protocol fee, in addition to the fees of the pool.
+		#[pallet::constant]
+		type ProtocolFee: Get<Perbill>;
+
+		/// The account receiving the protocol fees, e.g., the treasury.
+		type ProtocolFeeDestination: Get<Self::AccountId>;
+
+		/// The share of the amount paid into the AMM of a graduated pool which
+		/// is retained in its reserves for liquidity providers.
+		#[pallet::constant]
+		type SwapFee: Get<Perbill>;
+
+		/// The maximum number of vesting schedules of an account for a single
+		/// bonded currency.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// The maximum number of price observations kept for each pool. Along
+		/// with `PriceObservationPeriod`, this limits the window of
+		/// time-weighted average prices.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
+		/// The minimum number of blocks between two price observations of a
+		/// pool.
+		#[pallet::constant]
+		type PriceObservationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Implementation to send collateral to an account on another chain,
+		/// e.g., to return the proceeds of burning to the reserve chain of a
+		/// collateral received via XCM.
+		type RemoteCollaterals: RemoteCollateralTransfer<
+			Self::AccountId,
+			CollateralAssetIdOf<Self>,
+			CollateralBalanceOf<Self>,
+		>;
+
+		/// The maximum length of the description of a pool.
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+
+		/// The maximum length of the URI of a pool.
+		#[pallet::constant]
+		type MaxUriLength: Get<u32>;
+
+		/// The deposit required for storing the metadata of a pool and for
+		/// linking a pool to the identity of its owner, each.
+		#[pallet::constant]
+		type MetadataDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// Implementation to verify that the owner of a pool controls the
+		/// identity the pool is linked to, e.g., a DID backed by a public
+		/// credential.
+		type IssuerVerification: VerifyIssuer<Self::AccountId, Self::PoolId>;
+
 		/// The origin for most permissionless and privileged operations.
 		type DefaultOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 		/// The dedicated origin for creating new bonded currency pools
@@ -206,6 +320,10 @@ pub mod pallet {
 		type PoolCreateOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 		/// The origin for permissioned operations (force_* transactions).
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin for resolving the outcome of a pool. The success value
+		/// is compared against the designated resolver of the pool, which
+		/// allows for resolvers other than regular accounts, e.g., DIDs.
+		type ResolveOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 		/// The type used for pool ids
 		type PoolId: Parameter + MaxEncodedLen + From<[u8; 32]> + Into<Self::AccountId>;
 
@@ -254,6 +372,16 @@ pub mod pallet {
 				that the maximum scaling factor `10^MaxDenomination` is smaller than the fractional \
 				capacity `2^frac_nbits` of `CurveParameterType`",
 			);
+
+			assert!(
+				T::MaxPoolFee::get().saturating_add(T::ProtocolFee::get()) < Perbill::one(),
+				"The maximum pool fee and the protocol fee must not withhold the entire collateral released by burning",
+			);
+
+			assert!(
+				T::SwapFee::get() < Perbill::one(),
+				"The swap fee must not withhold the entire amount paid into the AMM of a graduated pool",
+			);
 		}
 
 		#[cfg(feature = "try-runtime")]
@@ -266,6 +394,98 @@ pub mod pallet {
 	#[pallet::getter(fn pool_with_id)]
 	pub type Pools<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, PoolDetailsOf<T>, OptionQuery>;
 
+	/// The fees charged by a pool on minting and burning.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_fees)]
+	pub type PoolFeeSettings<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, PoolFees, ValueQuery>;
+
+	/// The fees accrued by a pool which have not been claimed yet. They are
+	/// held by the pool account in the collateral currency but do not back the
+	/// bonded currencies.
+	#[pallet::storage]
+	#[pallet::getter(fn accrued_fees)]
+	pub type AccruedFees<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, CollateralBalanceOf<T>, ValueQuery>;
+
+	/// The conditions under which a pool graduates into a constant-product
+	/// AMM.
+	#[pallet::storage]
+	#[pallet::getter(fn graduation_settings)]
+	pub type PoolGraduationSettings<T: Config> =
+		StorageMap<_, Twox64Concat, T::PoolId, GraduationSettingsOf<T>, OptionQuery>;
+
+	/// The reserves of the constant-product AMMs of graduated pools.
+	#[pallet::storage]
+	#[pallet::getter(fn amm_pool)]
+	pub type AmmPools<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, AmmPoolOf<T>, OptionQuery>;
+
+	/// The liquidity shares of accounts in the AMM of a graduated pool.
+	#[pallet::storage]
+	#[pallet::getter(fn liquidity_shares)]
+	pub type LiquidityShares<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		CollateralBalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The vesting rules applied to bonded tokens minted in a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_settings)]
+	pub type PoolVestingSettings<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, VestingSettingsOf<T>, OptionQuery>;
+
+	/// The bonded tokens of an account which are frozen until they have
+	/// vested and been claimed.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_schedules)]
+	pub type VestingSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		FungiblesAssetIdOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		VestingSchedulesOf<T>,
+		ValueQuery,
+	>;
+
+	/// The designated resolver of a pool, who can declare its winning
+	/// currency.
+	#[pallet::storage]
+	#[pallet::getter(fn resolver)]
+	pub type PoolResolvers<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, T::AccountId, OptionQuery>;
+
+	/// The index of the winning currency of a resolved pool.
+	#[pallet::storage]
+	#[pallet::getter(fn resolved_outcome)]
+	pub type ResolvedOutcomes<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, u32, OptionQuery>;
+
+	/// The spot and cumulative prices of the bonded currencies of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn price_accumulator)]
+	pub type PriceAccumulators<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, PriceAccumulatorOf<T>, OptionQuery>;
+
+	/// Snapshots of the cumulative prices of a pool, taken at most once per
+	/// `PriceObservationPeriod`. The snapshots are stored in a ring buffer of
+	/// `MaxPriceObservations` slots.
+	#[pallet::storage]
+	#[pallet::getter(fn price_observation)]
+	pub type PriceObservations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::PoolId, Twox64Concat, u32, PriceObservationOf<T>, OptionQuery>;
+
+	/// Descriptive metadata of a pool, such as a description, a URI and the
+	/// hash of a logo.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_metadata)]
+	pub type Metadata<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, PoolMetadataOf<T>, OptionQuery>;
+
+	/// The identity of the owner of a pool, e.g., a DID, along with the
+	/// credential backing it.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_issuer)]
+	pub type PoolIssuers<T: Config> = StorageMap<_, Twox64Concat, T::PoolId, PoolIssuerOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -296,6 +516,120 @@ pub mod pallet {
 			admin: T::AccountId,
 			freezer: T::AccountId,
 		},
+		/// Fees have been charged on minting or burning bonded tokens.
+		FeesCharged {
+			id: T::PoolId,
+			pool_fee: CollateralBalanceOf<T>,
+			protocol_fee: CollateralBalanceOf<T>,
+		},
+		/// The accrued fees of a pool have been claimed.
+		FeesClaimed {
+			id: T::PoolId,
+			beneficiary: T::AccountId,
+			amount: CollateralBalanceOf<T>,
+		},
+		/// The graduation settings of a pool have been set or removed.
+		GraduationSettingsUpdated {
+			id: T::PoolId,
+			settings: Option<GraduationSettingsOf<T>>,
+		},
+		/// A pool has graduated and its liquidity has been migrated into a
+		/// constant-product AMM.
+		Graduated {
+			id: T::PoolId,
+			collateral_reserve: CollateralBalanceOf<T>,
+			bonded_reserve: FungiblesBalanceOf<T>,
+		},
+		/// Collateral has been swapped for bonded tokens in the AMM of a pool.
+		SwappedCollateralForBonded {
+			id: T::PoolId,
+			who: T::AccountId,
+			amount_in: CollateralBalanceOf<T>,
+			amount_out: FungiblesBalanceOf<T>,
+		},
+		/// Bonded tokens have been swapped for collateral in the AMM of a pool.
+		SwappedBondedForCollateral {
+			id: T::PoolId,
+			who: T::AccountId,
+			amount_in: FungiblesBalanceOf<T>,
+			amount_out: CollateralBalanceOf<T>,
+		},
+		/// Liquidity has been added to the AMM of a pool.
+		LiquidityAdded {
+			id: T::PoolId,
+			who: T::AccountId,
+			collateral_amount: CollateralBalanceOf<T>,
+			bonded_amount: FungiblesBalanceOf<T>,
+			shares: CollateralBalanceOf<T>,
+		},
+		/// Liquidity has been removed from the AMM of a pool.
+		LiquidityRemoved {
+			id: T::PoolId,
+			who: T::AccountId,
+			collateral_amount: CollateralBalanceOf<T>,
+			bonded_amount: FungiblesBalanceOf<T>,
+			shares: CollateralBalanceOf<T>,
+		},
+		/// The vesting settings of a pool have been set or removed.
+		VestingSettingsUpdated {
+			id: T::PoolId,
+			settings: Option<VestingSettingsOf<T>>,
+		},
+		/// Minted bonded tokens have been frozen until they vest.
+		VestingScheduleAdded {
+			id: T::PoolId,
+			who: T::AccountId,
+			schedule: VestingScheduleOf<T>,
+		},
+		/// Vested bonded tokens have been released.
+		VestedClaimed {
+			id: T::PoolId,
+			who: T::AccountId,
+			amount: FungiblesBalanceOf<T>,
+		},
+		/// Bonded tokens have been swapped for another bonded currency of the
+		/// same pool.
+		SwappedWithinPool {
+			id: T::PoolId,
+			who: T::AccountId,
+			from: FungiblesAssetIdOf<T>,
+			to: FungiblesAssetIdOf<T>,
+			amount_in: FungiblesBalanceOf<T>,
+			amount_out: FungiblesBalanceOf<T>,
+		},
+		/// The resolver of a pool has been set.
+		ResolverSet { id: T::PoolId, resolver: T::AccountId },
+		/// The outcome of a pool has been resolved. Holders of the winning
+		/// currency can now redeem the collateral of the pool.
+		Resolved { id: T::PoolId, winning_currency_idx: u32 },
+		/// The collateral released by burning bonded tokens has been sent to
+		/// an account on another chain.
+		CollateralSentRemote {
+			id: T::PoolId,
+			who: T::AccountId,
+			collateral: CollateralAssetIdOf<T>,
+			amount: CollateralBalanceOf<T>,
+			beneficiary: RemoteBeneficiaryOf<T>,
+		},
+		/// The metadata of a pool has been set or cleared.
+		MetadataUpdated {
+			id: T::PoolId,
+			metadata: Option<PoolMetadataOf<T>>,
+		},
+		/// A pool has been linked to or unlinked from the identity of its
+		/// owner.
+		IssuerUpdated {
+			id: T::PoolId,
+			issuer: Option<PoolIssuerOf<T>>,
+		},
+		/// The spot price of a currency of a pool could not be calculated
+		/// from the current supplies. The price oracle keeps accumulating the
+		/// previous spot price until the next update.
+		SpotPriceCarriedForward {
+			id: T::PoolId,
+			currency_idx: u32,
+			spot_price: CollateralBalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -335,6 +669,37 @@ pub mod pallet {
 		ZeroCollateral,
 		/// A pool has to contain at least one bonded currency.
 		ZeroBondedCurrency,
+		/// The pool has not accrued any fees to claim.
+		NoFeesToClaim,
+		/// The pool has no graduation settings.
+		GraduationNotConfigured,
+		/// The collateral reserves of the pool have not reached the graduation
+		/// threshold yet.
+		GraduationThresholdNotReached,
+		/// This operation can only be made when the pool has graduated into an
+		/// AMM.
+		NotGraduated,
+		/// The AMM reserves or the provided amounts are too small for the
+		/// operation, i.e., it would result in a zero amount.
+		InsufficientLiquidity,
+		/// The account does not own the given amount of liquidity shares.
+		InsufficientShares,
+		/// The account has reached the maximum number of vesting schedules for
+		/// the bonded currency.
+		TooManyVestingSchedules,
+		/// The account has no vested tokens to claim.
+		NothingToClaim,
+		/// The outcome of the pool cannot be resolved, as it is not using an
+		/// LMSR curve with multiple currencies.
+		NotResolvable,
+		/// The price history of the pool does not cover the requested window.
+		InsufficientPriceHistory,
+		/// The owner of the pool does not control the given identity, or the
+		/// credential backing it is invalid.
+		IssuerNotVerified,
+		/// The resolver of the pool has already been set and cannot be
+		/// changed.
+		ResolverAlreadySet,
 	}
 
 	#[pallet::call]
@@ -365,6 +730,8 @@ pub mod pallet {
 		///     allowed for this pool.
 		///   - `min_operation_balance`: The minimum amount that can be
 		///     minted/burnt.
+		/// - `fees`: The fees charged by the pool on the collateral of mint and
+		///   burn operations. These cannot be changed after the pool is created.
 		///
 		/// # Returns
 		/// - `DispatchResult`: The result of the dispatch.
@@ -374,6 +741,7 @@ pub mod pallet {
 		///   - the denomination is greater than the maximum allowed
 		///   - the curve input is invalid
 		///   - two currencies use the same name or symbol
+		///   - a fee is greater than the maximum allowed
 		/// - `Error::<T>::Internal`: If the conversion to `BoundedVec` fails.
 		/// - Other errors depending on the types in the config.
 		#[pallet::call_index(0)]
@@ -383,6 +751,8 @@ pub mod pallet {
 				CurveInput::Polynomial(_) => T::WeightInfo::create_pool_polynomial(currency_length),
 				CurveInput::SquareRoot(_) => T::WeightInfo::create_pool_square_root(currency_length),
 				CurveInput::Lmsr(_) => T::WeightInfo::create_pool_lmsr(currency_length),
+				CurveInput::PiecewiseLinear(_) => T::WeightInfo::create_pool_piecewise_linear(currency_length),
+				CurveInput::Exponential(_) => T::WeightInfo::create_pool_exponential(currency_length),
 			}
 		})]
 		pub fn create_pool(
@@ -391,6 +761,7 @@ pub mod pallet {
 			collateral_id: CollateralAssetIdOf<T>,
 			currencies: BoundedVec<TokenMetaOf<T>, T::MaxCurrenciesPerPool>,
 			currencies_settings: BondedCurrenciesSettingsOf<T>,
+			fees: PoolFees,
 		) -> DispatchResult {
 			let who = T::PoolCreateOrigin::ensure_origin(origin)?;
 
@@ -402,6 +773,10 @@ pub mod pallet {
 			} = currencies_settings;
 
 			ensure!(denomination <= T::MaxDenomination::get(), Error::<T>::InvalidInput);
+			ensure!(
+				fees.mint <= T::MaxPoolFee::get() && fees.burn <= T::MaxPoolFee::get(),
+				Error::<T>::InvalidInput
+			);
 			let checked_curve = curve.try_into().map_err(|_| Error::<T>::InvalidInput)?;
 
 			let currency_length = currencies.len();
@@ -484,6 +859,7 @@ pub mod pallet {
 					deposit_amount,
 				)),
 			);
+			PoolFeeSettings::<T>::insert(&pool_id, fees);
 
 			Self::deposit_event(Event::PoolCreated { id: pool_id });
 
@@ -691,7 +1067,8 @@ pub mod pallet {
 		/// Mints new bonded tokens. The tokens will be minted into the
 		/// beneficiary account. In exchange, an amount of collateral determined
 		/// by the pool's bonding curve is debited from the caller and
-		/// transferred to the pool account.
+		/// transferred to the pool account. The mint fee of the pool and the
+		/// protocol fee are charged on top of this amount.
 		/// The origin account must be a manager of the pool if its state is
 		/// `Locked` and `allow_mint` is false. The pool must be in a live
 		/// (non-refunding, non-destroying) state.
@@ -703,7 +1080,7 @@ pub mod pallet {
 		///   vector.
 		/// - `beneficiary`: The account to receive the minted tokens.
 		/// - `amount_to_mint`: The amount of bonded tokens to mint.
-		/// - `max_cost`: The maximum cost of collateral.
+		/// - `max_cost`: The maximum cost of collateral, including fees.
 		/// - `currency_count`: The maximum number of currencies allowed in the
 		///   pool.
 		///
@@ -727,7 +1104,14 @@ pub mod pallet {
 			let weight_polynomial = T::WeightInfo::mint_into_polynomial(currency_count.to_owned());
 			let weight_square_root = T::WeightInfo::mint_into_square_root(currency_count.to_owned());
 			let weight_lmsr = T::WeightInfo::mint_into_lmsr(currency_count.to_owned());
-			weight_polynomial.max(weight_square_root).max(weight_lmsr)
+			let weight_piecewise_linear = T::WeightInfo::mint_into_piecewise_linear(currency_count.to_owned());
+			let weight_exponential = T::WeightInfo::mint_into_exponential(currency_count.to_owned());
+			weight_polynomial
+				.max(weight_square_root)
+				.max(weight_lmsr)
+				.max(weight_piecewise_linear)
+				.max(weight_exponential)
+				.saturating_add(T::WeightInfo::update_price_accumulator(currency_count.to_owned()))
 		})]
 		pub fn mint_into(
 			origin: OriginFor<T>,
@@ -762,6 +1146,8 @@ pub mod pallet {
 
 			ensure!(pool_details.can_mint(&who), Error::<T>::NoPermission);
 
+			let vesting_settings = Self::applicable_vesting_settings(&pool_id, &pool_details, &who, amount_to_mint);
+
 			let bonded_currencies = pool_details.bonded_currencies;
 
 			let currency_idx: usize = currency_idx.saturated_into();
@@ -796,40 +1182,77 @@ pub mod pallet {
 			)?;
 
 			ensure!(cost > Zero::zero(), Error::<T>::ZeroCollateral);
-			// fail if cost > max_cost
-			ensure!(cost <= max_cost, Error::<T>::Slippage);
+
+			let (pool_fee, protocol_fee) = Self::calculate_fees(
+				PoolFeeSettings::<T>::get(&pool_id).mint,
+				pool_details.collateral.clone(),
+				cost,
+			);
+			let total_cost = cost
+				.checked_add(&pool_fee)
+				.and_then(|total| total.checked_add(&protocol_fee))
+				.ok_or(ArithmeticError::Overflow)?;
+			// fail if total_cost > max_cost
+			ensure!(total_cost <= max_cost, Error::<T>::Slippage);
 
 			// Transfer the collateral. We do not want to kill the minter, so this operation
-			// can fail if the account is being reaped.
+			// can fail if the account is being reaped. The pool fee is kept in the pool
+			// account until claimed.
 			T::Collaterals::transfer(
-				pool_details.collateral,
+				pool_details.collateral.clone(),
 				&who,
-				&pool_id.into(),
-				cost,
+				&pool_id.clone().into(),
+				cost.saturating_add(pool_fee),
 				Preservation::Preserve,
 			)?;
+			if !protocol_fee.is_zero() {
+				T::Collaterals::transfer(
+					pool_details.collateral.clone(),
+					&who,
+					&T::ProtocolFeeDestination::get(),
+					protocol_fee,
+					Preservation::Preserve,
+				)?;
+			}
+			Self::accrue_fees(pool_id.clone(), pool_fee, protocol_fee);
 
-			T::Fungibles::mint_into(target_currency_id.clone(), &beneficiary, amount_to_mint)?;
+			Self::mint_bonded(target_currency_id.clone(), &beneficiary, amount_to_mint, transferable)?;
 
-			if !transferable {
-				T::Fungibles::freeze(target_currency_id, &beneficiary).map_err(|freeze_error| {
-					log::info!(target: LOG_TARGET, "Failed to freeze account: {:?}", freeze_error);
-					freeze_error.into()
-				})?;
+			Self::update_price_accumulator(
+				&pool_id,
+				&pool_details.curve,
+				&bonded_currencies,
+				denomination,
+				pool_details.collateral,
+			);
+
+			if let Some(settings) = vesting_settings {
+				Self::add_vesting_schedule(
+					pool_id,
+					target_currency_id.clone(),
+					beneficiary,
+					amount_to_mint,
+					settings,
+				)?;
 			}
 
-			Ok(Some(match pool_details.curve {
+			let weight = match pool_details.curve {
 				Curve::Polynomial(_) => T::WeightInfo::mint_into_polynomial(number_of_currencies),
 				Curve::SquareRoot(_) => T::WeightInfo::mint_into_square_root(number_of_currencies),
 				Curve::Lmsr(_) => T::WeightInfo::mint_into_lmsr(number_of_currencies),
-			})
-			.into())
+				Curve::PiecewiseLinear(_) => T::WeightInfo::mint_into_piecewise_linear(number_of_currencies),
+				Curve::Exponential(_) => T::WeightInfo::mint_into_exponential(number_of_currencies),
+			}
+			.saturating_add(T::WeightInfo::update_price_accumulator(number_of_currencies));
+
+			Ok(Some(weight).into())
 		}
 
 		/// Burns a specified amount of bonded tokens from the callers account
 		/// and transfers the corresponding collateral to the beneficiary.
 		/// The amount of collateral to be transferred is calculated based on
-		/// the amount of bonded tokens burned.
+		/// the amount of bonded tokens burned, minus the burn fee of the pool
+		/// and the protocol fee.
 		///
 		/// # Parameters
 		/// - `origin`: The origin of the call.
@@ -838,7 +1261,8 @@ pub mod pallet {
 		///   vector.
 		/// - `beneficiary`: The account to receive the collateral.
 		/// - `amount_to_burn`: The amount of bonded tokens to burn.
-		/// - `min_return`: The minimum amount of collateral to return.
+		/// - `min_return`: The minimum amount of collateral to return, after
+		///   deducting fees.
 		/// - `currency_count`: The currency count in the pool, required for
 		///   weight calculation.
 		///
@@ -863,7 +1287,14 @@ pub mod pallet {
 			let weight_polynomial = T::WeightInfo::burn_into_polynomial(currency_count.to_owned());
 			let weight_square_root = T::WeightInfo::burn_into_square_root(currency_count.to_owned());
 			let weight_lmsr = T::WeightInfo::burn_into_lmsr(currency_count.to_owned());
-			weight_polynomial.max(weight_square_root).max(weight_lmsr)
+			let weight_piecewise_linear = T::WeightInfo::burn_into_piecewise_linear(currency_count.to_owned());
+			let weight_exponential = T::WeightInfo::burn_into_exponential(currency_count.to_owned());
+			weight_polynomial
+				.max(weight_square_root)
+				.max(weight_lmsr)
+				.max(weight_piecewise_linear)
+				.max(weight_exponential)
+				.saturating_add(T::WeightInfo::update_price_accumulator(currency_count.to_owned()))
 		})]
 		pub fn burn_into(
 			origin: OriginFor<T>,
@@ -877,104 +1308,17 @@ pub mod pallet {
 			let who = T::DefaultOrigin::ensure_origin(origin)?;
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 
-			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
-
-			let BondedCurrenciesSettings {
-				min_operation_balance,
-				denomination,
-				transferable,
-				..
-			} = pool_details.currencies_settings;
-
-			ensure!(
-				amount_to_burn >= min_operation_balance.saturated_into(),
-				TokenError::BelowMinimum
-			);
-
-			let number_of_currencies = Self::get_currencies_number(&pool_details);
-			ensure!(number_of_currencies <= currency_count, Error::<T>::CurrencyCount);
-
-			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
-			ensure!(pool_details.can_burn(&who), Error::<T>::NoPermission);
-
-			let bonded_currencies = pool_details.bonded_currencies;
-
-			let currency_idx: usize = currency_idx.saturated_into();
-			let round_kind = Round::Down;
-
-			let target_currency_id = bonded_currencies
-				.get(currency_idx)
-				.ok_or(Error::<T>::IndexOutOfBounds)?;
-
-			let (high, passive) = Self::calculate_normalized_passive_issuance(
-				&bonded_currencies,
-				denomination,
+			let (_, weight) = Self::do_burn_into(
+				who,
+				pool_id,
 				currency_idx,
-				round_kind,
-			)?;
-
-			let normalized_amount_to_burn = balance_to_fixed(amount_to_burn, denomination, round_kind)?;
-
-			let low = high
-				.checked_sub(normalized_amount_to_burn)
-				.ok_or(ArithmeticError::Underflow)?;
-
-			let collateral_return = Self::calculate_collateral(
-				low,
-				high,
-				passive,
-				&pool_details.curve,
-				pool_details.collateral.clone(),
-				round_kind,
-			)?;
-
-			ensure!(collateral_return > Zero::zero(), Error::<T>::ZeroCollateral);
-			ensure!(collateral_return >= min_return, Error::<T>::Slippage);
-
-			// Transfer the collateral to the beneficiary.
-			T::Collaterals::transfer(
-				pool_details.collateral,
-				&pool_id.into(),
-				&beneficiary,
-				collateral_return,
-				Preservation::Expendable,
-			)?;
-
-			// just remove any locks, if existing.
-			T::Fungibles::thaw(target_currency_id, &who).map_err(|freeze_error| {
-				log::info!(target: LOG_TARGET, "Failed to thaw account: {:?}", freeze_error);
-				// The thaw operation is failing, if there is no account to thaw. Overwrite the
-				// error with FungiblesError::FundsUnavailable
-				DispatchError::from(TokenError::FundsUnavailable)
-			})?;
-
-			// Burn the tokens from caller.
-			T::Fungibles::burn_from(
-				target_currency_id.clone(),
-				&who,
+				beneficiary,
 				amount_to_burn,
-				// We don't care, if account gets dusted.
-				Preservation::Expendable,
-				WithdrawalPrecision::Exact,
-				Fortitude::Force,
+				min_return,
+				currency_count,
 			)?;
 
-			let account_exists = T::Fungibles::total_balance(target_currency_id.clone(), &who) > Zero::zero();
-
-			if !transferable && account_exists {
-				// Restore locks.
-				T::Fungibles::freeze(target_currency_id, &who).map_err(|freeze_error| {
-					log::info!(target: LOG_TARGET, "Failed to freeze account: {:?}", freeze_error);
-					freeze_error.into()
-				})?;
-			}
-
-			Ok(Some(match pool_details.curve {
-				Curve::Polynomial(_) => T::WeightInfo::burn_into_polynomial(number_of_currencies),
-				Curve::SquareRoot(_) => T::WeightInfo::burn_into_square_root(number_of_currencies),
-				Curve::Lmsr(_) => T::WeightInfo::burn_into_lmsr(number_of_currencies),
-			})
-			.into())
+			Ok(Some(weight).into())
 		}
 
 		/// Starts the refund process for a pool. The pool will be set to a
@@ -1055,6 +1399,9 @@ pub mod pallet {
 		/// The amount of collateral to be refunded is calculated based on the
 		/// amount of bonded tokens burned by the account in proportion to the
 		/// total supply of bonded tokens.
+		/// If the outcome of the pool has been resolved, the collateral is
+		/// distributed among holders of the winning currency only, while
+		/// tokens of any other currency are burnt without compensation.
 		///
 		/// # Parameters
 		/// - `origin`: The origin of the call.
@@ -1101,6 +1448,8 @@ pub mod pallet {
 
 			ensure!(pool_details.state.is_refunding(), Error::<T>::NotRefunding);
 
+			let winning_currency_idx = ResolvedOutcomes::<T>::get(&pool_id);
+
 			// get asset id from linked assets vector
 			let asset_id: &FungiblesAssetIdOf<T> = pool_details
 				.bonded_currencies
@@ -1113,9 +1462,9 @@ pub mod pallet {
 			// distributed fairly; in case of any locks present on the pool account, this
 			// could lead to refunds failing to execute. This case would have to be
 			// resolved by governance, either by removing locks or force_destroying the
-			// pool.
-			let total_collateral_issuance =
-				T::Collaterals::total_balance(pool_details.collateral.clone(), &pool_account);
+			// pool. Accrued fees are not distributed, as they do not back the bonded
+			// currencies.
+			let total_collateral_issuance = Self::collateral_reserves(&pool_id, pool_details.collateral.clone());
 
 			// nothing to distribute; refunding is complete, user should call start_destroy
 			ensure!(
@@ -1123,6 +1472,9 @@ pub mod pallet {
 				Error::<T>::NothingToRefund
 			);
 
+			// Vesting does not apply to refunds.
+			VestingSchedules::<T>::remove(asset_id, &who);
+
 			//  remove any existing locks on the account prior to burning
 			T::Fungibles::thaw(asset_id, &who)
 				.map_err(|freeze_error| {
@@ -1145,15 +1497,23 @@ pub mod pallet {
 
 			ensure!(!burnt.is_zero(), TokenError::FundsUnavailable);
 
-			let sum_of_issuances = pool_details
-				.bonded_currencies
-				.into_iter()
-				.fold(U256::from(0u8), |sum, id| {
-					sum.saturating_add(T::Fungibles::total_issuance(id).into())
-				})
-				// Add the burnt amount back to the sum of total supplies
-				.checked_add(burnt)
-				.ok_or(ArithmeticError::Overflow)?;
+			// In a resolved pool, only the winning currency is backed by collateral.
+			// Holders of any other currency receive nothing.
+			let sum_of_issuances = match winning_currency_idx {
+				Some(winning_idx) if winning_idx != asset_idx => {
+					return Ok(Some(T::WeightInfo::refund_account(currency_count.to_owned())).into());
+				}
+				Some(_) => T::Fungibles::total_issuance(asset_id.clone()).into(),
+				None => pool_details
+					.bonded_currencies
+					.into_iter()
+					.fold(U256::from(0u8), |sum, id| {
+						sum.saturating_add(T::Fungibles::total_issuance(id).into())
+					}),
+			}
+			// Add the burnt amount back to the sum of total supplies
+			.checked_add(burnt)
+			.ok_or(ArithmeticError::Overflow)?;
 
 			let amount: CollateralBalanceOf<T> = burnt
 				.checked_mul(total_collateral_issuance.into())
@@ -1235,12 +1595,12 @@ pub mod pallet {
 		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
 		/// - `Error::<T>::CurrencyCount`: If the number of currencies exceeds
 		///   `max_currencies`.
-		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live or
-		///   refunding state.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live,
+		///   refunding or graduated state.
 		/// - `Error::<T>::NoPermission`: If the caller is not the owner or
 		///   manager of the pool.
 		/// - `Error::<T>::LivePool`: If there are holders or collateral to
-		///   distribute.
+		///   distribute, or liquidity provided to the AMM of a graduated pool.
 		#[pallet::call_index(11)]
 		#[pallet::weight(T::WeightInfo::start_destroy(currency_count.to_owned()))]
 		pub fn start_destroy(
@@ -1272,10 +1632,10 @@ pub mod pallet {
 		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
 		/// - `Error::<T>::CurrencyCount`: If the number of currencies exceeds
 		///   `max_currencies`.
-		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live or
-		///   refunding state.
-		/// - `Error::<T>::NoPermission`: If the caller is not the owner or
-		///   manager of the pool.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live,
+		///   refunding or graduated state.
+		/// - `Error::<T>::LivePool`: If there is liquidity provided to the AMM
+		///   of a graduated pool.
 		#[pallet::call_index(12)]
 		#[pallet::weight(T::WeightInfo::force_start_destroy(currency_count.to_owned()))]
 		pub fn force_start_destroy(
@@ -1291,8 +1651,8 @@ pub mod pallet {
 		}
 
 		/// Finishes the destruction process for a pool. The pool will be
-		/// removed from the storage and all leftover collateral and deposits
-		/// will be refunded to the owner.
+		/// removed from the storage and all leftover collateral, including
+		/// unclaimed fees, and deposits will be refunded to the owner.
 		///
 		/// # Parameters
 		/// - `origin`: The origin of the call.
@@ -1311,7 +1671,10 @@ pub mod pallet {
 		/// - `Error::<T>::LivePool`: If the pool is not in a destroying state
 		///   or if there are any accounts left on any currency.
 		#[pallet::call_index(13)]
-		#[pallet::weight(T::WeightInfo::finish_destroy(currency_count.to_owned()))]
+		#[pallet::weight(
+			T::WeightInfo::finish_destroy(currency_count.to_owned())
+				.saturating_add(Pallet::<T>::remove_price_history_weight())
+		)]
 		pub fn finish_destroy(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
@@ -1351,6 +1714,16 @@ pub mod pallet {
 			}
 
 			Pools::<T>::remove(&pool_id);
+			PoolFeeSettings::<T>::remove(&pool_id);
+			AccruedFees::<T>::remove(&pool_id);
+			PoolGraduationSettings::<T>::remove(&pool_id);
+			PoolVestingSettings::<T>::remove(&pool_id);
+			PoolResolvers::<T>::remove(&pool_id);
+			ResolvedOutcomes::<T>::remove(&pool_id);
+			PriceAccumulators::<T>::remove(&pool_id);
+			let _ = PriceObservations::<T>::clear_prefix(&pool_id, T::MaxPriceObservations::get(), None);
+			Metadata::<T>::remove(&pool_id);
+			PoolIssuers::<T>::remove(&pool_id);
 
 			let hold_reason = Self::calculate_hold_reason(&pool_id)?;
 			T::DepositCurrency::release(
@@ -1362,37 +1735,1203 @@ pub mod pallet {
 
 			Self::deposit_event(Event::Destroyed { id: pool_id });
 
-			Ok(Some(T::WeightInfo::finish_destroy(n_currencies)).into())
+			let weight =
+				T::WeightInfo::finish_destroy(n_currencies).saturating_add(Self::remove_price_history_weight());
+
+			Ok(Some(weight).into())
 		}
-	}
 
-	impl<T: Config> Pallet<T>
-	where
-		<CurveParameterTypeOf<T> as Fixed>::Bits:
-			Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign + TryFrom<U256> + TryInto<U256>,
-		CollateralBalanceOf<T>: TryFrom<U256> + TryInto<U256>,
-		FungiblesBalanceOf<T>: TryFrom<U256> + TryInto<U256>,
-	{
-		/// Calculates the collateral by the given curve and the normalized
-		/// costs. High is the upper bound of the curve, low is the lower bound.
-		/// The passive supply is the normalized total supply of all bonded
-		/// currencies, excluding the active currency.
+		/// Claims the fees accrued by a pool. The fees are transferred from the
+		/// pool account to the beneficiary. The origin account must be the
+		/// owner or a manager of the pool.
 		///
 		/// # Parameters
-		/// - `low`: The lower bound of the curve.
-		/// - `high`: The upper bound of the curve.
-		/// - `passive_supply`: The normalized total supply of all bonded
-		///   currencies, excluding the active currency.
-		/// - `curve`: A reference to the curve used for calculation.
-		/// - `collateral_currency_id`: The ID of the collateral currency.
+		/// - `origin`: The origin of the call, requiring the caller to be the
+		///   owner or a manager of the pool.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `beneficiary`: The account to receive the fees.
 		///
 		/// # Returns
-		/// - `Result<CollateralBalanceOf<T>, ArithmeticError>`: The calculated
-		///   collateral or an error.
+		/// - `DispatchResult`: The result of the dispatch.
 		///
 		/// # Errors
-		/// - `ArithmeticError`: If there is an operation overflowing,
-		///   underflowing, or division by zero.
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::NoPermission`: If the caller is not the owner or
+		///   manager of the pool.
+		/// - `Error::<T>::NoFeesToClaim`: If the pool has not accrued any fees.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::claim_fees())]
+		pub fn claim_fees(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			beneficiary: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			ensure!(
+				pool_details.is_owner(&who) || pool_details.is_manager(&who),
+				Error::<T>::NoPermission
+			);
+
+			let amount = AccruedFees::<T>::take(&pool_id);
+			ensure!(!amount.is_zero(), Error::<T>::NoFeesToClaim);
+
+			T::Collaterals::transfer(
+				pool_details.collateral,
+				&pool_id.clone().into(),
+				&beneficiary,
+				amount,
+				Preservation::Expendable,
+			)?;
+
+			Self::deposit_event(Event::FeesClaimed {
+				id: pool_id,
+				beneficiary,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Sets or removes the graduation settings of a pool. Once the
+		/// collateral reserves of the pool reach the configured threshold,
+		/// anyone can graduate the pool into a constant-product AMM. The origin
+		/// account must be a manager of the pool.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, requiring the caller to be a
+		///   manager of the pool.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `settings`: The graduation settings, or `None` to remove them.
+		///   - `collateral_threshold`: The collateral reserves required for
+		///     graduation.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NoPermission`: If the caller is not a manager of the
+		///   pool.
+		/// - `Error::<T>::InvalidInput`: If the pool has more than one bonded
+		///   currency or the threshold is zero.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::set_graduation())]
+		pub fn set_graduation(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			settings: Option<GraduationSettingsOf<T>>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+			ensure!(pool_details.is_manager(&who), Error::<T>::NoPermission);
+
+			if let Some(GraduationSettings { collateral_threshold }) = &settings {
+				// The AMM pairs the collateral with a single bonded currency.
+				ensure!(
+					Self::get_currencies_number(&pool_details) == 1,
+					Error::<T>::InvalidInput
+				);
+				ensure!(!collateral_threshold.is_zero(), Error::<T>::InvalidInput);
+			}
+
+			PoolGraduationSettings::<T>::set(&pool_id, settings.clone());
+
+			Self::deposit_event(Event::GraduationSettingsUpdated { id: pool_id, settings });
+
+			Ok(())
+		}
+
+		/// Graduates a pool into a constant-product AMM. Minting and burning on
+		/// the bonding curve is disabled from then on. The collateral reserves
+		/// of the pool seed the AMM. They are paired with the supply of the
+		/// bonded currency which they buy at the spot price of the bonding
+		/// curve, so that the AMM starts trading at the price at which the
+		/// curve stopped. The liquidity shares issued for this initial
+		/// liquidity are not owned by any account and thus permanently locked.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `pool_id`: The identifier of the pool.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::GraduationNotConfigured`: If the pool has no
+		///   graduation settings.
+		/// - `Error::<T>::GraduationThresholdNotReached`: If the collateral
+		///   reserves of the pool are below the graduation threshold.
+		/// - `Error::<T>::ZeroCollateral`: If the spot price of the bonded
+		///   currency is zero.
+		/// - `Error::<T>::InsufficientLiquidity`: If the collateral reserves do
+		///   not buy any bonded tokens at the spot price.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::graduate())]
+		pub fn graduate(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			T::DefaultOrigin::ensure_origin(origin)?;
+
+			let mut pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+
+			let settings = PoolGraduationSettings::<T>::take(&pool_id).ok_or(Error::<T>::GraduationNotConfigured)?;
+
+			let collateral_reserve = Self::collateral_reserves(&pool_id, pool_details.collateral.clone());
+			ensure!(
+				collateral_reserve >= settings.collateral_threshold,
+				Error::<T>::GraduationThresholdNotReached
+			);
+
+			let bonded_reserve = Self::calculate_graduation_bonded_reserve(&pool_details, collateral_reserve)?;
+
+			AmmPools::<T>::insert(
+				&pool_id,
+				AmmPool {
+					collateral_reserve,
+					bonded_reserve,
+					total_shares: collateral_reserve,
+				},
+			);
+
+			pool_details.state.graduate();
+			Pools::<T>::set(&pool_id, Some(pool_details));
+
+			Self::deposit_event(Event::Graduated {
+				id: pool_id,
+				collateral_reserve,
+				bonded_reserve,
+			});
+
+			Ok(())
+		}
+
+		/// Swaps collateral for bonded tokens in the AMM of a graduated pool.
+		/// The swap fee is deducted from `amount_in` and retained in the
+		/// reserves of the AMM. The vesting settings of the pool apply to the
+		/// received tokens as if they were minted.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `amount_in`: The amount of collateral to swap, including the swap
+		///   fee.
+		/// - `min_out`: The minimum amount of bonded tokens to receive.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::NotGraduated`: If the pool has not graduated.
+		/// - `Error::<T>::InsufficientLiquidity`: If the swap would return
+		///   zero bonded tokens.
+		/// - `Error::<T>::Slippage`: If the returned amount is less than
+		///   `min_out`.
+		/// - `Error::<T>::TooManyVestingSchedules`: If the received tokens vest
+		///   and the caller has reached the maximum number of vesting
+		///   schedules.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::swap_collateral_for_bonded())]
+		pub fn swap_collateral_for_bonded(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			amount_in: CollateralBalanceOf<T>,
+			min_out: FungiblesBalanceOf<T>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let (pool_details, mut amm_pool) = Self::graduated_pool(&pool_id)?;
+			let currency_id = pool_details
+				.bonded_currencies
+				.first()
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			let swap_fee = T::SwapFee::get().mul_ceil(amount_in);
+			let amount_out: FungiblesBalanceOf<T> = amm::get_amount_out(
+				amount_in.saturating_sub(swap_fee),
+				amm_pool.collateral_reserve,
+				amm_pool.bonded_reserve,
+			)?;
+
+			ensure!(!amount_out.is_zero(), Error::<T>::InsufficientLiquidity);
+			ensure!(amount_out >= min_out, Error::<T>::Slippage);
+
+			// The same rules as for minting on the bonding curve apply to the bought tokens.
+			let vesting_settings = Self::applicable_vesting_settings(&pool_id, &pool_details, &who, amount_out);
+
+			T::Collaterals::transfer(
+				pool_details.collateral.clone(),
+				&who,
+				&pool_id.clone().into(),
+				amount_in,
+				Preservation::Preserve,
+			)?;
+
+			Self::mint_bonded(
+				currency_id.clone(),
+				&who,
+				amount_out,
+				pool_details.currencies_settings.transferable,
+			)?;
+
+			if let Some(settings) = vesting_settings {
+				Self::add_vesting_schedule(pool_id.clone(), currency_id.clone(), who.clone(), amount_out, settings)?;
+			}
+
+			amm_pool.collateral_reserve = amm_pool
+				.collateral_reserve
+				.checked_add(&amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			amm_pool.bonded_reserve = amm_pool
+				.bonded_reserve
+				.checked_sub(&amount_out)
+				.ok_or(ArithmeticError::Underflow)?;
+			AmmPools::<T>::insert(&pool_id, amm_pool);
+
+			Self::deposit_event(Event::SwappedCollateralForBonded {
+				id: pool_id,
+				who,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Swaps bonded tokens for collateral in the AMM of a graduated pool.
+		/// The swap fee is deducted from `amount_in` and retained in the
+		/// reserves of the AMM.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `amount_in`: The amount of bonded tokens to swap, including the
+		///   swap fee.
+		/// - `min_out`: The minimum amount of collateral to receive.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::NotGraduated`: If the pool has not graduated.
+		/// - `Error::<T>::InsufficientLiquidity`: If the swap would return
+		///   zero collateral.
+		/// - `Error::<T>::Slippage`: If the returned amount is less than
+		///   `min_out`.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::swap_bonded_for_collateral())]
+		pub fn swap_bonded_for_collateral(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			amount_in: FungiblesBalanceOf<T>,
+			min_out: CollateralBalanceOf<T>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let (pool_details, mut amm_pool) = Self::graduated_pool(&pool_id)?;
+			let currency_id = pool_details
+				.bonded_currencies
+				.first()
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			let swap_fee = T::SwapFee::get().mul_ceil(amount_in);
+			let amount_out: CollateralBalanceOf<T> = amm::get_amount_out(
+				amount_in.saturating_sub(swap_fee),
+				amm_pool.bonded_reserve,
+				amm_pool.collateral_reserve,
+			)?;
+
+			ensure!(!amount_out.is_zero(), Error::<T>::InsufficientLiquidity);
+			ensure!(amount_out >= min_out, Error::<T>::Slippage);
+
+			Self::burn_bonded(
+				currency_id.clone(),
+				&who,
+				amount_in,
+				pool_details.currencies_settings.transferable,
+			)?;
+
+			T::Collaterals::transfer(
+				pool_details.collateral,
+				&pool_id.clone().into(),
+				&who,
+				amount_out,
+				Preservation::Expendable,
+			)?;
+
+			amm_pool.bonded_reserve = amm_pool
+				.bonded_reserve
+				.checked_add(&amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			amm_pool.collateral_reserve = amm_pool
+				.collateral_reserve
+				.checked_sub(&amount_out)
+				.ok_or(ArithmeticError::Underflow)?;
+			AmmPools::<T>::insert(&pool_id, amm_pool);
+
+			Self::deposit_event(Event::SwappedBondedForCollateral {
+				id: pool_id,
+				who,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Adds liquidity to the AMM of a graduated pool. The bonded tokens
+		/// are deposited in proportion to the current reserves of the AMM,
+		/// rounded in favour of the AMM.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `collateral_amount`: The amount of collateral to deposit.
+		/// - `max_bonded`: The maximum amount of bonded tokens to deposit.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::NotGraduated`: If the pool has not graduated.
+		/// - `Error::<T>::Slippage`: If the required bonded tokens exceed
+		///   `max_bonded`.
+		/// - `Error::<T>::InsufficientLiquidity`: If the deposit would not
+		///   issue any liquidity shares.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			collateral_amount: CollateralBalanceOf<T>,
+			max_bonded: FungiblesBalanceOf<T>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let (pool_details, mut amm_pool) = Self::graduated_pool(&pool_id)?;
+			let currency_id = pool_details
+				.bonded_currencies
+				.first()
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			let bonded_amount: FungiblesBalanceOf<T> = amm::mul_div(
+				collateral_amount,
+				amm_pool.bonded_reserve,
+				amm_pool.collateral_reserve,
+				Round::Up,
+			)?;
+			ensure!(bonded_amount <= max_bonded, Error::<T>::Slippage);
+
+			let shares: CollateralBalanceOf<T> = amm::mul_div(
+				collateral_amount,
+				amm_pool.total_shares,
+				amm_pool.collateral_reserve,
+				Round::Down,
+			)?;
+			ensure!(!shares.is_zero(), Error::<T>::InsufficientLiquidity);
+
+			T::Collaterals::transfer(
+				pool_details.collateral,
+				&who,
+				&pool_id.clone().into(),
+				collateral_amount,
+				Preservation::Preserve,
+			)?;
+
+			if !bonded_amount.is_zero() {
+				Self::burn_bonded(
+					currency_id.clone(),
+					&who,
+					bonded_amount,
+					pool_details.currencies_settings.transferable,
+				)?;
+			}
+
+			amm_pool.collateral_reserve = amm_pool
+				.collateral_reserve
+				.checked_add(&collateral_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			amm_pool.bonded_reserve = amm_pool
+				.bonded_reserve
+				.checked_add(&bonded_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			amm_pool.total_shares = amm_pool
+				.total_shares
+				.checked_add(&shares)
+				.ok_or(ArithmeticError::Overflow)?;
+			AmmPools::<T>::insert(&pool_id, amm_pool);
+
+			LiquidityShares::<T>::try_mutate(&pool_id, &who, |owned| -> DispatchResult {
+				*owned = owned.checked_add(&shares).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				id: pool_id,
+				who,
+				collateral_amount,
+				bonded_amount,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Removes liquidity from the AMM of a graduated pool. The caller
+		/// receives their share of both reserves, rounded down.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `shares`: The amount of liquidity shares to redeem.
+		/// - `min_collateral`: The minimum amount of collateral to receive.
+		/// - `min_bonded`: The minimum amount of bonded tokens to receive.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::NotGraduated`: If the pool has not graduated.
+		/// - `Error::<T>::InsufficientShares`: If `shares` is zero or exceeds
+		///   the shares owned by the caller.
+		/// - `Error::<T>::InsufficientLiquidity`: If the shares would not
+		///   return any collateral.
+		/// - `Error::<T>::Slippage`: If either returned amount is less than the
+		///   given minimum.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			shares: CollateralBalanceOf<T>,
+			min_collateral: CollateralBalanceOf<T>,
+			min_bonded: FungiblesBalanceOf<T>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let (pool_details, mut amm_pool) = Self::graduated_pool(&pool_id)?;
+			let currency_id = pool_details
+				.bonded_currencies
+				.first()
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			let owned_shares = LiquidityShares::<T>::get(&pool_id, &who);
+			ensure!(
+				!shares.is_zero() && shares <= owned_shares,
+				Error::<T>::InsufficientShares
+			);
+
+			let collateral_amount: CollateralBalanceOf<T> =
+				amm::mul_div(shares, amm_pool.collateral_reserve, amm_pool.total_shares, Round::Down)?;
+			let bonded_amount: FungiblesBalanceOf<T> =
+				amm::mul_div(shares, amm_pool.bonded_reserve, amm_pool.total_shares, Round::Down)?;
+
+			ensure!(!collateral_amount.is_zero(), Error::<T>::InsufficientLiquidity);
+			ensure!(
+				collateral_amount >= min_collateral && bonded_amount >= min_bonded,
+				Error::<T>::Slippage
+			);
+
+			T::Collaterals::transfer(
+				pool_details.collateral,
+				&pool_id.clone().into(),
+				&who,
+				collateral_amount,
+				Preservation::Expendable,
+			)?;
+
+			if !bonded_amount.is_zero() {
+				Self::mint_bonded(
+					currency_id.clone(),
+					&who,
+					bonded_amount,
+					pool_details.currencies_settings.transferable,
+				)?;
+			}
+
+			amm_pool.collateral_reserve = amm_pool
+				.collateral_reserve
+				.checked_sub(&collateral_amount)
+				.ok_or(ArithmeticError::Underflow)?;
+			amm_pool.bonded_reserve = amm_pool
+				.bonded_reserve
+				.checked_sub(&bonded_amount)
+				.ok_or(ArithmeticError::Underflow)?;
+			amm_pool.total_shares = amm_pool
+				.total_shares
+				.checked_sub(&shares)
+				.ok_or(ArithmeticError::Underflow)?;
+			AmmPools::<T>::insert(&pool_id, amm_pool);
+
+			let remaining_shares = owned_shares.saturating_sub(shares);
+			if remaining_shares.is_zero() {
+				LiquidityShares::<T>::remove(&pool_id, &who);
+			} else {
+				LiquidityShares::<T>::insert(&pool_id, &who, remaining_shares);
+			}
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				id: pool_id,
+				who,
+				collateral_amount,
+				bonded_amount,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Sets or removes the vesting settings of a pool. Bonded tokens minted
+		/// by the owner or the manager of the pool, or in amounts above the
+		/// configured threshold, are frozen on the beneficiary's account until
+		/// they have vested and been claimed. The same applies to tokens
+		/// received by swapping within the pool or, after graduation, by
+		/// buying from the AMM. Changes do not affect tokens minted before.
+		/// The origin account must be a manager of the pool.
+		///
+		/// The threshold applies per operation, so it only deters acquiring a
+		/// large amount at once and does not prevent splitting it into smaller
+		/// operations or across accounts.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, requiring the caller to be a
+		///   manager of the pool.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `settings`: The vesting settings, or `None` to remove them.
+		///   - `cliff`: The number of blocks after minting during which none of
+		///     the minted tokens vest.
+		///   - `duration`: The number of blocks after the cliff over which the
+		///     minted tokens vest linearly.
+		///   - `threshold`: Mints of more than this amount vest regardless of
+		///     the minting account.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NoPermission`: If the caller is not a manager of the
+		///   pool.
+		/// - `Error::<T>::InvalidInput`: If both the cliff and the duration are
+		///   zero.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::set_vesting())]
+		pub fn set_vesting(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			settings: Option<VestingSettingsOf<T>>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+			ensure!(pool_details.is_manager(&who), Error::<T>::NoPermission);
+
+			if let Some(VestingSettings { cliff, duration, .. }) = &settings {
+				ensure!(!cliff.is_zero() || !duration.is_zero(), Error::<T>::InvalidInput);
+			}
+
+			PoolVestingSettings::<T>::set(&pool_id, settings.clone());
+
+			Self::deposit_event(Event::VestingSettingsUpdated { id: pool_id, settings });
+
+			Ok(())
+		}
+
+		/// Releases the bonded tokens of an account which have vested. The
+		/// tokens which have not vested yet remain frozen. This call can be
+		/// made for any account.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `account`: The account holding the vesting tokens.
+		/// - `asset_idx`: The index of the asset in the bonded currencies
+		///   vector.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::IndexOutOfBounds`: If the asset index is out of
+		///   bounds.
+		/// - `Error::<T>::NothingToClaim`: If none of the account's tokens have
+		///   vested since the last claim.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::claim_vested())]
+		pub fn claim_vested(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			account: AccountIdLookupOf<T>,
+			asset_idx: u32,
+		) -> DispatchResult {
+			T::DefaultOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(account)?;
+
+			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			let asset_id = pool_details
+				.bonded_currencies
+				.get(asset_idx.saturated_into::<usize>())
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+
+			let amount = VestingSchedules::<T>::try_mutate_exists(
+				asset_id,
+				&who,
+				|maybe_schedules| -> Result<FungiblesBalanceOf<T>, DispatchError> {
+					let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::NothingToClaim)?;
+
+					let released = schedules
+						.iter_mut()
+						.fold(FungiblesBalanceOf::<T>::zero(), |sum, schedule| {
+							sum.saturating_add(schedule.release(now))
+						});
+					ensure!(!released.is_zero(), Error::<T>::NothingToClaim);
+
+					schedules.retain(|schedule| !schedule.locked.is_zero());
+					if schedules.is_empty() {
+						*maybe_schedules = None;
+					}
+
+					Ok(released)
+				},
+			)?;
+
+			Self::deposit_event(Event::VestedClaimed {
+				id: pool_id,
+				who,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Swaps bonded tokens of one currency for another currency of the
+		/// same pool in a single step. The collateral released by burning
+		/// `amount_in` is charged with the burn fee of the pool and the
+		/// remainder is spent on minting the target currency, so that apart
+		/// from the protocol fee no collateral leaves the pool.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `from_idx`: The index of the currency to burn.
+		/// - `to_idx`: The index of the currency to mint.
+		/// - `amount_in`: The amount of bonded tokens to burn.
+		/// - `min_out`: The minimum amount of bonded tokens to receive.
+		/// - `currency_count`: The currency count in the pool, required for
+		///   weight calculation.
+		///
+		/// # Returns
+		/// - `DispatchResultWithPostInfo`: The result of the dispatch with the
+		///   actual used weights.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::CurrencyCount`: If the number of currencies exceeds
+		///   `currency_count`.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NoPermission`: If the caller is not allowed to burn
+		///   or mint.
+		/// - `Error::<T>::InvalidInput`: If both indices are equal.
+		/// - `Error::<T>::IndexOutOfBounds`: If either index is out of bounds.
+		/// - `Error::<T>::ZeroCollateral`: If burning releases no collateral
+		///   after fees.
+		/// - `Error::<T>::Slippage`: If the returned amount is less than
+		///   `min_out`.
+		#[pallet::call_index(23)]
+		#[pallet::weight({
+			let weight_polynomial = T::WeightInfo::swap_within_pool_polynomial(currency_count.to_owned());
+			let weight_square_root = T::WeightInfo::swap_within_pool_square_root(currency_count.to_owned());
+			let weight_lmsr = T::WeightInfo::swap_within_pool_lmsr(currency_count.to_owned());
+			let weight_piecewise_linear = T::WeightInfo::swap_within_pool_piecewise_linear(currency_count.to_owned());
+			let weight_exponential = T::WeightInfo::swap_within_pool_exponential(currency_count.to_owned());
+			weight_polynomial
+				.max(weight_square_root)
+				.max(weight_lmsr)
+				.max(weight_piecewise_linear)
+				.max(weight_exponential)
+				.saturating_add(T::WeightInfo::update_price_accumulator(currency_count.to_owned()))
+		})]
+		pub fn swap_within_pool(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			from_idx: u32,
+			to_idx: u32,
+			amount_in: FungiblesBalanceOf<T>,
+			min_out: FungiblesBalanceOf<T>,
+			currency_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			let number_of_currencies = Self::get_currencies_number(&pool_details);
+			ensure!(number_of_currencies <= currency_count, Error::<T>::CurrencyCount);
+
+			let BondedCurrenciesSettings {
+				min_operation_balance,
+				transferable,
+				..
+			} = pool_details.currencies_settings;
+
+			ensure!(
+				amount_in >= min_operation_balance.saturated_into(),
+				TokenError::BelowMinimum
+			);
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+			ensure!(
+				pool_details.can_burn(&who) && pool_details.can_mint(&who),
+				Error::<T>::NoPermission
+			);
+
+			let from_idx: usize = from_idx.saturated_into();
+			let to_idx: usize = to_idx.saturated_into();
+
+			let (amount_out, pool_fee, protocol_fee) =
+				Self::calculate_swap_within_pool(&pool_id, &pool_details, from_idx, to_idx, amount_in)?;
+
+			ensure!(
+				amount_out >= min_operation_balance.saturated_into(),
+				TokenError::BelowMinimum
+			);
+			ensure!(amount_out >= min_out, Error::<T>::Slippage);
+
+			// The same rules as for minting apply to the received tokens.
+			let vesting_settings = Self::applicable_vesting_settings(&pool_id, &pool_details, &who, amount_out);
+
+			let from_currency_id = pool_details
+				.bonded_currencies
+				.get(from_idx)
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+			let to_currency_id = pool_details
+				.bonded_currencies
+				.get(to_idx)
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			// The released collateral stays in the pool. Only the protocol fee is
+			// transferred out.
+			if !protocol_fee.is_zero() {
+				T::Collaterals::transfer(
+					pool_details.collateral.clone(),
+					&pool_id.clone().into(),
+					&T::ProtocolFeeDestination::get(),
+					protocol_fee,
+					Preservation::Expendable,
+				)?;
+			}
+			Self::accrue_fees(pool_id.clone(), pool_fee, protocol_fee);
+
+			Self::burn_bonded(from_currency_id.clone(), &who, amount_in, transferable)?;
+			Self::mint_bonded(to_currency_id.clone(), &who, amount_out, transferable)?;
+
+			Self::update_price_accumulator(
+				&pool_id,
+				&pool_details.curve,
+				&pool_details.bonded_currencies,
+				pool_details.currencies_settings.denomination,
+				pool_details.collateral.clone(),
+			);
+
+			if let Some(settings) = vesting_settings {
+				Self::add_vesting_schedule(
+					pool_id.clone(),
+					to_currency_id.clone(),
+					who.clone(),
+					amount_out,
+					settings,
+				)?;
+			}
+
+			Self::deposit_event(Event::SwappedWithinPool {
+				id: pool_id,
+				who,
+				from: from_currency_id.clone(),
+				to: to_currency_id.clone(),
+				amount_in,
+				amount_out,
+			});
+
+			let weight = match pool_details.curve {
+				Curve::Polynomial(_) => T::WeightInfo::swap_within_pool_polynomial(number_of_currencies),
+				Curve::SquareRoot(_) => T::WeightInfo::swap_within_pool_square_root(number_of_currencies),
+				Curve::Lmsr(_) => T::WeightInfo::swap_within_pool_lmsr(number_of_currencies),
+				Curve::PiecewiseLinear(_) => T::WeightInfo::swap_within_pool_piecewise_linear(number_of_currencies),
+				Curve::Exponential(_) => T::WeightInfo::swap_within_pool_exponential(number_of_currencies),
+			}
+			.saturating_add(T::WeightInfo::update_price_accumulator(number_of_currencies));
+
+			Ok(Some(weight).into())
+		}
+
+		/// Sets the resolver of a pool, who can declare the winning currency of
+		/// the pool. Only pools using an LMSR curve with multiple currencies
+		/// can be resolved. The origin account must be the manager of the
+		/// pool. Once set, the resolver cannot be changed, so that holders can
+		/// rely on who declares the outcome. If the resolver fails to do so,
+		/// the pool can still be resolved via `force_resolve`.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, requiring the caller to be the
+		///   manager of the pool.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `resolver`: The resolver of the pool.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NoPermission`: If the caller is not the manager of the
+		///   pool.
+		/// - `Error::<T>::NotResolvable`: If the pool cannot be resolved.
+		/// - `Error::<T>::ResolverAlreadySet`: If the pool already has a
+		///   resolver.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_resolver())]
+		pub fn set_resolver(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			resolver: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+			ensure!(pool_details.is_manager(&who), Error::<T>::NoPermission);
+			ensure!(Self::is_resolvable(&pool_details), Error::<T>::NotResolvable);
+			ensure!(
+				!PoolResolvers::<T>::contains_key(&pool_id),
+				Error::<T>::ResolverAlreadySet
+			);
+
+			let resolver = T::Lookup::lookup(resolver)?;
+
+			PoolResolvers::<T>::insert(&pool_id, resolver.clone());
+
+			Self::deposit_event(Event::ResolverSet { id: pool_id, resolver });
+
+			Ok(())
+		}
+
+		/// Resolves the outcome of a pool by declaring its winning currency.
+		/// Minting and burning are halted, and the pool is moved to refunding
+		/// state, in which holders of the winning currency redeem the
+		/// collateral of the pool via `refund_account`. Holders of any other
+		/// currency receive nothing. The origin must be the resolver of the
+		/// pool.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, requiring the caller to be the
+		///   resolver of the pool.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `winning_currency_idx`: The index of the winning currency.
+		/// - `currency_count`: The currency count in the pool, required for
+		///   weight calculation.
+		///
+		/// # Returns
+		/// - `DispatchResultWithPostInfo`: The result of the dispatch with the
+		///   actual used weights.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::CurrencyCount`: If the number of currencies exceeds
+		///   `currency_count`.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NoPermission`: If the caller is not the resolver of
+		///   the pool.
+		/// - `Error::<T>::NotResolvable`: If the pool cannot be resolved.
+		/// - `Error::<T>::IndexOutOfBounds`: If the currency index is out of
+		///   bounds.
+		/// - `Error::<T>::NothingToRefund`: If there is no collateral or no
+		///   supply of the winning currency.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::resolve(currency_count.to_owned()))]
+		pub fn resolve(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			winning_currency_idx: u32,
+			currency_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = T::ResolveOrigin::ensure_origin(origin)?;
+
+			let actual_currency_count = Self::do_resolve(pool_id, winning_currency_idx, currency_count, Some(&who))?;
+
+			Ok(Some(T::WeightInfo::resolve(actual_currency_count)).into())
+		}
+
+		/// Resolves the outcome of a pool by declaring its winning currency,
+		/// regardless of the resolver of the pool. The origin requires force
+		/// privileges.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, requiring force privileges.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `winning_currency_idx`: The index of the winning currency.
+		/// - `currency_count`: The currency count in the pool, required for
+		///   weight calculation.
+		///
+		/// # Returns
+		/// - `DispatchResultWithPostInfo`: The result of the dispatch with the
+		///   actual used weights.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::CurrencyCount`: If the number of currencies exceeds
+		///   `currency_count`.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NotResolvable`: If the pool cannot be resolved.
+		/// - `Error::<T>::IndexOutOfBounds`: If the currency index is out of
+		///   bounds.
+		/// - `Error::<T>::NothingToRefund`: If there is no collateral or no
+		///   supply of the winning currency.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::force_resolve(currency_count.to_owned()))]
+		pub fn force_resolve(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			winning_currency_idx: u32,
+			currency_count: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let actual_currency_count = Self::do_resolve(pool_id, winning_currency_idx, currency_count, None)?;
+
+			Ok(Some(T::WeightInfo::force_resolve(actual_currency_count)).into())
+		}
+
+		/// Burns a specified amount of bonded tokens from the callers account
+		/// and sends the corresponding collateral to a beneficiary on another
+		/// chain, e.g., the reserve chain of a collateral which was received
+		/// via XCM. The collateral is calculated as in `burn_into` and is
+		/// transferred to the caller before being sent out.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `currency_idx`: The index of the currency in the bonded currencies
+		///   vector.
+		/// - `beneficiary`: The beneficiary of the collateral on the remote
+		///   chain.
+		/// - `amount_to_burn`: The amount of bonded tokens to burn.
+		/// - `min_return`: The minimum amount of collateral to return, after
+		///   deducting fees.
+		/// - `currency_count`: The currency count in the pool, required for
+		///   weight calculation.
+		///
+		/// # Returns
+		/// - `DispatchResultWithPostInfo`: The result of the dispatch with the
+		///   actual used weights.
+		///
+		/// # Errors
+		/// - All errors of `burn_into`.
+		/// - Any error returned by `T::RemoteCollaterals`, e.g., if the
+		///   collateral cannot be sent to the beneficiary.
+		#[pallet::call_index(27)]
+		#[pallet::weight({
+			let weight_polynomial = T::WeightInfo::burn_into_polynomial(currency_count.to_owned());
+			let weight_square_root = T::WeightInfo::burn_into_square_root(currency_count.to_owned());
+			let weight_lmsr = T::WeightInfo::burn_into_lmsr(currency_count.to_owned());
+			let weight_piecewise_linear = T::WeightInfo::burn_into_piecewise_linear(currency_count.to_owned());
+			let weight_exponential = T::WeightInfo::burn_into_exponential(currency_count.to_owned());
+			weight_polynomial
+				.max(weight_square_root)
+				.max(weight_lmsr)
+				.max(weight_piecewise_linear)
+				.max(weight_exponential)
+				.saturating_add(T::WeightInfo::update_price_accumulator(currency_count.to_owned()))
+				.saturating_add(Pallet::<T>::remote_transfer_weight(currency_count.to_owned()))
+		})]
+		pub fn burn_into_remote(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			currency_idx: u32,
+			beneficiary: Box<RemoteBeneficiaryOf<T>>,
+			amount_to_burn: FungiblesBalanceOf<T>,
+			min_return: CollateralBalanceOf<T>,
+			currency_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let collateral = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?.collateral;
+
+			let (collateral_return, weight) = Self::do_burn_into(
+				who.clone(),
+				pool_id.clone(),
+				currency_idx,
+				who.clone(),
+				amount_to_burn,
+				min_return,
+				currency_count,
+			)?;
+
+			let beneficiary = *beneficiary;
+			T::RemoteCollaterals::transfer(&who, collateral.clone(), collateral_return, beneficiary.clone())?;
+
+			Self::deposit_event(Event::CollateralSentRemote {
+				id: pool_id,
+				who,
+				collateral,
+				amount: collateral_return,
+				beneficiary,
+			});
+
+			Ok(Some(weight.saturating_add(Self::remote_transfer_weight(currency_count))).into())
+		}
+
+		/// Sets or clears the metadata of a pool, such as a description, a URI
+		/// and the hash of a logo. A deposit is taken from the owner while the
+		/// pool has metadata. The origin account must be the owner of the
+		/// pool.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, requiring the caller to be the
+		///   owner of the pool.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `metadata`: The new metadata of the pool, or `None` to clear it.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NoPermission`: If the caller is not the owner of the
+		///   pool.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			metadata: Option<PoolMetadataOf<T>>,
+		) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let mut pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+			ensure!(pool_details.is_owner(&who), Error::<T>::NoPermission);
+
+			Self::update_item_deposit(
+				&pool_id,
+				&mut pool_details,
+				Metadata::<T>::contains_key(&pool_id),
+				metadata.is_some(),
+			)?;
+
+			Metadata::<T>::set(&pool_id, metadata.clone());
+
+			Self::deposit_event(Event::MetadataUpdated { id: pool_id, metadata });
+
+			Ok(())
+		}
+
+		/// Links a pool to the identity of its owner, e.g., a DID, or removes
+		/// the link. The owner must control the identity, which must be backed
+		/// by a valid credential. A deposit is taken from the owner while the
+		/// pool is linked. The origin account must be the owner of the pool.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, requiring the caller to be the
+		///   owner of the pool.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `issuer`: The identity of the owner and the credential backing it,
+		///   or `None` to remove the link.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the dispatch.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NoPermission`: If the caller is not the owner of the
+		///   pool.
+		/// - `Error::<T>::IssuerNotVerified`: If the owner does not control the
+		///   identity, or the credential is invalid.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_issuer())]
+		pub fn set_issuer(origin: OriginFor<T>, pool_id: T::PoolId, issuer: Option<PoolIssuerOf<T>>) -> DispatchResult {
+			let who = T::DefaultOrigin::ensure_origin(origin)?;
+
+			let mut pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+			ensure!(pool_details.is_owner(&who), Error::<T>::NoPermission);
+
+			if let Some(PoolIssuer {
+				identifier,
+				credential_id,
+			}) = &issuer
+			{
+				ensure!(
+					T::IssuerVerification::is_verified(&who, &pool_id, identifier, credential_id),
+					Error::<T>::IssuerNotVerified
+				);
+			}
+
+			Self::update_item_deposit(
+				&pool_id,
+				&mut pool_details,
+				PoolIssuers::<T>::contains_key(&pool_id),
+				issuer.is_some(),
+			)?;
+
+			PoolIssuers::<T>::set(&pool_id, issuer.clone());
+
+			Self::deposit_event(Event::IssuerUpdated { id: pool_id, issuer });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		<CurveParameterTypeOf<T> as Fixed>::Bits:
+			Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign + TryFrom<U256> + TryInto<U256>,
+		CollateralBalanceOf<T>: TryFrom<U256> + TryInto<U256>,
+		FungiblesBalanceOf<T>: TryFrom<U256> + TryInto<U256>,
+	{
+		/// Calculates the collateral by the given curve and the normalized
+		/// costs. High is the upper bound of the curve, low is the lower bound.
+		/// The passive supply is the normalized total supply of all bonded
+		/// currencies, excluding the active currency.
+		///
+		/// # Parameters
+		/// - `low`: The lower bound of the curve.
+		/// - `high`: The upper bound of the curve.
+		/// - `passive_supply`: The normalized total supply of all bonded
+		///   currencies, excluding the active currency.
+		/// - `curve`: A reference to the curve used for calculation.
+		/// - `collateral_currency_id`: The ID of the collateral currency.
+		///
+		/// # Returns
+		/// - `Result<CollateralBalanceOf<T>, ArithmeticError>`: The calculated
+		///   collateral or an error.
+		///
+		/// # Errors
+		/// - `ArithmeticError`: If there is an operation overflowing,
+		///   underflowing, or division by zero.
 		fn calculate_collateral(
 			low: CurveParameterTypeOf<T>,
 			high: CurveParameterTypeOf<T>,
@@ -1449,6 +2988,145 @@ pub mod pallet {
 			Ok((active_issuance, normalized_total_issuances))
 		}
 
+		/// Calculates the outcome of swapping bonded tokens of one currency for
+		/// another currency of the same pool. The collateral released by
+		/// burning `amount_in` is charged with the burn fee of the pool, and
+		/// the remainder is spent on minting the target currency against the
+		/// supplies after burning.
+		///
+		/// The curves cannot be inverted in general, so the received amount is
+		/// searched for: it is the largest amount whose minting costs are
+		/// covered by the remaining collateral. Collateral left over due to
+		/// rounding stays in the pool. The search takes at most
+		/// `2 * MAX_SWAP_SEARCH_STEPS` curve evaluations.
+		///
+		/// # Parameters
+		/// - `pool_id`: The identifier of the pool.
+		/// - `pool_details`: The details of the pool.
+		/// - `from_idx`: The index of the currency to burn.
+		/// - `to_idx`: The index of the currency to mint.
+		/// - `amount_in`: The amount of bonded tokens to burn.
+		///
+		/// # Returns
+		/// - `Result<(FungiblesBalanceOf<T>, CollateralBalanceOf<T>,
+		///   CollateralBalanceOf<T>), DispatchError>`: The amount of the target
+		///   currency to mint, the pool fee and the protocol fee.
+		///
+		/// # Errors
+		/// - `Error::<T>::InvalidInput`: If both indices are equal.
+		/// - `Error::<T>::IndexOutOfBounds`: If either index is out of bounds.
+		/// - `Error::<T>::ZeroCollateral`: If burning releases no collateral
+		///   after fees.
+		/// - `ArithmeticError`: If there is an error during the calculation.
+		#[allow(clippy::type_complexity)]
+		pub fn calculate_swap_within_pool(
+			pool_id: &T::PoolId,
+			pool_details: &PoolDetailsOf<T>,
+			from_idx: usize,
+			to_idx: usize,
+			amount_in: FungiblesBalanceOf<T>,
+		) -> Result<(FungiblesBalanceOf<T>, CollateralBalanceOf<T>, CollateralBalanceOf<T>), DispatchError> {
+			ensure!(from_idx != to_idx, Error::<T>::InvalidInput);
+
+			let bonded_currencies = &pool_details.bonded_currencies;
+			let from_currency_id = bonded_currencies.get(from_idx).ok_or(Error::<T>::IndexOutOfBounds)?;
+			ensure!(to_idx < bonded_currencies.len(), Error::<T>::IndexOutOfBounds);
+
+			let denomination = pool_details.currencies_settings.denomination;
+
+			// Collateral released by burning the source currency.
+			let (high, passive) =
+				Self::calculate_normalized_passive_issuance(bonded_currencies, denomination, from_idx, Round::Down)?;
+			let low = high
+				.checked_sub(balance_to_fixed(amount_in, denomination, Round::Down)?)
+				.ok_or(ArithmeticError::Underflow)?;
+			let collateral_released = Self::calculate_collateral(
+				low,
+				high,
+				passive,
+				&pool_details.curve,
+				pool_details.collateral.clone(),
+				Round::Down,
+			)?;
+
+			let (pool_fee, protocol_fee) = Self::calculate_fees(
+				PoolFeeSettings::<T>::get(pool_id).burn,
+				pool_details.collateral.clone(),
+				collateral_released,
+			);
+			let available = collateral_released
+				.saturating_sub(pool_fee)
+				.saturating_sub(protocol_fee);
+			ensure!(!available.is_zero(), Error::<T>::ZeroCollateral);
+
+			// Minting the target currency is priced against the supply of the source
+			// currency after burning. `swap_remove` moves the last currency into the
+			// slot of the target currency.
+			let (active_pre, mut passive) =
+				Self::calculate_normalized_passive_issuance(bonded_currencies, denomination, to_idx, Round::Up)?;
+			let from_position = if from_idx == bonded_currencies.len().saturating_sub(1) {
+				to_idx
+			} else {
+				from_idx
+			};
+			let from_supply = T::Fungibles::total_issuance(from_currency_id.clone())
+				.checked_sub(&amount_in)
+				.ok_or(ArithmeticError::Underflow)?;
+			*passive.get_mut(from_position).ok_or(Error::<T>::IndexOutOfBounds)? =
+				balance_to_fixed(from_supply, denomination, Round::Up)?;
+
+			let is_covered = |amount_out: FungiblesBalanceOf<T>| -> bool {
+				balance_to_fixed(amount_out, denomination, Round::Up)
+					.ok()
+					.and_then(|normalized_amount| active_pre.checked_add(normalized_amount))
+					.and_then(|active_post| {
+						Self::calculate_collateral(
+							active_pre,
+							active_post,
+							passive.clone(),
+							&pool_details.curve,
+							pool_details.collateral.clone(),
+							Round::Up,
+						)
+						.ok()
+					})
+					.is_some_and(|costs| costs <= available)
+			};
+
+			// Find an amount that is not covered by doubling, starting from `amount_in`,
+			// which is not zero since it released collateral.
+			// Both phases are capped at `MAX_SWAP_SEARCH_STEPS` curve evaluations.
+			let mut covered = FungiblesBalanceOf::<T>::zero();
+			let mut uncovered = amount_in;
+			for _ in 0..MAX_SWAP_SEARCH_STEPS {
+				if !is_covered(uncovered) {
+					break;
+				}
+				covered = uncovered;
+				uncovered = uncovered.checked_mul(&2u32.into()).ok_or(ArithmeticError::Overflow)?;
+			}
+
+			// Bisect until the largest covered amount is found.
+			for _ in 0..MAX_SWAP_SEARCH_STEPS {
+				if uncovered.saturating_sub(covered) <= One::one() {
+					break;
+				}
+				let middle = covered.saturating_add(
+					uncovered
+						.saturating_sub(covered)
+						.checked_div(&2u32.into())
+						.ok_or(ArithmeticError::DivisionByZero)?,
+				);
+				if is_covered(middle) {
+					covered = middle;
+				} else {
+					uncovered = middle;
+				}
+			}
+
+			Ok((covered, pool_fee, protocol_fee))
+		}
+
 		/// Starts the refund process for a pool.
 		/// The `maybe_check_manager` parameter is used to check if the caller
 		/// is a manager. Fails if the pool is not live or there is nothing to
@@ -1493,8 +3171,7 @@ pub mod pallet {
 				ensure!(pool_details.is_manager(caller), Error::<T>::NoPermission);
 			}
 
-			let total_collateral_issuance =
-				T::Collaterals::total_balance(pool_details.collateral.clone(), &pool_id.clone().into());
+			let total_collateral_issuance = Self::collateral_reserves(&pool_id, pool_details.collateral.clone());
 			// nothing to distribute
 			ensure!(
 				total_collateral_issuance > CollateralBalanceOf::<T>::zero(),
@@ -1508,14 +3185,235 @@ pub mod pallet {
 			// no token holders to refund
 			ensure!(has_holders, Error::<T>::NothingToRefund);
 
-			// switch pool state to refunding
-			let mut new_pool_details = pool_details;
-			new_pool_details.state.start_refund();
-			Pools::<T>::set(&pool_id, Some(new_pool_details));
+			// switch pool state to refunding
+			let mut new_pool_details = pool_details;
+			new_pool_details.state.start_refund();
+			Pools::<T>::set(&pool_id, Some(new_pool_details));
+
+			Self::deposit_event(Event::RefundingStarted { id: pool_id });
+
+			Ok(n_currencies)
+		}
+
+		/// Resolves the outcome of a pool.
+		/// The `maybe_check_resolver` parameter is used to check if the caller
+		/// is the resolver of the pool. Fails if the pool is not live, cannot
+		/// be resolved, or there is nothing to redeem for the winning currency.
+		///
+		/// # Parameters
+		/// - `pool_id`: The identifier of the pool to resolve.
+		/// - `winning_currency_idx`: The index of the winning currency.
+		/// - `max_currencies`: The currency count in the pool, required for
+		///   weight calculation.
+		/// - `maybe_check_resolver`: An optional parameter to check if the
+		///   caller is the resolver.
+		///
+		/// # Returns
+		/// - `Result<u32, DispatchError>`: The number of currencies in the pool
+		///   or an error.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::CurrencyCount`: If the number of currencies exceeds
+		///   `max_currencies`.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::NoPermission`: If `maybe_check_resolver` is not equal
+		///   to the pool's resolver. This check is skipped if
+		///   `maybe_check_resolver` is set to `None`.
+		/// - `Error::<T>::NotResolvable`: If the pool cannot be resolved.
+		/// - `Error::<T>::IndexOutOfBounds`: If the currency index is out of
+		///   bounds.
+		/// - `Error::<T>::NothingToRefund`: If there is no collateral or no
+		///   supply of the winning currency.
+		fn do_resolve(
+			pool_id: T::PoolId,
+			winning_currency_idx: u32,
+			max_currencies: u32,
+			maybe_check_resolver: Option<&AccountIdOf<T>>,
+		) -> Result<u32, DispatchError> {
+			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			let n_currencies = Self::get_currencies_number(&pool_details);
+
+			ensure!(n_currencies <= max_currencies, Error::<T>::CurrencyCount);
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+
+			if let Some(caller) = maybe_check_resolver {
+				ensure!(
+					PoolResolvers::<T>::get(&pool_id).as_ref() == Some(caller),
+					Error::<T>::NoPermission
+				);
+			}
+
+			ensure!(Self::is_resolvable(&pool_details), Error::<T>::NotResolvable);
+
+			let winning_currency_id = pool_details
+				.bonded_currencies
+				.get(winning_currency_idx.saturated_into::<usize>())
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			let total_collateral_issuance = Self::collateral_reserves(&pool_id, pool_details.collateral.clone());
+			// nothing to distribute
+			ensure!(
+				total_collateral_issuance > CollateralBalanceOf::<T>::zero(),
+				Error::<T>::NothingToRefund
+			);
+			// no holders of the winning currency to redeem the collateral
+			ensure!(
+				T::Fungibles::total_issuance(winning_currency_id.clone()) > FungiblesBalanceOf::<T>::zero(),
+				Error::<T>::NothingToRefund
+			);
+
+			// switch pool state to refunding, which halts minting and burning
+			let mut new_pool_details = pool_details;
+			new_pool_details.state.start_refund();
+			Pools::<T>::set(&pool_id, Some(new_pool_details));
+
+			ResolvedOutcomes::<T>::insert(&pool_id, winning_currency_idx);
+
+			Self::deposit_event(Event::Resolved {
+				id: pool_id,
+				winning_currency_idx,
+			});
+
+			Ok(n_currencies)
+		}
+
+		/// Burns bonded tokens of `who` and transfers the released collateral,
+		/// minus fees, to `beneficiary`. This function is called by both
+		/// `burn_into` and `burn_into_remote`.
+		///
+		/// # Parameters
+		/// - `who`: The account burning the tokens.
+		/// - `pool_id`: The identifier of the pool.
+		/// - `currency_idx`: The index of the currency in the bonded currencies
+		///   vector.
+		/// - `beneficiary`: The account to receive the collateral.
+		/// - `amount_to_burn`: The amount of bonded tokens to burn.
+		/// - `min_return`: The minimum amount of collateral to return, after
+		///   deducting fees.
+		/// - `currency_count`: The currency count in the pool, required for
+		///   weight calculation.
+		///
+		/// # Returns
+		/// - `Result<(CollateralBalanceOf<T>, Weight), DispatchError>`: The
+		///   collateral transferred to the beneficiary and the actual weight of
+		///   the burn, or an error.
+		fn do_burn_into(
+			who: AccountIdOf<T>,
+			pool_id: T::PoolId,
+			currency_idx: u32,
+			beneficiary: AccountIdOf<T>,
+			amount_to_burn: FungiblesBalanceOf<T>,
+			min_return: CollateralBalanceOf<T>,
+			currency_count: u32,
+		) -> Result<(CollateralBalanceOf<T>, Weight), DispatchError> {
+			let pool_details = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolUnknown)?;
+
+			let BondedCurrenciesSettings {
+				min_operation_balance,
+				denomination,
+				transferable,
+				..
+			} = pool_details.currencies_settings;
+
+			ensure!(
+				amount_to_burn >= min_operation_balance.saturated_into(),
+				TokenError::BelowMinimum
+			);
+
+			let number_of_currencies = Self::get_currencies_number(&pool_details);
+			ensure!(number_of_currencies <= currency_count, Error::<T>::CurrencyCount);
+
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+			ensure!(pool_details.can_burn(&who), Error::<T>::NoPermission);
+
+			let bonded_currencies = pool_details.bonded_currencies;
+
+			let currency_idx: usize = currency_idx.saturated_into();
+			let round_kind = Round::Down;
+
+			let target_currency_id = bonded_currencies
+				.get(currency_idx)
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			let (high, passive) = Self::calculate_normalized_passive_issuance(
+				&bonded_currencies,
+				denomination,
+				currency_idx,
+				round_kind,
+			)?;
+
+			let normalized_amount_to_burn = balance_to_fixed(amount_to_burn, denomination, round_kind)?;
+
+			let low = high
+				.checked_sub(normalized_amount_to_burn)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			let collateral_return = Self::calculate_collateral(
+				low,
+				high,
+				passive,
+				&pool_details.curve,
+				pool_details.collateral.clone(),
+				round_kind,
+			)?;
+
+			ensure!(collateral_return > Zero::zero(), Error::<T>::ZeroCollateral);
+
+			let (pool_fee, protocol_fee) = Self::calculate_fees(
+				PoolFeeSettings::<T>::get(&pool_id).burn,
+				pool_details.collateral.clone(),
+				collateral_return,
+			);
+			let net_return = collateral_return.saturating_sub(pool_fee).saturating_sub(protocol_fee);
+			ensure!(net_return > Zero::zero(), Error::<T>::ZeroCollateral);
+			ensure!(net_return >= min_return, Error::<T>::Slippage);
+
+			let pool_account = pool_id.clone().into();
+
+			// Transfer the collateral to the beneficiary. The pool fee is kept in the pool
+			// account until claimed.
+			T::Collaterals::transfer(
+				pool_details.collateral.clone(),
+				&pool_account,
+				&beneficiary,
+				net_return,
+				Preservation::Expendable,
+			)?;
+			if !protocol_fee.is_zero() {
+				T::Collaterals::transfer(
+					pool_details.collateral.clone(),
+					&pool_account,
+					&T::ProtocolFeeDestination::get(),
+					protocol_fee,
+					Preservation::Expendable,
+				)?;
+			}
+			Self::accrue_fees(pool_id.clone(), pool_fee, protocol_fee);
+
+			// Burn the tokens from caller.
+			Self::burn_bonded(target_currency_id.clone(), &who, amount_to_burn, transferable)?;
 
-			Self::deposit_event(Event::RefundingStarted { id: pool_id });
+			Self::update_price_accumulator(
+				&pool_id,
+				&pool_details.curve,
+				&bonded_currencies,
+				denomination,
+				pool_details.collateral,
+			);
 
-			Ok(n_currencies)
+			let weight = match pool_details.curve {
+				Curve::Polynomial(_) => T::WeightInfo::burn_into_polynomial(number_of_currencies),
+				Curve::SquareRoot(_) => T::WeightInfo::burn_into_square_root(number_of_currencies),
+				Curve::Lmsr(_) => T::WeightInfo::burn_into_lmsr(number_of_currencies),
+				Curve::PiecewiseLinear(_) => T::WeightInfo::burn_into_piecewise_linear(number_of_currencies),
+				Curve::Exponential(_) => T::WeightInfo::burn_into_exponential(number_of_currencies),
+			}
+			.saturating_add(T::WeightInfo::update_price_accumulator(number_of_currencies));
+
+			Ok((net_return, weight))
 		}
 
 		/// Starts the destruction process for a pool.
@@ -1538,12 +3436,12 @@ pub mod pallet {
 		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
 		/// - `Error::<T>::CurrencyCount`: If the number of currencies exceeds
 		///   `max_currencies`.
-		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live or
-		///   refunding state.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live,
+		///   refunding or graduated state.
 		/// - `Error::<T>::NoPermission`: If the caller is not the owner or
 		///   manager of the pool.
 		/// - `Error::<T>::LivePool`: If there are holders or collateral to
-		///   distribute.
+		///   distribute, or liquidity provided to the AMM of a graduated pool.
 		fn do_start_destroy_pool(
 			pool_id: T::PoolId,
 			max_currencies: u32,
@@ -1557,10 +3455,18 @@ pub mod pallet {
 			ensure!(n_currencies <= max_currencies, Error::<T>::CurrencyCount);
 
 			ensure!(
-				pool_details.state.is_live() || pool_details.state.is_refunding(),
+				pool_details.state.is_live() || pool_details.state.is_refunding() || pool_details.state.is_graduated(),
 				Error::<T>::PoolNotLive
 			);
 
+			// The liquidity of the AMM of a graduated pool must be removed before its
+			// destruction, even if forced, as the collateral of the liquidity providers is
+			// not refunded.
+			ensure!(
+				LiquidityShares::<T>::iter_key_prefix(&pool_id).next().is_none(),
+				Error::<T>::LivePool
+			);
+
 			if let Some(caller) = maybe_check_manager {
 				ensure!(
 					pool_details.is_owner(caller) || pool_details.is_manager(caller),
@@ -1569,8 +3475,7 @@ pub mod pallet {
 			}
 
 			if !force_skip_refund {
-				let total_collateral_issuance =
-					T::Collaterals::total_balance(pool_details.collateral.clone(), &pool_id.clone().into());
+				let total_collateral_issuance = Self::collateral_reserves(&pool_id, pool_details.collateral.clone());
 
 				if total_collateral_issuance > CollateralBalanceOf::<T>::zero() {
 					let has_holders = pool_details.bonded_currencies.iter().any(|asset_id| {
@@ -1585,6 +3490,9 @@ pub mod pallet {
 			// cloning the currency ids now lets us avoid cloning the entire pool_details
 			let bonded_currencies = pool_details.bonded_currencies.clone();
 
+			// the collateral reserves of the AMM remain in the pool account
+			AmmPools::<T>::remove(&pool_id);
+
 			// switch pool state to destroying
 			let mut new_pool_details = pool_details;
 			new_pool_details.state.start_destroy();
@@ -1605,6 +3513,523 @@ pub mod pallet {
 			Ok(n_currencies)
 		}
 
+		/// Calculates the pool fee and the protocol fee charged on an amount of
+		/// collateral paid for minting or released by burning. Both fees are
+		/// rounded up.
+		///
+		/// The protocol fee is waived if it cannot be deposited to the
+		/// `ProtocolFeeDestination`, e.g., because it is below the minimum
+		/// balance of the collateral currency.
+		///
+		/// # Parameters
+		/// - `pool_fee_rate`: The mint or burn fee of the pool.
+		/// - `collateral_id`: The ID of the collateral currency.
+		/// - `amount`: The amount of collateral the fees are charged on.
+		///
+		/// # Returns
+		/// - `(CollateralBalanceOf<T>, CollateralBalanceOf<T>)`: The pool fee
+		///   and the protocol fee.
+		pub fn calculate_fees(
+			pool_fee_rate: Perbill,
+			collateral_id: CollateralAssetIdOf<T>,
+			amount: CollateralBalanceOf<T>,
+		) -> (CollateralBalanceOf<T>, CollateralBalanceOf<T>) {
+			let pool_fee = pool_fee_rate.mul_ceil(amount);
+			let protocol_fee = T::ProtocolFee::get().mul_ceil(amount);
+
+			if protocol_fee.is_zero() {
+				return (pool_fee, protocol_fee);
+			}
+
+			let deposit_consequence = T::Collaterals::can_deposit(
+				collateral_id,
+				&T::ProtocolFeeDestination::get(),
+				protocol_fee,
+				Provenance::Extant,
+			);
+			if deposit_consequence == DepositConsequence::Success {
+				(pool_fee, protocol_fee)
+			} else {
+				(pool_fee, Zero::zero())
+			}
+		}
+
+		/// Adds the pool fee to the fees accrued by the pool and emits an event
+		/// if any fees were charged.
+		fn accrue_fees(pool_id: T::PoolId, pool_fee: CollateralBalanceOf<T>, protocol_fee: CollateralBalanceOf<T>) {
+			if pool_fee.is_zero() && protocol_fee.is_zero() {
+				return;
+			}
+
+			AccruedFees::<T>::mutate(&pool_id, |accrued| *accrued = accrued.saturating_add(pool_fee));
+
+			Self::deposit_event(Event::FeesCharged {
+				id: pool_id,
+				pool_fee,
+				protocol_fee,
+			});
+		}
+
+		/// Mints bonded tokens into an account. If the bonded currency is not
+		/// transferable, the account is frozen afterwards.
+		///
+		/// # Parameters
+		/// - `currency_id`: The ID of the bonded currency.
+		/// - `who`: The account to receive the minted tokens.
+		/// - `amount`: The amount of bonded tokens to mint.
+		/// - `transferable`: Whether the bonded currency is transferable.
+		fn mint_bonded(
+			currency_id: FungiblesAssetIdOf<T>,
+			who: &T::AccountId,
+			amount: FungiblesBalanceOf<T>,
+			transferable: bool,
+		) -> DispatchResult {
+			T::Fungibles::mint_into(currency_id.clone(), who, amount)?;
+
+			if !transferable {
+				T::Fungibles::freeze(&currency_id, who).map_err(|freeze_error| {
+					log::info!(target: LOG_TARGET, "Failed to freeze account: {:?}", freeze_error);
+					freeze_error.into()
+				})?;
+			}
+
+			Ok(())
+		}
+
+		/// Burns bonded tokens from an account. Any freeze on the account is
+		/// lifted for burning and restored afterwards if the bonded currency is
+		/// not transferable and the account still exists.
+		///
+		/// # Parameters
+		/// - `currency_id`: The ID of the bonded currency.
+		/// - `who`: The account to burn the tokens from.
+		/// - `amount`: The amount of bonded tokens to burn.
+		/// - `transferable`: Whether the bonded currency is transferable.
+		fn burn_bonded(
+			currency_id: FungiblesAssetIdOf<T>,
+			who: &T::AccountId,
+			amount: FungiblesBalanceOf<T>,
+			transferable: bool,
+		) -> DispatchResult {
+			// just remove any locks, if existing.
+			T::Fungibles::thaw(&currency_id, who).map_err(|freeze_error| {
+				log::info!(target: LOG_TARGET, "Failed to thaw account: {:?}", freeze_error);
+				// The thaw operation is failing, if there is no account to thaw. Overwrite the
+				// error with FungiblesError::FundsUnavailable
+				DispatchError::from(TokenError::FundsUnavailable)
+			})?;
+
+			T::Fungibles::burn_from(
+				currency_id.clone(),
+				who,
+				amount,
+				// We don't care, if account gets dusted.
+				Preservation::Expendable,
+				WithdrawalPrecision::Exact,
+				Fortitude::Force,
+			)?;
+
+			let account_exists = T::Fungibles::total_balance(currency_id.clone(), who) > Zero::zero();
+
+			if !transferable && account_exists {
+				// Restore locks.
+				T::Fungibles::freeze(&currency_id, who).map_err(|freeze_error| {
+					log::info!(target: LOG_TARGET, "Failed to freeze account: {:?}", freeze_error);
+					freeze_error.into()
+				})?;
+			}
+
+			Ok(())
+		}
+
+		/// Gets the graduated pool and the reserves of its AMM.
+		///
+		/// # Errors
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::NotGraduated`: If the pool has not graduated.
+		fn graduated_pool(pool_id: &T::PoolId) -> Result<(PoolDetailsOf<T>, AmmPoolOf<T>), DispatchError> {
+			let pool_details = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolUnknown)?;
+			ensure!(pool_details.state.is_graduated(), Error::<T>::NotGraduated);
+
+			let amm_pool = AmmPools::<T>::get(pool_id).ok_or(Error::<T>::NotGraduated)?;
+
+			Ok((pool_details, amm_pool))
+		}
+
+		/// Returns the vesting settings of a pool if they apply to bonded
+		/// tokens acquired by an account, i.e., if the account is the owner or
+		/// a manager of the pool, or the amount is above the threshold.
+		///
+		/// The threshold applies per operation, so it does not prevent
+		/// acquiring a large amount in multiple smaller operations.
+		///
+		/// # Parameters
+		/// - `pool_id`: The identifier of the pool.
+		/// - `pool_details`: The details of the pool.
+		/// - `who`: The account acquiring the tokens.
+		/// - `amount`: The amount of acquired tokens.
+		fn applicable_vesting_settings(
+			pool_id: &T::PoolId,
+			pool_details: &PoolDetailsOf<T>,
+			who: &T::AccountId,
+			amount: FungiblesBalanceOf<T>,
+		) -> Option<VestingSettingsOf<T>> {
+			PoolVestingSettings::<T>::get(pool_id).filter(|settings| {
+				pool_details.is_owner(who)
+					|| pool_details.is_manager(who)
+					|| settings.threshold.is_some_and(|threshold| amount > threshold)
+			})
+		}
+
+		/// Freezes minted bonded tokens on an account until they vest
+		/// according to the given settings.
+		///
+		/// # Parameters
+		/// - `pool_id`: The identifier of the pool.
+		/// - `currency_id`: The ID of the bonded currency.
+		/// - `who`: The account holding the minted tokens.
+		/// - `amount`: The amount of minted tokens.
+		/// - `settings`: The vesting settings of the pool.
+		///
+		/// # Errors
+		/// - `Error::<T>::TooManyVestingSchedules`: If the account has reached
+		///   the maximum number of vesting schedules for the currency.
+		fn add_vesting_schedule(
+			pool_id: T::PoolId,
+			currency_id: FungiblesAssetIdOf<T>,
+			who: T::AccountId,
+			amount: FungiblesBalanceOf<T>,
+			settings: VestingSettingsOf<T>,
+		) -> DispatchResult {
+			let schedule = VestingScheduleOf::<T> {
+				locked: amount,
+				start: frame_system::Pallet::<T>::block_number().saturating_add(settings.cliff),
+				duration: settings.duration,
+			};
+
+			VestingSchedules::<T>::try_mutate(currency_id, &who, |schedules| {
+				schedules
+					.try_push(schedule.clone())
+					.map_err(|_| Error::<T>::TooManyVestingSchedules)
+			})?;
+
+			Self::deposit_event(Event::VestingScheduleAdded {
+				id: pool_id,
+				who,
+				schedule,
+			});
+
+			Ok(())
+		}
+
+		/// Gets the collateral held by the pool account which backs the bonded
+		/// currencies, i.e., excluding the fees accrued by the pool.
+		///
+		/// # Parameters
+		/// - `pool_id`: The identifier of the pool.
+		/// - `collateral_id`: The ID of the collateral currency.
+		///
+		/// # Returns
+		/// - `CollateralBalanceOf<T>`: The collateral reserves of the pool.
+		pub fn collateral_reserves(
+			pool_id: &T::PoolId,
+			collateral_id: CollateralAssetIdOf<T>,
+		) -> CollateralBalanceOf<T> {
+			T::Collaterals::total_balance(collateral_id, &pool_id.clone().into())
+				.saturating_sub(AccruedFees::<T>::get(pool_id))
+		}
+
+		/// Checks if the outcome of a pool can be resolved, which requires an
+		/// LMSR curve with multiple currencies.
+		pub(crate) fn is_resolvable(pool_details: &PoolDetailsOf<T>) -> bool {
+			matches!(pool_details.curve, Curve::Lmsr(_)) && pool_details.bonded_currencies.len() > 1
+		}
+
+		/// Calculates the spot price of each bonded currency of a pool, which
+		/// is the collateral required to mint one whole coin at the current
+		/// supplies, excluding fees.
+		///
+		/// # Parameters
+		/// - `curve`: The bonding curve of the pool.
+		/// - `bonded_currencies`: The bonded currencies of the pool.
+		/// - `denomination`: The denomination of the bonded currencies.
+		/// - `collateral_id`: The collateral currency of the pool.
+		///
+		/// # Returns
+		/// - `Vec<Option<CollateralBalanceOf<T>>>`: The spot price of each
+		///   currency, or `None` if it cannot be calculated.
+		pub fn calculate_spot_prices(
+			curve: &Curve<CurveParameterTypeOf<T>>,
+			bonded_currencies: &[FungiblesAssetIdOf<T>],
+			denomination: u8,
+			collateral_id: CollateralAssetIdOf<T>,
+		) -> Vec<Option<CollateralBalanceOf<T>>> {
+			// One whole coin is represented by 1 in the normalized domain.
+			let Some(one_coin) = CurveParameterTypeOf::<T>::checked_from_num(1u8) else {
+				return bonded_currencies.iter().map(|_| None).collect();
+			};
+
+			(0..bonded_currencies.len())
+				.map(|currency_idx| {
+					let (active_issuance, passive_issuance) = Self::calculate_normalized_passive_issuance(
+						bonded_currencies,
+						denomination,
+						currency_idx,
+						Round::Down,
+					)
+					.ok()?;
+					let high = active_issuance.checked_add(one_coin)?;
+					Self::calculate_collateral(
+						active_issuance,
+						high,
+						passive_issuance,
+						curve,
+						collateral_id.clone(),
+						Round::Down,
+					)
+					.ok()
+				})
+				.collect()
+		}
+
+		/// Calculates the supply of the bonded currency which is paired with
+		/// the collateral reserves of a graduating pool in its AMM, i.e., the
+		/// amount of bonded tokens the reserves buy at the spot price of the
+		/// bonding curve.
+		///
+		/// # Parameters
+		/// - `pool_details`: The details of the graduating pool.
+		/// - `collateral_reserve`: The collateral reserves seeding the AMM.
+		///
+		/// # Returns
+		/// - `Result<FungiblesBalanceOf<T>, DispatchError>`: The bonded
+		///   reserve of the AMM or an error.
+		///
+		/// # Errors
+		/// - `Error::<T>::ZeroCollateral`: If the spot price is zero or cannot
+		///   be calculated.
+		/// - `Error::<T>::InsufficientLiquidity`: If the bonded reserve would be
+		///   zero.
+		/// - `ArithmeticError::Overflow`: If the bonded reserve overflows.
+		fn calculate_graduation_bonded_reserve(
+			pool_details: &PoolDetailsOf<T>,
+			collateral_reserve: CollateralBalanceOf<T>,
+		) -> Result<FungiblesBalanceOf<T>, DispatchError> {
+			let denomination = pool_details.currencies_settings.denomination;
+			let spot_price = Self::calculate_spot_prices(
+				&pool_details.curve,
+				&pool_details.bonded_currencies,
+				denomination,
+				pool_details.collateral.clone(),
+			)
+			.first()
+			.cloned()
+			.flatten()
+			.filter(|price| !price.is_zero())
+			.ok_or(Error::<T>::ZeroCollateral)?;
+
+			// The spot price is the collateral for one whole coin.
+			let bonded_reserve: FungiblesBalanceOf<T> = U256::from(10u8)
+				.checked_pow(denomination.into())
+				.and_then(|one_coin| one_coin.checked_mul(collateral_reserve.into()))
+				.and_then(|reserve| reserve.checked_div(spot_price.into()))
+				.and_then(|reserve| reserve.checked_into())
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(!bonded_reserve.is_zero(), Error::<T>::InsufficientLiquidity);
+
+			Ok(bonded_reserve)
+		}
+
+		/// Accumulates the spot prices of the currencies of a pool since its
+		/// last update and records an observation of the cumulative prices,
+		/// if none has been recorded in the current observation period. The
+		/// spot prices are then recalculated from the current supplies. Must
+		/// be called whenever the supply of a currency of the pool changes.
+		///
+		/// Spot prices which cannot be calculated keep their previous value,
+		/// so that the price oracle never prevents trading. Each such price
+		/// is reported with a `SpotPriceCarriedForward` event.
+		///
+		/// # Parameters
+		/// - `pool_id`: The identifier of the pool.
+		/// - `curve`: The bonding curve of the pool.
+		/// - `bonded_currencies`: The bonded currencies of the pool.
+		/// - `denomination`: The denomination of the bonded currencies.
+		/// - `collateral_id`: The collateral currency of the pool.
+		pub(crate) fn update_price_accumulator(
+			pool_id: &T::PoolId,
+			curve: &Curve<CurveParameterTypeOf<T>>,
+			bonded_currencies: &[FungiblesAssetIdOf<T>],
+			denomination: u8,
+			collateral_id: CollateralAssetIdOf<T>,
+		) {
+			let now = frame_system::Pallet::<T>::block_number();
+			let previous = PriceAccumulators::<T>::get(pool_id);
+
+			let cumulative_prices = previous.as_ref().map_or_else(
+				|| BoundedVec::truncate_from(bonded_currencies.iter().map(|_| U256::zero()).collect()),
+				|accumulator| Self::cumulative_prices_at(accumulator, now),
+			);
+
+			let period = T::PriceObservationPeriod::get().max(One::one());
+			let current_epoch = now.checked_div(&period).unwrap_or_else(Zero::zero);
+			let slot = Self::observation_slot(current_epoch);
+			let is_observed = PriceObservations::<T>::get(pool_id, slot).is_some_and(|observation| {
+				observation.block.checked_div(&period).unwrap_or_else(Zero::zero) == current_epoch
+			});
+			if !is_observed {
+				PriceObservations::<T>::insert(
+					pool_id,
+					slot,
+					PriceObservationOf::<T> {
+						block: now,
+						cumulative_prices: cumulative_prices.clone(),
+					},
+				);
+			}
+
+			let spot_prices = Self::calculate_spot_prices(curve, bonded_currencies, denomination, collateral_id)
+				.into_iter()
+				.enumerate()
+				.map(|(idx, price)| {
+					price.unwrap_or_else(|| {
+						let spot_price = previous
+							.as_ref()
+							.and_then(|accumulator| accumulator.spot_prices.get(idx).copied())
+							.unwrap_or_else(Zero::zero);
+						Self::deposit_event(Event::SpotPriceCarriedForward {
+							id: pool_id.clone(),
+							currency_idx: idx.saturated_into(),
+							spot_price,
+						});
+						spot_price
+					})
+				})
+				.collect();
+
+			PriceAccumulators::<T>::insert(
+				pool_id,
+				PriceAccumulatorOf::<T> {
+					last_update: now,
+					spot_prices: BoundedVec::truncate_from(spot_prices),
+					cumulative_prices,
+				},
+			);
+		}
+
+		/// The weight of removing the price accumulator and all price
+		/// observations of a pool when it is destroyed, which is not part of
+		/// the `finish_destroy` benchmark yet.
+		pub(crate) fn remove_price_history_weight() -> frame_support::weights::Weight {
+			T::DbWeight::get().writes(T::MaxPriceObservations::get().saturating_add(1).into())
+		}
+
+		/// The weight of sending collateral to another chain, which is
+		/// benchmarked on top of burning from a polynomial pool.
+		pub(crate) fn remote_transfer_weight(currency_count: u32) -> frame_support::weights::Weight {
+			T::WeightInfo::burn_into_remote(currency_count)
+				.saturating_sub(T::WeightInfo::burn_into_polynomial(currency_count))
+		}
+
+		/// Calculates the cumulative prices of an accumulator at block `now`,
+		/// assuming the spot prices did not change since its last update.
+		pub(crate) fn cumulative_prices_at(
+			accumulator: &PriceAccumulatorOf<T>,
+			now: BlockNumberFor<T>,
+		) -> CumulativePricesOf<T> {
+			let elapsed = U256::from(now.saturating_sub(accumulator.last_update).saturated_into::<u128>());
+			let cumulative_prices = accumulator
+				.cumulative_prices
+				.iter()
+				.zip(accumulator.spot_prices.iter())
+				.map(|(cumulative, spot)| {
+					cumulative.saturating_add(U256::from(spot.saturated_into::<u128>()).saturating_mul(elapsed))
+				})
+				.collect();
+			BoundedVec::truncate_from(cumulative_prices)
+		}
+
+		/// Returns the slot of the ring buffer of price observations for an
+		/// observation period.
+		pub(crate) fn observation_slot(epoch: BlockNumberFor<T>) -> u32 {
+			epoch
+				.saturated_into::<u64>()
+				.checked_rem(T::MaxPriceObservations::get().max(1).into())
+				.unwrap_or_default()
+				.saturated_into()
+		}
+
+		/// Calculates the time-weighted average price of a bonded currency of
+		/// a pool, denominated in the smallest unit of the collateral
+		/// currency per whole coin. The average is taken from the latest
+		/// price observation which is at least `window` blocks old until now.
+		/// Hence, the actual window may exceed the requested window by up to
+		/// `PriceObservationPeriod` blocks, or more if the pool has not been
+		/// traded for some time.
+		///
+		/// # Parameters
+		/// - `pool_id`: The identifier of the pool.
+		/// - `currency_idx`: The index of the currency in the bonded currencies
+		///   vector.
+		/// - `window`: The minimum number of blocks to average over.
+		///
+		/// # Returns
+		/// - `Result<CollateralBalanceOf<T>, DispatchError>`: The
+		///   time-weighted average price or an error.
+		///
+		/// # Errors
+		/// - `Error::<T>::InvalidInput`: If `window` is zero.
+		/// - `Error::<T>::PoolUnknown`: If the pool does not exist.
+		/// - `Error::<T>::PoolNotLive`: If the pool is not in a live state.
+		/// - `Error::<T>::IndexOutOfBounds`: If the currency index is out of
+		///   bounds.
+		/// - `Error::<T>::InsufficientPriceHistory`: If no price observation is
+		///   old enough to cover the window.
+		pub fn calculate_twap(
+			pool_id: &T::PoolId,
+			currency_idx: usize,
+			window: BlockNumberFor<T>,
+		) -> Result<CollateralBalanceOf<T>, DispatchError> {
+			ensure!(!window.is_zero(), Error::<T>::InvalidInput);
+
+			let pool_details = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolUnknown)?;
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+			ensure!(
+				currency_idx < pool_details.bonded_currencies.len(),
+				Error::<T>::IndexOutOfBounds
+			);
+
+			let accumulator = PriceAccumulators::<T>::get(pool_id).ok_or(Error::<T>::InsufficientPriceHistory)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let start = now.checked_sub(&window).ok_or(Error::<T>::InsufficientPriceHistory)?;
+
+			let observation = PriceObservations::<T>::iter_prefix_values(pool_id)
+				.filter(|observation| observation.block <= start)
+				.max_by_key(|observation| observation.block)
+				.ok_or(Error::<T>::InsufficientPriceHistory)?;
+
+			let cumulative_now = Self::cumulative_prices_at(&accumulator, now);
+			let cumulative_end = cumulative_now.get(currency_idx).ok_or(Error::<T>::IndexOutOfBounds)?;
+			let cumulative_start = observation
+				.cumulative_prices
+				.get(currency_idx)
+				.ok_or(Error::<T>::IndexOutOfBounds)?;
+
+			let elapsed = U256::from(now.saturating_sub(observation.block).saturated_into::<u128>());
+
+			let twap: CollateralBalanceOf<T> = cumulative_end
+				.saturating_sub(*cumulative_start)
+				.checked_div(elapsed)
+				// `elapsed` is at least `window`, which is not zero.
+				.ok_or(ArithmeticError::DivisionByZero)?
+				.checked_into()
+				// The average cannot exceed the largest spot price.
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(twap)
+		}
+
 		/// Gets the number of bonded currencies in a pool.
 		///
 		/// # Parameters
@@ -1634,6 +4059,58 @@ pub mod pallet {
 				.saturating_add(T::DepositPerCurrency::get().saturating_mul(n_currencies.saturated_into()))
 		}
 
+		/// Holds or releases the deposit for an optional item of a pool, e.g.,
+		/// its metadata, when the item is added or removed. The deposit is
+		/// added to the deposit of the pool, so that it is released when the
+		/// pool is destroyed.
+		///
+		/// # Parameters
+		/// - `pool_id`: The identifier of the pool.
+		/// - `pool_details`: The details of the pool, which are updated in
+		///   storage if the deposit changes.
+		/// - `existed`: Whether the item was stored before.
+		/// - `exists`: Whether the item is stored after the update.
+		///
+		/// # Returns
+		/// - `DispatchResult`: The result of the operation.
+		fn update_item_deposit(
+			pool_id: &T::PoolId,
+			pool_details: &mut PoolDetailsOf<T>,
+			existed: bool,
+			exists: bool,
+		) -> DispatchResult {
+			let deposit = T::MetadataDeposit::get();
+
+			match (existed, exists) {
+				(false, true) => {
+					let hold_reason = Self::calculate_hold_reason(pool_id)?;
+					T::DepositCurrency::hold(&hold_reason, &pool_details.owner, deposit)?;
+					pool_details.deposit = pool_details
+						.deposit
+						.checked_add(&deposit)
+						.ok_or(ArithmeticError::Overflow)?;
+				}
+				(true, false) => {
+					let hold_reason = Self::calculate_hold_reason(pool_id)?;
+					T::DepositCurrency::release(
+						&hold_reason,
+						&pool_details.owner,
+						deposit,
+						WithdrawalPrecision::Exact,
+					)?;
+					pool_details.deposit = pool_details
+						.deposit
+						.checked_sub(&deposit)
+						.ok_or(ArithmeticError::Underflow)?;
+				}
+				_ => return Ok(()),
+			}
+
+			Pools::<T>::insert(pool_id, pool_details.clone());
+
+			Ok(())
+		}
+
 		/// Calculates the hold reason for a pool.
 		pub(crate) fn calculate_hold_reason(pool_id: &T::PoolId) -> Result<T::RuntimeHoldReason, Error<T>> {
 			let hold_reason = T::HoldReason::try_from(pool_id.to_owned()).map_err(|_| {
@@ -1644,4 +4121,52 @@ pub mod pallet {
 			Ok(T::RuntimeHoldReason::from(hold_reason))
 		}
 	}
+
+	/// Freezes the bonded tokens which have not been released from vesting.
+	/// Must be configured as the `Freezer` of the assets pallet instance
+	/// managing the bonded currencies.
+	impl<T: Config> FrozenBalance<FungiblesAssetIdOf<T>, T::AccountId, FungiblesBalanceOf<T>> for Pallet<T> {
+		fn frozen_balance(asset: FungiblesAssetIdOf<T>, who: &T::AccountId) -> Option<FungiblesBalanceOf<T>> {
+			let frozen = VestingSchedules::<T>::get(asset, who)
+				.iter()
+				.fold(FungiblesBalanceOf::<T>::zero(), |sum, schedule| {
+					sum.saturating_add(schedule.locked)
+				});
+
+			(!frozen.is_zero()).then_some(frozen)
+		}
+
+		fn died(asset: FungiblesAssetIdOf<T>, who: &T::AccountId) {
+			VestingSchedules::<T>::remove(asset, who);
+		}
+	}
+
+	impl<T: Config> BondedPriceOracle<T::PoolId, CollateralBalanceOf<T>, BlockNumberFor<T>> for Pallet<T>
+	where
+		<CurveParameterTypeOf<T> as Fixed>::Bits:
+			Copy + ToFixed + AddAssign + BitOrAssign + ShlAssign + TryFrom<U256> + TryInto<U256>,
+		CollateralBalanceOf<T>: TryFrom<U256> + TryInto<U256>,
+		FungiblesBalanceOf<T>: TryFrom<U256> + TryInto<U256>,
+	{
+		fn spot_price(pool_id: &T::PoolId, currency_idx: u32) -> Result<CollateralBalanceOf<T>, DispatchError> {
+			let pool_details = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolUnknown)?;
+			ensure!(pool_details.state.is_live(), Error::<T>::PoolNotLive);
+
+			let accumulator = PriceAccumulators::<T>::get(pool_id).ok_or(Error::<T>::InsufficientPriceHistory)?;
+
+			accumulator
+				.spot_prices
+				.get(currency_idx.saturated_into::<usize>())
+				.copied()
+				.ok_or_else(|| Error::<T>::IndexOutOfBounds.into())
+		}
+
+		fn twap(
+			pool_id: &T::PoolId,
+			currency_idx: u32,
+			window: BlockNumberFor<T>,
+		) -> Result<CollateralBalanceOf<T>, DispatchError> {
+			Self::calculate_twap(pool_id, currency_idx.saturated_into(), window)
+		}
+	}
 }
@@ -20,6 +20,7 @@ use parity_scale_codec::Codec;
 use substrate_fixed::traits::{FixedSigned, FixedUnsigned};
 
 use crate::curves::{
+	lmsr::LMSRParameters,
 	polynomial::{PolynomialParameters, PolynomialParametersInput},
 	Curve, CurveInput,
 };
@@ -54,7 +55,10 @@ pub mod runtime {
 	use frame_support::{
 		pallet_prelude::*,
 		parameter_types, storage_alias,
-		traits::{fungible::hold::Mutate as MutateHold, ConstU128, ConstU32, PalletInfoAccess, VariantCount},
+		traits::{
+			fungible::hold::Mutate as MutateHold, fungibles::Mutate as MutateFungibles, tokens::Preservation,
+			ConstU128, ConstU32, PalletInfoAccess, VariantCount,
+		},
 		weights::constants::RocksDbWeight,
 	};
 	use frame_system::{EnsureRoot, EnsureSigned};
@@ -62,15 +66,16 @@ pub mod runtime {
 	use sp_core::U256;
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
-		AccountId32, ArithmeticError, BoundedVec, BuildStorage, DispatchError, MultiSignature, Permill,
+		AccountId32, ArithmeticError, BoundedVec, BuildStorage, DispatchError, MultiSignature, Perbill, Permill,
 	};
 	use substrate_fixed::types::{I75F53, U75F53};
 
 	use crate::{
 		self as pallet_bonded_coins,
-		traits::NextAssetIds,
-		types::{BondedCurrenciesSettings, Locks, PoolStatus},
-		AccountIdOf, Config, DepositBalanceOf, FungiblesAssetIdOf, FungiblesBalanceOf, PoolDetailsOf,
+		traits::{NextAssetIds, RemoteCollateralTransfer, VerifyIssuer},
+		types::{BondedCurrenciesSettings, Locks, PoolFees, PoolStatus},
+		AccountIdOf, AmmPoolOf, Config, DepositBalanceOf, FungiblesAssetIdOf, FungiblesBalanceOf, PoolDetailsOf,
+		VestingScheduleOf, VestingSettingsOf,
 	};
 
 	pub type Hash = sp_core::H256;
@@ -84,8 +89,12 @@ pub mod runtime {
 	pub(crate) const ACCOUNT_00: AccountId = AccountId::new([0u8; 32]);
 	pub(crate) const ACCOUNT_01: AccountId = AccountId::new([1u8; 32]);
 	pub(crate) const ACCOUNT_99: AccountId = AccountId::new([99u8; 32]);
+	pub(crate) const PROTOCOL_FEE_ACCOUNT: AccountId = AccountId::new([98u8; 32]);
+	pub(crate) const REMOTE_RESERVE_ACCOUNT: AccountId = AccountId::new([97u8; 32]);
 	// Only used internally for setting up the test instance.
 	const ACCOUNT_100: AccountId = AccountId::new([100u8; 32]);
+	// credentials
+	pub(crate) const VALID_CREDENTIAL_ID: Hash = Hash::repeat_byte(1);
 	// assets
 	pub(crate) const DEFAULT_BONDED_CURRENCY_ID: AssetId = 1;
 	pub(crate) const DEFAULT_COLLATERAL_CURRENCY_ID: AssetId = 0;
@@ -132,6 +141,27 @@ pub mod runtime {
 		}
 	}
 
+	pub(crate) fn generate_amm_pool(
+		collateral_reserve: Balance,
+		bonded_reserve: Balance,
+		total_shares: Balance,
+	) -> AmmPoolOf<Test> {
+		AmmPoolOf::<Test> {
+			collateral_reserve,
+			bonded_reserve,
+			total_shares,
+		}
+	}
+
+	/// The bonded currencies of a pool with two outcomes.
+	pub(crate) fn two_bonded_currencies() -> Vec<AssetId> {
+		vec![DEFAULT_BONDED_CURRENCY_ID, DEFAULT_BONDED_CURRENCY_ID + 1]
+	}
+
+	pub(crate) fn get_lmsr_bonding_curve(m: u128) -> Curve<Float> {
+		Curve::Lmsr(LMSRParameters { m: Float::from_num(m) })
+	}
+
 	pub(crate) fn events() -> Vec<crate::Event<Test>> {
 		System::events()
 			.into_iter()
@@ -194,6 +224,43 @@ pub mod runtime {
 		}
 	}
 
+	/// Sends collateral to other chains by moving it to the account of the
+	/// reserve, which mimics a reserve transfer.
+	pub struct ReserveTransferMock;
+
+	impl RemoteCollateralTransfer<AccountId, AssetId, Balance> for ReserveTransferMock {
+		type Beneficiary = AccountId;
+
+		fn transfer(who: &AccountId, asset_id: AssetId, amount: Balance, _beneficiary: AccountId) -> DispatchResult {
+			<Assets as MutateFungibles<AccountId>>::transfer(
+				asset_id,
+				who,
+				&REMOTE_RESERVE_ACCOUNT,
+				amount,
+				Preservation::Expendable,
+			)
+			.map(|_| ())
+		}
+	}
+
+	/// Verifies issuers whose identifier is the account itself and whose
+	/// credential is `VALID_CREDENTIAL_ID`.
+	pub struct IssuerVerificationMock;
+
+	impl VerifyIssuer<AccountId, AccountId> for IssuerVerificationMock {
+		type Identifier = AccountId;
+		type CredentialId = Hash;
+
+		fn is_verified(who: &AccountId, _pool_id: &AccountId, identifier: &AccountId, credential_id: &Hash) -> bool {
+			who == identifier && credential_id == &VALID_CREDENTIAL_ID
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn create_verified_issuer(who: &AccountId, _pool_id: &AccountId) -> (AccountId, Hash) {
+			(who.clone(), VALID_CREDENTIAL_ID)
+		}
+	}
+
 	/// Store freezes for the assets pallet.
 	#[storage_alias]
 	pub type Freezes<Assets: PalletInfoAccess> = StorageDoubleMap<
@@ -209,10 +276,14 @@ pub mod runtime {
 	pub struct FreezesHook;
 
 	impl FrozenBalance<AssetId, AccountId, Balance> for FreezesHook {
-		fn died(_asset: AssetId, _who: &AccountId) {}
+		fn died(asset: AssetId, who: &AccountId) {
+			<BondingPallet as FrozenBalance<AssetId, AccountId, Balance>>::died(asset, who)
+		}
 
 		fn frozen_balance(asset: AssetId, who: &AccountId) -> Option<Balance> {
+			// Freezes set up by tests are combined with the freezes of vesting tokens.
 			Freezes::<Assets>::get(asset, who)
+				.max(<BondingPallet as FrozenBalance<AssetId, AccountId, Balance>>::frozen_balance(asset, who))
 		}
 	}
 
@@ -326,6 +397,16 @@ pub mod runtime {
 		pub const MaxCurrenciesPerPool: u32 = 50;
 		pub const CollateralAssetId: u32 = u32::MAX;
 		pub const MaxDenomination: u8 = 15;
+		pub const MaxPoolFee: Perbill = Perbill::from_percent(10);
+		pub const ProtocolFeeDestination: AccountId = PROTOCOL_FEE_ACCOUNT;
+		pub storage ProtocolFee: Perbill = Perbill::zero();
+		pub storage SwapFee: Perbill = Perbill::zero();
+		pub const MaxVestingSchedules: u32 = 10;
+		pub const MaxPriceObservations: u32 = 10;
+		pub const PriceObservationPeriod: u64 = 10;
+		pub const MaxDescriptionLength: u32 = 256;
+		pub const MaxUriLength: u32 = 128;
+		pub const MetadataDeposit: Balance = 1_000;
 	}
 
 	impl From<AccountId32> for TestRuntimeHoldReason {
@@ -352,6 +433,18 @@ pub mod runtime {
 
 			Balances::set_balance(who, amount);
 		}
+
+		fn calculate_remote_collateral_asset_id(seed: u32) -> crate::CollateralAssetIdOf<Test> {
+			seed
+		}
+
+		fn prepare_remote_transfer(seed: u32) -> crate::RemoteBeneficiaryOf<Test> {
+			use frame_support::traits::fungible::Mutate;
+
+			// The reserve account receives the collateral and must exist.
+			Balances::set_balance(&REMOTE_RESERVE_ACCOUNT, ExistentialDeposit::get());
+			frame_benchmarking::account("remote_beneficiary", seed, 0)
+		}
 	}
 
 	impl pallet_bonded_coins::Config for Test {
@@ -365,14 +458,27 @@ pub mod runtime {
 		type ForceOrigin = EnsureRoot<AccountId>;
 		type Fungibles = Assets;
 		type HoldReason = Self::PoolId;
+		type IssuerVerification = IssuerVerificationMock;
 		type MaxCurrenciesPerPool = MaxCurrenciesPerPool;
 		type MaxDenomination = MaxDenomination;
+		type MaxDescriptionLength = MaxDescriptionLength;
+		type MaxPoolFee = MaxPoolFee;
+		type MaxPriceObservations = MaxPriceObservations;
 		type MaxStringInputLength = StringLimit;
+		type MaxUriLength = MaxUriLength;
+		type MaxVestingSchedules = MaxVestingSchedules;
+		type MetadataDeposit = MetadataDeposit;
 		type NextAssetIds = NextAssetIdGenerator;
 		type PoolCreateOrigin = EnsureSigned<AccountId>;
 		type PoolId = AccountId;
+		type PriceObservationPeriod = PriceObservationPeriod;
+		type ProtocolFee = ProtocolFee;
+		type ProtocolFeeDestination = ProtocolFeeDestination;
+		type RemoteCollaterals = ReserveTransferMock;
+		type ResolveOrigin = EnsureSigned<AccountId>;
 		type RuntimeEvent = RuntimeEvent;
 		type RuntimeHoldReason = TestRuntimeHoldReason;
+		type SwapFee = SwapFee;
 		type WeightInfo = ();
 
 		#[cfg(feature = "runtime-benchmarks")]
@@ -387,6 +493,14 @@ pub mod runtime {
 		pools: Vec<(AccountId, PoolDetailsOf<Test>)>,
 		collaterals: Vec<AssetId>,
 		freezes: Vec<(AssetId, AccountId, Balance)>,
+		//  pool_id, fees
+		pool_fees: Vec<(AccountId, PoolFees)>,
+		//  pool_id, AmmPool
+		amm_pools: Vec<(AccountId, AmmPoolOf<Test>)>,
+		//  pool_id, VestingSettings
+		vesting_settings: Vec<(AccountId, VestingSettingsOf<Test>)>,
+		//  asset_id, account, VestingSchedules
+		vesting_schedules: Vec<(AssetId, AccountId, Vec<VestingScheduleOf<Test>>)>,
 	}
 
 	impl ExtBuilder {
@@ -415,6 +529,32 @@ pub mod runtime {
 			self
 		}
 
+		pub(crate) fn with_pool_fees(mut self, pool_fees: Vec<(AccountId, PoolFees)>) -> Self {
+			self.pool_fees = pool_fees;
+			self
+		}
+
+		pub(crate) fn with_amm_pools(mut self, amm_pools: Vec<(AccountId, AmmPoolOf<Test>)>) -> Self {
+			self.amm_pools = amm_pools;
+			self
+		}
+
+		pub(crate) fn with_vesting_settings(
+			mut self,
+			vesting_settings: Vec<(AccountId, VestingSettingsOf<Test>)>,
+		) -> Self {
+			self.vesting_settings = vesting_settings;
+			self
+		}
+
+		pub(crate) fn with_vesting_schedules(
+			mut self,
+			vesting_schedules: Vec<(AssetId, AccountId, Vec<VestingScheduleOf<Test>>)>,
+		) -> Self {
+			self.vesting_schedules = vesting_schedules;
+			self
+		}
+
 		pub(crate) fn build(self) -> sp_io::TestExternalities {
 			let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -485,6 +625,25 @@ pub mod runtime {
 					crate::Pools::<Test>::insert(pool_id, pool);
 				});
 
+				self.pool_fees.into_iter().for_each(|(pool_id, fees)| {
+					crate::PoolFeeSettings::<Test>::insert(pool_id, fees);
+				});
+
+				self.amm_pools.into_iter().for_each(|(pool_id, amm_pool)| {
+					crate::AmmPools::<Test>::insert(pool_id, amm_pool);
+				});
+
+				self.vesting_settings.into_iter().for_each(|(pool_id, settings)| {
+					crate::PoolVestingSettings::<Test>::insert(pool_id, settings);
+				});
+
+				self.vesting_schedules
+					.into_iter()
+					.for_each(|(asset_id, account, schedules)| {
+						let schedules = schedules.try_into().expect("Too many vesting schedules.");
+						crate::VestingSchedules::<Test>::insert(asset_id, account, schedules);
+					});
+
 				NextAssetId::<BondingPallet>::set(next_asset_id);
 
 				self.freezes.iter().for_each(|(asset_id, account, amount)| {
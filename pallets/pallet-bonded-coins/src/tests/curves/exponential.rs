@@ -0,0 +1,214 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use std::str::FromStr;
+
+use proptest::prelude::*;
+
+use crate::{
+	curves::{
+		balance_to_fixed,
+		exponential::{ExponentialParameters, ExponentialParametersInput},
+		fixed_to_balance, BondingFunction,
+	},
+	mock::runtime::{assert_relative_eq, Float, FloatInput},
+	types::Round,
+};
+
+/// e^2, which lets the price reach the cap at supply 2 for m = 1 and k = 1.
+const E_SQUARED: &str = "7.389056098930650227";
+
+fn curve(m: &str, k: &str, cap: &str) -> ExponentialParameters<Float> {
+	ExponentialParametersInput {
+		m: FloatInput::from_str(m).unwrap(),
+		k: FloatInput::from_str(k).unwrap(),
+		cap: FloatInput::from_str(cap).unwrap(),
+	}
+	.try_into()
+	.expect("Parameters should be valid")
+}
+
+#[test]
+fn mint_first_coin() {
+	// Create curve with shape f(x) = min(e^x, e^2), resulting in the integral
+	// function F(x) = e^x below the cap
+	let curve = curve("1", "1", E_SQUARED);
+
+	// Cost to mint the first coin: e^1 - e^0 = 1.718281828459045235
+	let costs = curve
+		.calculate_costs(Float::from_num(0), Float::from_num(1), vec![])
+		.unwrap();
+
+	let expected_costs = Float::from_str("1.718281828459045235").unwrap();
+
+	assert_relative_eq(costs, expected_costs, Float::from_str("0.000001").unwrap());
+}
+
+#[test]
+fn mint_above_cap() {
+	let curve = curve("1", "1", E_SQUARED);
+
+	// Price is capped above supply 2: e^2 * 2 = 14.778112197861300454
+	let costs = curve
+		.calculate_costs(Float::from_num(2), Float::from_num(4), vec![])
+		.unwrap();
+
+	let expected_costs = Float::from_str("14.778112197861300454").unwrap();
+
+	assert_relative_eq(costs, expected_costs, Float::from_str("0.000001").unwrap());
+}
+
+#[test]
+fn mint_across_cap() {
+	let curve = curve("1", "1", E_SQUARED);
+
+	// Exponential part: e^2 - e^1 = 4.670774270471604992
+	// Capped part: e^2 * 2 = 14.778112197861300454
+	let costs = curve
+		.calculate_costs(Float::from_num(1), Float::from_num(4), vec![])
+		.unwrap();
+
+	let expected_costs = Float::from_str("19.448886468332905446").unwrap();
+
+	assert_relative_eq(costs, expected_costs, Float::from_str("0.000001").unwrap());
+}
+
+#[test]
+fn mint_with_passive_supply() {
+	// Create curve with shape f(x) = min(0.5 * e^(0.5x), 100), resulting in the
+	// integral function F(x) = e^(0.5x) below the cap
+	let curve = curve("0.5", "0.5", "100");
+
+	// The passive supply shifts the bounds to 2 and 4: e^2 - e^1
+	let costs = curve
+		.calculate_costs(Float::from_num(0), Float::from_num(2), vec![Float::from_num(2)])
+		.unwrap();
+
+	let expected_costs = Float::from_str("4.670774270471604992").unwrap();
+
+	assert_relative_eq(costs, expected_costs, Float::from_str("0.000001").unwrap());
+}
+
+#[test]
+fn mint_with_price_at_cap() {
+	// The price starts at the cap, resulting in a constant price of 2
+	let curve = curve("2", "1", "2");
+
+	let costs = curve
+		.calculate_costs(Float::from_num(0), Float::from_num(10), vec![])
+		.unwrap();
+
+	assert_relative_eq(costs, Float::from_num(20), Float::from_str("0.000001").unwrap());
+}
+
+#[test]
+fn large_supply_does_not_overflow() {
+	// Without the cap, e^(100_000_000) would overflow the fixed point type.
+	let curve = curve("1", "1", E_SQUARED);
+
+	let costs = curve
+		.calculate_costs(Float::from_num(100_000_000), Float::from_num(100_000_001), vec![])
+		.unwrap();
+
+	assert_relative_eq(
+		costs,
+		Float::from_str(E_SQUARED).unwrap(),
+		Float::from_str("0.000001").unwrap(),
+	);
+}
+
+#[test]
+fn try_from_fails_for_invalid_parameters() {
+	let input = |m: &str, k: &str, cap: &str| ExponentialParametersInput {
+		m: FloatInput::from_str(m).unwrap(),
+		k: FloatInput::from_str(k).unwrap(),
+		cap: FloatInput::from_str(cap).unwrap(),
+	};
+
+	// m must be greater than zero
+	assert!(ExponentialParameters::<Float>::try_from(input("0", "1", "2")).is_err());
+	// k must be greater than zero
+	assert!(ExponentialParameters::<Float>::try_from(input("1", "0", "2")).is_err());
+	// cap must not be smaller than m
+	assert!(ExponentialParameters::<Float>::try_from(input("2", "1", "1")).is_err());
+}
+
+/// Generates valid parameters, represented in thousandths.
+fn arb_parameters() -> impl Strategy<Value = ExponentialParameters<Float>> {
+	(1u32..=10_000, 1u32..=1_000, 1u32..=100).prop_map(|(m, k, cap_factor)| {
+		let thousandths = |x: u32| FloatInput::from_num(x) / FloatInput::from_num(1_000);
+		ExponentialParametersInput {
+			m: thousandths(m),
+			k: thousandths(k),
+			cap: thousandths(m) * FloatInput::from_num(cap_factor),
+		}
+		.try_into()
+		.expect("Parameters should be valid")
+	})
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(256))]
+
+	/// The costs never decrease when the upper bound increases and equal the
+	/// sum of the costs of the sub-intervals.
+	#[test]
+	fn costs_are_monotonic_and_additive(
+		curve in arb_parameters(),
+		mut bounds in prop::collection::vec(0u64..=1_000_000, 3),
+	) {
+		bounds.sort_unstable();
+		let to_supply = |x: u64| Float::from_num(x) / Float::from_num(1_000);
+		let (low, mid, high) = (to_supply(bounds[0]), to_supply(bounds[1]), to_supply(bounds[2]));
+		let epsilon = Float::from_str("0.000001").unwrap();
+
+		let lower_costs = curve.calculate_costs(low, mid, vec![]).unwrap();
+		let upper_costs = curve.calculate_costs(mid, high, vec![]).unwrap();
+		let total_costs = curve.calculate_costs(low, high, vec![]).unwrap();
+
+		prop_assert!(lower_costs + epsilon >= Float::from_num(0));
+		prop_assert!(upper_costs + epsilon >= Float::from_num(0));
+		prop_assert!(total_costs + epsilon >= lower_costs);
+		prop_assert!((total_costs - (lower_costs + upper_costs)).abs() <= epsilon);
+	}
+
+	/// Minting and burning the same amount never returns more collateral than
+	/// was paid, when converting balances to and from the fixed point
+	/// representation with the rounding used by the pallet.
+	#[test]
+	fn mint_and_burn_round_trip(
+		curve in arb_parameters(),
+		supply in 0u128..=1_000_000_000,
+		amount in 1u128..=1_000_000_000,
+	) {
+		let denomination = 6;
+
+		// Minting rounds in favour of the pool.
+		let low = balance_to_fixed::<u128, Float>(supply, denomination, Round::Up).unwrap();
+		let delta = balance_to_fixed::<u128, Float>(amount, denomination, Round::Up).unwrap();
+		let mint_costs = curve.calculate_costs(low, low + delta, vec![]).unwrap();
+		let paid = fixed_to_balance::<u128, Float>(mint_costs, denomination, Round::Up).unwrap();
+
+		// Burning rounds in favour of the pool as well.
+		let high = balance_to_fixed::<u128, Float>(supply + amount, denomination, Round::Down).unwrap();
+		let delta = balance_to_fixed::<u128, Float>(amount, denomination, Round::Down).unwrap();
+		let burn_costs = curve.calculate_costs(high - delta, high, vec![]).unwrap();
+		let returned = fixed_to_balance::<u128, Float>(burn_costs, denomination, Round::Down).unwrap();
+
+		prop_assert!(returned <= paid, "returned {} but paid {}", returned, paid);
+	}
+}
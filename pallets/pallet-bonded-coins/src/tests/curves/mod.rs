@@ -16,6 +16,8 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 mod arithmetic;
+mod exponential;
 mod lmsr;
+mod piecewise_linear;
 mod polynomial;
 mod square_root;
@@ -0,0 +1,271 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use std::str::FromStr;
+
+use proptest::prelude::*;
+use substrate_fixed::traits::Fixed;
+
+use crate::{
+	curves::{
+		balance_to_fixed, fixed_to_balance,
+		piecewise_linear::{
+			ControlPoint, ControlPoints, PiecewiseLinearParameters, PiecewiseLinearParametersInput, MAX_CONTROL_POINTS,
+		},
+		BondingFunction,
+	},
+	mock::runtime::{Float, FloatInput},
+	types::Round,
+};
+
+fn control_points<F: Fixed>(points: &[(u32, u32)]) -> ControlPoints<F> {
+	points
+		.iter()
+		.map(|(supply, price)| ControlPoint {
+			supply: F::from_num(*supply),
+			price: F::from_num(*price),
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("Number of control points should not exceed the maximum")
+}
+
+fn curve(points: &[(u32, u32)]) -> PiecewiseLinearParameters<Float> {
+	PiecewiseLinearParametersInput {
+		points: control_points::<FloatInput>(points),
+	}
+	.try_into()
+	.expect("Control points should be valid")
+}
+
+#[test]
+fn mint_first_coin() {
+	// Capped linear curve with shape f(x) = 2x + 1 for x < 10 and f(x) = 21 above
+	let curve = curve(&[(0, 1), (10, 21)]);
+
+	// Cost to mint the first coin: 1^2 + 1 = 2
+	let costs = curve
+		.calculate_costs(Float::from_num(0), Float::from_num(1), vec![])
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(2));
+}
+
+#[test]
+fn mint_until_cap() {
+	let curve = curve(&[(0, 1), (10, 21)]);
+
+	// Integral of 2x + 1 from 0 to 10: 10^2 + 10 = 110
+	let costs = curve
+		.calculate_costs(Float::from_num(0), Float::from_num(10), vec![])
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(110));
+}
+
+#[test]
+fn mint_above_cap() {
+	let curve = curve(&[(0, 1), (10, 21)]);
+
+	// Price is constant above the last control point: 21 * 5 = 105
+	let costs = curve
+		.calculate_costs(Float::from_num(10), Float::from_num(15), vec![])
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(105));
+}
+
+#[test]
+fn mint_across_cap() {
+	let curve = curve(&[(0, 1), (10, 21)]);
+
+	// Linear part: (10^2 + 10) - (5^2 + 5) = 80
+	// Capped part: 21 * 5 = 105
+	let costs = curve
+		.calculate_costs(Float::from_num(5), Float::from_num(15), vec![])
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(185));
+}
+
+#[test]
+fn mint_across_multiple_segments() {
+	// Trapezoid shaped price: rises to 2, stays at 2 and falls back to 0
+	let curve = curve(&[(0, 0), (2, 2), (4, 2), (6, 0)]);
+
+	// First segment from 1 to 2: (1 + 2) / 2 * 1 = 1.5
+	// Second segment from 2 to 4: 2 * 2 = 4
+	// Third segment from 4 to 5: (2 + 1) / 2 * 1 = 1.5
+	let costs = curve
+		.calculate_costs(Float::from_num(1), Float::from_num(5), vec![])
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(7));
+
+	// Price is zero above the last control point.
+	let costs = curve
+		.calculate_costs(Float::from_num(0), Float::from_num(100), vec![])
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(8));
+}
+
+#[test]
+fn mint_with_passive_supply() {
+	let curve = curve(&[(0, 1), (10, 21)]);
+
+	// The passive supply shifts the bounds to 5 and 10:
+	// (10^2 + 10) - (5^2 + 5) = 80
+	let costs = curve
+		.calculate_costs(Float::from_num(0), Float::from_num(5), vec![Float::from_num(5)])
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(80));
+}
+
+#[test]
+fn mint_with_fractional_bounds() {
+	let curve = curve(&[(0, 1), (10, 21)]);
+
+	// (0.75^2 + 0.75) - (0.25^2 + 0.25) = 1
+	let costs = curve
+		.calculate_costs(
+			Float::from_str("0.25").unwrap(),
+			Float::from_str("0.75").unwrap(),
+			vec![],
+		)
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(1));
+}
+
+#[test]
+fn try_from_fails_without_control_points() {
+	let input = PiecewiseLinearParametersInput {
+		points: control_points::<FloatInput>(&[]),
+	};
+
+	assert!(PiecewiseLinearParameters::<Float>::try_from(input).is_err());
+}
+
+#[test]
+fn try_from_fails_if_first_supply_is_not_zero() {
+	let input = PiecewiseLinearParametersInput {
+		points: control_points::<FloatInput>(&[(1, 1), (10, 21)]),
+	};
+
+	assert!(PiecewiseLinearParameters::<Float>::try_from(input).is_err());
+}
+
+#[test]
+fn try_from_fails_if_supplies_are_not_increasing() {
+	let input = PiecewiseLinearParametersInput {
+		points: control_points::<FloatInput>(&[(0, 1), (10, 21), (10, 30)]),
+	};
+
+	assert!(PiecewiseLinearParameters::<Float>::try_from(input).is_err());
+
+	let input = PiecewiseLinearParametersInput {
+		points: control_points::<FloatInput>(&[(0, 1), (10, 21), (5, 30)]),
+	};
+
+	assert!(PiecewiseLinearParameters::<Float>::try_from(input).is_err());
+}
+
+#[test]
+fn try_from_accepts_constant_price() {
+	let curve = curve(&[(0, 3)]);
+
+	let costs = curve
+		.calculate_costs(Float::from_num(2), Float::from_num(12), vec![])
+		.unwrap();
+
+	assert_eq!(costs, Float::from_num(30));
+}
+
+/// Generates valid control points: the first control point is located at
+/// supply 0, the supplies of all following points are strictly increasing.
+fn arb_control_points() -> impl Strategy<Value = Vec<(u32, u32)>> {
+	let max_steps = usize::try_from(MAX_CONTROL_POINTS).unwrap().saturating_sub(1);
+	(
+		0u32..=1_000,
+		prop::collection::vec((1u32..=1_000, 0u32..=1_000), 0..=max_steps),
+	)
+		.prop_map(|(first_price, steps)| {
+			let mut supply = 0;
+			let mut points = vec![(0, first_price)];
+			for (step, price) in steps {
+				supply += step;
+				points.push((supply, price));
+			}
+			points
+		})
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(256))]
+
+	/// The costs never decrease when the upper bound increases and equal the
+	/// sum of the costs of the sub-intervals.
+	#[test]
+	fn costs_are_monotonic_and_additive(
+		points in arb_control_points(),
+		mut bounds in prop::collection::vec(0u64..=10_000_000, 3),
+	) {
+		let curve = curve(&points);
+		bounds.sort_unstable();
+		let to_supply = |x: u64| Float::from_num(x) / Float::from_num(1_000);
+		let (low, mid, high) = (to_supply(bounds[0]), to_supply(bounds[1]), to_supply(bounds[2]));
+		let epsilon = Float::from_str("0.000001").unwrap();
+
+		let lower_costs = curve.calculate_costs(low, mid, vec![]).unwrap();
+		let upper_costs = curve.calculate_costs(mid, high, vec![]).unwrap();
+		let total_costs = curve.calculate_costs(low, high, vec![]).unwrap();
+
+		prop_assert!(lower_costs >= Float::from_num(0));
+		prop_assert!(upper_costs >= Float::from_num(0));
+		prop_assert!(total_costs + epsilon >= lower_costs);
+		prop_assert!((total_costs - (lower_costs + upper_costs)).abs() <= epsilon);
+	}
+
+	/// Minting and burning the same amount never returns more collateral than
+	/// was paid, when converting balances to and from the fixed point
+	/// representation with the rounding used by the pallet.
+	#[test]
+	fn mint_and_burn_round_trip(
+		points in arb_control_points(),
+		supply in 0u128..=1_000_000_000_000,
+		amount in 1u128..=1_000_000_000_000,
+	) {
+		let curve = curve(&points);
+		let denomination = 10;
+
+		// Minting rounds in favour of the pool.
+		let low = balance_to_fixed::<u128, Float>(supply, denomination, Round::Up).unwrap();
+		let delta = balance_to_fixed::<u128, Float>(amount, denomination, Round::Up).unwrap();
+		let mint_costs = curve.calculate_costs(low, low + delta, vec![]).unwrap();
+		let paid = fixed_to_balance::<u128, Float>(mint_costs, denomination, Round::Up).unwrap();
+
+		// Burning rounds in favour of the pool as well.
+		let high = balance_to_fixed::<u128, Float>(supply + amount, denomination, Round::Down).unwrap();
+		let delta = balance_to_fixed::<u128, Float>(amount, denomination, Round::Down).unwrap();
+		let burn_costs = curve.calculate_costs(high - delta, high, vec![]).unwrap();
+		let returned = fixed_to_balance::<u128, Float>(burn_costs, denomination, Round::Down).unwrap();
+
+		prop_assert!(returned <= paid, "returned {} but paid {}", returned, paid);
+	}
+}
@@ -16,4 +16,5 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 mod curves;
+mod price_oracle;
 mod transactions;
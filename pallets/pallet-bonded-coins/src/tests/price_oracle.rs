@@ -0,0 +1,250 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok, traits::fungibles::Mutate};
+use frame_system::RawOrigin;
+use sp_runtime::assert_eq_error_rate;
+
+use crate::{
+	mock::{runtime::*, *},
+	traits::BondedPriceOracle,
+	AccountIdOf, Error, Event, PriceAccumulators, PriceObservations,
+};
+
+const ONE_COIN: u128 = 10u128.pow(DEFAULT_BONDED_DENOMINATION as u32);
+
+fn expected_spot_price(supply: u128) -> u128 {
+	mocks_curve_get_collateral_at_supply(supply + ONE_COIN) - mocks_curve_get_collateral_at_supply(supply)
+}
+
+fn build_pool(pool_id: &AccountIdOf<Test>) -> ExtBuilder {
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+				None,
+				None,
+			),
+		)])
+}
+
+fn mint_one_coin(pool_id: &AccountIdOf<Test>) {
+	assert_ok!(BondingPallet::mint_into(
+		RawOrigin::Signed(ACCOUNT_00).into(),
+		pool_id.clone(),
+		0,
+		ACCOUNT_00,
+		ONE_COIN,
+		ONE_HUNDRED_KILT,
+		1
+	));
+}
+
+#[test]
+fn mint_updates_price_accumulator() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	build_pool(&pool_id).build_and_execute_with_sanity_tests(|| {
+		let now = System::block_number();
+
+		mint_one_coin(&pool_id);
+
+		let accumulator = PriceAccumulators::<Test>::get(&pool_id).expect("Accumulator should exist");
+		let expected_price = expected_spot_price(ONE_COIN);
+
+		assert_eq!(accumulator.last_update, now);
+		assert_eq!(accumulator.cumulative_prices.into_inner(), vec![0.into()]);
+		assert_eq_error_rate!(
+			accumulator.spot_prices[0],
+			expected_price,
+			MAX_ERROR.mul_floor(expected_price)
+		);
+
+		let observation = PriceObservations::<Test>::get(&pool_id, 0).expect("Observation should exist");
+		assert_eq!(observation.block, now);
+
+		assert_eq!(BondingPallet::spot_price(&pool_id, 0), Ok(accumulator.spot_prices[0]));
+	});
+}
+
+#[test]
+fn burn_updates_price_accumulator() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	build_pool(&pool_id).build_and_execute_with_sanity_tests(|| {
+		let start = System::block_number();
+
+		mint_one_coin(&pool_id);
+		mint_one_coin(&pool_id);
+		let price_after_mint = PriceAccumulators::<Test>::get(&pool_id).unwrap().spot_prices[0];
+
+		System::set_block_number(start + 5);
+
+		assert_ok!(BondingPallet::burn_into(
+			RawOrigin::Signed(ACCOUNT_00).into(),
+			pool_id.clone(),
+			0,
+			ACCOUNT_00,
+			ONE_COIN,
+			0,
+			1
+		));
+
+		let accumulator = PriceAccumulators::<Test>::get(&pool_id).unwrap();
+		let expected_price = expected_spot_price(ONE_COIN);
+
+		assert_eq!(accumulator.last_update, start + 5);
+		assert_eq!(
+			accumulator.cumulative_prices.into_inner(),
+			vec![(price_after_mint * 5).into()]
+		);
+		assert_eq_error_rate!(
+			accumulator.spot_prices[0],
+			expected_price,
+			MAX_ERROR.mul_floor(expected_price)
+		);
+	});
+}
+
+#[test]
+fn update_carries_forward_price_which_cannot_be_calculated() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	build_pool(&pool_id).build_and_execute_with_sanity_tests(|| {
+		mint_one_coin(&pool_id);
+		let previous_price = PriceAccumulators::<Test>::get(&pool_id).unwrap().spot_prices[0];
+
+		// the supply cannot be represented by the curve parameter type
+		assert_ok!(Assets::mint_into(
+			DEFAULT_BONDED_CURRENCY_ID,
+			&ACCOUNT_01,
+			u128::MAX / 2
+		));
+
+		BondingPallet::update_price_accumulator(
+			&pool_id,
+			&get_linear_bonding_curve(),
+			&[DEFAULT_BONDED_CURRENCY_ID],
+			DEFAULT_BONDED_DENOMINATION,
+			DEFAULT_COLLATERAL_CURRENCY_ID,
+		);
+
+		assert_eq!(
+			PriceAccumulators::<Test>::get(&pool_id).unwrap().spot_prices[0],
+			previous_price
+		);
+		System::assert_last_event(
+			Event::SpotPriceCarriedForward {
+				id: pool_id,
+				currency_idx: 0,
+				spot_price: previous_price,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn twap_works() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	build_pool(&pool_id).build_and_execute_with_sanity_tests(|| {
+		let start = System::block_number();
+
+		mint_one_coin(&pool_id);
+		let first_price = PriceAccumulators::<Test>::get(&pool_id).unwrap().spot_prices[0];
+
+		System::set_block_number(start + 20);
+		mint_one_coin(&pool_id);
+		let second_price = PriceAccumulators::<Test>::get(&pool_id).unwrap().spot_prices[0];
+
+		System::set_block_number(start + 30);
+
+		// Averaged since the first observation.
+		assert_eq!(
+			BondingPallet::twap(&pool_id, 0, 30),
+			Ok((first_price * 20 + second_price * 10) / 30)
+		);
+		// Averaged since the second observation.
+		assert_eq!(BondingPallet::twap(&pool_id, 0, 10), Ok(second_price));
+		// The window is extended to the next older observation.
+		assert_eq!(
+			BondingPallet::twap(&pool_id, 0, 15),
+			Ok((first_price * 20 + second_price * 10) / 30)
+		);
+	});
+}
+
+#[test]
+fn twap_records_one_observation_per_period() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	build_pool(&pool_id).build_and_execute_with_sanity_tests(|| {
+		let start = System::block_number();
+
+		mint_one_coin(&pool_id);
+		System::set_block_number(start + 2);
+		mint_one_coin(&pool_id);
+
+		// Both trades happened in the same observation period.
+		assert_eq!(PriceObservations::<Test>::iter_prefix(&pool_id).count(), 1);
+		assert_eq!(PriceObservations::<Test>::get(&pool_id, 0).unwrap().block, start);
+	});
+}
+
+#[test]
+fn twap_fails_without_sufficient_history() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	build_pool(&pool_id).build_and_execute_with_sanity_tests(|| {
+		// no trades yet
+		assert_err!(
+			BondingPallet::twap(&pool_id, 0, 1),
+			Error::<Test>::InsufficientPriceHistory
+		);
+
+		mint_one_coin(&pool_id);
+		System::set_block_number(System::block_number() + 5);
+
+		assert_err!(
+			BondingPallet::twap(&pool_id, 0, 10),
+			Error::<Test>::InsufficientPriceHistory
+		);
+	});
+}
+
+#[test]
+fn twap_fails_on_invalid_input() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	build_pool(&pool_id).build_and_execute_with_sanity_tests(|| {
+		mint_one_coin(&pool_id);
+		System::set_block_number(System::block_number() + 5);
+
+		assert_err!(BondingPallet::twap(&pool_id, 0, 0), Error::<Test>::InvalidInput);
+		assert_err!(BondingPallet::twap(&pool_id, 1, 1), Error::<Test>::IndexOutOfBounds);
+		assert_err!(BondingPallet::twap(&ACCOUNT_99, 0, 1), Error::<Test>::PoolUnknown);
+	});
+}
@@ -23,12 +23,12 @@ use frame_support::{
 	},
 };
 use frame_system::{pallet_prelude::OriginFor, RawOrigin};
-use sp_runtime::{assert_eq_error_rate, bounded_vec, traits::Scale, TokenError};
+use sp_runtime::{assert_eq_error_rate, bounded_vec, traits::Scale, Perbill, TokenError};
 
 use crate::{
 	mock::{runtime::*, *},
-	types::{BondedCurrenciesSettings, Locks, PoolStatus},
-	AccountIdOf, Error, PoolDetailsOf,
+	types::{BondedCurrenciesSettings, Locks, PoolFees, PoolStatus},
+	AccountIdOf, AccruedFees, Error, Event as BondingPalletEvents, PoolDetailsOf,
 };
 
 // should not be u128::MAX, as a bug in the assets pallet results in transfers
@@ -779,3 +779,130 @@ fn burn_beyond_balance() {
 			);
 		});
 }
+
+#[test]
+fn burn_with_fees() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	let amount_to_burn: u128 = 1;
+	let expected_price =
+		(2 * amount_to_burn.pow(2) + 3 * amount_to_burn) * 10u128.pow(DEFAULT_COLLATERAL_DENOMINATION.into());
+	let fees = PoolFees {
+		mint: Perbill::zero(),
+		burn: Perbill::from_percent(10),
+	};
+	let protocol_fee_rate = Perbill::from_percent(1);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT), (ACCOUNT_99, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), LARGE_BALANCE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, amount_to_burn),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			PoolDetailsOf::<Test> {
+				curve: get_linear_bonding_curve(),
+				manager: None,
+				bonded_currencies: bounded_vec![DEFAULT_BONDED_CURRENCY_ID],
+				state: PoolStatus::Active,
+				collateral: DEFAULT_COLLATERAL_CURRENCY_ID,
+				currencies_settings: BondedCurrenciesSettings {
+					transferable: true,
+					allow_reset_team: true,
+					denomination: 0,
+					min_operation_balance: 1,
+				},
+				owner: ACCOUNT_99,
+				deposit: BondingPallet::calculate_pool_deposit(1),
+			},
+		)])
+		.with_pool_fees(vec![(pool_id.clone(), fees.clone())])
+		.build_and_execute_with_sanity_tests(|| {
+			ProtocolFee::set(&protocol_fee_rate);
+			let origin = RawOrigin::Signed(ACCOUNT_00).into();
+
+			assert_ok!(BondingPallet::burn_into(
+				origin,
+				pool_id.clone(),
+				0,
+				ACCOUNT_00,
+				amount_to_burn,
+				Perbill::from_percent(89).mul_floor(expected_price),
+				1
+			));
+
+			let pool_fee = AccruedFees::<Test>::get(&pool_id);
+			let protocol_fee = Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &PROTOCOL_FEE_ACCOUNT);
+			let net_return = Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_00);
+			let collateral_return = net_return + pool_fee + protocol_fee;
+
+			assert_eq_error_rate!(collateral_return, expected_price, MAX_ERROR.mul_floor(expected_price));
+			assert_eq!(pool_fee, fees.burn.mul_ceil(collateral_return));
+			assert_eq!(protocol_fee, protocol_fee_rate.mul_ceil(collateral_return));
+
+			// the pool fee stays in the pool account
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &pool_id),
+				LARGE_BALANCE - net_return - protocol_fee
+			);
+			assert!(events().contains(&BondingPalletEvents::FeesCharged {
+				id: pool_id,
+				pool_fee,
+				protocol_fee
+			}));
+		});
+}
+
+#[test]
+fn burn_with_fees_not_hitting_minimum() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	let amount_to_burn: u128 = 1;
+	// covers the price but not the deduction of fees
+	let min_return = (2 * amount_to_burn.pow(2) + 3 * amount_to_burn)
+		* 10u128.pow(DEFAULT_COLLATERAL_DENOMINATION.into())
+		/ 100 * 99;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), LARGE_BALANCE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, amount_to_burn),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			PoolDetailsOf::<Test> {
+				curve: get_linear_bonding_curve(),
+				manager: None,
+				bonded_currencies: bounded_vec![DEFAULT_BONDED_CURRENCY_ID],
+				state: PoolStatus::Active,
+				collateral: DEFAULT_COLLATERAL_CURRENCY_ID,
+				currencies_settings: BondedCurrenciesSettings {
+					transferable: true,
+					allow_reset_team: true,
+					denomination: 0,
+					min_operation_balance: 1,
+				},
+				owner: ACCOUNT_00,
+				deposit: BondingPallet::calculate_pool_deposit(1),
+			},
+		)])
+		.with_pool_fees(vec![(
+			pool_id.clone(),
+			PoolFees {
+				mint: Perbill::zero(),
+				burn: Perbill::from_percent(5),
+			},
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			let origin = RawOrigin::Signed(ACCOUNT_00).into();
+
+			assert_err!(
+				BondingPallet::burn_into(origin, pool_id, 0, ACCOUNT_00, amount_to_burn, min_return, 1),
+				Error::<Test>::Slippage
+			);
+		});
+}
@@ -0,0 +1,156 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok, traits::fungibles::Inspect};
+use frame_system::RawOrigin;
+use sp_runtime::{assert_eq_error_rate, bounded_vec};
+
+use crate::{
+	mock::{runtime::*, *},
+	types::{BondedCurrenciesSettings, PoolStatus},
+	AccountIdOf, Error, Event as BondingPalletEvents, PoolDetailsOf,
+};
+
+const LARGE_BALANCE: u128 = u128::MAX / 10;
+
+fn pool_details() -> PoolDetailsOf<Test> {
+	PoolDetailsOf::<Test> {
+		curve: get_linear_bonding_curve(),
+		manager: None,
+		bonded_currencies: bounded_vec![DEFAULT_BONDED_CURRENCY_ID],
+		state: PoolStatus::Active,
+		collateral: DEFAULT_COLLATERAL_CURRENCY_ID,
+		currencies_settings: BondedCurrenciesSettings {
+			transferable: true,
+			allow_reset_team: true,
+			denomination: 0,
+			min_operation_balance: 1,
+		},
+		owner: ACCOUNT_99,
+		deposit: BondingPallet::calculate_pool_deposit(1),
+	}
+}
+
+#[test]
+fn burn_into_remote_works() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	let amount_to_burn: u128 = 1;
+	let expected_price =
+		(2 * amount_to_burn.pow(2) + 3 * amount_to_burn) * 10u128.pow(DEFAULT_COLLATERAL_DENOMINATION.into());
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT), (ACCOUNT_99, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), LARGE_BALANCE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, amount_to_burn),
+		])
+		.with_pools(vec![(pool_id.clone(), pool_details())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::burn_into_remote(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				0,
+				Box::new(ACCOUNT_01),
+				amount_to_burn,
+				expected_price - 1, // rounding down may be happening in the conversion to fixed
+				1
+			));
+
+			let sent = Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &REMOTE_RESERVE_ACCOUNT);
+
+			assert_eq_error_rate!(sent, expected_price, MAX_ERROR.mul_floor(expected_price));
+			// The collateral passes through the account of the caller.
+			assert_eq!(Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_00), 0);
+			assert_eq!(Assets::total_balance(DEFAULT_BONDED_CURRENCY_ID, &ACCOUNT_00), 0);
+
+			System::assert_last_event(
+				BondingPalletEvents::CollateralSentRemote {
+					id: pool_id,
+					who: ACCOUNT_00,
+					collateral: DEFAULT_COLLATERAL_CURRENCY_ID,
+					amount: sent,
+					beneficiary: ACCOUNT_01,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn burn_into_remote_fails_on_slippage() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	let amount_to_burn: u128 = 1;
+	let expected_price =
+		(2 * amount_to_burn.pow(2) + 3 * amount_to_burn) * 10u128.pow(DEFAULT_COLLATERAL_DENOMINATION.into());
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT), (ACCOUNT_99, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), LARGE_BALANCE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, amount_to_burn),
+		])
+		.with_pools(vec![(pool_id.clone(), pool_details())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::burn_into_remote(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					0,
+					Box::new(ACCOUNT_01),
+					amount_to_burn,
+					expected_price + 1,
+					1
+				),
+				Error::<Test>::Slippage
+			);
+
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &REMOTE_RESERVE_ACCOUNT),
+				0
+			);
+			assert_eq!(
+				Assets::total_balance(DEFAULT_BONDED_CURRENCY_ID, &ACCOUNT_00),
+				amount_to_burn
+			);
+		});
+}
+
+#[test]
+fn burn_into_remote_fails_for_unknown_pool() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::burn_into_remote(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id,
+					0,
+					Box::new(ACCOUNT_01),
+					1,
+					0,
+					1
+				),
+				Error::<Test>::PoolUnknown
+			);
+		});
+}
@@ -0,0 +1,183 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok, traits::fungibles::Inspect};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::PoolStatus,
+	AccountIdOf, AccruedFees, Error as BondingPalletErrors, Event as BondingPalletEvents,
+};
+
+#[test]
+fn owner_claims_fees() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		None,
+		Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+		Some(ACCOUNT_00),
+		None,
+	);
+	let reserves = 10_000;
+	let accrued_fees = 1_000;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![(
+			DEFAULT_COLLATERAL_CURRENCY_ID,
+			pool_id.clone(),
+			reserves + accrued_fees,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			AccruedFees::<Test>::insert(&pool_id, accrued_fees);
+
+			assert_ok!(BondingPallet::claim_fees(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				ACCOUNT_01
+			));
+
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_01),
+				accrued_fees
+			);
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &pool_id),
+				reserves
+			);
+			assert_eq!(AccruedFees::<Test>::get(&pool_id), 0);
+			assert!(events().contains(&BondingPalletEvents::FeesClaimed {
+				id: pool_id,
+				beneficiary: ACCOUNT_01,
+				amount: accrued_fees
+			}));
+		});
+}
+
+#[test]
+fn manager_claims_fees() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Refunding),
+		Some(ACCOUNT_01),
+		Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+		Some(ACCOUNT_00),
+		None,
+	);
+	let accrued_fees = 1_000;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), accrued_fees)])
+		.build_and_execute_with_sanity_tests(|| {
+			AccruedFees::<Test>::insert(&pool_id, accrued_fees);
+
+			assert_ok!(BondingPallet::claim_fees(
+				RawOrigin::Signed(ACCOUNT_01).into(),
+				pool_id.clone(),
+				ACCOUNT_01
+			));
+
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_01),
+				accrued_fees
+			);
+			assert_eq!(AccruedFees::<Test>::get(&pool_id), 0);
+		});
+}
+
+#[test]
+fn fails_if_not_owner_or_manager() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+		Some(ACCOUNT_00),
+		None,
+	);
+	let accrued_fees = 1_000;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), accrued_fees)])
+		.build_and_execute_with_sanity_tests(|| {
+			AccruedFees::<Test>::insert(&pool_id, accrued_fees);
+
+			assert_err!(
+				BondingPallet::claim_fees(RawOrigin::Signed(ACCOUNT_01).into(), pool_id.clone(), ACCOUNT_01),
+				BondingPalletErrors::<Test>::NoPermission
+			);
+			assert_eq!(AccruedFees::<Test>::get(&pool_id), accrued_fees);
+		});
+}
+
+#[test]
+fn fails_if_no_fees_accrued() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		None,
+		Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+		Some(ACCOUNT_00),
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::claim_fees(RawOrigin::Signed(ACCOUNT_00).into(), pool_id, ACCOUNT_00),
+				BondingPalletErrors::<Test>::NoFeesToClaim
+			);
+		});
+}
+
+#[test]
+fn fails_if_pool_does_not_exist() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::claim_fees(RawOrigin::Signed(ACCOUNT_00).into(), pool_id, ACCOUNT_00),
+				BondingPalletErrors::<Test>::PoolUnknown
+			);
+		});
+}
@@ -0,0 +1,194 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{
+	assert_err, assert_ok,
+	traits::{
+		fungibles::Inspect,
+		tokens::{Fortitude, Preservation},
+	},
+};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::VestingSchedule,
+	AccountIdOf, Error as BondingPalletErrors, Event as BondingPalletEvents, VestingSchedules,
+};
+
+const VESTING_BALANCE: Balance = 1_000;
+
+fn schedule() -> VestingSchedule<u64, Balance> {
+	VestingSchedule {
+		locked: VESTING_BALANCE,
+		start: 10,
+		duration: 100,
+	}
+}
+
+fn ext_builder(pool_id: &AccountIdOf<Test>) -> ExtBuilder {
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT), (ACCOUNT_01, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+		)])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, VESTING_BALANCE),
+		])
+		.with_vesting_schedules(vec![(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, vec![schedule()])])
+}
+
+#[test]
+fn claims_vested_tokens() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ext_builder(&pool_id).build_and_execute_with_sanity_tests(|| {
+		System::set_block_number(60);
+
+		// anyone can release vested tokens of an account
+		assert_ok!(BondingPallet::claim_vested(
+			RawOrigin::Signed(ACCOUNT_01).into(),
+			pool_id.clone(),
+			ACCOUNT_00,
+			0
+		));
+
+		// the remaining tokens vest at the same rate
+		assert_eq!(
+			VestingSchedules::<Test>::get(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00).into_inner(),
+			vec![VestingSchedule {
+				locked: VESTING_BALANCE / 2,
+				start: 60,
+				duration: 50,
+			}]
+		);
+
+		assert!(events().contains(&BondingPalletEvents::VestedClaimed {
+			id: pool_id,
+			who: ACCOUNT_00,
+			amount: VESTING_BALANCE / 2,
+		}));
+	});
+}
+
+#[test]
+fn claims_all_tokens_after_vesting() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ext_builder(&pool_id).build_and_execute_with_sanity_tests(|| {
+		System::set_block_number(110);
+
+		assert_ok!(BondingPallet::claim_vested(
+			RawOrigin::Signed(ACCOUNT_00).into(),
+			pool_id.clone(),
+			ACCOUNT_00,
+			0
+		));
+
+		assert!(!VestingSchedules::<Test>::contains_key(
+			DEFAULT_BONDED_CURRENCY_ID,
+			ACCOUNT_00
+		));
+		assert_eq!(
+			Assets::reducible_balance(
+				DEFAULT_BONDED_CURRENCY_ID,
+				&ACCOUNT_00,
+				Preservation::Expendable,
+				Fortitude::Polite
+			),
+			VESTING_BALANCE
+		);
+	});
+}
+
+#[test]
+fn vesting_tokens_are_frozen() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ext_builder(&pool_id).build_and_execute_with_sanity_tests(|| {
+		// vested tokens remain frozen until claimed
+		System::set_block_number(110);
+
+		assert_eq!(
+			Assets::reducible_balance(
+				DEFAULT_BONDED_CURRENCY_ID,
+				&ACCOUNT_00,
+				Preservation::Expendable,
+				Fortitude::Polite
+			),
+			0
+		);
+		assert!(Assets::transfer(
+			RawOrigin::Signed(ACCOUNT_00).into(),
+			DEFAULT_BONDED_CURRENCY_ID,
+			ACCOUNT_01,
+			1
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn fails_during_cliff() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ext_builder(&pool_id).build_and_execute_with_sanity_tests(|| {
+		System::set_block_number(10);
+
+		assert_err!(
+			BondingPallet::claim_vested(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), ACCOUNT_00, 0),
+			BondingPalletErrors::<Test>::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn fails_without_vesting_schedules() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ext_builder(&pool_id).build_and_execute_with_sanity_tests(|| {
+		assert_err!(
+			BondingPallet::claim_vested(RawOrigin::Signed(ACCOUNT_01).into(), pool_id.clone(), ACCOUNT_01, 0),
+			BondingPalletErrors::<Test>::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn fails_with_invalid_index() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ext_builder(&pool_id).build_and_execute_with_sanity_tests(|| {
+		System::set_block_number(60);
+
+		assert_err!(
+			BondingPallet::claim_vested(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), ACCOUNT_00, 1),
+			BondingPalletErrors::<Test>::IndexOutOfBounds
+		);
+	});
+}
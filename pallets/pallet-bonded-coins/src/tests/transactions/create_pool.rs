@@ -23,13 +23,13 @@ use frame_support::{
 };
 use frame_system::{pallet_prelude::OriginFor, RawOrigin};
 use pallet_assets::Error as AssetsPalletErrors;
-use sp_runtime::{bounded_vec, ArithmeticError, BoundedVec};
+use sp_runtime::{bounded_vec, traits::Saturating, ArithmeticError, BoundedVec, Perbill};
 use sp_std::ops::Sub;
 
 use crate::{
 	mock::{runtime::*, *},
-	types::{BondedCurrenciesSettings, Locks, PoolStatus},
-	AccountIdOf, Error, Event as BondingPalletEvents, Pools, TokenMetaOf,
+	types::{BondedCurrenciesSettings, Locks, PoolFees, PoolStatus},
+	AccountIdOf, Error, Event as BondingPalletEvents, PoolFeeSettings, Pools, TokenMetaOf,
 };
 
 #[test]
@@ -61,6 +61,7 @@ fn single_currency() {
 					transferable: true,
 					min_operation_balance: 1
 				},
+				PoolFees::default(),
 			));
 
 			let pool_id: AccountIdOf<Test> = calculate_pool_id(&[new_asset_id]);
@@ -145,7 +146,8 @@ fn multi_currency() {
 					allow_reset_team: true,
 					transferable: true,
 					min_operation_balance: 1
-				}
+				},
+				PoolFees::default()
 			));
 
 			assert_eq!(NextAssetId::<BondingPallet>::get(), next_asset_id + 3);
@@ -199,7 +201,8 @@ fn multi_currency_with_empty_metadata() {
 					allow_reset_team: true,
 					transferable: true,
 					min_operation_balance: 1
-				}
+				},
+				PoolFees::default()
 			));
 
 			assert_eq!(NextAssetId::<BondingPallet>::get(), next_asset_id + 3);
@@ -252,7 +255,8 @@ fn can_create_identical_pools() {
 					allow_reset_team: true,
 					transferable: true,
 					min_operation_balance: 1
-				}
+				},
+				PoolFees::default()
 			));
 
 			assert_ok!(BondingPallet::create_pool(
@@ -265,7 +269,8 @@ fn can_create_identical_pools() {
 					allow_reset_team: true,
 					transferable: true,
 					min_operation_balance: 1
-				}
+				},
+				PoolFees::default()
 			));
 
 			assert_eq!(NextAssetId::<BondingPallet>::get(), next_asset_id + 2);
@@ -322,7 +327,8 @@ fn cannot_reuse_names() {
 						allow_reset_team: true,
 						transferable: true,
 						min_operation_balance: 1
-					}
+					},
+					PoolFees::default()
 				),
 				Error::<Test>::InvalidInput
 			);
@@ -368,7 +374,8 @@ fn cannot_reuse_symbols() {
 						allow_reset_team: true,
 						transferable: true,
 						min_operation_balance: 1
-					}
+					},
+					PoolFees::default()
 				),
 				Error::<Test>::InvalidInput
 			);
@@ -400,7 +407,8 @@ fn fails_if_collateral_not_exists() {
 						allow_reset_team: true,
 						transferable: true,
 						min_operation_balance: 1
-					}
+					},
+					PoolFees::default()
 				),
 				AssetsPalletErrors::<Test>::Unknown
 			);
@@ -435,7 +443,8 @@ fn cannot_create_circular_pool() {
 						allow_reset_team: true,
 						transferable: true,
 						min_operation_balance: 1
-					}
+					},
+					PoolFees::default()
 				),
 				AssetsPalletErrors::<Test>::Unknown
 			);
@@ -471,9 +480,107 @@ fn handles_asset_id_overflow() {
 						allow_reset_team: true,
 						transferable: true,
 						min_operation_balance: 1
-					}
+					},
+					PoolFees::default()
 				),
 				ArithmeticError::Overflow
 			);
 		});
 }
+
+#[test]
+fn stores_pool_fees() {
+	let initial_balance = ONE_HUNDRED_KILT;
+	let fees = PoolFees {
+		mint: Perbill::from_percent(1),
+		burn: MaxPoolFee::get(),
+	};
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, initial_balance)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			let origin = RawOrigin::Signed(ACCOUNT_00).into();
+			let curve = get_linear_bonding_curve_input();
+
+			let bonded_token = TokenMetaOf::<Test> {
+				name: BoundedVec::truncate_from(b"Bitcoin".to_vec()),
+				symbol: BoundedVec::truncate_from(b"btc".to_vec()),
+				min_balance: 1,
+			};
+
+			let new_asset_id = NextAssetId::<BondingPallet>::get();
+
+			assert_ok!(BondingPallet::create_pool(
+				origin,
+				curve,
+				DEFAULT_COLLATERAL_CURRENCY_ID,
+				bounded_vec![bonded_token],
+				BondedCurrenciesSettings {
+					denomination: DEFAULT_BONDED_DENOMINATION,
+					allow_reset_team: true,
+					transferable: true,
+					min_operation_balance: 1
+				},
+				fees.clone(),
+			));
+
+			let pool_id: AccountIdOf<Test> = calculate_pool_id(&[new_asset_id]);
+
+			assert_eq!(PoolFeeSettings::<Test>::get(&pool_id), fees);
+		});
+}
+
+#[test]
+fn fails_if_fee_exceeds_maximum() {
+	let initial_balance = ONE_HUNDRED_KILT;
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, initial_balance)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			let origin: OriginFor<Test> = RawOrigin::Signed(ACCOUNT_00).into();
+			let curve = get_linear_bonding_curve_input();
+
+			let bonded_token = TokenMetaOf::<Test> {
+				name: BoundedVec::truncate_from(b"Bitcoin".to_vec()),
+				symbol: BoundedVec::truncate_from(b"btc".to_vec()),
+				min_balance: 1,
+			};
+			let currencies_settings = BondedCurrenciesSettings {
+				denomination: DEFAULT_BONDED_DENOMINATION,
+				allow_reset_team: true,
+				transferable: true,
+				min_operation_balance: 1,
+			};
+			let excessive_fee = MaxPoolFee::get().saturating_add(Perbill::from_parts(1));
+
+			assert_err!(
+				BondingPallet::create_pool(
+					origin.clone(),
+					curve.clone(),
+					DEFAULT_COLLATERAL_CURRENCY_ID,
+					bounded_vec![bonded_token.clone()],
+					currencies_settings.clone(),
+					PoolFees {
+						mint: excessive_fee,
+						burn: Perbill::zero(),
+					}
+				),
+				Error::<Test>::InvalidInput
+			);
+
+			assert_err!(
+				BondingPallet::create_pool(
+					origin,
+					curve,
+					DEFAULT_COLLATERAL_CURRENCY_ID,
+					bounded_vec![bonded_token],
+					currencies_settings,
+					PoolFees {
+						mint: Perbill::zero(),
+						burn: excessive_fee,
+					}
+				),
+				Error::<Test>::InvalidInput
+			);
+		});
+}
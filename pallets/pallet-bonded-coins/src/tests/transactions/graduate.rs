@@ -0,0 +1,254 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::{AmmPool, GraduationSettings, PoolStatus},
+	AccountIdOf, AccruedFees, AmmPools, Error as BondingPalletErrors, Event as BondingPalletEvents,
+	PoolGraduationSettings, Pools,
+};
+
+const THRESHOLD: Balance = 10_000;
+
+fn settings() -> GraduationSettings<Balance> {
+	GraduationSettings {
+		collateral_threshold: THRESHOLD,
+	}
+}
+
+#[test]
+fn graduates_pool() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+	let reserves = THRESHOLD + 1_000;
+	let accrued_fees = 500;
+	// the first whole coin costs 5 coins of collateral on the linear curve, i.e., 2 * 1^2 + 3 * 1
+	let bonded_reserve = reserves / 5;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_01, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![(
+			DEFAULT_COLLATERAL_CURRENCY_ID,
+			pool_id.clone(),
+			reserves + accrued_fees,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			AccruedFees::<Test>::insert(&pool_id, accrued_fees);
+			PoolGraduationSettings::<Test>::insert(&pool_id, settings());
+
+			// anyone can graduate the pool
+			assert_ok!(BondingPallet::graduate(
+				RawOrigin::Signed(ACCOUNT_01).into(),
+				pool_id.clone()
+			));
+
+			let pool_details = Pools::<Test>::get(&pool_id).expect("Pool should exist");
+			assert_eq!(pool_details.state, PoolStatus::Graduated);
+
+			// the accrued fees are not migrated into the AMM, and the reserves are paired with
+			// the supply they buy at the spot price
+			assert_eq!(
+				AmmPools::<Test>::get(&pool_id),
+				Some(AmmPool {
+					collateral_reserve: reserves,
+					bonded_reserve,
+					total_shares: reserves,
+				})
+			);
+			assert_eq!(AccruedFees::<Test>::get(&pool_id), accrued_fees);
+			assert!(PoolGraduationSettings::<Test>::get(&pool_id).is_none());
+
+			assert!(events().contains(&BondingPalletEvents::Graduated {
+				id: pool_id,
+				collateral_reserve: reserves,
+				bonded_reserve,
+			}));
+		});
+}
+
+#[test]
+fn fails_below_threshold() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+	let accrued_fees = 500;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), THRESHOLD)])
+		.build_and_execute_with_sanity_tests(|| {
+			// accrued fees do not count towards the threshold
+			AccruedFees::<Test>::insert(&pool_id, accrued_fees);
+			PoolGraduationSettings::<Test>::insert(&pool_id, settings());
+
+			assert_err!(
+				BondingPallet::graduate(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone()),
+				BondingPalletErrors::<Test>::GraduationThresholdNotReached
+			);
+
+			assert_eq!(PoolGraduationSettings::<Test>::get(&pool_id), Some(settings()));
+			assert!(AmmPools::<Test>::get(&pool_id).is_none());
+		});
+}
+
+#[test]
+fn fails_without_settings() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), THRESHOLD)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::graduate(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone()),
+				BondingPalletErrors::<Test>::GraduationNotConfigured
+			);
+		});
+}
+
+#[test]
+fn fails_if_pool_not_live() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Refunding),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), THRESHOLD)])
+		.build_and_execute_with_sanity_tests(|| {
+			PoolGraduationSettings::<Test>::insert(&pool_id, settings());
+
+			assert_err!(
+				BondingPallet::graduate(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone()),
+				BondingPalletErrors::<Test>::PoolNotLive
+			);
+		});
+}
+
+#[test]
+fn bonding_curve_is_disabled_after_graduation() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), THRESHOLD),
+			(DEFAULT_COLLATERAL_CURRENCY_ID, ACCOUNT_00, ONE_HUNDRED_KILT),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, 1_000),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			PoolGraduationSettings::<Test>::insert(&pool_id, settings());
+
+			assert_ok!(BondingPallet::graduate(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone()
+			));
+
+			assert_err!(
+				BondingPallet::mint_into(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					0,
+					ACCOUNT_00,
+					1_000,
+					ONE_HUNDRED_KILT,
+					1
+				),
+				BondingPalletErrors::<Test>::PoolNotLive
+			);
+
+			assert_err!(
+				BondingPallet::burn_into(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					0,
+					ACCOUNT_00,
+					1_000,
+					0,
+					1
+				),
+				BondingPalletErrors::<Test>::PoolNotLive
+			);
+
+			// a graduated pool cannot be refunded, nor destroyed while there are holders
+			assert_err!(
+				BondingPallet::start_refund(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 1),
+				BondingPalletErrors::<Test>::PoolNotLive
+			);
+			assert_err!(
+				BondingPallet::start_destroy(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 1),
+				BondingPalletErrors::<Test>::LivePool
+			);
+		});
+}
@@ -23,13 +23,13 @@ use frame_support::{
 	},
 };
 use frame_system::{pallet_prelude::OriginFor, RawOrigin};
-use sp_runtime::{assert_eq_error_rate, ArithmeticError, TokenError};
+use sp_runtime::{assert_eq_error_rate, ArithmeticError, Perbill, TokenError};
 
 use crate::{
 	curves::{polynomial::PolynomialParameters, Curve},
 	mock::{runtime::*, *},
-	types::{Locks, PoolStatus},
-	AccountIdOf, Error,
+	types::{Locks, PoolFees, PoolStatus, VestingSchedule, VestingSettings},
+	AccountIdOf, AccruedFees, Error, Event as BondingPalletEvents, VestingSchedules,
 };
 
 #[test]
@@ -838,3 +838,331 @@ fn mint_more_than_fixed_can_represent() {
 			));
 		})
 }
+
+#[test]
+fn mint_with_fees() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	let initial_collateral = ONE_HUNDRED_KILT;
+	let amount_to_mint = 10u128.pow(DEFAULT_BONDED_DENOMINATION.into());
+	let expected_price = mocks_curve_get_collateral_at_supply(amount_to_mint);
+	let fees = PoolFees {
+		mint: Perbill::from_percent(5),
+		burn: Perbill::zero(),
+	};
+	let protocol_fee_rate = Perbill::from_percent(1);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, ACCOUNT_00, initial_collateral)])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+				None,
+				None,
+			),
+		)])
+		.with_pool_fees(vec![(pool_id.clone(), fees.clone())])
+		.build_and_execute_with_sanity_tests(|| {
+			ProtocolFee::set(&protocol_fee_rate);
+			let origin = RawOrigin::Signed(ACCOUNT_00).into();
+
+			assert_ok!(BondingPallet::mint_into(
+				origin,
+				pool_id.clone(),
+				0,
+				ACCOUNT_00,
+				amount_to_mint,
+				initial_collateral,
+				1
+			));
+
+			let pool_fee = AccruedFees::<Test>::get(&pool_id);
+			let protocol_fee = Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &PROTOCOL_FEE_ACCOUNT);
+			let cost = BondingPallet::collateral_reserves(&pool_id, DEFAULT_COLLATERAL_CURRENCY_ID);
+
+			assert_eq_error_rate!(cost, expected_price, MAX_ERROR.mul_floor(expected_price));
+			assert_eq!(pool_fee, fees.mint.mul_ceil(cost));
+			assert_eq!(protocol_fee, protocol_fee_rate.mul_ceil(cost));
+
+			// fees are charged on top of the cost
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_00),
+				initial_collateral - cost - pool_fee - protocol_fee
+			);
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &pool_id),
+				cost + pool_fee
+			);
+			assert!(events().contains(&BondingPalletEvents::FeesCharged {
+				id: pool_id,
+				pool_fee,
+				protocol_fee
+			}));
+		})
+}
+
+#[test]
+fn mint_with_fees_exceeding_max_collateral_cost() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	let amount_to_mint = 10u128.pow(DEFAULT_BONDED_DENOMINATION.into());
+	// covers the price but not the fees
+	let max_cost = Perbill::from_percent(101).mul_floor(mocks_curve_get_collateral_at_supply(amount_to_mint));
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+				None,
+				None,
+			),
+		)])
+		.with_pool_fees(vec![(
+			pool_id.clone(),
+			PoolFees {
+				mint: Perbill::from_percent(5),
+				burn: Perbill::zero(),
+			},
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			let origin = RawOrigin::Signed(ACCOUNT_00).into();
+
+			assert_err!(
+				BondingPallet::mint_into(origin, pool_id, 0, ACCOUNT_00, amount_to_mint, max_cost, 1),
+				Error::<Test>::Slippage
+			);
+		});
+}
+
+#[test]
+fn mint_by_manager_vests() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let amount_to_mint = 10u128.pow(10);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT), (ACCOUNT_01, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+				None,
+				None,
+			),
+		)])
+		.with_vesting_settings(vec![(
+			pool_id.clone(),
+			VestingSettings {
+				cliff: 10,
+				duration: 100,
+				threshold: None,
+			},
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			let origin = RawOrigin::Signed(ACCOUNT_00).into();
+
+			assert_ok!(BondingPallet::mint_into(
+				origin,
+				pool_id.clone(),
+				0,
+				ACCOUNT_01,
+				amount_to_mint,
+				ONE_HUNDRED_KILT,
+				1
+			));
+
+			let expected_schedule = VestingSchedule {
+				locked: amount_to_mint,
+				start: System::block_number() + 10,
+				duration: 100,
+			};
+			assert_eq!(
+				VestingSchedules::<Test>::get(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_01).into_inner(),
+				vec![expected_schedule.clone()]
+			);
+
+			assert_eq!(
+				Assets::total_balance(DEFAULT_BONDED_CURRENCY_ID, &ACCOUNT_01),
+				amount_to_mint
+			);
+			// The minted tokens are frozen until they vest
+			assert_eq!(
+				Assets::reducible_balance(
+					DEFAULT_BONDED_CURRENCY_ID,
+					&ACCOUNT_01,
+					Preservation::Expendable,
+					Fortitude::Polite
+				),
+				0
+			);
+
+			System::assert_has_event(
+				BondingPalletEvents::<Test>::VestingScheduleAdded {
+					id: pool_id,
+					who: ACCOUNT_01,
+					schedule: expected_schedule,
+				}
+				.into(),
+			);
+		})
+}
+
+#[test]
+fn mint_above_vesting_threshold_vests() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let threshold = 10u128.pow(10);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+				None,
+				None,
+			),
+		)])
+		.with_vesting_settings(vec![(
+			pool_id.clone(),
+			VestingSettings {
+				cliff: 0,
+				duration: 100,
+				threshold: Some(threshold),
+			},
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			// Mints up to the threshold do not vest
+			assert_ok!(BondingPallet::mint_into(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				0,
+				ACCOUNT_00,
+				threshold,
+				ONE_HUNDRED_KILT,
+				1
+			));
+			assert!(!VestingSchedules::<Test>::contains_key(
+				DEFAULT_BONDED_CURRENCY_ID,
+				ACCOUNT_00
+			));
+
+			assert_ok!(BondingPallet::mint_into(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				0,
+				ACCOUNT_00,
+				threshold + 1,
+				ONE_HUNDRED_KILT,
+				1
+			));
+			assert_eq!(
+				VestingSchedules::<Test>::get(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00).into_inner(),
+				vec![VestingSchedule {
+					locked: threshold + 1,
+					start: System::block_number(),
+					duration: 100,
+				}]
+			);
+
+			// Only the tokens of the second mint are frozen. Accounts with frozen
+			// balances must also retain the minimum balance.
+			assert_eq!(
+				Assets::reducible_balance(
+					DEFAULT_BONDED_CURRENCY_ID,
+					&ACCOUNT_00,
+					Preservation::Expendable,
+					Fortitude::Polite
+				),
+				threshold - 1
+			);
+		})
+}
+
+#[test]
+fn mint_exceeding_max_vesting_schedules() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let schedule = VestingSchedule {
+		locked: 1,
+		start: 100,
+		duration: 100,
+	};
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, ACCOUNT_00, ONE_HUNDRED_KILT),
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, 10),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+				None,
+				None,
+			),
+		)])
+		.with_vesting_settings(vec![(
+			pool_id.clone(),
+			VestingSettings {
+				cliff: 10,
+				duration: 100,
+				threshold: None,
+			},
+		)])
+		.with_vesting_schedules(vec![(
+			DEFAULT_BONDED_CURRENCY_ID,
+			ACCOUNT_00,
+			vec![schedule; MaxVestingSchedules::get() as usize],
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::mint_into(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					0,
+					ACCOUNT_00,
+					1,
+					ONE_HUNDRED_KILT,
+					1
+				),
+				Error::<Test>::TooManyVestingSchedules
+			);
+		})
+}
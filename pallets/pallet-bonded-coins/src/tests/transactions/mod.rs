@@ -15,14 +15,29 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+mod add_liquidity;
 mod burn_into;
+mod burn_into_remote;
+mod claim_fees;
+mod claim_vested;
 mod create_pool;
 mod finish_destroy;
+mod graduate;
 mod mint_into;
 mod refund_account;
+mod remove_liquidity;
 mod reset_manager;
 mod reset_team;
+mod resolve;
+mod set_graduation;
+mod set_issuer;
 mod set_lock;
+mod set_metadata;
+mod set_resolver;
+mod set_vesting;
 mod start_destroy;
 mod start_refund;
+mod swap_bonded_for_collateral;
+mod swap_collateral_for_bonded;
+mod swap_within_pool;
 mod unlock;
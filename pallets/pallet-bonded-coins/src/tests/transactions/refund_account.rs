@@ -23,10 +23,11 @@ use frame_system::{pallet_prelude::OriginFor, ConsumerLimits, RawOrigin};
 use sp_runtime::TokenError;
 
 use crate::{
+	curves::{lmsr::LMSRParameters, Curve},
 	mock::{runtime::*, *},
 	traits::FreezeAccounts,
-	types::PoolStatus,
-	AccountIdOf, Error, Event, Pools,
+	types::{PoolStatus, VestingSchedule},
+	AccountIdOf, Error, Event, Pools, ResolvedOutcomes, VestingSchedules,
 };
 
 #[test]
@@ -70,6 +71,131 @@ fn refund_account_works() {
 		});
 }
 
+#[test]
+fn refund_account_works_on_resolved_pool() {
+	let currencies = vec![DEFAULT_BONDED_CURRENCY_ID, DEFAULT_BONDED_CURRENCY_ID + 1];
+	let pool_details = generate_pool_details(
+		currencies.clone(),
+		Curve::Lmsr(LMSRParameters {
+			m: Float::from_num(100),
+		}),
+		true,
+		Some(PoolStatus::Refunding),
+		Some(ACCOUNT_00),
+		Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+		Some(ACCOUNT_00),
+		None,
+	);
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	let total_collateral = 10u128.pow(10);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_01, ONE_HUNDRED_KILT), (ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), total_collateral),
+			(currencies[0], ACCOUNT_00, total_collateral * 10),
+			(currencies[1], ACCOUNT_00, total_collateral),
+			(currencies[1], ACCOUNT_01, total_collateral * 3),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			ResolvedOutcomes::<Test>::insert(&pool_id, 1);
+
+			// holders of a losing currency receive nothing
+			assert_ok!(BondingPallet::refund_account(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				ACCOUNT_00,
+				0,
+				2
+			));
+			assert_eq!(Assets::total_balance(currencies[0], &ACCOUNT_00), 0);
+			assert_eq!(Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_00), 0);
+
+			// holders of the winning currency share the collateral at a fixed rate
+			assert_ok!(BondingPallet::refund_account(
+				RawOrigin::Signed(ACCOUNT_01).into(),
+				pool_id.clone(),
+				ACCOUNT_01,
+				1,
+				2
+			));
+			assert_eq!(Assets::total_balance(currencies[1], &ACCOUNT_01), 0);
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_01),
+				total_collateral * 3 / 4
+			);
+
+			assert_ok!(BondingPallet::refund_account(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				ACCOUNT_00,
+				1,
+				2
+			));
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_00),
+				total_collateral / 4
+			);
+
+			// All winning tokens are redeemed, so this should complete the refund
+			System::assert_has_event(Event::<Test>::RefundComplete { id: pool_id }.into());
+		});
+}
+
+#[test]
+fn refund_account_works_on_vesting_tokens() {
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Refunding),
+		Some(ACCOUNT_00),
+		Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+		Some(ACCOUNT_00),
+		None,
+	);
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	let total_collateral = 10u128.pow(10);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_01, ONE_HUNDRED_KILT), (ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), total_collateral),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_01, total_collateral * 10),
+		])
+		.with_vesting_schedules(vec![(
+			DEFAULT_BONDED_CURRENCY_ID,
+			ACCOUNT_01,
+			vec![VestingSchedule {
+				locked: total_collateral * 10,
+				start: 100,
+				duration: 100,
+			}],
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			let origin = RawOrigin::Signed(ACCOUNT_01).into();
+
+			assert_ok!(BondingPallet::refund_account(origin, pool_id.clone(), ACCOUNT_01, 0, 1));
+
+			assert_eq!(Assets::total_balance(DEFAULT_BONDED_CURRENCY_ID, &ACCOUNT_01), 0);
+			assert!(!VestingSchedules::<Test>::contains_key(
+				DEFAULT_BONDED_CURRENCY_ID,
+				ACCOUNT_01
+			));
+
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_01),
+				total_collateral
+			);
+		});
+}
+
 #[test]
 fn refund_account_works_on_frozen() {
 	let pool_details = generate_pool_details(
@@ -0,0 +1,261 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok, traits::fungibles::Inspect};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::PoolStatus,
+	AccountIdOf, AmmPools, Error as BondingPalletErrors, Event as BondingPalletEvents, LiquidityShares,
+};
+
+const COLLATERAL_RESERVE: Balance = 10_000;
+const BONDED_RESERVE: Balance = 5_000;
+const OWNED_SHARES: Balance = 2_000;
+
+#[test]
+fn removes_liquidity() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		None,
+		None,
+		None,
+		None,
+	);
+	let shares = 1_001;
+	// 1_001 * 10_000 / 10_000
+	let expected_collateral = 1_001;
+	// 1_001 * 5_000 / 10_000, rounded down
+	let expected_bonded = 500;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(
+			pool_id.clone(),
+			generate_amm_pool(COLLATERAL_RESERVE, BONDED_RESERVE, COLLATERAL_RESERVE),
+		)])
+		.with_bonded_balance(vec![(
+			DEFAULT_COLLATERAL_CURRENCY_ID,
+			pool_id.clone(),
+			COLLATERAL_RESERVE,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			LiquidityShares::<Test>::insert(&pool_id, ACCOUNT_00, OWNED_SHARES);
+
+			assert_ok!(BondingPallet::remove_liquidity(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				shares,
+				expected_collateral,
+				expected_bonded
+			));
+
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_00),
+				expected_collateral
+			);
+			assert_eq!(
+				Assets::total_balance(DEFAULT_BONDED_CURRENCY_ID, &ACCOUNT_00),
+				expected_bonded
+			);
+			assert_eq!(
+				AmmPools::<Test>::get(&pool_id),
+				Some(generate_amm_pool(
+					COLLATERAL_RESERVE - expected_collateral,
+					BONDED_RESERVE - expected_bonded,
+					COLLATERAL_RESERVE - shares
+				))
+			);
+			assert_eq!(
+				LiquidityShares::<Test>::get(&pool_id, ACCOUNT_00),
+				OWNED_SHARES - shares
+			);
+
+			assert!(events().contains(&BondingPalletEvents::LiquidityRemoved {
+				id: pool_id,
+				who: ACCOUNT_00,
+				collateral_amount: expected_collateral,
+				bonded_amount: expected_bonded,
+				shares,
+			}));
+		});
+}
+
+#[test]
+fn removes_share_entry_when_exhausted() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		None,
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(
+			pool_id.clone(),
+			generate_amm_pool(COLLATERAL_RESERVE, BONDED_RESERVE, COLLATERAL_RESERVE),
+		)])
+		.with_bonded_balance(vec![(
+			DEFAULT_COLLATERAL_CURRENCY_ID,
+			pool_id.clone(),
+			COLLATERAL_RESERVE,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			LiquidityShares::<Test>::insert(&pool_id, ACCOUNT_00, OWNED_SHARES);
+
+			assert_ok!(BondingPallet::remove_liquidity(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				OWNED_SHARES,
+				0,
+				0
+			));
+
+			assert!(!LiquidityShares::<Test>::contains_key(&pool_id, ACCOUNT_00));
+		});
+}
+
+#[test]
+fn fails_with_insufficient_shares() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		None,
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(
+			pool_id.clone(),
+			generate_amm_pool(COLLATERAL_RESERVE, BONDED_RESERVE, COLLATERAL_RESERVE),
+		)])
+		.with_bonded_balance(vec![(
+			DEFAULT_COLLATERAL_CURRENCY_ID,
+			pool_id.clone(),
+			COLLATERAL_RESERVE,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			LiquidityShares::<Test>::insert(&pool_id, ACCOUNT_00, OWNED_SHARES);
+
+			assert_err!(
+				BondingPallet::remove_liquidity(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					OWNED_SHARES + 1,
+					0,
+					0
+				),
+				BondingPalletErrors::<Test>::InsufficientShares
+			);
+
+			assert_err!(
+				BondingPallet::remove_liquidity(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 0, 0, 0),
+				BondingPalletErrors::<Test>::InsufficientShares
+			);
+		});
+}
+
+#[test]
+fn fails_on_slippage() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		None,
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(
+			pool_id.clone(),
+			generate_amm_pool(COLLATERAL_RESERVE, BONDED_RESERVE, COLLATERAL_RESERVE),
+		)])
+		.with_bonded_balance(vec![(
+			DEFAULT_COLLATERAL_CURRENCY_ID,
+			pool_id.clone(),
+			COLLATERAL_RESERVE,
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			LiquidityShares::<Test>::insert(&pool_id, ACCOUNT_00, OWNED_SHARES);
+
+			// withdrawal yields 1_001 collateral and 500 bonded tokens
+			assert_err!(
+				BondingPallet::remove_liquidity(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 1_001, 1_002, 0),
+				BondingPalletErrors::<Test>::Slippage
+			);
+
+			assert_err!(
+				BondingPallet::remove_liquidity(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 1_001, 0, 501),
+				BondingPalletErrors::<Test>::Slippage
+			);
+		});
+}
+
+#[test]
+fn fails_if_not_graduated() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Active),
+		None,
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::remove_liquidity(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 1_000, 0, 0),
+				BondingPalletErrors::<Test>::NotGraduated
+			);
+		});
+}
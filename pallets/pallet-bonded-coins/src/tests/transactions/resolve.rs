@@ -0,0 +1,299 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::traits::BadOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::PoolStatus,
+	AccountIdOf, Error, Event, PoolResolvers, Pools, ResolvedOutcomes,
+};
+
+#[test]
+fn resolve_works() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(currencies[0], ACCOUNT_00, ONE_HUNDRED_KILT),
+			(currencies[1], ACCOUNT_01, ONE_HUNDRED_KILT),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			PoolResolvers::<Test>::insert(&pool_id, ACCOUNT_01);
+
+			assert_ok!(BondingPallet::resolve(
+				RawOrigin::Signed(ACCOUNT_01).into(),
+				pool_id.clone(),
+				1,
+				2
+			));
+
+			let pool = Pools::<Test>::get(&pool_id).unwrap();
+			assert_eq!(pool.state, PoolStatus::Refunding);
+			assert_eq!(ResolvedOutcomes::<Test>::get(&pool_id), Some(1));
+
+			System::assert_last_event(
+				Event::Resolved {
+					id: pool_id,
+					winning_currency_idx: 1,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn force_resolve_works() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(currencies[0], ACCOUNT_00, ONE_HUNDRED_KILT),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			// no resolver is required
+			assert_ok!(BondingPallet::force_resolve(
+				RawOrigin::Root.into(),
+				pool_id.clone(),
+				0,
+				2
+			));
+
+			let pool = Pools::<Test>::get(&pool_id).unwrap();
+			assert_eq!(pool.state, PoolStatus::Refunding);
+			assert_eq!(ResolvedOutcomes::<Test>::get(&pool_id), Some(0));
+		});
+}
+
+#[test]
+fn resolve_fails_if_not_resolver() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(currencies[0], ACCOUNT_00, ONE_HUNDRED_KILT),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			// without a resolver, not even the manager can resolve the pool
+			assert_err!(
+				BondingPallet::resolve(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 0, 2),
+				Error::<Test>::NoPermission
+			);
+
+			PoolResolvers::<Test>::insert(&pool_id, ACCOUNT_01);
+
+			assert_err!(
+				BondingPallet::resolve(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 0, 2),
+				Error::<Test>::NoPermission
+			);
+
+			assert_err!(
+				BondingPallet::force_resolve(RawOrigin::Signed(ACCOUNT_01).into(), pool_id, 0, 2),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn resolve_fails_with_invalid_index() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(currencies[0], ACCOUNT_00, ONE_HUNDRED_KILT),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::force_resolve(RawOrigin::Root.into(), pool_id.clone(), 2, 2),
+				Error::<Test>::IndexOutOfBounds
+			);
+
+			assert_err!(
+				BondingPallet::force_resolve(RawOrigin::Root.into(), pool_id, 0, 1),
+				Error::<Test>::CurrencyCount
+			);
+		});
+}
+
+#[test]
+fn resolve_fails_without_winning_supply() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(currencies[0], ACCOUNT_00, ONE_HUNDRED_KILT),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			// nobody holds the winning currency, so the collateral would be stuck
+			assert_err!(
+				BondingPallet::force_resolve(RawOrigin::Root.into(), pool_id, 1, 2),
+				Error::<Test>::NothingToRefund
+			);
+		});
+}
+
+#[test]
+fn resolve_fails_if_not_resolvable() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(currencies[0], ACCOUNT_00, ONE_HUNDRED_KILT),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_linear_bonding_curve(),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::force_resolve(RawOrigin::Root.into(), pool_id, 0, 2),
+				Error::<Test>::NotResolvable
+			);
+		});
+}
+
+#[test]
+fn resolve_fails_if_pool_not_live() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(currencies[0], ACCOUNT_00, ONE_HUNDRED_KILT),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				Some(PoolStatus::Refunding),
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::force_resolve(RawOrigin::Root.into(), pool_id, 0, 2),
+				Error::<Test>::PoolNotLive
+			);
+		});
+}
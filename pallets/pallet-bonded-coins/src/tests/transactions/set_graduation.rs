@@ -0,0 +1,212 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::{GraduationSettings, PoolStatus},
+	AccountIdOf, Error as BondingPalletErrors, Event as BondingPalletEvents, PoolGraduationSettings,
+};
+
+fn settings() -> GraduationSettings<Balance> {
+	GraduationSettings {
+		collateral_threshold: 10_000,
+	}
+}
+
+#[test]
+fn manager_sets_graduation() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::set_graduation(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				Some(settings())
+			));
+
+			assert_eq!(PoolGraduationSettings::<Test>::get(&pool_id), Some(settings()));
+			assert!(events().contains(&BondingPalletEvents::GraduationSettingsUpdated {
+				id: pool_id,
+				settings: Some(settings())
+			}));
+		});
+}
+
+#[test]
+fn manager_removes_graduation() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			PoolGraduationSettings::<Test>::insert(&pool_id, settings());
+
+			assert_ok!(BondingPallet::set_graduation(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				None
+			));
+
+			assert!(PoolGraduationSettings::<Test>::get(&pool_id).is_none());
+			assert!(events().contains(&BondingPalletEvents::GraduationSettingsUpdated {
+				id: pool_id,
+				settings: None
+			}));
+		});
+}
+
+#[test]
+fn fails_if_not_manager() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		Some(ACCOUNT_01),
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			// the owner is not privileged to configure graduation
+			assert_err!(
+				BondingPallet::set_graduation(RawOrigin::Signed(ACCOUNT_01).into(), pool_id.clone(), Some(settings())),
+				BondingPalletErrors::<Test>::NoPermission
+			);
+
+			assert!(PoolGraduationSettings::<Test>::get(&pool_id).is_none());
+		});
+}
+
+#[test]
+fn fails_if_pool_not_live() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Refunding),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_graduation(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), Some(settings())),
+				BondingPalletErrors::<Test>::PoolNotLive
+			);
+		});
+}
+
+#[test]
+fn fails_for_multiple_currencies() {
+	let currencies = vec![DEFAULT_BONDED_CURRENCY_ID, DEFAULT_BONDED_CURRENCY_ID + 1];
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+	let pool_details = generate_pool_details(
+		currencies,
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_graduation(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), Some(settings())),
+				BondingPalletErrors::<Test>::InvalidInput
+			);
+		});
+}
+
+#[test]
+fn fails_for_zero_settings() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_graduation(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					Some(GraduationSettings {
+						collateral_threshold: 0
+					})
+				),
+				BondingPalletErrors::<Test>::InvalidInput
+			);
+		});
+}
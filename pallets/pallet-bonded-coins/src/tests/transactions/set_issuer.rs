@@ -0,0 +1,155 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok, traits::fungible::InspectHold};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::PoolIssuer,
+	AccountIdOf, Error, Event, PoolDetailsOf, PoolIssuerOf, PoolIssuers, Pools,
+};
+
+fn pool_with_owner(owner: AccountId) -> PoolDetailsOf<Test> {
+	generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		None,
+		None,
+		None,
+		Some(owner),
+		None,
+	)
+}
+
+#[test]
+fn set_issuer_works() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_deposit = BondingPallet::calculate_pool_deposit(1u32);
+	let issuer: PoolIssuerOf<Test> = PoolIssuer {
+		identifier: ACCOUNT_00,
+		credential_id: VALID_CREDENTIAL_ID,
+	};
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_with_owner(ACCOUNT_00))])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::set_issuer(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				Some(issuer.clone())
+			));
+
+			assert_eq!(PoolIssuers::<Test>::get(&pool_id), Some(issuer.clone()));
+			System::assert_last_event(
+				Event::IssuerUpdated {
+					id: pool_id.clone(),
+					issuer: Some(issuer),
+				}
+				.into(),
+			);
+
+			let expected_deposit = pool_deposit + MetadataDeposit::get();
+			assert_eq!(Pools::<Test>::get(&pool_id).unwrap().deposit, expected_deposit);
+			assert_eq!(
+				<Test as crate::Config>::DepositCurrency::total_balance_on_hold(&ACCOUNT_00),
+				expected_deposit
+			);
+
+			// removing the link releases the deposit
+			assert_ok!(BondingPallet::set_issuer(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				None
+			));
+
+			assert_eq!(PoolIssuers::<Test>::get(&pool_id), None);
+			assert_eq!(
+				<Test as crate::Config>::DepositCurrency::total_balance_on_hold(&ACCOUNT_00),
+				pool_deposit
+			);
+			System::assert_last_event(
+				Event::IssuerUpdated {
+					id: pool_id,
+					issuer: None,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn set_issuer_fails_if_not_verified() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_with_owner(ACCOUNT_00))])
+		.build_and_execute_with_sanity_tests(|| {
+			// the owner does not control the identity
+			assert_err!(
+				BondingPallet::set_issuer(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					Some(PoolIssuer {
+						identifier: ACCOUNT_01,
+						credential_id: VALID_CREDENTIAL_ID,
+					})
+				),
+				Error::<Test>::IssuerNotVerified
+			);
+
+			// the credential is invalid
+			assert_err!(
+				BondingPallet::set_issuer(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id,
+					Some(PoolIssuer {
+						identifier: ACCOUNT_00,
+						credential_id: Hash::repeat_byte(2),
+					})
+				),
+				Error::<Test>::IssuerNotVerified
+			);
+		});
+}
+
+#[test]
+fn set_issuer_fails_if_not_owner() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT), (ACCOUNT_01, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_with_owner(ACCOUNT_00))])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_issuer(
+					RawOrigin::Signed(ACCOUNT_01).into(),
+					pool_id,
+					Some(PoolIssuer {
+						identifier: ACCOUNT_01,
+						credential_id: VALID_CREDENTIAL_ID,
+					})
+				),
+				Error::<Test>::NoPermission
+			);
+		});
+}
@@ -0,0 +1,220 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok, traits::fungible::InspectHold, BoundedVec};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::{PoolMetadata, PoolStatus},
+	AccountIdOf, Error, Event, Metadata, PoolMetadataOf, Pools,
+};
+
+fn metadata() -> PoolMetadataOf<Test> {
+	PoolMetadata {
+		description: Some(BoundedVec::truncate_from(b"A pool for testing".to_vec())),
+		uri: Some(BoundedVec::truncate_from(b"https://kilt.io".to_vec())),
+		logo_hash: Some(Hash::repeat_byte(2)),
+	}
+}
+
+#[test]
+fn set_metadata_works() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_deposit = BondingPallet::calculate_pool_deposit(1u32);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				None,
+				Some(ACCOUNT_00),
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::set_metadata(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				Some(metadata())
+			));
+
+			assert_eq!(Metadata::<Test>::get(&pool_id), Some(metadata()));
+			System::assert_last_event(
+				Event::MetadataUpdated {
+					id: pool_id.clone(),
+					metadata: Some(metadata()),
+				}
+				.into(),
+			);
+
+			// the deposit for the metadata is added to the deposit of the pool
+			let expected_deposit = pool_deposit + MetadataDeposit::get();
+			assert_eq!(Pools::<Test>::get(&pool_id).unwrap().deposit, expected_deposit);
+			assert_eq!(
+				<Test as crate::Config>::DepositCurrency::total_balance_on_hold(&ACCOUNT_00),
+				expected_deposit
+			);
+
+			// updating the metadata does not take another deposit
+			let updated_metadata = PoolMetadataOf::<Test> {
+				logo_hash: None,
+				..metadata()
+			};
+			assert_ok!(BondingPallet::set_metadata(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				Some(updated_metadata.clone())
+			));
+
+			assert_eq!(Metadata::<Test>::get(&pool_id), Some(updated_metadata));
+			assert_eq!(
+				<Test as crate::Config>::DepositCurrency::total_balance_on_hold(&ACCOUNT_00),
+				expected_deposit
+			);
+
+			// clearing the metadata releases the deposit
+			assert_ok!(BondingPallet::set_metadata(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				None
+			));
+
+			assert_eq!(Metadata::<Test>::get(&pool_id), None);
+			assert_eq!(Pools::<Test>::get(&pool_id).unwrap().deposit, pool_deposit);
+			assert_eq!(
+				<Test as crate::Config>::DepositCurrency::total_balance_on_hold(&ACCOUNT_00),
+				pool_deposit
+			);
+			System::assert_last_event(
+				Event::MetadataUpdated {
+					id: pool_id,
+					metadata: None,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn metadata_deposit_is_released_on_destroy() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				Some(ACCOUNT_00),
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::set_metadata(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				Some(metadata())
+			));
+
+			assert_ok!(BondingPallet::start_destroy(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				1
+			));
+			assert_ok!(BondingPallet::finish_destroy(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				1
+			));
+
+			assert_eq!(Metadata::<Test>::get(&pool_id), None);
+			assert_eq!(
+				<Test as crate::Config>::DepositCurrency::total_balance_on_hold(&ACCOUNT_00),
+				0
+			);
+		});
+}
+
+#[test]
+fn set_metadata_fails_if_not_owner() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT), (ACCOUNT_01, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				None,
+				// the manager is not allowed to set the metadata
+				Some(ACCOUNT_01),
+				None,
+				Some(ACCOUNT_00),
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_metadata(RawOrigin::Signed(ACCOUNT_01).into(), pool_id, Some(metadata())),
+				Error::<Test>::NoPermission
+			);
+		});
+}
+
+#[test]
+fn set_metadata_fails_if_pool_not_live() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				vec![DEFAULT_BONDED_CURRENCY_ID],
+				get_linear_bonding_curve(),
+				true,
+				Some(PoolStatus::Refunding),
+				None,
+				None,
+				Some(ACCOUNT_00),
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_metadata(RawOrigin::Signed(ACCOUNT_00).into(), pool_id, Some(metadata())),
+				Error::<Test>::PoolNotLive
+			);
+		});
+}
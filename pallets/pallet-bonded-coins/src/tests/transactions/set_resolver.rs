@@ -0,0 +1,215 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::PoolStatus,
+	AccountIdOf, Error, Event, PoolResolvers,
+};
+
+#[test]
+fn set_resolver_works() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::set_resolver(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				ACCOUNT_01
+			));
+
+			assert_eq!(PoolResolvers::<Test>::get(&pool_id), Some(ACCOUNT_01));
+			System::assert_last_event(
+				Event::ResolverSet {
+					id: pool_id,
+					resolver: ACCOUNT_01,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn set_resolver_fails_if_already_set() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::set_resolver(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				ACCOUNT_01
+			));
+
+			// the resolver cannot be replaced, not even by the manager
+			assert_err!(
+				BondingPallet::set_resolver(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), ACCOUNT_00),
+				Error::<Test>::ResolverAlreadySet
+			);
+
+			assert_eq!(PoolResolvers::<Test>::get(&pool_id), Some(ACCOUNT_01));
+		});
+}
+
+#[test]
+fn set_resolver_fails_if_not_manager() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_resolver(RawOrigin::Signed(ACCOUNT_01).into(), pool_id, ACCOUNT_01),
+				Error::<Test>::NoPermission
+			);
+		});
+}
+
+#[test]
+fn set_resolver_fails_if_not_resolvable() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+	let single_currency_pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID + 2]);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![
+			(
+				pool_id.clone(),
+				generate_pool_details(
+					currencies,
+					get_linear_bonding_curve(),
+					true,
+					None,
+					Some(ACCOUNT_00),
+					None,
+					None,
+					None,
+				),
+			),
+			(
+				single_currency_pool_id.clone(),
+				generate_pool_details(
+					vec![DEFAULT_BONDED_CURRENCY_ID + 2],
+					get_lmsr_bonding_curve(100),
+					true,
+					None,
+					Some(ACCOUNT_00),
+					None,
+					None,
+					None,
+				),
+			),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			// only LMSR curves can be resolved
+			assert_err!(
+				BondingPallet::set_resolver(RawOrigin::Signed(ACCOUNT_00).into(), pool_id, ACCOUNT_01),
+				Error::<Test>::NotResolvable
+			);
+
+			// a single currency has no alternative outcome
+			assert_err!(
+				BondingPallet::set_resolver(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					single_currency_pool_id,
+					ACCOUNT_01
+				),
+				Error::<Test>::NotResolvable
+			);
+		});
+}
+
+#[test]
+fn set_resolver_fails_if_pool_not_live() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies,
+				get_lmsr_bonding_curve(100),
+				true,
+				Some(PoolStatus::Refunding),
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_resolver(RawOrigin::Signed(ACCOUNT_00).into(), pool_id, ACCOUNT_01),
+				Error::<Test>::PoolNotLive
+			);
+		});
+}
@@ -0,0 +1,186 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::{PoolStatus, VestingSettings},
+	AccountIdOf, Error as BondingPalletErrors, Event as BondingPalletEvents, PoolVestingSettings,
+};
+
+fn settings() -> VestingSettings<u64, Balance> {
+	VestingSettings {
+		cliff: 100,
+		duration: 1_000,
+		threshold: Some(10_000),
+	}
+}
+
+#[test]
+fn manager_sets_vesting() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::set_vesting(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				Some(settings())
+			));
+
+			assert_eq!(PoolVestingSettings::<Test>::get(&pool_id), Some(settings()));
+			assert!(events().contains(&BondingPalletEvents::VestingSettingsUpdated {
+				id: pool_id,
+				settings: Some(settings())
+			}));
+		});
+}
+
+#[test]
+fn manager_removes_vesting() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_vesting_settings(vec![(pool_id.clone(), settings())])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::set_vesting(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				None
+			));
+
+			assert_eq!(PoolVestingSettings::<Test>::get(&pool_id), None);
+			assert!(events().contains(&BondingPalletEvents::VestingSettingsUpdated {
+				id: pool_id,
+				settings: None
+			}));
+		});
+}
+
+#[test]
+fn fails_if_not_manager() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		Some(ACCOUNT_01),
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT), (ACCOUNT_01, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			// not even the owner may change the settings
+			assert_err!(
+				BondingPallet::set_vesting(RawOrigin::Signed(ACCOUNT_01).into(), pool_id.clone(), Some(settings())),
+				BondingPalletErrors::<Test>::NoPermission
+			);
+		});
+}
+
+#[test]
+fn fails_if_pool_not_live() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Refunding),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_vesting(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), Some(settings())),
+				BondingPalletErrors::<Test>::PoolNotLive
+			);
+		});
+}
+
+#[test]
+fn fails_without_cliff_and_duration() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::set_vesting(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					Some(VestingSettings {
+						cliff: 0,
+						duration: 0,
+						threshold: None,
+					})
+				),
+				BondingPalletErrors::<Test>::InvalidInput
+			);
+		});
+}
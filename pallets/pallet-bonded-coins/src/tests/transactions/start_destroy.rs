@@ -22,7 +22,7 @@ use sp_runtime::traits::BadOrigin;
 use crate::{
 	mock::{runtime::*, *},
 	types::PoolStatus,
-	AccountIdOf, Error, Event, Pools,
+	AccountIdOf, AmmPools, Error, Event, LiquidityShares, Pools,
 };
 
 #[test]
@@ -164,6 +164,77 @@ fn start_destroy_works_when_refunding() {
 		});
 }
 
+#[test]
+fn start_destroy_works_when_graduated() {
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		Some(ACCOUNT_00),
+		Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+		Some(ACCOUNT_00),
+		None,
+	);
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(pool_id.clone(), generate_amm_pool(10_000, 5_000, 10_000))])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), 10_000)])
+		.build_and_execute_with_sanity_tests(|| {
+			let origin = RawOrigin::Signed(ACCOUNT_00).into();
+
+			assert_ok!(BondingPallet::start_destroy(origin, pool_id.clone(), 1));
+
+			// Verify that the pool state has been updated to destroying and the AMM removed
+			let updated_pool = Pools::<Test>::get(&pool_id).unwrap();
+			assert_eq!(updated_pool.state, PoolStatus::Destroying);
+			assert!(AmmPools::<Test>::get(&pool_id).is_none());
+
+			// Verify the expected event has been deposited
+			System::assert_has_event(Event::DestructionStarted { id: pool_id }.into());
+		});
+}
+
+#[test]
+fn start_destroy_fails_when_liquidity_provided() {
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		Some(ACCOUNT_00),
+		Some(DEFAULT_COLLATERAL_CURRENCY_ID),
+		Some(ACCOUNT_00),
+		None,
+	);
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+
+	ExtBuilder::default()
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(pool_id.clone(), generate_amm_pool(10_000, 5_000, 10_000))])
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), 10_000)])
+		.build_and_execute_with_sanity_tests(|| {
+			LiquidityShares::<Test>::insert(&pool_id, ACCOUNT_01, 1_000);
+
+			assert_err!(
+				BondingPallet::start_destroy(RawOrigin::Signed(ACCOUNT_00).into(), pool_id.clone(), 1),
+				Error::<Test>::LivePool
+			);
+
+			// the collateral of liquidity providers is not refunded, so forcing does not help
+			assert_err!(
+				BondingPallet::force_start_destroy(RawOrigin::Root.into(), pool_id.clone(), 1),
+				Error::<Test>::LivePool
+			);
+		});
+}
+
 #[test]
 fn start_destroy_fails_when_pool_has_active_currencies() {
 	let pool_details = generate_pool_details(
@@ -22,7 +22,7 @@ use sp_runtime::traits::BadOrigin;
 use crate::{
 	mock::{runtime::*, *},
 	types::PoolStatus,
-	AccountIdOf, Error, Event, Pools,
+	AccountIdOf, AccruedFees, Error, Event, Pools,
 };
 
 #[test]
@@ -325,6 +325,42 @@ fn start_refund_fails_when_no_collateral() {
 		});
 }
 
+#[test]
+fn start_refund_fails_when_only_fees_held() {
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Active),
+		Some(ACCOUNT_00),
+		None,
+		None,
+		None,
+	);
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let currency_count = 10;
+	let accrued_fees = 1_000;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, u128::MAX / 10),
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), accrued_fees),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			AccruedFees::<Test>::insert(&pool_id, accrued_fees);
+			let origin = RawOrigin::Signed(ACCOUNT_00).into();
+
+			// Accrued fees do not back the bonded currencies and are not refunded
+			assert_err!(
+				BondingPallet::start_refund(origin, pool_id, currency_count),
+				Error::<Test>::NothingToRefund
+			);
+		});
+}
+
 #[test]
 fn pool_does_not_exist() {
 	let pool_details = generate_pool_details(
@@ -0,0 +1,250 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{
+	assert_err, assert_ok,
+	traits::{
+		fungibles::Inspect,
+		tokens::{Fortitude, Preservation},
+	},
+};
+use frame_system::RawOrigin;
+use sp_runtime::TokenError;
+
+use crate::{
+	mock::{runtime::*, *},
+	types::PoolStatus,
+	AccountIdOf, AmmPools, Error as BondingPalletErrors, Event as BondingPalletEvents,
+};
+
+const RESERVE: Balance = 10_000;
+const BONDED_BALANCE: Balance = 5_000;
+
+#[test]
+fn swaps_bonded_for_collateral() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		None,
+		None,
+		None,
+		None,
+	);
+	let amount_in = 1_000;
+	// 10_000 * 1_000 / (10_000 + 1_000), rounded down
+	let expected_out = 909;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(pool_id.clone(), generate_amm_pool(RESERVE, RESERVE, RESERVE))])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), RESERVE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, BONDED_BALANCE),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::swap_bonded_for_collateral(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				amount_in,
+				expected_out
+			));
+
+			assert_eq!(
+				Assets::total_balance(DEFAULT_BONDED_CURRENCY_ID, &ACCOUNT_00),
+				BONDED_BALANCE - amount_in
+			);
+			assert_eq!(
+				Assets::total_issuance(DEFAULT_BONDED_CURRENCY_ID),
+				BONDED_BALANCE - amount_in
+			);
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_00),
+				expected_out
+			);
+			assert_eq!(
+				AmmPools::<Test>::get(&pool_id),
+				Some(generate_amm_pool(RESERVE - expected_out, RESERVE + amount_in, RESERVE))
+			);
+
+			assert!(events().contains(&BondingPalletEvents::SwappedBondedForCollateral {
+				id: pool_id,
+				who: ACCOUNT_00,
+				amount_in,
+				amount_out: expected_out,
+			}));
+		});
+}
+
+#[test]
+fn freezes_remaining_non_transferable_tokens() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		false,
+		Some(PoolStatus::Graduated),
+		None,
+		None,
+		None,
+		None,
+	);
+	let amount_in = 1_000;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(pool_id.clone(), generate_amm_pool(RESERVE, RESERVE, RESERVE))])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), RESERVE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, BONDED_BALANCE),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::swap_bonded_for_collateral(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				amount_in,
+				1
+			));
+
+			assert_eq!(
+				Assets::total_balance(DEFAULT_BONDED_CURRENCY_ID, &ACCOUNT_00),
+				BONDED_BALANCE - amount_in
+			);
+			// The remaining balance is frozen again
+			assert_eq!(
+				Assets::reducible_balance(
+					DEFAULT_BONDED_CURRENCY_ID,
+					&ACCOUNT_00,
+					Preservation::Expendable,
+					Fortitude::Polite
+				),
+				0
+			);
+		});
+}
+
+#[test]
+fn fails_with_insufficient_balance() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		None,
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(pool_id.clone(), generate_amm_pool(RESERVE, RESERVE, RESERVE))])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), RESERVE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, BONDED_BALANCE),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::swap_bonded_for_collateral(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					BONDED_BALANCE + 1,
+					0
+				),
+				TokenError::FundsUnavailable
+			);
+		});
+}
+
+#[test]
+fn fails_on_slippage() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Graduated),
+		None,
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_amm_pools(vec![(pool_id.clone(), generate_amm_pool(RESERVE, RESERVE, RESERVE))])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), RESERVE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, BONDED_BALANCE),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::swap_bonded_for_collateral(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					1_000,
+					910
+				),
+				BondingPalletErrors::<Test>::Slippage
+			);
+		});
+}
+
+#[test]
+fn fails_if_not_graduated() {
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&[DEFAULT_BONDED_CURRENCY_ID]);
+	let pool_details = generate_pool_details(
+		vec![DEFAULT_BONDED_CURRENCY_ID],
+		get_linear_bonding_curve(),
+		true,
+		Some(PoolStatus::Active),
+		None,
+		None,
+		None,
+		None,
+	);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_pools(vec![(pool_id.clone(), pool_details)])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), RESERVE),
+			(DEFAULT_BONDED_CURRENCY_ID, ACCOUNT_00, BONDED_BALANCE),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::swap_bonded_for_collateral(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					1_000,
+					0
+				),
+				BondingPalletErrors::<Test>::NotGraduated
+			);
+		});
+}
@@ -0,0 +1,456 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_err, assert_ok, traits::fungibles::Inspect};
+use frame_system::RawOrigin;
+use sp_runtime::{assert_eq_error_rate, Perbill, TokenError};
+
+use crate::{
+	mock::{runtime::*, *},
+	types::{Locks, PoolFees, PoolStatus, VestingSettings},
+	AccountIdOf, AccruedFees, Error as BondingPalletErrors, Event as BondingPalletEvents, VestingSchedules,
+};
+
+// one coin in the bonded denomination
+const ONE_COIN: u128 = 10_000_000_000;
+
+#[test]
+fn swap_on_linear_curve() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	let initial_supply = 100 * ONE_COIN;
+	let amount_in = 50 * ONE_COIN;
+	let collateral = mocks_curve_get_collateral_at_supply(initial_supply);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), collateral),
+			(currencies[0], ACCOUNT_00, initial_supply),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies.clone(),
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::swap_within_pool(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				0,
+				1,
+				amount_in,
+				amount_in - 1,
+				2
+			));
+
+			// The price depends on the total supply only, which remains unchanged.
+			let amount_out = Assets::total_balance(currencies[1], &ACCOUNT_00);
+			assert_eq_error_rate!(amount_out, amount_in, 1);
+			assert_eq!(
+				Assets::total_balance(currencies[0], &ACCOUNT_00),
+				initial_supply - amount_in
+			);
+
+			// No collateral is moved.
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &pool_id),
+				collateral
+			);
+			assert_eq!(Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &ACCOUNT_00), 0);
+
+			assert!(events().contains(&BondingPalletEvents::SwappedWithinPool {
+				id: pool_id,
+				who: ACCOUNT_00,
+				from: currencies[0],
+				to: currencies[1],
+				amount_in,
+				amount_out
+			}));
+		});
+}
+
+#[test]
+fn swap_on_lmsr_curve() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	let m = 100;
+	let initial_supply = 200 * ONE_COIN;
+	let amount_in = 10 * ONE_COIN;
+	// m * ln(1 + e^((200 - 0) / m) * (1 - e^(-10 / m)))
+	let expected_amount_out = 53_248_632_180;
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), ONE_HUNDRED_KILT),
+			(currencies[0], ACCOUNT_00, initial_supply),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies.clone(),
+				get_lmsr_bonding_curve(m),
+				true,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::swap_within_pool(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				0,
+				1,
+				amount_in,
+				0,
+				2
+			));
+
+			// The unpopular outcome is cheaper, so more tokens are received.
+			assert_eq_error_rate!(
+				Assets::total_balance(currencies[1], &ACCOUNT_00),
+				expected_amount_out,
+				MAX_ERROR.mul_floor(expected_amount_out)
+			);
+			assert_eq!(
+				Assets::total_balance(currencies[0], &ACCOUNT_00),
+				initial_supply - amount_in
+			);
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &pool_id),
+				ONE_HUNDRED_KILT
+			);
+		});
+}
+
+#[test]
+fn swap_with_fees() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	let initial_supply = 100 * ONE_COIN;
+	let amount_in = 50 * ONE_COIN;
+	let collateral = mocks_curve_get_collateral_at_supply(initial_supply);
+	let fees = PoolFees {
+		mint: Perbill::zero(),
+		burn: Perbill::from_percent(10),
+	};
+	let protocol_fee_rate = Perbill::from_percent(1);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), collateral),
+			(currencies[0], ACCOUNT_00, initial_supply),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies.clone(),
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+		)])
+		.with_pool_fees(vec![(pool_id.clone(), fees)])
+		.build_and_execute_with_sanity_tests(|| {
+			ProtocolFee::set(&protocol_fee_rate);
+
+			// Without fees, the swap would be one to one.
+			assert_err!(
+				BondingPallet::swap_within_pool(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					0,
+					1,
+					amount_in,
+					amount_in - 1,
+					2
+				),
+				BondingPalletErrors::<Test>::Slippage
+			);
+
+			assert_ok!(BondingPallet::swap_within_pool(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				0,
+				1,
+				amount_in,
+				0,
+				2
+			));
+
+			let pool_fee = AccruedFees::<Test>::get(&pool_id);
+			let protocol_fee = Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &PROTOCOL_FEE_ACCOUNT);
+			assert!(pool_fee > 0);
+			assert!(protocol_fee > 0);
+
+			// Only the protocol fee leaves the pool.
+			assert_eq!(
+				Assets::total_balance(DEFAULT_COLLATERAL_CURRENCY_ID, &pool_id),
+				collateral - protocol_fee
+			);
+
+			let amount_out = Assets::total_balance(currencies[1], &ACCOUNT_00);
+			assert!(amount_out < amount_in);
+		});
+}
+
+#[test]
+fn swap_by_manager_vests() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	let initial_supply = 100 * ONE_COIN;
+	let amount_in = 50 * ONE_COIN;
+	let collateral = mocks_curve_get_collateral_at_supply(initial_supply);
+	let settings = VestingSettings {
+		cliff: 10,
+		duration: 100,
+		threshold: None,
+	};
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(DEFAULT_COLLATERAL_CURRENCY_ID, pool_id.clone(), collateral),
+			(currencies[0], ACCOUNT_00, initial_supply),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies.clone(),
+				get_linear_bonding_curve(),
+				true,
+				None,
+				Some(ACCOUNT_00),
+				None,
+				None,
+				None,
+			),
+		)])
+		.with_vesting_settings(vec![(pool_id.clone(), settings)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(BondingPallet::swap_within_pool(
+				RawOrigin::Signed(ACCOUNT_00).into(),
+				pool_id.clone(),
+				0,
+				1,
+				amount_in,
+				0,
+				2
+			));
+
+			let amount_out = Assets::total_balance(currencies[1], &ACCOUNT_00);
+			let schedules = VestingSchedules::<Test>::get(currencies[1], ACCOUNT_00);
+			assert_eq!(schedules.len(), 1);
+			assert_eq!(schedules[0].locked, amount_out);
+		});
+}
+
+#[test]
+fn fails_for_same_currency() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(
+				DEFAULT_COLLATERAL_CURRENCY_ID,
+				pool_id.clone(),
+				mocks_curve_get_collateral_at_supply(ONE_COIN),
+			),
+			(currencies[0], ACCOUNT_00, ONE_COIN),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies.clone(),
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::swap_within_pool(RawOrigin::Signed(ACCOUNT_00).into(), pool_id, 0, 0, ONE_COIN, 0, 2),
+				BondingPalletErrors::<Test>::InvalidInput
+			);
+		});
+}
+
+#[test]
+fn fails_with_invalid_index() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(
+				DEFAULT_COLLATERAL_CURRENCY_ID,
+				pool_id.clone(),
+				mocks_curve_get_collateral_at_supply(ONE_COIN),
+			),
+			(currencies[0], ACCOUNT_00, ONE_COIN),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies.clone(),
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::swap_within_pool(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id.clone(),
+					0,
+					2,
+					ONE_COIN,
+					0,
+					2
+				),
+				BondingPalletErrors::<Test>::IndexOutOfBounds
+			);
+
+			assert_err!(
+				BondingPallet::swap_within_pool(RawOrigin::Signed(ACCOUNT_00).into(), pool_id, 2, 0, ONE_COIN, 0, 2),
+				BondingPalletErrors::<Test>::IndexOutOfBounds
+			);
+		});
+}
+
+#[test]
+fn fails_on_locked_pool() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(
+				DEFAULT_COLLATERAL_CURRENCY_ID,
+				pool_id.clone(),
+				mocks_curve_get_collateral_at_supply(ONE_COIN),
+			),
+			(currencies[0], ACCOUNT_00, ONE_COIN),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies.clone(),
+				get_linear_bonding_curve(),
+				true,
+				Some(PoolStatus::Locked(Locks {
+					allow_mint: false,
+					allow_burn: true,
+				})),
+				None,
+				None,
+				None,
+				None,
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::swap_within_pool(RawOrigin::Signed(ACCOUNT_00).into(), pool_id, 0, 1, ONE_COIN, 0, 2),
+				BondingPalletErrors::<Test>::NoPermission
+			);
+		});
+}
+
+#[test]
+fn fails_below_minimum() {
+	let currencies = two_bonded_currencies();
+	let pool_id: AccountIdOf<Test> = calculate_pool_id(&currencies);
+
+	ExtBuilder::default()
+		.with_native_balances(vec![(ACCOUNT_00, ONE_HUNDRED_KILT)])
+		.with_collaterals(vec![DEFAULT_COLLATERAL_CURRENCY_ID])
+		.with_bonded_balance(vec![
+			(
+				DEFAULT_COLLATERAL_CURRENCY_ID,
+				pool_id.clone(),
+				mocks_curve_get_collateral_at_supply(ONE_COIN),
+			),
+			(currencies[0], ACCOUNT_00, ONE_COIN),
+		])
+		.with_pools(vec![(
+			pool_id.clone(),
+			generate_pool_details(
+				currencies.clone(),
+				get_linear_bonding_curve(),
+				true,
+				None,
+				None,
+				None,
+				None,
+				Some(ONE_COIN),
+			),
+		)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				BondingPallet::swap_within_pool(
+					RawOrigin::Signed(ACCOUNT_00).into(),
+					pool_id,
+					0,
+					1,
+					ONE_COIN - 1,
+					0,
+					2
+				),
+				TokenError::BelowMinimum
+			);
+		});
+}
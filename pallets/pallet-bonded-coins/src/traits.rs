@@ -15,9 +15,10 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
-use frame_support::{dispatch::DispatchResult, traits::fungibles::roles::Inspect};
+use frame_support::{dispatch::DispatchResult, traits::fungibles::roles::Inspect, Parameter};
 use frame_system::RawOrigin;
 use pallet_assets::{Config as AssetConfig, Pallet as AssetsPallet};
+use parity_scale_codec::MaxEncodedLen;
 use sp_runtime::{traits::StaticLookup, DispatchError};
 use sp_std::{fmt::Debug, prelude::*, vec::Vec};
 
@@ -121,3 +122,78 @@ pub trait NextAssetIds<T: Config> {
 	/// Get the next `n` asset ids.
 	fn try_get(n: u32) -> Result<Vec<FungiblesAssetIdOf<T>>, Self::Error>;
 }
+
+/// A trait for querying the prices of bonded currencies, e.g., to value bonded
+/// tokens used as collateral in other pallets. Prices are denominated in the
+/// smallest unit of the collateral currency of the pool per whole coin of the
+/// bonded currency.
+pub trait BondedPriceOracle<PoolId, Balance, BlockNumber> {
+	/// Get the current spot price of the currency at `currency_idx` in the
+	/// pool `pool_id`.
+	fn spot_price(pool_id: &PoolId, currency_idx: u32) -> Result<Balance, DispatchError>;
+
+	/// Get the time-weighted average price of the currency at `currency_idx`
+	/// in the pool `pool_id` over at least the last `window` blocks.
+	fn twap(pool_id: &PoolId, currency_idx: u32, window: BlockNumber) -> Result<Balance, DispatchError>;
+}
+
+/// A trait for sending collateral to an account on another chain.
+pub trait RemoteCollateralTransfer<AccountId, AssetId, Balance> {
+	/// The type identifying the beneficiary on the remote chain.
+	type Beneficiary: Parameter;
+
+	/// Send `amount` of the collateral `asset_id` from the account `who` to
+	/// the `beneficiary` on another chain.
+	fn transfer(who: &AccountId, asset_id: AssetId, amount: Balance, beneficiary: Self::Beneficiary) -> DispatchResult;
+}
+
+/// Implementation for runtimes which do not support sending collateral to
+/// other chains.
+impl<AccountId, AssetId, Balance> RemoteCollateralTransfer<AccountId, AssetId, Balance> for () {
+	type Beneficiary = ();
+
+	fn transfer(_who: &AccountId, _asset_id: AssetId, _amount: Balance, _beneficiary: ()) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+}
+
+/// A trait for verifying that an account controls an identity, e.g., a DID,
+/// which is backed by a valid credential about a pool.
+pub trait VerifyIssuer<AccountId, PoolId> {
+	/// The type identifying an issuer, e.g., a DID.
+	type Identifier: Parameter + MaxEncodedLen;
+
+	/// The type referencing the credential backing an identity.
+	type CredentialId: Parameter + MaxEncodedLen;
+
+	/// Checks whether the account `who` controls `identifier`, and whether the
+	/// credential `credential_id` of `identifier` is valid for the pool
+	/// `pool_id`.
+	fn is_verified(
+		who: &AccountId,
+		pool_id: &PoolId,
+		identifier: &Self::Identifier,
+		credential_id: &Self::CredentialId,
+	) -> bool;
+
+	/// Sets up an identity controlled by `who`, which is backed by a valid
+	/// credential for the pool `pool_id`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_verified_issuer(who: &AccountId, pool_id: &PoolId) -> (Self::Identifier, Self::CredentialId);
+}
+
+/// Implementation for runtimes which do not support linking pools to
+/// identities.
+impl<AccountId, PoolId> VerifyIssuer<AccountId, PoolId> for () {
+	type Identifier = ();
+	type CredentialId = ();
+
+	fn is_verified(_who: &AccountId, _pool_id: &PoolId, _identifier: &(), _credential_id: &()) -> bool {
+		false
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_verified_issuer(_who: &AccountId, _pool_id: &PoolId) -> ((), ()) {
+		((), ())
+	}
+}
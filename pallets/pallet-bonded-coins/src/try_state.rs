@@ -1,11 +1,19 @@
+use frame_support::traits::Get;
 use frame_support::traits::{
 	fungible::InspectHold,
 	fungibles::{metadata::Inspect as InspectMetadata, roles::Inspect as InspectRoles, Inspect},
 };
-use sp_runtime::{traits::Zero, TryRuntimeError};
+use sp_runtime::{
+	traits::{SaturatedConversion, Saturating, Zero},
+	TryRuntimeError,
+};
 use sp_std::vec::Vec;
 
-use crate::{types::PoolDetails, Config, FungiblesAssetIdOf, Pools};
+use crate::{
+	types::PoolDetails, AccruedFees, AmmPools, CollateralBalanceOf, Config, FungiblesAssetIdOf, FungiblesBalanceOf,
+	LiquidityShares, Metadata, PoolFeeSettings, PoolGraduationSettings, PoolIssuers, PoolResolvers,
+	PoolVestingSettings, Pools, PriceAccumulators, PriceObservations, ResolvedOutcomes, VestingSchedules,
+};
 
 pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	// checked currency ids. Each Currency should only be associated with one pool.
@@ -24,7 +32,7 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 
 		let pool_account = pool_id.clone().into();
 
-		let hold_reason = T::HoldReason::try_from(pool_id)
+		let hold_reason = T::HoldReason::try_from(pool_id.clone())
 			.map_err(|_| TryRuntimeError::Other("Failed to convert pool_id to HoldReason"))
 			.map(T::RuntimeHoldReason::from)?;
 
@@ -36,6 +44,33 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 		assert!(T::Collaterals::asset_exists(collateral.clone()));
 		let collateral_issuance_pool = T::Collaterals::total_balance(collateral, &pool_account);
 
+		// Fee checks
+		let fees = PoolFeeSettings::<T>::get(&pool_id);
+		assert!(fees.mint <= T::MaxPoolFee::get() && fees.burn <= T::MaxPoolFee::get());
+		// accrued fees are held by the pool account
+		assert!(collateral_issuance_pool >= AccruedFees::<T>::get(&pool_id));
+
+		// AMM checks
+		if state.is_graduated() {
+			let amm_pool =
+				AmmPools::<T>::get(&pool_id).ok_or(TryRuntimeError::Other("Graduated pool must have an AMM"))?;
+
+			// the collateral of the AMM and the accrued fees are held by the pool account
+			assert!(
+				collateral_issuance_pool
+					>= amm_pool
+						.collateral_reserve
+						.saturating_add(AccruedFees::<T>::get(&pool_id))
+			);
+
+			// the shares of all liquidity providers are part of the total shares
+			let provided_shares = LiquidityShares::<T>::iter_prefix_values(&pool_id)
+				.fold(CollateralBalanceOf::<T>::zero(), |acc, shares| {
+					acc.saturating_add(shares)
+				});
+			assert!(provided_shares <= amm_pool.total_shares);
+		}
+
 		// Bonded currencies checks
 		bonded_currencies
 			.iter()
@@ -44,10 +79,10 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 				assert!(!checked_currency_ids.contains(currency_id));
 				checked_currency_ids.push(currency_id.clone());
 
-				// if Pool is live or refunding, all underlying assets should be live.
-				// Other states are not checked because there is no trait to gather the
+				// if Pool is live, refunding or graduated, all underlying assets should be
+				// live. Other states are not checked because there is no trait to gather the
 				// information.
-				if state.is_live() || state.is_refunding() {
+				if state.is_live() || state.is_refunding() || state.is_graduated() {
 					assert!(T::Fungibles::asset_exists(currency_id.clone()));
 
 					// the owner and issuer should always be the pool account. Admins and Freezer
@@ -77,5 +112,53 @@ pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 			})?;
 
 		Ok(())
-	})
+	})?;
+
+	// Fee settings and accrued fees must belong to an existing pool.
+	assert!(PoolFeeSettings::<T>::iter_keys().all(|pool_id| Pools::<T>::contains_key(pool_id)));
+	assert!(AccruedFees::<T>::iter_keys().all(|pool_id| Pools::<T>::contains_key(pool_id)));
+	assert!(PoolGraduationSettings::<T>::iter_keys().all(|pool_id| Pools::<T>::contains_key(pool_id)));
+	assert!(PoolVestingSettings::<T>::iter_keys().all(|pool_id| Pools::<T>::contains_key(pool_id)));
+	assert!(PoolResolvers::<T>::iter_keys().all(|pool_id| Pools::<T>::contains_key(pool_id)));
+	assert!(Metadata::<T>::iter_keys().all(|pool_id| Pools::<T>::contains_key(pool_id)));
+	assert!(PoolIssuers::<T>::iter_keys().all(|pool_id| Pools::<T>::contains_key(pool_id)));
+
+	// Resolved outcomes must belong to a pool which is refunding or being
+	// destroyed, and point to one of its currencies.
+	ResolvedOutcomes::<T>::iter().for_each(|(pool_id, winning_currency_idx)| {
+		assert!(Pools::<T>::get(&pool_id).is_some_and(|pool| {
+			(pool.state.is_refunding() || pool.state.is_destroying())
+				&& winning_currency_idx.saturated_into::<usize>() < pool.bonded_currencies.len()
+		}));
+	});
+
+	// Vesting tokens must belong to a pool and be held by the account.
+	VestingSchedules::<T>::iter().for_each(|(currency_id, who, schedules)| {
+		assert!(checked_currency_ids.contains(&currency_id));
+		assert!(!schedules.is_empty());
+
+		let frozen = schedules.iter().fold(FungiblesBalanceOf::<T>::zero(), |acc, schedule| {
+			acc.saturating_add(schedule.locked)
+		});
+		assert!(T::Fungibles::total_balance(currency_id, &who) >= frozen);
+	});
+
+	// Price accumulators and observations must belong to a pool and hold a price
+	// for each of its currencies.
+	let currencies_number = |pool_id: &T::PoolId| Pools::<T>::get(pool_id).map(|pool| pool.bonded_currencies.len());
+	PriceAccumulators::<T>::iter().for_each(|(pool_id, accumulator)| {
+		let number = currencies_number(&pool_id);
+		assert!(number.is_some_and(|n| accumulator.spot_prices.len() == n && accumulator.cumulative_prices.len() == n));
+	});
+	PriceObservations::<T>::iter().for_each(|(pool_id, slot, observation)| {
+		assert!(slot < T::MaxPriceObservations::get());
+		assert!(currencies_number(&pool_id).is_some_and(|n| observation.cumulative_prices.len() == n));
+	});
+
+	// AMMs and liquidity shares must belong to a graduated pool.
+	let is_graduated = |pool_id: &T::PoolId| Pools::<T>::get(pool_id).is_some_and(|pool| pool.state.is_graduated());
+	assert!(AmmPools::<T>::iter_keys().all(|pool_id| is_graduated(&pool_id)));
+	assert!(LiquidityShares::<T>::iter_keys().all(|(pool_id, _)| is_graduated(&pool_id)));
+
+	Ok(())
 }
@@ -17,6 +17,10 @@
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Saturating, Zero},
+	Perbill,
+};
 
 /// Locks applied to a pool.
 #[derive(Default, Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
@@ -38,6 +42,7 @@ pub enum PoolStatus<LockType> {
 	Locked(LockType),
 	Refunding,
 	Destroying,
+	Graduated,
 }
 
 impl<LockType: Default> Default for PoolStatus<LockType> {
@@ -62,6 +67,11 @@ impl<LockType> PoolStatus<LockType> {
 		matches!(self, Self::Refunding)
 	}
 
+	/// Checks if the pool has graduated into a constant-product AMM.
+	pub const fn is_graduated(&self) -> bool {
+		matches!(self, Self::Graduated)
+	}
+
 	/// Freezes the pool with the given locks.
 	pub fn freeze(&mut self, lock: LockType) {
 		*self = Self::Locked(lock);
@@ -76,6 +86,11 @@ impl<LockType> PoolStatus<LockType> {
 	pub fn start_refund(&mut self) {
 		*self = Self::Refunding;
 	}
+
+	/// Graduates the pool into a constant-product AMM.
+	pub fn graduate(&mut self) {
+		*self = Self::Graduated;
+	}
 }
 
 #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug, Default)]
@@ -90,6 +105,140 @@ pub struct BondedCurrenciesSettings<FungiblesBalance> {
 	pub transferable: bool,
 }
 
+/// Fees charged by a pool on the collateral of mint and burn operations.
+#[derive(Default, Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct PoolFees {
+	/// The share of the collateral paid for minting which is charged on top.
+	pub mint: Perbill,
+	/// The share of the collateral released by burning which is withheld.
+	pub burn: Perbill,
+}
+
+/// Conditions under which a pool graduates from its bonding curve into a
+/// constant-product AMM.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct GraduationSettings<CollateralBalance> {
+	/// The collateral reserves required for the pool to graduate.
+	pub collateral_threshold: CollateralBalance,
+}
+
+/// Reserves of the constant-product AMM of a graduated pool.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct AmmPool<CollateralBalance, FungiblesBalance> {
+	/// The collateral held by the AMM. The collateral is held by the pool
+	/// account.
+	pub collateral_reserve: CollateralBalance,
+	/// The bonded currency held by the AMM. This supply is not issued; it is
+	/// minted when bought from the AMM and burnt when sold to it.
+	pub bonded_reserve: FungiblesBalance,
+	/// The total amount of liquidity shares. The shares issued upon
+	/// graduation are not owned by any account and thus permanently locked.
+	pub total_shares: CollateralBalance,
+}
+
+/// Vesting rules applied to bonded tokens minted by privileged accounts or in
+/// large amounts.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct VestingSettings<BlockNumber, FungiblesBalance> {
+	/// The number of blocks after minting during which none of the minted
+	/// tokens vest.
+	pub cliff: BlockNumber,
+	/// The number of blocks after the cliff over which the minted tokens vest
+	/// linearly.
+	pub duration: BlockNumber,
+	/// Mints of more than this amount vest regardless of the minting account.
+	/// If `None`, only mints by the owner or the manager of the pool vest.
+	pub threshold: Option<FungiblesBalance>,
+}
+
+/// Bonded tokens of an account which have not been released yet.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct VestingSchedule<BlockNumber, FungiblesBalance> {
+	/// The amount of tokens which are frozen on the account.
+	pub locked: FungiblesBalance,
+	/// The block from which the locked tokens start vesting.
+	pub start: BlockNumber,
+	/// The number of blocks over which the locked tokens vest linearly from
+	/// `start`.
+	pub duration: BlockNumber,
+}
+
+impl<BlockNumber, FungiblesBalance> VestingSchedule<BlockNumber, FungiblesBalance>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+	FungiblesBalance: AtLeast32BitUnsigned + Copy,
+{
+	/// Returns the amount of tokens which have not vested at block `now`.
+	/// Rounds in favour of the lock.
+	pub fn locked_at(&self, now: BlockNumber) -> FungiblesBalance {
+		if now < self.start {
+			return self.locked;
+		}
+		let elapsed = now.saturating_sub(self.start);
+		if elapsed >= self.duration {
+			return Zero::zero();
+		}
+		let remaining = self.duration.saturating_sub(elapsed);
+		Perbill::from_rational(remaining, self.duration).mul_ceil(self.locked)
+	}
+
+	/// Releases the tokens vested at block `now`, and returns their amount.
+	/// The remaining tokens continue to vest at the same rate.
+	pub fn release(&mut self, now: BlockNumber) -> FungiblesBalance {
+		let locked = self.locked_at(now);
+		let released = self.locked.saturating_sub(locked);
+		if now > self.start {
+			self.duration = self.duration.saturating_sub(now.saturating_sub(self.start));
+			self.start = now;
+		}
+		self.locked = locked;
+		released
+	}
+}
+
+/// Spot and cumulative prices of the bonded currencies of a pool, from which
+/// time-weighted average prices are derived.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct PriceAccumulator<BlockNumber, SpotPrices, CumulativePrices> {
+	/// The block in which the prices were last updated.
+	pub last_update: BlockNumber,
+	/// The spot price of each currency since the last update.
+	pub spot_prices: SpotPrices,
+	/// The sum of the spot prices of each currency, weighted by the number of
+	/// blocks they were valid for, up to the last update.
+	pub cumulative_prices: CumulativePrices,
+}
+
+/// Snapshot of the cumulative prices of a pool.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct PriceObservation<BlockNumber, CumulativePrices> {
+	/// The block in which the snapshot was taken.
+	pub block: BlockNumber,
+	/// The cumulative price of each currency at `block`.
+	pub cumulative_prices: CumulativePrices,
+}
+
+/// Descriptive information about a pool, e.g., to be displayed by UIs.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct PoolMetadata<Description, Uri, Hash> {
+	/// A description of the pool and its purpose.
+	pub description: Option<Description>,
+	/// A URI pointing to further information about the pool, e.g., a website.
+	pub uri: Option<Uri>,
+	/// The hash of the logo of the pool, which allows to verify a logo
+	/// obtained off-chain.
+	pub logo_hash: Option<Hash>,
+}
+
+/// Link of a pool to the decentralized identity of its owner.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct PoolIssuer<Identifier, CredentialId> {
+	/// The identity of the owner of the pool, e.g., a DID.
+	pub identifier: Identifier,
+	/// The reference to a credential backing the identity.
+	pub credential_id: CredentialId,
+}
+
 /// Details of a pool.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
 pub struct PoolDetails<AccountId, ParametrizedCurve, Currencies, BaseCurrencyId, DepositBalance, SharedSettings> {
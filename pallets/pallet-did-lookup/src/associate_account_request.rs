@@ -18,8 +18,11 @@
 
 use crate::{
 	account::{AccountId20, EthereumSignature},
+	bitcoin::{BitcoinAddress, BitcoinSignature},
+	cosmos::{CosmosAddress, CosmosSignature},
 	linkable_account::LinkableAccountId,
-	signature::get_wrapped_payload,
+	signature::{get_eip712_payload, get_wrapped_payload},
+	solana::{SolanaAddress, SolanaSignature},
 };
 
 use base58::ToBase58;
@@ -29,22 +32,44 @@ use scale_info::{
 	prelude::{format, string::String},
 	TypeInfo,
 };
-use sp_runtime::{traits::Verify, AccountId32, MultiSignature};
+use sp_runtime::{
+	traits::{UniqueSaturatedInto, Verify},
+	AccountId32, MultiSignature,
+};
 use sp_std::{fmt::Debug, vec, vec::Vec};
 
 #[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum AssociateAccountRequest {
 	Polkadot(AccountId32, MultiSignature),
 	Ethereum(AccountId20, EthereumSignature),
+	/// A BIP-137 or BIP-322 message signature by a Bitcoin address.
+	Bitcoin(BitcoinAddress, BitcoinSignature),
+	/// An ed25519 signature over a Solana off-chain message.
+	Solana(SolanaAddress, SolanaSignature),
+	/// An ADR-036 off-chain signature by a Cosmos address.
+	Cosmos(CosmosAddress, CosmosSignature),
+	/// An EIP-712 typed data signature by an Ethereum address.
+	EthereumTypedData(AccountId20, EthereumSignature),
+}
+
+/// The chain specific values of the EIP-712 domain used for typed data
+/// signatures.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eip712Domain {
+	/// The chain ID the wallet is expected to be connected to.
+	pub chain_id: u64,
+	/// The genesis hash of the chain, used as domain salt.
+	pub genesis_hash: [u8; 32],
 }
 
 impl AssociateAccountRequest {
-	pub fn verify<DidIdentifier: AsRef<[u8]>, BlockNumber: Debug>(
+	pub fn verify<DidIdentifier: AsRef<[u8]>, BlockNumber: Debug + UniqueSaturatedInto<u128>>(
 		&self,
 		did_identifier: &DidIdentifier,
 		expiration: BlockNumber,
+		eip712_domain: &Eip712Domain,
 	) -> bool {
-		let encoded_payload = get_challenge(did_identifier, expiration).into_bytes();
+		let encoded_payload = get_challenge(did_identifier, &expiration).into_bytes();
 		match self {
 			AssociateAccountRequest::Polkadot(acc, proof) => proof.verify(
 				&get_wrapped_payload(&encoded_payload[..], crate::signature::WrapType::Substrate)[..],
@@ -54,6 +79,19 @@ impl AssociateAccountRequest {
 				&get_wrapped_payload(&encoded_payload[..], crate::signature::WrapType::Ethereum)[..],
 				acc,
 			),
+			// The chain specific wrapping of the challenge happens as part of the verification.
+			AssociateAccountRequest::Bitcoin(acc, proof) => proof.verify(&encoded_payload[..], acc),
+			AssociateAccountRequest::Solana(acc, proof) => proof.verify(&encoded_payload[..], acc),
+			AssociateAccountRequest::Cosmos(acc, proof) => proof.verify(&encoded_payload[..], acc),
+			AssociateAccountRequest::EthereumTypedData(acc, proof) => proof.verify(
+				&get_eip712_payload(
+					&get_did_uri(did_identifier),
+					expiration.unique_saturated_into(),
+					eip712_domain.chain_id,
+					&eip712_domain.genesis_hash,
+				)[..],
+				acc,
+			),
 		}
 	}
 
@@ -61,6 +99,10 @@ impl AssociateAccountRequest {
 		match self {
 			AssociateAccountRequest::Polkadot(acc, _) => LinkableAccountId::AccountId32(acc.clone()),
 			AssociateAccountRequest::Ethereum(acc, _) => LinkableAccountId::AccountId20(*acc),
+			AssociateAccountRequest::Bitcoin(acc, _) => LinkableAccountId::Bitcoin(*acc),
+			AssociateAccountRequest::Solana(acc, _) => LinkableAccountId::Solana(*acc),
+			AssociateAccountRequest::Cosmos(acc, _) => LinkableAccountId::Cosmos(*acc),
+			AssociateAccountRequest::EthereumTypedData(acc, _) => LinkableAccountId::AccountId20(*acc),
 		}
 	}
 }
@@ -72,12 +114,17 @@ pub fn get_challenge<DidIdentifier: AsRef<[u8]>, BlockNumber: Debug>(
 	expiration: BlockNumber,
 ) -> String {
 	format!(
-		"Publicly link the signing address to did:kilt:{} before block number {:?}",
-		to_ss58(did_identifier.as_ref(), 38),
+		"Publicly link the signing address to {} before block number {:?}",
+		get_did_uri(did_identifier),
 		expiration
 	)
 }
 
+/// Build the `did:kilt` URI of the DID an account is linked to.
+pub fn get_did_uri<DidIdentifier: AsRef<[u8]>>(did_identifier: &DidIdentifier) -> String {
+	format!("did:kilt:{}", to_ss58(did_identifier.as_ref(), 38))
+}
+
 // Copied from https://github.com/paritytech/substrate/blob/ad5399644aebc54e32a107ac37ae08e6cd1f0cfb/primitives/core/src/crypto.rs#L324
 // Copyright (C) Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: Apache-2.0
@@ -118,7 +165,8 @@ fn ss58hash(data: &[u8]) -> Vec<u8> {
 
 #[cfg(test)]
 mod tests {
-	use super::get_challenge;
+	use super::{get_challenge, get_did_uri};
+	use crate::{mock::EIP712_CHAIN_ID, signature::get_eip712_payload};
 
 	#[test]
 	fn test_get_challenge() {
@@ -127,4 +175,13 @@ mod tests {
 			"Publicly link the signing address to did:kilt:4nwPAmtsK5toZfBM9WvmAe4Fa3LyZ3X3JHt7EUFfrcPPAZAm before block number 5"
 		);
 	}
+
+	#[test]
+	fn test_get_eip712_payload() {
+		let payload = get_eip712_payload(&get_did_uri(&[1u8; 32]), 5, EIP712_CHAIN_ID, &[2u8; 32]);
+		assert_eq!(
+			hex::encode(sp_io::hashing::keccak_256(&payload)),
+			"0ed45e6a98948917f511c54c6c69e125ca4e7ddf34c9b8fc2d4f04c1d2f7263d"
+		);
+	}
 }
@@ -28,22 +28,31 @@ use frame_support::{
 	},
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use parity_scale_codec::{Encode, MaxEncodedLen};
 use sha3::{Digest, Keccak256};
 use sp_io::crypto::{ecdsa_generate, ed25519_generate, sr25519_generate};
 use sp_runtime::{
 	app_crypto::{ed25519, sr25519},
-	traits::IdentifyAccount,
-	AccountId32, KeyTypeId,
+	traits::{IdentifyAccount, Saturating, UniqueSaturatedInto},
+	AccountId32, BoundedVec, KeyTypeId,
 };
+use sp_std::vec::Vec;
 
-use kilt_support::{traits::GenerateBenchmarkOrigin, Deposit};
+use kilt_support::{
+	traits::{GenerateBenchmarkOrigin, StorageDepositCollector},
+	Deposit,
+};
 
 use crate::{
 	account::AccountId20,
-	associate_account_request::{get_challenge, AssociateAccountRequest},
+	associate_account_request::{get_challenge, get_did_uri, AssociateAccountRequest},
+	bitcoin::{bip322_simple_sighash, hash160, BitcoinAddress, BitcoinSignature},
+	cosmos::{adr036_sign_doc_hash, CosmosAddress, CosmosSignature},
 	linkable_account::LinkableAccountId,
-	signature::get_wrapped_payload,
-	AccountIdOf, Call, Config, ConnectedAccounts, ConnectedDids, CurrencyOf, Pallet,
+	signature::{get_eip712_payload, get_wrapped_payload},
+	solana::SolanaAddress,
+	AccountIdOf, AccountPurpose, BalanceMigrationManagerOf, Call, Config, ConnectedAccounts, ConnectedAccountsMetadata,
+	ConnectedDids, CurrencyOf, LabelOf, LinkMetadataOf, LinkableAccountDepositCollector, Pallet, PrimaryAccounts,
 };
 
 const SEED: u32 = 0;
@@ -203,6 +212,150 @@ benchmarks_instance_pallet! {
 		assert!(ConnectedAccounts::<T, I>::get(did, LinkableAccountId::from(eth_account)).is_some());
 	}
 
+	associate_bitcoin_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		// BIP-322 is the more expensive of the two supported signature formats.
+		let public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
+		let compressed_public_key: [u8; 33] = public_key.as_ref().try_into().map_err(|_| "Invalid public key.")?;
+		let key_hash = hash160(&compressed_public_key);
+		let bitcoin_address = BitcoinAddress::P2wpkh(key_hash);
+
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(
+			KeyTypeId(*b"aura"),
+			&public_key,
+			&bip322_simple_sighash(get_challenge(&did, expire_at).as_bytes(), &key_hash),
+		).ok_or("Error while building signature.")?;
+		let der_sig = libsecp256k1::Signature::parse_standard_slice(sig.as_ref().get(..64).ok_or("Invalid signature.")?)
+			.map_err(|_| "Invalid signature.")?
+			.serialize_der();
+
+		make_free_for_did::<T, I>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T, I>::add_association(caller.clone(), previous_did.clone(), bitcoin_address.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T, I>::get(&previous_did, LinkableAccountId::from(bitcoin_address)).is_some());
+		let origin = T::AssociateOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::Bitcoin(bitcoin_address, BitcoinSignature::Bip322Simple {
+			signature: [der_sig.as_ref(), &[0x01]].concat().try_into().map_err(|_| "Signature too long.")?,
+			public_key: compressed_public_key,
+		});
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T, I>::get(LinkableAccountId::from(bitcoin_address)).is_some());
+		assert!(ConnectedAccounts::<T, I>::get(&previous_did, LinkableAccountId::from(bitcoin_address)).is_none());
+		assert!(ConnectedAccounts::<T, I>::get(did, LinkableAccountId::from(bitcoin_address)).is_some());
+	}
+
+	associate_solana_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let public_key = ed25519_generate(KeyTypeId(*b"aura"), None);
+		let solana_address = SolanaAddress::from(public_key);
+
+		let sig = sp_io::crypto::ed25519_sign(
+			KeyTypeId(*b"aura"),
+			&public_key,
+			&get_wrapped_payload(
+				get_challenge(&did, expire_at).as_bytes(),
+				crate::signature::WrapType::Solana,
+			))
+			.ok_or("Error while building signature.")?;
+
+		make_free_for_did::<T, I>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T, I>::add_association(caller.clone(), previous_did.clone(), solana_address.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T, I>::get(&previous_did, LinkableAccountId::from(solana_address)).is_some());
+		let origin = T::AssociateOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::Solana(solana_address, sig.into());
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T, I>::get(LinkableAccountId::from(solana_address)).is_some());
+		assert!(ConnectedAccounts::<T, I>::get(&previous_did, LinkableAccountId::from(solana_address)).is_none());
+		assert!(ConnectedAccounts::<T, I>::get(did, LinkableAccountId::from(solana_address)).is_some());
+	}
+
+	associate_cosmos_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
+		let compressed_public_key: [u8; 33] = public_key.as_ref().try_into().map_err(|_| "Invalid public key.")?;
+		let cosmos_address = CosmosAddress(hash160(&compressed_public_key));
+		let hrp = b"cosmos";
+
+		let sign_doc_hash = adr036_sign_doc_hash(hrp, &cosmos_address, get_challenge(&did, expire_at).as_bytes())
+			.ok_or("Error while building sign doc.")?;
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(
+			KeyTypeId(*b"aura"),
+			&public_key,
+			&sign_doc_hash,
+		).ok_or("Error while building signature.")?;
+
+		make_free_for_did::<T, I>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T, I>::add_association(caller.clone(), previous_did.clone(), cosmos_address.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T, I>::get(&previous_did, LinkableAccountId::from(cosmos_address)).is_some());
+		let origin = T::AssociateOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::Cosmos(cosmos_address, CosmosSignature {
+			hrp: hrp.to_vec().try_into().map_err(|_| "Prefix too long.")?,
+			public_key: compressed_public_key,
+			signature: sig.as_ref().get(..64).and_then(|rs| rs.try_into().ok()).ok_or("Invalid signature.")?,
+		});
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T, I>::get(LinkableAccountId::from(cosmos_address)).is_some());
+		assert!(ConnectedAccounts::<T, I>::get(&previous_did, LinkableAccountId::from(cosmos_address)).is_none());
+		assert!(ConnectedAccounts::<T, I>::get(did, LinkableAccountId::from(cosmos_address)).is_some());
+	}
+
+	associate_eth_typed_data_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		let eth_public_key = ecdsa_generate(KeyTypeId(*b"aura"), None);
+		let eth_account = AccountId20(eth_public_key.to_eth_address().unwrap());
+
+		let domain = Pallet::<T, I>::eip712_domain();
+		let typed_data_payload = get_eip712_payload(
+			&get_did_uri(&did),
+			expire_at.unique_saturated_into(),
+			domain.chain_id,
+			&domain.genesis_hash,
+		);
+
+		let sig = sp_io::crypto::ecdsa_sign_prehashed(
+			KeyTypeId(*b"aura"),
+			&eth_public_key,
+			&Keccak256::digest(typed_data_payload).into(),
+		).ok_or("Error while building signature.")?;
+
+		make_free_for_did::<T, I>(&caller);
+
+		// Add existing connected_acc -> previous_did connection that will be replaced
+		Pallet::<T, I>::add_association(caller.clone(), previous_did.clone(), eth_account.into()).expect("should create previous association");
+		assert!(ConnectedAccounts::<T, I>::get(&previous_did, LinkableAccountId::from(eth_account)).is_some());
+		let origin = T::AssociateOrigin::generate_origin(caller, did.clone());
+		let req = AssociateAccountRequest::EthereumTypedData(eth_account, sig.into());
+	}: associate_account<T::RuntimeOrigin>(origin, req, expire_at)
+	verify {
+		assert!(ConnectedDids::<T, I>::get(LinkableAccountId::from(eth_account)).is_some());
+		assert!(ConnectedAccounts::<T, I>::get(&previous_did, LinkableAccountId::from(eth_account)).is_none());
+		assert!(ConnectedAccounts::<T, I>::get(did, LinkableAccountId::from(eth_account)).is_some());
+	}
+
 	associate_sender {
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let linkable_id: LinkableAccountId = caller.clone().into();
@@ -300,6 +453,144 @@ benchmarks_instance_pallet! {
 			},
 		);
 	}
+
+	associate_accounts {
+		let n in 1 .. <T as Config<I>>::MaxAssociationsPerBatch::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		let previous_did: T::DidIdentifier = account("prev", 0, SEED + 1);
+		let expire_at: BlockNumberFor<T> = 500_u32.into();
+
+		CurrencyOf::<T, I>::set_balance(
+			&caller,
+			<CurrencyOf<T, I> as Inspect<AccountIdOf<T>>>::minimum_balance()
+				+ <T as Config<I>>::Deposit::get().saturating_mul(n.into()),
+		);
+
+		let mut linkable_ids = Vec::new();
+		let mut reqs = Vec::new();
+		for i in 0 .. n {
+			let connected_acc = ed25519_generate(KeyTypeId(*b"aura"), None);
+			let connected_acc_id: T::AccountId = connected_acc.into();
+			let linkable_id: LinkableAccountId = connected_acc_id.clone().into();
+
+			let sig = sp_io::crypto::ed25519_sign(
+				KeyTypeId(*b"aura"),
+				&connected_acc,
+				&get_wrapped_payload(
+					get_challenge(&did, expire_at).as_bytes(),
+					crate::signature::WrapType::Substrate,
+				))
+				.ok_or("Error while building signature.")?;
+
+			// Add existing connected_acc -> previous_did connections, each with its own
+			// deposit owner, that will be replaced
+			let previous_owner: T::AccountId = account("prev_owner", i, SEED);
+			make_free_for_did::<T, I>(&previous_owner);
+			Pallet::<T, I>::add_association(previous_owner, previous_did.clone(), linkable_id.clone()).expect("should create previous association");
+
+			reqs.push(AssociateAccountRequest::Polkadot(connected_acc_id.into(), sig.into()));
+			linkable_ids.push(linkable_id);
+		}
+		let reqs: BoundedVec<_, _> = reqs.try_into().expect("number of requests should fit into the bound");
+
+		let origin = T::AssociateOrigin::generate_origin(caller, did.clone());
+	}: _<T::RuntimeOrigin>(origin, reqs, expire_at)
+	verify {
+		for linkable_id in linkable_ids {
+			assert!(ConnectedAccounts::<T, I>::get(&previous_did, &linkable_id).is_none());
+			assert!(ConnectedAccounts::<T, I>::get(&did, &linkable_id).is_some());
+		}
+	}
+
+	remove_account_associations {
+		let n in 1 .. <T as Config<I>>::MaxAssociationsPerBatch::get();
+
+		let did: T::DidIdentifier = account("did", 0, SEED);
+
+		let mut linkable_ids = Vec::new();
+		for i in 0 .. n {
+			// Each link has its own deposit owner
+			let caller: T::AccountId = account("caller", i, SEED);
+			let linkable_id: LinkableAccountId = caller.clone().into();
+			make_free_for_did::<T, I>(&caller);
+			Pallet::<T, I>::add_association(caller, did.clone(), linkable_id.clone()).expect("should create association");
+			linkable_ids.push(linkable_id);
+		}
+		let accounts: BoundedVec<_, _> = linkable_ids.clone().try_into().expect("number of accounts should fit into the bound");
+
+		let origin = T::EnsureOrigin::generate_origin(account("caller", 0, SEED), did.clone());
+	}: _<T::RuntimeOrigin>(origin, accounts)
+	verify {
+		for linkable_id in linkable_ids {
+			assert!(ConnectedDids::<T, I>::get(&linkable_id).is_none());
+			assert!(ConnectedAccounts::<T, I>::get(&did, &linkable_id).is_none());
+		}
+	}
+
+	update_account_metadata {
+		let deposit_owner_old: T::AccountId = account("caller", 0, SEED);
+		let deposit_owner_new: T::AccountId = account("caller", 1, SEED);
+		let linkable_id: LinkableAccountId = deposit_owner_old.clone().into();
+		let previous_primary_id: LinkableAccountId = account::<T::AccountId>("caller", 2, SEED).into();
+		let did: T::DidIdentifier = account("did", 0, SEED);
+		make_free_for_did::<T, I>(&deposit_owner_old);
+		// The new primary account pays for its metadata and the primary entry.
+		let metadata_length = LinkMetadataOf::<T, I>::max_encoded_len() + did.encoded_size() + linkable_id.encoded_size();
+		let metadata_deposit = <T as Config<I>>::MetadataByteDeposit::get()
+			.saturating_mul(u32::try_from(metadata_length).expect("metadata size should fit into u32").into());
+		CurrencyOf::<T, I>::set_balance(
+			&deposit_owner_new,
+			<CurrencyOf<T, I> as Inspect<AccountIdOf<T>>>::minimum_balance()
+				+ <T as Config<I>>::Deposit::get()
+				+ metadata_deposit,
+		);
+
+		Pallet::<T, I>::add_association(deposit_owner_old.clone(), did.clone(), linkable_id.clone()).expect("should create association");
+		Pallet::<T, I>::add_association(deposit_owner_old, did.clone(), previous_primary_id.clone()).expect("should create association");
+		// Worst case: the primary flag moves from another linked account, whose
+		// deposit is updated as well.
+		let previous_metadata = LinkMetadataOf::<T, I> {
+			primary: true,
+			label: None,
+			purpose: None,
+		};
+		CurrencyOf::<T, I>::mint_into(
+			&deposit_owner_old,
+			Pallet::<T, I>::link_deposit(previous_metadata.encoded_size() + did.encoded_size() + previous_primary_id.encoded_size()),
+		).expect("should mint the deposit");
+		ConnectedAccountsMetadata::<T, I>::insert(&did, &previous_primary_id, previous_metadata);
+		PrimaryAccounts::<T, I>::insert(&did, &previous_primary_id);
+		LinkableAccountDepositCollector::<T, I>::update_deposit::<BalanceMigrationManagerOf<T, I>>(&previous_primary_id)
+			.expect("should update the deposit");
+
+		let label: LabelOf<T, I> = (0..<T as Config<I>>::MaxLabelLength::get())
+			.map(|_| b'a')
+			.collect::<sp_std::vec::Vec<_>>()
+			.try_into()
+			.expect("label should fit into the bound");
+		let metadata = LinkMetadataOf::<T, I> {
+			primary: true,
+			label: Some(label),
+			purpose: Some(AccountPurpose::ColdStorage),
+		};
+
+		let origin = T::EnsureOrigin::generate_origin(deposit_owner_new.clone(), did.clone());
+		let id_arg = linkable_id.clone();
+		let metadata_arg = metadata.clone();
+	}: _<T::RuntimeOrigin>(origin, id_arg, metadata_arg)
+	verify {
+		assert_eq!(PrimaryAccounts::<T, I>::get(&did), Some(linkable_id.clone()));
+		assert_eq!(ConnectedAccountsMetadata::<T, I>::get(&did, &linkable_id), Some(metadata.clone()));
+		assert_eq!(
+			ConnectedDids::<T, I>::get(&linkable_id).expect("should retain link").deposit,
+			Deposit {
+				owner: deposit_owner_new,
+				amount: Pallet::<T, I>::link_deposit(metadata.encoded_size() + did.encoded_size() + linkable_id.encoded_size()),
+			},
+		);
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,334 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! The Bitcoin address and message signature implementation.
+//!
+//! Two message signing formats are supported:
+//! * BIP-137, the legacy `signmessage` format with a recoverable signature,
+//!   for P2PKH, P2SH-P2WPKH and P2WPKH addresses.
+//! * The "simple" variant of BIP-322 for P2WPKH addresses.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use ripemd::{Digest, Ripemd160};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::ConstU32;
+use sp_io::hashing::sha2_256;
+use sp_runtime::{BoundedVec, RuntimeDebug};
+use sp_std::vec::Vec;
+
+use crate::signature::{get_wrapped_payload, verify_secp256k1_prehashed, WrapType};
+
+/// The maximum length of a DER encoded ECDSA signature, including the
+/// trailing sighash type byte.
+pub const MAX_DER_SIGNATURE_LENGTH: u32 = 73;
+
+const SIGHASH_ALL: u8 = 0x01;
+const BIP322_TAG: &[u8] = b"BIP0322-signed-message";
+
+/// A Bitcoin address that is controlled by a single secp256k1 key.
+#[derive(
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebug,
+	Serialize,
+	Deserialize,
+)]
+pub enum BitcoinAddress {
+	/// A legacy pay-to-public-key-hash address (`1...`).
+	P2pkh([u8; 20]),
+	/// A pay-to-script-hash address wrapping a P2WPKH script (`3...`).
+	P2shP2wpkh([u8; 20]),
+	/// A native segwit v0 pay-to-witness-public-key-hash address (`bc1q...`).
+	P2wpkh([u8; 20]),
+}
+
+impl BitcoinAddress {
+	/// The hash committed to by the address, i.e., the public key hash for
+	/// P2PKH and P2WPKH addresses and the script hash for P2SH addresses.
+	pub fn hash(&self) -> &[u8; 20] {
+		match self {
+			Self::P2pkh(hash) | Self::P2shP2wpkh(hash) | Self::P2wpkh(hash) => hash,
+		}
+	}
+
+	/// Whether the address is controlled by the provided public key.
+	fn is_controlled_by(&self, public_key: &[u8]) -> bool {
+		let key_hash = hash160(public_key);
+		match self {
+			Self::P2pkh(hash) => *hash == key_hash,
+			// Segwit addresses can only be derived from compressed keys.
+			Self::P2wpkh(hash) => public_key.len() == 33 && *hash == key_hash,
+			Self::P2shP2wpkh(hash) => {
+				public_key.len() == 33 && *hash == hash160(&[&[0x00, 0x14][..], &key_hash[..]].concat())
+			}
+		}
+	}
+}
+
+impl AsRef<[u8]> for BitcoinAddress {
+	fn as_ref(&self) -> &[u8] {
+		self.hash()
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for BitcoinAddress {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		use base58::ToBase58;
+
+		let base58_check = |version: u8, hash: &[u8; 20]| {
+			let mut payload = [&[version][..], &hash[..]].concat();
+			let checksum = sha2_256(&sha2_256(&payload));
+			payload.extend_from_slice(&checksum[..4]);
+			payload.to_base58()
+		};
+
+		match self {
+			Self::P2pkh(hash) => write!(f, "{}", base58_check(0x00, hash)),
+			Self::P2shP2wpkh(hash) => write!(f, "{}", base58_check(0x05, hash)),
+			Self::P2wpkh(hash) => {
+				let address = bech32::segwit::encode_v0(bech32::hrp::BC, hash).map_err(|_| std::fmt::Error)?;
+				write!(f, "{}", address)
+			}
+		}
+	}
+}
+
+/// A signature over a message by the key controlling a [`BitcoinAddress`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum BitcoinSignature {
+	/// A BIP-137 signature: the header byte, which encodes the recovery ID and
+	/// the address type, followed by the 64 bytes of `r` and `s`.
+	Bip137([u8; 65]),
+	/// A BIP-322 "simple" signature for a P2WPKH address, given as the two
+	/// elements of the witness stack.
+	Bip322Simple {
+		/// The DER encoded ECDSA signature, followed by the sighash type.
+		signature: BoundedVec<u8, ConstU32<MAX_DER_SIGNATURE_LENGTH>>,
+		/// The compressed public key.
+		public_key: [u8; 33],
+	},
+}
+
+impl BitcoinSignature {
+	pub fn verify(&self, message: &[u8], address: &BitcoinAddress) -> bool {
+		match self {
+			Self::Bip137(signature) => verify_bip137(signature, message, address),
+			Self::Bip322Simple { signature, public_key } => {
+				verify_bip322_simple(signature, public_key, message, address)
+			}
+		}
+	}
+}
+
+fn verify_bip137(signature: &[u8; 65], message: &[u8], address: &BitcoinAddress) -> bool {
+	let Some((header, rs)) = signature.split_first() else {
+		return false;
+	};
+	// Headers 27-30 are used for uncompressed keys, 31-42 for compressed keys of
+	// the different address types. The address type is determined by the
+	// provided address, hence the header is only used for the recovery ID.
+	let is_compressed = match header {
+		27..=30 => false,
+		31..=42 => true,
+		_ => return false,
+	};
+	let mut recoverable_signature = [0u8; 65];
+	let (signature_rs, signature_v) = recoverable_signature.split_at_mut(64);
+	signature_rs.copy_from_slice(rs);
+	signature_v.fill(header.saturating_sub(27) & 0b11);
+
+	let message_hash = bip137_message_hash(message);
+	if is_compressed {
+		sp_io::crypto::secp256k1_ecdsa_recover_compressed(&recoverable_signature, &message_hash)
+			.map_or(false, |public_key| address.is_controlled_by(&public_key))
+	} else {
+		sp_io::crypto::secp256k1_ecdsa_recover(&recoverable_signature, &message_hash).map_or(false, |public_key| {
+			address.is_controlled_by(&[&[0x04][..], &public_key[..]].concat())
+		})
+	}
+}
+
+fn verify_bip322_simple(signature: &[u8], public_key: &[u8; 33], message: &[u8], address: &BitcoinAddress) -> bool {
+	// The simple format is only defined for P2WPKH here.
+	let BitcoinAddress::P2wpkh(key_hash) = address else {
+		return false;
+	};
+	if !address.is_controlled_by(public_key) {
+		return false;
+	}
+	let Some((&SIGHASH_ALL, der_signature)) = signature.split_last() else {
+		return false;
+	};
+	let Ok(parsed_signature) = libsecp256k1::Signature::parse_der(der_signature) else {
+		return false;
+	};
+	verify_secp256k1_prehashed(
+		&parsed_signature.serialize(),
+		&bip322_simple_sighash(message, key_hash),
+		public_key,
+	)
+}
+
+/// The hash signed for a BIP-137 signature.
+pub(crate) fn bip137_message_hash(message: &[u8]) -> [u8; 32] {
+	sha2_256(&sha2_256(&get_wrapped_payload(message, WrapType::Bitcoin)))
+}
+
+/// The BIP-143 sighash of the single input of the virtual `to_sign`
+/// transaction, as defined by BIP-322 for a P2WPKH address.
+pub(crate) fn bip322_simple_sighash(message: &[u8], key_hash: &[u8; 20]) -> [u8; 32] {
+	let tag_hash = sha2_256(BIP322_TAG);
+	let message_hash = sha2_256(&[&tag_hash[..], &tag_hash[..], message].concat());
+	let script_pubkey = [&[0x00, 0x14][..], &key_hash[..]].concat();
+
+	// The virtual `to_spend` transaction: version 0, one input spending the
+	// null outpoint with `OP_0 PUSH32[message_hash]`, one output of value 0
+	// locked to the address, lock time 0.
+	let to_spend: Vec<u8> = [
+		&0u32.to_le_bytes()[..],
+		&[0x01],
+		&[0u8; 32],
+		&u32::MAX.to_le_bytes(),
+		&[0x22, 0x00, 0x20],
+		&message_hash,
+		&0u32.to_le_bytes(),
+		&[0x01],
+		&0u64.to_le_bytes(),
+		&[0x16],
+		&script_pubkey,
+		&0u32.to_le_bytes(),
+	]
+	.concat();
+	let to_spend_txid = sha2_256(&sha2_256(&to_spend));
+
+	// The virtual `to_sign` transaction spends output 0 of `to_spend` with
+	// sequence 0 into a single `OP_RETURN` output of value 0.
+	let outpoint = [&to_spend_txid[..], &0u32.to_le_bytes()[..]].concat();
+	let script_code = [&[0x19, 0x76, 0xa9, 0x14][..], &key_hash[..], &[0x88, 0xac][..]].concat();
+	let hash_prevouts = sha2_256(&sha2_256(&outpoint));
+	let hash_sequence = sha2_256(&sha2_256(&0u32.to_le_bytes()));
+	let hash_outputs = sha2_256(&sha2_256(&[&0u64.to_le_bytes()[..], &[0x01, 0x6a][..]].concat()));
+
+	let preimage: Vec<u8> = [
+		&0u32.to_le_bytes()[..],
+		&hash_prevouts,
+		&hash_sequence,
+		&outpoint,
+		&script_code,
+		&0u64.to_le_bytes(),
+		&0u32.to_le_bytes(),
+		&hash_outputs,
+		&0u32.to_le_bytes(),
+		&u32::from(SIGHASH_ALL).to_le_bytes(),
+	]
+	.concat();
+	sha2_256(&sha2_256(&preimage))
+}
+
+/// `RIPEMD160(SHA256(data))`, used to derive addresses from public keys.
+pub(crate) fn hash160(data: &[u8]) -> [u8; 20] {
+	Ripemd160::digest(sha2_256(data)).into()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{ecdsa, Pair};
+
+	const MESSAGE: &[u8] = b"Publicly link the signing address to did:kilt:4abc before block number 5";
+
+	fn compressed_public_key(pair: &ecdsa::Pair) -> [u8; 33] {
+		pair.public().as_ref().try_into().unwrap()
+	}
+
+	#[test]
+	fn test_hash160() {
+		// HASH160 of the empty string.
+		let mut expected = [0u8; 20];
+		hex::decode_to_slice("b472a266d0bd89c13706a4132ccfb16f7c3b9fcb", &mut expected).unwrap();
+		assert_eq!(hash160(b""), expected);
+	}
+
+	#[test]
+	fn test_bip137_compressed() {
+		let pair = ecdsa::Pair::generate().0;
+		let key_hash = hash160(&compressed_public_key(&pair));
+		let raw_signature = pair.sign_prehashed(&bip137_message_hash(MESSAGE));
+
+		let raw: &[u8] = raw_signature.as_ref();
+		let mut signature = [0u8; 65];
+		signature[0] = 31 + raw[64];
+		signature[1..].copy_from_slice(&raw[..64]);
+		let signature = BitcoinSignature::Bip137(signature);
+
+		assert!(signature.verify(MESSAGE, &BitcoinAddress::P2pkh(key_hash)));
+		assert!(signature.verify(MESSAGE, &BitcoinAddress::P2wpkh(key_hash)));
+		assert!(signature.verify(
+			MESSAGE,
+			&BitcoinAddress::P2shP2wpkh(hash160(&[&[0x00, 0x14][..], &key_hash[..]].concat()))
+		));
+		assert!(!signature.verify(b"another message", &BitcoinAddress::P2pkh(key_hash)));
+		assert!(!signature.verify(MESSAGE, &BitcoinAddress::P2pkh([0u8; 20])));
+	}
+
+	#[test]
+	fn test_bip137_invalid_header() {
+		let pair = ecdsa::Pair::generate().0;
+		let key_hash = hash160(&compressed_public_key(&pair));
+		let raw_signature = pair.sign_prehashed(&bip137_message_hash(MESSAGE));
+
+		let raw: &[u8] = raw_signature.as_ref();
+		let mut signature = [0u8; 65];
+		signature[0] = 43;
+		signature[1..].copy_from_slice(&raw[..64]);
+
+		assert!(!BitcoinSignature::Bip137(signature).verify(MESSAGE, &BitcoinAddress::P2pkh(key_hash)));
+	}
+
+	#[test]
+	fn test_bip322_simple() {
+		let pair = ecdsa::Pair::generate().0;
+		let public_key = compressed_public_key(&pair);
+		let key_hash = hash160(&public_key);
+		let raw_signature = pair.sign_prehashed(&bip322_simple_sighash(MESSAGE, &key_hash));
+
+		let raw: &[u8] = raw_signature.as_ref();
+		let der = libsecp256k1::Signature::parse_standard_slice(&raw[..64])
+			.unwrap()
+			.serialize_der();
+		let signature = BitcoinSignature::Bip322Simple {
+			signature: [der.as_ref(), &[SIGHASH_ALL][..]].concat().try_into().unwrap(),
+			public_key,
+		};
+
+		assert!(signature.verify(MESSAGE, &BitcoinAddress::P2wpkh(key_hash)));
+		// Only P2WPKH addresses are supported by the simple format.
+		assert!(!signature.verify(MESSAGE, &BitcoinAddress::P2pkh(key_hash)));
+		assert!(!signature.verify(b"another message", &BitcoinAddress::P2wpkh(key_hash)));
+	}
+}
@@ -0,0 +1,171 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! The Cosmos address and ADR-036 off-chain signature implementation.
+//!
+//! ADR-036 signatures are produced by signing the amino JSON encoding of a
+//! transaction containing a single `sign/MsgSignData` message, with all
+//! transaction specific fields left empty.
+
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::{prelude::format, TypeInfo};
+use serde::{Deserialize, Serialize};
+use sp_core::ConstU32;
+use sp_io::hashing::sha2_256;
+use sp_runtime::{BoundedVec, RuntimeDebug};
+
+use crate::{bitcoin::hash160, signature::verify_secp256k1_prehashed};
+
+/// The maximum length of the human-readable part of a bech32 address.
+pub const MAX_HRP_LENGTH: u32 = 83;
+
+/// A Cosmos SDK account address, derived from a secp256k1 public key.
+///
+/// The address is stored without the human-readable part, so the same key
+/// controls the same address on all chains using the Cosmos coin type.
+#[derive(
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebug,
+	Serialize,
+	Deserialize,
+)]
+pub struct CosmosAddress(pub [u8; 20]);
+
+impl From<[u8; 20]> for CosmosAddress {
+	fn from(bytes: [u8; 20]) -> Self {
+		Self(bytes)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for CosmosAddress {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "0x{}", hex::encode(self.0))
+	}
+}
+
+/// An ADR-036 signature by the key controlling a [`CosmosAddress`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct CosmosSignature {
+	/// The human-readable part of the bech32 signer address, e.g. `cosmos`.
+	pub hrp: BoundedVec<u8, ConstU32<MAX_HRP_LENGTH>>,
+	/// The compressed secp256k1 public key of the signer.
+	pub public_key: [u8; 33],
+	/// The 64 bytes of `r` and `s`.
+	pub signature: [u8; 64],
+}
+
+impl CosmosSignature {
+	pub fn verify(&self, message: &[u8], address: &CosmosAddress) -> bool {
+		if hash160(&self.public_key) != address.0 {
+			return false;
+		}
+		adr036_sign_doc_hash(&self.hrp, address, message).map_or(false, |sign_doc_hash| {
+			verify_secp256k1_prehashed(&self.signature, &sign_doc_hash, &self.public_key)
+		})
+	}
+}
+
+/// The hash of the ADR-036 sign document for the message, as signed by
+/// `address` with the given human-readable part.
+///
+/// Returns `None` if the human-readable part is not valid.
+pub(crate) fn adr036_sign_doc_hash(hrp: &[u8], address: &CosmosAddress, message: &[u8]) -> Option<[u8; 32]> {
+	let hrp = sp_std::str::from_utf8(hrp).ok()?;
+	// Cosmos addresses are always lowercase.
+	if hrp.bytes().any(|c| c.is_ascii_uppercase()) {
+		return None;
+	}
+	let signer = bech32::encode::<bech32::Bech32>(bech32::Hrp::parse(hrp).ok()?, &address.0).ok()?;
+
+	// Amino JSON with lexicographically sorted keys and no whitespace.
+	let sign_doc = format!(
+		r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+		BASE64_STANDARD.encode(message),
+		signer
+	);
+	Some(sha2_256(sign_doc.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{ecdsa, Pair};
+
+	const MESSAGE: &[u8] = b"Publicly link the signing address to did:kilt:4abc before block number 5";
+
+	fn sign(pair: &ecdsa::Pair, hrp: &[u8]) -> (CosmosAddress, CosmosSignature) {
+		let public_key: [u8; 33] = pair.public().as_ref().try_into().unwrap();
+		let address = CosmosAddress(hash160(&public_key));
+		let raw_signature = pair.sign_prehashed(&adr036_sign_doc_hash(hrp, &address, MESSAGE).unwrap());
+		let raw: &[u8] = raw_signature.as_ref();
+		(
+			address,
+			CosmosSignature {
+				hrp: hrp.to_vec().try_into().unwrap(),
+				public_key,
+				signature: raw[..64].try_into().unwrap(),
+			},
+		)
+	}
+
+	#[test]
+	fn test_sign_doc_hash() {
+		// The all-zero address with the `cosmos` prefix signing `hello`.
+		let address = CosmosAddress([0u8; 20]);
+		let sign_doc = r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"aGVsbG8=","signer":"cosmos1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqnrql8a"}}],"sequence":"0"}"#;
+		assert_eq!(
+			adr036_sign_doc_hash(b"cosmos", &address, b"hello"),
+			Some(sha2_256(sign_doc.as_bytes()))
+		);
+	}
+
+	#[test]
+	fn test_verify() {
+		let pair = ecdsa::Pair::generate().0;
+		let (address, signature) = sign(&pair, b"cosmos");
+
+		assert!(signature.verify(MESSAGE, &address));
+		assert!(!signature.verify(b"another message", &address));
+		assert!(!signature.verify(MESSAGE, &CosmosAddress([0u8; 20])));
+
+		// The signature commits to the prefix of the signer address.
+		let other_prefix = CosmosSignature {
+			hrp: b"osmo".to_vec().try_into().unwrap(),
+			..signature
+		};
+		assert!(!other_prefix.verify(MESSAGE, &address));
+	}
+
+	#[test]
+	fn test_invalid_prefix() {
+		let address = CosmosAddress([0u8; 20]);
+		assert_eq!(adr036_sign_doc_hash(b"", &address, MESSAGE), None);
+		assert_eq!(adr036_sign_doc_hash(b"Cosmos", &address, MESSAGE), None);
+	}
+}
@@ -54,11 +54,18 @@ pub trait WeightInfo {
 	fn associate_account_multisig_ed25519() -> Weight;
 	fn associate_account_multisig_ecdsa() -> Weight;
 	fn associate_eth_account() -> Weight;
+	fn associate_bitcoin_account() -> Weight;
+	fn associate_solana_account() -> Weight;
+	fn associate_cosmos_account() -> Weight;
+	fn associate_eth_typed_data_account() -> Weight;
 	fn associate_sender() -> Weight;
 	fn remove_sender_association() -> Weight;
 	fn remove_account_association() -> Weight;
 	fn change_deposit_owner() -> Weight;
 	fn update_deposit() -> Weight;
+	fn update_account_metadata() -> Weight;
+	fn associate_accounts(n: u32, ) -> Weight;
+	fn remove_account_associations(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_did_lookup using the Substrate node and recommended hardware.
@@ -130,6 +137,68 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn associate_bitcoin_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `5211`
+		// Minimum execution time: 62_719 nanoseconds.
+		Weight::from_parts(67_703_000, 5211)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn associate_solana_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `5211`
+		// Minimum execution time: 57_393 nanoseconds.
+		Weight::from_parts(61_954_000, 5211)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn associate_cosmos_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `5211`
+		// Minimum execution time: 61_535 nanoseconds.
+		Weight::from_parts(66_425_000, 5211)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn associate_eth_typed_data_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `5211`
+		// Minimum execution time: 60_352 nanoseconds.
+		Weight::from_parts(65_148_000, 5211)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	fn associate_sender() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `403`
@@ -195,6 +264,75 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup PrimaryAccounts (r:1 w:1)
+	/// Proof: DidLookup PrimaryAccounts (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccountsMetadata (r:2 w:2)
+	/// Proof: DidLookup ConnectedAccountsMetadata (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn update_account_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `816`
+		//  Estimated: `7818`
+		// Minimum execution time: 35_473 nanoseconds.
+		Weight::from_parts(36_331_000, 7818)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:51 w:51)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:51 w:51)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(301), added: 2776, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:50 w:50)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup PrimaryAccounts (r:50 w:0)
+	/// Proof: DidLookup PrimaryAccounts (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccountsMetadata (r:0 w:50)
+	/// Proof: DidLookup ConnectedAccountsMetadata (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:100)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 50]`.
+	fn associate_accounts(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462 + n * (212 ±0)`
+		//  Estimated: `3766 + n * (2776 ±0)`
+		// Minimum execution time: 67_972 nanoseconds.
+		Weight::from_parts(17_010_000, 3766)
+			// Standard Error: 539_317
+			.saturating_add(Weight::from_parts(52_348_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2776).saturating_mul(n.into()))
+	}
+	/// Storage: DidLookup ConnectedDids (r:50 w:50)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:50 w:50)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:50 w:50)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(301), added: 2776, mode: MaxEncodedLen)
+	/// Storage: DidLookup PrimaryAccounts (r:50 w:0)
+	/// Proof: DidLookup PrimaryAccounts (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccountsMetadata (r:0 w:50)
+	/// Proof: DidLookup ConnectedAccountsMetadata (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:50)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 50]`.
+	fn remove_account_associations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160 + n * (380 ±0)`
+		//  Estimated: `990 + n * (2776 ±0)`
+		// Minimum execution time: 20_695 nanoseconds.
+		Weight::from_parts(4_314_000, 990)
+			// Standard Error: 173_317
+			.saturating_add(Weight::from_parts(16_803_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2776).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -265,6 +403,68 @@ impl WeightInfo for () {
 	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
 	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
 	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn associate_bitcoin_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `5211`
+		// Minimum execution time: 62_719 nanoseconds.
+		Weight::from_parts(67_703_000, 5211)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn associate_solana_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `5211`
+		// Minimum execution time: 57_393 nanoseconds.
+		Weight::from_parts(61_954_000, 5211)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn associate_cosmos_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `5211`
+		// Minimum execution time: 61_535 nanoseconds.
+		Weight::from_parts(66_425_000, 5211)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn associate_eth_typed_data_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `5211`
+		// Minimum execution time: 60_352 nanoseconds.
+		Weight::from_parts(65_148_000, 5211)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:2)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	fn associate_sender() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `403`
@@ -330,4 +530,73 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: DidLookup ConnectedDids (r:1 w:1)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup PrimaryAccounts (r:1 w:1)
+	/// Proof: DidLookup PrimaryAccounts (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccountsMetadata (r:2 w:2)
+	/// Proof: DidLookup ConnectedAccountsMetadata (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	fn update_account_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `816`
+		//  Estimated: `7818`
+		// Minimum execution time: 35_473 nanoseconds.
+		Weight::from_parts(36_331_000, 7818)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: System Account (r:51 w:51)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:51 w:51)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(301), added: 2776, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedDids (r:50 w:50)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: DidLookup PrimaryAccounts (r:50 w:0)
+	/// Proof: DidLookup PrimaryAccounts (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccountsMetadata (r:0 w:50)
+	/// Proof: DidLookup ConnectedAccountsMetadata (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:100)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 50]`.
+	fn associate_accounts(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462 + n * (212 ±0)`
+		//  Estimated: `3766 + n * (2776 ±0)`
+		// Minimum execution time: 67_972 nanoseconds.
+		Weight::from_parts(17_010_000, 3766)
+			// Standard Error: 539_317
+			.saturating_add(Weight::from_parts(52_348_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2776).saturating_mul(n.into()))
+	}
+	/// Storage: DidLookup ConnectedDids (r:50 w:50)
+	/// Proof: DidLookup ConnectedDids (max_values: None, max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:50 w:50)
+	/// Proof: System Account (max_values: None, max_size: Some(132), added: 2607, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:50 w:50)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(301), added: 2776, mode: MaxEncodedLen)
+	/// Storage: DidLookup PrimaryAccounts (r:50 w:0)
+	/// Proof: DidLookup PrimaryAccounts (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccountsMetadata (r:0 w:50)
+	/// Proof: DidLookup ConnectedAccountsMetadata (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: DidLookup ConnectedAccounts (r:0 w:50)
+	/// Proof: DidLookup ConnectedAccounts (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 50]`.
+	fn remove_account_associations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160 + n * (380 ±0)`
+		//  Estimated: `990 + n * (2776 ±0)`
+		// Minimum execution time: 20_695 nanoseconds.
+		Weight::from_parts(4_314_000, 990)
+			// Standard Error: 173_317
+			.saturating_add(Weight::from_parts(16_803_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2776).saturating_mul(n.into()))
+	}
 }
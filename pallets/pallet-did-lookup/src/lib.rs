@@ -26,11 +26,16 @@
 
 pub mod account;
 pub mod associate_account_request;
+pub mod bitcoin;
+pub mod cosmos;
 pub mod default_weights;
 pub mod linkable_account;
 pub mod migrations;
+pub mod solana;
+pub mod traits;
 
 mod connection_record;
+mod link_metadata;
 mod signature;
 
 #[cfg(test)]
@@ -63,8 +68,10 @@ pub use crate::{default_weights::WeightInfo, pallet::*};
 #[allow(clippy::manual_inspect)]
 pub mod pallet {
 	use crate::{
-		associate_account_request::AssociateAccountRequest, default_weights::WeightInfo,
+		associate_account_request::{AssociateAccountRequest, Eip712Domain},
+		default_weights::WeightInfo,
 		linkable_account::LinkableAccountId,
+		traits::DidNameProvider,
 	};
 	use frame_support::{
 		ensure,
@@ -79,9 +86,15 @@ pub mod pallet {
 		traits::{BalanceMigrationManager, CallSources, StorageDepositCollector},
 		Deposit,
 	};
-	use sp_runtime::traits::{BlockNumberProvider, MaybeSerializeDeserialize};
+	use sp_runtime::{
+		traits::{BlockNumberProvider, MaybeSerializeDeserialize, Saturating, Zero},
+		SaturatedConversion,
+	};
 
-	pub use crate::connection_record::ConnectionRecord;
+	pub use crate::{
+		connection_record::ConnectionRecord,
+		link_metadata::{AccountPurpose, LinkMetadata},
+	};
 
 	/// The native identifier for accounts in this runtime.
 	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -96,9 +109,16 @@ pub mod pallet {
 	/// The connection record type.
 	pub(crate) type ConnectionRecordOf<T, I> = ConnectionRecord<DidIdentifierOf<T, I>, AccountIdOf<T>, BalanceOf<T, I>>;
 
+	/// The label of a linked account.
+	pub type LabelOf<T, I> = BoundedVec<u8, <T as Config<I>>::MaxLabelLength>;
+	/// The metadata of a linked account.
+	pub type LinkMetadataOf<T, I> = LinkMetadata<LabelOf<T, I>>;
+	/// The name of a DID stored for each account linked to it.
+	pub type LinkedNameOf<T, I> = <<T as Config<I>>::NameProvider as DidNameProvider<DidIdentifierOf<T, I>>>::Name;
+
 	pub(crate) type BalanceMigrationManagerOf<T, I> = <T as Config<I>>::BalanceMigrationManager;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::composite_enum]
 	pub enum HoldReason<I: 'static = ()> {
@@ -131,6 +151,26 @@ pub mod pallet {
 		#[pallet::constant]
 		type Deposit: Get<BalanceOf<Self, I>>;
 
+		/// The amount of balance that will be taken, in addition to
+		/// `Deposit`, for each byte of metadata attached to a linked account.
+		#[pallet::constant]
+		type MetadataByteDeposit: Get<BalanceOf<Self, I>>;
+
+		/// The maximum length of the label of a linked account.
+		#[pallet::constant]
+		type MaxLabelLength: Get<u32>;
+
+		/// The maximum number of accounts that can be associated or
+		/// unassociated in a single call.
+		#[pallet::constant]
+		type MaxAssociationsPerBatch: Get<u32>;
+
+		/// The maximum number of accounts that can be linked to a single DID.
+		/// It bounds the number of stored names that are updated when the
+		/// name of a DID changes.
+		#[pallet::constant]
+		type MaxLinkedAccounts: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -141,6 +181,15 @@ pub mod pallet {
 		// link, or multiple.
 		#[pallet::constant]
 		type UniqueLinkingEnabled: Get<bool>;
+
+		/// The chain ID that is part of the EIP-712 domain for typed data
+		/// signatures of Ethereum accounts.
+		#[pallet::constant]
+		type Eip712ChainId: Get<u64>;
+
+		/// The provider of the DID names that are stored for each linked
+		/// account in `ConnectedNames`.
+		type NameProvider: DidNameProvider<Self::DidIdentifier>;
 	}
 
 	#[pallet::pallet]
@@ -161,6 +210,35 @@ pub mod pallet {
 	pub type ConnectedAccounts<T: Config<I>, I: 'static = ()> =
 		StorageDoubleMap<_, Blake2_128Concat, DidIdentifierOf<T, I>, Blake2_128Concat, LinkableAccountId, ()>;
 
+	/// Mapping from (DID + account identifier) -> metadata of the link.
+	/// Links without metadata have no entry.
+	#[pallet::storage]
+	pub type ConnectedAccountsMetadata<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DidIdentifierOf<T, I>,
+		Blake2_128Concat,
+		LinkableAccountId,
+		LinkMetadataOf<T, I>,
+	>;
+
+	/// Mapping from DID -> the linked account that is marked as primary.
+	#[pallet::storage]
+	pub type PrimaryAccounts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T, I>, LinkableAccountId>;
+
+	/// Mapping from DID -> the number of accounts linked to it.
+	#[pallet::storage]
+	pub type ConnectedAccountCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T, I>, u32, ValueQuery>;
+
+	/// Mapping from account identifiers to the name of the DID they are
+	/// linked to, so that the name can be resolved with a single read.
+	/// Accounts linked to a DID without a name have no entry.
+	#[pallet::storage]
+	pub type ConnectedNames<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, LinkableAccountId, LinkedNameOf<T, I>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -184,6 +262,15 @@ pub mod pallet {
 			/// The new deposit owner.
 			to: AccountIdOf<T>,
 		},
+		/// The metadata of a linked account has been updated.
+		AccountMetadataUpdated {
+			/// The DID the account is linked to.
+			did: DidIdentifierOf<T, I>,
+			/// The linked account.
+			account: LinkableAccountId,
+			/// The new metadata.
+			metadata: LinkMetadataOf<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -209,6 +296,8 @@ pub mod pallet {
 		/// The deployed pallet supports a single account <-> link, which has
 		/// already been previously created for the provided DID.
 		LinkExisting,
+		/// The DID has reached the maximum number of linked accounts.
+		TooManyLinkedAccounts,
 	}
 
 	#[pallet::genesis_config]
@@ -230,6 +319,7 @@ pub mod pallet {
 			for (acc, connection) in &self.links {
 				ConnectedDids::<T, I>::insert(acc, connection);
 				ConnectedAccounts::<T, I>::insert(&connection.did, acc, ());
+				Pallet::<T, I>::index_link(&connection.did, acc);
 			}
 		}
 	}
@@ -257,6 +347,10 @@ pub mod pallet {
 		/// method specific id of the did identifier and the block number after
 		/// which the signature should be regarded invalid.
 		///
+		/// Ethereum accounts can alternatively sign an EIP-712 `AccountLink`
+		/// message containing the DID URI and the expiration block, bound to
+		/// the domain returned by [`Pallet::eip712_domain`].
+		///
 		/// Emits `AssociationEstablished` and, optionally, `AssociationRemoved`
 		/// if there was a previous association for the account.
 		///
@@ -270,8 +364,12 @@ pub mod pallet {
 			<T as Config<I>>::WeightInfo::associate_account_multisig_sr25519().max(
 			<T as Config<I>>::WeightInfo::associate_account_multisig_ed25519().max(
 			<T as Config<I>>::WeightInfo::associate_account_multisig_ecdsa().max(
-			<T as Config<I>>::WeightInfo::associate_eth_account()
-		))))]
+			<T as Config<I>>::WeightInfo::associate_eth_account().max(
+			<T as Config<I>>::WeightInfo::associate_bitcoin_account().max(
+			<T as Config<I>>::WeightInfo::associate_solana_account().max(
+			<T as Config<I>>::WeightInfo::associate_cosmos_account().max(
+			<T as Config<I>>::WeightInfo::associate_eth_typed_data_account()
+		))))))))]
 		pub fn associate_account(
 			origin: OriginFor<T>,
 			req: AssociateAccountRequest,
@@ -296,7 +394,7 @@ pub mod pallet {
 			);
 
 			ensure!(
-				req.verify::<T::DidIdentifier, BlockNumberFor<T>>(&did_identifier, expiration),
+				req.verify::<T::DidIdentifier, BlockNumberFor<T>>(&did_identifier, expiration, &Self::eip712_domain()),
 				Error::<T, I>::NotAuthorized
 			);
 
@@ -439,38 +537,304 @@ pub mod pallet {
 			LinkableAccountDepositCollector::<T, I>::update_deposit::<BalanceMigrationManagerOf<T, I>>(&account)
 		}
 
+		/// Set the metadata of an account linked to the DID that authorized
+		/// this call.
+		///
+		/// Marking the account as primary removes the primary flag from the
+		/// previous primary account of the DID. Empty metadata removes any
+		/// stored metadata.
+		///
+		/// The deposit of the link is adjusted to cover the bytes of the
+		/// metadata and, for the primary account, of the entry marking it as
+		/// primary. The sender of the call becomes the deposit owner.
+		///
+		/// Emits `AccountMetadataUpdated` and, optionally,
+		/// `DepositOwnerChanged` if the sender was not the deposit owner.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::update_account_metadata())]
+		pub fn update_account_metadata(
+			origin: OriginFor<T>,
+			account: LinkableAccountId,
+			metadata: LinkMetadataOf<T, I>,
+		) -> DispatchResult {
+			let source = <T as Config<I>>::EnsureOrigin::ensure_origin(origin)?;
+			let subject = source.subject();
+			let sender = source.sender();
+
+			let record = ConnectedDids::<T, I>::get(&account).ok_or(Error::<T, I>::NotFound)?;
+			ensure!(record.did == subject, Error::<T, I>::NotAuthorized);
+
+			let previous_primary = PrimaryAccounts::<T, I>::get(&subject);
+			let mut replaced_primary = None;
+			if metadata.primary {
+				if let Some(previous_account) = previous_primary.filter(|previous| *previous != account) {
+					// The flag is cleared in place. If nothing else is set, the entry is removed
+					// since empty metadata is not stored.
+					ConnectedAccountsMetadata::<T, I>::mutate_exists(&subject, &previous_account, |entry| {
+						*entry = entry
+							.take()
+							.map(|mut previous_metadata| {
+								previous_metadata.primary = false;
+								previous_metadata
+							})
+							.filter(|previous_metadata| !previous_metadata.is_empty());
+					});
+					replaced_primary = Some(previous_account);
+				}
+				PrimaryAccounts::<T, I>::insert(&subject, &account);
+			} else if previous_primary.as_ref() == Some(&account) {
+				PrimaryAccounts::<T, I>::remove(&subject);
+			}
+
+			if metadata.is_empty() {
+				ConnectedAccountsMetadata::<T, I>::remove(&subject, &account);
+			} else {
+				ConnectedAccountsMetadata::<T, I>::insert(&subject, &account, &metadata);
+			}
+
+			if record.deposit.owner != sender {
+				let old_deposit_owner = LinkableAccountDepositCollector::<T, I>::change_deposit_owner::<
+					BalanceMigrationManagerOf<T, I>,
+				>(&account, sender.clone())?;
+
+				Self::deposit_event(Event::<T, I>::DepositOwnerChanged {
+					id: (subject.clone(), account.clone()),
+					from: old_deposit_owner,
+					to: sender,
+				});
+			}
+			LinkableAccountDepositCollector::<T, I>::update_deposit::<BalanceMigrationManagerOf<T, I>>(&account)?;
+			// The previous primary account no longer pays for the primary entry.
+			if let Some(previous_account) = replaced_primary {
+				LinkableAccountDepositCollector::<T, I>::update_deposit::<BalanceMigrationManagerOf<T, I>>(
+					&previous_account,
+				)?;
+			}
+
+			Self::deposit_event(Event::<T, I>::AccountMetadataUpdated {
+				did: subject,
+				account,
+				metadata,
+			});
+
+			Ok(())
+		}
+
+		/// Associate multiple accounts to the DID that authorized this call.
+		///
+		/// Each request is verified as in `associate_account`, using the same
+		/// expiration block. The deposits for all the links are reserved from
+		/// the sender at once.
+		///
+		/// Emits `AssociationEstablished` for each account and, optionally,
+		/// `AssociationRemoved` for each account that had a previous
+		/// association.
+		///
+		/// # <weight>
+		/// Weight: O(N) where N is the number of requests.
+		/// - Reads: N * (ConnectedDids + ConnectedAccounts) + DID Origin Check
+		/// - Writes: N * (ConnectedDids + ConnectedAccounts)
+		/// # </weight>
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::associate_accounts(reqs.len().saturated_into()))]
+		pub fn associate_accounts(
+			origin: OriginFor<T>,
+			reqs: BoundedVec<AssociateAccountRequest, <T as Config<I>>::MaxAssociationsPerBatch>,
+			expiration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let source = <T as Config<I>>::AssociateOrigin::ensure_origin(origin)?;
+			let did_identifier = source.subject();
+			let sender = source.sender();
+
+			ensure!(
+				frame_system::Pallet::<T>::current_block_number() <= expiration,
+				Error::<T, I>::OutdatedProof
+			);
+
+			let total_deposit = <T as Config<I>>::Deposit::get().saturating_mul(reqs.len().saturated_into());
+			ensure!(
+				<<T as Config<I>>::Currency as InspectHold<AccountIdOf<T>>>::can_hold(
+					&HoldReason::Deposit.into(),
+					&sender,
+					total_deposit
+				),
+				Error::<T, I>::InsufficientFunds
+			);
+
+			let eip712_domain = Self::eip712_domain();
+			ensure!(
+				reqs.iter().all(|req| req.verify::<T::DidIdentifier, BlockNumberFor<T>>(
+					&did_identifier,
+					expiration,
+					&eip712_domain
+				)),
+				Error::<T, I>::NotAuthorized
+			);
+
+			LinkableAccountDepositCollector::<T, I>::create_deposit(sender.clone(), total_deposit)?;
+
+			for req in reqs {
+				let account = req.get_linkable_account();
+				Self::ensure_can_link(&did_identifier, &account)?;
+				Self::insert_association(sender.clone(), did_identifier.clone(), account)?;
+			}
+
+			Ok(())
+		}
+
+		/// Remove the association of the provided accounts. This call doesn't
+		/// require the authorization of the account IDs, but the associated
+		/// DID of each account needs to match the DID that authorized this
+		/// call.
+		///
+		/// Emits `AssociationRemoved` for each account.
+		///
+		/// # <weight>
+		/// Weight: O(N) where N is the number of accounts.
+		/// - Reads: N * (ConnectedDids + ConnectedAccounts) + DID Origin Check
+		/// - Writes: N * (ConnectedDids + ConnectedAccounts)
+		/// # </weight>
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::remove_account_associations(accounts.len().saturated_into()))]
+		pub fn remove_account_associations(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<LinkableAccountId, <T as Config<I>>::MaxAssociationsPerBatch>,
+		) -> DispatchResult {
+			let source = <T as Config<I>>::EnsureOrigin::ensure_origin(origin)?;
+			let subject = source.subject();
+
+			for account in accounts {
+				let connection_record = ConnectedDids::<T, I>::get(&account).ok_or(Error::<T, I>::NotFound)?;
+				ensure!(connection_record.did == subject, Error::<T, I>::NotAuthorized);
+
+				Self::remove_association(account)?;
+			}
+
+			Ok(())
+		}
+
 		// Old call that was used to migrate
 		// #[pallet::call_index(254)]
 		// pub fn migrate(origin: OriginFor<T>, limit: u32) -> DispatchResult
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The EIP-712 domain that typed data signatures must be bound to.
+		pub fn eip712_domain() -> Eip712Domain {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			Eip712Domain {
+				chain_id: <T as Config<I>>::Eip712ChainId::get(),
+				// Runtimes with hashes that are not 32 bytes long use an all-zero salt.
+				genesis_hash: genesis_hash.as_ref().try_into().unwrap_or_default(),
+			}
+		}
+
+		/// The accounts linked to the DID, together with their metadata.
+		pub fn linked_accounts(
+			did_identifier: &DidIdentifierOf<T, I>,
+		) -> impl Iterator<Item = (LinkableAccountId, Option<LinkMetadataOf<T, I>>)> + '_ {
+			ConnectedAccounts::<T, I>::iter_key_prefix(did_identifier).map(move |account| {
+				let metadata = ConnectedAccountsMetadata::<T, I>::get(did_identifier, &account);
+				(account, metadata)
+			})
+		}
+
+		/// The deposit for a link with the given amount of metadata bytes.
+		pub(crate) fn link_deposit(metadata_length: usize) -> BalanceOf<T, I> {
+			let metadata_length = u32::try_from(metadata_length).unwrap_or(u32::MAX);
+			<T as Config<I>>::Deposit::get()
+				.saturating_add(<T as Config<I>>::MetadataByteDeposit::get().saturating_mul(metadata_length.into()))
+		}
+
+		/// Remove the metadata of a link that is about to be removed.
+		fn remove_link_metadata(did_identifier: &DidIdentifierOf<T, I>, account: &LinkableAccountId) {
+			ConnectedAccountsMetadata::<T, I>::remove(did_identifier, account);
+			if PrimaryAccounts::<T, I>::get(did_identifier).as_ref() == Some(account) {
+				PrimaryAccounts::<T, I>::remove(did_identifier);
+			}
+		}
+
 		pub fn add_association(
 			sender: AccountIdOf<T>,
 			did_identifier: DidIdentifierOf<T, I>,
 			account: LinkableAccountId,
 		) -> DispatchResult {
-			let deposit = Deposit {
-				owner: sender,
-				amount: T::Deposit::get(),
-			};
-			let record = ConnectionRecord {
-				deposit,
-				did: did_identifier.clone(),
-			};
+			Self::ensure_can_link(&did_identifier, &account)?;
+
+			LinkableAccountDepositCollector::<T, I>::create_deposit(sender.clone(), T::Deposit::get())?;
 
+			Self::insert_association(sender, did_identifier, account)
+		}
+
+		/// Fails if the DID cannot be linked to the account.
+		fn ensure_can_link(did_identifier: &DidIdentifierOf<T, I>, account: &LinkableAccountId) -> DispatchResult {
 			let is_unique_flag_enabled = <T as Config<I>>::UniqueLinkingEnabled::get();
 			if is_unique_flag_enabled {
-				let is_did_already_linked = ConnectedAccounts::<T, I>::iter_key_prefix(&did_identifier)
+				let is_did_already_linked = ConnectedAccounts::<T, I>::iter_key_prefix(did_identifier)
 					.next()
 					.is_some();
 				ensure!(!is_did_already_linked, Error::<T, I>::LinkExisting);
 			}
+			// Linking an account again to the same DID does not add a link.
+			if !ConnectedAccounts::<T, I>::contains_key(did_identifier, account) {
+				ensure!(
+					ConnectedAccountCount::<T, I>::get(did_identifier) < <T as Config<I>>::MaxLinkedAccounts::get(),
+					Error::<T, I>::TooManyLinkedAccounts
+				);
+			}
+			Ok(())
+		}
+
+		/// Count a new link of the DID and store the name of the DID for the
+		/// linked account.
+		pub(crate) fn index_link(did_identifier: &DidIdentifierOf<T, I>, account: &LinkableAccountId) {
+			ConnectedAccountCount::<T, I>::mutate(did_identifier, |count| count.saturating_inc());
+			match <T as Config<I>>::NameProvider::name_of(did_identifier) {
+				Some(name) => ConnectedNames::<T, I>::insert(account, name),
+				None => ConnectedNames::<T, I>::remove(account),
+			}
+		}
+
+		/// Uncount a link of the DID that is about to be removed and remove
+		/// the name stored for the linked account.
+		pub(crate) fn unindex_link(did_identifier: &DidIdentifierOf<T, I>, account: &LinkableAccountId) {
+			ConnectedAccountCount::<T, I>::mutate_exists(did_identifier, |count| {
+				*count = count
+					.map(|count| count.saturating_sub(1))
+					.filter(|count| !count.is_zero());
+			});
+			ConnectedNames::<T, I>::remove(account);
+		}
+
+		/// Update the name stored for each account linked to the DID after
+		/// the name of the DID has changed.
+		///
+		/// The number of updated accounts is bounded by `MaxLinkedAccounts`.
+		pub fn update_linked_names(did_identifier: &DidIdentifierOf<T, I>, name: Option<&LinkedNameOf<T, I>>) {
+			ConnectedAccounts::<T, I>::iter_key_prefix(did_identifier).for_each(|account| match name {
+				Some(name) => ConnectedNames::<T, I>::insert(&account, name),
+				None => ConnectedNames::<T, I>::remove(&account),
+			});
+		}
+
+		/// Store the link between the DID and the account, replacing any
+		/// previous link of the account.
+		///
+		/// The deposit of the link must have been already reserved from the
+		/// deposit owner.
+		fn insert_association(
+			deposit_owner: AccountIdOf<T>,
+			did_identifier: DidIdentifierOf<T, I>,
+			account: LinkableAccountId,
+		) -> DispatchResult {
+			let record = ConnectionRecord {
+				deposit: Deposit {
+					owner: deposit_owner,
+					amount: T::Deposit::get(),
+				},
+				did: did_identifier.clone(),
+			};
 
-			LinkableAccountDepositCollector::<T, I>::create_deposit(
-				record.clone().deposit.owner,
-				record.deposit.amount,
-			)?;
 			<T as Config<I>>::BalanceMigrationManager::exclude_key_from_migration(
 				&ConnectedDids::<T, I>::hashed_key_for(&account),
 			);
@@ -478,12 +842,15 @@ pub mod pallet {
 			ConnectedDids::<T, I>::mutate(&account, |did_entry| -> DispatchResult {
 				if let Some(old_connection) = did_entry.replace(record) {
 					ConnectedAccounts::<T, I>::remove(&old_connection.did, &account);
+					Self::remove_link_metadata(&old_connection.did, &account);
+					Self::unindex_link(&old_connection.did, &account);
 					Self::deposit_event(Event::<T, I>::AssociationRemoved(account.clone(), old_connection.did));
 					LinkableAccountDepositCollector::<T, I>::free_deposit(old_connection.deposit)?;
 				}
 				Ok(())
 			})?;
 			ConnectedAccounts::<T, I>::insert(&did_identifier, &account, ());
+			Self::index_link(&did_identifier, &account);
 			Self::deposit_event(Event::AssociationEstablished(account, did_identifier));
 
 			Ok(())
@@ -505,6 +872,8 @@ pub mod pallet {
 				}
 
 				ConnectedAccounts::<T, I>::remove(&connection.did, &account);
+				Self::remove_link_metadata(&connection.did, &account);
+				Self::unindex_link(&connection.did, &account);
 				Self::deposit_event(Event::AssociationRemoved(account, connection.did));
 				Ok(())
 			} else {
@@ -536,8 +905,20 @@ pub mod pallet {
 			Ok(record.deposit)
 		}
 
-		fn deposit_amount(_key: &LinkableAccountId) -> <Self::Currency as Inspect<AccountIdOf<T>>>::Balance {
-			T::Deposit::get()
+		fn deposit_amount(key: &LinkableAccountId) -> <Self::Currency as Inspect<AccountIdOf<T>>>::Balance {
+			let Some(record) = ConnectedDids::<T, I>::get(key) else {
+				return Pallet::<T, I>::link_deposit(0);
+			};
+			let metadata_length = ConnectedAccountsMetadata::<T, I>::get(&record.did, key)
+				.map(|metadata| metadata.encoded_size())
+				.unwrap_or_default();
+			// The primary account also pays for the entry marking it as primary.
+			let primary_length = if PrimaryAccounts::<T, I>::get(&record.did).as_ref() == Some(key) {
+				record.did.encoded_size().saturating_add(key.encoded_size())
+			} else {
+				0
+			};
+			Pallet::<T, I>::link_deposit(metadata_length.saturating_add(primary_length))
 		}
 
 		fn store_deposit(
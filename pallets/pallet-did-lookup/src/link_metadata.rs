@@ -0,0 +1,58 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+
+/// What a linked account is used for by the DID it is linked to.
+#[derive(Clone, Copy, Decode, Debug, Encode, TypeInfo, Eq, PartialEq, MaxEncodedLen, Serialize, Deserialize)]
+pub enum AccountPurpose {
+	/// The account receives payouts, e.g., rewards or refunds.
+	Payout,
+	/// The account is used for staking.
+	Staking,
+	/// The account is used for governance participation.
+	Governance,
+	/// The account holds funds that are rarely moved.
+	ColdStorage,
+	/// Any other purpose.
+	Other,
+}
+
+/// The metadata a DID attached to one of its linked accounts. Stored in the
+/// ConnectedAccountsMetadata map.
+#[derive(Clone, Decode, Debug, Default, Encode, TypeInfo, Eq, PartialEq, MaxEncodedLen, Serialize, Deserialize)]
+pub struct LinkMetadata<Label> {
+	/// Whether the account is the primary account of the DID. A DID has at
+	/// most one primary account.
+	pub primary: bool,
+
+	/// A short, human readable label for the account.
+	pub label: Option<Label>,
+
+	/// What the account is used for.
+	pub purpose: Option<AccountPurpose>,
+}
+
+impl<Label> LinkMetadata<Label> {
+	/// Whether no information is set. Empty metadata is not stored.
+	pub fn is_empty(&self) -> bool {
+		!self.primary && self.label.is_none() && self.purpose.is_none()
+	}
+}
@@ -21,7 +21,7 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_runtime::AccountId32;
 
-use crate::account::AccountId20;
+use crate::{account::AccountId20, bitcoin::BitcoinAddress, cosmos::CosmosAddress, solana::SolanaAddress};
 
 #[derive(
 	Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, MaxEncodedLen, TypeInfo, Serialize, Deserialize,
@@ -29,6 +29,9 @@ use crate::account::AccountId20;
 pub enum LinkableAccountId {
 	AccountId20(AccountId20),
 	AccountId32(AccountId32),
+	Bitcoin(BitcoinAddress),
+	Solana(SolanaAddress),
+	Cosmos(CosmosAddress),
 }
 
 impl From<AccountId20> for LinkableAccountId {
@@ -43,6 +46,24 @@ impl From<AccountId32> for LinkableAccountId {
 	}
 }
 
+impl From<BitcoinAddress> for LinkableAccountId {
+	fn from(address: BitcoinAddress) -> Self {
+		Self::Bitcoin(address)
+	}
+}
+
+impl From<SolanaAddress> for LinkableAccountId {
+	fn from(address: SolanaAddress) -> Self {
+		Self::Solana(address)
+	}
+}
+
+impl From<CosmosAddress> for LinkableAccountId {
+	fn from(address: CosmosAddress) -> Self {
+		Self::Cosmos(address)
+	}
+}
+
 impl From<[u8; 20]> for LinkableAccountId {
 	fn from(account_id: [u8; 20]) -> Self {
 		Self::AccountId20(account_id.into())
@@ -60,6 +81,9 @@ impl AsRef<[u8]> for LinkableAccountId {
 		match self {
 			LinkableAccountId::AccountId20(value) => &value.0,
 			LinkableAccountId::AccountId32(value) => value.as_ref(),
+			LinkableAccountId::Bitcoin(value) => value.as_ref(),
+			LinkableAccountId::Solana(value) => &value.0,
+			LinkableAccountId::Cosmos(value) => &value.0,
 		}
 	}
 }
@@ -70,6 +94,9 @@ impl std::fmt::Display for LinkableAccountId {
 		match self {
 			Self::AccountId20(account_id) => write!(f, "{}", account_id),
 			Self::AccountId32(account_id) => write!(f, "{}", account_id),
+			Self::Bitcoin(address) => write!(f, "{}", address),
+			Self::Solana(address) => write!(f, "{}", address),
+			Self::Cosmos(address) => write!(f, "{}", address),
 		}
 	}
 }
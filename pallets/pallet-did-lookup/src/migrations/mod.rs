@@ -16,6 +16,8 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
+pub mod v5;
+
 use frame_support::{
 	pallet_prelude::DispatchResult,
 	traits::{fungible::Inspect, ReservableCurrency},
@@ -0,0 +1,130 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+use sp_runtime::traits::Saturating;
+
+use crate::{traits::DidNameProvider, Config, ConnectedAccountCount, ConnectedDids, ConnectedNames};
+
+#[cfg(feature = "try-runtime")]
+const LOG_TARGET: &str = "migration::did-lookup";
+
+pub struct InnerMigrateV4ToV5<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for InnerMigrateV4ToV5<T, I> {
+	/// Return the number of links so we can check that they were all counted
+	/// in `InnerMigrateV4ToV5::post_upgrade`.
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		use sp_runtime::SaturatedConversion;
+
+		let links: u32 = ConnectedDids::<T, I>::iter_keys().count().saturated_into();
+		Ok(links.encode())
+	}
+
+	/// Migrate the storage from V4 to V5.
+	///
+	/// Each link is added to the count of accounts linked to its DID and the
+	/// name of the DID, if any, is stored for the linked account.
+	///
+	/// DIDs that already have more than `MaxLinkedAccounts` linked accounts
+	/// keep them, but cannot link any further account.
+	fn on_runtime_upgrade() -> Weight {
+		let mut links = 0u64;
+		let mut names = 0u64;
+		ConnectedDids::<T, I>::iter().for_each(|(account, record)| {
+			links.saturating_inc();
+			ConnectedAccountCount::<T, I>::mutate(&record.did, |count| count.saturating_inc());
+			if let Some(name) = <T as Config<I>>::NameProvider::name_of(&record.did) {
+				names.saturating_inc();
+				ConnectedNames::<T, I>::insert(&account, name);
+			}
+		});
+
+		// Three reads for each link, the count and the name of its DID, and one write
+		// for the count as well as for each stored name
+		T::DbWeight::get().reads_writes(links.saturating_mul(3), links.saturating_add(names))
+	}
+
+	/// Verifies the storage was migrated correctly.
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let links = u32::decode(&mut &state[..])
+			.map_err(|_| sp_runtime::TryRuntimeError::Other("Failed to decode the number of links"))?;
+
+		let counted =
+			ConnectedAccountCount::<T, I>::iter_values().fold(0u32, |total, count| total.saturating_add(count));
+		ensure!(
+			links == counted,
+			"the number of links before and after the migration should be the same"
+		);
+
+		log::info!(target: LOG_TARGET, "Counted {} linked accounts", counted);
+
+		crate::try_state::do_try_state::<T, I>()
+	}
+}
+
+pub type MigrateV4ToV5<T, I = ()> = frame_support::migrations::VersionedMigration<
+	4, // The migration will only execute when the on-chain storage version is 4
+	5, // The on-chain storage version will be set to 5 after the migration is complete
+	InnerMigrateV4ToV5<T, I>,
+	crate::pallet::Pallet<T, I>,
+	<T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	use super::*;
+	use crate::mock::*;
+
+	#[test]
+	fn counts_links_and_stores_names() {
+		ExtBuilder::default()
+			.with_balances(vec![
+				(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+				(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+			])
+			.with_connections(vec![
+				(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00),
+				(ACCOUNT_01, DID_00, LINKABLE_ACCOUNT_01),
+			])
+			.build_and_execute_with_sanity_tests(|| {
+				let name = MockName::truncate_from(b"alice".to_vec());
+				MockNameProvider::set_name(&DID_00, Some(name.clone()));
+
+				// reset the storage to the v4 layout
+				StorageVersion::new(4).put::<DidLookup>();
+				let _ = ConnectedAccountCount::<Test>::clear(u32::MAX, None);
+				let _ = ConnectedNames::<Test>::clear(u32::MAX, None);
+
+				MigrateV4ToV5::<Test>::on_runtime_upgrade();
+
+				assert_eq!(DidLookup::on_chain_storage_version(), StorageVersion::new(5));
+				assert_eq!(ConnectedAccountCount::<Test>::get(DID_00), 2);
+				assert!(!ConnectedAccountCount::<Test>::contains_key(DID_01));
+				assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_00), Some(name.clone()));
+				assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_01), Some(name));
+			});
+	}
+}
@@ -16,22 +16,22 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
-use frame_support::{pallet_prelude::ValueQuery, parameter_types, storage_alias};
+use frame_support::{pallet_prelude::ValueQuery, parameter_types, storage_alias, Blake2_128Concat, BoundedVec};
 use frame_system::pallet_prelude::BlockNumberFor;
 use kilt_support::{
 	mock::{mock_origin, SubjectId},
 	traits::StorageDepositCollector,
 };
 
-use sp_core::Get;
+use sp_core::{ConstU128, ConstU32, ConstU64, Get};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 	BuildStorage, MultiSignature,
 };
 
 use crate::{
-	self as pallet_did_lookup, linkable_account::LinkableAccountId, AccountIdOf, BalanceOf, Config, ConnectedAccounts,
-	ConnectedDids, ConnectionRecord, DidIdentifierOf, LinkableAccountDepositCollector,
+	self as pallet_did_lookup, linkable_account::LinkableAccountId, traits::DidNameProvider, AccountIdOf, BalanceOf,
+	Config, ConnectedAccounts, ConnectedDids, ConnectionRecord, DidIdentifierOf, LinkableAccountDepositCollector,
 };
 
 pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
@@ -132,6 +132,30 @@ impl Get<bool> for UniqueLinkEnabledFlag {
 	}
 }
 
+pub(crate) type MockName = BoundedVec<u8, ConstU32<32>>;
+
+pub struct MockNameProvider;
+
+#[storage_alias]
+type NameStorage = StorageMap<DidLookup, Blake2_128Concat, SubjectId, MockName>;
+
+impl MockNameProvider {
+	/// Set the name of the DID and update the names stored for its linked
+	/// accounts, as the runtime does when a web3name changes.
+	pub(crate) fn set_name(did: &SubjectId, name: Option<MockName>) {
+		NameStorage::set(did, name.clone());
+		pallet_did_lookup::Pallet::<Test>::update_linked_names(did, name.as_ref());
+	}
+}
+
+impl DidNameProvider<SubjectId> for MockNameProvider {
+	type Name = MockName;
+
+	fn name_of(did: &SubjectId) -> Option<MockName> {
+		NameStorage::get(did)
+	}
+}
+
 impl pallet_did_lookup::Config for Test {
 	type BalanceMigrationManager = ();
 	type RuntimeEvent = RuntimeEvent;
@@ -144,6 +168,12 @@ impl pallet_did_lookup::Config for Test {
 	type DidIdentifier = SubjectId;
 	type WeightInfo = ();
 	type UniqueLinkingEnabled = UniqueLinkEnabledFlag;
+	type Eip712ChainId = ConstU64<EIP712_CHAIN_ID>;
+	type MetadataByteDeposit = ConstU128<1>;
+	type MaxLabelLength = ConstU32<32>;
+	type MaxAssociationsPerBatch = ConstU32<10>;
+	type MaxLinkedAccounts = ConstU32<10>;
+	type NameProvider = MockNameProvider;
 }
 
 impl mock_origin::Config for Test {
@@ -152,6 +182,10 @@ impl mock_origin::Config for Test {
 	type SubjectId = SubjectId;
 }
 
+/// The chain ID of the EIP-712 domain. The KILT runtimes use the same value,
+/// see `constants::did_lookup::EIP712_CHAIN_ID` in `runtime-common`.
+pub(crate) const EIP712_CHAIN_ID: u64 = 2086;
+
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
 pub(crate) const DID_00: SubjectId = SubjectId(ACCOUNT_00);
@@ -176,11 +210,13 @@ pub(crate) fn insert_raw_connection<T: Config>(
 	ConnectedDids::<T>::mutate(&account, |did_entry| {
 		if let Some(old_connection) = did_entry.replace(record) {
 			ConnectedAccounts::<T>::remove(&old_connection.did, &account);
+			pallet_did_lookup::Pallet::<T>::unindex_link(&old_connection.did, &account);
 			LinkableAccountDepositCollector::<T>::free_deposit(old_connection.deposit)
 				.expect("Could not release deposit of account");
 		}
 	});
 	ConnectedAccounts::<T>::insert(&did_identifier, &account, ());
+	pallet_did_lookup::Pallet::<T>::index_link(&did_identifier, &account);
 }
 
 #[derive(Clone, Default)]
@@ -17,15 +17,30 @@
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
 use parity_scale_codec::alloc::string::ToString;
-use sp_std::vec::Vec;
+use sp_io::hashing::keccak_256;
+use sp_std::{vec, vec::Vec};
 
 // According to https://github.com/polkadot-js/common/blob/5d5c7e4c0ace06e3301ccadfd3c3351955f1e251/packages/util/src/u8a/wrap.ts#L13
 const PAYLOAD_BYTES_WRAPPER_PREFIX: &[u8; 7] = b"<Bytes>";
 const PAYLOAD_BYTES_WRAPPER_POSTFIX: &[u8; 8] = b"</Bytes>";
 const ETHEREUM_SIGNATURE_PREFIX: &[u8; 26] = b"\x19Ethereum Signed Message:\n";
+// According to https://github.com/bitcoin/bitcoin/blob/v27.0/src/common/signmessage.cpp#L17
+const BITCOIN_SIGNATURE_PREFIX: &[u8; 25] = b"\x18Bitcoin Signed Message:\n";
+// According to https://github.com/solana-labs/solana/blob/v1.18.0/sdk/src/offchain_message.rs#L19
+const SOLANA_OFFCHAIN_SIGNING_DOMAIN: &[u8; 16] = b"\xffsolana offchain";
+// Header version 0, message format 0 (restricted ASCII).
+const SOLANA_OFFCHAIN_HEADER: &[u8; 2] = &[0u8, 0u8];
+// According to https://eips.ethereum.org/EIPS/eip-712
+const EIP712_PREFIX: &[u8; 2] = b"\x19\x01";
+const EIP712_DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)";
+const EIP712_DOMAIN_NAME: &[u8] = b"KILT";
+const EIP712_DOMAIN_VERSION: &[u8] = b"1";
+const EIP712_ACCOUNT_LINK_TYPE: &[u8] = b"AccountLink(string did,uint256 expiration)";
 pub(crate) enum WrapType {
 	Substrate,
 	Ethereum,
+	Bitcoin,
+	Solana,
 }
 
 pub(crate) fn get_wrapped_payload(payload: &[u8], wrap_type: WrapType) -> Vec<u8> {
@@ -43,5 +58,84 @@ pub(crate) fn get_wrapped_payload(payload: &[u8], wrap_type: WrapType) -> Vec<u8
 			.chain(payload.iter())
 			.copied()
 			.collect(),
+		WrapType::Bitcoin => BITCOIN_SIGNATURE_PREFIX
+			.iter()
+			// bitcoin wrapping contains the length of the payload as compact size
+			.chain(compact_size(payload.len()).iter())
+			.chain(payload.iter())
+			.copied()
+			.collect(),
+		WrapType::Solana => SOLANA_OFFCHAIN_SIGNING_DOMAIN
+			.iter()
+			.chain(SOLANA_OFFCHAIN_HEADER.iter())
+			// Payloads longer than `u16::MAX` cannot be represented, and the resulting
+			// message will not match any valid signature.
+			.chain(u16::try_from(payload.len()).unwrap_or(u16::MAX).to_le_bytes().iter())
+			.chain(payload.iter())
+			.copied()
+			.collect(),
 	}
 }
+
+/// Build the EIP-712 encoding `0x19 0x01 ‖ domainSeparator ‖ hashStruct(message)`
+/// of an `AccountLink` message. Its Keccak-256 hash is what the wallet signs.
+///
+/// The domain is `{ name: "KILT", version: "1", chainId, salt: genesisHash }`.
+pub(crate) fn get_eip712_payload(did: &str, expiration: u128, chain_id: u64, genesis_hash: &[u8; 32]) -> Vec<u8> {
+	let domain_separator = keccak_256(
+		&[
+			&keccak_256(EIP712_DOMAIN_TYPE)[..],
+			&keccak_256(EIP712_DOMAIN_NAME),
+			&keccak_256(EIP712_DOMAIN_VERSION),
+			&encode_uint256(chain_id.into()),
+			genesis_hash,
+		]
+		.concat(),
+	);
+	let struct_hash = keccak_256(
+		&[
+			&keccak_256(EIP712_ACCOUNT_LINK_TYPE)[..],
+			&keccak_256(did.as_bytes()),
+			&encode_uint256(expiration),
+		]
+		.concat(),
+	);
+	[&EIP712_PREFIX[..], &domain_separator, &struct_hash].concat()
+}
+
+/// ABI encoding of an unsigned integer as a 32 byte big endian word.
+fn encode_uint256(value: u128) -> [u8; 32] {
+	let mut word = [0u8; 32];
+	let (_, low) = word.split_at_mut(16);
+	low.copy_from_slice(&value.to_be_bytes());
+	word
+}
+
+/// Bitcoin's variable length integer encoding, as used for message lengths.
+fn compact_size(length: usize) -> Vec<u8> {
+	match (u8::try_from(length), u16::try_from(length), u32::try_from(length)) {
+		(Ok(short), _, _) if short < 0xfd => vec![short],
+		(_, Ok(medium), _) => [&[0xfdu8][..], &medium.to_le_bytes()[..]].concat(),
+		(_, _, Ok(long)) => [&[0xfeu8][..], &long.to_le_bytes()[..]].concat(),
+		_ => {
+			let long = u64::try_from(length).unwrap_or(u64::MAX);
+			[&[0xffu8][..], &long.to_le_bytes()[..]].concat()
+		}
+	}
+}
+
+/// Verify a 64 byte compact secp256k1 signature over a pre-hashed message
+/// against a compressed public key.
+///
+/// The host only exposes public key recovery, so both possible recovery IDs
+/// are tried.
+pub(crate) fn verify_secp256k1_prehashed(signature: &[u8; 64], message_hash: &[u8; 32], public_key: &[u8; 33]) -> bool {
+	(0u8..=1).any(|recovery_id| {
+		let mut recoverable_signature = [0u8; 65];
+		let (rs, v) = recoverable_signature.split_at_mut(64);
+		rs.copy_from_slice(signature);
+		v.fill(recovery_id);
+		sp_io::crypto::secp256k1_ecdsa_recover_compressed(&recoverable_signature, message_hash)
+			.map_or(false, |recovered| recovered == *public_key)
+	})
+}
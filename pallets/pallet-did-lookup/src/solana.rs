@@ -0,0 +1,117 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! The Solana address and off-chain message signature implementation.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::ed25519;
+use sp_runtime::RuntimeDebug;
+
+use crate::signature::{get_wrapped_payload, WrapType};
+
+/// A Solana address, i.e., an ed25519 public key.
+#[derive(
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebug,
+	Serialize,
+	Deserialize,
+)]
+pub struct SolanaAddress(pub [u8; 32]);
+
+impl From<[u8; 32]> for SolanaAddress {
+	fn from(bytes: [u8; 32]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl From<ed25519::Public> for SolanaAddress {
+	fn from(public: ed25519::Public) -> Self {
+		Self(public.into())
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for SolanaAddress {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		use base58::ToBase58;
+
+		write!(f, "{}", self.0.to_base58())
+	}
+}
+
+/// An ed25519 signature over a Solana off-chain message.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct SolanaSignature(ed25519::Signature);
+
+impl From<ed25519::Signature> for SolanaSignature {
+	fn from(signature: ed25519::Signature) -> Self {
+		Self(signature)
+	}
+}
+
+impl SolanaSignature {
+	/// Verify the signature over the message, wrapped in the version 0
+	/// off-chain message format.
+	pub fn verify(&self, message: &[u8], address: &SolanaAddress) -> bool {
+		sp_io::crypto::ed25519_verify(
+			&self.0,
+			&get_wrapped_payload(message, WrapType::Solana),
+			&ed25519::Public::from_raw(address.0),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::Pair;
+
+	const MESSAGE: &[u8] = b"Publicly link the signing address to did:kilt:4abc before block number 5";
+
+	#[test]
+	fn test_offchain_message_format() {
+		let wrapped = get_wrapped_payload(b"abc", WrapType::Solana);
+		assert_eq!(wrapped, b"\xffsolana offchain\x00\x00\x03\x00abc".to_vec());
+	}
+
+	#[test]
+	fn test_verify() {
+		let pair = ed25519::Pair::generate().0;
+		let address = SolanaAddress::from(pair.public());
+		let signature = SolanaSignature::from(pair.sign(&get_wrapped_payload(MESSAGE, WrapType::Solana)));
+
+		assert!(signature.verify(MESSAGE, &address));
+		assert!(!signature.verify(b"another message", &address));
+		assert!(!signature.verify(MESSAGE, &SolanaAddress([0u8; 32])));
+
+		// A signature over the raw message is not accepted.
+		let raw_signature = SolanaSignature::from(pair.sign(MESSAGE));
+		assert!(!raw_signature.verify(MESSAGE, &address));
+	}
+}
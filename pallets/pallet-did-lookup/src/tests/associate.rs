@@ -16,23 +16,33 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
-use frame_support::{assert_noop, assert_ok, crypto::ecdsa::ECDSAExt, traits::fungible::InspectHold};
+use frame_support::{
+	assert_noop, assert_ok,
+	crypto::ecdsa::ECDSAExt,
+	traits::{fungible::InspectHold, Get},
+};
 use frame_system::pallet_prelude::BlockNumberFor;
-use kilt_support::{mock::mock_origin, Deposit};
+use kilt_support::{
+	mock::{mock_origin, SubjectId},
+	Deposit,
+};
 use parity_scale_codec::Encode;
 use sha3::{Digest, Keccak256};
 use sp_runtime::{
-	app_crypto::{ecdsa, sr25519, Pair},
+	app_crypto::{ecdsa, ed25519, sr25519, Pair},
 	traits::IdentifyAccount,
 	MultiSignature, MultiSigner,
 };
 
 use crate::{
 	account::{AccountId20, EthereumSignature},
-	associate_account_request::{get_challenge, AssociateAccountRequest},
+	associate_account_request::{get_challenge, get_did_uri, AssociateAccountRequest},
+	bitcoin::{bip137_message_hash, hash160, BitcoinAddress, BitcoinSignature},
+	cosmos::{adr036_sign_doc_hash, CosmosAddress, CosmosSignature},
 	linkable_account::LinkableAccountId,
 	mock::*,
-	signature::get_wrapped_payload,
+	signature::{get_eip712_payload, get_wrapped_payload},
+	solana::{SolanaAddress, SolanaSignature},
 	ConnectedAccounts, ConnectedDids, ConnectionRecord, Error, HoldReason,
 };
 
@@ -227,6 +237,203 @@ fn test_add_eth_association() {
 		});
 }
 
+#[test]
+fn test_add_eth_typed_data_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let eth_pair = ecdsa::Pair::generate().0;
+			let eth_account = AccountId20(eth_pair.public().to_eth_address().unwrap());
+			let domain = DidLookup::eip712_domain();
+			assert_eq!(domain.chain_id, EIP712_CHAIN_ID);
+
+			let typed_data_payload = get_eip712_payload(
+				&get_did_uri(&DID_00),
+				expire_at.into(),
+				domain.chain_id,
+				&domain.genesis_hash,
+			);
+			let sig = eth_pair.sign_prehashed(&Keccak256::digest(typed_data_payload).into());
+
+			// The typed data signature is not valid for a personal-sign request.
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::Ethereum(eth_account, EthereumSignature::from(sig.clone())),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+
+			// A signature bound to another chain is not accepted.
+			let other_chain_payload =
+				get_eip712_payload(&get_did_uri(&DID_00), expire_at.into(), 1, &domain.genesis_hash);
+			let other_chain_sig = eth_pair.sign_prehashed(&Keccak256::digest(other_chain_payload).into());
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					AssociateAccountRequest::EthereumTypedData(eth_account, EthereumSignature::from(other_chain_sig)),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::EthereumTypedData(eth_account, EthereumSignature::from(sig)),
+				expire_at,
+			));
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(eth_account)),
+				Some(ConnectionRecord {
+					did: DID_00,
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: 10,
+					}
+				})
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(eth_account)).is_some());
+		});
+}
+
+#[test]
+fn test_add_bitcoin_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let pair = ecdsa::Pair::generate().0;
+			let bitcoin_address = BitcoinAddress::P2wpkh(hash160(pair.public().as_ref()));
+
+			let raw_sig = pair.sign_prehashed(&bip137_message_hash(get_challenge(&DID_00, expire_at).as_bytes()));
+			let raw: &[u8] = raw_sig.as_ref();
+			let mut sig = [0u8; 65];
+			// Header for a P2WPKH address, including the recovery ID.
+			sig[0] = 39 + raw[64];
+			sig[1..].copy_from_slice(&raw[..64]);
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Bitcoin(bitcoin_address, BitcoinSignature::Bip137(sig)),
+				expire_at,
+			));
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(bitcoin_address)),
+				Some(ConnectionRecord {
+					did: DID_00,
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: 10,
+					}
+				})
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(bitcoin_address)).is_some());
+
+			// The signature does not authorize a link to another DID.
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+					AssociateAccountRequest::Bitcoin(bitcoin_address, BitcoinSignature::Bip137(sig)),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_add_solana_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let pair = ed25519::Pair::generate().0;
+			let solana_address = SolanaAddress::from(pair.public());
+
+			let sig = pair.sign(&get_wrapped_payload(
+				get_challenge(&DID_00, expire_at).as_bytes(),
+				crate::signature::WrapType::Solana,
+			));
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Solana(solana_address, SolanaSignature::from(sig)),
+				expire_at,
+			));
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(solana_address)),
+				Some(ConnectionRecord {
+					did: DID_00,
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: 10,
+					}
+				})
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(solana_address)).is_some());
+
+			// The signature does not authorize a link to another DID.
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+					AssociateAccountRequest::Solana(solana_address, SolanaSignature::from(sig)),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_add_cosmos_association() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let pair = ecdsa::Pair::generate().0;
+			let public_key: [u8; 33] = pair.public().as_ref().try_into().unwrap();
+			let cosmos_address = CosmosAddress(hash160(&public_key));
+
+			let sign_doc_hash =
+				adr036_sign_doc_hash(b"cosmos", &cosmos_address, get_challenge(&DID_00, expire_at).as_bytes()).unwrap();
+			let raw_sig = pair.sign_prehashed(&sign_doc_hash);
+			let sig = CosmosSignature {
+				hrp: b"cosmos".to_vec().try_into().unwrap(),
+				public_key,
+				signature: raw_sig.as_ref()[..64].try_into().unwrap(),
+			};
+
+			assert_ok!(DidLookup::associate_account(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				AssociateAccountRequest::Cosmos(cosmos_address, sig.clone()),
+				expire_at,
+			));
+			assert_eq!(
+				ConnectedDids::<Test>::get(LinkableAccountId::from(cosmos_address)),
+				Some(ConnectionRecord {
+					did: DID_00,
+					deposit: Deposit {
+						owner: ACCOUNT_00,
+						amount: 10,
+					}
+				})
+			);
+			assert!(ConnectedAccounts::<Test>::get(DID_00, LinkableAccountId::from(cosmos_address)).is_some());
+
+			// The signature does not authorize a link to another DID.
+			assert_noop!(
+				DidLookup::associate_account(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+					AssociateAccountRequest::Cosmos(cosmos_address, sig),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
 #[test]
 fn test_add_association_account_invalid_signature() {
 	ExtBuilder::default()
@@ -373,6 +580,184 @@ fn test_remove_association_account_not_authorized() {
 		});
 }
 
+fn polkadot_request_for(did: &SubjectId, seed: &[u8; 32], expire_at: BlockNumberFor<Test>) -> AssociateAccountRequest {
+	let pair = sr25519::Pair::from_seed(seed);
+	let account = MultiSigner::from(pair.public()).into_account();
+	let sig = MultiSignature::from(
+		pair.sign(&[b"<Bytes>", get_challenge(did, expire_at).as_bytes(), b"</Bytes>"].concat()[..]),
+	);
+	AssociateAccountRequest::Polkadot(account, sig)
+}
+
+#[test]
+fn test_add_associations_batch() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let req_alice = polkadot_request_for(&DID_00, b"Alice                           ", expire_at);
+			let req_bob = polkadot_request_for(&DID_00, b"Bob                             ", expire_at);
+			let account_alice = req_alice.get_linkable_account();
+			let account_bob = req_bob.get_linkable_account();
+
+			assert_ok!(DidLookup::associate_accounts(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				vec![req_alice, req_bob].try_into().unwrap(),
+				expire_at,
+			));
+			for account in [account_alice, account_bob] {
+				assert_eq!(
+					ConnectedDids::<Test>::get(&account),
+					Some(ConnectionRecord {
+						did: DID_00,
+						deposit: Deposit {
+							owner: ACCOUNT_00,
+							amount: 10,
+						}
+					})
+				);
+				assert!(ConnectedAccounts::<Test>::get(DID_00, account).is_some());
+			}
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as crate::Config>::Deposit::get() * 2
+			);
+		});
+}
+
+#[test]
+fn test_add_associations_batch_invalid_signature() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let req_alice = polkadot_request_for(&DID_00, b"Alice                           ", expire_at);
+			// Signed for another DID.
+			let req_bob = polkadot_request_for(&DID_01, b"Bob                             ", expire_at);
+
+			assert_noop!(
+				DidLookup::associate_accounts(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					vec![req_alice, req_bob].try_into().unwrap(),
+					expire_at,
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_add_associations_batch_insufficient_funds() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			// Enough for a single link, but not for two.
+			(
+				ACCOUNT_00,
+				<Test as crate::Config>::Deposit::get() + ExistentialDeposit::get(),
+			),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			let expire_at: BlockNumberFor<Test> = 500;
+			let req_alice = polkadot_request_for(&DID_00, b"Alice                           ", expire_at);
+			let req_bob = polkadot_request_for(&DID_00, b"Bob                             ", expire_at);
+
+			assert_noop!(
+				DidLookup::associate_accounts(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					vec![req_alice, req_bob].try_into().unwrap(),
+					expire_at,
+				),
+				Error::<Test>::InsufficientFunds
+			);
+		});
+}
+
+#[test]
+fn test_remove_associations_batch() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.with_connections(vec![
+			(ACCOUNT_00, DID_01, LINKABLE_ACCOUNT_00),
+			(ACCOUNT_01, DID_01, LINKABLE_ACCOUNT_01),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(DidLookup::remove_account_associations(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+				vec![LINKABLE_ACCOUNT_00, LINKABLE_ACCOUNT_01].try_into().unwrap(),
+			));
+			for account in [LINKABLE_ACCOUNT_00, LINKABLE_ACCOUNT_01] {
+				assert_eq!(ConnectedDids::<Test>::get(&account), None);
+				assert!(ConnectedAccounts::<Test>::get(DID_01, account).is_none());
+			}
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00), 0);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01), 0);
+		});
+}
+
+#[test]
+fn test_remove_associations_batch_not_authorized() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.with_connections(vec![
+			(ACCOUNT_00, DID_01, LINKABLE_ACCOUNT_00),
+			(ACCOUNT_01, DID_00, LINKABLE_ACCOUNT_01),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				DidLookup::remove_account_associations(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+					vec![LINKABLE_ACCOUNT_00, LINKABLE_ACCOUNT_01].try_into().unwrap(),
+				),
+				Error::<Test>::NotAuthorized
+			);
+		});
+}
+
+#[test]
+fn test_add_association_too_many_linked_accounts() {
+	let max_linked_accounts: u8 = <Test as crate::Config>::MaxLinkedAccounts::get().try_into().unwrap();
+	// Fill up the links of the DID, including the link of `ACCOUNT_00`.
+	let connections = (1..max_linked_accounts)
+		.map(|i| LinkableAccountId::from(AccountId::new([100 + i; 32])))
+		.chain([LINKABLE_ACCOUNT_00])
+		.map(|account| (ACCOUNT_00, DID_00, account))
+		.collect();
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.with_connections(connections)
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				DidLookup::associate_sender(mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into()),
+				Error::<Test>::TooManyLinkedAccounts
+			);
+
+			// Linking an account again does not add a link.
+			assert_ok!(DidLookup::associate_sender(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into()
+			));
+
+			// Another DID can still link the account.
+			assert_ok!(DidLookup::associate_sender(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_01).into()
+			));
+		})
+}
+
 #[test]
 fn test_add_association_with_unique_linking_enabled() {
 	ExtBuilder::default()
@@ -0,0 +1,254 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
+use kilt_support::mock::mock_origin;
+use sp_runtime::traits::Zero;
+
+use crate::{
+	mock::*, AccountPurpose, ConnectedAccountsMetadata, Error, Event, HoldReason, LinkMetadataOf, PrimaryAccounts,
+};
+
+fn cold_wallet_metadata() -> LinkMetadataOf<Test, ()> {
+	LinkMetadataOf::<Test, ()> {
+		primary: true,
+		label: Some(b"cold wallet".to_vec().try_into().unwrap()),
+		purpose: Some(AccountPurpose::ColdStorage),
+	}
+}
+
+#[test]
+fn test_update_account_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(DidLookup::update_account_metadata(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				cold_wallet_metadata(),
+			));
+			assert_eq!(
+				ConnectedAccountsMetadata::<Test>::get(DID_00, LINKABLE_ACCOUNT_00),
+				Some(cold_wallet_metadata())
+			);
+			assert_eq!(PrimaryAccounts::<Test>::get(DID_00), Some(LINKABLE_ACCOUNT_00));
+			// primary flag (1) + label (13) + purpose (2) + primary entry: DID (32) +
+			// account (33)
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as crate::Config>::Deposit::get() + 81
+			);
+			assert_eq!(
+				DidLookup::connected_dids(LINKABLE_ACCOUNT_00).map(|r| r.deposit.amount),
+				Some(<Test as crate::Config>::Deposit::get() + 81)
+			);
+			assert!(System::events().iter().any(|e| e.event
+				== Event::<Test>::AccountMetadataUpdated {
+					did: DID_00,
+					account: LINKABLE_ACCOUNT_00,
+					metadata: cold_wallet_metadata(),
+				}
+				.into()));
+
+			// Removing the metadata releases the additional deposit.
+			assert_ok!(DidLookup::update_account_metadata(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				Default::default(),
+			));
+			assert!(ConnectedAccountsMetadata::<Test>::get(DID_00, LINKABLE_ACCOUNT_00).is_none());
+			assert!(PrimaryAccounts::<Test>::get(DID_00).is_none());
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as crate::Config>::Deposit::get()
+			);
+		})
+}
+
+#[test]
+fn test_update_account_metadata_switch_primary() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![
+			(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00),
+			(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_01),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(DidLookup::update_account_metadata(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				cold_wallet_metadata(),
+			));
+			let staking_metadata = LinkMetadataOf::<Test, ()> {
+				primary: true,
+				label: None,
+				purpose: Some(AccountPurpose::Staking),
+			};
+			assert_ok!(DidLookup::update_account_metadata(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_01,
+				staking_metadata.clone(),
+			));
+
+			assert_eq!(PrimaryAccounts::<Test>::get(DID_00), Some(LINKABLE_ACCOUNT_01));
+			assert_eq!(
+				ConnectedAccountsMetadata::<Test>::get(DID_00, LINKABLE_ACCOUNT_01),
+				Some(staking_metadata)
+			);
+			// The previous primary account keeps its label and purpose.
+			assert_eq!(
+				ConnectedAccountsMetadata::<Test>::get(DID_00, LINKABLE_ACCOUNT_00),
+				Some(LinkMetadataOf::<Test, ()> {
+					primary: false,
+					..cold_wallet_metadata()
+				})
+			);
+			// The primary entry is paid for by the new primary account only.
+			assert_eq!(
+				DidLookup::connected_dids(LINKABLE_ACCOUNT_00).map(|r| r.deposit.amount),
+				Some(<Test as crate::Config>::Deposit::get() + 16)
+			);
+			assert_eq!(
+				DidLookup::connected_dids(LINKABLE_ACCOUNT_01).map(|r| r.deposit.amount),
+				Some(<Test as crate::Config>::Deposit::get() + 69)
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00),
+				<Test as crate::Config>::Deposit::get() * 2 + 85
+			);
+		})
+}
+
+#[test]
+fn test_update_account_metadata_switch_primary_removes_empty_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![
+			(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00),
+			(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_01),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			let primary_metadata = LinkMetadataOf::<Test, ()> {
+				primary: true,
+				label: None,
+				purpose: None,
+			};
+			assert_ok!(DidLookup::update_account_metadata(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				primary_metadata.clone(),
+			));
+			assert_ok!(DidLookup::update_account_metadata(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_01,
+				primary_metadata,
+			));
+
+			assert_eq!(PrimaryAccounts::<Test>::get(DID_00), Some(LINKABLE_ACCOUNT_01));
+			// Without the primary flag, nothing is left of the previous primary metadata.
+			assert!(ConnectedAccountsMetadata::<Test>::get(DID_00, LINKABLE_ACCOUNT_00).is_none());
+			assert_eq!(
+				DidLookup::connected_dids(LINKABLE_ACCOUNT_00).map(|r| r.deposit.amount),
+				Some(<Test as crate::Config>::Deposit::get())
+			);
+		})
+}
+
+#[test]
+fn test_update_account_metadata_changes_deposit_owner() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(DidLookup::update_account_metadata(
+				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				cold_wallet_metadata(),
+			));
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_01),
+				<Test as crate::Config>::Deposit::get() + 81
+			);
+			assert!(System::events().iter().any(|e| e.event
+				== Event::<Test>::DepositOwnerChanged {
+					id: (DID_00, LINKABLE_ACCOUNT_00),
+					from: ACCOUNT_00,
+					to: ACCOUNT_01
+				}
+				.into()));
+		})
+}
+
+#[test]
+fn test_update_account_metadata_not_found() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				DidLookup::update_account_metadata(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+					LINKABLE_ACCOUNT_00,
+					cold_wallet_metadata(),
+				),
+				Error::<Test>::NotFound
+			);
+		})
+}
+
+#[test]
+fn test_update_account_metadata_not_authorized() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				DidLookup::update_account_metadata(
+					mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into(),
+					LINKABLE_ACCOUNT_00,
+					cold_wallet_metadata(),
+				),
+				Error::<Test>::NotAuthorized
+			);
+		})
+}
+
+#[test]
+fn test_remove_association_removes_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(DidLookup::update_account_metadata(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00,
+				cold_wallet_metadata(),
+			));
+			assert_ok!(DidLookup::remove_account_association(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				LINKABLE_ACCOUNT_00
+			));
+			assert!(ConnectedAccountsMetadata::<Test>::get(DID_00, LINKABLE_ACCOUNT_00).is_none());
+			assert!(PrimaryAccounts::<Test>::get(DID_00).is_none());
+			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
+		})
+}
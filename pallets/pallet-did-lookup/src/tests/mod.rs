@@ -17,3 +17,5 @@
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 mod associate;
 mod deposit;
+mod metadata;
+mod names;
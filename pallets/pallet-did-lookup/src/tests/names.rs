@@ -0,0 +1,87 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use frame_support::assert_ok;
+use kilt_support::mock::mock_origin;
+
+use crate::{mock::*, ConnectedAccountCount, ConnectedNames};
+
+fn alice_name() -> MockName {
+	MockName::truncate_from(b"alice".to_vec())
+}
+
+#[test]
+fn test_add_association_stores_name() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.build_and_execute_with_sanity_tests(|| {
+			MockNameProvider::set_name(&DID_00, Some(alice_name()));
+
+			assert_ok!(DidLookup::associate_sender(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_00).into()
+			));
+			assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_00), Some(alice_name()));
+			assert_eq!(ConnectedAccountCount::<Test>::get(DID_00), 1);
+
+			// Linking the account to a DID without a name removes the name.
+			assert_ok!(DidLookup::associate_sender(
+				mock_origin::DoubleOrigin(ACCOUNT_00, DID_01).into()
+			));
+			assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_00), None);
+			assert!(!ConnectedAccountCount::<Test>::contains_key(DID_00));
+			assert_eq!(ConnectedAccountCount::<Test>::get(DID_01), 1);
+		});
+}
+
+#[test]
+fn test_remove_association_removes_name() {
+	ExtBuilder::default()
+		.with_balances(vec![(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50)])
+		.with_connections(vec![(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00)])
+		.build_and_execute_with_sanity_tests(|| {
+			MockNameProvider::set_name(&DID_00, Some(alice_name()));
+
+			assert_ok!(DidLookup::remove_sender_association(RuntimeOrigin::signed(ACCOUNT_00)));
+			assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_00), None);
+			assert!(!ConnectedAccountCount::<Test>::contains_key(DID_00));
+		});
+}
+
+#[test]
+fn test_update_linked_names() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(ACCOUNT_00, <Test as crate::Config>::Deposit::get() * 50),
+			(ACCOUNT_01, <Test as crate::Config>::Deposit::get() * 50),
+		])
+		.with_connections(vec![
+			(ACCOUNT_00, DID_00, LINKABLE_ACCOUNT_00),
+			(ACCOUNT_01, DID_00, LINKABLE_ACCOUNT_01),
+		])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_00), None);
+
+			MockNameProvider::set_name(&DID_00, Some(alice_name()));
+			assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_00), Some(alice_name()));
+			assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_01), Some(alice_name()));
+
+			MockNameProvider::set_name(&DID_00, None);
+			assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_00), None);
+			assert_eq!(ConnectedNames::<Test>::get(LINKABLE_ACCOUNT_01), None);
+		});
+}
@@ -0,0 +1,38 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use frame_support::Parameter;
+use parity_scale_codec::MaxEncodedLen;
+
+/// Provides the name of a DID, which is stored for each account linked to
+/// the DID so that the name can be resolved from the account with a single
+/// storage read.
+pub trait DidNameProvider<DidIdentifier> {
+	type Name: Parameter + MaxEncodedLen;
+
+	/// Return the current name of the DID, if any.
+	fn name_of(did: &DidIdentifier) -> Option<Self::Name>;
+}
+
+impl<DidIdentifier> DidNameProvider<DidIdentifier> for () {
+	type Name = ();
+
+	fn name_of(_did: &DidIdentifier) -> Option<Self::Name> {
+		None
+	}
+}
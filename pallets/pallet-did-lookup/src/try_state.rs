@@ -24,7 +24,10 @@ use sp_runtime::{
 	TryRuntimeError,
 };
 
-use crate::{Config, ConnectedAccounts, ConnectedDids, ConnectionRecord};
+use crate::{
+	traits::DidNameProvider, Config, ConnectedAccountCount, ConnectedAccounts, ConnectedAccountsMetadata,
+	ConnectedDids, ConnectedNames, ConnectionRecord, PrimaryAccounts,
+};
 
 pub(crate) fn do_try_state<T: Config<I>, I: 'static>() -> Result<(), TryRuntimeError> {
 	// Verify DID -> account link integrity.
@@ -33,6 +36,42 @@ pub(crate) fn do_try_state<T: Config<I>, I: 'static>() -> Result<(), TryRuntimeE
 			ConnectedAccounts::<T, I>::contains_key(&record.did, &account),
 			log_and_return_error_message(format!("Account {:?} with did {:?} not found", record.did, account))
 		);
+		ensure!(
+			ConnectedNames::<T, I>::get(&account) == <T as Config<I>>::NameProvider::name_of(&record.did),
+			log_and_return_error_message(format!(
+				"Name of account {:?} with did {:?} not match",
+				account, record.did
+			))
+		);
+		Ok(())
+	})?;
+
+	// Verify that names are only stored for linked accounts.
+	ConnectedNames::<T, I>::iter_keys().try_for_each(|account| -> Result<(), TryRuntimeError> {
+		ensure!(
+			ConnectedDids::<T, I>::contains_key(&account),
+			log_and_return_error_message(format!("Name for account {:?} without link", account))
+		);
+		Ok(())
+	})?;
+
+	// Verify that the number of linked accounts is tracked for each DID.
+	ConnectedAccounts::<T, I>::iter_keys().try_for_each(|(did_identifier, _)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			ConnectedAccountCount::<T, I>::contains_key(&did_identifier),
+			log_and_return_error_message(format!("Linked accounts of did {:?} not counted", did_identifier))
+		);
+		Ok(())
+	})?;
+	ConnectedAccountCount::<T, I>::iter().try_for_each(|(did_identifier, count)| -> Result<(), TryRuntimeError> {
+		let linked_accounts = ConnectedAccounts::<T, I>::iter_key_prefix(&did_identifier).count();
+		ensure!(
+			usize::try_from(count).is_ok_and(|count| count == linked_accounts),
+			log_and_return_error_message(format!(
+				"Count of linked accounts {:?} for did {:?} not match {:?}",
+				count, did_identifier, linked_accounts
+			))
+		);
 		Ok(())
 	})?;
 
@@ -50,6 +89,42 @@ pub(crate) fn do_try_state<T: Config<I>, I: 'static>() -> Result<(), TryRuntimeE
 		},
 	)?;
 
+	// Verify that metadata is only stored for existing links.
+	ConnectedAccountsMetadata::<T, I>::iter().try_for_each(
+		|(did_identifier, linked_account_id, metadata)| -> Result<(), TryRuntimeError> {
+			ensure!(
+				ConnectedAccounts::<T, I>::contains_key(&did_identifier, &linked_account_id),
+				log_and_return_error_message(format!(
+					"Metadata for account {:?} and did {:?} without link",
+					linked_account_id, did_identifier
+				))
+			);
+			ensure!(
+				metadata.primary == (PrimaryAccounts::<T, I>::get(&did_identifier) == Some(linked_account_id.clone())),
+				log_and_return_error_message(format!(
+					"Primary flag of account {:?} for did {:?} not match",
+					linked_account_id, did_identifier
+				))
+			);
+			Ok(())
+		},
+	)?;
+
+	// Verify that primary accounts are flagged as such in their metadata.
+	PrimaryAccounts::<T, I>::iter().try_for_each(
+		|(did_identifier, linked_account_id)| -> Result<(), TryRuntimeError> {
+			ensure!(
+				ConnectedAccountsMetadata::<T, I>::get(&did_identifier, &linked_account_id)
+					.is_some_and(|metadata| metadata.primary),
+				log_and_return_error_message(format!(
+					"Primary account {:?} for did {:?} not flagged",
+					linked_account_id, did_identifier
+				))
+			);
+			Ok(())
+		},
+	)?;
+
 	// Verify account <-> DID link unicity.
 	if <T as Config<I>>::UniqueLinkingEnabled::get() {
 		let mut did_linked_to_accounts = ConnectedDids::<T, I>::iter_values().map(|ConnectionRecord { did, .. }| did);
@@ -96,7 +96,13 @@ impl pallet_did_lookup::Config for TestRuntime {
 	type Currency = Balances;
 	type Deposit = ConstU64<1>;
 	type DidIdentifier = AccountId32;
+	type Eip712ChainId = ConstU64<0>;
 	type EnsureOrigin = EnsureDipOrigin<AccountId32, AccountId32, ()>;
+	type MaxAssociationsPerBatch = ConstU32<50>;
+	type MaxLabelLength = ConstU32<32>;
+	type MaxLinkedAccounts = ConstU32<50>;
+	type MetadataByteDeposit = ConstU64<1>;
+	type NameProvider = ();
 	type OriginSuccess = DipOrigin<AccountId32, AccountId32, ()>;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -0,0 +1,229 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_benchmarking::v2::*;
+use sp_std::vec::Vec;
+
+use crate::{AccountIdOf, Call, Config, FungiblesAssetIdOf, Pallet};
+
+/// Helper trait to set up the derivative token, collators and staking rewards
+/// used in benchmarks.
+pub trait BenchmarkHelper<T: Config> {
+	/// The asset id of the derivative token.
+	fn derivative_asset_id() -> FungiblesAssetIdOf<T>;
+
+	/// Register a collator which can be delegated to.
+	fn setup_collator(seed: u32) -> AccountIdOf<T>;
+
+	/// Let the delegations of `delegator` earn staking rewards.
+	fn accrue_rewards(delegator: &AccountIdOf<T>);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use frame_support::traits::{
+		fungible::{Inspect, Mutate},
+		fungibles::Inspect as InspectFungibles,
+		EnsureOrigin, Get, Hooks,
+	};
+	use frame_system::RawOrigin;
+	use sp_runtime::{
+		traits::{CheckedDiv, One, Saturating, Zero},
+		BoundedVec, SaturatedConversion,
+	};
+	use sp_std::vec;
+
+	use crate::{
+		traits::DelegateStake, BalanceOf, Collators, DerivativeAsset, QueuedUnbond, StringInputOf, Unbonding,
+		UnbondingBatches,
+	};
+
+	use super::*;
+
+	// helper functions
+	fn deposit_amount<T: Config>() -> BalanceOf<T> {
+		T::Staking::min_delegation()
+			.max(T::MinDeposit::get())
+			.saturating_mul(10u32.into())
+	}
+
+	fn fund<T: Config>(who: &AccountIdOf<T>) {
+		let amount = T::Currency::minimum_balance().saturating_add(deposit_amount::<T>().saturating_mul(10u32.into()));
+		T::Currency::set_balance(who, amount);
+	}
+
+	fn max_string<T: Config>(byte: u8) -> StringInputOf<T> {
+		BoundedVec::truncate_from(vec![byte; T::MaxStringInputLength::get().saturated_into()])
+	}
+
+	fn initialize_pallet<T: Config>() -> FungiblesAssetIdOf<T> {
+		let origin = T::ForceOrigin::try_successful_origin().expect("creating origin should not fail");
+		let asset_id = T::BenchmarkHelper::derivative_asset_id();
+		Pallet::<T>::initialize(origin, asset_id.clone(), max_string::<T>(b'n'), max_string::<T>(b's'))
+			.expect("Initializing the pallet should work");
+		asset_id
+	}
+
+	fn setup_collators<T: Config>(from: u32, n: u32) -> Vec<AccountIdOf<T>> {
+		(from..from.saturating_add(n))
+			.map(T::BenchmarkHelper::setup_collator)
+			.collect()
+	}
+
+	/// Initialize the pallet and deposit once for each of `n` collators, so
+	/// that the pallet account delegates to all of them. The delegations have
+	/// earned staking rewards which have not been claimed yet.
+	fn setup_pool<T: Config>(n: u32) -> FungiblesAssetIdOf<T> {
+		let asset_id = initialize_pallet::<T>();
+		Collators::<T>::put(BoundedVec::truncate_from(setup_collators::<T>(0, n)));
+
+		for i in 0..n {
+			let depositor = account("depositor", i, 0);
+			fund::<T>(&depositor);
+			Pallet::<T>::deposit(RawOrigin::Signed(depositor).into(), deposit_amount::<T>())
+				.expect("Depositing should work");
+		}
+		T::BenchmarkHelper::accrue_rewards(&Pallet::<T>::account_id());
+
+		asset_id
+	}
+
+	#[benchmark]
+	fn initialize() {
+		let origin = T::ForceOrigin::try_successful_origin().expect("creating origin should not fail");
+		let asset_id = T::BenchmarkHelper::derivative_asset_id();
+		let name = max_string::<T>(b'n');
+		let symbol = max_string::<T>(b's');
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset_id.clone(), name, symbol);
+
+		// Verify
+		assert_eq!(DerivativeAsset::<T>::get(), Some(asset_id));
+	}
+
+	#[benchmark]
+	fn set_collators(n: Linear<1, { T::MaxCollators::get() }>) {
+		setup_pool::<T>(n);
+		let origin = T::ForceOrigin::try_successful_origin().expect("creating origin should not fail");
+		let collators: crate::CollatorsOf<T> = BoundedVec::truncate_from(setup_collators::<T>(n, n));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, collators.clone());
+
+		// Verify
+		assert_eq!(Collators::<T>::get(), collators);
+		assert!(T::Staking::delegations(&Pallet::<T>::account_id()).is_empty());
+	}
+
+	#[benchmark]
+	fn deposit(n: Linear<1, { T::MaxCollators::get() }>) {
+		let asset_id = setup_pool::<T>(n);
+		let depositor: AccountIdOf<T> = account("depositor", n, 0);
+		fund::<T>(&depositor);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(depositor.clone()), deposit_amount::<T>());
+
+		// Verify
+		assert!(!T::Fungibles::balance(asset_id, &depositor).is_zero());
+	}
+
+	#[benchmark]
+	fn redeem(n: Linear<1, { T::MaxCollators::get() }>) {
+		let asset_id = setup_pool::<T>(n);
+		let redeemer: AccountIdOf<T> = account("depositor", 0, 0);
+		let amount = T::Fungibles::balance(asset_id.clone(), &redeemer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(redeemer.clone()), amount);
+
+		// Verify
+		assert!(T::Fungibles::balance(asset_id, &redeemer).is_zero());
+		assert!(!QueuedUnbond::<T>::get().is_zero());
+	}
+
+	#[benchmark]
+	fn withdraw_unbonded(u: Linear<1, { T::MaxUnbondingRequests::get() }>) {
+		let asset_id = setup_pool::<T>(T::MaxCollators::get());
+		let redeemer: AccountIdOf<T> = account("depositor", 0, 0);
+		let amount = T::Fungibles::balance(asset_id, &redeemer)
+			.checked_div(&u.into())
+			.unwrap_or_default();
+
+		let mut now = frame_system::Pallet::<T>::block_number();
+		for _ in 0..u {
+			Pallet::<T>::redeem(RawOrigin::Signed(redeemer.clone()).into(), amount).expect("Redeeming should work");
+			Pallet::<T>::process_batch(now);
+			now = now.saturating_add(One::one());
+			frame_system::Pallet::<T>::set_block_number(now);
+		}
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::UnbondingPeriod::get()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(redeemer.clone()));
+
+		// Verify
+		assert!(Unbonding::<T>::get(&redeemer).is_empty());
+	}
+
+	#[benchmark]
+	fn compound(n: Linear<1, { T::MaxCollators::get() }>) {
+		setup_pool::<T>(n);
+		let caller: AccountIdOf<T> = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		// Verify
+		assert_eq!(
+			T::Staking::delegations(&Pallet::<T>::account_id()).len(),
+			n.saturated_into::<usize>()
+		);
+	}
+
+	#[benchmark]
+	fn on_initialize_unbond_batch(n: Linear<1, { T::MaxCollators::get() }>) {
+		let asset_id = setup_pool::<T>(n);
+		for i in 0..n {
+			let redeemer: AccountIdOf<T> = account("depositor", i, 0);
+			let amount = T::Fungibles::balance(asset_id.clone(), &redeemer);
+			Pallet::<T>::redeem(RawOrigin::Signed(redeemer).into(), amount).expect("Redeeming should work");
+		}
+		let now = T::BatchPeriod::get();
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(now);
+		}
+
+		// Verify
+		assert!(QueuedUnbond::<T>::get().is_zero());
+		assert!(UnbondingBatches::<T>::get(0).is_some());
+	}
+
+	#[cfg(test)]
+	mod benchmark_tests {
+		use crate::Pallet;
+
+		frame_benchmarking::v2::impl_benchmark_test_suite!(
+			Pallet,
+			crate::mock::ExtBuilder::default().build(),
+			crate::mock::Test
+		);
+	}
+}
@@ -0,0 +1,710 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! # Liquid Staking
+//!
+//! Staked KILT is frozen until it has been unstaked and `StakeDuration`
+//! blocks have passed. This pallet provides a liquid derivative token for
+//! staked KILT instead.
+//!
+//! Users `deposit` KILT into the account of the pallet and receive the
+//! derivative token in exchange, which is minted via the `Fungibles`
+//! implementation. The pallet account delegates the deposited funds to the
+//! collators selected by governance via `set_collators`, always backing the
+//! collator it has staked the least for. The staking rewards are claimed
+//! and staked again whenever the pallet is used or `compound` is called.
+//! They are not paid out to the holders but increase the amount of KILT
+//! backing each derivative token instead.
+//!
+//! Derivative tokens are burnt via `redeem` in exchange for their share of
+//! KILT. Redemptions go through an unbonding queue: all redemptions of a
+//! batch are unstaked together at the next multiple of `BatchPeriod` blocks,
+//! so that the pallet account only requires a single unstaking request per
+//! batch. The redeemed funds can be withdrawn via `withdraw_unbonded` once
+//! `UnbondingPeriod` blocks have passed after the batch has been unstaked.
+//!
+//! Any loss of the pallet account, e.g., due to a slash of its delegations,
+//! is shared by all holders of the derivative token.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+#[cfg(any(feature = "try-runtime", test))]
+mod try_state;
+
+mod default_weights;
+pub mod traits;
+mod types;
+#[cfg(feature = "runtime-benchmarks")]
+pub use benchmarking::BenchmarkHelper;
+
+pub use types::UnbondingBatch;
+
+pub use default_weights::WeightInfo;
+
+#[frame_support::pallet]
+// `.expect()` is used in the macro-generated code, and we have to ignore it.
+#[allow(clippy::expect_used)]
+// `unreachable` is used in the macro-generated code, and we have to ignore it.
+#[allow(clippy::unreachable)]
+// `ref` keyword is used in the macro-generated code, and we have to ignore it.
+#[allow(clippy::ref_patterns)]
+// The `pallet::event` macro shadows the `deposit_event` definition of `frame_system::Config`.
+#[allow(clippy::shadow_reuse)]
+// map_err is used over inspect_err in the macro-generated code, and we have to ignore it.
+#[allow(clippy::manual_inspect)]
+pub mod pallet {
+
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{
+			fungible::{Inspect as InspectFungible, Mutate as MutateFungible},
+			fungibles::{
+				metadata::Mutate as MutateMetadata, Create as CreateFungibles, Inspect as InspectFungibles,
+				Mutate as MutateFungibles,
+			},
+			tokens::{Fortitude, Precision, Preservation},
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{AccountIdConversion, CheckedRem, One, SaturatedConversion, Saturating, Zero},
+		Rounding,
+	};
+	use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+	use crate::{traits::DelegateStake, types::UnbondingBatch, WeightInfo};
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+	pub(crate) type BalanceOf<T> = <<T as Config>::Currency as InspectFungible<AccountIdOf<T>>>::Balance;
+
+	pub type FungiblesAssetIdOf<T> = <<T as Config>::Fungibles as InspectFungibles<AccountIdOf<T>>>::AssetId;
+
+	pub(crate) type StringInputOf<T> = BoundedVec<u8, <T as Config>::MaxStringInputLength>;
+
+	pub(crate) type CollatorsOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxCollators>;
+
+	pub(crate) type UnbondingRequestsOf<T> =
+		BoundedBTreeMap<BatchIndex, BalanceOf<T>, <T as Config>::MaxUnbondingRequests>;
+
+	pub type UnbondingBatchOf<T> = UnbondingBatch<BlockNumberFor<T>, BalanceOf<T>>;
+
+	/// Index of a batch of redemptions which are unstaked together.
+	pub type BatchIndex = u32;
+
+	pub(crate) const LOG_TARGET: &str = "runtime::pallet-liquid-staking";
+
+	/// Configure the pallet by specifying the parameters and types on which it
+	/// depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's
+		/// definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The currency which is staked.
+		type Currency: MutateFungible<Self::AccountId>;
+		/// Implementation of creating and managing the derivative token.
+		type Fungibles: CreateFungibles<Self::AccountId>
+			+ MutateMetadata<Self::AccountId>
+			+ MutateFungibles<Self::AccountId>
+			+ InspectFungibles<Self::AccountId, Balance = BalanceOf<Self>>;
+		/// The staking implementation used to delegate the deposited funds.
+		type Staking: DelegateStake<Self::AccountId, BalanceOf<Self>>;
+		/// The id of the pallet, from which its account is derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// The origin for selecting the collators and creating the derivative
+		/// token.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The maximum number of collators the pallet account delegates to.
+		/// This must not exceed the number of collators a single delegator can
+		/// back.
+		#[pallet::constant]
+		type MaxCollators: Get<u32>;
+		/// The maximum number of batches an account can have pending
+		/// redemptions in.
+		#[pallet::constant]
+		type MaxUnbondingRequests: Get<u32>;
+		/// The minimum amount which can be deposited.
+		#[pallet::constant]
+		type MinDeposit: Get<BalanceOf<Self>>;
+		/// The number of blocks after which the queued redemptions are
+		/// unstaked. This should be long enough for the staking
+		/// implementation to accept one unstaking request per batch.
+		#[pallet::constant]
+		type BatchPeriod: Get<BlockNumberFor<Self>>;
+		/// The number of blocks after which unstaked funds can be withdrawn.
+		/// This must not be shorter than the unstaking period of the staking
+		/// implementation.
+		#[pallet::constant]
+		type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+		/// The denomination of the derivative token.
+		#[pallet::constant]
+		type Decimals: Get<u8>;
+
+		#[pallet::constant]
+		type MaxStringInputLength: Get<u32>;
+
+		type WeightInfo: WeightInfo;
+
+		/// Benchmark helper to set up the derivative token, collators and
+		/// staking rewards.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::benchmarking::BenchmarkHelper<Self>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			if now.checked_rem(&T::BatchPeriod::get()).is_some_and(|r| r.is_zero()) {
+				Self::process_batch(now)
+			} else {
+				Weight::zero()
+			}
+		}
+
+		fn integrity_test() {
+			assert!(
+				!T::BatchPeriod::get().is_zero(),
+				"`BatchPeriod` must be greater than zero, otherwise redemptions are never unstaked."
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			crate::try_state::do_try_state::<T>()
+		}
+	}
+
+	/// The asset id of the derivative token, set via `initialize`.
+	#[pallet::storage]
+	#[pallet::getter(fn derivative_asset)]
+	pub type DerivativeAsset<T: Config> = StorageValue<_, FungiblesAssetIdOf<T>, OptionQuery>;
+
+	/// The collators the pallet account delegates to.
+	#[pallet::storage]
+	#[pallet::getter(fn collators)]
+	pub type Collators<T: Config> = StorageValue<_, CollatorsOf<T>, ValueQuery>;
+
+	/// The amount of KILT backing the supply of the derivative token.
+	#[pallet::storage]
+	#[pallet::getter(fn total_pooled)]
+	pub type TotalPooled<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The amount of KILT redeemed in the current batch, which is still
+	/// staked.
+	#[pallet::storage]
+	#[pallet::getter(fn queued_unbond)]
+	pub type QueuedUnbond<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The amount of KILT redeemed in unstaked batches, which has not been
+	/// withdrawn yet.
+	#[pallet::storage]
+	#[pallet::getter(fn total_unbonding)]
+	pub type TotalUnbonding<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The index of the batch which collects the current redemptions.
+	#[pallet::storage]
+	#[pallet::getter(fn current_batch)]
+	pub type CurrentBatch<T: Config> = StorageValue<_, BatchIndex, ValueQuery>;
+
+	/// The unstaked batches with funds which have not been withdrawn yet.
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding_batch)]
+	pub type UnbondingBatches<T: Config> = StorageMap<_, Twox64Concat, BatchIndex, UnbondingBatchOf<T>, OptionQuery>;
+
+	/// The amount of KILT redeemed by an account per batch.
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding)]
+	pub type Unbonding<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, UnbondingRequestsOf<T>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The derivative token has been created.
+		Initialized { asset_id: FungiblesAssetIdOf<T> },
+		/// The collators the pallet account delegates to have been set.
+		CollatorsSet { collators: CollatorsOf<T> },
+		/// KILT has been deposited in exchange for derivative tokens.
+		Deposited {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			minted: BalanceOf<T>,
+		},
+		/// Derivative tokens have been redeemed for KILT, which is unstaked
+		/// with the given batch.
+		Redeemed {
+			who: T::AccountId,
+			burnt: BalanceOf<T>,
+			amount: BalanceOf<T>,
+			batch: BatchIndex,
+		},
+		/// The redemptions of a batch have been unstaked and can be withdrawn
+		/// from the given block on.
+		BatchUnbonded {
+			batch: BatchIndex,
+			amount: BalanceOf<T>,
+			unlock_at: BlockNumberFor<T>,
+		},
+		/// Redeemed KILT has been withdrawn.
+		Withdrawn { who: T::AccountId, amount: BalanceOf<T> },
+		/// Staking rewards have been claimed and added to the pooled KILT.
+		RewardsCompounded { rewards: BalanceOf<T> },
+		/// A loss of the pallet account has been deducted from the pooled
+		/// KILT.
+		LossApplied { amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The derivative token has not been created yet.
+		NotInitialized,
+		/// The derivative token has already been created.
+		AlreadyInitialized,
+		/// The deposit is below `MinDeposit`.
+		DepositBelowMinimum,
+		/// The amount of minted or redeemed funds would be zero.
+		ZeroAmount,
+		/// The account has pending redemptions in too many batches.
+		TooManyUnbondingRequests,
+		/// No redeemed funds can be withdrawn yet.
+		NothingToWithdraw,
+		/// A collator is included more than once.
+		DuplicateCollator,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create the derivative token with the pallet account as its owner.
+		///
+		/// The metadata deposit, if any, is reserved from the pallet account.
+		///
+		/// The dispatch origin must be `ForceOrigin`.
+		///
+		/// Emits `Initialized`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::initialize())]
+		pub fn initialize(
+			origin: OriginFor<T>,
+			asset_id: FungiblesAssetIdOf<T>,
+			name: StringInputOf<T>,
+			symbol: StringInputOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(DerivativeAsset::<T>::get().is_none(), Error::<T>::AlreadyInitialized);
+
+			let pallet_account = Self::account_id();
+			T::Fungibles::create(asset_id.clone(), pallet_account.clone(), false, BalanceOf::<T>::one())?;
+			T::Fungibles::set(
+				asset_id.clone(),
+				&pallet_account,
+				name.into_inner(),
+				symbol.into_inner(),
+				T::Decimals::get(),
+			)?;
+			DerivativeAsset::<T>::put(asset_id.clone());
+
+			Self::deposit_event(Event::Initialized { asset_id });
+			Ok(())
+		}
+
+		/// Set the collators the pallet account delegates to.
+		///
+		/// Delegations to collators which are not included anymore are
+		/// revoked. The unstaked funds are delegated to the new collators once
+		/// they have been unlocked.
+		///
+		/// The dispatch origin must be `ForceOrigin`.
+		///
+		/// Emits `CollatorsSet`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_collators(T::MaxCollators::get()))]
+		pub fn set_collators(origin: OriginFor<T>, collators: CollatorsOf<T>) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let mut seen = BTreeSet::new();
+			ensure!(
+				collators.iter().all(|collator| seen.insert(collator)),
+				Error::<T>::DuplicateCollator
+			);
+
+			let pallet_account = Self::account_id();
+			for (collator, stake) in T::Staking::delegations(&pallet_account) {
+				if !collators.contains(&collator) {
+					T::Staking::undelegate(&pallet_account, &collator, stake)?;
+				}
+			}
+			Collators::<T>::put(collators.clone());
+
+			Self::deposit_event(Event::CollatorsSet { collators });
+			Ok(())
+		}
+
+		/// Deposit KILT in exchange for derivative tokens at the current
+		/// exchange rate.
+		///
+		/// The deposit is staked for the collator the pallet account has
+		/// staked the least for. If it cannot be staked yet, e.g., because it
+		/// is below the minimum delegation of a new collator, it is staked
+		/// with a later deposit or call to `compound`.
+		///
+		/// Emits `Deposited`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::deposit(T::MaxCollators::get()))]
+		pub fn deposit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let asset_id = DerivativeAsset::<T>::get().ok_or(Error::<T>::NotInitialized)?;
+			ensure!(amount >= T::MinDeposit::get(), Error::<T>::DepositBelowMinimum);
+
+			Self::harvest()?;
+			let minted = Self::to_derivative(&asset_id, amount);
+			ensure!(!minted.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Currency::transfer(&who, &Self::account_id(), amount, Preservation::Preserve)?;
+			T::Fungibles::mint_into(asset_id, &who, minted)?;
+			TotalPooled::<T>::mutate(|pooled| *pooled = pooled.saturating_add(amount));
+			Self::delegate_idle();
+
+			Self::deposit_event(Event::Deposited { who, amount, minted });
+			Ok(())
+		}
+
+		/// Burn derivative tokens in exchange for KILT at the current exchange
+		/// rate.
+		///
+		/// The KILT is unstaked with the current batch and can be withdrawn
+		/// via `withdraw_unbonded` once the batch has been unstaked and
+		/// `UnbondingPeriod` blocks have passed.
+		///
+		/// Emits `Redeemed`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::redeem(T::MaxCollators::get()))]
+		pub fn redeem(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let asset_id = DerivativeAsset::<T>::get().ok_or(Error::<T>::NotInitialized)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			Self::harvest()?;
+			let redeemed = Self::to_native(&asset_id, amount);
+			ensure!(!redeemed.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Fungibles::burn_from(
+				asset_id,
+				&who,
+				amount,
+				Preservation::Expendable,
+				Precision::Exact,
+				Fortitude::Polite,
+			)?;
+
+			let batch = CurrentBatch::<T>::get();
+			Unbonding::<T>::try_mutate(&who, |requests| -> DispatchResult {
+				let total = redeemed.saturating_add(requests.get(&batch).copied().unwrap_or_default());
+				requests
+					.try_insert(batch, total)
+					.map_err(|_| Error::<T>::TooManyUnbondingRequests)?;
+				Ok(())
+			})?;
+			TotalPooled::<T>::mutate(|pooled| *pooled = pooled.saturating_sub(redeemed));
+			QueuedUnbond::<T>::mutate(|queued| *queued = queued.saturating_add(redeemed));
+
+			Self::deposit_event(Event::Redeemed {
+				who,
+				burnt: amount,
+				amount: redeemed,
+				batch,
+			});
+			Ok(())
+		}
+
+		/// Withdraw the redeemed KILT of all batches which can be withdrawn.
+		///
+		/// Emits `Withdrawn`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded(T::MaxUnbondingRequests::get()))]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut requests = Unbonding::<T>::get(&who);
+
+			let withdrawable: Vec<BatchIndex> = requests
+				.keys()
+				.filter(|batch| UnbondingBatches::<T>::get(batch).is_some_and(|b| b.unlock_at <= now))
+				.copied()
+				.collect();
+			ensure!(!withdrawable.is_empty(), Error::<T>::NothingToWithdraw);
+
+			let mut amount = BalanceOf::<T>::zero();
+			for batch in withdrawable {
+				let unbonded = requests.remove(&batch).unwrap_or_default();
+				amount = amount.saturating_add(unbonded);
+				UnbondingBatches::<T>::mutate_exists(batch, |maybe_batch| {
+					if let Some(unbonding_batch) = maybe_batch {
+						unbonding_batch.remaining = unbonding_batch.remaining.saturating_sub(unbonded);
+						if unbonding_batch.remaining.is_zero() {
+							*maybe_batch = None;
+						}
+					}
+				});
+			}
+
+			let pallet_account = Self::account_id();
+			T::Staking::unlock(&pallet_account)?;
+			T::Currency::transfer(&pallet_account, &who, amount, Preservation::Expendable)?;
+			TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(amount));
+
+			if requests.is_empty() {
+				Unbonding::<T>::remove(&who);
+			} else {
+				Unbonding::<T>::insert(&who, requests);
+			}
+
+			Self::deposit_event(Event::Withdrawn { who, amount });
+			Ok(())
+		}
+
+		/// Claim the staking rewards of the pallet account and stake them
+		/// together with any other funds which are not staked yet.
+		///
+		/// Can be called by anyone.
+		///
+		/// Emits `RewardsCompounded` if rewards have been claimed.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::compound(T::MaxCollators::get()))]
+		pub fn compound(origin: OriginFor<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(DerivativeAsset::<T>::get().is_some(), Error::<T>::NotInitialized);
+
+			Self::harvest()?;
+			Self::delegate_idle();
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account of the pallet which holds and stakes the deposits.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// The amount of derivative tokens minted for depositing `amount`
+		/// KILT.
+		pub(crate) fn to_derivative(asset_id: &FungiblesAssetIdOf<T>, amount: BalanceOf<T>) -> BalanceOf<T> {
+			let supply = T::Fungibles::total_issuance(asset_id.clone());
+			let pooled = TotalPooled::<T>::get();
+			if supply.is_zero() || pooled.is_zero() {
+				return amount;
+			}
+			Self::mul_div(amount, supply, pooled)
+		}
+
+		/// The amount of KILT paid out for redeeming `amount` derivative
+		/// tokens.
+		pub(crate) fn to_native(asset_id: &FungiblesAssetIdOf<T>, amount: BalanceOf<T>) -> BalanceOf<T> {
+			let supply = T::Fungibles::total_issuance(asset_id.clone());
+			if supply.is_zero() {
+				return BalanceOf::<T>::zero();
+			}
+			Self::mul_div(amount, TotalPooled::<T>::get(), supply)
+		}
+
+		fn mul_div(value: BalanceOf<T>, numerator: BalanceOf<T>, denominator: BalanceOf<T>) -> BalanceOf<T> {
+			multiply_by_rational_with_rounding(
+				value.saturated_into(),
+				numerator.saturated_into(),
+				denominator.saturated_into(),
+				Rounding::Down,
+			)
+			.map(|result| result.saturated_into())
+			.unwrap_or_else(Zero::zero)
+		}
+
+		/// Claim the staking rewards of the pallet account and add them to the
+		/// pooled KILT. Any shortfall of the balance of the pallet account is
+		/// deducted from the pooled KILT.
+		///
+		/// Returns the amount of claimed rewards.
+		pub(crate) fn harvest() -> Result<BalanceOf<T>, DispatchError> {
+			let pallet_account = Self::account_id();
+			let rewards = T::Staking::claim_rewards(&pallet_account)?;
+			let mut total_pooled = TotalPooled::<T>::get().saturating_add(rewards);
+			if !rewards.is_zero() {
+				Self::deposit_event(Event::RewardsCompounded { rewards });
+			}
+
+			let owed = QueuedUnbond::<T>::get().saturating_add(TotalUnbonding::<T>::get());
+			let expected = total_pooled.saturating_add(owed);
+			let balance = T::Currency::total_balance(&pallet_account);
+			if balance < expected {
+				let loss = expected.saturating_sub(balance);
+				total_pooled = total_pooled.saturating_sub(loss);
+				Self::deposit_event(Event::LossApplied { amount: loss });
+			}
+			TotalPooled::<T>::put(total_pooled);
+
+			Ok(rewards)
+		}
+
+		/// Unlock the unstaked funds of the pallet account and stake the pooled
+		/// funds which are not staked yet for the collator the pallet account
+		/// has staked the least for.
+		///
+		/// Funds which are still frozen or owed to redeemers are never staked.
+		/// If staking fails, the funds remain unstaked until the next attempt.
+		pub(crate) fn delegate_idle() {
+			let pallet_account = Self::account_id();
+			if let Err(e) = T::Staking::unlock(&pallet_account) {
+				log::warn!(target: LOG_TARGET, "Failed to unlock unstaked funds: {:?}", e);
+			}
+			let delegations = T::Staking::delegations(&pallet_account);
+			let delegated = delegations
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |sum, (_, stake)| sum.saturating_add(*stake));
+
+			let idle = TotalPooled::<T>::get()
+				.saturating_add(QueuedUnbond::<T>::get())
+				.saturating_sub(delegated);
+			let free = T::Currency::reducible_balance(&pallet_account, Preservation::Expendable, Fortitude::Polite)
+				.saturating_sub(TotalUnbonding::<T>::get());
+			let amount = idle.min(free);
+			if amount.is_zero() {
+				return;
+			}
+
+			let min_delegation = T::Staking::min_delegation();
+			let target = Collators::<T>::get()
+				.into_iter()
+				.map(|collator| {
+					let stake = delegations
+						.iter()
+						.find(|(delegated_to, _)| delegated_to == &collator)
+						.map(|(_, stake)| *stake)
+						.unwrap_or_default();
+					(collator, stake)
+				})
+				.filter(|(_, stake)| !stake.is_zero() || amount >= min_delegation)
+				.min_by_key(|(_, stake)| *stake);
+
+			if let Some((collator, _)) = target {
+				if let Err(e) = with_storage_layer(|| T::Staking::delegate(&pallet_account, &collator, amount)) {
+					log::warn!(target: LOG_TARGET, "Failed to delegate {:?} to {:?}: {:?}", amount, collator, e);
+				}
+			}
+		}
+
+		/// Unstake `amount` from the delegations of the pallet account,
+		/// starting with the largest one. A delegation which would fall below
+		/// the minimum is revoked entirely and the excess is staked again
+		/// once it has been unlocked.
+		///
+		/// Returns the weight consumed by the staking pallet.
+		pub(crate) fn undelegate(amount: BalanceOf<T>) -> Result<Weight, DispatchError> {
+			let pallet_account = Self::account_id();
+			let min_delegation = T::Staking::min_delegation();
+			let mut delegations = T::Staking::delegations(&pallet_account);
+			delegations.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+			let mut remaining = amount;
+			let mut weight = Weight::zero();
+			for (collator, stake) in delegations {
+				if remaining.is_zero() {
+					break;
+				}
+				let less = if stake.saturating_sub(remaining) < min_delegation {
+					stake
+				} else {
+					remaining
+				};
+				weight = weight.saturating_add(T::Staking::undelegate(&pallet_account, &collator, less)?);
+				remaining = remaining.saturating_sub(less);
+			}
+			Ok(weight)
+		}
+
+		/// Unstake the redemptions of the current batch and start collecting
+		/// redemptions for the next one.
+		///
+		/// The batches which have been unstaked at least `UnbondingPeriod`
+		/// blocks ago are unlocked beforehand to free their unstaking requests
+		/// in the staking pallet.
+		///
+		/// If unstaking fails, the batch keeps collecting redemptions and is
+		/// unstaked with the next attempt.
+		pub(crate) fn process_batch(now: BlockNumberFor<T>) -> Weight {
+			let queued = QueuedUnbond::<T>::get();
+			if queued.is_zero() {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let unlock_weight = T::Staking::unlock(&Self::account_id()).unwrap_or_else(|e| {
+				log::warn!(target: LOG_TARGET, "Failed to unlock unstaked funds: {:?}", e);
+				Weight::zero()
+			});
+			let staking_weight = match with_storage_layer(|| Self::undelegate(queued)) {
+				Ok(staking_weight) => staking_weight,
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Failed to unstake batch: {:?}", e);
+					return T::WeightInfo::on_initialize_unbond_batch(T::MaxCollators::get())
+						.saturating_add(unlock_weight);
+				}
+			};
+			// the weight of the batch accounting on top of the actual weight of the staking pallet
+			let weight = T::WeightInfo::on_initialize_unbond_batch(0)
+				.saturating_add(unlock_weight)
+				.saturating_add(staking_weight);
+
+			let batch = CurrentBatch::<T>::get();
+			let unlock_at = now.saturating_add(T::UnbondingPeriod::get());
+			UnbondingBatches::<T>::insert(
+				batch,
+				UnbondingBatch {
+					unlock_at,
+					remaining: queued,
+				},
+			);
+			TotalUnbonding::<T>::mutate(|total| *total = total.saturating_add(queued));
+			QueuedUnbond::<T>::kill();
+			CurrentBatch::<T>::put(batch.saturating_add(1));
+
+			Self::deposit_event(Event::BatchUnbonded {
+				batch,
+				amount: queued,
+				unlock_at,
+			});
+			weight
+		}
+	}
+}
@@ -0,0 +1,391 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::{
+	pallet_prelude::*,
+	parameter_types, storage_alias,
+	traits::{
+		fungible::{Inspect, Mutate, MutateFreeze},
+		tokens::{Fortitude, Precision, Preservation},
+		ConstU128, ConstU32, Hooks, VariantCount,
+	},
+	weights::constants::RocksDbWeight,
+	PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BoundedVec, BuildStorage, DispatchError, DispatchResult, TokenError,
+};
+
+use crate::{self as pallet_liquid_staking, traits::DelegateStake};
+
+pub type Hash = sp_core::H256;
+pub type Balance = u128;
+pub type AssetId = u32;
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+// accounts
+pub(crate) const ALICE: AccountId = 1;
+pub(crate) const BOB: AccountId = 2;
+pub(crate) const CHARLIE: AccountId = 3;
+pub(crate) const COLLATOR_A: AccountId = 101;
+pub(crate) const COLLATOR_B: AccountId = 102;
+pub(crate) const COLLATOR_C: AccountId = 103;
+// assets
+pub(crate) const DERIVATIVE_ASSET_ID: AssetId = 0;
+pub(crate) const DECIMALS: u8 = 15;
+// staking
+pub(crate) const MIN_DELEGATION: Balance = 10;
+pub(crate) const BATCH_PERIOD: BlockNumber = 10;
+pub(crate) const UNBONDING_PERIOD: BlockNumber = 20;
+
+pub type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) fn events() -> Vec<crate::Event<Test>> {
+	System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(|e| {
+			if let RuntimeEvent::LiquidStaking(e) = e {
+				Some(e)
+			} else {
+				None
+			}
+		})
+		.collect::<Vec<_>>()
+}
+
+/// Run `on_initialize` of the pallet for all blocks up to and including `n`.
+pub(crate) fn roll_to(n: BlockNumber) {
+	let mut now = System::block_number();
+	while now < n {
+		now += 1;
+		System::set_block_number(now);
+		LiquidStaking::on_initialize(now);
+	}
+}
+
+/// The amount staked by `who` for each collator.
+#[storage_alias]
+pub type Delegations =
+	StorageDoubleMap<StakingMock, Twox64Concat, AccountId, Twox64Concat, AccountId, Balance, ValueQuery>;
+
+/// The amounts unstaked by `who` and the blocks from which on they can be
+/// unlocked.
+#[storage_alias]
+pub type Unstaking = StorageMap<StakingMock, Twox64Concat, AccountId, Vec<(BlockNumber, Balance)>, ValueQuery>;
+
+/// The staking rewards which can be claimed by `who`.
+#[storage_alias]
+pub type StakingRewards = StorageMap<StakingMock, Twox64Concat, AccountId, Balance, ValueQuery>;
+
+/// A simplified staking implementation which freezes all staked and unstaked
+/// funds of a delegator.
+pub struct MockStaking;
+
+impl MockStaking {
+	fn update_freeze(who: &AccountId) -> DispatchResult {
+		let staked: Balance = Delegations::iter_prefix_values(who).sum();
+		let unstaking: Balance = Unstaking::get(who).iter().map(|(_, amount)| amount).sum();
+		Balances::set_freeze(&TestFreezeReason::Staking, who, staked + unstaking)
+	}
+
+	pub(crate) fn add_rewards(who: &AccountId, amount: Balance) {
+		StakingRewards::mutate(who, |rewards| *rewards += amount);
+	}
+
+	/// Burn `amount` of the stake of `who` for `collator`.
+	pub(crate) fn slash(who: &AccountId, collator: &AccountId, amount: Balance) {
+		Delegations::mutate(who, collator, |stake| *stake -= amount);
+		Self::update_freeze(who).expect("Updating the freeze should not fail");
+		Balances::burn_from(
+			who,
+			amount,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Force,
+		)
+		.expect("Slashing should not fail");
+	}
+}
+
+impl DelegateStake<AccountId, Balance> for MockStaking {
+	fn delegations(who: &AccountId) -> Vec<(AccountId, Balance)> {
+		Delegations::iter_prefix(who).collect()
+	}
+
+	fn min_delegation() -> Balance {
+		MIN_DELEGATION
+	}
+
+	fn delegate(who: &AccountId, collator: &AccountId, amount: Balance) -> Result<Weight, DispatchError> {
+		let free = Balances::reducible_balance(who, Preservation::Expendable, Fortitude::Polite);
+		ensure!(free >= amount, TokenError::FundsUnavailable);
+		let stake = Delegations::get(who, collator);
+		ensure!(stake + amount >= MIN_DELEGATION, TokenError::BelowMinimum);
+		Delegations::insert(who, collator, stake + amount);
+		Self::update_freeze(who)?;
+		Ok(Weight::zero())
+	}
+
+	fn undelegate(who: &AccountId, collator: &AccountId, amount: Balance) -> Result<Weight, DispatchError> {
+		let stake = Delegations::get(who, collator);
+		ensure!(!stake.is_zero(), DispatchError::Other("Not delegating"));
+		let less = amount.min(stake);
+		if less == stake {
+			Delegations::remove(who, collator);
+		} else {
+			ensure!(stake - less >= MIN_DELEGATION, TokenError::BelowMinimum);
+			Delegations::insert(who, collator, stake - less);
+		}
+		Unstaking::mutate(who, |unstaking| {
+			unstaking.push((System::block_number() + UNBONDING_PERIOD, less));
+		});
+		Self::update_freeze(who)?;
+		Ok(Weight::zero())
+	}
+
+	fn unlock(who: &AccountId) -> Result<Weight, DispatchError> {
+		let now = System::block_number();
+		Unstaking::mutate(who, |unstaking| unstaking.retain(|(unlock_at, _)| *unlock_at > now));
+		Self::update_freeze(who)?;
+		Ok(Weight::zero())
+	}
+
+	fn claim_rewards(who: &AccountId) -> Result<Balance, DispatchError> {
+		let rewards = StakingRewards::take(who);
+		if !rewards.is_zero() {
+			Balances::mint_into(who, rewards)?;
+		}
+		Ok(rewards)
+	}
+}
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		LiquidStaking: crate,
+	}
+);
+
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TestFreezeReason {
+	Staking,
+}
+impl VariantCount for TestFreezeReason {
+	const VARIANT_COUNT: u32 = 1;
+}
+
+parameter_types! {
+	pub const SS58Prefix: u8 = 38;
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type Block = Block;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockWeights = ();
+	type DbWeight = RocksDbWeight;
+	type Hash = Hash;
+	type Hashing = BlakeTwo256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type Nonce = u64;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeTask = ();
+	type SS58Prefix = SS58Prefix;
+	type SystemWeightInfo = ();
+	type Version = ();
+	type MultiBlockMigrator = ();
+	type SingleBlockMigrations = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+	type PreInherents = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type FreezeIdentifier = TestFreezeReason;
+	type MaxFreezes = ConstU32<1>;
+	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = ();
+	type RuntimeHoldReason = ();
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const StringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Test {
+	type ApprovalDeposit = ConstU128<0>;
+	type AssetAccountDeposit = ConstU128<0>;
+	type AssetDeposit = ConstU128<0>;
+	type AssetId = AssetId;
+	type AssetIdParameter = AssetId;
+	type Balance = Balance;
+	type CallbackHandle = ();
+	type CreateOrigin = EnsureSigned<AccountId>;
+	type Currency = Balances;
+	type Extra = ();
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Freezer = ();
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type RemoveItemsLimit = ConstU32<5>;
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const LiquidStakingPalletId: PalletId = PalletId(*b"kilt/lst");
+	pub const MaxCollators: u32 = 3;
+	pub const MaxUnbondingRequests: u32 = 3;
+	pub const MinDeposit: Balance = 5;
+	pub const BatchPeriod: BlockNumber = BATCH_PERIOD;
+	pub const UnbondingPeriod: BlockNumber = UNBONDING_PERIOD;
+	pub const Decimals: u8 = DECIMALS;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<Test> for BenchmarkHelper {
+	fn derivative_asset_id() -> AssetId {
+		DERIVATIVE_ASSET_ID
+	}
+
+	fn setup_collator(seed: u32) -> AccountId {
+		1_000 + AccountId::from(seed)
+	}
+
+	fn accrue_rewards(delegator: &AccountId) {
+		MockStaking::add_rewards(delegator, 1_000);
+	}
+}
+
+impl pallet_liquid_staking::Config for Test {
+	type BatchPeriod = BatchPeriod;
+	type Currency = Balances;
+	type Decimals = Decimals;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Fungibles = Assets;
+	type MaxCollators = MaxCollators;
+	type MaxStringInputLength = StringLimit;
+	type MaxUnbondingRequests = MaxUnbondingRequests;
+	type MinDeposit = MinDeposit;
+	type PalletId = LiquidStakingPalletId;
+	type RuntimeEvent = RuntimeEvent;
+	type Staking = MockStaking;
+	type UnbondingPeriod = UnbondingPeriod;
+	type WeightInfo = ();
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BenchmarkHelper;
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+	collators: Vec<AccountId>,
+	initialized: bool,
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub(crate) fn with_collators(mut self, collators: Vec<AccountId>) -> Self {
+		self.collators = collators;
+		self
+	}
+
+	pub(crate) fn initialized(mut self) -> Self {
+		self.initialized = true;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut storage)
+		.expect("assimilate should not fail");
+
+		let mut ext = sp_io::TestExternalities::new(storage);
+
+		ext.execute_with(|| {
+			System::set_block_number(1);
+
+			if self.initialized {
+				LiquidStaking::initialize(
+					RuntimeOrigin::root(),
+					DERIVATIVE_ASSET_ID,
+					BoundedVec::truncate_from(b"Liquid KILT".to_vec()),
+					BoundedVec::truncate_from(b"LKILT".to_vec()),
+				)
+				.expect("Initializing the pallet should not fail.");
+			}
+			crate::Collators::<Test>::put(BoundedVec::truncate_from(self.collators));
+		});
+
+		ext
+	}
+
+	pub fn build_and_execute_with_sanity_tests(self, test: impl FnOnce()) {
+		self.build().execute_with(|| {
+			test();
+			crate::try_state::do_try_state::<Test>().expect("Sanity test for pallet-liquid-staking failed.");
+		})
+	}
+}
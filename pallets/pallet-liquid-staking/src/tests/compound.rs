@@ -0,0 +1,71 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::{assert_err, assert_ok};
+use frame_system::RawOrigin;
+
+use crate::{mock::*, traits::DelegateStake, Error, Event, Pallet, TotalPooled};
+
+#[test]
+fn compound_stakes_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.with_collators(vec![COLLATOR_A, COLLATOR_B])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			let pallet_account = Pallet::<Test>::account_id();
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+			MockStaking::add_rewards(&pallet_account, 20);
+
+			assert_ok!(LiquidStaking::compound(RawOrigin::Signed(BOB).into()));
+
+			assert_eq!(TotalPooled::<Test>::get(), 120);
+			let mut delegations = MockStaking::delegations(&pallet_account);
+			delegations.sort();
+			assert_eq!(delegations, vec![(COLLATOR_A, 100), (COLLATOR_B, 20)]);
+			assert!(events().contains(&Event::RewardsCompounded { rewards: 20 }));
+		});
+}
+
+#[test]
+fn compound_applies_loss() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			let pallet_account = Pallet::<Test>::account_id();
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+			MockStaking::slash(&pallet_account, &COLLATOR_A, 10);
+
+			assert_ok!(LiquidStaking::compound(RawOrigin::Signed(BOB).into()));
+
+			assert_eq!(TotalPooled::<Test>::get(), 90);
+			assert_eq!(MockStaking::delegations(&pallet_account), vec![(COLLATOR_A, 90)]);
+			System::assert_last_event(Event::LossApplied { amount: 10 }.into());
+		});
+}
+
+#[test]
+fn compound_fails_when_not_initialized() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_err!(
+			LiquidStaking::compound(RawOrigin::Signed(BOB).into()),
+			Error::<Test>::NotInitialized
+		);
+	});
+}
@@ -0,0 +1,141 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::{assert_err, assert_ok, traits::fungibles::Inspect};
+use frame_system::RawOrigin;
+
+use crate::{mock::*, traits::DelegateStake, Error, Event, Pallet, TotalPooled};
+
+#[test]
+fn deposit_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			let pallet_account = Pallet::<Test>::account_id();
+
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+
+			// The first deposit is minted one to one.
+			assert_eq!(Assets::balance(DERIVATIVE_ASSET_ID, ALICE), 100);
+			assert_eq!(Balances::free_balance(ALICE), 900);
+			assert_eq!(TotalPooled::<Test>::get(), 100);
+			assert_eq!(MockStaking::delegations(&pallet_account), vec![(COLLATOR_A, 100)]);
+
+			System::assert_last_event(
+				Event::Deposited {
+					who: ALICE,
+					amount: 100,
+					minted: 100,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn deposit_spreads_stake_across_collators() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000), (CHARLIE, 1_000)])
+		.with_collators(vec![COLLATOR_A, COLLATOR_B, COLLATOR_C])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			let pallet_account = Pallet::<Test>::account_id();
+
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(BOB).into(), 50));
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(CHARLIE).into(), 30));
+			// Backs the collator with the least stake.
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(CHARLIE).into(), 40));
+
+			let mut delegations = MockStaking::delegations(&pallet_account);
+			delegations.sort();
+			assert_eq!(delegations, vec![(COLLATOR_A, 100), (COLLATOR_B, 50), (COLLATOR_C, 70)]);
+		});
+}
+
+#[test]
+fn deposit_below_min_delegation_is_staked_later() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			let pallet_account = Pallet::<Test>::account_id();
+
+			assert_ok!(LiquidStaking::deposit(
+				RawOrigin::Signed(ALICE).into(),
+				MIN_DELEGATION - 1
+			));
+			assert!(MockStaking::delegations(&pallet_account).is_empty());
+
+			assert_ok!(LiquidStaking::deposit(
+				RawOrigin::Signed(BOB).into(),
+				MIN_DELEGATION - 1
+			));
+			assert_eq!(
+				MockStaking::delegations(&pallet_account),
+				vec![(COLLATOR_A, 2 * MIN_DELEGATION - 2)]
+			);
+		});
+}
+
+#[test]
+fn deposit_mints_at_exchange_rate() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+			MockStaking::add_rewards(&Pallet::<Test>::account_id(), 25);
+
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(BOB).into(), 50));
+
+			// 125 KILT back 100 derivative tokens before the deposit.
+			assert_eq!(Assets::balance(DERIVATIVE_ASSET_ID, BOB), 40);
+			assert_eq!(Assets::total_issuance(DERIVATIVE_ASSET_ID), 140);
+			assert_eq!(TotalPooled::<Test>::get(), 175);
+		});
+}
+
+#[test]
+fn deposit_fails_below_minimum() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 4),
+				Error::<Test>::DepositBelowMinimum
+			);
+		});
+}
+
+#[test]
+fn deposit_fails_when_not_initialized() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100),
+				Error::<Test>::NotInitialized
+			);
+			assert!(events().is_empty());
+		});
+}
@@ -0,0 +1,83 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::{
+	assert_err, assert_ok,
+	traits::fungibles::{metadata::Inspect as InspectMetadata, roles::Inspect as InspectRoles},
+};
+use frame_system::RawOrigin;
+use sp_runtime::{BoundedVec, DispatchError};
+
+use crate::{mock::*, DerivativeAsset, Error, Event, Pallet};
+
+#[test]
+fn initialize_works() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_ok!(LiquidStaking::initialize(
+			RawOrigin::Root.into(),
+			DERIVATIVE_ASSET_ID,
+			BoundedVec::truncate_from(b"Liquid KILT".to_vec()),
+			BoundedVec::truncate_from(b"LKILT".to_vec()),
+		));
+
+		assert_eq!(DerivativeAsset::<Test>::get(), Some(DERIVATIVE_ASSET_ID));
+		assert_eq!(Assets::owner(DERIVATIVE_ASSET_ID), Some(Pallet::<Test>::account_id()));
+		assert_eq!(Assets::name(DERIVATIVE_ASSET_ID), b"Liquid KILT".to_vec());
+		assert_eq!(Assets::symbol(DERIVATIVE_ASSET_ID), b"LKILT".to_vec());
+		assert_eq!(Assets::decimals(DERIVATIVE_ASSET_ID), DECIMALS);
+
+		System::assert_last_event(
+			Event::Initialized {
+				asset_id: DERIVATIVE_ASSET_ID,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn initialize_fails_when_initialized() {
+	ExtBuilder::default()
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				LiquidStaking::initialize(
+					RawOrigin::Root.into(),
+					DERIVATIVE_ASSET_ID + 1,
+					BoundedVec::new(),
+					BoundedVec::new(),
+				),
+				Error::<Test>::AlreadyInitialized
+			);
+		});
+}
+
+#[test]
+fn initialize_fails_without_force_origin() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_err!(
+			LiquidStaking::initialize(
+				RawOrigin::Signed(ALICE).into(),
+				DERIVATIVE_ASSET_ID,
+				BoundedVec::new(),
+				BoundedVec::new(),
+			),
+			DispatchError::BadOrigin
+		);
+		assert_eq!(DerivativeAsset::<Test>::get(), None);
+	});
+}
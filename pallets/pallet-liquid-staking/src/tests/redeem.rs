@@ -0,0 +1,125 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::{assert_err, assert_ok, traits::fungibles::Inspect};
+use frame_system::RawOrigin;
+
+use crate::{mock::*, traits::DelegateStake, CurrentBatch, Error, Event, Pallet, QueuedUnbond, TotalPooled, Unbonding};
+
+#[test]
+fn redeem_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+
+			assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 40));
+
+			assert_eq!(Assets::balance(DERIVATIVE_ASSET_ID, ALICE), 60);
+			assert_eq!(TotalPooled::<Test>::get(), 60);
+			assert_eq!(QueuedUnbond::<Test>::get(), 40);
+			assert_eq!(Unbonding::<Test>::get(ALICE).into_inner(), [(0, 40)].into());
+			// The redemption is unstaked with the batch.
+			assert_eq!(
+				MockStaking::delegations(&Pallet::<Test>::account_id()),
+				vec![(COLLATOR_A, 100)]
+			);
+
+			System::assert_last_event(
+				Event::Redeemed {
+					who: ALICE,
+					burnt: 40,
+					amount: 40,
+					batch: 0,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn redeem_pays_out_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(BOB).into(), 50));
+			MockStaking::add_rewards(&Pallet::<Test>::account_id(), 30);
+
+			assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 100));
+
+			assert_eq!(Unbonding::<Test>::get(ALICE).into_inner(), [(0, 120)].into());
+			assert_eq!(TotalPooled::<Test>::get(), 60);
+			assert_eq!(Assets::total_issuance(DERIVATIVE_ASSET_ID), 50);
+		});
+}
+
+#[test]
+fn redeem_adds_to_request_of_batch() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+
+			assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 10));
+			assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 15));
+
+			assert_eq!(Unbonding::<Test>::get(ALICE).into_inner(), [(0, 25)].into());
+			assert_eq!(QueuedUnbond::<Test>::get(), 25);
+		});
+}
+
+#[test]
+fn redeem_fails_with_too_many_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+
+			for batch in 1..=3 {
+				assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 10));
+				roll_to(batch * BATCH_PERIOD);
+			}
+			assert_eq!(CurrentBatch::<Test>::get(), 3);
+
+			assert_err!(
+				LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 10),
+				Error::<Test>::TooManyUnbondingRequests
+			);
+		});
+}
+
+#[test]
+fn redeem_fails_with_zero_amount() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 0),
+				Error::<Test>::ZeroAmount
+			);
+		});
+}
@@ -0,0 +1,74 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::{assert_err, assert_ok};
+use frame_system::RawOrigin;
+use sp_runtime::BoundedVec;
+
+use crate::{mock::*, traits::DelegateStake, Collators, Error, Event, Pallet};
+
+#[test]
+fn set_collators_works() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		let collators = BoundedVec::truncate_from(vec![COLLATOR_A, COLLATOR_B]);
+
+		assert_ok!(LiquidStaking::set_collators(RawOrigin::Root.into(), collators.clone()));
+
+		assert_eq!(Collators::<Test>::get(), collators);
+		System::assert_last_event(Event::CollatorsSet { collators }.into());
+	});
+}
+
+#[test]
+fn set_collators_fails_with_duplicates() {
+	ExtBuilder::default().build_and_execute_with_sanity_tests(|| {
+		assert_err!(
+			LiquidStaking::set_collators(
+				RawOrigin::Root.into(),
+				BoundedVec::truncate_from(vec![COLLATOR_A, COLLATOR_B, COLLATOR_A])
+			),
+			Error::<Test>::DuplicateCollator
+		);
+	});
+}
+
+#[test]
+fn set_collators_revokes_removed_collators() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			let pallet_account = Pallet::<Test>::account_id();
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+			assert_eq!(MockStaking::delegations(&pallet_account), vec![(COLLATOR_A, 100)]);
+
+			assert_ok!(LiquidStaking::set_collators(
+				RawOrigin::Root.into(),
+				BoundedVec::truncate_from(vec![COLLATOR_B])
+			));
+			assert!(MockStaking::delegations(&pallet_account).is_empty());
+
+			// The unstaked funds are delegated to the new collator once unlocked.
+			assert_ok!(LiquidStaking::compound(RawOrigin::Signed(BOB).into()));
+			assert!(MockStaking::delegations(&pallet_account).is_empty());
+
+			roll_to(1 + UNBONDING_PERIOD);
+			assert_ok!(LiquidStaking::compound(RawOrigin::Signed(BOB).into()));
+			assert_eq!(MockStaking::delegations(&pallet_account), vec![(COLLATOR_B, 100)]);
+		});
+}
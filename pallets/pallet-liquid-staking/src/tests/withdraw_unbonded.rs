@@ -0,0 +1,120 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::{assert_err, assert_ok};
+use frame_system::RawOrigin;
+
+use crate::{
+	mock::*, traits::DelegateStake, types::UnbondingBatch, CurrentBatch, Error, Event, Pallet, QueuedUnbond,
+	TotalUnbonding, Unbonding, UnbondingBatches,
+};
+
+#[test]
+fn withdraw_unbonded_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+			assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 40));
+
+			roll_to(BATCH_PERIOD);
+			let unlock_at = BATCH_PERIOD + UNBONDING_PERIOD;
+			System::assert_last_event(
+				Event::BatchUnbonded {
+					batch: 0,
+					amount: 40,
+					unlock_at,
+				}
+				.into(),
+			);
+			assert_eq!(CurrentBatch::<Test>::get(), 1);
+			assert_eq!(QueuedUnbond::<Test>::get(), 0);
+			assert_eq!(TotalUnbonding::<Test>::get(), 40);
+			assert_eq!(
+				UnbondingBatches::<Test>::get(0),
+				Some(UnbondingBatch {
+					unlock_at,
+					remaining: 40
+				})
+			);
+			assert_eq!(
+				MockStaking::delegations(&Pallet::<Test>::account_id()),
+				vec![(COLLATOR_A, 60)]
+			);
+
+			roll_to(unlock_at - 1);
+			assert_err!(
+				LiquidStaking::withdraw_unbonded(RawOrigin::Signed(ALICE).into()),
+				Error::<Test>::NothingToWithdraw
+			);
+
+			roll_to(unlock_at);
+			assert_ok!(LiquidStaking::withdraw_unbonded(RawOrigin::Signed(ALICE).into()));
+
+			assert_eq!(Balances::free_balance(ALICE), 940);
+			assert_eq!(TotalUnbonding::<Test>::get(), 0);
+			assert!(Unbonding::<Test>::get(ALICE).is_empty());
+			assert_eq!(UnbondingBatches::<Test>::get(0), None);
+			System::assert_last_event(Event::Withdrawn { who: ALICE, amount: 40 }.into());
+		});
+}
+
+#[test]
+fn withdraw_unbonded_keeps_pending_batches() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000), (BOB, 1_000)])
+		.with_collators(vec![COLLATOR_A])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(ALICE).into(), 100));
+			assert_ok!(LiquidStaking::deposit(RawOrigin::Signed(BOB).into(), 100));
+			assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 20));
+			assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(BOB).into(), 30));
+			roll_to(BATCH_PERIOD);
+			assert_ok!(LiquidStaking::redeem(RawOrigin::Signed(ALICE).into(), 10));
+			roll_to(2 * BATCH_PERIOD);
+
+			roll_to(BATCH_PERIOD + UNBONDING_PERIOD);
+			assert_ok!(LiquidStaking::withdraw_unbonded(RawOrigin::Signed(ALICE).into()));
+
+			assert_eq!(Balances::free_balance(ALICE), 920);
+			assert_eq!(Unbonding::<Test>::get(ALICE).into_inner(), [(1, 10)].into());
+			assert_eq!(
+				UnbondingBatches::<Test>::get(0),
+				Some(UnbondingBatch {
+					unlock_at: BATCH_PERIOD + UNBONDING_PERIOD,
+					remaining: 30
+				})
+			);
+			assert_eq!(TotalUnbonding::<Test>::get(), 40);
+		});
+}
+
+#[test]
+fn withdraw_unbonded_fails_without_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000)])
+		.initialized()
+		.build_and_execute_with_sanity_tests(|| {
+			assert_err!(
+				LiquidStaking::withdraw_unbonded(RawOrigin::Signed(ALICE).into()),
+				Error::<Test>::NothingToWithdraw
+			);
+		});
+}
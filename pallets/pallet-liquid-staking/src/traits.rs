@@ -0,0 +1,135 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use frame_support::{
+	traits::{fungible::Inspect, Get},
+	weights::Weight,
+};
+use parachain_staking::{Pallet as StakingPallet, WeightInfo};
+use sp_runtime::{traits::Zero, DispatchError};
+use sp_std::vec::Vec;
+
+use crate::AccountIdOf;
+
+/// A trait for staking funds of an account as a delegator.
+pub trait DelegateStake<AccountId, Balance> {
+	/// The collators backed by `who` and the amount staked for each of them.
+	fn delegations(who: &AccountId) -> Vec<(AccountId, Balance)>;
+
+	/// The minimum amount a single delegation has to stake.
+	fn min_delegation() -> Balance;
+
+	/// Stake `amount` for `collator`, adding a new delegation if `who` does
+	/// not back `collator` yet.
+	///
+	/// Returns the consumed weight.
+	fn delegate(who: &AccountId, collator: &AccountId, amount: Balance) -> Result<Weight, DispatchError>;
+
+	/// Unstake `amount` from the delegation of `who` for `collator`. The
+	/// delegation is revoked if the whole stake is unstaked.
+	///
+	/// Returns the consumed weight.
+	fn undelegate(who: &AccountId, collator: &AccountId, amount: Balance) -> Result<Weight, DispatchError>;
+
+	/// Unlock the unstaked funds of `who` whose unstaking period has passed.
+	///
+	/// Returns the consumed weight.
+	fn unlock(who: &AccountId) -> Result<Weight, DispatchError>;
+
+	/// Claim the staking rewards of `who`.
+	///
+	/// Returns the amount of claimed rewards.
+	fn claim_rewards(who: &AccountId) -> Result<Balance, DispatchError>;
+}
+
+type StakingBalanceOf<T> =
+	<<T as parachain_staking::Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Implementation of the staking trait for the parachain staking pallet,
+/// relying on the internal functions of the pallet which back its calls.
+impl<T> DelegateStake<AccountIdOf<T>, StakingBalanceOf<T>> for StakingPallet<T>
+where
+	T: parachain_staking::Config,
+{
+	fn delegations(who: &AccountIdOf<T>) -> Vec<(AccountIdOf<T>, StakingBalanceOf<T>)> {
+		StakingPallet::<T>::delegator_state(who)
+			.map(|state| {
+				state
+					.delegations
+					.iter()
+					.map(|stake| (stake.owner.clone(), stake.amount))
+					.collect()
+			})
+			.unwrap_or_default()
+	}
+
+	fn min_delegation() -> StakingBalanceOf<T> {
+		<T as parachain_staking::Config>::MinDelegatorStake::get()
+	}
+
+	fn delegate(
+		who: &AccountIdOf<T>,
+		collator: &AccountIdOf<T>,
+		amount: StakingBalanceOf<T>,
+	) -> Result<Weight, DispatchError> {
+		match StakingPallet::<T>::delegator_state(who) {
+			Some(state) if state.delegations.iter().any(|stake| &stake.owner == collator) => {
+				StakingPallet::<T>::do_delegator_stake_more(who, collator, amount)
+			}
+			Some(_) => StakingPallet::<T>::do_delegate_another_candidate(who, collator, amount),
+			None => StakingPallet::<T>::do_join_delegators(who, collator, amount),
+		}
+	}
+
+	fn undelegate(
+		who: &AccountIdOf<T>,
+		collator: &AccountIdOf<T>,
+		amount: StakingBalanceOf<T>,
+	) -> Result<Weight, DispatchError> {
+		let stake = Self::delegations(who)
+			.into_iter()
+			.find(|(delegated_to, _)| delegated_to == collator)
+			.map(|(_, stake)| stake)
+			.unwrap_or_default();
+		if amount >= stake {
+			StakingPallet::<T>::do_revoke_delegation(who, collator)
+		} else {
+			StakingPallet::<T>::do_delegator_stake_less(who, collator, amount)
+		}
+	}
+
+	fn unlock(who: &AccountIdOf<T>) -> Result<Weight, DispatchError> {
+		if StakingPallet::<T>::unstaking(who).is_empty() {
+			return Ok(T::DbWeight::get().reads(1));
+		}
+		let unstaking_len = StakingPallet::<T>::do_unlock(who)?;
+		Ok(<T as parachain_staking::Config>::WeightInfo::unlock_unstaked(
+			unstaking_len,
+		))
+	}
+
+	fn claim_rewards(who: &AccountIdOf<T>) -> Result<StakingBalanceOf<T>, DispatchError> {
+		if StakingPallet::<T>::delegator_state(who).is_some() {
+			StakingPallet::<T>::do_increment_delegator_rewards(who)?;
+		}
+		if StakingPallet::<T>::rewards(who).is_zero() {
+			return Ok(Zero::zero());
+		}
+		StakingPallet::<T>::do_claim_rewards(who)
+	}
+}
@@ -0,0 +1,71 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::traits::fungibles::Inspect;
+use sp_runtime::{traits::Saturating, TryRuntimeError};
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+use crate::{
+	BalanceOf, BatchIndex, Collators, Config, CurrentBatch, DerivativeAsset, QueuedUnbond, TotalUnbonding, Unbonding,
+	UnbondingBatches,
+};
+
+pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
+	if let Some(asset_id) = DerivativeAsset::<T>::get() {
+		assert!(T::Fungibles::asset_exists(asset_id));
+	}
+
+	// Collator checks
+	let collators = Collators::<T>::get();
+	let unique_collators = collators.iter().collect::<BTreeSet<_>>();
+	assert_eq!(unique_collators.len(), collators.len());
+
+	// Redemption checks
+	let current_batch = CurrentBatch::<T>::get();
+	let mut redeemed_per_batch = BTreeMap::<BatchIndex, BalanceOf<T>>::new();
+	Unbonding::<T>::iter_values().for_each(|requests| {
+		requests.into_iter().for_each(|(batch, amount)| {
+			let redeemed = redeemed_per_batch.entry(batch).or_default();
+			*redeemed = redeemed.saturating_add(amount);
+		});
+	});
+
+	// The redemptions of the current batch are still staked.
+	let queued = redeemed_per_batch.remove(&current_batch).unwrap_or_default();
+	assert_eq!(queued, QueuedUnbond::<T>::get());
+
+	// The redemptions of previous batches have been unstaked and are tracked
+	// until they have been withdrawn.
+	let mut total_unbonding = BalanceOf::<T>::default();
+	UnbondingBatches::<T>::iter().try_for_each(|(batch, details)| -> Result<(), TryRuntimeError> {
+		if batch >= current_batch {
+			return Err(TryRuntimeError::Other("Unbonding batch has not been unstaked yet"));
+		}
+		let redeemed = redeemed_per_batch.remove(&batch).unwrap_or_default();
+		assert_eq!(redeemed, details.remaining);
+		total_unbonding = total_unbonding.saturating_add(details.remaining);
+		Ok(())
+	})?;
+	assert_eq!(total_unbonding, TotalUnbonding::<T>::get());
+
+	// Each redemption belongs to the current batch or an unbonding batch.
+	if !redeemed_per_batch.is_empty() {
+		return Err(TryRuntimeError::Other("Redemption of unknown batch"));
+	}
+
+	Ok(())
+}
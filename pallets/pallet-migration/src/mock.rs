@@ -36,7 +36,7 @@ use pallet_did_lookup::linkable_account::LinkableAccountId;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use public_credentials::InputSubjectIdOf;
 use scale_info::TypeInfo;
-use sp_core::{ed25519, ConstBool, ConstU128, ConstU32};
+use sp_core::{ed25519, ConstBool, ConstU128, ConstU32, ConstU64};
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 	AccountId32, BoundedVec, BuildStorage, MultiSignature, MultiSigner, Perquintill, RuntimeDebug, SaturatedConversion,
@@ -296,6 +296,12 @@ impl pallet_did_lookup::Config for Test {
 	type WeightInfo = ();
 	type BalanceMigrationManager = Migration;
 	type UniqueLinkingEnabled = ConstBool<false>;
+	type Eip712ChainId = ConstU64<0>;
+	type MetadataByteDeposit = ConstU128<MICRO_KILT>;
+	type MaxLabelLength = ConstU32<32>;
+	type MaxAssociationsPerBatch = ConstU32<50>;
+	type MaxLinkedAccounts = ConstU32<50>;
+	type NameProvider = ();
 }
 
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, PartialEq, Eq, PartialOrd, Ord, Clone)]
@@ -348,6 +354,7 @@ impl pallet_web3_names::Config for Test {
 	type Web3Name = TestWeb3Name;
 	type Web3NameOwner = TestWeb3NameOwner;
 	type WeightInfo = ();
+	type NameChangeHook = ();
 	type BalanceMigrationManager = Migration;
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -24,6 +24,7 @@
 mod default_weights;
 
 pub mod migrations;
+pub mod traits;
 pub mod web3_name;
 
 #[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -76,7 +77,7 @@ pub mod pallet {
 	};
 
 	use super::WeightInfo;
-	use crate::web3_name::Web3NameOwnership;
+	use crate::{traits::Web3NameChangeHook, web3_name::Web3NameOwnership};
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -151,6 +152,8 @@ pub mod pallet {
 		type Web3NameOwner: Parameter + MaxEncodedLen;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+		/// The hook called whenever a name is claimed or released.
+		type NameChangeHook: Web3NameChangeHook<Self::Web3NameOwner, Self::Web3Name>;
 
 		/// Migration manager to handle new created entries
 		type BalanceMigrationManager: BalanceMigrationManager<AccountIdOf<Self>, BalanceOf<Self, I>>;
@@ -247,7 +250,10 @@ pub mod pallet {
 		/// - Writes: Names, Owner storage entries + currency deposit reserve
 		/// # </weight>
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config<I>>::WeightInfo::claim(name.len().saturated_into()))]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::claim(name.len().saturated_into())
+				.saturating_add(<T as Config<I>>::NameChangeHook::on_name_changed_weight())
+		)]
 		pub fn claim(origin: OriginFor<T>, name: Web3NameInput<T, I>) -> DispatchResult {
 			let runtime_origin = T::ClaimOrigin::ensure_origin(origin)?;
 			let payer = runtime_origin.sender();
@@ -273,7 +279,10 @@ pub mod pallet {
 		/// - Writes: Names, Owner storage entries + currency deposit release
 		/// # </weight>
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config<I>>::WeightInfo::release_by_owner())]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::release_by_owner()
+				.saturating_add(<T as Config<I>>::NameChangeHook::on_name_changed_weight())
+		)]
 		pub fn release_by_owner(origin: OriginFor<T>) -> DispatchResult {
 			let runtime_origin = T::OwnerOrigin::ensure_origin(origin)?;
 			let owner = runtime_origin.subject();
@@ -298,7 +307,10 @@ pub mod pallet {
 		/// - Writes: Names, Owner storage entries + currency deposit release
 		/// # </weight>
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config<I>>::WeightInfo::reclaim_deposit(name.len().saturated_into()))]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::reclaim_deposit(name.len().saturated_into())
+				.saturating_add(<T as Config<I>>::NameChangeHook::on_name_changed_weight())
+		)]
 		pub fn reclaim_deposit(origin: OriginFor<T>, name: Web3NameInput<T, I>) -> DispatchResult {
 			let caller = ensure_signed(origin)?;
 
@@ -326,7 +338,10 @@ pub mod pallet {
 		///   release
 		/// # </weight>
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config<I>>::WeightInfo::ban(name.len().saturated_into()))]
+		#[pallet::weight(
+			<T as Config<I>>::WeightInfo::ban(name.len().saturated_into())
+				.saturating_add(<T as Config<I>>::NameChangeHook::on_name_changed_weight())
+		)]
 		pub fn ban(origin: OriginFor<T>, name: Web3NameInput<T, I>) -> DispatchResult {
 			T::BanOrigin::ensure_origin(origin)?;
 
@@ -474,6 +489,7 @@ pub mod pallet {
 					deposit,
 				},
 			);
+			<T as Config<I>>::NameChangeHook::on_name_changed(&owner, Some(&name));
 
 			Self::deposit_event(Event::<T, I>::Web3NameClaimed { owner, name });
 			Ok(())
@@ -513,6 +529,7 @@ pub mod pallet {
 		fn unregister_name(name: &Web3NameOf<T, I>) -> Result<Web3OwnershipOf<T, I>, DispatchError> {
 			let name_ownership = Owner::<T, I>::take(name).ok_or(Error::<T, I>::OwnerNotFound)?;
 			Names::<T, I>::remove(&name_ownership.owner);
+			<T as Config<I>>::NameChangeHook::on_name_changed(&name_ownership.owner, None);
 
 			let is_key_migrated =
 				<T as Config<I>>::BalanceMigrationManager::is_key_migrated(&Owner::<T, I>::hashed_key_for(name));
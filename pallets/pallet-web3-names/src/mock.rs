@@ -53,7 +53,7 @@ pub use crate::mock::runtime::*;
 // Mocks that are only used internally
 #[cfg(test)]
 pub(crate) mod runtime {
-	use frame_support::{ensure, parameter_types};
+	use frame_support::{ensure, parameter_types, storage_alias, Blake2_128Concat};
 	use frame_system::EnsureRoot;
 	use kilt_support::mock::{mock_origin, SubjectId};
 	use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -64,7 +64,7 @@ pub(crate) mod runtime {
 		BoundedVec, BuildStorage, MultiSignature, SaturatedConversion,
 	};
 
-	use crate::{self as pallet_web3_names, Config, Error};
+	use crate::{self as pallet_web3_names, traits::Web3NameChangeHook, Config, Error};
 
 	type BlockNumber = u64;
 	pub(crate) type Balance = u128;
@@ -183,6 +183,24 @@ pub(crate) mod runtime {
 		pub const Web3NameDeposit: Balance = 2 * ExistentialDeposit::get();
 	}
 
+	#[storage_alias]
+	type HookedNames = StorageMap<Web3Names, Blake2_128Concat, TestWeb3NameOwner, TestWeb3Name>;
+
+	/// Keeps track of the names reported by the pallet.
+	pub struct MockNameChangeHook;
+
+	impl MockNameChangeHook {
+		pub(crate) fn name_of(owner: &TestWeb3NameOwner) -> Option<TestWeb3Name> {
+			HookedNames::get(owner)
+		}
+	}
+
+	impl Web3NameChangeHook<TestWeb3NameOwner, TestWeb3Name> for MockNameChangeHook {
+		fn on_name_changed(owner: &TestWeb3NameOwner, name: Option<&TestWeb3Name>) {
+			HookedNames::set(owner, name.cloned());
+		}
+	}
+
 	impl pallet_web3_names::Config for Test {
 		type BanOrigin = TestBanOrigin;
 		type ClaimOrigin = TestOwnerOrigin;
@@ -197,6 +215,7 @@ pub(crate) mod runtime {
 		type Web3Name = TestWeb3Name;
 		type Web3NameOwner = TestWeb3NameOwner;
 		type WeightInfo = ();
+		type NameChangeHook = MockNameChangeHook;
 		type BalanceMigrationManager = ();
 
 		#[cfg(feature = "runtime-benchmarks")]
@@ -92,6 +92,7 @@ fn banning_successful() {
 			assert!(Names::<Test>::get(&DID_00).is_none());
 			assert!(Owner::<Test>::get(&web3_name_00).is_none());
 			assert!(Banned::<Test>::get(&web3_name_00).is_some());
+			assert!(MockNameChangeHook::name_of(&DID_00).is_none());
 
 			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
 			assert_eq!(Balances::balance(&ACCOUNT_00), initial_balance);
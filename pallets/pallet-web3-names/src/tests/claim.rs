@@ -47,6 +47,7 @@ fn claiming_successful() {
 
 			// Test that the name matches
 			assert_eq!(web3_name, web3_name_00);
+			assert_eq!(MockNameChangeHook::name_of(&DID_00), Some(web3_name_00.clone()));
 			// Test that the ownership details match
 			assert_eq!(
 				owner_details,
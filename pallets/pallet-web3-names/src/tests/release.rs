@@ -35,12 +35,14 @@ fn releasing_by_owner_successful() {
 		.with_balances(vec![(ACCOUNT_00, initial_balance)])
 		.with_web3_names(vec![(DID_00, web3_name_00.clone(), ACCOUNT_00)])
 		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(MockNameChangeHook::name_of(&DID_00), Some(web3_name_00.clone()));
 			assert_ok!(Pallet::<Test>::release_by_owner(
 				// Submitter != deposit payer, owner == name owner
 				mock_origin::DoubleOrigin(ACCOUNT_01, DID_00).into(),
 			));
 			assert!(Names::<Test>::get(&DID_00).is_none());
 			assert!(Owner::<Test>::get(&web3_name_00).is_none());
+			assert!(MockNameChangeHook::name_of(&DID_00).is_none());
 
 			// Test that the deposit was returned to the payer correctly.
 			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
@@ -63,6 +65,7 @@ fn releasing_by_payer_successful() {
 			));
 			assert!(Names::<Test>::get(&DID_00).is_none());
 			assert!(Owner::<Test>::get(&web3_name_00).is_none());
+			assert!(MockNameChangeHook::name_of(&DID_00).is_none());
 			// Test that the deposit was returned to the payer correctly.
 			assert!(Balances::balance_on_hold(&HoldReason::Deposit.into(), &ACCOUNT_00).is_zero());
 			assert_eq!(Balances::balance(&ACCOUNT_00), initial_balance);
@@ -0,0 +1,37 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use frame_support::weights::Weight;
+
+/// Runtime logic evaluated by the web3name pallet whenever a name is
+/// claimed or released.
+pub trait Web3NameChangeHook<Owner, Name> {
+	/// React to the name of the owner being set, or removed if `None`.
+	fn on_name_changed(owner: &Owner, name: Option<&Name>);
+
+	/// Return the maximum weight consumed by `on_name_changed`, which is
+	/// charged by the calls claiming or releasing a name on top of their own
+	/// weight.
+	fn on_name_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl<Owner, Name> Web3NameChangeHook<Owner, Name> for () {
+	fn on_name_changed(_owner: &Owner, _name: Option<&Name>) {}
+}
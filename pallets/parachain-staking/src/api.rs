@@ -17,55 +17,168 @@
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
 use crate::{
-	types::BalanceOf, BlocksAuthored, BlocksRewarded, CandidatePool, Config, DelegatorState, InflationConfig, Pallet,
-	Rewards, TotalCollatorStake,
+	types::{BalanceOf, IdentityOf},
+	AutoCompound, BlocksAuthored, BlocksRewarded, CandidatePool, CollatorHistory, Config, DelegationBlocksRewarded,
+	DelegatorState, InflationConfig, MaxCommission, Pallet, Rewards, Round, ScheduledCommission, TotalCollatorStake,
 };
-use frame_support::traits::fungible::Inspect;
+use frame_support::traits::{fungible::Inspect, Get};
 use sp_runtime::{
-	traits::{SaturatedConversion, Saturating, Zero},
-	Perquintill,
+	traits::{CheckedDiv, SaturatedConversion, Saturating, Zero},
+	FixedPointNumber, FixedU128, PerThing, Perbill, Perquintill,
 };
+use sp_std::vec::Vec;
 
 impl<T: Config> Pallet<T> {
 	/// Calculates the staking rewards for a given account address.
 	///
 	/// Subtracts the number of rewarded blocks from the number of authored
 	/// blocks by the collator and multiplies that with the current stake
-	/// as well as reward rate.
+	/// as well as reward rate. For delegators, this is done for each of their
+	/// delegations and the commission of the respective collator is deducted.
+	/// Collators additionally receive the commission on the unclaimed rewards
+	/// of their delegators.
 	///
 	/// At least used in Runtime API.
 	pub fn get_unclaimed_staking_rewards(acc: &T::AccountId) -> BalanceOf<T> {
-		let count_rewarded = BlocksRewarded::<T>::get(acc);
 		let rewards = Rewards::<T>::get(acc);
 
 		// delegators and collators need to be handled differently
 		if let Some(delegator_state) = DelegatorState::<T>::get(acc) {
-			// #blocks for unclaimed staking rewards equals
-			// #blocks_authored_by_collator - #blocks_claimed_by_delegator
-			let count_unclaimed = BlocksAuthored::<T>::get(&delegator_state.owner).saturating_sub(count_rewarded);
-			let stake = delegator_state.amount;
-			// rewards += stake * reward_count * delegator_reward_rate
-			rewards.saturating_add(Self::calc_block_rewards_delegator(
-				stake,
-				count_unclaimed.saturated_into(),
-			))
-		} else if Self::is_active_candidate(acc).is_some() {
+			delegator_state.delegations.iter().fold(rewards, |rewards, delegation| {
+				let reward = Self::unclaimed_delegation_rewards(acc, &delegation.owner, delegation.amount);
+				let commission = Self::commission_of(&delegation.owner).mul_floor(reward);
+				rewards.saturating_add(reward.saturating_sub(commission))
+			})
+		} else if let Some(state) = CandidatePool::<T>::get(acc) {
 			// #blocks for unclaimed staking rewards equals
 			// #blocks_authored_by_collator - #blocks_claimed_by_collator
+			let count_rewarded = BlocksRewarded::<T>::get(acc);
 			let count_unclaimed = BlocksAuthored::<T>::get(acc).saturating_sub(count_rewarded);
-			let stake = CandidatePool::<T>::get(acc)
-				.map(|state| state.stake)
-				.unwrap_or_else(BalanceOf::<T>::zero);
 			// rewards += stake * self_count * collator_reward_rate
-			rewards.saturating_add(Self::calc_block_rewards_collator(
-				stake,
+			let rewards = rewards.saturating_add(Self::calc_block_rewards_collator(
+				state.stake,
 				count_unclaimed.saturated_into(),
-			))
+			));
+
+			// rewards += commission on the unclaimed rewards of the delegators
+			let commission = Self::commission_of(acc);
+			if commission.is_zero() {
+				return rewards;
+			}
+			state.delegators.iter().fold(rewards, |rewards, delegation| {
+				let reward = Self::unclaimed_delegation_rewards(&delegation.owner, acc, delegation.amount);
+				rewards.saturating_add(commission.mul_floor(reward))
+			})
 		} else {
 			rewards
 		}
 	}
 
+	/// Calculates the unclaimed staking rewards of a single delegation before
+	/// deducting the commission of the collator.
+	fn unclaimed_delegation_rewards(
+		delegator: &T::AccountId,
+		collator: &T::AccountId,
+		stake: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		// #blocks for unclaimed staking rewards equals
+		// #blocks_authored_by_collator - #blocks_claimed_by_delegator
+		let count_rewarded = DelegationBlocksRewarded::<T>::get(delegator, collator);
+		let count_unclaimed = BlocksAuthored::<T>::get(collator).saturating_sub(count_rewarded);
+		// rewards = stake * reward_count * delegator_reward_rate
+		Self::calc_block_rewards_delegator(stake, count_unclaimed.saturated_into())
+	}
+
+	/// Returns the commission details of a collator candidate or `None` if the
+	/// account is not a candidate.
+	///
+	/// At least used in Runtime API.
+	pub fn get_collator_commission(acc: &T::AccountId) -> Option<kilt_runtime_api_staking::CollatorCommission> {
+		CandidatePool::<T>::contains_key(acc).then(|| kilt_runtime_api_staking::CollatorCommission {
+			commission: Self::commission_of(acc),
+			max_commission: MaxCommission::<T>::get(),
+			scheduled: ScheduledCommission::<T>::get(acc),
+		})
+	}
+
+	/// Returns the share of rewards which is automatically staked for the
+	/// given collator candidate or delegator.
+	///
+	/// At least used in Runtime API.
+	pub fn get_auto_compound(acc: &T::AccountId) -> Perbill {
+		AutoCompound::<T>::get(acc)
+	}
+
+	/// Returns the statistics of a collator for the last `rounds` completed
+	/// rounds in which the collator was selected, ordered from oldest to
+	/// newest.
+	///
+	/// The number of rounds is limited by `MaxRoundHistory`.
+	///
+	/// At least used in Runtime API.
+	pub fn get_collator_stats(
+		collator: &T::AccountId,
+		rounds: u32,
+	) -> Vec<kilt_runtime_api_staking::CollatorRoundStats<BalanceOf<T>>> {
+		let current = pallet_session::Pallet::<T>::current_index();
+		let first = current.saturating_sub(rounds.min(T::MaxRoundHistory::get()));
+		(first..current)
+			.filter_map(|round| {
+				CollatorHistory::<T>::get(round, collator).map(|stats| kilt_runtime_api_staking::CollatorRoundStats {
+					round,
+					blocks_authored: stats.blocks_authored,
+					stake: stats.stake,
+					delegators_stake: stats.delegators_stake,
+					commission: stats.commission,
+					collator_rewards: stats.collator_rewards,
+					delegator_rewards: stats.delegator_rewards,
+				})
+			})
+			.collect()
+	}
+
+	/// Calculates the effective annual percentage yield of the delegators of
+	/// a collator based on the rewards credited in the last `rounds` completed
+	/// rounds, or `None` if there are no statistics for these rounds.
+	///
+	/// The yield is not compounded, already accounts for the commission of
+	/// the collator and is not capped at 100%.
+	///
+	/// At least used in Runtime API.
+	pub fn get_delegator_apy(collator: &T::AccountId, rounds: u32) -> Option<FixedU128> {
+		let (rewards, stake) = Self::get_collator_stats(collator, rounds).into_iter().fold(
+			(BalanceOf::<T>::zero(), BalanceOf::<T>::zero()),
+			|(rewards, stake), stats| {
+				(
+					rewards.saturating_add(stats.delegator_rewards),
+					stake.saturating_add(stats.delegators_stake),
+				)
+			},
+		);
+
+		// the average reward rate per round scaled to a year
+		let reward_rate =
+			FixedU128::checked_from_rational(rewards.saturated_into::<u128>(), stake.saturated_into::<u128>())?;
+		let rounds_per_year = T::BLOCKS_PER_YEAR
+			.checked_div(&Round::<T>::get().length)
+			.unwrap_or_default()
+			.saturated_into::<u128>();
+		Some(reward_rate.saturating_mul(FixedU128::saturating_from_integer(rounds_per_year)))
+	}
+
+	/// Returns all collator candidates together with the identity they are
+	/// linked to, if the link is still valid.
+	///
+	/// At least used in Runtime API.
+	pub fn get_candidate_identities() -> Vec<(T::AccountId, Option<IdentityOf<T>>)> {
+		CandidatePool::<T>::iter_keys()
+			.map(|collator| {
+				let identity = Self::verified_identity(&collator);
+				(collator, identity)
+			})
+			.collect()
+	}
+
 	/// Calculates the current staking and reward rates for collators and
 	/// delegators.
 	///
@@ -19,7 +19,13 @@
 // Old benchmarking macros are a mess.
 #![allow(clippy::tests_outside_test_module)]
 
-use crate::{types::RoundInfo, *};
+use crate::{
+	traits::CollatorIdentityProvider,
+	types::{
+		DeferredSlash, InflationScheduleOf, Offence, PoolId, PoolRoles, PoolState, RoundInfo, SlashingConfiguration,
+	},
+	*,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::{
 	assert_ok,
@@ -31,8 +37,8 @@ use frame_support::{
 use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin};
 use pallet_session::Pallet as Session;
 use sp_runtime::{
-	traits::{One, SaturatedConversion, StaticLookup, Zero},
-	Perquintill,
+	traits::{One, SaturatedConversion, Saturating, StaticLookup, Zero},
+	Perbill, Perquintill,
 };
 use sp_std::{convert::TryInto, vec::Vec};
 
@@ -117,6 +123,7 @@ where
 			|delegator| {
 				assert_ok!(Pallet::<T>::delegator_stake_less(
 					RawOrigin::Signed(delegator.clone()).into(),
+					T::Lookup::unlookup(collator.clone()),
 					T::CurrencyBalance::one()
 				));
 			},
@@ -127,6 +134,22 @@ where
 	assert!(Unstaking::<T>::get(who).len() <= T::MaxUnstakeRequests::get().try_into().unwrap());
 }
 
+/// Creates a staking pool which delegates the given amount to the collator.
+fn setup_pool<T: Config>(collator: &T::AccountId, amount: T::CurrencyBalance) -> (PoolId, T::AccountId)
+where
+	<T as Config>::Currency: Mutate<T::AccountId>,
+{
+	let owner: T::AccountId = account("pool-owner", 0, DELEGATOR_ACCOUNT_SEED);
+	<T::Currency as Mutate<T::AccountId>>::set_balance(&owner, amount + amount);
+	let pool_id = NextPoolId::<T>::get();
+	assert_ok!(Pallet::<T>::create_pool(
+		RawOrigin::Signed(owner.clone()).into(),
+		T::Lookup::unlookup(collator.clone()),
+		amount,
+	));
+	(pool_id, owner)
+}
+
 benchmarks! {
 	where_clause { where u64: Into<BlockNumberFor<T>>,
 		<T as Config>::Currency: Mutate<T::AccountId>
@@ -411,7 +434,7 @@ benchmarks! {
 			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
 		}
 		let collator = candidates[0].clone();
-		let delegator = account("new-delegator", 0, DELEGATOR_ACCOUNT_SEED);
+		let delegator: T::AccountId = account("new-delegator", 0, DELEGATOR_ACCOUNT_SEED);
 		let amount = T::MinDelegatorStake::get();
 		T::Currency::set_balance(&delegator, amount + amount + amount + amount);
 		let unlookup_collator = T::Lookup::unlookup(collator.clone());
@@ -441,23 +464,24 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount);
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount);
 
 		// increase stake so we can unstake, because current stake is minimum
 		T::Currency::set_balance(&delegator, T::CurrencyBalance::from(10u128.pow(17)));
-		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::CurrencyBalance::from(u as u64)));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount + T::CurrencyBalance::from(u as u64));
+		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), T::CurrencyBalance::from(u as u64)));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount + T::CurrencyBalance::from(u as u64));
 
 		// fill unstake BTreeMap by unstaked many entries of 1
 		fill_unstaking::<T>(&collator, Some(&delegator), u as u64);
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount);
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount);
 
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
 		let origin = RawOrigin::Signed(delegator.clone());
-	}: _(origin, amount)
+	}: _(origin, unlookup_collator, amount)
 	verify {
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount + amount);
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount + amount);
 		assert!(Unstaking::<T>::get(&delegator).is_empty());
 	}
 
@@ -477,30 +501,31 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 
 		// increase stake so we can unstake, because current stake is minimum
 		T::Currency::set_balance(&delegator, T::CurrencyBalance::from(10u128.pow(17)));
-		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), amount + amount));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount + amount);
+		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount + amount));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount + amount);
 
 		// decrease stake once so we have an unstaking entry for this block
-		assert_ok!(Pallet::<T>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), amount));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount);
+		assert_ok!(Pallet::<T>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount);
 		assert_eq!(Unstaking::<T>::get(&delegator).len(), 1);
 
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
 		let origin = RawOrigin::Signed(delegator.clone());
-	}: _(origin, amount)
+	}: _(origin, unlookup_collator, amount)
 	verify {
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 		assert_eq!(Unstaking::<T>::get(&delegator).len(), 2);
 	}
 
 	leave_delegators {
-		// we need at least 1 collators
-		let n in 1 .. T::MaxTopCandidates::get();
+		// number of delegations of the leaving delegator
+		let n in 1 .. T::MaxCollatorsPerDelegator::get();
 		// we need at least 1 delegator
 		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
 
@@ -514,16 +539,25 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 
-		// increase stake so we can unstake, because current stake is minimum
+		// delegate the remaining collators
 		T::Currency::set_balance(&delegator, T::CurrencyBalance::from(10u128.pow(17)));
-		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), amount + amount));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount + amount);
+		for c in candidates.iter().skip(1).take(n.saturating_sub(1) as usize) {
+			// reset the delegation counter to not exceed MaxDelegationsPerRound
+			LastDelegation::<T>::remove(&delegator);
+			assert_ok!(Pallet::<T>::delegate_another_candidate(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(c.clone()), T::MinDelegatorStake::get()));
+		}
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().delegations.len(), n as usize);
+		let total = DelegatorState::<T>::get(&delegator).unwrap().total;
+
+		// increase stake so we can unstake, because current stake is minimum
+		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount + amount));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, total + amount + amount);
 
 		// decrease stake once so we have an unstaking entry for this block
-		assert_ok!(Pallet::<T>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), amount));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount);
+		assert_ok!(Pallet::<T>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, total + amount);
 		assert_eq!(Unstaking::<T>::get(&delegator).len(), 1);
 
 		let origin = RawOrigin::Signed(delegator.clone());
@@ -535,6 +569,61 @@ benchmarks! {
 		assert_eq!(Unstaking::<T>::get(&delegator).len(), 2);
 	}
 
+	delegate_another_candidate {
+		// we need at least 2 collators
+		let n in 2 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[0].clone();
+		let delegator: T::AccountId = account("new-delegator", 0, DELEGATOR_ACCOUNT_SEED);
+		let amount = T::MinDelegatorStake::get();
+		T::Currency::set_balance(&delegator, amount + amount + amount + amount);
+
+		// join the set of delegators by delegating another collator
+		assert_ok!(Pallet::<T>::join_delegators(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(candidates[1].clone()), amount));
+		// reset the delegation counter to not exceed MaxDelegationsPerRound
+		LastDelegation::<T>::remove(&delegator);
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_collator, amount)
+	verify {
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount + amount);
+	}
+
+	revoke_delegation {
+		// we need at least 1 collators
+		let n in 1 .. T::MaxTopCandidates::get();
+		// we need at least 1 delegator
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[0].clone();
+
+		// make sure delegator collated to collator
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_collator)
+	verify {
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		assert!(!state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert!(DelegatorState::<T>::get(&delegator).is_none());
+		assert_eq!(Unstaking::<T>::get(&delegator).len(), 1);
+	}
+
 	unlock_unstaked {
 		let u in 1 .. (T::MaxUnstakeRequests::get() - 1);
 
@@ -581,7 +670,7 @@ benchmarks! {
 
 		// mock high values to compensate for tiny values in unit test env
 		let stake = T::CurrencyBalance::from(1_000_000_000_000_000_000u128);
-		DelegatorState::<T>::insert(&delegator, crate::types::Delegator { owner: collator.clone(), amount: stake});
+		DelegatorState::<T>::insert(&delegator, crate::types::Delegator::try_new(collator.clone(), stake).unwrap());
 		BlocksAuthored::<T>::insert(&collator, u64::MAX.into());
 
 		assert!(Rewards::<T>::get(&delegator).is_zero());
@@ -589,7 +678,7 @@ benchmarks! {
 	}: _(origin)
 	verify {
 		assert!(!Rewards::<T>::get(&delegator).is_zero());
-		assert_eq!(BlocksRewarded::<T>::get(&delegator), u64::MAX.into());
+		assert_eq!(DelegationBlocksRewarded::<T>::get(&delegator, &collator), u64::MAX.into());
 	}
 
 	increment_collator_rewards {
@@ -644,6 +733,379 @@ benchmarks! {
 		assert!(new.delegator.reward_rate.annual < old.delegator.reward_rate.annual);
 	}
 
+	set_commission {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let commission = Perbill::from_percent(10);
+		MaxCommission::<T>::put(Perbill::from_percent(50));
+		let origin = RawOrigin::Signed(collator.clone());
+	}: _(origin, commission)
+	verify {
+		let effective_round = Round::<T>::get().current + T::CommissionChangeDelay::get();
+		assert_eq!(ScheduledCommission::<T>::get(&collator), Some((commission, effective_round)));
+	}
+
+	execute_commission_change {
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let delegators = fill_delegators::<T>(m, collator.clone(), COLLATOR_ACCOUNT_SEED);
+
+		// mock high counter to compensate for tiny amounts in unit test env
+		BlocksAuthored::<T>::insert(&collator, u64::MAX.into());
+		let commission = Perbill::from_percent(10);
+		MaxCommission::<T>::put(Perbill::from_percent(50));
+		CandidateCommission::<T>::insert(&collator, Perbill::from_percent(5));
+		ScheduledCommission::<T>::insert(&collator, (commission, Round::<T>::get().current));
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+	}: _(RawOrigin::Signed(collator.clone()), unlookup_collator)
+	verify {
+		assert_eq!(CandidateCommission::<T>::get(&collator), commission);
+		assert!(ScheduledCommission::<T>::get(&collator).is_none());
+		for delegator in delegators {
+			assert_eq!(DelegationBlocksRewarded::<T>::get(&delegator, &collator), u64::MAX.into());
+		}
+	}
+
+	set_max_commission {
+		let new = Perbill::from_percent(20);
+	}: _(RawOrigin::Root, new)
+	verify {
+		assert_eq!(MaxCommission::<T>::get(), new);
+	}
+
+	set_auto_compound {
+		// worst case is a collator candidate since delegators are checked first
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let share = Perbill::from_percent(50);
+	}: _(RawOrigin::Signed(collator.clone()), share)
+	verify {
+		assert_eq!(AutoCompound::<T>::get(&collator), share);
+	}
+
+	report_equivocation {
+		let n in (T::MinCollators::get() + 1) .. T::MaxTopCandidates::get() - 1;
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let candidate = candidates[0].clone();
+		SlashingConfig::<T>::put(SlashingConfiguration {
+			equivocation_slash: Perbill::from_percent(10),
+			suspension_rounds: 2,
+			..Default::default()
+		});
+		let unlookup_candidate = T::Lookup::unlookup(candidate.clone());
+	}: _(RawOrigin::Root, unlookup_candidate)
+	verify {
+		assert!(DeferredSlashes::<T>::contains_key(&candidate));
+		assert!(CandidatePool::<T>::get(&candidate).unwrap().is_suspended());
+	}
+
+	apply_deferred_slash {
+		let n in (T::MinCollators::get() + 1) .. T::MaxTopCandidates::get() - 1;
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let candidate = candidates[0].clone();
+
+		// increase stakes above the minimum such that nobody is removed by the slash
+		T::Currency::set_balance(&candidate, T::CurrencyBalance::from(10u128.pow(20)));
+		assert_ok!(Pallet::<T>::candidate_stake_more(
+			RawOrigin::Signed(candidate.clone()).into(),
+			T::MinCollatorCandidateStake::get()
+		));
+		for delegation in CandidatePool::<T>::get(&candidate).unwrap().delegators.into_bounded_vec() {
+			T::Currency::set_balance(&delegation.owner, T::CurrencyBalance::from(10u128.pow(20)));
+			assert_ok!(Pallet::<T>::delegator_stake_more(
+				RawOrigin::Signed(delegation.owner.clone()).into(),
+				T::Lookup::unlookup(candidate.clone()),
+				T::MinDelegatorStake::get()
+			));
+		}
+
+		// mock high counter to compensate for tiny amounts in unit test env
+		BlocksAuthored::<T>::insert(&candidate, u64::MAX.into());
+		let stake_before = CandidatePool::<T>::get(&candidate).unwrap().total;
+		DeferredSlashes::<T>::insert(&candidate, DeferredSlash {
+			offence: Offence::Equivocation,
+			fraction: Perbill::from_percent(10),
+			apply_at: Round::<T>::get().current,
+		});
+		let unlookup_candidate = T::Lookup::unlookup(candidate.clone());
+	}: _(RawOrigin::Signed(candidate.clone()), unlookup_candidate)
+	verify {
+		assert!(!DeferredSlashes::<T>::contains_key(&candidate));
+		assert!(CandidatePool::<T>::get(&candidate).unwrap().total < stake_before);
+	}
+
+	cancel_deferred_slash {
+		let candidate = setup_collator_candidates::<T>(1, None)[0].clone();
+		DeferredSlashes::<T>::insert(&candidate, DeferredSlash {
+			offence: Offence::Equivocation,
+			fraction: Perbill::from_percent(10),
+			apply_at: Round::<T>::get().current,
+		});
+		let unlookup_candidate = T::Lookup::unlookup(candidate.clone());
+	}: _(RawOrigin::Root, unlookup_candidate)
+	verify {
+		assert!(!DeferredSlashes::<T>::contains_key(&candidate));
+	}
+
+	resume_candidate {
+		let n in (T::MinCollators::get() + 1) .. T::MaxTopCandidates::get() - 1;
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let candidate = candidates[0].clone();
+
+		SlashingConfig::<T>::put(SlashingConfiguration {
+			suspension_rounds: 1,
+			..Default::default()
+		});
+		assert_ok!(Pallet::<T>::report_equivocation(
+			RawOrigin::Root.into(),
+			T::Lookup::unlookup(candidate.clone())
+		));
+		Round::<T>::mutate(|round| round.current += 1);
+
+		let origin = RawOrigin::Signed(candidate.clone());
+	}: _(origin)
+	verify {
+		let candidates = TopCandidates::<T>::get();
+		assert!(candidates.into_iter().any(|other| other.owner == candidate));
+	}
+
+	set_slashing_config {
+		let config = SlashingConfiguration {
+			min_authored_share: Perbill::from_percent(50),
+			downtime_rounds: 3,
+			downtime_slash: Perbill::from_percent(1),
+			equivocation_slash: Perbill::from_percent(10),
+			suspension_rounds: 12,
+		};
+	}: _(RawOrigin::Root, config.clone())
+	verify {
+		assert_eq!(SlashingConfig::<T>::get(), config);
+	}
+
+	create_pool {
+		let n in 1 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[0].clone();
+		let owner: T::AccountId = account("pool-owner", 0, DELEGATOR_ACCOUNT_SEED);
+		let amount = T::MinDelegatorStake::get();
+		T::Currency::set_balance(&owner, amount + amount);
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+	}: _(RawOrigin::Signed(owner.clone()), unlookup_collator, amount)
+	verify {
+		let pool_account = Pallet::<T>::pool_account(0);
+		assert_eq!(Pools::<T>::get(0).unwrap().roles.owner, owner);
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		assert!(state.delegators.into_iter().any(|x| x.owner == pool_account));
+	}
+
+	join_pool {
+		let n in 1 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[0].clone();
+		let amount = T::MinDelegatorStake::get();
+		let (pool_id, _) = setup_pool::<T>(&collator, amount);
+
+		let member: T::AccountId = account("pool-member", 0, DELEGATOR_ACCOUNT_SEED);
+		T::Currency::set_balance(&member, amount + amount);
+	}: _(RawOrigin::Signed(member.clone()), pool_id, amount)
+	verify {
+		assert_eq!(PoolMembers::<T>::get(pool_id, &member).unwrap().points, amount);
+		assert_eq!(DelegatorState::<T>::get(Pallet::<T>::pool_account(pool_id)).unwrap().total, amount + amount);
+	}
+
+	claim_pool_rewards {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let collator = candidates[0].clone();
+		let (pool_id, owner) = setup_pool::<T>(&collator, T::CurrencyBalance::from(10u128.pow(15)));
+
+		// let the collator author some blocks
+		BlocksAuthored::<T>::insert(&collator, BlockNumberFor::<T>::from(1000u64));
+	}: _(RawOrigin::Signed(owner.clone()), pool_id)
+	verify {
+		assert!(!Rewards::<T>::get(&owner).is_zero());
+	}
+
+	unbond_from_pool {
+		let n in 1 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[0].clone();
+		let amount = T::MinDelegatorStake::get();
+		let (pool_id, _) = setup_pool::<T>(&collator, amount);
+
+		let member: T::AccountId = account("pool-member", 0, DELEGATOR_ACCOUNT_SEED);
+		T::Currency::set_balance(&member, amount + amount);
+		assert_ok!(Pallet::<T>::join_pool(RawOrigin::Signed(member.clone()).into(), pool_id, amount));
+		let points = PoolMembers::<T>::get(pool_id, &member).unwrap().points;
+	}: _(RawOrigin::Signed(member.clone()), pool_id, points)
+	verify {
+		assert!(PoolMembers::<T>::get(pool_id, &member).is_none());
+		assert_eq!(PoolUnbonding::<T>::get(pool_id, &member).len(), 1);
+		assert_eq!(DelegatorState::<T>::get(Pallet::<T>::pool_account(pool_id)).unwrap().total, amount);
+	}
+
+	withdraw_from_pool {
+		let u in 1 .. (T::MaxUnstakeRequests::get().saturated_into::<u32>() - 1);
+
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let collator = candidates[0].clone();
+		let amount = T::MinDelegatorStake::get();
+		let (pool_id, _) = setup_pool::<T>(&collator, amount);
+
+		let member: T::AccountId = account("pool-member", 0, DELEGATOR_ACCOUNT_SEED);
+		T::Currency::set_balance(&member, amount + amount);
+		assert_ok!(Pallet::<T>::join_pool(RawOrigin::Signed(member.clone()).into(), pool_id, amount));
+
+		// unbond in u different periods, as unbonds within a period are batched
+		for _ in 0 .. u {
+			assert_ok!(Pallet::<T>::unbond_from_pool(
+				RawOrigin::Signed(member.clone()).into(),
+				pool_id,
+				T::CurrencyBalance::one()
+			));
+			System::<T>::set_block_number(System::<T>::block_number() + T::StakeDuration::get());
+		}
+		assert_eq!(PoolUnbonding::<T>::get(pool_id, &member).len(), u as usize);
+		System::<T>::set_block_number(System::<T>::block_number() + T::StakeDuration::get());
+	}: _(RawOrigin::Signed(member.clone()), pool_id)
+	verify {
+		assert!(PoolUnbonding::<T>::get(pool_id, &member).is_empty());
+		assert!(Unstaking::<T>::get(Pallet::<T>::pool_account(pool_id)).is_empty());
+	}
+
+	set_pool_roles {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let (pool_id, owner) = setup_pool::<T>(&candidates[0], T::MinDelegatorStake::get());
+		let operator: T::AccountId = account("pool-operator", 0, DELEGATOR_ACCOUNT_SEED);
+		let roles = PoolRoles {
+			owner: owner.clone(),
+			operator,
+		};
+	}: _(RawOrigin::Signed(owner), pool_id, roles.clone())
+	verify {
+		assert_eq!(Pools::<T>::get(pool_id).unwrap().roles, roles);
+	}
+
+	set_pool_commission {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let (pool_id, owner) = setup_pool::<T>(&candidates[0], T::MinDelegatorStake::get());
+		MaxCommission::<T>::put(Perbill::from_percent(50));
+	}: _(RawOrigin::Signed(owner), pool_id, Perbill::from_percent(10))
+	verify {
+		assert_eq!(Pools::<T>::get(pool_id).unwrap().commission, Perbill::from_percent(10));
+	}
+
+	set_pool_state {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let (pool_id, owner) = setup_pool::<T>(&candidates[0], T::MinDelegatorStake::get());
+	}: _(RawOrigin::Signed(owner), pool_id, PoolState::Blocked)
+	verify {
+		assert_eq!(Pools::<T>::get(pool_id).unwrap().state, PoolState::Blocked);
+	}
+
+	link_identity {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let collator = candidates[0].clone();
+		let identity = T::IdentityProvider::link_identity(&collator);
+	}: _(RawOrigin::Signed(collator.clone()))
+	verify {
+		assert_eq!(CandidateIdentity::<T>::get(&collator), Some(identity));
+	}
+
+	unlink_identity {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let collator = candidates[0].clone();
+		T::IdentityProvider::link_identity(&collator);
+		assert_ok!(Pallet::<T>::link_identity(RawOrigin::Signed(collator.clone()).into()));
+	}: _(RawOrigin::Signed(collator.clone()))
+	verify {
+		assert!(CandidateIdentity::<T>::get(&collator).is_none());
+	}
+
+	set_identity_requirement {
+	}: _(RawOrigin::Root, true)
+	verify {
+		assert!(IdentityRequired::<T>::get());
+	}
+
+	fast_unstake {
+		// we need at least 1 collators
+		let n in 1 .. T::MaxTopCandidates::get();
+		// we need at least 1 delegator
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[0].clone();
+
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+
+		// the history of all rounds overlapping with the stake duration has to be checked
+		Round::<T>::mutate(|round| round.current = Pallet::<T>::fast_unstake_rounds());
+		assert!(Pallet::<T>::can_fast_unstake(&delegator, &collator));
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_collator)
+	verify {
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		assert!(!state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert!(DelegatorState::<T>::get(&delegator).is_none());
+		assert!(Unstaking::<T>::get(&delegator).is_empty());
+	}
+
+	set_inflation_schedule {
+		let s in 0 .. T::MaxInflationSteps::get();
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let step = InflationStep {
+			start: now,
+			collator_max_rate: Perquintill::from_percent(10),
+			collator_reward_rate: Perquintill::from_percent(10),
+			delegator_max_rate: Perquintill::from_percent(40),
+			delegator_reward_rate: Perquintill::from_percent(8),
+		};
+		let schedule: InflationScheduleOf<T> = (1..=s)
+			.map(|i| InflationStep { start: now.saturating_add(i.into()), ..step.clone() })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(RawOrigin::Root, schedule)
+	verify {
+		assert_eq!(InflationSchedule::<T>::get().len(), s.saturated_into::<usize>());
+	}
+
 }
 
 impl_benchmark_test_suite!(
@@ -68,12 +68,36 @@ pub trait WeightInfo {
 	fn delegator_stake_more(n: u32, m: u32, u: u32, ) -> Weight;
 	fn delegator_stake_less(n: u32, m: u32, ) -> Weight;
 	fn leave_delegators(n: u32, m: u32, ) -> Weight;
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight;
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight;
 	fn unlock_unstaked(u: u32, ) -> Weight;
 	fn set_max_candidate_stake() -> Weight;
 	fn increment_delegator_rewards() -> Weight;
 	fn increment_collator_rewards() -> Weight;
 	fn claim_rewards() -> Weight;
 	fn execute_scheduled_reward_change(n: u32, m: u32, ) -> Weight;
+	fn set_commission() -> Weight;
+	fn execute_commission_change(m: u32, ) -> Weight;
+	fn set_max_commission() -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn report_equivocation(n: u32, m: u32, ) -> Weight;
+	fn apply_deferred_slash(n: u32, m: u32, ) -> Weight;
+	fn cancel_deferred_slash() -> Weight;
+	fn resume_candidate(n: u32, m: u32, ) -> Weight;
+	fn set_slashing_config() -> Weight;
+	fn create_pool(n: u32, m: u32, ) -> Weight;
+	fn join_pool(n: u32, m: u32, ) -> Weight;
+	fn claim_pool_rewards() -> Weight;
+	fn unbond_from_pool(n: u32, m: u32, ) -> Weight;
+	fn withdraw_from_pool(u: u32, ) -> Weight;
+	fn set_pool_roles() -> Weight;
+	fn set_pool_commission() -> Weight;
+	fn set_pool_state() -> Weight;
+	fn link_identity() -> Weight;
+	fn unlink_identity() -> Weight;
+	fn set_identity_requirement() -> Weight;
+	fn fast_unstake(n: u32, m: u32, ) -> Weight;
+	fn set_inflation_schedule(s: u32, ) -> Weight;
 }
 
 /// Weights for parachain_staking using the Substrate node and recommended hardware.
@@ -602,6 +626,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `join_delegators`, this adds a delegation to a candidate, locks the
+	/// additional stake and updates the top candidates.
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::join_delegators(n, m)
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `leave_delegators`, this increments the rewards of the delegation,
+	/// removes it from the candidate and unstakes it.
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::leave_delegators(n, m)
+	}
 	/// Storage: ParachainStaking Unstaking (r:1 w:1)
 	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
@@ -722,6 +758,171 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 70593).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(0, 129441).saturating_mul(m.into()))
 	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the candidate, the maximum commission and the current round before
+	/// scheduling the change.
+	fn set_commission() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. The
+	/// rewards of the collator and of each of its `m` delegators are
+	/// incremented at the previous commission before the new one applies.
+	fn execute_commission_change(m: u32, ) -> Weight {
+		<Self as WeightInfo>::increment_collator_rewards()
+			.saturating_add(<Self as WeightInfo>::increment_delegator_rewards().saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_max_commission() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the delegation of the caller before storing the setting.
+	fn set_auto_compound() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `candidate_stake_less`, this lowers the stake of the candidate and
+	/// updates the top candidates, and additionally stores the deferred slash.
+	fn report_equivocation(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::candidate_stake_less(n, m)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `force_remove_candidate`, this touches the stake, freeze and rewards of
+	/// the candidate and of each of its delegators.
+	fn apply_deferred_slash(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::force_remove_candidate(n, m)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Removes
+	/// a single deferred slash.
+	fn cancel_deferred_slash() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `cancel_leave_candidates`, this inserts the candidate into the top
+	/// candidates again.
+	fn resume_candidate(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::cancel_leave_candidates(n, m)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_slashing_config() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `join_delegators`, this delegates the initial bond to a candidate, and
+	/// additionally transfers the bond to the pool account and stores the pool
+	/// and its first member.
+	fn create_pool(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::join_delegators(n, m)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `delegator_stake_more`, this increases the delegation of the pool after
+	/// incrementing its rewards, and additionally transfers the bond and updates
+	/// the pool and the member.
+	fn join_pool(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::delegator_stake_more(n, m, 1)
+			.saturating_add(<Self as WeightInfo>::increment_delegator_rewards())
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_rewards`, this pays out rewards after incrementing those of the
+	/// pool, and additionally updates the pool and the member.
+	fn claim_pool_rewards() -> Weight {
+		<Self as WeightInfo>::increment_delegator_rewards()
+			.saturating_add(<Self as WeightInfo>::claim_rewards())
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `delegator_stake_less`, this lowers the delegation of the pool after
+	/// incrementing its rewards, and additionally records the unbonding of the
+	/// member.
+	fn unbond_from_pool(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::delegator_stake_less(n, m)
+			.saturating_add(<Self as WeightInfo>::increment_delegator_rewards())
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `unlock_unstaked`, this unlocks the unstaked bonds of the pool, and
+	/// additionally transfers them to the member.
+	fn withdraw_from_pool(u: u32, ) -> Weight {
+		<Self as WeightInfo>::unlock_unstaked(u)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_pool_rewards`, this harvests the rewards of the pool before
+	/// updating it.
+	fn set_pool_roles() -> Weight {
+		<Self as WeightInfo>::claim_pool_rewards()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_pool_rewards`, this harvests the rewards of the pool before
+	/// updating it, and additionally checks the maximum commission.
+	fn set_pool_commission() -> Weight {
+		<Self as WeightInfo>::claim_pool_rewards()
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the roles of the caller before updating the pool.
+	fn set_pool_state() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the candidate and the identity of the caller before replacing the link
+	/// and the candidate counts of the previous and the new identity.
+	fn link_identity() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// whether identities are required before removing the link and
+	/// decrementing the candidate count of the identity.
+	fn unlink_identity() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_identity_requirement() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `revoke_delegation`, this removes the delegation, and additionally
+	/// unlocks the stake right away like `unlock_unstaked`. Checking the
+	/// collator history reads at most one entry per round of the
+	/// `MaxRoundHistory` of 360 rounds.
+	fn fast_unstake(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::revoke_delegation(n, m)
+			.saturating_add(<Self as WeightInfo>::unlock_unstaked(1))
+			.saturating_add(T::DbWeight::get().reads(363_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value of at most
+	/// `s` steps, and additionally removes a pending step for an empty schedule.
+	fn set_inflation_schedule(_s: u32, ) -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1249,6 +1450,18 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `join_delegators`, this adds a delegation to a candidate, locks the
+	/// additional stake and updates the top candidates.
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::join_delegators(n, m)
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `leave_delegators`, this increments the rewards of the delegation,
+	/// removes it from the candidate and unstakes it.
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::leave_delegators(n, m)
+	}
 	/// Storage: ParachainStaking Unstaking (r:1 w:1)
 	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
@@ -1369,4 +1582,169 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 70593).saturating_mul(n.into()))
 			.saturating_add(Weight::from_parts(0, 129441).saturating_mul(m.into()))
 	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the candidate, the maximum commission and the current round before
+	/// scheduling the change.
+	fn set_commission() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. The
+	/// rewards of the collator and of each of its `m` delegators are
+	/// incremented at the previous commission before the new one applies.
+	fn execute_commission_change(m: u32, ) -> Weight {
+		<Self as WeightInfo>::increment_collator_rewards()
+			.saturating_add(<Self as WeightInfo>::increment_delegator_rewards().saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_max_commission() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the delegation of the caller before storing the setting.
+	fn set_auto_compound() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `candidate_stake_less`, this lowers the stake of the candidate and
+	/// updates the top candidates, and additionally stores the deferred slash.
+	fn report_equivocation(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::candidate_stake_less(n, m)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `force_remove_candidate`, this touches the stake, freeze and rewards of
+	/// the candidate and of each of its delegators.
+	fn apply_deferred_slash(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::force_remove_candidate(n, m)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Removes
+	/// a single deferred slash.
+	fn cancel_deferred_slash() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `cancel_leave_candidates`, this inserts the candidate into the top
+	/// candidates again.
+	fn resume_candidate(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::cancel_leave_candidates(n, m)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_slashing_config() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `join_delegators`, this delegates the initial bond to a candidate, and
+	/// additionally transfers the bond to the pool account and stores the pool
+	/// and its first member.
+	fn create_pool(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::join_delegators(n, m)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `delegator_stake_more`, this increases the delegation of the pool after
+	/// incrementing its rewards, and additionally transfers the bond and updates
+	/// the pool and the member.
+	fn join_pool(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::delegator_stake_more(n, m, 1)
+			.saturating_add(<Self as WeightInfo>::increment_delegator_rewards())
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_rewards`, this pays out rewards after incrementing those of the
+	/// pool, and additionally updates the pool and the member.
+	fn claim_pool_rewards() -> Weight {
+		<Self as WeightInfo>::increment_delegator_rewards()
+			.saturating_add(<Self as WeightInfo>::claim_rewards())
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `delegator_stake_less`, this lowers the delegation of the pool after
+	/// incrementing its rewards, and additionally records the unbonding of the
+	/// member.
+	fn unbond_from_pool(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::delegator_stake_less(n, m)
+			.saturating_add(<Self as WeightInfo>::increment_delegator_rewards())
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `unlock_unstaked`, this unlocks the unstaked bonds of the pool, and
+	/// additionally transfers them to the member.
+	fn withdraw_from_pool(u: u32, ) -> Weight {
+		<Self as WeightInfo>::unlock_unstaked(u)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_pool_rewards`, this harvests the rewards of the pool before
+	/// updating it.
+	fn set_pool_roles() -> Weight {
+		<Self as WeightInfo>::claim_pool_rewards()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_pool_rewards`, this harvests the rewards of the pool before
+	/// updating it, and additionally checks the maximum commission.
+	fn set_pool_commission() -> Weight {
+		<Self as WeightInfo>::claim_pool_rewards()
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the roles of the caller before updating the pool.
+	fn set_pool_state() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the candidate and the identity of the caller before replacing the link
+	/// and the candidate counts of the previous and the new identity.
+	fn link_identity() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// whether identities are required before removing the link and
+	/// decrementing the candidate count of the identity.
+	fn unlink_identity() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_identity_requirement() -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `revoke_delegation`, this removes the delegation, and additionally
+	/// unlocks the stake right away like `unlock_unstaked`. Checking the
+	/// collator history reads at most one entry per round of the
+	/// `MaxRoundHistory` of 360 rounds.
+	fn fast_unstake(n: u32, m: u32, ) -> Weight {
+		<Self as WeightInfo>::revoke_delegation(n, m)
+			.saturating_add(<Self as WeightInfo>::unlock_unstaked(1))
+			.saturating_add(RocksDbWeight::get().reads(363_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value of at most
+	/// `s` steps, and additionally removes a pending step for an empty schedule.
+	fn set_inflation_schedule(_s: u32, ) -> Weight {
+		<Self as WeightInfo>::set_max_candidate_stake()
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
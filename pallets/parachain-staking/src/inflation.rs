@@ -152,6 +152,53 @@ impl InflationInfo {
 	}
 }
 
+/// A step of the inflation schedule which replaces the current inflation
+/// configuration at the start of the first round beginning at or after block
+/// `start`.
+#[derive(
+	Eq, PartialEq, Clone, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+pub struct InflationStep<BlockNumber> {
+	/// The block from which on the step applies.
+	pub start: BlockNumber,
+	/// Maximum staking rate of collators.
+	pub collator_max_rate: Perquintill,
+	/// Annual reward rate of collators.
+	pub collator_reward_rate: Perquintill,
+	/// Maximum staking rate of delegators.
+	pub delegator_max_rate: Perquintill,
+	/// Annual reward rate of delegators.
+	pub delegator_reward_rate: Perquintill,
+}
+
+impl<BlockNumber> InflationStep<BlockNumber> {
+	/// Convert the step into the inflation info it applies.
+	pub fn to_inflation_info(&self, blocks_per_year: u64) -> InflationInfo {
+		InflationInfo::new(
+			blocks_per_year,
+			self.collator_max_rate,
+			self.collator_reward_rate,
+			self.delegator_max_rate,
+			self.delegator_reward_rate,
+		)
+	}
+
+	/// Check whether the inflation info derived from the step is valid.
+	pub fn is_valid(&self, blocks_per_year: u64) -> bool {
+		self.to_inflation_info(blocks_per_year).is_valid(blocks_per_year)
+	}
+}
+
+/// Check whether the steps of an inflation schedule start in strictly
+/// ascending order and each of them is valid.
+pub fn is_valid_schedule<BlockNumber: PartialOrd>(steps: &[InflationStep<BlockNumber>], blocks_per_year: u64) -> bool {
+	steps.iter().all(|step| step.is_valid(blocks_per_year))
+		&& steps.windows(2).all(|pair| match pair {
+			[prev, next] => prev.start < next.start,
+			_ => true,
+		})
+}
+
 #[cfg(test)]
 mod tests {
 	use sp_runtime::Perbill;
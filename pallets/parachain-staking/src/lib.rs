@@ -47,9 +47,56 @@
 //! sessions.
 //!
 //! To join the set of delegators, an account must call `join_delegators` with
-//! stake >= `MinDelegatorStake`. There are also runtime methods for delegating
-//! additional collators and revoking delegations.
+//! stake >= `MinDelegatorStake`. Afterwards, a delegator can back up to
+//! `MaxCollatorsPerDelegator` many collators by calling
+//! `delegate_another_candidate` and revoke single delegations via
+//! `revoke_delegation`.
 //!
+//! Collator candidates can charge a commission on the rewards of their
+//! delegators by calling `set_commission`. The change can be applied via
+//! `execute_commission_change` at least `CommissionChangeDelay` rounds later
+//! and the commission is capped by the governance-set `MaxCommission`.
+//!
+//! Collator candidates and delegators can opt into staking a share of their
+//! rewards automatically via `set_auto_compound`. The share is staked whenever
+//! their rewards are actively incremented.
+//!
+//! Collators which author less than `min_authored_share` of the average number
+//! of blocks for `downtime_rounds` many consecutive rounds commit the
+//! `Downtime` offence. Equivocations are reported by governance via
+//! `report_equivocation`. Offenders are suspended from the set of top
+//! candidates and a slash of their and their delegators' stake is deferred by
+//! `SlashDeferDuration` rounds. It can be canceled by governance via
+//! `cancel_deferred_slash` or applied by anyone via `apply_deferred_slash`.
+//!
+//! For the last `MaxRoundHistory` rounds, the pallet keeps the stake at the
+//! start of the round as well as the authored blocks and the rewards credited
+//! for the blocks of each collator during the round, which are exposed via the
+//! runtime API.
+//!
+//! Accounts which cannot afford `MinDelegatorStake` can join a staking pool
+//! created via `create_pool` with as little as `MinPoolJoin`. The pool account
+//! is a regular delegator of a single collator. Members hold points for their
+//! share of its stake, receive its rewards pro-rata after deducting the
+//! commission of the pool operator and redeem their points via
+//! `unbond_from_pool` and `withdraw_from_pool`.
+//!
+//! Collator candidates can link their account to the identity provided by
+//! `IdentityProvider`, e.g., the DID the account is connected to, via
+//! `link_identity`. Governance can require candidates to be linked to an
+//! identity via `set_identity_requirement`, in which case only candidates
+//! whose link is still valid are selected as collators.
+//!
+//! Delegators can revoke a delegation to a collator candidate which was not
+//! selected in any round of the last `StakeDuration` blocks via
+//! `fast_unstake`. As the delegation did not earn any rewards, the funds are
+//! released immediately instead of going through the unstaking queue.
+//!
+//! Governance can schedule up to `MaxInflationSteps` upcoming changes of the
+//! inflation configuration via `set_inflation_schedule`. At the start of each
+//! round, the latest step whose start block has been reached is queued to
+//! replace the current inflation configuration once the rewards of all
+//! stakers have been incremented over the following blocks.
 //!
 //! - [`Config`]
 //! - [`Call`]
@@ -62,7 +109,7 @@
 //!   `MaxSelectedCandidates` are chosen to become active collators for the next
 //!   session. That makes the set of active collators the set of block authors
 //!   by handing it over to the session and the authority pallet.
-//! - Delegating to a collator candidate by staking for them.
+//! - Delegating to one or more collator candidates by staking for them.
 //! - Increasing and reducing your stake as a collator or delegator.
 //! - Revoking your delegation entirely.
 //! - Requesting to leave the set of collator candidates.
@@ -129,7 +176,9 @@ mod try_state;
 
 pub mod api;
 mod inflation;
+pub mod migrations;
 mod set;
+pub mod traits;
 mod types;
 
 use frame_support::pallet;
@@ -154,7 +203,7 @@ pub use crate::{default_weights::WeightInfo, pallet::*};
 #[allow(clippy::manual_inspect)]
 pub mod pallet {
 	use super::*;
-	pub use crate::inflation::{InflationInfo, RewardRate, StakingInfo};
+	pub use crate::inflation::{InflationInfo, InflationStep, RewardRate, StakingInfo};
 
 	use core::cmp::Ordering;
 	use frame_support::{
@@ -163,20 +212,24 @@ pub mod pallet {
 		traits::{
 			fungible::Balanced,
 			tokens::{
-				fungible::{Inspect, MutateFreeze, Unbalanced},
+				fungible::{Inspect, InspectFreeze, MutateFreeze, Unbalanced},
 				Fortitude, IdAmount, Precision, Preservation,
 			},
-			BuildGenesisConfig, EstimateNextSessionRotation, Get, OnUnbalanced, StorageVersion,
+			BuildGenesisConfig, EstimateNextSessionRotation, Get, Imbalance, OnUnbalanced, StorageVersion,
 		},
-		BoundedVec,
+		BoundedVec, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
 	use pallet_balances::Freezes;
 	use pallet_session::ShouldEndSession;
 	use scale_info::TypeInfo;
 	use sp_runtime::{
-		traits::{Convert, One, SaturatedConversion, Saturating, StaticLookup, Zero},
-		Permill, Perquintill,
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{
+			AccountIdConversion, CheckedDiv, CheckedRem, CheckedSub, Convert, One, SaturatedConversion, Saturating,
+			StaticLookup, Zero,
+		},
+		FixedPointNumber, FixedU128, Perbill, Permill, Perquintill, Rounding, TokenError,
 	};
 	use sp_staking::SessionIndex;
 	use sp_std::prelude::*;
@@ -184,15 +237,18 @@ pub mod pallet {
 
 	use crate::{
 		set::OrderedSet,
+		traits::CollatorIdentityProvider,
 		types::{
-			BalanceOf, Candidate, CandidateOf, CandidateStatus, CreditOf, DelegationCounter, Delegator, RoundInfo,
-			Stake, StakeOf, TotalStake,
+			BalanceOf, Candidate, CandidateOf, CandidateStatus, CollatorStats, CreditOf, DeferredSlash,
+			DelegationCounter, Delegator, IdentityOf, InflationScheduleOf, Offence, PoolId, PoolMember, PoolMemberOf,
+			PoolRoles, PoolState, RoundInfo, RoundStats, SlashingConfiguration, Stake, StakeOf, StakingPool,
+			StakingPoolOf, TotalStake,
 		},
 	};
 	use sp_std::{convert::TryInto, fmt::Debug};
 
 	/// The current storage version.
-	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
 
 	/// The 5.1% inflation rate of the third year
 	const INFLATION_3RD_YEAR: Perquintill = Perquintill::from_parts(51_000_000_000_000_000);
@@ -265,6 +321,33 @@ pub mod pallet {
 		/// request to leave the set of collator candidates.
 		#[pallet::constant]
 		type ExitQueueDelay: Get<u32>;
+		/// Number of rounds a collator candidate has to wait after scheduling
+		/// a change of their commission before it can be applied.
+		#[pallet::constant]
+		type CommissionChangeDelay: Get<u32>;
+		/// Number of rounds a reported slash is deferred before it can be
+		/// applied. In the meantime, governance can cancel the slash.
+		#[pallet::constant]
+		type SlashDeferDuration: Get<u32>;
+		/// Number of past rounds for which staking statistics are kept.
+		#[pallet::constant]
+		type MaxRoundHistory: Get<u32>;
+		/// Maximum number of steps of the inflation schedule.
+		#[pallet::constant]
+		type MaxInflationSteps: Get<u32>;
+		/// Maximum number of collator candidates which can be linked to the
+		/// same identity.
+		#[pallet::constant]
+		type MaxCandidatesPerIdentity: Get<u32>;
+		/// Maximum number of accounts for which rewards are compounded per
+		/// block after the start of a round.
+		#[pallet::constant]
+		type MaxAutoCompoundsPerBlock: Get<u32>;
+		/// Maximum number of collator candidates for which the rewards, along
+		/// with the ones of their delegators, are incremented per block while
+		/// applying a step of the inflation schedule.
+		#[pallet::constant]
+		type MaxInflationUpdatesPerBlock: Get<u32>;
 
 		/// Minimum number of collators selected from the set of candidates at
 		/// every validation round.
@@ -288,6 +371,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxDelegatorsPerCollator: Get<u32> + Debug + PartialEq;
 
+		/// Maximum number of collators a single delegator can delegate.
+		#[pallet::constant]
+		type MaxCollatorsPerDelegator: Get<u32> + Debug + PartialEq;
+
 		/// Maximum size of the top candidates set.
 		#[pallet::constant]
 		type MaxTopCandidates: Get<u32> + Debug + PartialEq;
@@ -319,6 +406,16 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUnstakeRequests: Get<u32>;
 
+		/// The identifier from which the accounts of staking pools are
+		/// derived.
+		#[pallet::constant]
+		type PoolPalletId: Get<PalletId>;
+
+		/// Minimum amount of funds an account has to add when joining a
+		/// staking pool.
+		#[pallet::constant]
+		type MinPoolJoin: Get<BalanceOf<Self>>;
+
 		/// The starting block number for the network rewards. Once the current
 		/// block number exceeds this start, the beneficiary will receive the
 		/// configured reward in each block.
@@ -334,6 +431,13 @@ pub mod pallet {
 		/// The beneficiary to receive the network rewards.
 		type NetworkRewardBeneficiary: OnUnbalanced<CreditOf<Self>>;
 
+		/// The beneficiary to receive slashed funds, e.g. the treasury.
+		type Slash: OnUnbalanced<CreditOf<Self>>;
+
+		/// The provider of the identities collator candidates can be linked
+		/// to.
+		type IdentityProvider: CollatorIdentityProvider<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -418,7 +522,8 @@ pub mod pallet {
 		/// The number of selected candidates per staking round is
 		/// below the minimum value allowed.
 		CannotSetBelowMin,
-		/// An invalid inflation configuration is trying to be set.
+		/// An invalid inflation configuration or inflation schedule is trying
+		/// to be set.
 		InvalidSchedule,
 		/// The staking reward being unlocked does not exist.
 		/// Max unlocking requests reached.
@@ -435,6 +540,65 @@ pub mod pallet {
 		/// Invalid input provided. The meaning of this error is
 		/// extrinsic-dependent.
 		InvalidInput,
+		/// The commission is higher than the maximum commission allowed.
+		CommissionAboveMax,
+		/// The collator candidate has not scheduled a change of their
+		/// commission.
+		NoScheduledCommission,
+		/// The scheduled commission cannot be applied before waiting for
+		/// `CommissionChangeDelay` many rounds.
+		CommissionChangeTooEarly,
+		/// The account is neither a collator candidate nor a delegator.
+		NotStaking,
+		/// The collator candidate does not have a deferred slash.
+		NoDeferredSlash,
+		/// The deferred slash cannot be applied before waiting for
+		/// `SlashDeferDuration` many rounds.
+		SlashNotDue,
+		/// The collator candidate cannot leave before their deferred slash has
+		/// been applied or canceled.
+		SlashPending,
+		/// The collator candidate has been suspended because of an offence
+		/// and cannot perform this action in the meantime.
+		CandidateSuspended,
+		/// The collator candidate is not suspended.
+		NotSuspended,
+		/// The collator candidate tried to resume before the end of their
+		/// suspension.
+		CannotResumeYet,
+		/// The slashing configuration is invalid, e.g. a slash would take the
+		/// entire stake.
+		InvalidSlashingConfig,
+		/// The staking pool does not exist.
+		PoolNotFound,
+		/// The account is not a member of the staking pool.
+		PoolMemberNotFound,
+		/// The staking pool does not accept new funds.
+		PoolBlocked,
+		/// The staking pool does not delegate anymore, e.g. because its
+		/// delegation has been replaced by a higher one.
+		PoolNotDelegating,
+		/// The amount added to a staking pool is below `MinPoolJoin`.
+		PoolJoinBelowMin,
+		/// The pool member tried to redeem more points than they hold.
+		InsufficientPoolPoints,
+		/// The pool member has no unbonded funds which can be withdrawn yet.
+		NothingToWithdraw,
+		/// The origin is not permitted to manage the staking pool.
+		NotPoolManager,
+		/// The account is not linked to an identity by the identity provider.
+		IdentityNotFound,
+		/// The collator candidate is not linked to an identity.
+		IdentityNotLinked,
+		/// Collator candidates are required to be linked to an identity.
+		IdentityRequired,
+		/// The identity is already linked to the maximum number of collator
+		/// candidates.
+		TooManyIdentityCandidates,
+		/// The collator candidate was selected within the last
+		/// `StakeDuration` blocks or the delegator restaked funds which would
+		/// still be unstaking.
+		FastUnstakeNotAllowed,
 	}
 
 	#[pallet::event]
@@ -523,6 +687,82 @@ pub mod pallet {
 		/// \[round number, first block in the current round, old value, new
 		/// value\]
 		BlocksPerRoundSet(SessionIndex, BlockNumberFor<T>, BlockNumberFor<T>, BlockNumberFor<T>),
+		/// A collator candidate has scheduled a change of their commission.
+		/// \[collator's account, new commission, round number from which on
+		/// the change can be applied\]
+		CommissionChangeScheduled(T::AccountId, Perbill, SessionIndex),
+		/// The commission of a collator candidate has changed.
+		/// \[collator's account, new commission\]
+		CommissionSet(T::AccountId, Perbill),
+		/// The maximum commission of collator candidates has changed.
+		/// \[new max commission\]
+		MaxCommissionSet(Perbill),
+		/// A collator candidate or delegator has changed the share of their
+		/// rewards which is automatically staked. \[account, new share\]
+		AutoCompoundSet(T::AccountId, Perbill),
+		/// Rewards of a collator candidate or delegator have been staked
+		/// automatically. \[account, amount of rewards staked\]
+		RewardsCompounded(T::AccountId, BalanceOf<T>),
+		/// A collator candidate has committed an offence and a slash has been
+		/// deferred. \[collator's account, offence, slashed share of the stake,
+		/// round from which on the slash can be applied\]
+		OffenceReported(T::AccountId, Offence, Perbill, SessionIndex),
+		/// A collator candidate has been removed from the set of top candidates
+		/// because of an offence. \[collator's account, round until which the
+		/// candidate is suspended\]
+		CandidateSuspended(T::AccountId, SessionIndex),
+		/// A suspended collator candidate has rejoined the set of candidates.
+		/// \[collator's account\]
+		CandidateResumed(T::AccountId),
+		/// Staked funds have been slashed because of an offence of a collator
+		/// candidate. \[collator's account, slashed account, slashed amount\]
+		Slashed(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// A deferred slash has been canceled. \[collator's account\]
+		DeferredSlashCanceled(T::AccountId),
+		/// The slashing configuration has changed. \[new configuration\]
+		SlashingConfigSet(SlashingConfiguration),
+		/// A staking pool has been created. \[pool id, owner's account,
+		/// collator's account, initial stake\]
+		PoolCreated(PoolId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// An account has added funds to a staking pool. \[pool id, member's
+		/// account, added amount, received points\]
+		PoolJoined(PoolId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// The pending rewards of a pool member have been added to their
+		/// rewards. \[pool id, member's account, amount of rewards\]
+		PoolRewardsClaimed(PoolId, T::AccountId, BalanceOf<T>),
+		/// A pool member has unbonded funds from a staking pool. \[pool id,
+		/// member's account, redeemed points, unbonded amount\]
+		PoolUnbonded(PoolId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// A pool member has withdrawn unbonded funds. \[pool id, member's
+		/// account, withdrawn amount\]
+		PoolWithdrawn(PoolId, T::AccountId, BalanceOf<T>),
+		/// All funds of a staking pool have been withdrawn and the pool has
+		/// been removed. \[pool id\]
+		PoolDestroyed(PoolId),
+		/// The roles of a staking pool have changed. \[pool id, new roles\]
+		PoolRolesSet(PoolId, PoolRoles<T::AccountId>),
+		/// The commission of a staking pool has changed. \[pool id, new
+		/// commission\]
+		PoolCommissionSet(PoolId, Perbill),
+		/// The state of a staking pool has changed. \[pool id, new state\]
+		PoolStateSet(PoolId, PoolState),
+		/// A collator candidate has been linked to an identity. \[account,
+		/// identity\]
+		IdentityLinked(T::AccountId, IdentityOf<T>),
+		/// The link of a collator candidate to an identity has been removed.
+		/// \[account\]
+		IdentityUnlinked(T::AccountId),
+		/// The requirement for collator candidates to be linked to an identity
+		/// has been set. \[is required\]
+		IdentityRequirementSet(bool),
+		/// A delegation has been revoked and its funds have been released
+		/// immediately. \[delegator's account, collator's account, amount\]
+		FastUnstaked(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// The inflation schedule has been replaced. \[number of steps\]
+		InflationScheduleSet(u32),
+		/// A step of the inflation schedule which became due at the start of a
+		/// round has been applied. \[round number, start block of the step\]
+		InflationStepApplied(SessionIndex, BlockNumberFor<T>),
 	}
 
 	#[pallet::hooks]
@@ -539,8 +779,16 @@ pub mod pallet {
 				Round::<T>::put(round);
 
 				Self::deposit_event(Event::NewRound(round.first, round.current));
-				post_weight = <T as Config>::WeightInfo::on_initialize_round_update();
+				post_weight = <T as Config>::WeightInfo::on_initialize_round_update()
+					.saturating_add(Self::apply_inflation_schedule(now, round.current));
+
+				// compound the rewards of the last round unless still busy with a previous one
+				if !AutoCompoundCursor::<T>::exists() {
+					AutoCompoundCursor::<T>::put(None::<T::AccountId>);
+				}
 			}
+			post_weight = post_weight.saturating_add(Self::process_inflation_step());
+			post_weight = post_weight.saturating_add(Self::process_auto_compound());
 			// check for network reward and mint
 			// on success, mint each block
 			if now > T::NetworkRewardStart::get() {
@@ -581,8 +829,13 @@ pub mod pallet {
 	/// It maps from an account to its delegation details.
 	#[pallet::storage]
 	#[pallet::getter(fn delegator_state)]
-	pub(crate) type DelegatorState<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, Delegator<T::AccountId, BalanceOf<T>>, OptionQuery>;
+	pub(crate) type DelegatorState<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Delegator<T::AccountId, BalanceOf<T>, T::MaxCollatorsPerDelegator>,
+		OptionQuery,
+	>;
 
 	/// The staking information for a candidate.
 	///
@@ -627,6 +880,14 @@ pub mod pallet {
 	#[pallet::getter(fn inflation_config)]
 	pub(crate) type InflationConfig<T: Config> = StorageValue<_, InflationInfo, ValueQuery>;
 
+	/// The upcoming steps of the inflation configuration, sorted by their
+	/// start block.
+	///
+	/// Due steps are applied and removed at the start of each round.
+	#[pallet::storage]
+	#[pallet::getter(fn inflation_schedule)]
+	pub(crate) type InflationSchedule<T: Config> = StorageValue<_, InflationScheduleOf<T>, ValueQuery>;
+
 	/// The funds waiting to be unstaked.
 	///
 	/// It maps from accounts to all the funds addressed to them in the future
@@ -662,20 +923,28 @@ pub mod pallet {
 	pub(crate) type BlocksAuthored<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
 
-	/// The number of blocks for which rewards have been claimed by an address.
+	/// The number of blocks for which rewards have been claimed by a collator.
 	///
-	/// For collators, this can be at most BlocksAuthored. It is updated when
-	/// incrementing collator rewards, either when calling
-	/// `inc_collator_rewards` or updating the `InflationInfo`.
-	///
-	/// For delegators, this can be at most BlocksAuthored of the collator.It is
-	/// updated when incrementing delegator rewards, either when calling
-	/// `inc_delegator_rewards` or updating the `InflationInfo`.
+	/// This can be at most BlocksAuthored. It is updated when incrementing
+	/// collator rewards, either when calling `inc_collator_rewards` or updating
+	/// the `InflationInfo`.
 	#[pallet::storage]
 	#[pallet::getter(fn blocks_rewarded)]
 	pub(crate) type BlocksRewarded<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
 
+	/// The number of blocks for which rewards have been claimed by a delegator
+	/// for one of their delegations.
+	///
+	/// It maps from a delegator and a delegated collator to at most
+	/// BlocksAuthored of the collator. It is updated when incrementing
+	/// delegator rewards, either when calling `inc_delegator_rewards` or
+	/// updating the `InflationInfo`.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation_blocks_rewarded)]
+	pub(crate) type DelegationBlocksRewarded<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
+
 	/// The accumulated rewards for collator candidates and delegators.
 	///
 	/// It maps from accounts to their total rewards since the last payout.
@@ -683,12 +952,190 @@ pub mod pallet {
 	#[pallet::getter(fn rewards)]
 	pub(crate) type Rewards<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// The share of their delegators' rewards a collator candidate charges as
+	/// commission.
+	///
+	/// It maps from a collator candidate to their commission which is capped
+	/// by `MaxCommission` when rewards are incremented.
+	#[pallet::storage]
+	#[pallet::getter(fn commission)]
+	pub(crate) type CandidateCommission<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill, ValueQuery>;
+
+	/// The commission changes scheduled by collator candidates.
+	///
+	/// It maps from a collator candidate to their new commission and the
+	/// round from which on it can be applied.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_commission)]
+	pub(crate) type ScheduledCommission<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (Perbill, SessionIndex), OptionQuery>;
+
+	/// The maximum commission a collator candidate can charge.
+	#[pallet::storage]
+	#[pallet::getter(fn max_commission)]
+	pub(crate) type MaxCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	/// The share of rewards which is automatically staked.
+	///
+	/// It maps from collator candidates and delegators to the share of their
+	/// rewards which is staked whenever their rewards are actively
+	/// incremented and at the start of each round.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_compound)]
+	pub(crate) type AutoCompound<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill, ValueQuery>;
+
+	/// The progress of compounding the rewards of all accounts in
+	/// `AutoCompound` after the start of a round.
+	///
+	/// Not set if all accounts have been processed. Otherwise, it contains
+	/// the last processed account or `None` if processing starts with the
+	/// first account.
+	#[pallet::storage]
+	pub(crate) type AutoCompoundCursor<T: Config> = StorageValue<_, Option<T::AccountId>, OptionQuery>;
+
+	/// The step of the inflation schedule which is being applied, along with
+	/// the round in which it became due and the progress of incrementing the
+	/// rewards of all collator candidates and their delegators.
+	///
+	/// The progress is the last processed collator candidate or `None` if
+	/// processing starts with the first one. The step is applied once all
+	/// candidates have been processed.
+	#[pallet::storage]
+	pub(crate) type PendingInflationStep<T: Config> =
+		StorageValue<_, (InflationStep<BlockNumberFor<T>>, SessionIndex, Option<T::AccountId>), OptionQuery>;
+
+	/// The governance-controlled parameters for slashing collators.
+	#[pallet::storage]
+	#[pallet::getter(fn slashing_config)]
+	pub(crate) type SlashingConfig<T: Config> = StorageValue<_, SlashingConfiguration, ValueQuery>;
+
+	/// The collators selected for a session.
+	///
+	/// It maps from a session index to the collators which are expected to
+	/// author blocks in that session.
+	#[pallet::storage]
+	pub(crate) type SessionCollators<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, BoundedVec<T::AccountId, T::MaxTopCandidates>, OptionQuery>;
+
+	/// The number of blocks authored by a collator in the current round.
+	#[pallet::storage]
+	#[pallet::getter(fn blocks_authored_in_round)]
+	pub(crate) type BlocksAuthoredInRound<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The number of consecutive rounds in which a collator authored too few
+	/// blocks.
+	#[pallet::storage]
+	#[pallet::getter(fn missed_rounds)]
+	pub(crate) type MissedRounds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The slashes which have been reported but not applied yet.
+	///
+	/// It maps from a collator candidate to their pending slash.
+	#[pallet::storage]
+	#[pallet::getter(fn deferred_slash)]
+	pub(crate) type DeferredSlashes<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, DeferredSlash, OptionQuery>;
+
+	/// The staking statistics of the last `MaxRoundHistory` rounds.
+	///
+	/// It maps from a round index to the statistics of that round.
+	#[pallet::storage]
+	#[pallet::getter(fn round_stats)]
+	pub(crate) type RoundHistory<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, RoundStats<BalanceOf<T>>, OptionQuery>;
+
+	/// The staking statistics of the collators for the last
+	/// `MaxRoundHistory` rounds.
+	///
+	/// It maps from a round index and a collator to the statistics of the
+	/// collator in that round.
+	#[pallet::storage]
+	#[pallet::getter(fn collator_stats)]
+	pub(crate) type CollatorHistory<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		CollatorStats<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
 	pub type GenesisStaker<T> = Vec<(
 		<T as frame_system::Config>::AccountId,
 		Option<<T as frame_system::Config>::AccountId>,
 		BalanceOf<T>,
 	)>;
 
+	/// The identifier of the next staking pool.
+	#[pallet::storage]
+	#[pallet::getter(fn next_pool_id)]
+	pub(crate) type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	/// The staking pools which delegate on behalf of their members.
+	#[pallet::storage]
+	#[pallet::getter(fn pool)]
+	pub(crate) type Pools<T: Config> = StorageMap<_, Twox64Concat, PoolId, StakingPoolOf<T>, OptionQuery>;
+
+	/// The members of staking pools.
+	///
+	/// It maps from a pool and an account to the share of the account in the
+	/// pool.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_member)]
+	pub(crate) type PoolMembers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PoolId, Twox64Concat, T::AccountId, PoolMemberOf<T>, OptionQuery>;
+
+	/// The funds unbonded by pool members which can be withdrawn after
+	/// `StakeDuration` blocks.
+	///
+	/// It maps from a pool and an account to the block numbers at which
+	/// unbonded funds become available.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_unbonding)]
+	pub(crate) type PoolUnbonding<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Twox64Concat,
+		T::AccountId,
+		BoundedBTreeMap<BlockNumberFor<T>, BalanceOf<T>, T::MaxUnstakeRequests>,
+		ValueQuery,
+	>;
+
+	/// The identities collator candidates are linked to.
+	#[pallet::storage]
+	#[pallet::getter(fn candidate_identity)]
+	pub(crate) type CandidateIdentity<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, IdentityOf<T>, OptionQuery>;
+
+	/// The collator candidates linked to an identity.
+	///
+	/// It maps from an identity and a collator candidate to the empty tuple.
+	#[pallet::storage]
+	pub(crate) type IdentityCandidates<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, IdentityOf<T>, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// The number of collator candidates linked to an identity.
+	///
+	/// It is bounded by `MaxCandidatesPerIdentity`.
+	#[pallet::storage]
+	pub(crate) type IdentityCandidateCount<T: Config> = StorageMap<_, Twox64Concat, IdentityOf<T>, u32, ValueQuery>;
+
+	/// Whether collator candidates have to be linked to an identity to join
+	/// the set of candidates and to be selected as collators.
+	#[pallet::storage]
+	#[pallet::getter(fn identity_required)]
+	pub(crate) type IdentityRequired<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The block until which the funds an account restaked from its pending
+	/// unstaking requests would have been locked.
+	///
+	/// Delegators cannot unstake via `fast_unstake` before this block.
+	#[pallet::storage]
+	#[pallet::getter(fn restaked_until)]
+	pub(crate) type RestakedUntil<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn new_round_forced)]
 	pub(crate) type ForceNewRound<T: Config> = StorageValue<_, bool, ValueQuery>;
@@ -726,11 +1173,19 @@ pub mod pallet {
 						));
 					},
 					|delegated_val| {
-						frame_support::assert_ok!(Pallet::<T>::join_delegators(
-							T::RuntimeOrigin::from(Some(actor.clone()).into()),
-							T::Lookup::unlookup(delegated_val.clone()),
-							*balance,
-						));
+						if Pallet::<T>::is_delegator(actor) {
+							frame_support::assert_ok!(Pallet::<T>::delegate_another_candidate(
+								T::RuntimeOrigin::from(Some(actor.clone()).into()),
+								T::Lookup::unlookup(delegated_val.clone()),
+								*balance,
+							));
+						} else {
+							frame_support::assert_ok!(Pallet::<T>::join_delegators(
+								T::RuntimeOrigin::from(Some(actor.clone()).into()),
+								T::Lookup::unlookup(delegated_val.clone()),
+								*balance,
+							));
+						}
 					},
 				);
 			}
@@ -1007,12 +1462,18 @@ pub mod pallet {
 		/// The dispatch origin must not be already part of the collator
 		/// candidates nor of the delegators set.
 		///
-		/// Emits `JoinedCollatorCandidates`.
+		/// If the dispatch origin is linked to an identity by the identity
+		/// provider, the new candidate is linked to it, which fails if
+		/// `MaxCandidatesPerIdentity` candidates are linked to it already. If
+		/// identities are required, the dispatch origin must be linked to one.
+		///
+		/// Emits `JoinedCollatorCandidates` and, if an identity is linked,
+		/// `IdentityLinked`.
 		#[pallet::call_index(6)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::join_candidates(
 			T::MaxTopCandidates::get(),
 			T::MaxDelegatorsPerCollator::get()
-		))]
+		).saturating_add(<T as pallet::Config>::WeightInfo::link_identity()))]
 		pub fn join_candidates(origin: OriginFor<T>, stake: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			if let Some(is_active_candidate) = Self::is_active_candidate(&sender) {
@@ -1032,6 +1493,17 @@ pub mod pallet {
 				Unstaking::<T>::get(&sender).len().saturated_into::<u32>() < T::MaxUnstakeRequests::get(),
 				Error::<T>::CannotJoinBeforeUnlocking
 			);
+			let identity = T::IdentityProvider::identity_of(&sender);
+			ensure!(
+				identity.is_some() || !IdentityRequired::<T>::get(),
+				Error::<T>::IdentityRequired
+			);
+			ensure!(
+				identity
+					.as_ref()
+					.map_or(true, |identity| Self::can_link_identity(&sender, identity)),
+				Error::<T>::TooManyIdentityCandidates
+			);
 
 			Self::increase_lock(&sender, stake, BalanceOf::<T>::zero())?;
 
@@ -1045,11 +1517,14 @@ pub mod pallet {
 			);
 			CandidatePool::<T>::insert(&sender, candidate);
 
-			Self::deposit_event(Event::JoinedCollatorCandidates(sender, stake));
-			Ok(Some(<T as pallet::Config>::WeightInfo::join_candidates(
-				n,
-				T::MaxDelegatorsPerCollator::get(),
-			))
+			Self::deposit_event(Event::JoinedCollatorCandidates(sender.clone(), stake));
+			if let Some(identity) = identity {
+				Self::do_link_identity(&sender, identity);
+			}
+			Ok(Some(
+				<T as pallet::Config>::WeightInfo::join_candidates(n, T::MaxDelegatorsPerCollator::get())
+					.saturating_add(<T as pallet::Config>::WeightInfo::link_identity()),
+			)
 			.into())
 		}
 
@@ -1089,6 +1564,7 @@ pub mod pallet {
 			let collator = ensure_signed(origin)?;
 			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
 			ensure!(!state.is_leaving(), Error::<T>::AlreadyLeaving);
+			ensure!(!state.is_suspended(), Error::<T>::CandidateSuspended);
 			let mut candidates = TopCandidates::<T>::get();
 			ensure!(
 				candidates.len().saturated_into::<u32>() > T::MinRequiredCollators::get(),
@@ -1155,6 +1631,10 @@ pub mod pallet {
 			let state = CandidatePool::<T>::get(&collator_account).ok_or(Error::<T>::CandidateNotFound)?;
 			ensure!(state.is_leaving(), Error::<T>::NotLeaving);
 			ensure!(state.can_exit(Round::<T>::get().current), Error::<T>::CannotLeaveYet);
+			ensure!(
+				!DeferredSlashes::<T>::contains_key(&collator_account),
+				Error::<T>::SlashPending
+			);
 
 			let num_delegators = state.delegators.len().saturated_into::<u32>();
 			let total_amount = state.total;
@@ -1233,49 +1713,8 @@ pub mod pallet {
 		))]
 		pub fn candidate_stake_more(origin: OriginFor<T>, more: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let collator = ensure_signed(origin)?;
-
-			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
-			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
-			ensure!(!state.is_leaving(), Error::<T>::CannotStakeIfLeaving);
-
-			let CandidateOf::<T, _> {
-				stake: before_stake,
-				total: before_total,
-				..
-			} = state;
-			state.stake_more(more);
-			let after_stake = state.stake;
-			ensure!(
-				state.stake <= MaxCollatorCandidateStake::<T>::get(),
-				Error::<T>::ValStakeAboveMax
-			);
-
-			let unstaking_len = Self::increase_lock(&collator, state.stake, more)?;
-
-			let n = if state.is_active() {
-				Self::update_top_candidates(
-					collator.clone(),
-					before_stake,
-					// safe because total >= stake
-					before_total - before_stake,
-					state.stake,
-					state.total - state.stake,
-				)
-			} else {
-				0u32
-			};
-			CandidatePool::<T>::insert(&collator, state);
-
-			// increment rewards for collator and update number of rewarded blocks
-			Self::do_inc_collator_reward(&collator, before_stake);
-
-			Self::deposit_event(Event::CollatorStakedMore(collator, before_stake, after_stake));
-			Ok(Some(<T as pallet::Config>::WeightInfo::candidate_stake_more(
-				n,
-				T::MaxDelegatorsPerCollator::get(),
-				unstaking_len,
-			))
-			.into())
+			let weight = Self::do_candidate_stake_more(&collator, more)?;
+			Ok(Some(weight).into())
 		}
 
 		/// Stake less funds for a collator candidate.
@@ -1377,105 +1816,13 @@ pub mod pallet {
 			amount: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let acc = ensure_signed(origin)?;
-			let collator_account = T::Lookup::lookup(collator)?;
-
-			// check balance
-			ensure!(
-				pallet_balances::Pallet::<T>::balance(&acc) >= amount.into(),
-				pallet_balances::Error::<T>::InsufficientBalance
-			);
-
-			// first delegation
-			ensure!(DelegatorState::<T>::get(&acc).is_none(), Error::<T>::AlreadyDelegating);
-			ensure!(amount >= T::MinDelegatorStake::get(), Error::<T>::DelegationBelowMin);
-
-			// cannot be a collator candidate and delegator with same AccountId
-			ensure!(Self::is_active_candidate(&acc).is_none(), Error::<T>::CandidateExists);
-			ensure!(
-				Unstaking::<T>::get(&acc).len().saturated_into::<u32>() < T::MaxUnstakeRequests::get(),
-				Error::<T>::CannotJoinBeforeUnlocking
-			);
-			// cannot delegate if number of delegations in this round exceeds
-			// MaxDelegationsPerRound
-			let delegation_counter = Self::get_delegation_counter(&acc)?;
-
-			// prepare update of collator state
-			let mut state = CandidatePool::<T>::get(&collator_account).ok_or(Error::<T>::CandidateNotFound)?;
-			let num_delegations_pre_insertion: u32 = state.delegators.len().saturated_into();
-
-			ensure!(!state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
-			let delegation = Stake {
-				owner: acc.clone(),
-				amount,
-			};
-
-			// attempt to insert delegator and check for uniqueness
-			// NOTE: excess is handled below because we support replacing a delegator with
-			// fewer stake
-			let insert_delegator = state
-				.delegators
-				// we handle TooManyDelegators error below in do_update_delegator
-				.try_insert(delegation.clone())
-				.unwrap_or(true);
-			// should never fail but let's be safe
-			ensure!(insert_delegator, Error::<T>::DelegatorExists);
-
-			let delegator_state = Delegator {
-				amount,
-				owner: collator_account.clone(),
-			};
-			let CandidateOf::<T, _> {
-				stake: old_stake,
-				total: old_total,
-				..
-			} = state;
-
-			// update state and potentially prepare kicking a delegator with less staked
-			// amount (includes setting rewards for kicked delegator)
-			let new_collator_state = if num_delegations_pre_insertion == T::MaxDelegatorsPerCollator::get() {
-				Self::do_update_delegator(delegation, state)?
-			} else {
-				state.total = state.total.saturating_add(amount);
-				state
-			};
-			let new_total = new_collator_state.total;
-
-			// lock stake
-			Self::increase_lock(&acc, amount, BalanceOf::<T>::zero())?;
-
-			// update top candidates and total amount at stake
-			let n = if new_collator_state.is_active() {
-				Self::update_top_candidates(
-					collator_account.clone(),
-					old_stake,
-					// safe because total >= stake
-					old_total - old_stake,
-					new_collator_state.stake,
-					new_collator_state.total - new_collator_state.stake,
-				)
-			} else {
-				0u32
-			};
-
-			// update states
-			CandidatePool::<T>::insert(&collator_account, new_collator_state);
-			DelegatorState::<T>::insert(&acc, delegator_state);
-			LastDelegation::<T>::insert(&acc, delegation_counter);
-
-			// initiate rewarded counter to match the current authored counter of the
-			// candidate
-			BlocksRewarded::<T>::insert(&acc, BlocksAuthored::<T>::get(&collator_account));
-
-			Self::deposit_event(Event::Delegation(acc, amount, collator_account, new_total));
-			Ok(Some(<T as pallet::Config>::WeightInfo::join_delegators(
-				n,
-				T::MaxDelegatorsPerCollator::get(),
-			))
-			.into())
+			let collator = T::Lookup::lookup(collator)?;
+			let weight = Self::do_join_delegators(&acc, &collator, amount)?;
+			Ok(Some(weight).into())
 		}
 
-		/// Leave the set of delegators and, by implication, revoke the ongoing
-		/// delegation.
+		/// Leave the set of delegators and, by implication, revoke all ongoing
+		/// delegations.
 		///
 		/// All staked funds are not unlocked immediately, but they are added to
 		/// the queue of pending unstaking, and will effectively be released
@@ -1486,34 +1833,90 @@ pub mod pallet {
 		/// their chances to be included in the set of candidates in the next
 		/// rounds.
 		///
-		/// Automatically increments the accumulated rewards of the origin of
-		/// the current delegation.
+		/// Automatically increments the accumulated rewards of the origin for
+		/// each of the revoked delegations.
 		///
+		/// Emits `DelegatorLeftCollator` for each revoked delegation.
 		/// Emits `DelegatorLeft`.
 		#[pallet::call_index(13)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::leave_delegators(
-			T::MaxTopCandidates::get(),
+			T::MaxCollatorsPerDelegator::get(),
 			T::MaxDelegatorsPerCollator::get()
 		))]
 		pub fn leave_delegators(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let acc = ensure_signed(origin)?;
-			let delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
-			let collator = delegator.owner;
-			Self::delegator_leaves_collator(acc.clone(), collator)?;
-
-			DelegatorState::<T>::remove(&acc);
-
-			Self::deposit_event(Event::DelegatorLeft(acc, delegator.amount));
-			Ok(Some(<T as pallet::Config>::WeightInfo::leave_delegators(
-				1,
-				T::MaxDelegatorsPerCollator::get(),
-			))
-			.into())
+			let weight = Self::do_leave_delegators(&acc)?;
+			Ok(Some(weight).into())
 		}
 
-		/// Increase the stake for delegating a collator candidate.
+		/// Delegate another collator candidate by staking some funds and
+		/// increasing the pallet's as well as the collator's total stake.
 		///
-		/// If not in the set of candidates, staking enough funds allows the
+		/// The account that wants to delegate cannot be part of the collator
+		/// candidates set as well and must already be a delegator. The
+		/// delegator can back at most `MaxCollatorsPerDelegator` many
+		/// collator candidates.
+		///
+		/// The total amount of funds staked must be within the allowed range as
+		/// set in the pallet's configuration.
+		///
+		/// As it is possible that a collator candidate already has the maximum
+		/// number of delegators, the new delegation must be larger than the
+		/// smallest delegation of that candidate, which is then replaced.
+		///
+		/// Emits `Delegation`.
+		/// Emits `DelegationReplaced` if the candidate has
+		/// `MaxDelegatorsPerCollator` many delegations but this delegator
+		/// staked more than one of the other delegators of this candidate.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegate_another_candidate(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn delegate_another_candidate(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let weight = Self::do_delegate_another_candidate(&acc, &collator, amount)?;
+			Ok(Some(weight).into())
+		}
+
+		/// Revoke a single delegation of the origin.
+		///
+		/// The staked funds are not unlocked immediately, but they are added to
+		/// the queue of pending unstaking, and will effectively be released
+		/// after `StakeDuration` blocks from the moment the delegation is
+		/// revoked.
+		///
+		/// If this was the last delegation of the origin, they leave the set
+		/// of delegators.
+		///
+		/// Automatically increments the accumulated rewards of the origin for
+		/// the revoked delegation.
+		///
+		/// Emits `DelegatorLeftCollator`.
+		/// Emits `DelegatorLeft` if no delegations are left.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_delegation(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn revoke_delegation(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let weight = Self::do_revoke_delegation(&acc, &collator)?;
+			Ok(Some(weight).into())
+		}
+
+		/// Increase the stake for delegating a collator candidate.
+		///
+		/// If not in the set of candidates, staking enough funds allows the
 		/// collator candidate to be added to it.
 		///
 		/// Emits `DelegatorStakedMore`.
@@ -1523,56 +1926,15 @@ pub mod pallet {
 			T::MaxDelegatorsPerCollator::get(),
 			T::MaxUnstakeRequests::get().saturated_into::<u32>())
 		)]
-		pub fn delegator_stake_more(origin: OriginFor<T>, more: BalanceOf<T>) -> DispatchResultWithPostInfo {
+		pub fn delegator_stake_more(
+			origin: OriginFor<T>,
+			candidate: <T::Lookup as StaticLookup>::Source,
+			more: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
-			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
-
-			let mut delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let candidate = delegation.owner.clone();
-			let mut collator = CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
-			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
-			let stake_after = delegation
-				.try_increment(candidate.clone(), more)
-				.map_err(|_| Error::<T>::DelegationNotFound)?;
-
-			// update lock
-			let unstaking_len = Self::increase_lock(&delegator, stake_after, more)?;
-
-			let CandidateOf::<T, _> {
-				stake: before_stake,
-				total: before_total,
-				..
-			} = collator;
-			collator.inc_delegator(delegator.clone(), more);
-			let after = collator.total;
-
-			// update top candidates and total amount at stake
-			let n = if collator.is_active() {
-				Self::update_top_candidates(
-					candidate.clone(),
-					before_stake,
-					// safe because total >= stake
-					before_total - before_stake,
-					collator.stake,
-					collator.total - collator.stake,
-				)
-			} else {
-				0u32
-			};
-
-			// increment rewards and update number of rewarded blocks
-			Self::do_inc_delegator_reward(&delegator, stake_after.saturating_sub(more), &candidate);
-
-			CandidatePool::<T>::insert(&candidate, collator);
-			DelegatorState::<T>::insert(&delegator, delegation);
-
-			Self::deposit_event(Event::DelegatorStakedMore(delegator, candidate, before_total, after));
-			Ok(Some(<T as pallet::Config>::WeightInfo::delegator_stake_more(
-				n,
-				T::MaxDelegatorsPerCollator::get(),
-				unstaking_len,
-			))
-			.into())
+			let candidate = T::Lookup::lookup(candidate)?;
+			let weight = Self::do_delegator_stake_more(&delegator, &candidate, more)?;
+			Ok(Some(weight).into())
 		}
 
 		/// Reduce the stake for delegating a collator candidate.
@@ -1596,60 +1958,15 @@ pub mod pallet {
 			T::MaxTopCandidates::get(),
 			T::MaxDelegatorsPerCollator::get()
 		))]
-		pub fn delegator_stake_less(origin: OriginFor<T>, less: BalanceOf<T>) -> DispatchResultWithPostInfo {
+		pub fn delegator_stake_less(
+			origin: OriginFor<T>,
+			candidate: <T::Lookup as StaticLookup>::Source,
+			less: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
-			ensure!(!less.is_zero(), Error::<T>::ValStakeZero);
-
-			let mut delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let candidate = delegation.owner.clone();
-			let mut collator = CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
-			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
-			let stake_after = delegation
-				.try_decrement(candidate.clone(), less)
-				.map_err(|_| Error::<T>::DelegationNotFound)?
-				.ok_or(Error::<T>::Underflow)?;
-
-			ensure!(
-				stake_after >= T::MinDelegatorStake::get(),
-				Error::<T>::DelegationBelowMin
-			);
-
-			Self::prep_unstake(&delegator, less, false)?;
-
-			let CandidateOf::<T, _> {
-				stake: before_stake,
-				total: before_total,
-				..
-			} = collator;
-			collator.dec_delegator(delegator.clone(), less);
-			let after = collator.total;
-
-			// update top candidates and total amount at stake
-			let n = if collator.is_active() {
-				Self::update_top_candidates(
-					candidate.clone(),
-					before_stake,
-					// safe because total >= stake
-					before_total - before_stake,
-					collator.stake,
-					collator.total - collator.stake,
-				)
-			} else {
-				0u32
-			};
-
-			// increment rewards and update number of rewarded blocks
-			Self::do_inc_delegator_reward(&delegator, stake_after.saturating_add(less), &candidate);
-
-			CandidatePool::<T>::insert(&candidate, collator);
-			DelegatorState::<T>::insert(&delegator, delegation);
-
-			Self::deposit_event(Event::DelegatorStakedLess(delegator, candidate, before_total, after));
-			Ok(Some(<T as pallet::Config>::WeightInfo::delegator_stake_less(
-				n,
-				T::MaxDelegatorsPerCollator::get(),
-			))
-			.into())
+			let candidate = T::Lookup::lookup(candidate)?;
+			let weight = Self::do_delegator_stake_less(&delegator, &candidate, less)?;
+			Ok(Some(weight).into())
 		}
 
 		/// Unlock all previously staked funds that are now available for
@@ -1690,17 +2007,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
 		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
 			let target = ensure_signed(origin)?;
-
-			// reset rewards
-			let rewards = Rewards::<T>::take(&target);
-			ensure!(!rewards.is_zero(), Error::<T>::RewardsNotFound);
-
-			// mint into target
-			let rewarded =
-				<T::Currency as Unbalanced<AccountIdOf<T>>>::increase_balance(&target, rewards, Precision::Exact)?;
-
-			Self::deposit_event(Event::Rewarded(target, rewarded));
-
+			Self::do_claim_rewards(&target)?;
 			Ok(())
 		}
 
@@ -1709,36 +2016,52 @@ pub mod pallet {
 		/// The same effect is triggered by changing the stake or leaving the
 		/// network.
 		///
+		/// If the collator opted into auto-compounding, the configured share
+		/// of the incremented rewards is staked.
+		///
 		/// The dispatch origin must be a collator.
+		///
+		/// Emits `RewardsCompounded` if rewards were staked.
 		#[pallet::call_index(18)]
-		#[pallet::weight(<T as Config>::WeightInfo::increment_collator_rewards())]
-		pub fn increment_collator_rewards(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::increment_collator_rewards().saturating_add(
+			<T as Config>::WeightInfo::candidate_stake_more(
+				T::MaxTopCandidates::get(),
+				T::MaxDelegatorsPerCollator::get(),
+				T::MaxUnstakeRequests::get().saturated_into::<u32>()
+			)
+		))]
+		pub fn increment_collator_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let collator = ensure_signed(origin)?;
-			let state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
-
-			// increment rewards and update number of rewarded blocks
-			Self::do_inc_collator_reward(&collator, state.stake);
-
-			Ok(())
+			let weight = Self::do_increment_collator_rewards(&collator)?;
+			Ok(Some(weight).into())
 		}
 
-		/// Actively increment the rewards of a delegator.
+		/// Actively increment the rewards of a delegator for all of their
+		/// delegations.
 		///
 		/// The same effect is triggered by changing the stake or revoking
 		/// delegations.
 		///
+		/// If the delegator opted into auto-compounding, the configured share
+		/// of the rewards incremented for each delegation is staked for the
+		/// same collator candidate.
+		///
 		/// The dispatch origin must be a delegator.
+		///
+		/// Emits `RewardsCompounded` for each delegation for which rewards were
+		/// staked.
 		#[pallet::call_index(19)]
-		#[pallet::weight(<T as Config>::WeightInfo::increment_delegator_rewards())]
-		pub fn increment_delegator_rewards(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::increment_delegator_rewards().saturating_add(
+			<T as Config>::WeightInfo::delegator_stake_more(
+				T::MaxTopCandidates::get(),
+				T::MaxDelegatorsPerCollator::get(),
+				T::MaxUnstakeRequests::get().saturated_into::<u32>()
+			)
+		).saturating_mul(T::MaxCollatorsPerDelegator::get().into()))]
+		pub fn increment_delegator_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
-			let delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let collator = delegation.owner;
-
-			// increment rewards and update number of rewarded blocks
-			Self::do_inc_delegator_reward(&delegator, delegation.amount, &collator);
-
-			Ok(())
+			let weight = Self::do_increment_delegator_rewards(&delegator)?;
+			Ok(Some(weight).into())
 		}
 
 		/// Executes the annual reduction of the reward rates for collators and
@@ -1804,93 +2127,1208 @@ pub mod pallet {
 
 			Ok(Some(<T as pallet::Config>::WeightInfo::set_inflation(num_col, num_del)).into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		/// Check whether an account is currently delegating.
-		pub fn is_delegator(acc: &T::AccountId) -> bool {
-			DelegatorState::<T>::get(acc).is_some()
+		/// Schedule a change of the commission the origin charges on the
+		/// rewards of their delegators.
+		///
+		/// The new commission can be applied by calling
+		/// `execute_commission_change` after waiting at least
+		/// `CommissionChangeDelay` many rounds, which gives delegators the
+		/// chance to react. A previously scheduled change is replaced.
+		///
+		/// The commission must not exceed `MaxCommission`.
+		///
+		/// Emits `CommissionChangeScheduled`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_commission())]
+		pub fn set_commission(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+			let collator = ensure_signed(origin)?;
+			let state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!state.is_leaving(), Error::<T>::CannotStakeIfLeaving);
+			ensure!(commission <= MaxCommission::<T>::get(), Error::<T>::CommissionAboveMax);
+
+			let effective_round = Round::<T>::get()
+				.current
+				.saturating_add(T::CommissionChangeDelay::get());
+			ScheduledCommission::<T>::insert(&collator, (commission, effective_round));
+
+			Self::deposit_event(Event::CommissionChangeScheduled(collator, commission, effective_round));
+			Ok(())
 		}
 
-		/// Check whether an account is currently a collator candidate and
-		/// whether their state is CollatorStatus::Active.
+		/// Apply the commission change of a collator candidate which was
+		/// scheduled at least `CommissionChangeDelay` rounds ago.
 		///
-		/// Returns Some(is_active) if the account is a candidate, else None.
-		pub fn is_active_candidate(acc: &T::AccountId) -> Option<bool> {
-			if let Some(state) = CandidatePool::<T>::get(acc) {
-				Some(state.status == CandidateStatus::Active)
+		/// Increments the rewards of all delegators of the candidate before
+		/// the new commission takes effect. The new commission is capped by the
+		/// current `MaxCommission`.
+		///
+		/// The dispatch origin can be any signed one.
+		///
+		/// Emits `CommissionSet`.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::execute_commission_change(
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn execute_commission_change(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let collator_account = T::Lookup::lookup(collator)?;
+			let state = CandidatePool::<T>::get(&collator_account).ok_or(Error::<T>::CandidateNotFound)?;
+			let (commission, effective_round) =
+				ScheduledCommission::<T>::get(&collator_account).ok_or(Error::<T>::NoScheduledCommission)?;
+			ensure!(
+				Round::<T>::get().current >= effective_round,
+				Error::<T>::CommissionChangeTooEarly
+			);
+
+			// increment rewards of delegators with the previous commission
+			for stake in state.delegators.iter() {
+				Self::do_inc_delegator_reward(&stake.owner, stake.amount, &collator_account);
+			}
+
+			let commission = commission.min(MaxCommission::<T>::get());
+			if commission.is_zero() {
+				CandidateCommission::<T>::remove(&collator_account);
 			} else {
-				None
+				CandidateCommission::<T>::insert(&collator_account, commission);
 			}
+			ScheduledCommission::<T>::remove(&collator_account);
+
+			Self::deposit_event(Event::CommissionSet(collator_account, commission));
+			Ok(Some(<T as pallet::Config>::WeightInfo::execute_commission_change(
+				state.delegators.len().saturated_into::<u32>(),
+			))
+			.into())
 		}
-		/// Set the annual inflation rate to derive per-round inflation.
+
+		/// Set the maximum commission collator candidates can charge.
 		///
-		/// The inflation details are considered valid if the annual reward rate
-		/// is approximately the per-block reward rate multiplied by the
-		/// estimated* total number of blocks per year.
+		/// Existing commissions are not changed but capped by the new maximum
+		/// whenever rewards are incremented.
 		///
-		/// The estimated average block time is twelve seconds.
+		/// The dispatch origin must be Root.
 		///
-		/// NOTE: Iterates over CandidatePool and for each candidate over their
-		/// delegators to update their rewards before the reward rates change.
-		/// Needs to be improved when scaling up `MaxTopCandidates`.
+		/// Emits `MaxCommissionSet`.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_max_commission())]
+		pub fn set_max_commission(origin: OriginFor<T>, new: Perbill) -> DispatchResult {
+			ensure_root(origin)?;
+
+			MaxCommission::<T>::put(new);
+
+			Self::deposit_event(Event::MaxCommissionSet(new));
+			Ok(())
+		}
+
+		/// Set the share of rewards which is automatically staked whenever the
+		/// rewards of the origin are actively incremented via
+		/// `increment_collator_rewards` or `increment_delegator_rewards`.
+		/// Additionally, the rewards of all accounts which opted in are
+		/// incremented and compounded once per round, spread over the first
+		/// blocks of the round.
 		///
-		/// Emits `RoundInflationSet`.
-		fn do_set_inflation(
-			blocks_per_year: BlockNumberFor<T>,
-			col_max_rate: Perquintill,
-			col_reward_rate: Perquintill,
-			del_max_rate: Perquintill,
-			del_reward_rate: Perquintill,
-		) -> Result<(u32, u32), DispatchError> {
-			// Check validity of new inflation
-			let inflation = InflationInfo::new(
-				blocks_per_year.saturated_into(),
-				col_max_rate,
-				col_reward_rate,
-				del_max_rate,
-				del_reward_rate,
-			);
+		/// Collator candidates stake the rewards for themselves, delegators
+		/// for the collator candidate the rewards were earned with. Setting the
+		/// share to zero disables auto-compounding.
+		///
+		/// The dispatch origin must be a collator candidate or delegator.
+		///
+		/// Emits `AutoCompoundSet`.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(origin: OriginFor<T>, share: Perbill) -> DispatchResult {
+			let acc = ensure_signed(origin)?;
 			ensure!(
-				inflation.is_valid(T::BLOCKS_PER_YEAR.saturated_into()),
-				Error::<T>::InvalidSchedule
+				Self::is_delegator(&acc) || CandidatePool::<T>::contains_key(&acc),
+				Error::<T>::NotStaking
 			);
 
-			// Increment rewards for all collators and delegators due to change of reward
-			// rates
-			let mut num_delegators = 0u32;
-			CandidatePool::<T>::iter().for_each(|(id, state)| {
-				// increment collator rewards
-				Self::do_inc_collator_reward(&id, state.stake);
-				// increment delegator rewards
-				state.delegators.into_iter().for_each(|delegator_state| {
-					Self::do_inc_delegator_reward(&delegator_state.owner, delegator_state.amount, &id);
-					num_delegators = num_delegators.saturating_add(1u32);
-				});
-			});
-
-			// Update inflation
-			InflationConfig::<T>::put(inflation);
-			Self::deposit_event(Event::RoundInflationSet(
-				col_max_rate,
-				col_reward_rate,
-				del_max_rate,
-				del_reward_rate,
-			));
+			if share.is_zero() {
+				AutoCompound::<T>::remove(&acc);
+			} else {
+				AutoCompound::<T>::insert(&acc, share);
+			}
 
-			Ok((CandidatePool::<T>::count(), num_delegators))
+			Self::deposit_event(Event::AutoCompoundSet(acc, share));
+			Ok(())
 		}
 
-		/// Update the top candidates and total amount at stake after mutating
-		/// an active candidate's stake.
+		/// Report an equivocation of a collator candidate.
 		///
-		/// NOTE: It is assumed that the calling context checks whether the
-		/// collator candidate is currently active before calling this function.
-		fn update_top_candidates(
-			candidate: T::AccountId,
-			old_self: BalanceOf<T>,
-			old_delegators: BalanceOf<T>,
-			new_self: BalanceOf<T>,
+		/// Equivocations of parachain block authors are not reported to the
+		/// parachain by the relay chain, hence they are reported by
+		/// governance. The slash is deferred for `SlashDeferDuration` many
+		/// rounds and can be canceled via `cancel_deferred_slash` in the
+		/// meantime. If configured, the candidate is removed from the set of
+		/// top candidates until the end of their suspension.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `OffenceReported` and potentially `CandidateSuspended`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::report_equivocation(
+			T::MaxTopCandidates::get(),
+			T::MaxTopCandidates::get().saturating_mul(T::MaxDelegatorsPerCollator::get())
+		))]
+		pub fn report_equivocation(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			ensure!(
+				CandidatePool::<T>::contains_key(&collator),
+				Error::<T>::CandidateNotFound
+			);
+
+			let (num_collators, num_delegators) = Self::do_report_offence(&collator, Offence::Equivocation);
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::report_equivocation(
+				num_collators,
+				num_delegators,
+			))
+			.into())
+		}
+
+		/// Apply the deferred slash of a collator candidate after waiting at
+		/// least `SlashDeferDuration` rounds since the offence was reported.
+		///
+		/// The slashed share of the stake of the candidate and of each of
+		/// their delegations is withdrawn from the staked funds and handed to
+		/// `Slash`. Rewards are incremented before the stake is reduced.
+		///
+		/// Delegations which fall below `MinDelegatorStake` are removed and
+		/// their remaining stake is unstaked. If the stake of the candidate
+		/// falls below `MinCollatorCandidateStake`, the candidate is removed
+		/// unless this would leave less than `MinRequiredCollators` many.
+		///
+		/// The dispatch origin can be any signed one because the slash was
+		/// already decided when the offence was reported.
+		///
+		/// Emits `Slashed` for the candidate and each slashed delegator.
+		/// Emits `DelegatorLeftCollator` for each removed delegation.
+		/// Emits `CollatorRemoved` if the candidate is removed.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::apply_deferred_slash(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get(),
+		).saturating_add(<T as pallet::Config>::WeightInfo::force_remove_candidate(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get(),
+		)))]
+		pub fn apply_deferred_slash(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			use sp_runtime::PerThing;
+
+			ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let slash = DeferredSlashes::<T>::get(&collator).ok_or(Error::<T>::NoDeferredSlash)?;
+			ensure!(slash.apply_at <= Round::<T>::get().current, Error::<T>::SlashNotDue);
+			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			let num_delegators = state.delegators.len().saturated_into::<u32>();
+
+			// increment rewards with the stake before the slash
+			Self::do_inc_collator_reward(&collator, state.stake);
+
+			let old_self = state.stake;
+			let old_delegators = state.total.saturating_sub(state.stake);
+			let mut slashed = CreditOf::<T>::zero();
+
+			let amount = slash.fraction.mul_floor(state.stake);
+			if !amount.is_zero() {
+				let credit = Self::do_slash(&collator, amount)?;
+				let slashed_amount = credit.peek();
+				state.stake = state.stake.saturating_sub(slashed_amount);
+				state.total = state.total.saturating_sub(slashed_amount);
+				slashed.subsume(credit);
+				Self::deposit_event(Event::Slashed(collator.clone(), collator.clone(), slashed_amount));
+			}
+
+			for stake in state.delegators.clone().into_bounded_vec() {
+				Self::do_inc_delegator_reward(&stake.owner, stake.amount, &collator);
+
+				let amount = slash.fraction.mul_floor(stake.amount);
+				if amount.is_zero() {
+					continue;
+				}
+				let credit = Self::do_slash(&stake.owner, amount)?;
+				let slashed_amount = credit.peek();
+				slashed.subsume(credit);
+				Self::deposit_event(Event::Slashed(collator.clone(), stake.owner.clone(), slashed_amount));
+
+				let remaining = stake.amount.saturating_sub(slashed_amount);
+				if remaining < T::MinDelegatorStake::get() {
+					// remove the delegation and unstake what is left of it
+					state.delegators.remove(&stake);
+					state.total = state.total.saturating_sub(stake.amount);
+					if !remaining.is_zero() {
+						Self::prep_unstake(&stake.owner, remaining, true)?;
+					}
+					Self::remove_delegation(&stake.owner, &collator)?;
+					Self::deposit_event(Event::DelegatorLeftCollator(
+						stake.owner,
+						collator.clone(),
+						remaining,
+						state.total,
+					));
+				} else {
+					DelegatorState::<T>::try_mutate(&stake.owner, |maybe_delegator| -> DispatchResult {
+						let delegator = maybe_delegator.as_mut().ok_or(Error::<T>::DelegatorNotFound)?;
+						delegator
+							.try_decrement(collator.clone(), slashed_amount)
+							.map_err(|_| Error::<T>::DelegationNotFound)?
+							.ok_or(Error::<T>::Underflow)?;
+						Ok(())
+					})?;
+					state.dec_delegator(stake.owner, slashed_amount);
+				}
+			}
+			DeferredSlashes::<T>::remove(&collator);
+			T::Slash::on_unbalanced(slashed);
+
+			// remove the candidate if the stake fell below the minimum
+			let mut candidates = TopCandidates::<T>::get();
+			let top_candidate = Stake {
+				owner: collator.clone(),
+				amount: state.total,
+			};
+			let is_top_candidate = candidates.contains(&top_candidate);
+			if state.stake < T::MinCollatorCandidateStake::get()
+				&& (!is_top_candidate || candidates.len().saturated_into::<u32>() > T::MinRequiredCollators::get())
+			{
+				let total_amount = state.total;
+				Self::remove_candidate(&collator, &state)?;
+
+				let (num_collators, num_top_delegators) = if is_top_candidate {
+					candidates.remove(&top_candidate);
+					TopCandidates::<T>::put(candidates);
+					// update total amount at stake from scratch
+					Self::update_total_stake()
+				} else {
+					(0u32, 0u32)
+				};
+
+				Self::deposit_event(Event::CollatorRemoved(collator, total_amount));
+				return Ok(Some(
+					<T as pallet::Config>::WeightInfo::apply_deferred_slash(0, num_delegators).saturating_add(
+						<T as pallet::Config>::WeightInfo::force_remove_candidate(num_collators, num_top_delegators),
+					),
+				)
+				.into());
+			}
+
+			let n = if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_self,
+					old_delegators,
+					state.stake,
+					// safe because total >= stake
+					state.total - state.stake,
+				)
+			} else {
+				0u32
+			};
+			CandidatePool::<T>::insert(&collator, state);
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::apply_deferred_slash(
+				n,
+				num_delegators,
+			))
+			.into())
+		}
+
+		/// Cancel the deferred slash of a collator candidate.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `DeferredSlashCanceled`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_deferred_slash())]
+		pub fn cancel_deferred_slash(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			ensure!(
+				DeferredSlashes::<T>::take(&collator).is_some(),
+				Error::<T>::NoDeferredSlash
+			);
+
+			Self::deposit_event(Event::DeferredSlashCanceled(collator));
+			Ok(())
+		}
+
+		/// Rejoin the set of collator candidates after the suspension because
+		/// of an offence has ended. On success, adds back the candidate to the
+		/// TopCandidates and updates the collators.
+		///
+		/// The dispatch origin must be a suspended collator candidate.
+		///
+		/// Emits `CandidateResumed`.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resume_candidate(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get(),
+		))]
+		pub fn resume_candidate(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let candidate = ensure_signed(origin)?;
+			let mut state = CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(state.is_suspended(), Error::<T>::NotSuspended);
+			ensure!(state.can_resume(Round::<T>::get().current), Error::<T>::CannotResumeYet);
+
+			state.resume();
+
+			let n = Self::update_top_candidates(
+				candidate.clone(),
+				state.stake,
+				// safe because total >= stake
+				state.total - state.stake,
+				state.stake,
+				state.total - state.stake,
+			);
+
+			CandidatePool::<T>::insert(&candidate, state);
+			MissedRounds::<T>::remove(&candidate);
+
+			Self::deposit_event(Event::CandidateResumed(candidate));
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::resume_candidate(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Set the parameters for slashing collators.
+		///
+		/// The slashed shares must be below 100% to keep the offender in the
+		/// set of candidates.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `SlashingConfigSet`.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_slashing_config())]
+		pub fn set_slashing_config(origin: OriginFor<T>, config: SlashingConfiguration) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				config.downtime_slash < Perbill::one() && config.equivocation_slash < Perbill::one(),
+				Error::<T>::InvalidSlashingConfig
+			);
+
+			SlashingConfig::<T>::put(config.clone());
+
+			Self::deposit_event(Event::SlashingConfigSet(config));
+			Ok(())
+		}
+
+		/// Create a staking pool which delegates to a collator candidate on
+		/// behalf of its members.
+		///
+		/// The initial stake is transferred from the origin to the account of
+		/// the pool, which joins the set of delegators. Hence, it must be
+		/// above `MinDelegatorStake`. The origin becomes the first member as
+		/// well as the owner and operator of the pool.
+		///
+		/// Emits `PoolCreated`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_pool(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			ensure!(amount >= T::MinDelegatorStake::get(), Error::<T>::DelegationBelowMin);
+
+			let pool_id = NextPoolId::<T>::get();
+			let pool_account = Self::pool_account(pool_id);
+			Self::do_transfer(&owner, &pool_account, amount, Preservation::Preserve)?;
+			let delegation_weight = Self::do_join_delegators(&pool_account, &collator, amount)?;
+
+			Pools::<T>::insert(
+				pool_id,
+				StakingPool {
+					roles: PoolRoles {
+						owner: owner.clone(),
+						operator: owner.clone(),
+					},
+					collator: collator.clone(),
+					commission: Perbill::zero(),
+					state: PoolState::Open,
+					points: amount,
+					unbonding: BalanceOf::<T>::zero(),
+					reward_per_point: FixedU128::zero(),
+					member_count: 1,
+				},
+			);
+			PoolMembers::<T>::insert(
+				pool_id,
+				&owner,
+				PoolMember {
+					points: amount,
+					last_reward_per_point: FixedU128::zero(),
+				},
+			);
+			NextPoolId::<T>::put(pool_id.saturating_add(1));
+
+			Self::deposit_event(Event::PoolCreated(pool_id, owner, collator, amount));
+			// the weight of the pool accounting on top of the actual weight of the delegation
+			let weight = <T as pallet::Config>::WeightInfo::create_pool(0, 0).saturating_add(delegation_weight);
+			Ok(Some(weight).into())
+		}
+
+		/// Add funds to a staking pool in exchange for points.
+		///
+		/// The funds are transferred to the account of the pool and staked
+		/// for its delegation. The points entitle to a pro-rata share of the
+		/// stake and rewards of the pool. Pending rewards of an existing
+		/// member are settled beforehand.
+		///
+		/// The amount must be at least `MinPoolJoin` and the pool must be
+		/// open.
+		///
+		/// Emits `PoolJoined`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::join_pool(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn join_pool(origin: OriginFor<T>, pool_id: PoolId, amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			let member = ensure_signed(origin)?;
+			ensure!(amount >= T::MinPoolJoin::get(), Error::<T>::PoolJoinBelowMin);
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.state == PoolState::Open, Error::<T>::PoolBlocked);
+
+			let pool_account = Self::pool_account(pool_id);
+			let bonded = DelegatorState::<T>::get(&pool_account)
+				.ok_or(Error::<T>::PoolNotDelegating)?
+				.total;
+
+			Self::do_harvest_pool(&pool_account, &mut pool);
+			let mut pool_member = PoolMembers::<T>::get(pool_id, &member).unwrap_or_else(|| {
+				pool.member_count.saturating_inc();
+				PoolMember {
+					points: BalanceOf::<T>::zero(),
+					last_reward_per_point: pool.reward_per_point,
+				}
+			});
+			Self::do_settle_pool_rewards(&pool, &member, &mut pool_member);
+
+			let points = Self::balance_to_points(amount, pool.points, bonded);
+			ensure!(!points.is_zero(), Error::<T>::PoolJoinBelowMin);
+
+			Self::do_transfer(&member, &pool_account, amount, Preservation::Preserve)?;
+			let delegation_weight = Self::do_delegator_stake_more(&pool_account, &pool.collator, amount)?;
+
+			pool.points = pool.points.saturating_add(points);
+			pool_member.points = pool_member.points.saturating_add(points);
+			Pools::<T>::insert(pool_id, pool);
+			PoolMembers::<T>::insert(pool_id, &member, pool_member);
+
+			Self::deposit_event(Event::PoolJoined(pool_id, member, amount, points));
+			// the weight of the pool accounting on top of the actual weight of the delegation
+			let weight = <T as pallet::Config>::WeightInfo::join_pool(0, 0).saturating_add(delegation_weight);
+			Ok(Some(weight).into())
+		}
+
+		/// Add the pending rewards of the origin in a staking pool to their
+		/// rewards, which can then be claimed via `claim_rewards`.
+		///
+		/// Before, the rewards of the pool delegation are incremented and the
+		/// commission of the pool operator is deducted.
+		///
+		/// Emits `PoolRewardsClaimed`.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_pool_rewards())]
+		pub fn claim_pool_rewards(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let member = ensure_signed(origin)?;
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let mut pool_member = PoolMembers::<T>::get(pool_id, &member).ok_or(Error::<T>::PoolMemberNotFound)?;
+
+			Self::do_harvest_pool(&Self::pool_account(pool_id), &mut pool);
+			let rewards = Self::do_settle_pool_rewards(&pool, &member, &mut pool_member);
+			ensure!(!rewards.is_zero(), Error::<T>::RewardsNotFound);
+
+			Pools::<T>::insert(pool_id, pool);
+			PoolMembers::<T>::insert(pool_id, &member, pool_member);
+
+			Self::deposit_event(Event::PoolRewardsClaimed(pool_id, member, rewards));
+			Ok(())
+		}
+
+		/// Redeem points of a staking pool for the pro-rata share of its
+		/// stake.
+		///
+		/// The share is unstaked from the pool delegation and can be withdrawn
+		/// via `withdraw_from_pool` at the end of the period of
+		/// `StakeDuration` blocks following the next `StakeDuration` blocks.
+		/// Batching the unbonds per period keeps the number of unstaking
+		/// requests of the pool account bounded. Pending rewards are settled
+		/// beforehand. If all points of the pool are redeemed or the remaining
+		/// stake of the pool would drop below `MinDelegatorStake`, the pool
+		/// leaves the set of delegators and the remaining points are backed by
+		/// the unstaking funds of the pool.
+		///
+		/// Emits `PoolUnbonded`.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unbond_from_pool(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn unbond_from_pool(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			points: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let member = ensure_signed(origin)?;
+			ensure!(!points.is_zero(), Error::<T>::ValStakeZero);
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let mut pool_member = PoolMembers::<T>::get(pool_id, &member).ok_or(Error::<T>::PoolMemberNotFound)?;
+			ensure!(points <= pool_member.points, Error::<T>::InsufficientPoolPoints);
+
+			let pool_account = Self::pool_account(pool_id);
+			Self::do_harvest_pool(&pool_account, &mut pool);
+			Self::do_settle_pool_rewards(&pool, &member, &mut pool_member);
+
+			let bonded = Self::pool_bonded(&pool_account, &pool);
+			let amount = Self::points_to_balance(points, pool.points, bonded);
+			pool.points = pool.points.saturating_sub(points);
+			pool_member.points = pool_member.points.saturating_sub(points);
+
+			let unstake_delay = Self::pool_unstake_delay();
+			let mut delegation_weight = Weight::zero();
+			// if the delegation was removed, the funds are already unstaking
+			if DelegatorState::<T>::contains_key(&pool_account) {
+				// free the unstaking requests of the pool which can be unlocked already
+				let now = frame_system::Pallet::<T>::block_number();
+				if Unstaking::<T>::get(&pool_account)
+					.keys()
+					.next()
+					.is_some_and(|block_number| *block_number <= now)
+				{
+					Self::do_unlock(&pool_account)?;
+				}
+
+				if pool.points.is_zero() || bonded.saturating_sub(amount) < T::MinDelegatorStake::get() {
+					delegation_weight = Self::do_leave_delegators_with_delay(&pool_account, unstake_delay)?;
+				} else {
+					ensure!(!amount.is_zero(), Error::<T>::InsufficientPoolPoints);
+					delegation_weight =
+						Self::do_delegator_stake_less_with_delay(&pool_account, &pool.collator, amount, unstake_delay)?;
+				}
+			}
+
+			if !amount.is_zero() {
+				let unlock_block = frame_system::Pallet::<T>::block_number().saturating_add(unstake_delay);
+				PoolUnbonding::<T>::try_mutate(pool_id, &member, |unbonding| -> DispatchResult {
+					let total = amount.saturating_add(unbonding.get(&unlock_block).copied().unwrap_or_default());
+					unbonding
+						.try_insert(unlock_block, total)
+						.map_err(|_| Error::<T>::NoMoreUnstaking)?;
+					Ok(())
+				})?;
+				pool.unbonding = pool.unbonding.saturating_add(amount);
+			}
+
+			if pool_member.points.is_zero() {
+				pool.member_count.saturating_dec();
+				PoolMembers::<T>::remove(pool_id, &member);
+			} else {
+				PoolMembers::<T>::insert(pool_id, &member, pool_member);
+			}
+			Pools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::PoolUnbonded(pool_id, member, points, amount));
+			// the weight of the pool accounting on top of the actual weight of the delegation
+			let weight = <T as pallet::Config>::WeightInfo::unbond_from_pool(0, 0).saturating_add(delegation_weight);
+			Ok(Some(weight).into())
+		}
+
+		/// Withdraw the funds of the origin which were unbonded from a
+		/// staking pool at least `StakeDuration` blocks ago.
+		///
+		/// Unlocks the unstaked funds of the pool account beforehand, which
+		/// can also be done by anyone via `unlock_unstaked`. Once all points
+		/// have been redeemed and all funds have been withdrawn, the pool is
+		/// removed and the remaining dust is transferred to the origin.
+		///
+		/// Emits `PoolWithdrawn`.
+		/// Emits `PoolDestroyed` if the pool has been removed.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_from_pool(
+			T::MaxUnstakeRequests::get().saturated_into::<u32>()
+		))]
+		pub fn withdraw_from_pool(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
+			let member = ensure_signed(origin)?;
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let mut unbonding = PoolUnbonding::<T>::get(pool_id, &member);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut amount = BalanceOf::<T>::zero();
+			unbonding.retain(|block_number, unbonded| {
+				if *block_number <= now {
+					amount = amount.saturating_add(*unbonded);
+					false
+				} else {
+					true
+				}
+			});
+			ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+			let pool_account = Self::pool_account(pool_id);
+			if !Unstaking::<T>::get(&pool_account).is_empty() {
+				Self::do_unlock(&pool_account)?;
+			}
+
+			pool.unbonding = pool.unbonding.saturating_sub(amount);
+			let destroy = pool.points.is_zero() && pool.unbonding.is_zero();
+			if destroy {
+				amount = <T::Currency as Inspect<AccountIdOf<T>>>::reducible_balance(
+					&pool_account,
+					Preservation::Expendable,
+					Fortitude::Polite,
+				);
+				Self::do_transfer(&pool_account, &member, amount, Preservation::Expendable)?;
+			} else {
+				Self::do_transfer(&pool_account, &member, amount, Preservation::Preserve)?;
+			}
+
+			if unbonding.is_empty() {
+				PoolUnbonding::<T>::remove(pool_id, &member);
+			} else {
+				PoolUnbonding::<T>::insert(pool_id, &member, unbonding);
+			}
+
+			Self::deposit_event(Event::PoolWithdrawn(pool_id, member, amount));
+			if destroy {
+				Pools::<T>::remove(pool_id);
+				Self::deposit_event(Event::PoolDestroyed(pool_id));
+			} else {
+				Pools::<T>::insert(pool_id, pool);
+			}
+			Ok(())
+		}
+
+		/// Set the owner and operator of a staking pool.
+		///
+		/// The dispatch origin must be the owner of the pool.
+		///
+		/// Emits `PoolRolesSet`.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pool_roles())]
+		pub fn set_pool_roles(origin: OriginFor<T>, pool_id: PoolId, roles: PoolRoles<T::AccountId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(who == pool.roles.owner, Error::<T>::NotPoolManager);
+
+			// the commission accrued so far belongs to the previous operator
+			Self::do_harvest_pool(&Self::pool_account(pool_id), &mut pool);
+			pool.roles = roles.clone();
+			Pools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::PoolRolesSet(pool_id, roles));
+			Ok(())
+		}
+
+		/// Set the share of the rewards of a staking pool which is paid to its
+		/// operator. The commission is capped by `MaxCommission`.
+		///
+		/// The dispatch origin must be the operator of the pool.
+		///
+		/// Emits `PoolCommissionSet`.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pool_commission())]
+		pub fn set_pool_commission(origin: OriginFor<T>, pool_id: PoolId, commission: Perbill) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(who == pool.roles.operator, Error::<T>::NotPoolManager);
+			ensure!(commission <= MaxCommission::<T>::get(), Error::<T>::CommissionAboveMax);
+
+			// the rewards accrued so far are subject to the previous commission
+			Self::do_harvest_pool(&Self::pool_account(pool_id), &mut pool);
+			pool.commission = commission;
+			Pools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::PoolCommissionSet(pool_id, commission));
+			Ok(())
+		}
+
+		/// Set whether new funds can be added to a staking pool.
+		///
+		/// The dispatch origin must be the operator of the pool.
+		///
+		/// Emits `PoolStateSet`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_pool_state())]
+		pub fn set_pool_state(origin: OriginFor<T>, pool_id: PoolId, state: PoolState) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+				ensure!(who == pool.roles.operator, Error::<T>::NotPoolManager);
+				pool.state = state;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PoolStateSet(pool_id, state));
+			Ok(())
+		}
+
+		/// Link a collator candidate to the identity their account is
+		/// currently linked to by the identity provider, e.g., their DID.
+		///
+		/// Replaces any identity the candidate was linked to before. At most
+		/// `MaxCandidatesPerIdentity` candidates can be linked to the same
+		/// identity.
+		///
+		/// The dispatch origin must be a collator candidate.
+		///
+		/// Emits `IdentityLinked`.
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::link_identity())]
+		pub fn link_identity(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(CandidatePool::<T>::contains_key(&who), Error::<T>::CandidateNotFound);
+			let identity = T::IdentityProvider::identity_of(&who).ok_or(Error::<T>::IdentityNotFound)?;
+			ensure!(
+				Self::can_link_identity(&who, &identity),
+				Error::<T>::TooManyIdentityCandidates
+			);
+
+			Self::do_link_identity(&who, identity);
+			Ok(())
+		}
+
+		/// Remove the link of a collator candidate to an identity.
+		///
+		/// Fails if identities are required.
+		///
+		/// The dispatch origin must be a collator candidate which is linked to
+		/// an identity.
+		///
+		/// Emits `IdentityUnlinked`.
+		#[pallet::call_index(41)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unlink_identity())]
+		pub fn unlink_identity(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!IdentityRequired::<T>::get(), Error::<T>::IdentityRequired);
+			ensure!(Self::do_unlink_identity(&who).is_some(), Error::<T>::IdentityNotLinked);
+
+			Self::deposit_event(Event::IdentityUnlinked(who));
+			Ok(())
+		}
+
+		/// Set whether collator candidates have to be linked to an identity.
+		///
+		/// If identities are required, accounts which are not linked to an
+		/// identity cannot join the set of candidates and candidates whose
+		/// identity is missing or outdated are not selected as collators
+		/// anymore.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `IdentityRequirementSet`.
+		#[pallet::call_index(42)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_identity_requirement())]
+		pub fn set_identity_requirement(origin: OriginFor<T>, required: bool) -> DispatchResult {
+			ensure_root(origin)?;
+
+			IdentityRequired::<T>::put(required);
+
+			Self::deposit_event(Event::IdentityRequirementSet(required));
+			Ok(())
+		}
+
+		/// Revoke a single delegation of the origin and release the staked
+		/// funds immediately.
+		///
+		/// This is only possible if the collator candidate was neither
+		/// selected in any round overlapping with the last `StakeDuration`
+		/// blocks nor for the next session, i.e., the delegation did not earn
+		/// any rewards. Moreover, the origin must not have restaked funds
+		/// from their pending unstaking requests which would still be locked.
+		///
+		/// If this was the last delegation of the origin, they leave the set
+		/// of delegators.
+		///
+		/// Emits `DelegatorLeftCollator` and `FastUnstaked`.
+		/// Emits `DelegatorLeft` if no delegations are left.
+		#[pallet::call_index(43)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::fast_unstake(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn fast_unstake(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let collator_account = T::Lookup::lookup(collator)?;
+			let mut delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
+			ensure!(
+				Self::can_fast_unstake(&acc, &collator_account),
+				Error::<T>::FastUnstakeNotAllowed
+			);
+
+			let amount = delegator
+				.rm_delegation(collator_account.clone())
+				.ok_or(Error::<T>::DelegationNotFound)?;
+			Self::delegator_leaves_collator(acc.clone(), collator_account.clone(), BlockNumberFor::<T>::zero())?;
+			RestakedUntil::<T>::remove(&acc);
+			Self::do_unlock(&acc)?;
+
+			Self::deposit_event(Event::FastUnstaked(acc.clone(), collator_account, amount));
+			if delegator.delegations.is_empty() {
+				DelegatorState::<T>::remove(&acc);
+				AutoCompound::<T>::remove(&acc);
+				Self::deposit_event(Event::DelegatorLeft(acc, amount));
+			} else {
+				DelegatorState::<T>::insert(&acc, delegator);
+			}
+
+			Ok(Some(<T as pallet::Config>::WeightInfo::fast_unstake(
+				1,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Replace the schedule of upcoming inflation configurations.
+		///
+		/// At the start of every round, the latest step whose start block has
+		/// been reached is queued to replace the current inflation
+		/// configuration. Earlier due steps are skipped. An empty schedule
+		/// removes all upcoming steps, including a queued step which has not
+		/// been applied yet.
+		///
+		/// NOTE: A queued step does not take effect at the round boundary
+		/// itself. It is applied as if it was set via `set_inflation` once the
+		/// rewards of all collator candidates and their delegators have been
+		/// incremented. As this is spread over the following blocks with
+		/// `MaxInflationUpdatesPerBlock` candidates per block, the step takes
+		/// effect up to `ceil(candidates / MaxInflationUpdatesPerBlock)` blocks
+		/// after the start of the round. Blocks authored in the meantime by
+		/// candidates whose rewards were already incremented are credited at
+		/// the rates of the step.
+		///
+		/// The steps must start in strictly ascending order after the current
+		/// block and each of them must be a valid inflation configuration.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `InflationScheduleSet`.
+		#[pallet::call_index(44)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_inflation_schedule(schedule.len().saturated_into()))]
+		pub fn set_inflation_schedule(origin: OriginFor<T>, schedule: InflationScheduleOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				schedule.first().map_or(true, |step| step.start > now)
+					&& crate::inflation::is_valid_schedule(&schedule, T::BLOCKS_PER_YEAR.saturated_into()),
+				Error::<T>::InvalidSchedule
+			);
+
+			let len = schedule.len().saturated_into();
+			if schedule.is_empty() {
+				PendingInflationStep::<T>::kill();
+			}
+			InflationSchedule::<T>::put(schedule);
+			Self::deposit_event(Event::InflationScheduleSet(len));
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Returns the number of rounds before the current one which can
+		/// overlap with the last `StakeDuration` blocks.
+		///
+		/// Since each round lasts at least `MinBlocksPerRound` blocks, this
+		/// bounds the number of rounds checked by `fast_unstake`.
+		pub fn fast_unstake_rounds() -> u32 {
+			let min_round_length = T::MinBlocksPerRound::get().max(BlockNumberFor::<T>::one());
+			T::StakeDuration::get()
+				.saturating_add(min_round_length)
+				.saturating_sub(BlockNumberFor::<T>::one())
+				.checked_div(&min_round_length)
+				.unwrap_or_default()
+				.saturated_into()
+		}
+
+		/// Returns whether the delegation of a delegator to a collator
+		/// candidate can be revoked via `fast_unstake`.
+		///
+		/// This requires the collator candidate to not be selected in any
+		/// round overlapping with the last `StakeDuration` blocks or for the
+		/// next session and the delegator to not have restaked funds which
+		/// would still be unstaking.
+		pub fn can_fast_unstake(delegator: &T::AccountId, collator: &T::AccountId) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			if RestakedUntil::<T>::get(delegator).is_some_and(|until| until > now) {
+				return false;
+			}
+
+			let rounds = Self::fast_unstake_rounds();
+			// without the complete history, we cannot rule out that the collator was
+			// selected
+			if rounds >= T::MaxRoundHistory::get() {
+				return false;
+			}
+
+			let current = pallet_session::Pallet::<T>::current_index();
+			let was_selected = (current.saturating_sub(rounds)..=current)
+				.any(|round| CollatorHistory::<T>::contains_key(round, collator));
+			let is_queued = SessionCollators::<T>::get(current.saturating_add(1))
+				.is_some_and(|collators| collators.contains(collator));
+			!was_selected && !is_queued
+		}
+
+		/// Returns the identity of a collator candidate if the candidate is
+		/// still linked to it by the identity provider.
+		pub fn verified_identity(collator: &T::AccountId) -> Option<IdentityOf<T>> {
+			CandidateIdentity::<T>::get(collator)
+				.filter(|identity| T::IdentityProvider::identity_of(collator).as_ref() == Some(identity))
+		}
+
+		/// Returns whether any collator candidate is linked to the identity.
+		pub fn is_identity_linked(identity: &IdentityOf<T>) -> bool {
+			IdentityCandidateCount::<T>::contains_key(identity)
+		}
+
+		/// Removes the links of all collator candidates to the identity, e.g.
+		/// when the identity is deleted.
+		///
+		/// As at most `MaxCandidatesPerIdentity` candidates are linked to the
+		/// identity, this is bounded by as many `unlink_identity` calls.
+		///
+		/// Emits `IdentityUnlinked` for every unlinked candidate.
+		pub fn unlink_identity_candidates(identity: &IdentityOf<T>) {
+			for collator in IdentityCandidates::<T>::drain_prefix(identity).map(|(collator, _)| collator) {
+				CandidateIdentity::<T>::remove(&collator);
+				Self::deposit_event(Event::IdentityUnlinked(collator));
+			}
+			IdentityCandidateCount::<T>::remove(identity);
+		}
+
+		/// Returns whether a collator candidate can be linked to the identity
+		/// without exceeding `MaxCandidatesPerIdentity`.
+		fn can_link_identity(collator: &T::AccountId, identity: &IdentityOf<T>) -> bool {
+			CandidateIdentity::<T>::get(collator).as_ref() == Some(identity)
+				|| IdentityCandidateCount::<T>::get(identity) < T::MaxCandidatesPerIdentity::get()
+		}
+
+		/// Returns whether a collator candidate can be selected with respect
+		/// to the identity requirement.
+		fn meets_identity_requirement(collator: &T::AccountId) -> bool {
+			!IdentityRequired::<T>::get() || Self::verified_identity(collator).is_some()
+		}
+
+		/// Links a collator candidate to an identity and removes the link to
+		/// the previous one.
+		///
+		/// Emits `IdentityLinked`.
+		fn do_link_identity(collator: &T::AccountId, identity: IdentityOf<T>) {
+			Self::do_unlink_identity(collator);
+			IdentityCandidates::<T>::insert(&identity, collator, ());
+			IdentityCandidateCount::<T>::mutate(&identity, |count| *count = count.saturating_add(1));
+			CandidateIdentity::<T>::insert(collator, &identity);
+
+			Self::deposit_event(Event::IdentityLinked(collator.clone(), identity));
+		}
+
+		/// Removes the link of a collator candidate to an identity and returns
+		/// the identity, if any.
+		fn do_unlink_identity(collator: &T::AccountId) -> Option<IdentityOf<T>> {
+			let identity = CandidateIdentity::<T>::take(collator)?;
+			IdentityCandidates::<T>::remove(&identity, collator);
+			IdentityCandidateCount::<T>::mutate_exists(&identity, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+			Some(identity)
+		}
+
+		/// Returns the commission a collator candidate charges on the rewards
+		/// of their delegators, capped by `MaxCommission`.
+		pub fn commission_of(collator: &T::AccountId) -> Perbill {
+			CandidateCommission::<T>::get(collator).min(MaxCommission::<T>::get())
+		}
+
+		/// Check whether an account is currently delegating.
+		pub fn is_delegator(acc: &T::AccountId) -> bool {
+			DelegatorState::<T>::get(acc).is_some()
+		}
+
+		/// Check whether an account is currently a collator candidate and
+		/// whether their state is CollatorStatus::Active.
+		///
+		/// Returns Some(is_active) if the account is a candidate, else None.
+		pub fn is_active_candidate(acc: &T::AccountId) -> Option<bool> {
+			if let Some(state) = CandidatePool::<T>::get(acc) {
+				Some(state.status == CandidateStatus::Active)
+			} else {
+				None
+			}
+		}
+		/// Set the annual inflation rate to derive per-round inflation.
+		///
+		/// The inflation details are considered valid if the annual reward rate
+		/// is approximately the per-block reward rate multiplied by the
+		/// estimated* total number of blocks per year.
+		///
+		/// The estimated average block time is twelve seconds.
+		///
+		/// NOTE: Iterates over CandidatePool and for each candidate over their
+		/// delegators to update their rewards before the reward rates change.
+		/// Needs to be improved when scaling up `MaxTopCandidates`.
+		///
+		/// Emits `RoundInflationSet`.
+		fn do_set_inflation(
+			blocks_per_year: BlockNumberFor<T>,
+			col_max_rate: Perquintill,
+			col_reward_rate: Perquintill,
+			del_max_rate: Perquintill,
+			del_reward_rate: Perquintill,
+		) -> Result<(u32, u32), DispatchError> {
+			// Check validity of new inflation
+			let inflation = InflationInfo::new(
+				blocks_per_year.saturated_into(),
+				col_max_rate,
+				col_reward_rate,
+				del_max_rate,
+				del_reward_rate,
+			);
+			ensure!(
+				inflation.is_valid(T::BLOCKS_PER_YEAR.saturated_into()),
+				Error::<T>::InvalidSchedule
+			);
+
+			// Increment rewards for all collators and delegators due to change of reward
+			// rates
+			let num_delegators = CandidatePool::<T>::iter().fold(0u32, |num_delegators, (id, state)| {
+				num_delegators.saturating_add(Self::do_inc_candidate_rewards(&id, state))
+			});
+
+			// Update inflation
+			InflationConfig::<T>::put(inflation);
+			Self::deposit_event(Event::RoundInflationSet(
+				col_max_rate,
+				col_reward_rate,
+				del_max_rate,
+				del_reward_rate,
+			));
+
+			Ok((CandidatePool::<T>::count(), num_delegators))
+		}
+
+		/// Increment the rewards of a collator candidate and all of their
+		/// delegators.
+		///
+		/// Returns the number of delegators.
+		fn do_inc_candidate_rewards(id: &T::AccountId, state: CandidateOf<T, T::MaxDelegatorsPerCollator>) -> u32 {
+			Self::do_inc_collator_reward(id, state.stake);
+			for delegator_state in state.delegators.iter() {
+				Self::do_inc_delegator_reward(&delegator_state.owner, delegator_state.amount, id);
+			}
+			state.delegators.len().saturated_into()
+		}
+
+		/// Queue the latest step of the inflation schedule which is due at
+		/// block `now` and remove all due steps from the schedule.
+		///
+		/// A step which is still pending is replaced. The step is applied by
+		/// `process_inflation_step` over the following blocks.
+		///
+		/// Called at the start of every round.
+		fn apply_inflation_schedule(now: BlockNumberFor<T>, round: SessionIndex) -> Weight {
+			let mut schedule = InflationSchedule::<T>::get();
+			let num_due = schedule.iter().take_while(|step| step.start <= now).count();
+			let Some(step) = num_due.checked_sub(1).and_then(|idx| schedule.get(idx)).cloned() else {
+				return T::DbWeight::get().reads(1);
+			};
+			schedule.retain(|step| step.start > now);
+			InflationSchedule::<T>::put(schedule);
+			PendingInflationStep::<T>::put((step, round, None::<T::AccountId>));
+
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		/// Increment the rewards of at most `MaxInflationUpdatesPerBlock`
+		/// collator candidates and their delegators, continuing after the
+		/// candidate stored in `PendingInflationStep`. Once all candidates have
+		/// been processed, the pending step of the inflation schedule is
+		/// applied.
+		///
+		/// NOTE: Blocks authored by already processed candidates before the
+		/// step is applied are credited at the rates of the step.
+		///
+		/// Returns the consumed weight.
+		///
+		/// Emits `RoundInflationSet` and `InflationStepApplied` once the step is
+		/// applied.
+		fn process_inflation_step() -> Weight {
+			let Some((step, round, cursor)) = PendingInflationStep::<T>::get() else {
+				return T::DbWeight::get().reads(1);
+			};
+
+			let mut candidates = match cursor {
+				Some(last) => CandidatePool::<T>::iter_from(CandidatePool::<T>::hashed_key_for(last)),
+				None => CandidatePool::<T>::iter(),
+			};
+			let mut num_candidates = 0u32;
+			let mut num_delegators = 0u32;
+			let mut last = None;
+			for (id, state) in candidates
+				.by_ref()
+				.take(T::MaxInflationUpdatesPerBlock::get().saturated_into())
+			{
+				num_delegators = num_delegators.saturating_add(Self::do_inc_candidate_rewards(&id, state));
+				num_candidates = num_candidates.saturating_add(1);
+				last = Some(id);
+			}
+			let done = candidates.next().is_none();
+			let weight = <T as Config>::WeightInfo::set_inflation(num_candidates, num_delegators)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+
+			match last {
+				Some(last) if !done => PendingInflationStep::<T>::put((step, round, Some(last))),
+				_ => {
+					PendingInflationStep::<T>::kill();
+					// The schedule is validated when it is set, so the step is valid.
+					InflationConfig::<T>::put(step.to_inflation_info(T::BLOCKS_PER_YEAR.saturated_into()));
+					Self::deposit_event(Event::RoundInflationSet(
+						step.collator_max_rate,
+						step.collator_reward_rate,
+						step.delegator_max_rate,
+						step.delegator_reward_rate,
+					));
+					Self::deposit_event(Event::InflationStepApplied(round, step.start));
+				}
+			}
+			weight
+		}
+
+		/// Update the top candidates and total amount at stake after mutating
+		/// an active candidate's stake.
+		///
+		/// NOTE: It is assumed that the calling context checks whether the
+		/// collator candidate is currently active before calling this function.
+		fn update_top_candidates(
+			candidate: T::AccountId,
+			old_self: BalanceOf<T>,
+			old_delegators: BalanceOf<T>,
+			new_self: BalanceOf<T>,
 			new_delegators: BalanceOf<T>,
 		) -> u32 {
 			let mut top_candidates = TopCandidates::<T>::get();
@@ -1899,156 +3337,613 @@ pub mod pallet {
 				owner: candidate.clone(),
 				amount: old_self.saturating_add(old_delegators),
 			};
-			let new_stake = Stake {
-				owner: candidate.clone(),
-				amount: new_self.saturating_add(new_delegators),
+			let new_stake = Stake {
+				owner: candidate.clone(),
+				amount: new_self.saturating_add(new_delegators),
+			};
+
+			// update TopCandidates set
+			let maybe_top_candidate_update = if let Ok(i) = top_candidates.linear_search(&old_stake) {
+				// case 1: candidate is member of TopCandidates with old stake
+				top_candidates.mutate(|vec| {
+					if let Some(stake) = vec.get_mut(i) {
+						stake.amount = new_stake.amount;
+					}
+				});
+				Some((Some(i), top_candidates))
+			} else if top_candidates.try_insert_replace(new_stake.clone()).is_ok() {
+				// case 2: candidate ascends into TopCandidates with new stake
+				// and might replace another candidate if TopCandidates is full
+				Self::deposit_event(Event::EnteredTopCandidates(candidate));
+				Some((None, top_candidates))
+			} else {
+				// case 3: candidate neither was nor will be member of TopCandidates
+				None
+			};
+
+			// update storage for TotalCollatorStake and TopCandidates
+			if let Some((maybe_old_idx, new_top_candidates)) = maybe_top_candidate_update {
+				let max_selected_candidates = MaxSelectedCandidates::<T>::get().saturated_into::<usize>();
+				let was_collating = maybe_old_idx.map(|i| i < max_selected_candidates).unwrap_or(false);
+				let is_collating = new_top_candidates
+					.linear_search(&new_stake)
+					.map(|i| i < max_selected_candidates)
+					.unwrap_or(false);
+
+				// update TopCollatorStake storage iff candidate was or will be a collator
+				match (was_collating, is_collating) {
+					(true, true) => {
+						Self::update_total_stake_by(new_self, new_delegators, old_self, old_delegators);
+					}
+					(true, false) => {
+						// candidate left the collator set because they staked less and have been
+						// replaced by the next candidate in the queue at position
+						// min(max_selected_candidates, top_candidates) - 1 in TopCandidates
+						let new_col_idx = max_selected_candidates.min(new_top_candidates.len()).saturating_sub(1);
+
+						// get displacer
+						let (add_collators, add_delegators) =
+							Self::get_top_candidate_stake_at(&new_top_candidates, new_col_idx)
+								// shouldn't be possible to fail, but we handle it gracefully
+								.unwrap_or((new_self, new_delegators));
+						Self::update_total_stake_by(add_collators, add_delegators, old_self, old_delegators);
+					}
+					(false, true) => {
+						// candidate pushed out the least staked collator which is now at position
+						let (drop_self, drop_delegators) = match max_selected_candidates.cmp(&new_top_candidates.len())
+						{
+							// top candidates are not full
+							Ordering::Greater => (BalanceOf::<T>::zero(), BalanceOf::<T>::zero()),
+							// top candidates are full. the collator with the lowest stake is at index old_col_idx
+							_ => {
+								// we can unwrap here without problems, since we compared
+								// [max_selected_candidates] with [top_candidates] length, but lets be
+								// safe.
+								Self::get_top_candidate_stake_at(&new_top_candidates, max_selected_candidates)
+									.unwrap_or((BalanceOf::<T>::zero(), BalanceOf::<T>::zero()))
+							}
+						};
+
+						// get amount to subtract from TotalCollatorStake
+						Self::update_total_stake_by(new_self, new_delegators, drop_self, drop_delegators);
+					}
+					_ => {}
+				}
+
+				// update TopCandidates storage
+				TopCandidates::<T>::put(new_top_candidates);
+			}
+
+			num_top_candidates
+		}
+
+		/// Retrieve the staked amounts (self, sum of delegators) of member of
+		/// [TopCandidates] at the given index, if it exists.
+		fn get_top_candidate_stake_at(
+			top_candidates: &OrderedSet<StakeOf<T>, T::MaxTopCandidates>,
+			index: usize,
+		) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+			top_candidates
+				.get(index)
+				.and_then(|stake| CandidatePool::<T>::get(&stake.owner))
+				// SAFETY: the total is always more than the stake
+				.map(|state| (state.stake, state.total - state.stake))
+		}
+
+		/// Mutate the [TotalCollatorStake] by both incrementing and decreasing
+		/// it by the provided values.
+		fn update_total_stake_by(
+			add_collators: BalanceOf<T>,
+			add_delegators: BalanceOf<T>,
+			sub_collators: BalanceOf<T>,
+			sub_delegators: BalanceOf<T>,
+		) {
+			TotalCollatorStake::<T>::mutate(|total| {
+				total.collators = total
+					.collators
+					.saturating_sub(sub_collators)
+					.saturating_add(add_collators);
+				total.delegators = total
+					.delegators
+					.saturating_sub(sub_delegators)
+					.saturating_add(add_delegators);
+			});
+		}
+
+		/// Iterate over the top `MaxSelectedCandidates` many collators in terms
+		/// of cumulated stake (self + from delegators) from the [TopCandidates]
+		/// and recalculate the [TotalCollatorStake] from scratch.
+		///
+		/// NOTE: Should only be called in rare circumstances in which we cannot
+		/// guarantee a single candidate's stake has changed, e.g. on genesis or
+		/// when a collator leaves. Otherwise, please use
+		/// [update_total_stake_by].
+		fn update_total_stake() -> (u32, u32) {
+			let mut num_of_delegators = 0u32;
+			let mut collator_stake = BalanceOf::<T>::zero();
+			let mut delegator_stake = BalanceOf::<T>::zero();
+
+			let collators = Self::selected_candidates();
+
+			// Snapshot exposure for round for weighting reward distribution
+			for account in collators.iter() {
+				let state =
+					CandidatePool::<T>::get(account).expect("all members of TopCandidates must be candidates q.e.d");
+				num_of_delegators = num_of_delegators.max(state.delegators.len().saturated_into::<u32>());
+
+				// sum up total stake and amount of collators, delegators
+				let amount_collator = state.stake;
+				collator_stake = collator_stake.saturating_add(state.stake);
+				// safe to subtract because total >= stake
+				let amount_delegators = state.total - amount_collator;
+				delegator_stake = delegator_stake.saturating_add(amount_delegators);
+			}
+
+			TotalCollatorStake::<T>::mutate(|total| {
+				total.collators = collator_stake;
+				total.delegators = delegator_stake;
+			});
+
+			// return number of selected candidates and the corresponding number of their
+			// delegators for post-weight correction
+			(collators.len().saturated_into(), num_of_delegators)
+		}
+
+		/// Increase the stake of a collator candidate and extend the lock of
+		/// their funds accordingly.
+		///
+		/// Returns the consumed weight.
+		fn do_candidate_stake_more(collator: &T::AccountId, more: BalanceOf<T>) -> Result<Weight, DispatchError> {
+			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
+			let mut state = CandidatePool::<T>::get(collator).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!state.is_leaving(), Error::<T>::CannotStakeIfLeaving);
+
+			let CandidateOf::<T, _> {
+				stake: before_stake,
+				total: before_total,
+				..
+			} = state;
+			state.stake_more(more);
+			let after_stake = state.stake;
+			ensure!(
+				state.stake <= MaxCollatorCandidateStake::<T>::get(),
+				Error::<T>::ValStakeAboveMax
+			);
+
+			let unstaking_len = Self::increase_lock(collator, state.stake, more)?;
+
+			let n = if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					before_stake,
+					// safe because total >= stake
+					before_total - before_stake,
+					state.stake,
+					state.total - state.stake,
+				)
+			} else {
+				0u32
+			};
+			CandidatePool::<T>::insert(collator, state);
+
+			// increment rewards for collator and update number of rewarded blocks
+			Self::do_inc_collator_reward(collator, before_stake);
+
+			Self::deposit_event(Event::CollatorStakedMore(collator.clone(), before_stake, after_stake));
+			Ok(<T as pallet::Config>::WeightInfo::candidate_stake_more(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+				unstaking_len,
+			))
+		}
+
+		/// Increase the stake of a delegation and extend the lock of the
+		/// delegator's funds accordingly.
+		///
+		/// Returns the consumed weight.
+		pub fn do_delegator_stake_more(
+			delegator: &T::AccountId,
+			candidate: &T::AccountId,
+			more: BalanceOf<T>,
+		) -> Result<Weight, DispatchError> {
+			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
+
+			let mut delegation = DelegatorState::<T>::get(delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			let mut collator = CandidatePool::<T>::get(candidate).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+			let stake_after = delegation
+				.try_increment(candidate.clone(), more)
+				.map_err(|_| Error::<T>::DelegationNotFound)?;
+
+			// update lock
+			let unstaking_len = Self::increase_lock(delegator, delegation.total, more)?;
+
+			let CandidateOf::<T, _> {
+				stake: before_stake,
+				total: before_total,
+				..
+			} = collator;
+			collator.inc_delegator(delegator.clone(), more);
+			let after = collator.total;
+
+			// update top candidates and total amount at stake
+			let n = if collator.is_active() {
+				Self::update_top_candidates(
+					candidate.clone(),
+					before_stake,
+					// safe because total >= stake
+					before_total - before_stake,
+					collator.stake,
+					collator.total - collator.stake,
+				)
+			} else {
+				0u32
+			};
+
+			// increment rewards and update number of rewarded blocks
+			Self::do_inc_delegator_reward(delegator, stake_after.saturating_sub(more), candidate);
+
+			CandidatePool::<T>::insert(candidate, collator);
+			DelegatorState::<T>::insert(delegator, delegation);
+
+			Self::deposit_event(Event::DelegatorStakedMore(
+				delegator.clone(),
+				candidate.clone(),
+				before_total,
+				after,
+			));
+			Ok(<T as pallet::Config>::WeightInfo::delegator_stake_more(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+				unstaking_len,
+			))
+		}
+
+		/// Join the set of delegators by delegating to a collator candidate.
+		///
+		/// See `join_delegators` for the requirements.
+		///
+		/// Returns the consumed weight.
+		pub fn do_join_delegators(
+			acc: &T::AccountId,
+			collator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<Weight, DispatchError> {
+			// check balance
+			ensure!(
+				pallet_balances::Pallet::<T>::balance(acc) >= amount.into(),
+				pallet_balances::Error::<T>::InsufficientBalance
+			);
+
+			// first delegation
+			ensure!(DelegatorState::<T>::get(acc).is_none(), Error::<T>::AlreadyDelegating);
+			ensure!(amount >= T::MinDelegatorStake::get(), Error::<T>::DelegationBelowMin);
+
+			// cannot be a collator candidate and delegator with same AccountId
+			ensure!(Self::is_active_candidate(acc).is_none(), Error::<T>::CandidateExists);
+			ensure!(
+				Unstaking::<T>::get(acc).len().saturated_into::<u32>() < T::MaxUnstakeRequests::get(),
+				Error::<T>::CannotJoinBeforeUnlocking
+			);
+			// cannot delegate if number of delegations in this round exceeds
+			// MaxDelegationsPerRound
+			let delegation_counter = Self::get_delegation_counter(acc)?;
+
+			// prepare update of collator state
+			let mut state = CandidatePool::<T>::get(collator).ok_or(Error::<T>::CandidateNotFound)?;
+			let num_delegations_pre_insertion: u32 = state.delegators.len().saturated_into();
+
+			ensure!(!state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+			let delegation = Stake {
+				owner: acc.clone(),
+				amount,
+			};
+
+			// attempt to insert delegator and check for uniqueness
+			// NOTE: excess is handled below because we support replacing a delegator with
+			// fewer stake
+			let insert_delegator = state
+				.delegators
+				// we handle TooManyDelegators error below in do_update_delegator
+				.try_insert(delegation.clone())
+				.unwrap_or(true);
+			// should never fail but let's be safe
+			ensure!(insert_delegator, Error::<T>::DelegatorExists);
+
+			let delegator_state = Delegator::try_new(collator.clone(), amount)
+				.map_err(|_| Error::<T>::MaxCollatorsPerDelegatorExceeded)?;
+			let CandidateOf::<T, _> {
+				stake: old_stake,
+				total: old_total,
+				..
+			} = state;
+
+			// update state and potentially prepare kicking a delegator with less staked
+			// amount (includes setting rewards for kicked delegator)
+			let new_collator_state = if num_delegations_pre_insertion == T::MaxDelegatorsPerCollator::get() {
+				Self::do_update_delegator(delegation, state)?
+			} else {
+				state.total = state.total.saturating_add(amount);
+				state
 			};
+			let new_total = new_collator_state.total;
 
-			// update TopCandidates set
-			let maybe_top_candidate_update = if let Ok(i) = top_candidates.linear_search(&old_stake) {
-				// case 1: candidate is member of TopCandidates with old stake
-				top_candidates.mutate(|vec| {
-					if let Some(stake) = vec.get_mut(i) {
-						stake.amount = new_stake.amount;
-					}
-				});
-				Some((Some(i), top_candidates))
-			} else if top_candidates.try_insert_replace(new_stake.clone()).is_ok() {
-				// case 2: candidate ascends into TopCandidates with new stake
-				// and might replace another candidate if TopCandidates is full
-				Self::deposit_event(Event::EnteredTopCandidates(candidate));
-				Some((None, top_candidates))
+			// lock stake
+			Self::increase_lock(acc, amount, BalanceOf::<T>::zero())?;
+
+			// update top candidates and total amount at stake
+			let n = if new_collator_state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_stake,
+					// safe because total >= stake
+					old_total - old_stake,
+					new_collator_state.stake,
+					new_collator_state.total - new_collator_state.stake,
+				)
 			} else {
-				// case 3: candidate neither was nor will be member of TopCandidates
-				None
+				0u32
 			};
 
-			// update storage for TotalCollatorStake and TopCandidates
-			if let Some((maybe_old_idx, new_top_candidates)) = maybe_top_candidate_update {
-				let max_selected_candidates = MaxSelectedCandidates::<T>::get().saturated_into::<usize>();
-				let was_collating = maybe_old_idx.map(|i| i < max_selected_candidates).unwrap_or(false);
-				let is_collating = new_top_candidates
-					.linear_search(&new_stake)
-					.map(|i| i < max_selected_candidates)
-					.unwrap_or(false);
+			// update states
+			CandidatePool::<T>::insert(collator, new_collator_state);
+			DelegatorState::<T>::insert(acc, delegator_state);
+			LastDelegation::<T>::insert(acc, delegation_counter);
 
-				// update TopCollatorStake storage iff candidate was or will be a collator
-				match (was_collating, is_collating) {
-					(true, true) => {
-						Self::update_total_stake_by(new_self, new_delegators, old_self, old_delegators);
-					}
-					(true, false) => {
-						// candidate left the collator set because they staked less and have been
-						// replaced by the next candidate in the queue at position
-						// min(max_selected_candidates, top_candidates) - 1 in TopCandidates
-						let new_col_idx = max_selected_candidates.min(new_top_candidates.len()).saturating_sub(1);
+			// initiate rewarded counter to match the current authored counter of the
+			// candidate
+			DelegationBlocksRewarded::<T>::insert(acc, collator, BlocksAuthored::<T>::get(collator));
 
-						// get displacer
-						let (add_collators, add_delegators) =
-							Self::get_top_candidate_stake_at(&new_top_candidates, new_col_idx)
-								// shouldn't be possible to fail, but we handle it gracefully
-								.unwrap_or((new_self, new_delegators));
-						Self::update_total_stake_by(add_collators, add_delegators, old_self, old_delegators);
-					}
-					(false, true) => {
-						// candidate pushed out the least staked collator which is now at position
-						let (drop_self, drop_delegators) = match max_selected_candidates.cmp(&new_top_candidates.len())
-						{
-							// top candidates are not full
-							Ordering::Greater => (BalanceOf::<T>::zero(), BalanceOf::<T>::zero()),
-							// top candidates are full. the collator with the lowest stake is at index old_col_idx
-							_ => {
-								// we can unwrap here without problems, since we compared
-								// [max_selected_candidates] with [top_candidates] length, but lets be
-								// safe.
-								Self::get_top_candidate_stake_at(&new_top_candidates, max_selected_candidates)
-									.unwrap_or((BalanceOf::<T>::zero(), BalanceOf::<T>::zero()))
-							}
-						};
+			Self::deposit_event(Event::Delegation(acc.clone(), amount, collator.clone(), new_total));
+			Ok(<T as pallet::Config>::WeightInfo::join_delegators(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+		}
 
-						// get amount to subtract from TotalCollatorStake
-						Self::update_total_stake_by(new_self, new_delegators, drop_self, drop_delegators);
-					}
-					_ => {}
-				}
+		/// Leave the set of delegators and revoke all delegations.
+		///
+		/// The funds can be unlocked after `StakeDuration` blocks.
+		///
+		/// Returns the consumed weight.
+		pub fn do_leave_delegators(acc: &T::AccountId) -> Result<Weight, DispatchError> {
+			Self::do_leave_delegators_with_delay(acc, T::StakeDuration::get())
+		}
 
-				// update TopCandidates storage
-				TopCandidates::<T>::put(new_top_candidates);
+		/// Leave the set of delegators and revoke all delegations. The funds
+		/// can be unlocked after `unstake_delay` blocks.
+		///
+		/// Returns the consumed weight.
+		fn do_leave_delegators_with_delay(
+			acc: &T::AccountId,
+			unstake_delay: BlockNumberFor<T>,
+		) -> Result<Weight, DispatchError> {
+			let delegator = DelegatorState::<T>::get(acc).ok_or(Error::<T>::DelegatorNotFound)?;
+			let num_delegations: u32 = delegator.delegations.len().saturated_into();
+			for stake in delegator.delegations.iter() {
+				Self::delegator_leaves_collator(acc.clone(), stake.owner.clone(), unstake_delay)?;
 			}
 
-			num_top_candidates
+			DelegatorState::<T>::remove(acc);
+			AutoCompound::<T>::remove(acc);
+
+			Self::deposit_event(Event::DelegatorLeft(acc.clone(), delegator.total));
+			Ok(<T as pallet::Config>::WeightInfo::leave_delegators(
+				num_delegations,
+				T::MaxDelegatorsPerCollator::get(),
+			))
 		}
 
-		/// Retrieve the staked amounts (self, sum of delegators) of member of
-		/// [TopCandidates] at the given index, if it exists.
-		fn get_top_candidate_stake_at(
-			top_candidates: &OrderedSet<StakeOf<T>, T::MaxTopCandidates>,
-			index: usize,
-		) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
-			top_candidates
-				.get(index)
-				.and_then(|stake| CandidatePool::<T>::get(&stake.owner))
-				// SAFETY: the total is always more than the stake
-				.map(|state| (state.stake, state.total - state.stake))
+		/// Delegate another collator candidate in addition to the existing
+		/// delegations.
+		///
+		/// See `delegate_another_candidate` for the requirements.
+		///
+		/// Returns the consumed weight.
+		pub fn do_delegate_another_candidate(
+			acc: &T::AccountId,
+			collator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<Weight, DispatchError> {
+			let mut delegator = DelegatorState::<T>::get(acc).ok_or(Error::<T>::NotYetDelegating)?;
+
+			ensure!(amount >= T::MinDelegatorStake::get(), Error::<T>::DelegationBelowMin);
+			ensure!(
+				delegator.delegation_of(collator).is_none(),
+				Error::<T>::AlreadyDelegatedCollator
+			);
+			ensure!(
+				delegator.delegations.len().saturated_into::<u32>() < T::MaxCollatorsPerDelegator::get(),
+				Error::<T>::MaxCollatorsPerDelegatorExceeded
+			);
+			ensure!(
+				Unstaking::<T>::get(acc).len().saturated_into::<u32>() < T::MaxUnstakeRequests::get(),
+				Error::<T>::CannotJoinBeforeUnlocking
+			);
+			// cannot delegate if number of delegations in this round exceeds
+			// MaxDelegationsPerRound
+			let delegation_counter = Self::get_delegation_counter(acc)?;
+
+			// prepare update of collator state
+			let mut state = CandidatePool::<T>::get(collator).ok_or(Error::<T>::CandidateNotFound)?;
+			let num_delegations_pre_insertion: u32 = state.delegators.len().saturated_into();
+			ensure!(!state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+
+			let delegation = Stake {
+				owner: acc.clone(),
+				amount,
+			};
+
+			// attempt to insert delegation into the delegator state
+			let insert_delegation = delegator
+				.add_delegation(Stake {
+					owner: collator.clone(),
+					amount,
+				})
+				.map_err(|_| Error::<T>::MaxCollatorsPerDelegatorExceeded)?;
+			// should never fail but let's be safe
+			ensure!(insert_delegation, Error::<T>::AlreadyDelegatedCollator);
+
+			// attempt to insert delegator and check for uniqueness
+			// NOTE: excess is handled below because we support replacing a delegator with
+			// fewer stake
+			let insert_delegator = state
+				.delegators
+				// we handle TooManyDelegators error below in do_update_delegator
+				.try_insert(delegation.clone())
+				.unwrap_or(true);
+			// should never fail but let's be safe
+			ensure!(insert_delegator, Error::<T>::DelegatorExists);
+
+			let CandidateOf::<T, _> {
+				stake: old_stake,
+				total: old_total,
+				..
+			} = state;
+
+			// update state and potentially prepare kicking a delegator with less staked
+			// amount (includes setting rewards for kicked delegator)
+			let new_collator_state = if num_delegations_pre_insertion == T::MaxDelegatorsPerCollator::get() {
+				Self::do_update_delegator(delegation, state)?
+			} else {
+				state.total = state.total.saturating_add(amount);
+				state
+			};
+			let new_total = new_collator_state.total;
+
+			// lock stake
+			Self::increase_lock(acc, delegator.total, amount)?;
+
+			// update top candidates and total amount at stake
+			let n = if new_collator_state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_stake,
+					// safe because total >= stake
+					old_total - old_stake,
+					new_collator_state.stake,
+					new_collator_state.total - new_collator_state.stake,
+				)
+			} else {
+				0u32
+			};
+
+			// update states
+			CandidatePool::<T>::insert(collator, new_collator_state);
+			DelegatorState::<T>::insert(acc, delegator);
+			LastDelegation::<T>::insert(acc, delegation_counter);
+
+			// initiate rewarded counter to match the current authored counter of the
+			// candidate
+			DelegationBlocksRewarded::<T>::insert(acc, collator, BlocksAuthored::<T>::get(collator));
+
+			Self::deposit_event(Event::Delegation(acc.clone(), amount, collator.clone(), new_total));
+			Ok(<T as pallet::Config>::WeightInfo::delegate_another_candidate(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
 		}
 
-		/// Mutate the [TotalCollatorStake] by both incrementing and decreasing
-		/// it by the provided values.
-		fn update_total_stake_by(
-			add_collators: BalanceOf<T>,
-			add_delegators: BalanceOf<T>,
-			sub_collators: BalanceOf<T>,
-			sub_delegators: BalanceOf<T>,
-		) {
-			TotalCollatorStake::<T>::mutate(|total| {
-				total.collators = total
-					.collators
-					.saturating_sub(sub_collators)
-					.saturating_add(add_collators);
-				total.delegators = total
-					.delegators
-					.saturating_sub(sub_delegators)
-					.saturating_add(add_delegators);
-			});
+		/// Revoke a single delegation and leave the set of delegators if it was
+		/// the last one.
+		///
+		/// Returns the consumed weight.
+		pub fn do_revoke_delegation(acc: &T::AccountId, collator: &T::AccountId) -> Result<Weight, DispatchError> {
+			let mut delegator = DelegatorState::<T>::get(acc).ok_or(Error::<T>::DelegatorNotFound)?;
+
+			let amount = delegator
+				.rm_delegation(collator.clone())
+				.ok_or(Error::<T>::DelegationNotFound)?;
+			let (n, m) = Self::delegator_leaves_collator(acc.clone(), collator.clone(), T::StakeDuration::get())?;
+
+			if delegator.delegations.is_empty() {
+				DelegatorState::<T>::remove(acc);
+				AutoCompound::<T>::remove(acc);
+				Self::deposit_event(Event::DelegatorLeft(acc.clone(), amount));
+			} else {
+				DelegatorState::<T>::insert(acc, delegator);
+			}
+
+			Ok(<T as pallet::Config>::WeightInfo::revoke_delegation(n, m))
 		}
 
-		/// Iterate over the top `MaxSelectedCandidates` many collators in terms
-		/// of cumulated stake (self + from delegators) from the [TopCandidates]
-		/// and recalculate the [TotalCollatorStake] from scratch.
+		/// Decrease the stake of a delegation. The funds can be unlocked after
+		/// `StakeDuration` blocks.
 		///
-		/// NOTE: Should only be called in rare circumstances in which we cannot
-		/// guarantee a single candidate's stake has changed, e.g. on genesis or
-		/// when a collator leaves. Otherwise, please use
-		/// [update_total_stake_by].
-		fn update_total_stake() -> (u32, u32) {
-			let mut num_of_delegators = 0u32;
-			let mut collator_stake = BalanceOf::<T>::zero();
-			let mut delegator_stake = BalanceOf::<T>::zero();
+		/// Returns the consumed weight.
+		pub fn do_delegator_stake_less(
+			delegator: &T::AccountId,
+			candidate: &T::AccountId,
+			less: BalanceOf<T>,
+		) -> Result<Weight, DispatchError> {
+			Self::do_delegator_stake_less_with_delay(delegator, candidate, less, T::StakeDuration::get())
+		}
 
-			let collators = Self::selected_candidates();
+		/// Decrease the stake of a delegation. The funds can be unlocked after
+		/// `unstake_delay` blocks.
+		///
+		/// Returns the consumed weight.
+		fn do_delegator_stake_less_with_delay(
+			delegator: &T::AccountId,
+			candidate: &T::AccountId,
+			less: BalanceOf<T>,
+			unstake_delay: BlockNumberFor<T>,
+		) -> Result<Weight, DispatchError> {
+			ensure!(!less.is_zero(), Error::<T>::ValStakeZero);
+
+			let mut delegation = DelegatorState::<T>::get(delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			let mut collator = CandidatePool::<T>::get(candidate).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+			let stake_after = delegation
+				.try_decrement(candidate.clone(), less)
+				.map_err(|_| Error::<T>::DelegationNotFound)?
+				.ok_or(Error::<T>::Underflow)?;
+
+			ensure!(
+				stake_after >= T::MinDelegatorStake::get(),
+				Error::<T>::DelegationBelowMin
+			);
+
+			Self::prep_unstake_with_delay(delegator, less, false, unstake_delay)?;
+
+			let CandidateOf::<T, _> {
+				stake: before_stake,
+				total: before_total,
+				..
+			} = collator;
+			collator.dec_delegator(delegator.clone(), less);
+			let after = collator.total;
 
-			// Snapshot exposure for round for weighting reward distribution
-			for account in collators.iter() {
-				let state =
-					CandidatePool::<T>::get(account).expect("all members of TopCandidates must be candidates q.e.d");
-				num_of_delegators = num_of_delegators.max(state.delegators.len().saturated_into::<u32>());
+			// update top candidates and total amount at stake
+			let n = if collator.is_active() {
+				Self::update_top_candidates(
+					candidate.clone(),
+					before_stake,
+					// safe because total >= stake
+					before_total - before_stake,
+					collator.stake,
+					collator.total - collator.stake,
+				)
+			} else {
+				0u32
+			};
 
-				// sum up total stake and amount of collators, delegators
-				let amount_collator = state.stake;
-				collator_stake = collator_stake.saturating_add(state.stake);
-				// safe to subtract because total >= stake
-				let amount_delegators = state.total - amount_collator;
-				delegator_stake = delegator_stake.saturating_add(amount_delegators);
-			}
+			// increment rewards and update number of rewarded blocks
+			Self::do_inc_delegator_reward(delegator, stake_after.saturating_add(less), candidate);
 
-			TotalCollatorStake::<T>::mutate(|total| {
-				total.collators = collator_stake;
-				total.delegators = delegator_stake;
-			});
+			CandidatePool::<T>::insert(candidate, collator);
+			DelegatorState::<T>::insert(delegator, delegation);
 
-			// return number of selected candidates and the corresponding number of their
-			// delegators for post-weight correction
-			(collators.len().saturated_into(), num_of_delegators)
+			Self::deposit_event(Event::DelegatorStakedLess(
+				delegator.clone(),
+				candidate.clone(),
+				before_total,
+				after,
+			));
+			Ok(<T as pallet::Config>::WeightInfo::delegator_stake_less(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
 		}
 
 		/// Update the collator's state by removing the delegator's stake and
@@ -2056,7 +3951,14 @@ pub mod pallet {
 		/// as incrementing their accumulated rewards.
 		///
 		/// This operation affects the pallet's total stake.
-		fn delegator_leaves_collator(delegator: T::AccountId, collator: T::AccountId) -> DispatchResult {
+		///
+		/// Returns the number of top candidates and the number of remaining
+		/// delegators of the collator for post-weight correction.
+		fn delegator_leaves_collator(
+			delegator: T::AccountId,
+			collator: T::AccountId,
+			unstake_delay: BlockNumberFor<T>,
+		) -> Result<(u32, u32), DispatchError> {
 			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
 
 			let delegator_stake = state
@@ -2079,13 +3981,13 @@ pub mod pallet {
 
 			// increment rewards and kill storage for number of rewarded blocks
 			Self::do_inc_delegator_reward(&delegator, delegator_stake, &collator);
-			BlocksRewarded::<T>::remove(&delegator);
+			DelegationBlocksRewarded::<T>::remove(&delegator, &collator);
 
 			// we don't unlock immediately
-			Self::prep_unstake(&delegator, delegator_stake, false)?;
+			Self::prep_unstake_with_delay(&delegator, delegator_stake, false, unstake_delay)?;
 
 			// update top candidates and total amount at stake
-			if state.is_active() {
+			let n = if state.is_active() {
 				Self::update_top_candidates(
 					collator.clone(),
 					old_stake,
@@ -2093,8 +3995,11 @@ pub mod pallet {
 					old_total - old_stake,
 					state.stake,
 					state.total - state.stake,
-				);
-			}
+				)
+			} else {
+				0u32
+			};
+			let m: u32 = state.delegators.len().saturated_into();
 			CandidatePool::<T>::insert(&collator, state);
 
 			Self::deposit_event(Event::DelegatorLeftCollator(
@@ -2103,7 +4008,7 @@ pub mod pallet {
 				delegator_stake,
 				new_total,
 			));
-			Ok(())
+			Ok((n, m))
 		}
 
 		/// Return the best `MaxSelectedCandidates` many candidates.
@@ -2122,6 +4027,7 @@ pub mod pallet {
 			// Choose the top MaxSelectedCandidates qualified candidates
 			let collators = candidates
 				.into_iter()
+				.filter(|x| Self::meets_identity_requirement(&x.owner))
 				.take(top_n)
 				.filter(|x| x.amount >= T::MinCollatorStake::get())
 				.map(|x| x.owner)
@@ -2176,8 +4082,8 @@ pub mod pallet {
 				);
 				// prepare unstaking for kicked delegator
 				Self::prep_unstake(&existing_stake_to_remove.owner, existing_stake_to_remove.amount, true)?;
-				// remove Delegator state for kicked delegator
-				DelegatorState::<T>::remove(&existing_stake_to_remove.owner);
+				// remove the delegation from the state of the kicked delegator
+				Self::remove_delegation(&existing_stake_to_remove.owner, &state.id)?;
 
 				Self::deposit_event(Event::DelegationReplaced(
 					stake.owner,
@@ -2210,6 +4116,8 @@ pub mod pallet {
 			);
 
 			let mut unstaking_len = 0u32;
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut restaked_until: Option<BlockNumberFor<T>> = None;
 
 			// update Unstaking by consuming up to {amount | more}
 			Unstaking::<T>::try_mutate(who, |unstaking| -> DispatchResult {
@@ -2222,7 +4130,12 @@ pub mod pallet {
 				for (block_number, locked_balance) in unstaking.clone() {
 					if amt_consuming_unstaking.is_zero() {
 						break;
-					} else if locked_balance > amt_consuming_unstaking {
+					}
+					// remember how long the restaked funds would have been locked
+					if block_number > now {
+						restaked_until = restaked_until.max(Some(block_number));
+					}
+					if locked_balance > amt_consuming_unstaking {
 						// amount is only reducible by locked_balance - amt_consuming_unstaking
 						let delta = locked_balance.saturating_sub(amt_consuming_unstaking);
 						// replace old entry with delta
@@ -2238,6 +4151,9 @@ pub mod pallet {
 				}
 				Ok(())
 			})?;
+			if let Some(until) = restaked_until {
+				RestakedUntil::<T>::mutate(who, |restaked| *restaked = (*restaked).max(Some(until)));
+			}
 
 			// Either set a new lock or potentially extend the existing one if amount
 			// exceeds the currently locked amount
@@ -2254,11 +4170,23 @@ pub mod pallet {
 		/// requests exceed limit. The latter defends against stake reduction
 		/// spamming.
 		fn prep_unstake(who: &T::AccountId, amount: BalanceOf<T>, is_removal: bool) -> DispatchResult {
+			Self::prep_unstake_with_delay(who, amount, is_removal, T::StakeDuration::get())
+		}
+
+		/// Set the unlocking block for the account and corresponding amount
+		/// which can be unfreezed via `unlock_unstaked` after waiting at
+		/// least for `delay` many blocks.
+		fn prep_unstake_with_delay(
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+			is_removal: bool,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
 			// should never occur but let's be safe
 			ensure!(!amount.is_zero(), Error::<T>::StakeNotFound);
 
 			let now = frame_system::Pallet::<T>::block_number();
-			let unlock_block = now.saturating_add(T::StakeDuration::get());
+			let unlock_block = now.saturating_add(delay);
 			let mut unstaking = Unstaking::<T>::get(who);
 
 			let allowed_unstakings = if is_removal {
@@ -2298,15 +4226,12 @@ pub mod pallet {
 				// prepare unstaking of delegator
 				Self::prep_unstake(&stake.owner, stake.amount, true)?;
 				// remove delegation from delegator state
-				if let Some(mut delegator) = DelegatorState::<T>::get(&stake.owner) {
-					delegator
-						.try_clear(collator.clone())
-						.map_err(|_| Error::<T>::DelegationNotFound)?;
-					DelegatorState::<T>::remove(&stake.owner);
-				}
+				Self::remove_delegation(&stake.owner, collator)?;
+			}
+			// prepare unstaking of collator candidate, the stake can be zero after a slash
+			if !state.stake.is_zero() {
+				Self::prep_unstake(&state.id, state.stake, true)?;
 			}
-			// prepare unstaking of collator candidate
-			Self::prep_unstake(&state.id, state.stake, true)?;
 
 			// increment rewards of collator
 			Self::do_inc_collator_reward(collator, state.stake);
@@ -2325,13 +4250,44 @@ pub mod pallet {
 			// Kill storage
 			BlocksAuthored::<T>::remove(collator);
 			BlocksRewarded::<T>::remove(collator);
+			CandidateCommission::<T>::remove(collator);
+			ScheduledCommission::<T>::remove(collator);
+			AutoCompound::<T>::remove(collator);
+			BlocksAuthoredInRound::<T>::remove(collator);
+			MissedRounds::<T>::remove(collator);
+			DeferredSlashes::<T>::remove(collator);
+			Self::do_unlink_identity(collator);
 			CandidatePool::<T>::remove(collator);
 			Ok(())
 		}
 
+		/// Remove the delegation for the collator from the delegator's state
+		/// along with its counter of rewarded blocks.
+		///
+		/// Removes the delegator state if this was their last delegation.
+		///
+		/// NOTE: The rewards of the delegation have to be incremented before.
+		fn remove_delegation(delegator: &T::AccountId, collator: &T::AccountId) -> DispatchResult {
+			DelegationBlocksRewarded::<T>::remove(delegator, collator);
+			if let Some(mut state) = DelegatorState::<T>::get(delegator) {
+				state
+					.rm_delegation(collator.clone())
+					.ok_or(Error::<T>::DelegationNotFound)?;
+				if state.delegations.is_empty() {
+					DelegatorState::<T>::remove(delegator);
+					AutoCompound::<T>::remove(delegator);
+				} else {
+					DelegatorState::<T>::insert(delegator, state);
+				}
+			}
+			Ok(())
+		}
+
 		/// Withdraw all staked currency which was unstaked at least
 		/// `StakeDuration` blocks ago.
-		fn do_unlock(who: &T::AccountId) -> Result<u32, DispatchError> {
+		///
+		/// Returns the number of unstaking requests before unlocking.
+		pub fn do_unlock(who: &T::AccountId) -> Result<u32, DispatchError> {
 			let now = frame_system::Pallet::<T>::block_number();
 			let mut unstaking = Unstaking::<T>::get(who);
 			let unstaking_len = unstaking.len().saturated_into::<u32>();
@@ -2478,7 +4434,9 @@ pub mod pallet {
 		///
 		/// Updates Rewarded(col) and sets BlocksRewarded(col) to equal
 		/// BlocksAuthored(col).
-		fn do_inc_collator_reward(acc: &T::AccountId, stake: BalanceOf<T>) {
+		///
+		/// Returns the amount the rewards were incremented by.
+		fn do_inc_collator_reward(acc: &T::AccountId, stake: BalanceOf<T>) -> BalanceOf<T> {
 			let count_authored = BlocksAuthored::<T>::get(acc);
 			// We can already mutate thanks to extrinsics being transactional
 			let count_rewarded = BlocksRewarded::<T>::mutate(acc, |rewarded| {
@@ -2488,35 +4446,583 @@ pub mod pallet {
 			});
 			let unclaimed_blocks = count_authored.saturating_sub(count_rewarded);
 
-			Rewards::<T>::mutate(acc, |reward| {
-				*reward = reward.saturating_add(Self::calc_block_rewards_collator(
-					stake,
-					unclaimed_blocks.saturated_into(),
-				));
+			let reward = Self::calc_block_rewards_collator(stake, unclaimed_blocks.saturated_into());
+			Rewards::<T>::mutate(acc, |rewards| {
+				*rewards = rewards.saturating_add(reward);
 			});
+			Self::note_rewards_credited(acc, reward, BalanceOf::<T>::zero());
+			reward
 		}
 
 		/// Increment the accumulated rewards of a delegator by checking the
 		/// number of authored blocks by the collator.
 		///
-		/// Updates Rewarded(del) and sets BlocksRewarded(del) to equal
-		/// BlocksAuthored(col).
-		fn do_inc_delegator_reward(acc: &T::AccountId, stake: BalanceOf<T>, col: &T::AccountId) {
+		/// The commission of the collator is deducted from the rewards of the
+		/// delegator and added to the rewards of the collator.
+		///
+		/// Updates Rewarded(del) as well as Rewarded(col) and sets
+		/// DelegationBlocksRewarded(del, col) to equal BlocksAuthored(col).
+		///
+		/// Returns the amount the rewards of the delegator were incremented by.
+		fn do_inc_delegator_reward(acc: &T::AccountId, stake: BalanceOf<T>, col: &T::AccountId) -> BalanceOf<T> {
+			use sp_runtime::PerThing;
+
 			let count_authored = BlocksAuthored::<T>::get(col);
 			// We can already mutate thanks to extrinsics being transactional
-			let count_rewarded = BlocksRewarded::<T>::mutate(acc, |rewarded| {
+			let count_rewarded = DelegationBlocksRewarded::<T>::mutate(acc, col, |rewarded| {
 				let old = *rewarded;
 				*rewarded = count_authored;
 				old
 			});
 			let unclaimed_blocks = count_authored.saturating_sub(count_rewarded);
 
-			Rewards::<T>::mutate(acc, |reward| {
-				*reward = reward.saturating_add(Self::calc_block_rewards_delegator(
-					stake,
-					unclaimed_blocks.saturated_into(),
-				))
+			let reward = Self::calc_block_rewards_delegator(stake, unclaimed_blocks.saturated_into());
+			let commission = Self::commission_of(col).mul_floor(reward);
+			if !commission.is_zero() {
+				Rewards::<T>::mutate(col, |rewards| *rewards = rewards.saturating_add(commission));
+			}
+			let reward = reward.saturating_sub(commission);
+			Rewards::<T>::mutate(acc, |rewards| {
+				*rewards = rewards.saturating_add(reward);
+			});
+			Self::note_rewards_credited(col, commission, reward);
+			reward
+		}
+
+		/// Add the rewards credited for the blocks of a collator to the
+		/// statistics of the current round.
+		///
+		/// If the collator is not selected in the current round, the rewards
+		/// are only added to the total of the round.
+		fn note_rewards_credited(
+			collator: &T::AccountId,
+			collator_rewards: BalanceOf<T>,
+			delegator_rewards: BalanceOf<T>,
+		) {
+			let credited = collator_rewards.saturating_add(delegator_rewards);
+			if credited.is_zero() {
+				return;
+			}
+
+			let round = pallet_session::Pallet::<T>::current_index();
+			CollatorHistory::<T>::mutate(round, collator, |maybe_stats| {
+				if let Some(stats) = maybe_stats {
+					stats.collator_rewards = stats.collator_rewards.saturating_add(collator_rewards);
+					stats.delegator_rewards = stats.delegator_rewards.saturating_add(delegator_rewards);
+				}
+			});
+			RoundHistory::<T>::mutate(round, |maybe_stats| {
+				if let Some(stats) = maybe_stats {
+					stats.total_rewarded = stats.total_rewarded.saturating_add(credited);
+				}
+			});
+		}
+
+		/// Increment and compound the rewards of at most
+		/// `MaxAutoCompoundsPerBlock` accounts which opted into
+		/// auto-compounding, continuing after the account stored in
+		/// `AutoCompoundCursor`.
+		///
+		/// Returns the consumed weight.
+		fn process_auto_compound() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			let Some(cursor) = AutoCompoundCursor::<T>::get() else {
+				return weight;
+			};
+
+			let mut accounts = match cursor {
+				Some(last) => AutoCompound::<T>::iter_keys_from(AutoCompound::<T>::hashed_key_for(last)),
+				None => AutoCompound::<T>::iter_keys(),
+			};
+			let batch: Vec<T::AccountId> = accounts
+				.by_ref()
+				.take(T::MaxAutoCompoundsPerBlock::get().saturated_into())
+				.collect();
+			let done = accounts.next().is_none();
+			weight = weight.saturating_add(T::DbWeight::get().reads(batch.len().saturating_add(1).saturated_into()));
+
+			for acc in batch.iter() {
+				// accounts which opted into auto-compounding are either collator candidates or
+				// delegators
+				let result = if CandidatePool::<T>::contains_key(acc) {
+					Self::do_increment_collator_rewards(acc)
+				} else {
+					Self::do_increment_delegator_rewards(acc)
+				};
+				weight = weight.saturating_add(result.unwrap_or_else(|_| T::DbWeight::get().reads(2)));
+			}
+
+			match batch.last() {
+				Some(last) if !done => AutoCompoundCursor::<T>::put(Some(last.clone())),
+				_ => AutoCompoundCursor::<T>::kill(),
+			}
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+
+		/// Mint the accumulated rewards of an account into its balance.
+		///
+		/// Returns the amount of minted rewards.
+		pub fn do_claim_rewards(target: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+			// reset rewards
+			let rewards = Rewards::<T>::take(target);
+			ensure!(!rewards.is_zero(), Error::<T>::RewardsNotFound);
+
+			// mint into target
+			let rewarded =
+				<T::Currency as Unbalanced<AccountIdOf<T>>>::increase_balance(target, rewards, Precision::Exact)?;
+
+			Self::deposit_event(Event::Rewarded(target.clone(), rewarded));
+			Ok(rewarded)
+		}
+
+		/// Increment the rewards of a collator candidate and stake the share
+		/// they opted into via `set_auto_compound`.
+		///
+		/// Returns the consumed weight.
+		fn do_increment_collator_rewards(collator: &T::AccountId) -> Result<Weight, DispatchError> {
+			let state = CandidatePool::<T>::get(collator).ok_or(Error::<T>::CandidateNotFound)?;
+
+			// increment rewards and update number of rewarded blocks
+			let reward = Self::do_inc_collator_reward(collator, state.stake);
+			let compound_weight = Self::do_compound_rewards(collator, reward, None);
+
+			Ok(<T as Config>::WeightInfo::increment_collator_rewards().saturating_add(compound_weight))
+		}
+
+		/// Increment the rewards of a delegator for all of their delegations
+		/// and stake the share they opted into via `set_auto_compound`.
+		///
+		/// Returns the consumed weight.
+		pub fn do_increment_delegator_rewards(delegator: &T::AccountId) -> Result<Weight, DispatchError> {
+			let state = DelegatorState::<T>::get(delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+
+			// increment rewards and update number of rewarded blocks
+			let mut weight = Weight::zero();
+			for delegation in state.delegations.iter() {
+				let reward = Self::do_inc_delegator_reward(delegator, delegation.amount, &delegation.owner);
+				weight = weight
+					.saturating_add(<T as Config>::WeightInfo::increment_delegator_rewards())
+					.saturating_add(Self::do_compound_rewards(delegator, reward, Some(&delegation.owner)));
+			}
+
+			Ok(weight)
+		}
+
+		/// Stake the share of the given rewards the staker opted into via
+		/// `set_auto_compound`.
+		///
+		/// The share is taken from the accumulated rewards, minted into the
+		/// account and staked either for the given collator candidate of a
+		/// delegation or, if `None`, for the collator candidate itself. If
+		/// staking fails, e.g. because the candidate is leaving or would exceed
+		/// `MaxCollatorCandidateStake`, the rewards are kept untouched.
+		///
+		/// Returns the weight consumed by staking the rewards.
+		fn do_compound_rewards(acc: &T::AccountId, reward: BalanceOf<T>, collator: Option<&T::AccountId>) -> Weight {
+			use sp_runtime::PerThing;
+
+			let amount = AutoCompound::<T>::get(acc).mul_floor(reward);
+			if amount.is_zero() {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let result = frame_support::storage::with_storage_layer(|| -> Result<Weight, DispatchError> {
+				Rewards::<T>::try_mutate(acc, |rewards| -> DispatchResult {
+					*rewards = rewards.checked_sub(&amount).ok_or(Error::<T>::Underflow)?;
+					Ok(())
+				})?;
+				<T::Currency as Unbalanced<AccountIdOf<T>>>::increase_balance(acc, amount, Precision::Exact)?;
+
+				if let Some(collator) = collator {
+					Self::do_delegator_stake_more(acc, collator, amount)
+				} else {
+					Self::do_candidate_stake_more(acc, amount)
+				}
+			});
+
+			match result {
+				Ok(weight) => {
+					Self::deposit_event(Event::RewardsCompounded(acc.clone(), amount));
+					weight.saturating_add(T::DbWeight::get().reads_writes(3, 2))
+				}
+				// staking fails before mutating the stake, the worst case is reading all storage
+				Err(_) => match collator {
+					Some(_) => <T as Config>::WeightInfo::delegator_stake_more(
+						T::MaxTopCandidates::get(),
+						T::MaxDelegatorsPerCollator::get(),
+						T::MaxUnstakeRequests::get().saturated_into::<u32>(),
+					),
+					None => <T as Config>::WeightInfo::candidate_stake_more(
+						T::MaxTopCandidates::get(),
+						T::MaxDelegatorsPerCollator::get(),
+						T::MaxUnstakeRequests::get().saturated_into::<u32>(),
+					),
+				},
+			}
+		}
+
+		/// Defer a slash for the offence of a collator candidate and, if
+		/// configured, suspend the candidate.
+		///
+		/// If a slash is already pending, the higher share is slashed once the
+		/// latest offence can be applied.
+		///
+		/// Suspending removes an active candidate from the TopCandidates
+		/// unless this would leave less than `MinRequiredCollators` many.
+		///
+		/// Returns the number of collators and delegators for which the total
+		/// stake was updated.
+		fn do_report_offence(collator: &T::AccountId, offence: Offence) -> (u32, u32) {
+			let config = SlashingConfig::<T>::get();
+			let fraction = match offence {
+				Offence::Downtime => config.downtime_slash,
+				Offence::Equivocation => config.equivocation_slash,
+			};
+			let now = Round::<T>::get().current;
+			let apply_at = now.saturating_add(T::SlashDeferDuration::get());
+
+			if !fraction.is_zero() {
+				DeferredSlashes::<T>::mutate(collator, |maybe_slash| {
+					let fraction = maybe_slash
+						.as_ref()
+						.map_or(fraction, |slash| slash.fraction.max(fraction));
+					*maybe_slash = Some(DeferredSlash {
+						offence,
+						fraction,
+						apply_at,
+					});
+				});
+			}
+			Self::deposit_event(Event::OffenceReported(collator.clone(), offence, fraction, apply_at));
+
+			let Some(mut state) = CandidatePool::<T>::get(collator) else {
+				return (0u32, 0u32);
+			};
+			if config.suspension_rounds.is_zero() || state.is_leaving() {
+				return (0u32, 0u32);
+			}
+
+			let mut counts = (0u32, 0u32);
+			if state.is_active() {
+				let mut candidates = TopCandidates::<T>::get();
+				// never suspend the candidates required to keep the chain running
+				if candidates.len().saturated_into::<u32>() <= T::MinRequiredCollators::get() {
+					return counts;
+				}
+				if candidates
+					.remove(&Stake {
+						owner: collator.clone(),
+						amount: state.total,
+					})
+					.is_some()
+				{
+					TopCandidates::<T>::put(candidates);
+					Self::deposit_event(Event::LeftTopCandidates(collator.clone()));
+					counts = Self::update_total_stake();
+				}
+			}
+
+			let until = now.saturating_add(config.suspension_rounds);
+			state.suspend(until);
+			CandidatePool::<T>::insert(collator, state);
+
+			Self::deposit_event(Event::CandidateSuspended(collator.clone(), until));
+			counts
+		}
+
+		/// Take the number of blocks authored by each collator of the ending
+		/// session.
+		fn take_blocks_authored(session: SessionIndex) -> Vec<(T::AccountId, u32)> {
+			SessionCollators::<T>::take(session)
+				.map(|collators| {
+					collators
+						.into_iter()
+						.map(|collator| {
+							let count = BlocksAuthoredInRound::<T>::take(&collator);
+							(collator, count)
+						})
+						.collect()
+				})
+				.unwrap_or_default()
+		}
+
+		/// Record the stake of the collators of the starting round and prune
+		/// the statistics of the round which drops out of the history.
+		///
+		/// Returns the consumed weight.
+		fn note_round_start(round: SessionIndex) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			if let Some(expired) = round.checked_sub(T::MaxRoundHistory::get()) {
+				RoundHistory::<T>::remove(expired);
+				let removed = CollatorHistory::<T>::clear_prefix(expired, T::MaxTopCandidates::get(), None);
+				weight = weight.saturating_add(T::DbWeight::get().writes(u64::from(removed.unique).saturating_add(1)));
+			}
+
+			let collators = SessionCollators::<T>::get(round).unwrap_or_default();
+			for collator in collators.iter() {
+				if let Some(state) = CandidatePool::<T>::get(collator) {
+					CollatorHistory::<T>::insert(
+						round,
+						collator,
+						CollatorStats {
+							stake: state.stake,
+							delegators_stake: state.total.saturating_sub(state.stake),
+							commission: Self::commission_of(collator),
+							..Default::default()
+						},
+					);
+				}
+			}
+			RoundHistory::<T>::insert(
+				round,
+				RoundStats {
+					total_stake: TotalCollatorStake::<T>::get(),
+					..Default::default()
+				},
+			);
+
+			let num_collators = collators.len().saturated_into::<u64>();
+			weight.saturating_add(
+				T::DbWeight::get().reads_writes(num_collators.saturating_mul(3).saturating_add(1), num_collators),
+			)
+		}
+
+		/// Record the blocks authored in the ending round.
+		///
+		/// Returns the consumed weight.
+		fn note_round_end(round: SessionIndex, authored: &[(T::AccountId, u32)]) -> Weight {
+			let mut total_authored = 0u32;
+			for (collator, count) in authored {
+				total_authored = total_authored.saturating_add(*count);
+				CollatorHistory::<T>::mutate(round, collator, |maybe_stats| {
+					if let Some(stats) = maybe_stats {
+						stats.blocks_authored = *count;
+					}
+				});
+			}
+			RoundHistory::<T>::mutate(round, |maybe_stats| {
+				if let Some(stats) = maybe_stats {
+					stats.blocks_authored = total_authored;
+				}
 			});
+
+			let num_collators = authored.len().saturated_into::<u64>();
+			T::DbWeight::get().reads_writes(num_collators.saturating_add(1), num_collators.saturating_add(1))
+		}
+
+		/// Check which collators of the ending session authored less than
+		/// `min_authored_share` of the average number of blocks and report the
+		/// `Downtime` offence for those which have been offline for
+		/// `downtime_rounds` many consecutive rounds.
+		///
+		/// Returns the consumed weight.
+		fn check_downtime(authored: Vec<(T::AccountId, u32)>) -> Weight {
+			use sp_runtime::PerThing;
+
+			let config = SlashingConfig::<T>::get();
+			let num_collators = authored.len().saturated_into::<u32>();
+			let total_authored = authored
+				.iter()
+				.fold(0u32, |total, (_, count)| total.saturating_add(*count));
+			let min_authored = config
+				.min_authored_share
+				.mul_floor(total_authored.checked_div(num_collators).unwrap_or_default());
+
+			let mut weight = T::DbWeight::get().reads_writes(
+				u64::from(num_collators).saturating_add(2),
+				u64::from(num_collators).saturating_mul(2).saturating_add(1),
+			);
+			for (collator, count) in authored {
+				if count >= min_authored {
+					MissedRounds::<T>::remove(&collator);
+					continue;
+				}
+
+				let missed = MissedRounds::<T>::mutate(&collator, |missed| {
+					*missed = missed.saturating_add(1);
+					*missed
+				});
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+				if missed >= config.downtime_rounds.max(1) {
+					MissedRounds::<T>::remove(&collator);
+					let (num_collators, num_delegators) = Self::do_report_offence(&collator, Offence::Downtime);
+					weight = weight.saturating_add(<T as pallet::Config>::WeightInfo::report_equivocation(
+						num_collators,
+						num_delegators,
+					));
+				}
+			}
+			weight
+		}
+
+		/// Withdraw up to the given amount from the staked, i.e. frozen, funds
+		/// of an account.
+		///
+		/// The freeze is only lowered by the amount which was actually
+		/// withdrawn.
+		fn do_slash(who: &T::AccountId, amount: BalanceOf<T>) -> Result<CreditOf<T>, DispatchError> {
+			let freeze_id = <T as pallet::Config>::FreezeIdentifier::from(FreezeReason::Staking);
+			let set_freeze = |frozen: BalanceOf<T>| -> DispatchResult {
+				if frozen.is_zero() {
+					T::Currency::thaw(&freeze_id, who)
+				} else {
+					T::Currency::set_freeze(&freeze_id, who, frozen)
+				}
+			};
+
+			// lift the freeze of the slashed funds to be able to withdraw them
+			let frozen = T::Currency::balance_frozen(&freeze_id, who);
+			set_freeze(frozen.saturating_sub(amount))?;
+
+			let credit = T::Currency::withdraw(
+				who,
+				amount,
+				Precision::BestEffort,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			)?;
+
+			// funds which could not be withdrawn stay frozen
+			if credit.peek() < amount {
+				set_freeze(frozen.saturating_sub(credit.peek()))?;
+			}
+			Ok(credit)
+		}
+
+		/// Returns the account of a staking pool which delegates on behalf of
+		/// its members.
+		pub fn pool_account(pool_id: PoolId) -> T::AccountId {
+			T::PoolPalletId::get().into_sub_account_truncating(pool_id)
+		}
+
+		/// Returns the delay after which funds unbonded from a staking pool
+		/// can be unlocked.
+		///
+		/// The funds are unlocked at the end of the period of `StakeDuration`
+		/// blocks following the next `StakeDuration` blocks, such that a pool
+		/// has at most two unstaking requests which cannot be unlocked yet.
+		fn pool_unstake_delay() -> BlockNumberFor<T> {
+			let period = T::StakeDuration::get();
+			let earliest = frame_system::Pallet::<T>::block_number().saturating_add(period);
+			let remainder = earliest.checked_rem(&period).unwrap_or_default();
+			if remainder.is_zero() {
+				period
+			} else {
+				period.saturating_add(period.saturating_sub(remainder))
+			}
+		}
+
+		/// Returns the funds of a staking pool which are attributed to the
+		/// points of its members.
+		///
+		/// If the delegation of the pool was removed, e.g. because it was
+		/// replaced by a higher one, these are the funds of the pool account
+		/// which were not unbonded by members.
+		fn pool_bonded(pool_account: &T::AccountId, pool: &StakingPoolOf<T>) -> BalanceOf<T> {
+			if let Some(state) = DelegatorState::<T>::get(pool_account) {
+				state.total
+			} else {
+				<T::Currency as Inspect<AccountIdOf<T>>>::total_balance(pool_account).saturating_sub(pool.unbonding)
+			}
+		}
+
+		/// Convert an amount of funds into points of a staking pool.
+		///
+		/// Funds are converted at par if the pool has no points or stake.
+		fn balance_to_points(amount: BalanceOf<T>, points: BalanceOf<T>, bonded: BalanceOf<T>) -> BalanceOf<T> {
+			if points.is_zero() || bonded.is_zero() {
+				return amount;
+			}
+			multiply_by_rational_with_rounding(
+				amount.saturated_into(),
+				points.saturated_into(),
+				bonded.saturated_into(),
+				Rounding::Down,
+			)
+			.unwrap_or_default()
+			.saturated_into()
+		}
+
+		/// Convert points of a staking pool into the share of its funds.
+		fn points_to_balance(amount: BalanceOf<T>, points: BalanceOf<T>, bonded: BalanceOf<T>) -> BalanceOf<T> {
+			if points.is_zero() {
+				return BalanceOf::<T>::zero();
+			}
+			multiply_by_rational_with_rounding(
+				amount.saturated_into(),
+				bonded.saturated_into(),
+				points.saturated_into(),
+				Rounding::Down,
+			)
+			.unwrap_or_default()
+			.saturated_into()
+		}
+
+		/// Increment the rewards of the delegation of a staking pool and
+		/// distribute them to the points of the pool after crediting the
+		/// commission to the rewards of the operator.
+		///
+		/// If the pool has no points, all rewards are credited to the operator.
+		fn do_harvest_pool(pool_account: &T::AccountId, pool: &mut StakingPoolOf<T>) {
+			use sp_runtime::PerThing;
+
+			if let Some(state) = DelegatorState::<T>::get(pool_account) {
+				for delegation in state.delegations.iter() {
+					Self::do_inc_delegator_reward(pool_account, delegation.amount, &delegation.owner);
+				}
+			}
+
+			let rewards = Rewards::<T>::take(pool_account);
+			if rewards.is_zero() {
+				return;
+			}
+			let commission = pool.commission.mul_floor(rewards);
+			match FixedU128::checked_from_rational(
+				rewards.saturating_sub(commission).saturated_into::<u128>(),
+				pool.points.saturated_into::<u128>(),
+			) {
+				Some(per_point) => {
+					pool.reward_per_point = pool.reward_per_point.saturating_add(per_point);
+					if !commission.is_zero() {
+						Rewards::<T>::mutate(&pool.roles.operator, |r| *r = r.saturating_add(commission));
+					}
+				}
+				None => Rewards::<T>::mutate(&pool.roles.operator, |r| *r = r.saturating_add(rewards)),
+			}
+		}
+
+		/// Add the rewards of a pool member accrued since their last
+		/// settlement to their rewards.
+		///
+		/// Returns the amount of settled rewards.
+		fn do_settle_pool_rewards(
+			pool: &StakingPoolOf<T>,
+			member: &T::AccountId,
+			pool_member: &mut PoolMemberOf<T>,
+		) -> BalanceOf<T> {
+			let rewards: BalanceOf<T> = pool
+				.reward_per_point
+				.saturating_sub(pool_member.last_reward_per_point)
+				.saturating_mul_int(pool_member.points.saturated_into::<u128>())
+				.saturated_into();
+			pool_member.last_reward_per_point = pool.reward_per_point;
+			if !rewards.is_zero() {
+				Rewards::<T>::mutate(member, |r| *r = r.saturating_add(rewards));
+			}
+			rewards
+		}
+
+		/// Transfer funds which are neither frozen nor held between two
+		/// accounts.
+		fn do_transfer(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+			preservation: Preservation,
+		) -> DispatchResult {
+			let credit = T::Currency::withdraw(from, amount, Precision::Exact, preservation, Fortitude::Polite)?;
+			T::Currency::resolve(to, credit).map_err(|_| TokenError::BelowMinimum)?;
+			Ok(())
 		}
 	}
 
@@ -2535,10 +5041,13 @@ pub mod pallet {
 				BlocksAuthored::<T>::mutate(&author, |count| {
 					*count = count.saturating_add(authors.len().saturated_into::<BlockNumberFor<T>>());
 				});
+				BlocksAuthoredInRound::<T>::mutate(&author, |count| {
+					*count = count.saturating_add(1);
+				});
 			}
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::DbWeight::get().reads_writes(2, 1),
+				T::DbWeight::get().reads_writes(3, 2),
 				DispatchClass::Mandatory,
 			);
 		}
@@ -2559,27 +5068,42 @@ pub mod pallet {
 				frame_system::Pallet::<T>::block_number(),
 			);
 
+			// the identity of each top candidate is verified if identities are required
+			let identity_reads: u64 = if IdentityRequired::<T>::get() {
+				T::MaxTopCandidates::get().saturating_mul(2).into()
+			} else {
+				0
+			};
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::DbWeight::get().reads(2),
+				T::DbWeight::get().reads_writes(3u64.saturating_add(identity_reads), 1),
 				DispatchClass::Mandatory,
 			);
 
-			let collators = Pallet::<T>::selected_candidates().to_vec();
+			let collators = Pallet::<T>::selected_candidates();
 			if collators.is_empty() {
 				// we never want to pass an empty set of collators. This would brick the chain.
 				log::error!("💥 keeping old session because of empty collator set!");
 				None
 			} else {
-				Some(collators)
+				// remember the expected authors to detect downtime at the end of the session
+				SessionCollators::<T>::insert(new_index, &collators);
+				Some(collators.to_vec())
 			}
 		}
 
-		fn end_session(_end_index: SessionIndex) {
-			// we too are not caring.
+		/// Records the statistics of the ending session and checks its
+		/// collators for downtime.
+		fn end_session(end_index: SessionIndex) {
+			let authored = Pallet::<T>::take_blocks_authored(end_index);
+			let weight =
+				Pallet::<T>::note_round_end(end_index, &authored).saturating_add(Pallet::<T>::check_downtime(authored));
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
 		}
 
-		fn start_session(_start_index: SessionIndex) {
-			// we too are not caring.
+		/// Records the stake of the collators of the starting session.
+		fn start_session(start_index: SessionIndex) {
+			let weight = Pallet::<T>::note_round_start(start_index);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
 		}
 	}
 
@@ -2604,6 +5128,10 @@ pub mod pallet {
 				round.update(now);
 				Round::<T>::put(round);
 				Self::deposit_event(Event::NewRound(round.first, round.current));
+				frame_system::Pallet::<T>::register_extra_weight_unchecked(
+					Self::apply_inflation_schedule(now, round.current),
+					DispatchClass::Mandatory,
+				);
 				true
 			} else {
 				false
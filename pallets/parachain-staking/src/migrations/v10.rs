@@ -0,0 +1,179 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+#[cfg(feature = "try-runtime")]
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Saturating;
+
+use crate::{
+	types::{BalanceOf, Delegator, Stake},
+	BlocksRewarded, Config, DelegationBlocksRewarded,
+};
+
+#[cfg(feature = "try-runtime")]
+const LOG_TARGET: &str = "migration::parachain-staking";
+
+/// Collection of storage item formats from the previous storage version.
+///
+/// Required so we can read values in the v9 storage format during the
+/// migration.
+mod v9 {
+	use super::*;
+	use frame_support::{storage_alias, Twox64Concat};
+
+	/// V9 delegator state which backs exactly one collator.
+	pub type DelegatorOf<T> = Stake<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+	/// V9 type for [`crate::DelegatorState`].
+	#[storage_alias]
+	pub type DelegatorState<T: crate::Config> =
+		StorageMap<crate::Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, DelegatorOf<T>, OptionQuery>;
+}
+
+fn v9_to_v10<T: Config>(old_value: v9::DelegatorOf<T>) -> Option<crate::types::DelegatorOf<T>> {
+	let Stake { owner, amount } = old_value;
+	Delegator::try_new(owner, amount).ok()
+}
+
+pub struct InnerMigrateV9ToV10<T: crate::Config>(core::marker::PhantomData<T>);
+
+impl<T: crate::Config> UncheckedOnRuntimeUpgrade for InnerMigrateV9ToV10<T> {
+	/// Return a vector of existing [`crate::DelegatorState`] values so we can
+	/// check that they were correctly set in
+	/// `InnerMigrateV9ToV10::post_upgrade`.
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		let old_values: sp_std::vec::Vec<(T::AccountId, v9::DelegatorOf<T>, BlockNumberFor<T>)> =
+			v9::DelegatorState::<T>::iter()
+				.map(|(delegator, state)| {
+					let rewarded = BlocksRewarded::<T>::get(&delegator);
+					(delegator, state, rewarded)
+				})
+				.collect();
+		Ok(old_values.encode())
+	}
+
+	/// Migrate the storage from V9 to V10.
+	///
+	/// Each single delegation becomes the only entry of the new delegator
+	/// state and the counter of rewarded blocks of the delegator is moved to
+	/// [`crate::DelegationBlocksRewarded`].
+	fn on_runtime_upgrade() -> Weight {
+		let mut translated = 0u64;
+		crate::DelegatorState::<T>::translate::<v9::DelegatorOf<T>, _>(|delegator, old_value| {
+			translated.saturating_inc();
+			let collator = old_value.owner.clone();
+			let rewarded = BlocksRewarded::<T>::take(&delegator);
+			DelegationBlocksRewarded::<T>::insert(&delegator, &collator, rewarded);
+			v9_to_v10::<T>(old_value)
+		});
+
+		// One read for the old state and the rewarded counter, and three writes for
+		// the new state as well as moving the counter
+		T::DbWeight::get().reads_writes(translated.saturating_mul(2), translated.saturating_mul(3))
+	}
+
+	/// Verifies the storage was migrated correctly.
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use sp_runtime::traits::SaturatedConversion;
+
+		let old_values =
+			sp_std::vec::Vec::<(T::AccountId, v9::DelegatorOf<T>, BlockNumberFor<T>)>::decode(&mut &state[..])
+				.map_err(|_| sp_runtime::TryRuntimeError::Other("Failed to decode old value from storage"))?;
+
+		let prev_count: u32 = old_values.len().saturated_into();
+		let post_count: u32 = crate::DelegatorState::<T>::iter().count().saturated_into();
+
+		ensure!(
+			prev_count == post_count,
+			"the delegator count before and after the migration should be the same"
+		);
+
+		log::info!(target: LOG_TARGET, "Migrated {} delegator entries", post_count);
+
+		old_values.into_iter().try_for_each(|(delegator, old_value, rewarded)| {
+			let collator = old_value.owner.clone();
+			let expected_new_value = v9_to_v10::<T>(old_value);
+			let actual_new_value = crate::DelegatorState::<T>::get(&delegator);
+
+			ensure!(actual_new_value.is_some(), {
+				log::error!(target: LOG_TARGET, "Expected delegator {:?} but found none", &delegator);
+				sp_runtime::TryRuntimeError::Other("Delegator not migrated")
+			});
+			ensure!(actual_new_value == expected_new_value, {
+				log::error!(target: LOG_TARGET, "Delegator {:?} contains unexpected data", &delegator);
+				sp_runtime::TryRuntimeError::Other("Incorrect delegator data")
+			});
+			ensure!(DelegationBlocksRewarded::<T>::get(&delegator, &collator) == rewarded, {
+				log::error!(target: LOG_TARGET, "Rewarded blocks of delegator {:?} not migrated", &delegator);
+				sp_runtime::TryRuntimeError::Other("Incorrect rewarded blocks")
+			});
+			ensure!(!BlocksRewarded::<T>::contains_key(&delegator), {
+				log::error!(target: LOG_TARGET, "Rewarded blocks of delegator {:?} not removed", &delegator);
+				sp_runtime::TryRuntimeError::Other("Stale rewarded blocks")
+			});
+
+			Ok(())
+		})
+	}
+}
+
+pub type MigrateV9ToV10<T> = frame_support::migrations::VersionedMigration<
+	9,  // The migration will only execute when the on-chain storage version is 9
+	10, // The on-chain storage version will be set to 10 after the migration is complete
+	InnerMigrateV9ToV10<T>,
+	crate::pallet::Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	use super::*;
+	use crate::mock::{ExtBuilder, StakePallet, Test};
+
+	#[test]
+	fn migrates_single_delegation() {
+		ExtBuilder::default()
+			.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+			.with_collators(vec![(1, 20), (2, 20)])
+			.with_delegators(vec![(3, 1, 10)])
+			.build_and_execute_with_sanity_tests(|| {
+				// reset the delegator to the v9 layout
+				StorageVersion::new(9).put::<StakePallet>();
+				v9::DelegatorState::<Test>::insert(3, Stake { owner: 1, amount: 10 });
+				DelegationBlocksRewarded::<Test>::remove(3, 1);
+				BlocksRewarded::<Test>::insert(3, 7);
+
+				MigrateV9ToV10::<Test>::on_runtime_upgrade();
+
+				assert_eq!(StakePallet::on_chain_storage_version(), StorageVersion::new(10));
+				let state = crate::DelegatorState::<Test>::get(3).expect("Delegator should be migrated");
+				assert_eq!(state.total, 10);
+				assert_eq!(state.delegation_of(&1), Some(10));
+				assert_eq!(state.delegations.len(), 1);
+				assert_eq!(DelegationBlocksRewarded::<Test>::get(3, 1), 7);
+				assert!(!BlocksRewarded::<Test>::contains_key(3));
+			});
+	}
+}
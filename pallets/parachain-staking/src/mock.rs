@@ -24,6 +24,7 @@ use crate::{self as stake, types::CreditOf};
 use frame_support::{
 	assert_ok, construct_runtime, parameter_types,
 	traits::{fungible::Balanced, OnFinalize, OnInitialize, OnUnbalanced},
+	PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_authorship::EventHandler;
@@ -137,16 +138,27 @@ parameter_types! {
 	pub const MinBlocksPerRound: BlockNumber = 3;
 	pub const StakeDuration: u32 = 2;
 	pub const ExitQueueDelay: u32 = 2;
+	pub const CommissionChangeDelay: u32 = 2;
+	pub const SlashDeferDuration: u32 = 2;
+	pub const MaxRoundHistory: u32 = 3;
+	pub const MaxInflationSteps: u32 = 3;
+	pub const MaxCandidatesPerIdentity: u32 = 2;
+	pub const MaxAutoCompoundsPerBlock: u32 = 2;
+	pub const MaxInflationUpdatesPerBlock: u32 = 1;
 	pub const DefaultBlocksPerRound: BlockNumber = BLOCKS_PER_ROUND;
 	pub const MinCollators: u32 = 2;
 	pub const MaxDelegationsPerRound: u32 = 2;
 	#[derive(Debug, Eq, PartialEq)]
 	pub const MaxDelegatorsPerCollator: u32 = 4;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorsPerDelegator: u32 = 4;
 	pub const MinCollatorStake: Balance = 10;
 	#[derive(Debug, Eq, PartialEq)]
 	pub const MaxCollatorCandidates: u32 = 10;
 	pub const MinDelegatorStake: Balance = 5;
 	pub const MaxUnstakeRequests: u32 = 6;
+	pub const PoolPalletId: PalletId = PalletId(*b"kilt/spl");
+	pub const MinPoolJoin: Balance = 1;
 	pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(10);
 	pub const NetworkRewardStart: BlockNumber = 5 * 5 * 60 * 24 * 36525 / 100;
 }
@@ -159,6 +171,31 @@ impl OnUnbalanced<CreditOf<Test>> for ToBeneficiary {
 	}
 }
 
+#[frame_support::storage_alias]
+type Identities = StorageMap<IdentityMock, frame_support::Twox64Concat, AccountId, AccountId>;
+
+/// Provides the identities set via `set_identity`.
+pub(crate) struct MockIdentityProvider;
+
+impl crate::traits::CollatorIdentityProvider<AccountId> for MockIdentityProvider {
+	type Identity = AccountId;
+
+	fn identity_of(who: &AccountId) -> Option<AccountId> {
+		Identities::get(who)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn link_identity(who: &AccountId) -> AccountId {
+		set_identity(*who, Some(*who));
+		*who
+	}
+}
+
+/// Sets or removes the identity the account is linked to.
+pub(crate) fn set_identity(who: AccountId, identity: Option<AccountId>) {
+	Identities::set(who, identity);
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -167,18 +204,30 @@ impl Config for Test {
 	type DefaultBlocksPerRound = DefaultBlocksPerRound;
 	type StakeDuration = StakeDuration;
 	type ExitQueueDelay = ExitQueueDelay;
+	type CommissionChangeDelay = CommissionChangeDelay;
+	type SlashDeferDuration = SlashDeferDuration;
+	type MaxRoundHistory = MaxRoundHistory;
+	type MaxInflationSteps = MaxInflationSteps;
+	type MaxCandidatesPerIdentity = MaxCandidatesPerIdentity;
+	type MaxAutoCompoundsPerBlock = MaxAutoCompoundsPerBlock;
+	type MaxInflationUpdatesPerBlock = MaxInflationUpdatesPerBlock;
 	type MinCollators = MinCollators;
 	type MinRequiredCollators = MinCollators;
 	type MaxDelegationsPerRound = MaxDelegationsPerRound;
 	type MaxDelegatorsPerCollator = MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = MaxCollatorsPerDelegator;
 	type MinCollatorStake = MinCollatorStake;
 	type MinCollatorCandidateStake = MinCollatorStake;
 	type MaxTopCandidates = MaxCollatorCandidates;
 	type MinDelegatorStake = MinDelegatorStake;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
+	type PoolPalletId = PoolPalletId;
+	type MinPoolJoin = MinPoolJoin;
 	type NetworkRewardRate = NetworkRewardRate;
 	type NetworkRewardStart = NetworkRewardStart;
 	type NetworkRewardBeneficiary = ToBeneficiary;
+	type Slash = ToBeneficiary;
+	type IdentityProvider = MockIdentityProvider;
 	type WeightInfo = ();
 	type FreezeIdentifier = RuntimeFreezeReason;
 	const BLOCKS_PER_YEAR: BlockNumberFor<Test> = 5 * 60 * 24 * 36525 / 100;
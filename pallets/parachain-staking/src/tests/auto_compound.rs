@@ -0,0 +1,208 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! Unit testing
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::Zero, PerThing, Perbill};
+
+use crate::{
+	mock::{
+		events, last_event, roll_to, Balances, ExtBuilder, RuntimeOrigin, StakePallet, Test, BLOCKS_PER_ROUND, DECIMALS,
+	},
+	AutoCompound, AutoCompoundCursor, Error, Event, MaxCollatorCandidateStake,
+};
+
+#[test]
+fn set_auto_compound() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.with_delegators(vec![(3, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::set_auto_compound(RuntimeOrigin::signed(4), Perbill::from_percent(10)),
+				Error::<Test>::NotStaking
+			);
+
+			// collator
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(10)
+			));
+			assert_eq!(last_event(), Event::AutoCompoundSet(1, Perbill::from_percent(10)));
+			assert_eq!(StakePallet::auto_compound(1), Perbill::from_percent(10));
+			assert_eq!(StakePallet::get_auto_compound(&1), Perbill::from_percent(10));
+
+			// delegator
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(3),
+				Perbill::from_percent(100)
+			));
+			assert_eq!(StakePallet::auto_compound(3), Perbill::from_percent(100));
+
+			// disabling removes the entry
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(1),
+				Perbill::zero()
+			));
+			assert_eq!(last_event(), Event::AutoCompoundSet(1, Perbill::zero()));
+			assert!(!AutoCompound::<Test>::contains_key(1));
+			assert_eq!(StakePallet::get_auto_compound(&4), Perbill::zero());
+
+			// leaving removes the setting
+			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
+			assert!(!AutoCompound::<Test>::contains_key(3));
+		});
+}
+
+#[test]
+fn collator_compounds_rewards() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (3, stake)])
+		.with_delegators(vec![(2, 1, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			let inflation_config = StakePallet::inflation_config();
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(50)
+			));
+
+			roll_to(2, vec![None, Some(1)]);
+			let collator_rewards = inflation_config.collator.reward_rate.per_block * stake * 2;
+			let compounded = Perbill::from_percent(50).mul_floor(collator_rewards);
+			assert!(!compounded.is_zero());
+
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(last_event(), Event::RewardsCompounded(1, compounded));
+			assert_eq!(StakePallet::rewards(1), collator_rewards - compounded);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, stake + compounded);
+			assert_eq!(Balances::free_balance(1), stake + compounded);
+
+			// nothing left to compound
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(StakePallet::rewards(1), collator_rewards - compounded);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, stake + compounded);
+		});
+}
+
+#[test]
+fn delegator_compounds_rewards() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (3, stake)])
+		.with_delegators(vec![(2, 1, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			let inflation_config = StakePallet::inflation_config();
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(2),
+				Perbill::from_percent(100)
+			));
+
+			roll_to(2, vec![None, Some(1)]);
+			let delegator_rewards = inflation_config.delegator.reward_rate.per_block * stake * 2;
+			assert!(!delegator_rewards.is_zero());
+
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
+			assert_eq!(last_event(), Event::RewardsCompounded(2, delegator_rewards));
+			assert!(StakePallet::rewards(2).is_zero());
+			let state = StakePallet::delegator_state(2).unwrap();
+			assert_eq!(state.delegation_of(&1), Some(stake + delegator_rewards));
+			assert_eq!(state.total, stake + delegator_rewards);
+			assert_eq!(
+				StakePallet::candidate_pool(1).unwrap().total,
+				2 * stake + delegator_rewards
+			);
+		});
+}
+
+#[test]
+fn failed_compounding_keeps_rewards() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (3, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			let inflation_config = StakePallet::inflation_config();
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(100)
+			));
+			// staking more would exceed the maximum candidate stake
+			MaxCollatorCandidateStake::<Test>::put(stake);
+
+			roll_to(2, vec![None, Some(1)]);
+			let collator_rewards = inflation_config.collator.reward_rate.per_block * stake * 2;
+
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(StakePallet::rewards(1), collator_rewards);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, stake);
+			assert_eq!(Balances::free_balance(1), stake);
+		});
+}
+
+#[test]
+fn rewards_compounded_at_round_start() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake), (4, stake)])
+		.with_collators(vec![(1, stake), (3, stake)])
+		.with_delegators(vec![(2, 1, stake), (4, 3, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			(1..=4).for_each(|id| {
+				assert_ok!(StakePallet::set_auto_compound(
+					RuntimeOrigin::signed(id),
+					Perbill::from_percent(100)
+				));
+			});
+
+			roll_to(BLOCKS_PER_ROUND - 1, vec![None, Some(1), Some(3)]);
+			assert!(!AutoCompoundCursor::<Test>::exists());
+			(1..=4).for_each(|id| {
+				assert!(StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
+			});
+
+			// two accounts are processed per block once the round starts
+			roll_to(BLOCKS_PER_ROUND, vec![]);
+			assert_eq!(StakePallet::round().current, 1);
+			assert!(AutoCompoundCursor::<Test>::get().is_some());
+			roll_to(BLOCKS_PER_ROUND + 1, vec![]);
+			assert!(!AutoCompoundCursor::<Test>::exists());
+
+			let compounded: Vec<_> = events()
+				.into_iter()
+				.filter_map(|event| match event {
+					Event::RewardsCompounded(id, amount) => Some((id, amount)),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(compounded.len(), 4);
+			(1..=4).for_each(|id| {
+				assert!(StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
+			});
+			for (id, amount) in compounded {
+				assert!(!amount.is_zero(), "acc_id {:?}", id);
+				assert_eq!(Balances::free_balance(id), stake + amount);
+			}
+			assert!(StakePallet::candidate_pool(1).unwrap().stake > stake);
+			assert!(StakePallet::delegator_state(4).unwrap().total > stake);
+		});
+}
@@ -384,19 +384,19 @@ fn execute_leave_candidates_with_delay() {
 			}
 			assert_eq!(
 				StakePallet::delegator_state(11),
-				Some(Delegator::<AccountId, Balance> { owner: 1, amount: 110 })
+				Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator>::try_new(1, 110).ok()
 			);
 			assert_eq!(
 				StakePallet::delegator_state(12),
-				Some(Delegator::<AccountId, Balance> { owner: 1, amount: 120 })
+				Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator>::try_new(1, 120).ok()
 			);
 			assert_eq!(
 				StakePallet::delegator_state(13),
-				Some(Delegator::<AccountId, Balance> { owner: 2, amount: 130 })
+				Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator>::try_new(2, 130).ok()
 			);
 			assert_eq!(
 				StakePallet::delegator_state(14),
-				Some(Delegator::<AccountId, Balance> { owner: 2, amount: 140 })
+				Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator>::try_new(2, 140).ok()
 			);
 			for delegator in 11u64..=14u64 {
 				assert!(StakePallet::is_delegator(&delegator));
@@ -473,19 +473,19 @@ fn execute_leave_candidates_with_delay() {
 			}
 			assert_eq!(
 				StakePallet::delegator_state(11),
-				Some(Delegator::<AccountId, Balance> { owner: 1, amount: 110 })
+				Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator>::try_new(1, 110).ok()
 			);
 			assert_eq!(
 				StakePallet::delegator_state(12),
-				Some(Delegator::<AccountId, Balance> { owner: 1, amount: 120 })
+				Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator>::try_new(1, 120).ok()
 			);
 			assert_eq!(
 				StakePallet::delegator_state(13),
-				Some(Delegator::<AccountId, Balance> { owner: 2, amount: 130 })
+				Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator>::try_new(2, 130).ok()
 			);
 			assert_eq!(
 				StakePallet::delegator_state(14),
-				Some(Delegator::<AccountId, Balance> { owner: 2, amount: 140 })
+				Delegator::<AccountId, Balance, <Test as Config>::MaxCollatorsPerDelegator>::try_new(2, 140).ok()
 			);
 			for delegator in 11u64..=14u64 {
 				assert!(StakePallet::is_delegator(&delegator));
@@ -718,11 +718,11 @@ fn candidate_leaves() {
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(RuntimeOrigin::signed(12), 1),
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(12), 1, 1),
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(12), 1),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(12), 1, 1),
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_noop!(
@@ -1127,7 +1127,7 @@ fn prioritize_collators() {
 			);
 
 			// 7 decreases delegation
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 5, 10));
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![5, 3]);
 			assert_eq!(
 				StakePallet::top_candidates(),
@@ -1192,7 +1192,7 @@ fn update_total_stake_collators_stay() {
 					delegators: 150
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 1, 10));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -1200,7 +1200,7 @@ fn update_total_stake_collators_stay() {
 					delegators: 160
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(4), 5));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(4), 2, 5));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -1245,10 +1245,10 @@ fn update_total_stake_displace_collators() {
 					delegators: 105
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(8), 45)); // vec![(5, 1, 50), (6, 2, 50), (7, 3, 55), (8, 4, 10)]
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(8), 4, 45)); // vec![(5, 1, 50), (6, 2, 50), (7, 3, 55), (8, 4, 10)]
 
 			// 3 is pushed out by delegator staking less
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 45)); // vec![(5, 1, 50), (6, 2, 50), (7, 3, 10), (8, 4, 10)]
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 3, 45)); // vec![(5, 1, 50), (6, 2, 50), (7, 3, 10), (8, 4, 10)]
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -1342,7 +1342,7 @@ fn update_total_stake_no_collator_changes() {
 					delegators: 110
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(5), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(5), 1, 10));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -1358,7 +1358,7 @@ fn update_total_stake_no_collator_changes() {
 					delegators: 110
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 2, 10));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
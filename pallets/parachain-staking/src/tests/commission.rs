@@ -0,0 +1,227 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! Unit testing
+
+use frame_support::{assert_noop, assert_ok};
+use kilt_runtime_api_staking::CollatorCommission;
+use sp_runtime::{traits::Zero, DispatchError, PerThing, Perbill};
+
+use crate::{
+	mock::{last_event, roll_to, ExtBuilder, RuntimeOrigin, StakePallet, Test, DECIMALS},
+	CandidateCommission, Error, Event, MaxCommission,
+};
+
+#[test]
+fn set_commission() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.with_delegators(vec![(3, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::set_commission(RuntimeOrigin::signed(3), Perbill::from_percent(10)),
+				Error::<Test>::CandidateNotFound
+			);
+			// no commission allowed before governance sets the maximum
+			assert_noop!(
+				StakePallet::set_commission(RuntimeOrigin::signed(1), Perbill::from_percent(10)),
+				Error::<Test>::CommissionAboveMax
+			);
+			assert_noop!(
+				StakePallet::set_max_commission(RuntimeOrigin::signed(1), Perbill::from_percent(20)),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(StakePallet::set_max_commission(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(20)
+			));
+			assert_eq!(last_event(), Event::MaxCommissionSet(Perbill::from_percent(20)));
+			assert_noop!(
+				StakePallet::set_commission(RuntimeOrigin::signed(1), Perbill::from_percent(21)),
+				Error::<Test>::CommissionAboveMax
+			);
+
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(10)
+			));
+			assert_eq!(
+				last_event(),
+				Event::CommissionChangeScheduled(1, Perbill::from_percent(10), 2)
+			);
+			assert_eq!(
+				StakePallet::scheduled_commission(1),
+				Some((Perbill::from_percent(10), 2))
+			);
+			assert!(!CandidateCommission::<Test>::contains_key(1));
+
+			// the change can only be applied after CommissionChangeDelay many rounds
+			assert_noop!(
+				StakePallet::execute_commission_change(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::CommissionChangeTooEarly
+			);
+			assert_noop!(
+				StakePallet::execute_commission_change(RuntimeOrigin::signed(3), 2),
+				Error::<Test>::NoScheduledCommission
+			);
+			roll_to(10, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(RuntimeOrigin::signed(3), 1));
+			assert_eq!(last_event(), Event::CommissionSet(1, Perbill::from_percent(10)));
+			assert_eq!(StakePallet::commission(1), Perbill::from_percent(10));
+			assert_eq!(StakePallet::scheduled_commission(1), None);
+			assert_noop!(
+				StakePallet::execute_commission_change(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::NoScheduledCommission
+			);
+		});
+}
+
+#[test]
+fn commission_is_deducted_from_delegator_rewards() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (3, stake)])
+		.with_delegators(vec![(2, 1, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			let inflation_config = StakePallet::inflation_config();
+			MaxCommission::<Test>::put(Perbill::from_percent(50));
+			CandidateCommission::<Test>::insert(1, Perbill::from_percent(20));
+
+			roll_to(2, vec![None, Some(1)]);
+			let collator_rewards = inflation_config.collator.reward_rate.per_block * stake * 2;
+			let delegator_rewards = inflation_config.delegator.reward_rate.per_block * stake * 2;
+			let commission = Perbill::from_percent(20).mul_floor(delegator_rewards);
+			assert!(!commission.is_zero());
+
+			// the API should consider the commission
+			assert_eq!(
+				StakePallet::get_unclaimed_staking_rewards(&2),
+				delegator_rewards - commission
+			);
+			assert_eq!(
+				StakePallet::get_unclaimed_staking_rewards(&1),
+				collator_rewards + commission
+			);
+
+			// incrementing the delegator rewards credits the commission to the collator
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
+			assert_eq!(StakePallet::rewards(2), delegator_rewards - commission);
+			assert_eq!(StakePallet::rewards(1), commission);
+			assert_eq!(
+				StakePallet::get_unclaimed_staking_rewards(&1),
+				collator_rewards + commission
+			);
+
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(StakePallet::rewards(1), collator_rewards + commission);
+		});
+}
+
+#[test]
+fn commission_change_increments_delegator_rewards() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (3, stake)])
+		.with_delegators(vec![(2, 1, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			let inflation_config = StakePallet::inflation_config();
+			MaxCommission::<Test>::put(Perbill::from_percent(50));
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(50)
+			));
+
+			roll_to(2, vec![None, Some(1)]);
+			let delegator_rewards = inflation_config.delegator.reward_rate.per_block * stake * 2;
+
+			roll_to(10, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(RuntimeOrigin::signed(2), 1));
+			// rewards earned before the change are not subject to the new commission
+			assert_eq!(StakePallet::rewards(2), delegator_rewards);
+			assert!(StakePallet::rewards(1).is_zero());
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(2, 1),
+				StakePallet::blocks_authored(1)
+			);
+		});
+}
+
+#[test]
+fn commission_is_capped_by_max_commission() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			MaxCommission::<Test>::put(Perbill::from_percent(30));
+			CandidateCommission::<Test>::insert(1, Perbill::from_percent(30));
+			assert_eq!(StakePallet::commission_of(&1), Perbill::from_percent(30));
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(20)
+			));
+
+			assert_ok!(StakePallet::set_max_commission(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(10)
+			));
+			assert_eq!(StakePallet::commission_of(&1), Perbill::from_percent(10));
+			assert_eq!(
+				StakePallet::get_collator_commission(&1),
+				Some(CollatorCommission {
+					commission: Perbill::from_percent(10),
+					max_commission: Perbill::from_percent(10),
+					scheduled: Some((Perbill::from_percent(20), 2)),
+				})
+			);
+			assert_eq!(StakePallet::get_collator_commission(&3), None);
+
+			// the scheduled commission is capped as well
+			roll_to(10, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(RuntimeOrigin::signed(2), 1));
+			assert_eq!(StakePallet::commission(1), Perbill::from_percent(10));
+		});
+}
+
+#[test]
+fn leaving_candidate_removes_commission() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			MaxCommission::<Test>::put(Perbill::from_percent(50));
+			CandidateCommission::<Test>::insert(2, Perbill::from_percent(10));
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(2),
+				Perbill::from_percent(20)
+			));
+
+			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(2)));
+			assert_noop!(
+				StakePallet::set_commission(RuntimeOrigin::signed(2), Perbill::from_percent(30)),
+				Error::<Test>::CannotStakeIfLeaving
+			);
+			roll_to(15, vec![]);
+			assert_ok!(StakePallet::execute_leave_candidates(RuntimeOrigin::signed(2), 2));
+
+			assert!(!CandidateCommission::<Test>::contains_key(2));
+			assert_eq!(StakePallet::scheduled_commission(2), None);
+		});
+}
@@ -33,7 +33,7 @@ use crate::{
 	},
 	set::OrderedSet,
 	types::{BalanceOf, DelegationCounter, Stake, StakeOf},
-	Config, Error, Event, Event as StakeEvent,
+	Config, DelegationBlocksRewarded, Error, Event, Event as StakeEvent,
 };
 
 #[test]
@@ -124,8 +124,13 @@ fn multiple_delegations() {
 			// 11 should be initiated with the same rewarded counter as the authored counter
 			// by their collator 2
 			assert_eq!(StakePallet::blocks_rewarded(2), StakePallet::blocks_authored(11));
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(11, 2),
+				StakePallet::blocks_authored(2)
+			);
 
 			assert!(StakePallet::delegator_state(9).is_none());
+			assert!(!DelegationBlocksRewarded::<Test>::contains_key(9, 2));
 			assert_eq!(StakePallet::unstaking(9).get(&23), Some(&10u128));
 			assert!(!StakePallet::candidate_pool(2)
 				.unwrap()
@@ -175,8 +180,8 @@ fn multiple_delegations() {
 				.unwrap()
 				.delegators
 				.contains(&StakeOf::<Test> { owner: 17, amount: 11 }));
-			assert_eq!(StakePallet::delegator_state(8).unwrap().amount, 10);
-			assert_eq!(StakePallet::delegator_state(17).unwrap().amount, 11);
+			assert_eq!(StakePallet::delegator_state(8).unwrap().total, 10);
+			assert_eq!(StakePallet::delegator_state(17).unwrap().total, 11);
 			assert_eq!(Balances::usable_balance(8), 90);
 			assert_eq!(Balances::usable_balance(17), 89);
 			assert_eq!(Balances::balance(&8), 100);
@@ -234,24 +239,24 @@ fn delegators_bond() {
 				Error::<Test>::AlreadyDelegating
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(RuntimeOrigin::signed(1), 50),
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(1), 2, 50),
 				Error::<Test>::DelegatorNotFound
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(1), 50),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(1), 2, 50),
 				Error::<Test>::DelegatorNotFound
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 11),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 1, 11),
 				Error::<Test>::Underflow
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 8),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 1, 8),
 				Error::<Test>::DelegationBelowMin
 			);
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 1, 10));
 			assert_noop!(
-				StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 81),
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 1, 81),
 				BalancesError::<Test>::InsufficientBalance
 			);
 			assert_noop!(
@@ -330,14 +335,14 @@ fn kick_delegator_with_full_unstaking() {
 			// Fill unstake requests
 			for block in 1u64..1u64.saturating_add(max_unstake_reqs as u64) {
 				System::set_block_number(block);
-				assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 1));
+				assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 1, 1));
 			}
 			assert_eq!(StakePallet::unstaking(5).into_inner().len(), max_unstake_reqs);
 
 			// Additional unstake should fail
 			System::set_block_number(100);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 1),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 1, 1),
 				Error::<Test>::NoMoreUnstaking
 			);
 
@@ -356,6 +361,56 @@ fn kick_delegator_with_full_unstaking() {
 		});
 }
 
+#[test]
+fn delegate_another_candidate_with_full_unstaking() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 200),
+			(2, 200),
+			(3, 200),
+			(4, 200),
+			(5, 420),
+			(6, 200),
+			(7, 100),
+		])
+		.with_collators(vec![(1, 200), (7, 10)])
+		.with_delegators(vec![(2, 1, 200), (3, 1, 200), (4, 1, 200), (5, 7, 200)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(
+				RuntimeOrigin::signed(5),
+				1,
+				150
+			));
+			let max_unstake_reqs: usize = <Test as Config>::MaxUnstakeRequests::get()
+				.saturating_sub(1)
+				.saturated_into();
+			// Fill unstake requests
+			for block in 1u64..1u64.saturating_add(max_unstake_reqs as u64) {
+				System::set_block_number(block);
+				assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 7, 1));
+			}
+
+			// Fill last unstake request by replacing the delegation to 1
+			System::set_block_number(100);
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(6), 1, 200));
+			assert_eq!(StakePallet::unstaking(5).into_inner().len(), max_unstake_reqs + 1);
+			assert_eq!(StakePallet::delegator_state(5).unwrap().delegation_of(&1), None);
+
+			// Cannot delegate another candidate with full unstaking
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(5), 1, 201),
+				Error::<Test>::CannotJoinBeforeUnlocking
+			);
+			System::set_block_number(200);
+			assert_ok!(StakePallet::unlock_unstaked(RuntimeOrigin::signed(5), 5));
+			assert_ok!(StakePallet::delegate_another_candidate(
+				RuntimeOrigin::signed(5),
+				1,
+				201
+			));
+		});
+}
+
 #[test]
 fn exceed_delegations_per_round() {
 	ExtBuilder::default()
@@ -450,7 +505,7 @@ fn prioritize_delegators() {
 			);
 
 			// delegate_less
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 2, 10));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -466,7 +521,7 @@ fn prioritize_delegators() {
 			);
 
 			// delegate_more
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 2, 10));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -480,7 +535,7 @@ fn prioritize_delegators() {
 					.unwrap()
 				)
 			);
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 2, 10));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -542,3 +597,204 @@ fn replace_lowest_delegator() {
 			assert!(StakePallet::delegator_state(6).is_some());
 		})
 }
+
+#[test]
+fn delegate_another_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 100),
+			(2, 100),
+			(3, 100),
+			(4, 100),
+			(5, 100),
+			(6, 100),
+			(7, 200),
+			(8, 100),
+		])
+		.with_collators(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+		.with_delegators(vec![(6, 1, 10), (7, 1, 10)])
+		.set_blocks_per_round(5)
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(8), 2, 10),
+				Error::<Test>::NotYetDelegating
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(7), 1, 10),
+				Error::<Test>::AlreadyDelegatedCollator
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(7), 2, 4),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(7), 99, 10),
+				Error::<Test>::CandidateNotFound
+			);
+
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(7), 2, 20));
+			assert_eq!(last_event(), StakeEvent::Delegation(7, 20, 2, 120));
+			let state = StakePallet::delegator_state(7).unwrap();
+			assert_eq!(state.total, 30);
+			assert_eq!(state.delegations.len(), 2);
+			assert_eq!(state.delegation_of(&1), Some(10));
+			assert_eq!(state.delegation_of(&2), Some(20));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 120);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 120);
+			assert!(StakePallet::candidate_pool(2)
+				.unwrap()
+				.delegators
+				.contains(&StakeOf::<Test> { owner: 7, amount: 20 }));
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(7, 2),
+				StakePallet::blocks_authored(2)
+			);
+			// the freeze covers all delegations
+			assert_eq!(Balances::usable_balance(7), 170);
+
+			// reached max delegations in this round
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(7), 3, 10),
+				Error::<Test>::DelegationsPerRoundExceeded
+			);
+			roll_to(5, vec![]);
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(7), 3, 10));
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(7), 4, 10));
+			assert_eq!(StakePallet::delegator_state(7).unwrap().total, 50);
+			assert_eq!(Balances::usable_balance(7), 150);
+
+			// reached MaxCollatorsPerDelegator
+			roll_to(10, vec![]);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(7), 5, 10),
+				Error::<Test>::MaxCollatorsPerDelegatorExceeded
+			);
+		});
+}
+
+#[test]
+fn revoke_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 100), (2, 100)])
+		.with_delegators(vec![(3, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(3), 2, 20));
+			assert_noop!(
+				StakePallet::revoke_delegation(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::DelegatorNotFound
+			);
+			assert_noop!(
+				StakePallet::revoke_delegation(RuntimeOrigin::signed(3), 99),
+				Error::<Test>::DelegationNotFound
+			);
+
+			// revoking a single delegation keeps the delegator state
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(3), 1));
+			assert_eq!(last_event(), StakeEvent::DelegatorLeftCollator(3, 1, 10, 100));
+			let state = StakePallet::delegator_state(3).unwrap();
+			assert_eq!(state.total, 20);
+			assert_eq!(state.delegation_of(&1), None);
+			assert_eq!(state.delegation_of(&2), Some(20));
+			assert!(StakePallet::candidate_pool(1).unwrap().delegators.is_empty());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
+			assert_eq!(StakePallet::unstaking(3).get(&3), Some(&10));
+			// funds remain frozen until unlocked
+			assert_eq!(Balances::usable_balance(3), 70);
+
+			// revoking the last delegation removes the delegator
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(3), 2));
+			assert_eq!(last_event(), StakeEvent::DelegatorLeft(3, 20));
+			assert!(StakePallet::delegator_state(3).is_none());
+			assert!(StakePallet::candidate_pool(2).unwrap().delegators.is_empty());
+			assert_eq!(StakePallet::unstaking(3).get(&3), Some(&30));
+		});
+}
+
+#[test]
+fn stake_per_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+		.with_collators(vec![(1, 100), (2, 100), (4, 100)])
+		.with_delegators(vec![(3, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(3), 2, 20));
+
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 2, 10));
+			let state = StakePallet::delegator_state(3).unwrap();
+			assert_eq!(state.total, 40);
+			assert_eq!(state.delegation_of(&1), Some(10));
+			assert_eq!(state.delegation_of(&2), Some(30));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 110);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 130);
+			assert_eq!(Balances::usable_balance(3), 60);
+
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 1, 5));
+			let state = StakePallet::delegator_state(3).unwrap();
+			assert_eq!(state.total, 35);
+			assert_eq!(state.delegation_of(&1), Some(5));
+			assert_eq!(state.delegation_of(&2), Some(30));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 105);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 130);
+			assert_eq!(StakePallet::unstaking(3).get(&3), Some(&5));
+
+			assert_noop!(
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 1, 1),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_noop!(
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 4, 1),
+				Error::<Test>::DelegationNotFound
+			);
+			assert_noop!(
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 4, 1),
+				Error::<Test>::DelegationNotFound
+			);
+		});
+}
+
+#[test]
+fn leave_delegators_with_multiple_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 100), (2, 100)])
+		.with_delegators(vec![(3, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(3), 2, 20));
+			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
+			assert_eq!(last_event(), StakeEvent::DelegatorLeft(3, 30));
+			assert!(StakePallet::delegator_state(3).is_none());
+			assert!(StakePallet::candidate_pool(1).unwrap().delegators.is_empty());
+			assert!(StakePallet::candidate_pool(2).unwrap().delegators.is_empty());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 100);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 100);
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 2).is_zero());
+			assert_eq!(StakePallet::unstaking(3).get(&3), Some(&30));
+			assert_eq!(Balances::usable_balance(3), 70);
+		});
+}
+
+#[test]
+fn candidate_leaving_keeps_other_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 100), (2, 100), (3, 100)])
+		.with_delegators(vec![(4, 1, 10)])
+		.set_blocks_per_round(5)
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(4), 2, 20));
+			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(2)));
+			roll_to(15, vec![]);
+			assert_ok!(StakePallet::execute_leave_candidates(RuntimeOrigin::signed(2), 2));
+
+			let state = StakePallet::delegator_state(4).unwrap();
+			assert_eq!(state.total, 10);
+			assert_eq!(state.delegation_of(&1), Some(10));
+			assert_eq!(state.delegation_of(&2), None);
+			assert!(StakePallet::candidate_pool(1)
+				.unwrap()
+				.delegators
+				.contains(&StakeOf::<Test> { owner: 4, amount: 10 }));
+		});
+}
@@ -0,0 +1,181 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+//! Unit testing
+
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use proptest::prelude::*;
+use sp_std::collections::btree_set::BTreeSet;
+
+use crate::{
+	mock::{
+		last_event, roll_to, AccountId, Balances, BlockNumber, ExtBuilder, RuntimeOrigin, Session, StakePallet, System,
+		Test,
+	},
+	Config, Error, Event as StakeEvent,
+};
+
+#[test]
+fn fast_unstake_from_unselected_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+		.with_collators(vec![(1, 50), (2, 40), (3, 10)])
+		.with_delegators(vec![(4, 3, 20), (5, 1, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_eq!(StakePallet::fast_unstake_rounds(), 1);
+			assert!(StakePallet::can_fast_unstake(&4, &3));
+			assert!(!StakePallet::can_fast_unstake(&5, &1));
+
+			assert_noop!(
+				StakePallet::fast_unstake(RuntimeOrigin::signed(5), 1),
+				Error::<Test>::FastUnstakeNotAllowed
+			);
+			assert_noop!(
+				StakePallet::fast_unstake(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::DelegationNotFound
+			);
+			assert_noop!(
+				StakePallet::fast_unstake(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::DelegatorNotFound
+			);
+
+			assert_ok!(StakePallet::fast_unstake(RuntimeOrigin::signed(4), 3));
+			assert_eq!(last_event(), StakeEvent::DelegatorLeft(4, 20));
+			assert!(StakePallet::delegator_state(4).is_none());
+			assert!(StakePallet::unstaking(4).is_empty());
+			assert_eq!(Balances::usable_balance(4), 100);
+			assert_eq!(StakePallet::candidate_pool(3).unwrap().total, 10);
+		});
+}
+
+#[test]
+fn fast_unstake_after_leaving_top_candidates() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 80), (2, 40), (3, 30)])
+		.with_delegators(vec![(4, 2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			// collator 2 drops out of the selected candidates
+			assert_ok!(StakePallet::candidate_stake_more(RuntimeOrigin::signed(3), 40));
+			assert!(!StakePallet::can_fast_unstake(&4, &2));
+
+			// collator 2 is still selected for the next round
+			roll_to(5, vec![]);
+			assert!(!StakePallet::can_fast_unstake(&4, &2));
+
+			// collator 2 was selected in the previous round
+			roll_to(10, vec![]);
+			assert!(Session::validators().iter().all(|collator| *collator != 2));
+			assert!(!StakePallet::can_fast_unstake(&4, &2));
+
+			roll_to(15, vec![]);
+			assert!(StakePallet::can_fast_unstake(&4, &2));
+			assert_ok!(StakePallet::fast_unstake(RuntimeOrigin::signed(4), 2));
+			assert_eq!(Balances::usable_balance(4), 100);
+		});
+}
+
+#[test]
+fn fast_unstake_respects_restaked_funds() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 50), (2, 40), (3, 10)])
+		.with_delegators(vec![(4, 1, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			// the funds of the delegation to the selected collator are unstaking
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(4), 1));
+			assert_eq!(StakePallet::unstaking(4).get(&3), Some(&20));
+
+			// restake the unstaking funds for the unselected candidate
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(4), 3, 20));
+			assert!(StakePallet::unstaking(4).is_empty());
+			assert_eq!(StakePallet::restaked_until(4), Some(3));
+			assert_noop!(
+				StakePallet::fast_unstake(RuntimeOrigin::signed(4), 3),
+				Error::<Test>::FastUnstakeNotAllowed
+			);
+
+			// the funds would have been unstaked by now
+			roll_to(3, vec![]);
+			assert_ok!(StakePallet::fast_unstake(RuntimeOrigin::signed(4), 3));
+			assert_eq!(StakePallet::restaked_until(4), None);
+			assert_eq!(Balances::usable_balance(4), 100);
+		});
+}
+
+const DELEGATOR: AccountId = 10;
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(64))]
+
+	/// Fast unstaking never releases a delegation to a collator which authored
+	/// blocks in the last `StakeDuration` blocks or is queued for the next
+	/// session.
+	#[test]
+	fn fast_unstake_never_releases_selected_stake(
+		target in 1u64..=4,
+		stake_changes in prop::collection::vec((1u64..=4, 0u128..=60), 1..6),
+		blocks in 1u64..=30,
+	) {
+		ExtBuilder::default()
+			.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000), (DELEGATOR, 100)])
+			.with_collators(vec![(1, 50), (2, 40), (3, 30), (4, 20)])
+			.with_delegators(vec![(DELEGATOR, target, 20)])
+			.build()
+			.execute_with(|| -> Result<(), TestCaseError> {
+				// remember the selected collators of each block
+				let mut selected: Vec<(BlockNumber, Vec<AccountId>)> = vec![(0, Session::validators())];
+				let mut changes = stake_changes.iter();
+				while System::block_number() < blocks {
+					// change the stake of a candidate at the start of each round
+					if StakePallet::round().first == System::block_number() {
+						if let Some((candidate, more)) = changes.next() {
+							if *more > 0 {
+								assert_ok!(StakePallet::candidate_stake_more(RuntimeOrigin::signed(*candidate), *more));
+							}
+						}
+					}
+					roll_to(System::block_number() + 1, vec![]);
+					selected.push((System::block_number(), Session::validators()));
+				}
+
+				let now = System::block_number();
+				let window_start = now.saturating_sub(<Test as Config>::StakeDuration::get());
+				let backed_selected = selected
+					.iter()
+					.filter(|(block, _)| *block >= window_start)
+					.flat_map(|(_, collators)| collators.iter().copied())
+					.chain(Session::queued_keys().into_iter().map(|(collator, _)| collator))
+					.collect::<BTreeSet<AccountId>>()
+					.contains(&target);
+
+				if StakePallet::can_fast_unstake(&DELEGATOR, &target) {
+					prop_assert!(!backed_selected);
+					assert_ok!(StakePallet::fast_unstake(RuntimeOrigin::signed(DELEGATOR), target));
+					prop_assert_eq!(Balances::usable_balance(DELEGATOR), 100);
+					prop_assert!(StakePallet::delegator_state(DELEGATOR).is_none());
+				} else {
+					assert_noop!(
+						StakePallet::fast_unstake(RuntimeOrigin::signed(DELEGATOR), target),
+						Error::<Test>::FastUnstakeNotAllowed
+					);
+				}
+				crate::try_state::do_try_state::<Test>().expect("Sanity test for parachain staking failed.");
+				Ok(())
+			})?;
+	}
+}
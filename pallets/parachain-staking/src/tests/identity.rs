@@ -0,0 +1,212 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+//! Unit testing
+
+use frame_support::{assert_noop, assert_ok};
+
+use crate::{
+	mock::{events, last_event, set_identity, ExtBuilder, RuntimeOrigin, StakePallet, Test},
+	Error, Event as StakeEvent,
+};
+
+#[test]
+fn link_and_unlink_identity() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::link_identity(RuntimeOrigin::signed(3)),
+				Error::<Test>::CandidateNotFound
+			);
+			assert_noop!(
+				StakePallet::link_identity(RuntimeOrigin::signed(1)),
+				Error::<Test>::IdentityNotFound
+			);
+
+			set_identity(1, Some(11));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(1)));
+			assert_eq!(last_event(), StakeEvent::IdentityLinked(1, 11));
+			assert_eq!(StakePallet::candidate_identity(1), Some(11));
+			assert_eq!(StakePallet::verified_identity(&1), Some(11));
+			assert!(StakePallet::is_identity_linked(&11));
+
+			// relinking replaces the previous identity
+			set_identity(1, Some(12));
+			assert_eq!(StakePallet::verified_identity(&1), None);
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(1)));
+			assert_eq!(StakePallet::verified_identity(&1), Some(12));
+			assert!(!StakePallet::is_identity_linked(&11));
+			assert!(StakePallet::is_identity_linked(&12));
+
+			assert_noop!(
+				StakePallet::unlink_identity(RuntimeOrigin::signed(2)),
+				Error::<Test>::IdentityNotLinked
+			);
+			assert_ok!(StakePallet::unlink_identity(RuntimeOrigin::signed(1)));
+			assert_eq!(last_event(), StakeEvent::IdentityUnlinked(1));
+			assert_eq!(StakePallet::candidate_identity(1), None);
+			assert!(!StakePallet::is_identity_linked(&12));
+		});
+}
+
+#[test]
+fn join_candidates_links_identity() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			set_identity(3, Some(13));
+			assert_ok!(StakePallet::join_candidates(RuntimeOrigin::signed(3), 20));
+			assert_eq!(last_event(), StakeEvent::IdentityLinked(3, 13));
+			assert_eq!(StakePallet::candidate_identity(3), Some(13));
+
+			// accounts without an identity can join as long as it is not required
+			assert_ok!(StakePallet::join_candidates(RuntimeOrigin::signed(4), 20));
+			assert_eq!(StakePallet::candidate_identity(4), None);
+		});
+}
+
+#[test]
+fn identity_requirement() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+		.with_collators(vec![(1, 50), (2, 40), (3, 30)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::set_identity_requirement(RuntimeOrigin::signed(1), true),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			set_identity(2, Some(12));
+			set_identity(3, Some(13));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(2)));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(3)));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+
+			assert_ok!(StakePallet::set_identity_requirement(RuntimeOrigin::root(), true));
+			assert_eq!(last_event(), StakeEvent::IdentityRequirementSet(true));
+			assert!(StakePallet::identity_required());
+
+			// candidates without an identity are skipped during the selection
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![2, 3]);
+			assert_noop!(
+				StakePallet::join_candidates(RuntimeOrigin::signed(4), 20),
+				Error::<Test>::IdentityRequired
+			);
+			assert_noop!(
+				StakePallet::unlink_identity(RuntimeOrigin::signed(2)),
+				Error::<Test>::IdentityRequired
+			);
+
+			// outdated identities do not count
+			set_identity(2, None);
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![3]);
+			set_identity(2, Some(22));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![3]);
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(2)));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![2, 3]);
+
+			assert_ok!(StakePallet::set_identity_requirement(RuntimeOrigin::root(), false));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+		});
+}
+
+#[test]
+fn removing_candidate_unlinks_identity() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			set_identity(3, Some(13));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(3)));
+			assert_eq!(
+				StakePallet::get_candidate_identities()
+					.into_iter()
+					.find(|(collator, _)| *collator == 3),
+				Some((3, Some(13)))
+			);
+
+			assert_ok!(StakePallet::force_remove_candidate(RuntimeOrigin::root(), 3));
+			assert_eq!(StakePallet::candidate_identity(3), None);
+			assert!(!StakePallet::is_identity_linked(&13));
+			assert!(StakePallet::get_candidate_identities()
+				.into_iter()
+				.all(|(collator, _)| collator != 3));
+		});
+}
+
+#[test]
+fn deleting_identity_unlinks_candidates() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			set_identity(1, Some(11));
+			set_identity(2, Some(11));
+			set_identity(3, Some(13));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(1)));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(2)));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(3)));
+
+			StakePallet::unlink_identity_candidates(&11);
+			assert!(
+				events().ends_with(&[StakeEvent::IdentityUnlinked(1), StakeEvent::IdentityUnlinked(2)])
+					|| events().ends_with(&[StakeEvent::IdentityUnlinked(2), StakeEvent::IdentityUnlinked(1)])
+			);
+			assert_eq!(StakePallet::candidate_identity(1), None);
+			assert_eq!(StakePallet::candidate_identity(2), None);
+			assert!(!StakePallet::is_identity_linked(&11));
+			assert_eq!(StakePallet::candidate_identity(3), Some(13));
+			assert!(StakePallet::is_identity_linked(&13));
+		});
+}
+
+#[test]
+fn candidates_per_identity_are_bounded() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (2, 20), (3, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			set_identity(1, Some(11));
+			set_identity(2, Some(11));
+			set_identity(3, Some(11));
+			set_identity(4, Some(11));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(1)));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(2)));
+			assert_noop!(
+				StakePallet::link_identity(RuntimeOrigin::signed(3)),
+				Error::<Test>::TooManyIdentityCandidates
+			);
+			assert_noop!(
+				StakePallet::join_candidates(RuntimeOrigin::signed(4), 20),
+				Error::<Test>::TooManyIdentityCandidates
+			);
+
+			// relinking does not count twice
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(2)));
+
+			assert_ok!(StakePallet::unlink_identity(RuntimeOrigin::signed(2)));
+			assert_ok!(StakePallet::link_identity(RuntimeOrigin::signed(3)));
+			assert_eq!(StakePallet::candidate_identity(3), Some(11));
+
+			StakePallet::unlink_identity_candidates(&11);
+			assert!(!StakePallet::is_identity_linked(&11));
+			assert_ok!(StakePallet::join_candidates(RuntimeOrigin::signed(4), 20));
+			assert_eq!(StakePallet::candidate_identity(4), Some(11));
+		});
+}
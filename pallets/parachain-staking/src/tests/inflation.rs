@@ -23,10 +23,23 @@ use pallet_authorship::EventHandler;
 use sp_runtime::{traits::Zero, Perquintill};
 
 use crate::{
-	mock::{roll_to_claim_rewards, ExtBuilder, RuntimeOrigin, StakePallet, System, Test, DECIMALS},
-	Config, Error, InflationInfo, RewardRate, StakingInfo,
+	mock::{
+		events, last_event, roll_to, roll_to_claim_rewards, ExtBuilder, RuntimeOrigin, StakePallet, System, Test,
+		DECIMALS,
+	},
+	Config, Error, Event as StakeEvent, InflationInfo, InflationStep, PendingInflationStep, RewardRate, StakingInfo,
 };
 
+fn inflation_step(start: u64, percent: u64) -> InflationStep<u64> {
+	InflationStep {
+		start,
+		collator_max_rate: Perquintill::from_percent(percent),
+		collator_reward_rate: Perquintill::from_percent(percent),
+		delegator_max_rate: Perquintill::from_percent(percent),
+		delegator_reward_rate: Perquintill::from_percent(percent),
+	}
+}
+
 #[test]
 fn rewards_set_inflation() {
 	let hundred = Perquintill::from_percent(100);
@@ -57,9 +70,18 @@ fn rewards_set_inflation() {
 			));
 			// rewards and counters should be set
 			(1..=5).for_each(|id| {
-				assert!(!StakePallet::blocks_rewarded(id).is_zero(), "acc_id {:?}", id);
 				assert!(!StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
 			});
+			(1..=2).for_each(|id| {
+				assert!(!StakePallet::blocks_rewarded(id).is_zero(), "acc_id {:?}", id);
+			});
+			[(3, 1), (4, 1), (5, 2)].into_iter().for_each(|(id, collator)| {
+				assert!(
+					!StakePallet::delegation_blocks_rewarded(id, collator).is_zero(),
+					"acc_id {:?}",
+					id
+				);
+			});
 		});
 }
 
@@ -95,12 +117,21 @@ fn rewards_yearly_inflation_adjustment() {
 				2
 			));
 			(1..=5).for_each(|id| {
+				assert!(!StakePallet::rewards(id).is_zero(), "Zero rewards for acc_id {:?}", id);
+			});
+			(1..=2).for_each(|id| {
 				assert!(
 					!StakePallet::blocks_rewarded(id).is_zero(),
 					"Zero rewarded blocks for acc_id {:?}",
 					id
 				);
-				assert!(!StakePallet::rewards(id).is_zero(), "Zero rewards for acc_id {:?}", id);
+			});
+			[(3, 1), (4, 1), (5, 2)].into_iter().for_each(|(id, collator)| {
+				assert!(
+					!StakePallet::delegation_blocks_rewarded(id, collator).is_zero(),
+					"Zero rewarded blocks for acc_id {:?}",
+					id
+				);
 			});
 		});
 }
@@ -185,3 +216,171 @@ fn too_small_candidate_size_provided_for_new_inflation() {
 			);
 		});
 }
+
+#[test]
+fn set_inflation_schedule() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10), (2, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let schedule = vec![inflation_step(3, 10), inflation_step(7, 20)];
+			assert_ok!(StakePallet::set_inflation_schedule(
+				RuntimeOrigin::root(),
+				schedule.clone().try_into().unwrap()
+			));
+			assert_eq!(StakePallet::inflation_schedule().into_inner(), schedule);
+			assert_eq!(last_event(), StakeEvent::InflationScheduleSet(2));
+
+			// an empty schedule removes all steps
+			assert_ok!(StakePallet::set_inflation_schedule(
+				RuntimeOrigin::root(),
+				vec![].try_into().unwrap()
+			));
+			assert!(StakePallet::inflation_schedule().is_empty());
+		});
+}
+
+#[test]
+fn set_invalid_inflation_schedule() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10), (2, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			System::set_block_number(5);
+
+			// steps have to start in the future
+			assert_noop!(
+				StakePallet::set_inflation_schedule(
+					RuntimeOrigin::root(),
+					vec![inflation_step(5, 10)].try_into().unwrap()
+				),
+				Error::<Test>::InvalidSchedule
+			);
+			// steps have to be sorted by their start
+			assert_noop!(
+				StakePallet::set_inflation_schedule(
+					RuntimeOrigin::root(),
+					vec![inflation_step(10, 10), inflation_step(8, 20)].try_into().unwrap()
+				),
+				Error::<Test>::InvalidSchedule
+			);
+			// steps cannot start at the same block
+			assert_noop!(
+				StakePallet::set_inflation_schedule(
+					RuntimeOrigin::root(),
+					vec![inflation_step(10, 10), inflation_step(10, 20)].try_into().unwrap()
+				),
+				Error::<Test>::InvalidSchedule
+			);
+			assert_noop!(
+				StakePallet::set_inflation_schedule(RuntimeOrigin::signed(1), vec![].try_into().unwrap()),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn inflation_schedule_applies_at_round_start() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10), (2, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let old = StakePallet::inflation_config();
+			let bpy = <Test as Config>::BLOCKS_PER_YEAR;
+			assert_ok!(StakePallet::set_inflation_schedule(
+				RuntimeOrigin::root(),
+				vec![
+					inflation_step(3, 10),
+					inflation_step(6, 20),
+					inflation_step(7, 30),
+					inflation_step(20, 40)
+				]
+				.try_into()
+				.unwrap()
+			));
+
+			// the first step is due but the round has not ended yet
+			roll_to(4, vec![]);
+			assert_eq!(StakePallet::inflation_config(), old);
+			assert_eq!(StakePallet::inflation_schedule().len(), 4);
+
+			// the step is applied once the rewards of all candidates have been incremented,
+			// one candidate per block
+			roll_to(5, vec![]);
+			assert_eq!(StakePallet::round().current, 1);
+			assert_eq!(StakePallet::inflation_schedule().len(), 3);
+			assert_eq!(StakePallet::inflation_config(), old);
+			assert!(PendingInflationStep::<Test>::get().is_some());
+			roll_to(6, vec![]);
+			assert_eq!(
+				StakePallet::inflation_config(),
+				inflation_step(3, 10).to_inflation_info(bpy)
+			);
+			assert!(PendingInflationStep::<Test>::get().is_none());
+			assert!(events().contains(&StakeEvent::InflationStepApplied(1, 3)));
+
+			// only the latest due step is applied
+			roll_to(11, vec![]);
+			assert_eq!(StakePallet::round().current, 2);
+			assert_eq!(
+				StakePallet::inflation_config(),
+				inflation_step(7, 30).to_inflation_info(bpy)
+			);
+			assert_eq!(
+				StakePallet::inflation_schedule().into_inner(),
+				vec![inflation_step(20, 40)]
+			);
+			assert!(events().contains(&StakeEvent::InflationStepApplied(2, 7)));
+			assert!(!events().contains(&StakeEvent::InflationStepApplied(2, 6)));
+		});
+}
+
+#[test]
+fn empty_inflation_schedule_cancels_pending_step() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10), (2, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let old = StakePallet::inflation_config();
+			assert_ok!(StakePallet::set_inflation_schedule(
+				RuntimeOrigin::root(),
+				vec![inflation_step(3, 10)].try_into().unwrap()
+			));
+
+			roll_to(5, vec![]);
+			assert!(PendingInflationStep::<Test>::get().is_some());
+
+			assert_ok!(StakePallet::set_inflation_schedule(
+				RuntimeOrigin::root(),
+				vec![].try_into().unwrap()
+			));
+			assert!(PendingInflationStep::<Test>::get().is_none());
+
+			roll_to(11, vec![]);
+			assert_eq!(StakePallet::inflation_config(), old);
+			assert!(!events().contains(&StakeEvent::InflationStepApplied(1, 3)));
+		});
+}
+
+#[test]
+fn inflation_schedule_applies_at_forced_round() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 10), (2, 100)])
+		.with_collators(vec![(1, 10), (2, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			let bpy = <Test as Config>::BLOCKS_PER_YEAR;
+			assert_ok!(StakePallet::set_inflation_schedule(
+				RuntimeOrigin::root(),
+				vec![inflation_step(2, 10)].try_into().unwrap()
+			));
+			assert_ok!(StakePallet::force_new_round(RuntimeOrigin::root()));
+
+			roll_to(5, vec![]);
+			assert_eq!(StakePallet::round().current, 1);
+			assert_eq!(
+				StakePallet::inflation_config(),
+				inflation_step(2, 10).to_inflation_info(bpy)
+			);
+			assert!(StakePallet::inflation_schedule().is_empty());
+		});
+}
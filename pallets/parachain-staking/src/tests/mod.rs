@@ -18,11 +18,18 @@
 
 //! Unit testing
 
+mod auto_compound;
 mod collators;
+mod commission;
 mod delegator;
+mod fast_unstake;
 mod genesis;
+mod identity;
 mod inflation;
+mod pools;
 mod rewards;
 mod round;
+mod round_stats;
 mod session;
+mod slashing;
 mod stake;
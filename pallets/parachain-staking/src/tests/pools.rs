@@ -0,0 +1,312 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+//! Unit testing
+
+use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
+use sp_runtime::{traits::Zero, PerThing, Perbill};
+
+use crate::{
+	mock::{last_event, roll_to, Balances, ExtBuilder, RuntimeOrigin, StakePallet, System, Test, DECIMALS},
+	types::{PoolRoles, PoolState},
+	Config, Error, Event as StakeEvent, MaxCommission,
+};
+
+#[test]
+fn create_and_join_pool() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (4, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_noop!(
+				StakePallet::create_pool(RuntimeOrigin::signed(2), 1, 4),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_ok!(StakePallet::create_pool(RuntimeOrigin::signed(2), 1, 10));
+			assert_eq!(last_event(), StakeEvent::PoolCreated(0, 2, 1, 10));
+
+			// the pool account is a regular delegator
+			let pool_account = StakePallet::pool_account(0);
+			assert_eq!(StakePallet::delegator_state(pool_account).unwrap().total, 10);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 30);
+			assert_eq!(Balances::usable_balance(2), 90);
+			let pool = StakePallet::pool(0).unwrap();
+			assert_eq!(pool.roles, PoolRoles { owner: 2, operator: 2 });
+			assert_eq!(pool.points, 10);
+			assert_eq!(pool.member_count, 1);
+			assert_eq!(StakePallet::pool_member(0, 2).unwrap().points, 10);
+
+			assert_noop!(
+				StakePallet::join_pool(RuntimeOrigin::signed(3), 0, 0),
+				Error::<Test>::PoolJoinBelowMin
+			);
+			assert_noop!(
+				StakePallet::join_pool(RuntimeOrigin::signed(3), 1, 20),
+				Error::<Test>::PoolNotFound
+			);
+			// members may join with less than MinDelegatorStake
+			assert_ok!(StakePallet::join_pool(RuntimeOrigin::signed(3), 0, 1));
+			assert_ok!(StakePallet::join_pool(RuntimeOrigin::signed(3), 0, 19));
+			assert_eq!(last_event(), StakeEvent::PoolJoined(0, 3, 19, 19));
+			assert_eq!(StakePallet::delegator_state(pool_account).unwrap().total, 30);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 50);
+			assert_eq!(Balances::usable_balance(3), 80);
+			let pool = StakePallet::pool(0).unwrap();
+			assert_eq!(pool.points, 30);
+			assert_eq!(pool.member_count, 2);
+			assert_eq!(StakePallet::pool_member(0, 3).unwrap().points, 20);
+		});
+}
+
+#[test]
+fn pool_rewards_are_shared_pro_rata() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake), (5, stake), (6, stake)])
+		.with_collators(vec![(1, stake), (6, stake)])
+		.with_delegators(vec![(5, 1, 3 * stake / 4)])
+		.build_and_execute_with_sanity_tests(|| {
+			MaxCommission::<Test>::put(Perbill::from_percent(50));
+			assert_ok!(StakePallet::create_pool(RuntimeOrigin::signed(2), 1, stake / 4));
+			assert_ok!(StakePallet::join_pool(RuntimeOrigin::signed(3), 0, stake / 2));
+			assert_ok!(StakePallet::set_pool_roles(
+				RuntimeOrigin::signed(2),
+				0,
+				PoolRoles { owner: 2, operator: 4 }
+			));
+			assert_ok!(StakePallet::set_pool_commission(
+				RuntimeOrigin::signed(4),
+				0,
+				Perbill::from_percent(10)
+			));
+
+			roll_to(11, vec![Some(1); 11]);
+
+			// delegator 5 has the same stake as the pool
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(5)));
+			let total = StakePallet::rewards(5);
+			assert!(!total.is_zero());
+
+			assert_ok!(StakePallet::claim_pool_rewards(RuntimeOrigin::signed(3), 0));
+			assert_ok!(StakePallet::claim_pool_rewards(RuntimeOrigin::signed(2), 0));
+			let commission = Perbill::from_percent(10).mul_floor(total);
+			assert_eq!(StakePallet::rewards(4), commission);
+			let rewards_2 = StakePallet::rewards(2);
+			let rewards_3 = StakePallet::rewards(3);
+			assert!(rewards_3.abs_diff(2 * rewards_2) <= 1);
+			assert!(total - commission - rewards_2 - rewards_3 <= 2);
+
+			// nothing left to claim
+			assert_noop!(
+				StakePallet::claim_pool_rewards(RuntimeOrigin::signed(3), 0),
+				Error::<Test>::RewardsNotFound
+			);
+			assert_noop!(
+				StakePallet::claim_pool_rewards(RuntimeOrigin::signed(5), 0),
+				Error::<Test>::PoolMemberNotFound
+			);
+
+			// settled rewards are claimed like any other rewards
+			let balance = Balances::balance(&3);
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(3)));
+			assert_eq!(Balances::balance(&3), balance + rewards_3);
+		});
+}
+
+#[test]
+fn unbond_and_withdraw_from_pool() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (4, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			let pool_account = StakePallet::pool_account(0);
+			assert_ok!(StakePallet::create_pool(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::join_pool(RuntimeOrigin::signed(3), 0, 10));
+
+			assert_noop!(
+				StakePallet::unbond_from_pool(RuntimeOrigin::signed(3), 0, 0),
+				Error::<Test>::ValStakeZero
+			);
+			assert_noop!(
+				StakePallet::unbond_from_pool(RuntimeOrigin::signed(3), 0, 11),
+				Error::<Test>::InsufficientPoolPoints
+			);
+
+			// the unbonds are unlocked at the end of the period following the next
+			// `StakeDuration` blocks
+			assert_eq!(System::block_number(), 1);
+			let unlock_block = 2 * <Test as Config>::StakeDuration::get();
+			assert_ok!(StakePallet::unbond_from_pool(RuntimeOrigin::signed(3), 0, 10));
+			assert_eq!(last_event(), StakeEvent::PoolUnbonded(0, 3, 10, 10));
+			assert_eq!(StakePallet::delegator_state(pool_account).unwrap().total, 10);
+			assert_eq!(StakePallet::pool_unbonding(0, 3).get(&unlock_block), Some(&10));
+			assert!(StakePallet::pool_member(0, 3).is_none());
+			let pool = StakePallet::pool(0).unwrap();
+			assert_eq!(pool.points, 10);
+			assert_eq!(pool.unbonding, 10);
+			assert_eq!(pool.member_count, 1);
+
+			assert_noop!(
+				StakePallet::withdraw_from_pool(RuntimeOrigin::signed(3), 0),
+				Error::<Test>::NothingToWithdraw
+			);
+			roll_to(unlock_block, vec![]);
+			assert_ok!(StakePallet::withdraw_from_pool(RuntimeOrigin::signed(3), 0));
+			assert_eq!(last_event(), StakeEvent::PoolWithdrawn(0, 3, 10));
+			assert_eq!(Balances::usable_balance(3), 100);
+			assert!(StakePallet::pool_unbonding(0, 3).is_empty());
+
+			// redeeming all points removes the pool delegation
+			assert_ok!(StakePallet::unbond_from_pool(RuntimeOrigin::signed(2), 0, 10));
+			assert!(StakePallet::delegator_state(pool_account).is_none());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 20);
+			assert_noop!(
+				StakePallet::join_pool(RuntimeOrigin::signed(3), 0, 10),
+				Error::<Test>::PoolNotDelegating
+			);
+
+			// anyone can unlock the funds of the pool account
+			roll_to(System::block_number() + <Test as Config>::StakeDuration::get(), vec![]);
+			assert_ok!(StakePallet::unlock_unstaked(RuntimeOrigin::signed(4), pool_account));
+			assert_ok!(StakePallet::withdraw_from_pool(RuntimeOrigin::signed(2), 0));
+			assert_eq!(last_event(), StakeEvent::PoolDestroyed(0));
+			assert!(StakePallet::pool(0).is_none());
+			assert_eq!(Balances::usable_balance(2), 100);
+			assert!(Balances::balance(&pool_account).is_zero());
+		});
+}
+
+#[test]
+fn pool_management() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (4, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::create_pool(RuntimeOrigin::signed(2), 1, 10));
+			let roles = PoolRoles { owner: 2, operator: 3 };
+
+			// only the owner sets the roles
+			assert_noop!(
+				StakePallet::set_pool_roles(RuntimeOrigin::signed(3), 0, roles.clone()),
+				Error::<Test>::NotPoolManager
+			);
+			assert_ok!(StakePallet::set_pool_roles(RuntimeOrigin::signed(2), 0, roles.clone()));
+			assert_eq!(last_event(), StakeEvent::PoolRolesSet(0, roles));
+
+			// only the operator sets the commission and state
+			assert_noop!(
+				StakePallet::set_pool_commission(RuntimeOrigin::signed(2), 0, Perbill::from_percent(10)),
+				Error::<Test>::NotPoolManager
+			);
+			assert_noop!(
+				StakePallet::set_pool_commission(RuntimeOrigin::signed(3), 0, Perbill::from_percent(10)),
+				Error::<Test>::CommissionAboveMax
+			);
+			MaxCommission::<Test>::put(Perbill::from_percent(20));
+			assert_ok!(StakePallet::set_pool_commission(
+				RuntimeOrigin::signed(3),
+				0,
+				Perbill::from_percent(10)
+			));
+			assert_eq!(StakePallet::pool(0).unwrap().commission, Perbill::from_percent(10));
+
+			assert_noop!(
+				StakePallet::set_pool_state(RuntimeOrigin::signed(2), 0, PoolState::Blocked),
+				Error::<Test>::NotPoolManager
+			);
+			assert_ok!(StakePallet::set_pool_state(
+				RuntimeOrigin::signed(3),
+				0,
+				PoolState::Blocked
+			));
+			assert_noop!(
+				StakePallet::join_pool(RuntimeOrigin::signed(4), 0, 10),
+				Error::<Test>::PoolBlocked
+			);
+			// existing members can still leave
+			assert_ok!(StakePallet::unbond_from_pool(RuntimeOrigin::signed(2), 0, 5));
+
+			assert_ok!(StakePallet::set_pool_state(
+				RuntimeOrigin::signed(3),
+				0,
+				PoolState::Open
+			));
+			assert_ok!(StakePallet::join_pool(RuntimeOrigin::signed(4), 0, 10));
+		});
+}
+
+#[test]
+fn pool_unbonds_are_batched() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (4, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			let pool_account = StakePallet::pool_account(0);
+			assert_ok!(StakePallet::create_pool(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::join_pool(RuntimeOrigin::signed(3), 0, 50));
+
+			// unbonding in every block exceeds `MaxUnstakeRequests` without batching
+			let stake_duration = <Test as Config>::StakeDuration::get();
+			let blocks = 3 * <Test as Config>::MaxUnstakeRequests::get();
+			for block in 1..=blocks {
+				roll_to(block.into(), vec![]);
+				assert_ok!(StakePallet::unbond_from_pool(RuntimeOrigin::signed(3), 0, 1));
+				assert!(StakePallet::unstaking(pool_account).len() <= 2);
+				if block >= 2 * stake_duration && block % stake_duration == 0 {
+					assert_ok!(StakePallet::withdraw_from_pool(RuntimeOrigin::signed(3), 0));
+				}
+				assert!(StakePallet::pool_unbonding(0, 3).len() <= 3);
+			}
+			assert_eq!(
+				StakePallet::delegator_state(pool_account).unwrap().total,
+				60 - u128::from(blocks)
+			);
+		});
+}
+
+#[test]
+fn pool_leaves_delegators_below_min_stake() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 20), (4, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			let pool_account = StakePallet::pool_account(0);
+			assert_ok!(StakePallet::create_pool(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::join_pool(RuntimeOrigin::signed(3), 0, 2));
+
+			// the remaining stake would drop below `MinDelegatorStake`
+			assert_ok!(StakePallet::unbond_from_pool(RuntimeOrigin::signed(2), 0, 8));
+			assert!(StakePallet::delegator_state(pool_account).is_none());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 20);
+			let pool = StakePallet::pool(0).unwrap();
+			assert_eq!(pool.points, 4);
+			assert_eq!(pool.unbonding, 8);
+
+			// the remaining points are backed by the unstaking funds of the pool
+			assert_ok!(StakePallet::unbond_from_pool(RuntimeOrigin::signed(3), 0, 2));
+			assert_eq!(last_event(), StakeEvent::PoolUnbonded(0, 3, 2, 2));
+			assert_ok!(StakePallet::unbond_from_pool(RuntimeOrigin::signed(2), 0, 2));
+			assert_eq!(last_event(), StakeEvent::PoolUnbonded(0, 2, 2, 2));
+
+			roll_to(10, vec![]);
+			assert_ok!(StakePallet::withdraw_from_pool(RuntimeOrigin::signed(3), 0));
+			assert_eq!(Balances::usable_balance(3), 100);
+			assert_ok!(StakePallet::withdraw_from_pool(RuntimeOrigin::signed(2), 0));
+			assert_eq!(last_event(), StakeEvent::PoolDestroyed(0));
+			assert_eq!(Balances::usable_balance(2), 100);
+		});
+}
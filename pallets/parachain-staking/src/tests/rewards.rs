@@ -611,7 +611,7 @@ fn rewards_candidate_stake_less() {
 					id
 				);
 				assert!(
-					StakePallet::blocks_rewarded(id).is_zero(),
+					StakePallet::delegation_blocks_rewarded(id, 1).is_zero(),
 					"BlocksRewaeded not zero for acc_id {:?}",
 					id
 				);
@@ -656,8 +656,8 @@ fn rewards_candidate_leave_network() {
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 
 			// count for delegators should not be incremented
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 
 			// rewards should not be incremented
 			(1..=3).for_each(|id| {
@@ -677,7 +677,7 @@ fn rewards_candidate_leave_network() {
 			(2..=3).for_each(|id| {
 				assert!(!StakePallet::rewards(id).is_zero(), "Zero rewards acc_id {:?}", id);
 				assert_eq!(
-					StakePallet::blocks_rewarded(id),
+					StakePallet::delegation_blocks_rewarded(id, 1),
 					4 * 2,
 					"Rewarded blocks Delegator {:?} do not match up with exited collator",
 					id
@@ -721,7 +721,7 @@ fn rewards_force_remove_candidate() {
 				);
 				if id > 1 {
 					assert_eq!(
-						StakePallet::blocks_rewarded(id),
+						StakePallet::delegation_blocks_rewarded(id, 1),
 						2,
 						"Rewarded counter does not match for delegator {:?}",
 						id
@@ -750,7 +750,10 @@ fn blocks_rewarded_join_delegators() {
 			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100));
 			// delegator's rewarded counter should equal of collator's authored counter upon
 			// joining
-			assert_eq!(StakePallet::blocks_rewarded(2), StakePallet::blocks_authored(1));
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(2, 1),
+				StakePallet::blocks_authored(1)
+			);
 		});
 }
 
@@ -764,23 +767,23 @@ fn rewards_delegator_stake_more() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::blocks_authored(1), 2);
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
 			});
 
 			// stake less to trigger reward incrementing just for 3
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), DECIMALS));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 1, DECIMALS));
 			// 1 should still have counter 1 but no rewards
 			assert_eq!(StakePallet::blocks_authored(1), 2);
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 			assert!(StakePallet::rewards(1).is_zero());
 			// 2 should still have neither rewards nor counter
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			// 3 should have rewards and the same counter as 1
-			assert_eq!(StakePallet::blocks_rewarded(3), 2);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(3, 1), 2);
 			assert!(!StakePallet::rewards(3).is_zero());
 		});
 }
@@ -795,23 +798,23 @@ fn rewards_delegator_stake_less() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::blocks_authored(1), 2);
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
 			});
 
 			// stake less to trigger reward incrementing just for 3
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), DECIMALS));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 1, DECIMALS));
 			// 1 should still have counter 1 but no rewards
 			assert_eq!(StakePallet::blocks_authored(1), 2);
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 			assert!(StakePallet::rewards(1).is_zero());
 			// 2 should still have neither rewards nor counter
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			// 3 should have rewards and the same counter as 1
-			assert_eq!(StakePallet::blocks_rewarded(3), 2);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(3, 1), 2);
 			assert!(!StakePallet::rewards(3).is_zero());
 		});
 }
@@ -844,10 +847,10 @@ fn rewards_delegator_replaced() {
 			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(6), 1, 2 * DECIMALS));
 			// 5 should have rewards and counter updated
 			assert!(!StakePallet::rewards(5).is_zero());
-			assert_eq!(StakePallet::blocks_rewarded(5), 2);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(5, 1), 2);
 			// 6 should not have rewards but same counter as former collator
 			assert!(StakePallet::rewards(6).is_zero());
-			assert_eq!(StakePallet::blocks_rewarded(6), 2);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(6, 1), 2);
 		});
 }
 
@@ -861,8 +864,8 @@ fn rewards_delegator_leaves() {
 			// note collator once to set their counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::blocks_authored(1), 2);
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
 			});
@@ -871,12 +874,12 @@ fn rewards_delegator_leaves() {
 			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 			assert!(StakePallet::rewards(1).is_zero());
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			assert!(!StakePallet::rewards(3).is_zero());
 			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&3), StakePallet::rewards(3));
 			// counter should be reset due to leaving
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 		});
 }
 
@@ -898,7 +901,7 @@ fn rewards_incrementing_and_claiming() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::blocks_authored(1), 2);
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
 
 			// claiming should not be possible before incrementing rewards
 			(1..=4).for_each(|id| {
@@ -910,10 +913,10 @@ fn rewards_incrementing_and_claiming() {
 
 			// increment rewards for 2 and match counter to collator
 			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
-			assert_eq!(StakePallet::blocks_rewarded(2), 2);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(2, 1), 2);
 			let rewards_2 = StakePallet::rewards(2);
 			assert!(!rewards_2.is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 			assert!(StakePallet::rewards(3).is_zero());
 
 			// should only update rewards for collator as well
@@ -923,7 +926,7 @@ fn rewards_incrementing_and_claiming() {
 			// rewards of 2 should not be changed
 			assert_eq!(StakePallet::rewards(2), rewards_2);
 			// 3 should still not have blocks rewarded bumped
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 
 			// claim for 1 to move rewards into balance
 			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(1)));
@@ -936,7 +939,7 @@ fn rewards_incrementing_and_claiming() {
 			// incrementing again should not change anything because collator has not
 			// authored blocks since last inc
 			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
-			assert_eq!(StakePallet::blocks_rewarded(2), 2);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(2, 1), 2);
 			// claim for 2 to move rewards into balance
 			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(2)));
 			assert!(Balances::balance(&2) > DECIMALS);
@@ -1004,6 +1007,47 @@ fn api_get_unclaimed_staking_rewards() {
 		});
 }
 
+#[test]
+fn api_get_unclaimed_staking_rewards_multiple_delegations() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, 2 * stake), (3, stake)])
+		.with_collators(vec![(1, stake), (3, stake)])
+		.with_delegators(vec![(2, 1, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			let inflation_config = StakePallet::inflation_config();
+			assert_ok!(StakePallet::delegate_another_candidate(
+				RuntimeOrigin::signed(2),
+				3,
+				stake
+			));
+
+			// Only the delegation to 1 should be rewarded
+			roll_to(2, vec![None, Some(1)]);
+			let rewards_per_delegation = inflation_config.delegator.reward_rate.per_block * stake * 2;
+			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&2), rewards_per_delegation);
+
+			// Both delegations should be rewarded
+			roll_to(3, vec![None, None, Some(3)]);
+			let rewards_2 = StakePallet::get_unclaimed_staking_rewards(&2);
+			assert_eq!(rewards_2, 2 * rewards_per_delegation);
+
+			// API and actual claiming should match
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(2, 1),
+				StakePallet::blocks_authored(1)
+			);
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(2, 3),
+				StakePallet::blocks_authored(3)
+			);
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(2)));
+			assert_eq!(rewards_2, Balances::usable_balance(2));
+			assert!(StakePallet::get_unclaimed_staking_rewards(&2).is_zero());
+		});
+}
+
 #[test]
 fn too_small_candidate_size_provided_for_reward_adjustment() {
 	ExtBuilder::default()
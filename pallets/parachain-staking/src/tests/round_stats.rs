@@ -0,0 +1,217 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+//! Unit testing
+
+use frame_support::assert_ok;
+use sp_runtime::{
+	traits::{One, Zero},
+	FixedPointNumber, FixedU128, PerThing, Perbill,
+};
+
+use crate::{
+	mock::{roll_to, ExtBuilder, RuntimeOrigin, StakePallet, Test, BLOCKS_PER_ROUND, DECIMALS},
+	types::{CollatorStats, RoundStats, TotalStake},
+	Config,
+};
+
+#[test]
+fn round_stats_are_recorded() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.with_delegators(vec![(3, 1, stake / 2)])
+		.build_and_execute_with_sanity_tests(|| {
+			// the stake is recorded at the start of the round
+			roll_to(6, vec![]);
+			assert_eq!(
+				StakePallet::collator_stats(1, 1),
+				Some(CollatorStats {
+					stake,
+					delegators_stake: stake / 2,
+					..Default::default()
+				})
+			);
+			assert_eq!(
+				StakePallet::round_stats(1),
+				Some(RoundStats {
+					total_stake: TotalStake {
+						collators: 2 * stake,
+						delegators: stake / 2,
+					},
+					..Default::default()
+				})
+			);
+
+			// only collator 1 authors blocks in round 1
+			roll_to(11, vec![Some(1); 11]);
+			assert_eq!(
+				StakePallet::collator_stats(1, 1),
+				Some(CollatorStats {
+					blocks_authored: 5,
+					stake,
+					delegators_stake: stake / 2,
+					..Default::default()
+				})
+			);
+
+			// the rewards are recorded in the round in which they are credited
+			let collator_rewards = StakePallet::get_unclaimed_staking_rewards(&1);
+			let delegator_rewards = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert!(!collator_rewards.is_zero());
+			assert!(!delegator_rewards.is_zero());
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(3)));
+			let stats = CollatorStats {
+				blocks_authored: 0,
+				stake,
+				delegators_stake: stake / 2,
+				commission: Perbill::zero(),
+				collator_rewards,
+				delegator_rewards,
+			};
+			assert_eq!(StakePallet::collator_stats(2, 1), Some(stats));
+			assert_eq!(
+				StakePallet::round_stats(2),
+				Some(RoundStats {
+					total_stake: TotalStake {
+						collators: 2 * stake,
+						delegators: stake / 2,
+					},
+					blocks_authored: 0,
+					total_rewarded: collator_rewards + delegator_rewards,
+				})
+			);
+
+			// the runtime API only returns completed rounds
+			roll_to(16, vec![]);
+			assert_eq!(
+				StakePallet::get_collator_stats(&1, 1),
+				vec![kilt_runtime_api_staking::CollatorRoundStats {
+					round: 2,
+					blocks_authored: 0,
+					stake,
+					delegators_stake: stake / 2,
+					commission: Perbill::zero(),
+					collator_rewards,
+					delegator_rewards,
+				}]
+			);
+
+			let rounds_per_year = u128::from(<Test as Config>::BLOCKS_PER_YEAR / BLOCKS_PER_ROUND);
+			let reward_rate = FixedU128::from_rational(delegator_rewards, stake / 2);
+			assert_eq!(
+				StakePallet::get_delegator_apy(&1, 1),
+				Some(reward_rate * FixedU128::saturating_from_integer(rounds_per_year))
+			);
+			// collator 2 neither has delegators nor was credited rewards
+			assert_eq!(StakePallet::get_delegator_apy(&2, 1), None);
+		});
+}
+
+#[test]
+fn delegator_apy_is_not_capped() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1_000), (2, 1_000), (3, 1_000)])
+		.with_collators(vec![(1, 100), (2, 100)])
+		.with_delegators(vec![(3, 1, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			roll_to(6, vec![]);
+			// the delegators are credited as much as their stake within a single round
+			crate::CollatorHistory::<Test>::mutate(1, 1, |maybe_stats| {
+				let stats = maybe_stats.as_mut().expect("Stats are recorded");
+				stats.delegator_rewards = stats.delegators_stake;
+			});
+			roll_to(11, vec![]);
+
+			let rounds_per_year = u128::from(<Test as Config>::BLOCKS_PER_YEAR / BLOCKS_PER_ROUND);
+			let apy = StakePallet::get_delegator_apy(&1, 1).expect("Stats are recorded");
+			assert_eq!(apy, FixedU128::saturating_from_integer(rounds_per_year));
+			assert!(apy > FixedU128::one());
+		});
+}
+
+#[test]
+fn round_stats_account_for_commission() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.with_delegators(vec![(3, 1, stake)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_max_commission(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(20)
+			));
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(10)
+			));
+			// the commission can be applied from round 2 on
+			roll_to(11, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(RuntimeOrigin::signed(2), 1));
+
+			// the commission is recorded at the start of round 3
+			roll_to(16, vec![Some(1); 16]);
+			let collator_rewards = StakePallet::rewards(1);
+			let delegator_rewards = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(3)));
+			let commission = StakePallet::rewards(1) - collator_rewards;
+			assert!(!commission.is_zero());
+
+			let stats = StakePallet::collator_stats(3, 1).expect("Stats are recorded");
+			assert_eq!(stats.commission, Perbill::from_percent(10));
+			assert_eq!(stats.collator_rewards, commission);
+			assert_eq!(stats.delegator_rewards, delegator_rewards);
+			assert_eq!(
+				Perbill::from_percent(10).mul_floor(commission + delegator_rewards),
+				commission
+			);
+		});
+}
+
+#[test]
+fn round_history_is_pruned() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			roll_to(11, vec![]);
+			assert!(StakePallet::round_stats(1).is_some());
+			assert!(StakePallet::collator_stats(1, 1).is_some());
+
+			// the history is limited to `MaxRoundHistory` rounds
+			roll_to(26, vec![]);
+			assert_eq!(StakePallet::round().current, 5);
+			for round in 0..=2 {
+				assert_eq!(StakePallet::round_stats(round), None);
+				assert_eq!(StakePallet::collator_stats(round, 1), None);
+				assert_eq!(StakePallet::collator_stats(round, 2), None);
+			}
+			for round in 3..=5 {
+				assert!(StakePallet::round_stats(round).is_some());
+				assert!(StakePallet::collator_stats(round, 1).is_some());
+			}
+
+			let rounds: Vec<u32> = StakePallet::get_collator_stats(&1, 10)
+				.into_iter()
+				.map(|stats| stats.round)
+				.collect();
+			assert_eq!(rounds, vec![3, 4]);
+		});
+}
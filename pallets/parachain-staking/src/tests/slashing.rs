@@ -0,0 +1,325 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! Unit testing
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError, Perbill};
+
+use crate::{
+	mock::{last_event, roll_to, Balances, ExtBuilder, RuntimeOrigin, StakePallet, Test, TREASURY_ACC},
+	types::{CandidateStatus, DeferredSlash, Offence, SlashingConfiguration, Stake},
+	Error, Event,
+};
+
+#[test]
+fn set_slashing_config() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_collators(vec![(1, 20), (2, 20)])
+		.build_and_execute_with_sanity_tests(|| {
+			let config = SlashingConfiguration {
+				min_authored_share: Perbill::from_percent(50),
+				downtime_rounds: 2,
+				downtime_slash: Perbill::from_percent(1),
+				equivocation_slash: Perbill::from_percent(10),
+				suspension_rounds: 4,
+			};
+			assert_noop!(
+				StakePallet::set_slashing_config(RuntimeOrigin::signed(1), config.clone()),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				StakePallet::set_slashing_config(
+					RuntimeOrigin::root(),
+					SlashingConfiguration {
+						equivocation_slash: Perbill::one(),
+						..config.clone()
+					}
+				),
+				Error::<Test>::InvalidSlashingConfig
+			);
+
+			assert_ok!(StakePallet::set_slashing_config(RuntimeOrigin::root(), config.clone()));
+			assert_eq!(last_event(), Event::SlashingConfigSet(config.clone()));
+			assert_eq!(StakePallet::slashing_config(), config);
+		});
+}
+
+#[test]
+fn report_equivocation_suspends_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 30), (2, 20), (3, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_slashing_config(
+				RuntimeOrigin::root(),
+				SlashingConfiguration {
+					equivocation_slash: Perbill::from_percent(10),
+					suspension_rounds: 2,
+					..Default::default()
+				}
+			));
+			assert_noop!(
+				StakePallet::report_equivocation(RuntimeOrigin::signed(2), 1),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				StakePallet::report_equivocation(RuntimeOrigin::root(), 4),
+				Error::<Test>::CandidateNotFound
+			);
+
+			assert_ok!(StakePallet::report_equivocation(RuntimeOrigin::root(), 1));
+			assert_eq!(last_event(), Event::CandidateSuspended(1, 2));
+			assert_eq!(
+				StakePallet::deferred_slash(1),
+				Some(DeferredSlash {
+					offence: Offence::Equivocation,
+					fraction: Perbill::from_percent(10),
+					apply_at: 2,
+				})
+			);
+			assert_eq!(
+				StakePallet::candidate_pool(1).unwrap().status,
+				CandidateStatus::Suspended(2)
+			);
+			assert_eq!(
+				StakePallet::top_candidates()
+					.into_iter()
+					.map(|s| s.owner)
+					.collect::<Vec<u64>>(),
+				vec![2, 3]
+			);
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![2, 3]);
+
+			// suspended candidates cannot leave or rejoin before the end of the suspension
+			assert_noop!(
+				StakePallet::init_leave_candidates(RuntimeOrigin::signed(1)),
+				Error::<Test>::CandidateSuspended
+			);
+			assert_noop!(
+				StakePallet::resume_candidate(RuntimeOrigin::signed(1)),
+				Error::<Test>::CannotResumeYet
+			);
+			assert_noop!(
+				StakePallet::resume_candidate(RuntimeOrigin::signed(2)),
+				Error::<Test>::NotSuspended
+			);
+			// never suspend the required collators
+			assert_ok!(StakePallet::report_equivocation(RuntimeOrigin::root(), 2));
+			assert!(StakePallet::candidate_pool(2).unwrap().is_active());
+
+			roll_to(10, vec![]);
+			assert_ok!(StakePallet::resume_candidate(RuntimeOrigin::signed(1)));
+			assert_eq!(last_event(), Event::CandidateResumed(1));
+			assert!(StakePallet::candidate_pool(1).unwrap().is_active());
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+		});
+}
+
+#[test]
+fn apply_deferred_slash() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 100),
+			(2, 100),
+			(3, 100),
+			(4, 50),
+			(5, 10),
+			(TREASURY_ACC, 100),
+		])
+		.with_collators(vec![(1, 100), (2, 100), (3, 100)])
+		.with_delegators(vec![(4, 1, 50), (5, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_slashing_config(
+				RuntimeOrigin::root(),
+				SlashingConfiguration {
+					equivocation_slash: Perbill::from_percent(60),
+					suspension_rounds: 2,
+					..Default::default()
+				}
+			));
+			assert_noop!(
+				StakePallet::apply_deferred_slash(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::NoDeferredSlash
+			);
+			assert_ok!(StakePallet::report_equivocation(RuntimeOrigin::root(), 1));
+			assert_noop!(
+				StakePallet::apply_deferred_slash(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::SlashNotDue
+			);
+
+			roll_to(10, vec![]);
+			assert_ok!(StakePallet::apply_deferred_slash(RuntimeOrigin::signed(3), 1));
+			assert_eq!(StakePallet::deferred_slash(1), None);
+
+			let state = StakePallet::candidate_pool(1).unwrap();
+			assert_eq!(state.stake, 40);
+			assert_eq!(state.total, 40 + 20);
+			assert_eq!(StakePallet::delegator_state(4).unwrap().delegation_of(&1), Some(20));
+			assert_eq!(Balances::free_balance(1), 40);
+			assert_eq!(Balances::free_balance(4), 20);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), 100 + 60 + 30 + 6);
+
+			// the delegation of 5 fell below the minimum and the remainder is unstaked
+			assert!(StakePallet::delegator_state(5).is_none());
+			assert!(!state.delegators.contains(&Stake { owner: 5, amount: 4 }));
+			assert_eq!(Balances::free_balance(5), 4);
+			assert_eq!(StakePallet::unstaking(5).get(&12), Some(&4));
+			assert_eq!(last_event(), Event::DelegatorLeftCollator(5, 1, 4, 60));
+
+			// the slashed candidate rejoins with the reduced stake
+			assert_ok!(StakePallet::resume_candidate(RuntimeOrigin::signed(1)));
+			assert!(StakePallet::top_candidates().contains(&Stake { owner: 1, amount: 60 }));
+		});
+}
+
+#[test]
+fn apply_deferred_slash_removes_candidate_below_minimum() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 100), (3, 100), (4, 10), (TREASURY_ACC, 100)])
+		.with_collators(vec![(1, 20), (2, 100), (3, 100)])
+		.with_delegators(vec![(4, 1, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_slashing_config(
+				RuntimeOrigin::root(),
+				SlashingConfiguration {
+					equivocation_slash: Perbill::from_percent(60),
+					..Default::default()
+				}
+			));
+			assert_ok!(StakePallet::report_equivocation(RuntimeOrigin::root(), 1));
+
+			roll_to(10, vec![]);
+			assert_ok!(StakePallet::apply_deferred_slash(RuntimeOrigin::signed(3), 1));
+			assert_eq!(last_event(), Event::CollatorRemoved(1, 8));
+			assert!(StakePallet::candidate_pool(1).is_none());
+			assert!(!StakePallet::top_candidates().contains(&Stake { owner: 1, amount: 8 }));
+			assert!(StakePallet::delegator_state(4).is_none());
+
+			// the remaining stake is unstaked
+			assert_eq!(Balances::free_balance(1), 8);
+			assert_eq!(StakePallet::unstaking(1).get(&12), Some(&8));
+			assert_eq!(Balances::free_balance(4), 4);
+			assert_eq!(StakePallet::unstaking(4).get(&12), Some(&4));
+			assert_eq!(Balances::free_balance(TREASURY_ACC), 100 + 12 + 6);
+		});
+}
+
+#[test]
+fn cancel_deferred_slash() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 100), (2, 100), (3, 100)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_slashing_config(
+				RuntimeOrigin::root(),
+				SlashingConfiguration {
+					equivocation_slash: Perbill::from_percent(10),
+					..Default::default()
+				}
+			));
+			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(3)));
+			assert_ok!(StakePallet::report_equivocation(RuntimeOrigin::root(), 3));
+			assert_eq!(
+				last_event(),
+				Event::OffenceReported(3, Offence::Equivocation, Perbill::from_percent(10), 2)
+			);
+
+			// offenders cannot leave before the slash is applied or canceled
+			roll_to(10, vec![]);
+			assert_noop!(
+				StakePallet::execute_leave_candidates(RuntimeOrigin::signed(3), 3),
+				Error::<Test>::SlashPending
+			);
+
+			assert_noop!(
+				StakePallet::cancel_deferred_slash(RuntimeOrigin::signed(1), 3),
+				DispatchError::BadOrigin
+			);
+			assert_ok!(StakePallet::cancel_deferred_slash(RuntimeOrigin::root(), 3));
+			assert_eq!(last_event(), Event::DeferredSlashCanceled(3));
+			assert_noop!(
+				StakePallet::cancel_deferred_slash(RuntimeOrigin::root(), 3),
+				Error::<Test>::NoDeferredSlash
+			);
+
+			assert_ok!(StakePallet::execute_leave_candidates(RuntimeOrigin::signed(3), 3));
+			assert_eq!(Balances::free_balance(3), 100);
+		});
+}
+
+#[test]
+fn downtime_is_slashed() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 30), (2, 20), (3, 10)])
+		.build_and_execute_with_sanity_tests(|| {
+			assert_ok!(StakePallet::set_slashing_config(
+				RuntimeOrigin::root(),
+				SlashingConfiguration {
+					min_authored_share: Perbill::from_percent(50),
+					downtime_rounds: 2,
+					downtime_slash: Perbill::from_percent(1),
+					suspension_rounds: 1,
+					..Default::default()
+				}
+			));
+
+			// only collator 1 authors blocks in round 0
+			roll_to(6, vec![None, Some(1), Some(1), Some(1), Some(1), Some(1)]);
+			assert_eq!(StakePallet::missed_rounds(1), 0);
+			assert_eq!(StakePallet::missed_rounds(2), 1);
+			assert_eq!(StakePallet::deferred_slash(2), None);
+
+			// collator 2 authors enough blocks in round 1
+			roll_to(
+				11,
+				vec![
+					None,
+					None,
+					None,
+					None,
+					None,
+					Some(2),
+					Some(1),
+					Some(2),
+					Some(2),
+					Some(1),
+					Some(1),
+				],
+			);
+			assert_eq!(StakePallet::missed_rounds(2), 0);
+
+			// collator 2 is offline for two consecutive rounds
+			roll_to(21, vec![Some(1); 21]);
+			assert_eq!(
+				StakePallet::deferred_slash(2),
+				Some(DeferredSlash {
+					offence: Offence::Downtime,
+					fraction: Perbill::from_percent(1),
+					apply_at: 5,
+				})
+			);
+			assert_eq!(
+				StakePallet::candidate_pool(2).unwrap().status,
+				CandidateStatus::Suspended(4)
+			);
+			assert_eq!(StakePallet::missed_rounds(2), 0);
+		});
+}
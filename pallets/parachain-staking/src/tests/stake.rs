@@ -79,13 +79,13 @@ fn should_update_total_stake() {
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 50));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 1, 50));
 			assert_noop!(
-				StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 0),
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 1, 0),
 				Error::<Test>::ValStakeZero
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 0),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 1, 0),
 				Error::<Test>::ValStakeZero
 			);
 			assert_eq!(
@@ -97,7 +97,7 @@ fn should_update_total_stake() {
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 50));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 1, 50));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -117,7 +117,7 @@ fn should_update_total_stake() {
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_eq!(StakePallet::delegator_state(11).unwrap().amount, 200);
+			assert_eq!(StakePallet::delegator_state(11).unwrap().total, 200);
 			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(11)));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
@@ -128,7 +128,7 @@ fn should_update_total_stake() {
 			);
 
 			let old_stake = StakePallet::total_collator_stake();
-			assert_eq!(StakePallet::delegator_state(8).unwrap().amount, 10);
+			assert_eq!(StakePallet::delegator_state(8).unwrap().total, 10);
 			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(8)));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
@@ -362,12 +362,12 @@ fn unlock_unstaked() {
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10),);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10),);
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10),);
 			let mut unstaking: BoundedBTreeMap<BlockNumber, BalanceOf<Test>, <Test as Config>::MaxUnstakeRequests> =
 				BoundedBTreeMap::new();
 			assert_ok!(unstaking.try_insert(3, 60));
@@ -389,7 +389,7 @@ fn unlock_unstaked() {
 
 			roll_to(2, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10),);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10),);
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10),);
 			assert_ok!(unstaking.try_insert(4, 10));
 			assert_eq!(Freezes::<Test>::get(1), vec![lock.clone()]);
 			assert_eq!(Freezes::<Test>::get(2), vec![lock.clone()]);
@@ -405,7 +405,7 @@ fn unlock_unstaked() {
 
 			roll_to(3, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10),);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10),);
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10),);
 			assert_ok!(unstaking.try_insert(5, 10));
 			assert_ok!(unstaking.try_insert(5, 10));
 			assert_eq!(Freezes::<Test>::get(1), vec![lock.clone()]);
@@ -425,13 +425,13 @@ fn unlock_unstaked() {
 			// reach MaxUnstakeRequests
 			roll_to(4, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
 			roll_to(5, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
 			roll_to(6, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
 			assert_ok!(unstaking.try_insert(6, 10));
 			assert_ok!(unstaking.try_insert(7, 10));
 			assert_ok!(unstaking.try_insert(8, 10));
@@ -446,7 +446,7 @@ fn unlock_unstaked() {
 				Error::<Test>::NoMoreUnstaking
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10),
 				Error::<Test>::NoMoreUnstaking
 			);
 			assert_ok!(StakePallet::unlock_unstaked(RuntimeOrigin::signed(1), 1));
@@ -461,10 +461,10 @@ fn unlock_unstaked() {
 			assert_eq!(Freezes::<Test>::get(1), vec![lock.clone()]);
 			assert_eq!(Freezes::<Test>::get(2), vec![lock.clone()]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 40));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 40));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 40));
 			assert_ok!(unstaking.try_insert(9, 40));
 			assert_ok!(StakePallet::candidate_stake_more(RuntimeOrigin::signed(1), 30));
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(2), 30));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(2), 1, 30));
 			unstaking.remove(&8);
 			assert_ok!(unstaking.try_insert(9, 20));
 			assert_eq!(StakePallet::unstaking(1), unstaking);
@@ -533,7 +533,7 @@ fn api_get_staking_rates() {
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), stake / 2));
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(2), stake / 2));
 			// delegator stakes more to exceed
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), stake));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 1, stake));
 			rates.collator_staking_rate = Perquintill::from_percent(25);
 			rates.collator_reward_rate = Perquintill::from_percent(10);
 			rates.delegator_staking_rate = Perquintill::from_percent(50);
@@ -0,0 +1,44 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_support::Parameter;
+use parity_scale_codec::MaxEncodedLen;
+
+/// Provides the verified identity an account is linked to, e.g., a DID.
+pub trait CollatorIdentityProvider<AccountId> {
+	/// The identity a collator candidate can be linked to.
+	type Identity: Parameter + MaxEncodedLen;
+
+	/// Returns the identity the account is currently linked to, if any.
+	fn identity_of(who: &AccountId) -> Option<Self::Identity>;
+
+	/// Links the account to an identity and returns it.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn link_identity(who: &AccountId) -> Self::Identity;
+}
+
+/// No account is linked to an identity.
+impl<AccountId> CollatorIdentityProvider<AccountId> for () {
+	type Identity = ();
+
+	fn identity_of(_who: &AccountId) -> Option<Self::Identity> {
+		None
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn link_identity(_who: &AccountId) -> Self::Identity {}
+}
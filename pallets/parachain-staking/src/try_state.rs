@@ -27,14 +27,23 @@ use sp_runtime::{
 use crate::{
 	set::OrderedSet,
 	types::{BalanceOf, Candidate, Stake},
-	CandidatePool, Config, DelegatorState, LastDelegation, MaxCollatorCandidateStake, MaxSelectedCandidates, Pallet,
-	Round, TopCandidates, TotalCollatorStake,
+	AutoCompound, CandidateCommission, CandidateIdentity, CandidatePool, Config, DeferredSlashes,
+	DelegationBlocksRewarded, DelegatorState, IdentityCandidateCount, IdentityCandidates, InflationConfig,
+	InflationSchedule, LastDelegation, MaxCollatorCandidateStake, MaxSelectedCandidates, Pallet, Round, RoundHistory,
+	ScheduledCommission, TopCandidates, TotalCollatorStake,
 };
 
 pub(crate) fn do_try_state<T: Config>() -> Result<(), TryRuntimeError> {
 	validate_candiate_pool::<T>()?;
 	validate_delegators::<T>()?;
 	validate_top_candidates::<T>()?;
+	validate_commission::<T>()?;
+	validate_auto_compound::<T>()?;
+	validate_deferred_slashes::<T>()?;
+	validate_identities::<T>()?;
+	validate_inflation::<T>()?;
+	validate_round_history::<T>()?;
+	validate_pools::<T>()?;
 	validate_stake::<T>()
 }
 
@@ -221,10 +230,181 @@ fn validate_stake<T: Config>() -> Result<(), TryRuntimeError> {
 }
 
 fn validate_delegators<T: Config>() -> Result<(), TryRuntimeError> {
-	DelegatorState::<T>::iter_values().try_for_each(|delegator_details| -> Result<(), TryRuntimeError> {
+	DelegatorState::<T>::iter().try_for_each(|(delegator, delegator_details)| -> Result<(), TryRuntimeError> {
 		ensure!(
-			CandidatePool::<T>::contains_key(&delegator_details.owner),
-			log_and_return_error_message(format!("Collator {:?} not found", delegator_details.owner))
+			!delegator_details.delegations.is_empty(),
+			log_and_return_error_message(format!("Delegator {:?} has no delegations", delegator))
+		);
+
+		let sum_delegations: BalanceOf<T> = delegator_details.delegations.iter().try_fold(
+			Zero::zero(),
+			|acc: BalanceOf<T>, stake| -> Result<_, TryRuntimeError> {
+				let collator = CandidatePool::<T>::get(&stake.owner)
+					.ok_or_else(|| log_and_return_error_message(format!("Collator {:?} not found", stake.owner)))?;
+
+				// the delegation has to be part of the collator's delegators
+				ensure!(
+					collator
+						.delegators
+						.iter()
+						.any(|d| d.owner == delegator && d.amount == stake.amount),
+					log_and_return_error_message(format!(
+						"Delegation of {:?} to collator {:?} not found",
+						delegator, stake.owner
+					))
+				);
+
+				Ok(acc.saturating_add(stake.amount))
+			},
+		)?;
+
+		// total stake of a delegator should be the sum of their delegations.
+		ensure!(
+			sum_delegations == delegator_details.total,
+			log_and_return_error_message(format!(
+				"Total stake of delegator {:?} does not match. Saved stake: {:?}. Calculated stake: {:?}",
+				delegator, delegator_details.total, sum_delegations
+			))
+		);
+		Ok(())
+	})?;
+
+	// rewarded blocks are only tracked for existing delegations
+	DelegationBlocksRewarded::<T>::iter_keys().try_for_each(|(delegator, collator)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			DelegatorState::<T>::get(&delegator).is_some_and(|state| state.delegation_of(&collator).is_some()),
+			log_and_return_error_message(format!(
+				"Rewarded blocks of unknown delegation of {:?} to collator {:?}",
+				delegator, collator
+			))
+		);
+		Ok(())
+	})
+}
+
+fn validate_commission<T: Config>() -> Result<(), TryRuntimeError> {
+	CandidateCommission::<T>::iter_keys()
+		.chain(ScheduledCommission::<T>::iter_keys())
+		.try_for_each(|collator| -> Result<(), TryRuntimeError> {
+			// only collator candidates can charge a commission.
+			ensure!(
+				CandidatePool::<T>::contains_key(&collator),
+				log_and_return_error_message(format!("Commission set for unknown candidate {:?}", collator))
+			);
+			Ok(())
+		})
+}
+
+fn validate_auto_compound<T: Config>() -> Result<(), TryRuntimeError> {
+	AutoCompound::<T>::iter_keys().try_for_each(|acc| -> Result<(), TryRuntimeError> {
+		// only collator candidates and delegators can compound their rewards.
+		ensure!(
+			CandidatePool::<T>::contains_key(&acc) || DelegatorState::<T>::contains_key(&acc),
+			log_and_return_error_message(format!("Auto-compounding set for unknown staker {:?}", acc))
+		);
+		Ok(())
+	})
+}
+
+fn validate_deferred_slashes<T: Config>() -> Result<(), TryRuntimeError> {
+	DeferredSlashes::<T>::iter_keys().try_for_each(|collator| -> Result<(), TryRuntimeError> {
+		// only collator candidates can be slashed.
+		ensure!(
+			CandidatePool::<T>::contains_key(&collator),
+			log_and_return_error_message(format!("Deferred slash for unknown candidate {:?}", collator))
+		);
+		Ok(())
+	})
+}
+
+fn validate_identities<T: Config>() -> Result<(), TryRuntimeError> {
+	CandidateIdentity::<T>::iter().try_for_each(|(collator, identity)| -> Result<(), TryRuntimeError> {
+		// only collator candidates can be linked to an identity.
+		ensure!(
+			CandidatePool::<T>::contains_key(&collator),
+			log_and_return_error_message(format!("Identity linked to unknown candidate {:?}", collator))
+		);
+		ensure!(
+			IdentityCandidates::<T>::contains_key(&identity, &collator),
+			log_and_return_error_message(format!("Missing reverse link of identity of candidate {:?}", collator))
+		);
+		Ok(())
+	})?;
+
+	IdentityCandidates::<T>::iter_keys().try_for_each(|(identity, collator)| -> Result<(), TryRuntimeError> {
+		ensure!(
+			CandidateIdentity::<T>::get(&collator) == Some(identity),
+			log_and_return_error_message(format!("Dangling reverse link of identity of candidate {:?}", collator))
+		);
+		ensure!(
+			IdentityCandidateCount::<T>::contains_key(&identity),
+			log_and_return_error_message(format!("Missing candidate count of identity {:?}", identity))
+		);
+		Ok(())
+	})?;
+
+	IdentityCandidateCount::<T>::iter().try_for_each(|(identity, count)| -> Result<(), TryRuntimeError> {
+		let linked = IdentityCandidates::<T>::iter_key_prefix(&identity).count();
+		ensure!(
+			count <= T::MaxCandidatesPerIdentity::get() && count == linked.saturated_into::<u32>(),
+			log_and_return_error_message(format!("Invalid candidate count of identity {:?}", identity))
+		);
+		Ok(())
+	})
+}
+
+fn validate_inflation<T: Config>() -> Result<(), TryRuntimeError> {
+	let blocks_per_year = T::BLOCKS_PER_YEAR.saturated_into();
+	ensure!(
+		InflationConfig::<T>::get().is_valid(blocks_per_year),
+		log_and_return_error_message(format!("Invalid inflation config {:?}", InflationConfig::<T>::get()))
+	);
+
+	let schedule = InflationSchedule::<T>::get();
+	ensure!(
+		crate::inflation::is_valid_schedule(&schedule, blocks_per_year),
+		log_and_return_error_message(format!("Invalid inflation schedule {:?}", schedule))
+	);
+	Ok(())
+}
+
+fn validate_round_history<T: Config>() -> Result<(), TryRuntimeError> {
+	let current = pallet_session::Pallet::<T>::current_index();
+	RoundHistory::<T>::iter_keys().try_for_each(|round| -> Result<(), TryRuntimeError> {
+		// expired rounds have to be pruned.
+		ensure!(
+			round.saturating_add(T::MaxRoundHistory::get()) > current,
+			log_and_return_error_message(format!("Expired statistics for round {:?}", round))
+		);
+		Ok(())
+	})
+}
+
+fn validate_pools<T: Config>() -> Result<(), TryRuntimeError> {
+	Pools::<T>::iter().try_for_each(|(pool_id, pool)| -> Result<(), TryRuntimeError> {
+		let (points, member_count) = PoolMembers::<T>::iter_prefix_values(pool_id)
+			.fold((BalanceOf::<T>::zero(), 0u32), |(points, count), member| {
+				(points.saturating_add(member.points), count.saturating_add(1))
+			});
+		// the points of the pool have to be the sum of the points of its members.
+		ensure!(
+			points == pool.points && member_count == pool.member_count,
+			log_and_return_error_message(format!("Corrupted points or members of pool {:?}", pool_id))
+		);
+
+		let unbonding = PoolUnbonding::<T>::iter_prefix_values(pool_id)
+			.flat_map(|unbonding| unbonding.into_iter().map(|(_, amount)| amount))
+			.fold(BalanceOf::<T>::zero(), |total, amount| total.saturating_add(amount));
+		ensure!(
+			unbonding == pool.unbonding,
+			log_and_return_error_message(format!("Corrupted unbonding funds of pool {:?}", pool_id))
+		);
+
+		// the account of a pool can only delegate.
+		let pool_account = Pallet::<T>::pool_account(pool_id);
+		ensure!(
+			Pallet::<T>::is_active_candidate(&pool_account).is_none(),
+			log_and_return_error_message(format!("Account of pool {:?} is a candidate", pool_id))
 		);
 		Ok(())
 	})
@@ -16,15 +16,19 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
-use frame_support::traits::{
-	fungible::{Credit, Inspect},
-	Get,
+use frame_support::{
+	traits::{
+		fungible::{Credit, Inspect},
+		Get,
+	},
+	BoundedVec,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, CheckedSub, Saturating, Zero},
-	RuntimeDebug,
+	FixedU128, Perbill, RuntimeDebug,
 };
 use sp_staking::SessionIndex;
 use sp_std::{
@@ -33,7 +37,7 @@ use sp_std::{
 	ops::{Add, Sub},
 };
 
-use crate::{set::OrderedSet, Config};
+use crate::{inflation::InflationStep, set::OrderedSet, traits::CollatorIdentityProvider, Config};
 
 /// A struct represented an amount of staked funds.
 ///
@@ -92,6 +96,9 @@ pub enum CandidateStatus {
 	Active,
 	/// Staked until the inner round
 	Leaving(SessionIndex),
+	/// Removed from the set of top candidates because of an offence until
+	/// the inner round
+	Suspended(SessionIndex),
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -155,6 +162,22 @@ where
 		self.status = CandidateStatus::Active;
 	}
 
+	pub const fn is_suspended(&self) -> bool {
+		matches!(self.status, CandidateStatus::Suspended(_))
+	}
+
+	pub const fn can_resume(&self, when: u32) -> bool {
+		matches!(self.status, CandidateStatus::Suspended(until) if until <= when)
+	}
+
+	pub fn suspend(&mut self, until: SessionIndex) {
+		self.status = CandidateStatus::Suspended(until);
+	}
+
+	pub fn resume(&mut self) {
+		self.status = CandidateStatus::Active;
+	}
+
 	pub fn stake_more(&mut self, more: B) {
 		self.stake = self.stake.saturating_add(more);
 		self.total = self.total.saturating_add(more);
@@ -199,45 +222,122 @@ where
 	}
 }
 
-pub type Delegator<AccountId, Balance> = Stake<AccountId, Balance>;
-impl<AccountId, Balance> Delegator<AccountId, Balance>
+/// The delegation state of an account which backs one or more collator
+/// candidates.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxCollatorsPerDelegator))]
+pub struct Delegator<AccountId, Balance, MaxCollatorsPerDelegator>
+where
+	MaxCollatorsPerDelegator: Get<u32> + Debug + PartialEq,
+{
+	/// The collator candidates backed by the delegator and the amount staked
+	/// for each of them.
+	pub delegations: OrderedSet<Stake<AccountId, Balance>, MaxCollatorsPerDelegator>,
+
+	/// The total amount staked over all delegations.
+	pub total: Balance,
+}
+
+// We access indices only after making sure they are properly verified.
+#[allow(clippy::indexing_slicing)]
+impl<AccountId, Balance, MaxCollatorsPerDelegator> Delegator<AccountId, Balance, MaxCollatorsPerDelegator>
 where
 	AccountId: Eq + Ord + Clone + Debug,
 	Balance: Copy + Add<Output = Balance> + Saturating + PartialOrd + Eq + Ord + Debug + Zero + Default + CheckedSub,
+	MaxCollatorsPerDelegator: Get<u32> + Debug + PartialEq,
 {
-	/// Returns Ok if the delegation for the
-	/// collator exists and `Err` otherwise.
-	pub fn try_clear(&mut self, collator: AccountId) -> Result<(), ()> {
-		if self.owner == collator {
-			self.amount = Balance::zero();
-			Ok(())
-		} else {
-			Err(())
+	/// Creates a new delegator state with a single delegation.
+	///
+	/// Returns `Err` if `MaxCollatorsPerDelegator` is zero.
+	pub fn try_new(collator: AccountId, amount: Balance) -> Result<Self, ()> {
+		let mut delegations = OrderedSet::new();
+		delegations
+			.try_insert(Stake {
+				owner: collator,
+				amount,
+			})
+			.map_err(|_| ())?;
+		Ok(Delegator {
+			delegations,
+			total: amount,
+		})
+	}
+
+	/// Adds a new delegation.
+	///
+	/// Returns Ok(true) if the delegation was added, Ok(false) if the
+	/// collator is already delegated and `Err` if `MaxCollatorsPerDelegator`
+	/// would be exceeded.
+	pub fn add_delegation(&mut self, stake: Stake<AccountId, Balance>) -> Result<bool, ()> {
+		let amount = stake.amount;
+		let inserted = self.delegations.try_insert(stake).map_err(|_| ())?;
+		if inserted {
+			self.total = self.total.saturating_add(amount);
 		}
+		Ok(inserted)
+	}
+
+	/// Returns the amount delegated to the collator, if a delegation for it
+	/// exists.
+	pub fn delegation_of(&self, collator: &AccountId) -> Option<Balance> {
+		self.delegations
+			.linear_search(&Stake {
+				owner: collator.clone(),
+				amount: Balance::zero(),
+			})
+			.ok()
+			.and_then(|i| self.delegations.get(i))
+			.map(|stake| stake.amount)
+	}
+
+	/// Removes the delegation for the collator.
+	///
+	/// Returns Some(delegated_amount) if successful, None if the delegation
+	/// was not found.
+	pub fn rm_delegation(&mut self, collator: AccountId) -> Option<Balance> {
+		let amount = self
+			.delegations
+			.remove(&Stake {
+				owner: collator,
+				// amount is irrelevant for removal
+				amount: Balance::zero(),
+			})?
+			.amount;
+		self.total = self.total.saturating_sub(amount);
+		Some(amount)
 	}
 
 	/// Returns Ok(delegated_amount) if successful, `Err` if delegation was
 	/// not found.
 	pub fn try_increment(&mut self, collator: AccountId, more: Balance) -> Result<Balance, ()> {
-		if self.owner == collator {
-			self.amount = self.amount.saturating_add(more);
-			Ok(self.amount)
-		} else {
-			Err(())
-		}
+		let i = self
+			.delegations
+			.linear_search(&Stake {
+				owner: collator,
+				amount: Balance::zero(),
+			})
+			.map_err(|_| ())?;
+		let new_amount = self.delegations[i].amount.saturating_add(more);
+		self.delegations.mutate(|vec| vec[i].amount = new_amount);
+		self.total = self.total.saturating_add(more);
+		Ok(new_amount)
 	}
 
 	/// Returns Ok(Some(delegated_amount)) if successful, `Err` if delegation
 	/// was not found and Ok(None) if delegated stake would underflow.
 	pub fn try_decrement(&mut self, collator: AccountId, less: Balance) -> Result<Option<Balance>, ()> {
-		if self.owner == collator {
-			Ok(self.amount.checked_sub(&less).map(|new| {
-				self.amount = new;
-				self.amount
-			}))
-		} else {
-			Err(())
-		}
+		let i = self
+			.delegations
+			.linear_search(&Stake {
+				owner: collator,
+				amount: Balance::zero(),
+			})
+			.map_err(|_| ())?;
+		Ok(self.delegations[i].amount.checked_sub(&less).map(|new_amount| {
+			self.delegations.mutate(|vec| vec[i].amount = new_amount);
+			self.total = self.total.saturating_sub(less);
+			new_amount
+		}))
 	}
 }
 
@@ -304,8 +404,141 @@ pub struct DelegationCounter {
 	pub counter: u32,
 }
 
+/// The misbehaviour a collator can be slashed for.
+#[derive(Copy, Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum Offence {
+	/// The collator authored too few blocks for `downtime_rounds` many
+	/// consecutive rounds.
+	Downtime,
+	/// The collator authored conflicting blocks.
+	Equivocation,
+}
+
+/// The governance-controlled parameters for slashing collators.
+///
+/// The default configuration disables slashing.
+#[derive(Default, Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SlashingConfiguration {
+	/// The share of the average number of blocks authored by the collators of
+	/// a round, a collator has to author to not be regarded offline.
+	pub min_authored_share: Perbill,
+	/// The number of consecutive rounds a collator has to be offline to
+	/// commit the `Downtime` offence.
+	pub downtime_rounds: u32,
+	/// The share of the stake of the collator and their delegators which is
+	/// slashed for the `Downtime` offence.
+	pub downtime_slash: Perbill,
+	/// The share of the stake of the collator and their delegators which is
+	/// slashed for the `Equivocation` offence.
+	pub equivocation_slash: Perbill,
+	/// The number of rounds an offender is removed from the set of top
+	/// candidates.
+	pub suspension_rounds: u32,
+}
+
+/// A slash which has been reported but not applied yet.
+#[derive(Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct DeferredSlash {
+	/// The latest offence reported for the collator.
+	pub offence: Offence,
+	/// The share of the stake which is slashed.
+	pub fraction: Perbill,
+	/// The round from which on the slash can be applied.
+	pub apply_at: SessionIndex,
+}
+
+/// The staking statistics of a collator for a single round.
+#[derive(Default, Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CollatorStats<Balance> {
+	/// The number of blocks authored by the collator.
+	pub blocks_authored: u32,
+	/// The own stake of the collator at the start of the round.
+	pub stake: Balance,
+	/// The sum of the stakes of the delegators at the start of the round.
+	pub delegators_stake: Balance,
+	/// The commission charged by the collator at the start of the round.
+	pub commission: Perbill,
+	/// The rewards credited to the collator during the round including the
+	/// commission.
+	pub collator_rewards: Balance,
+	/// The rewards credited to the delegators during the round after
+	/// deducting the commission.
+	pub delegator_rewards: Balance,
+}
+
+/// The staking statistics of a single round.
+#[derive(Default, Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RoundStats<Balance: Default> {
+	/// The total stake of collators and delegators at the start of the round.
+	pub total_stake: TotalStake<Balance>,
+	/// The number of blocks authored by all collators.
+	pub blocks_authored: u32,
+	/// The total rewards credited to collators and delegators during the
+	/// round.
+	pub total_rewarded: Balance,
+}
+
+/// The identifier of a staking pool.
+pub type PoolId = u32;
+
+/// Whether new members can join a staking pool.
+#[derive(Copy, Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PoolState {
+	/// Anyone can join the pool.
+	Open,
+	/// No new funds can be added to the pool.
+	Blocked,
+}
+
+/// The accounts which are permitted to manage a staking pool.
+#[derive(Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PoolRoles<AccountId> {
+	/// Can change the roles of the pool.
+	pub owner: AccountId,
+	/// Can change the commission and the state of the pool and receives the
+	/// commission.
+	pub operator: AccountId,
+}
+
+/// A staking pool whose account delegates on behalf of its members.
+#[derive(Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct StakingPool<AccountId, Balance> {
+	/// The accounts managing the pool.
+	pub roles: PoolRoles<AccountId>,
+	/// The collator candidate backed by the pool.
+	pub collator: AccountId,
+	/// The share of the rewards of the pool paid to the operator.
+	pub commission: Perbill,
+	/// Whether new members can join the pool.
+	pub state: PoolState,
+	/// The sum of the points of all members.
+	pub points: Balance,
+	/// The funds which were unbonded by members but not withdrawn yet.
+	pub unbonding: Balance,
+	/// The accumulated rewards per point since the creation of the pool.
+	pub reward_per_point: FixedU128,
+	/// The number of members holding points.
+	pub member_count: u32,
+}
+
+/// The share of a member in a staking pool.
+#[derive(Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PoolMember<Balance> {
+	/// The points of the member which entitle to a pro-rata share of the
+	/// stake and rewards of the pool.
+	pub points: Balance,
+	/// The rewards per point of the pool when the rewards of the member were
+	/// last settled.
+	pub last_reward_per_point: FixedU128,
+}
+
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
 pub type CandidateOf<T, S> = Candidate<AccountIdOf<T>, BalanceOf<T>, S>;
 pub type StakeOf<T> = Stake<AccountIdOf<T>, BalanceOf<T>>;
+pub type DelegatorOf<T> = Delegator<AccountIdOf<T>, BalanceOf<T>, <T as Config>::MaxCollatorsPerDelegator>;
 pub(crate) type CreditOf<T> = Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+pub type StakingPoolOf<T> = StakingPool<AccountIdOf<T>, BalanceOf<T>>;
+pub type PoolMemberOf<T> = PoolMember<BalanceOf<T>>;
+pub type IdentityOf<T> = <<T as Config>::IdentityProvider as CollatorIdentityProvider<AccountIdOf<T>>>::Identity;
+pub type InflationScheduleOf<T> = BoundedVec<InflationStep<BlockNumberFor<T>>, <T as Config>::MaxInflationSteps>;
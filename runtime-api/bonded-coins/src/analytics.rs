@@ -0,0 +1,101 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use pallet_bonded_coins::PoolStatus;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Filter for the status of listed pools.
+#[derive(Clone, Copy, Decode, Encode, TypeInfo, PartialEq, Eq, Debug)]
+pub enum PoolStatusFilter {
+	/// Pools which are active.
+	Active,
+	/// Pools which are locked.
+	Locked,
+	/// Pools which are either active or locked.
+	Live,
+	/// Pools which are refunding.
+	Refunding,
+	/// Pools which are being destroyed.
+	Destroying,
+	/// Pools which have graduated into an AMM.
+	Graduated,
+}
+
+impl PoolStatusFilter {
+	/// Checks if the given status passes the filter.
+	pub const fn matches<LockType>(&self, status: &PoolStatus<LockType>) -> bool {
+		match self {
+			Self::Active => matches!(status, PoolStatus::Active),
+			Self::Locked => matches!(status, PoolStatus::Locked(_)),
+			Self::Live => status.is_live(),
+			Self::Refunding => status.is_refunding(),
+			Self::Destroying => status.is_destroying(),
+			Self::Graduated => status.is_graduated(),
+		}
+	}
+}
+
+/// A page of listed pools.
+#[derive(Decode, Encode, TypeInfo)]
+pub struct PoolsPage<PoolId, Pool> {
+	/// The pools of the page.
+	pub pools: Vec<Pool>,
+	/// The pool to start the next page after. `None` if all pools have been
+	/// listed.
+	pub next: Option<PoolId>,
+}
+
+/// Market data of a pool used for the runtime API.
+#[derive(Decode, Encode, TypeInfo)]
+pub struct PoolAnalytics<AssetId, Balance> {
+	/// The collateral held by the pool, excluding the fees accrued by the
+	/// pool.
+	pub collateral_locked: Balance,
+	/// The market data of the bonded currencies of the pool.
+	pub currencies: Vec<CurrencyAnalytics<AssetId, Balance>>,
+}
+
+/// Market data of a bonded currency used for the runtime API.
+#[derive(Decode, Encode, TypeInfo)]
+pub struct CurrencyAnalytics<AssetId, Balance> {
+	pub id: AssetId,
+	pub supply: Balance,
+	/// The collateral required to mint one whole coin at the current supply,
+	/// excluding fees. `None` if the pool is not trading.
+	pub spot_price: Option<Balance>,
+}
+
+/// Holder of a bonded currency used for the runtime API.
+#[derive(Decode, Encode, TypeInfo)]
+pub struct Holder<AccountId, Balance> {
+	pub account: AccountId,
+	pub balance: Balance,
+}
+
+/// The largest holders of a bonded currency among a page of accounts used
+/// for the runtime API.
+#[derive(Decode, Encode, TypeInfo)]
+pub struct TopHolders<AccountId, Balance> {
+	/// The largest holders of the page, sorted by balance in descending order.
+	pub holders: Vec<Holder<AccountId, Balance>>,
+	/// The account to start the next page after. `None` if all accounts
+	/// holding the currency have been considered.
+	pub next: Option<AccountId>,
+}
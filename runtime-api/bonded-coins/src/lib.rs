@@ -23,7 +23,9 @@ use parity_scale_codec::{alloc::string::String, Codec, Decode, Encode};
 use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
+mod analytics;
 mod pool_details;
+pub use analytics::*;
 pub use pool_details::*;
 
 /// Coefficient representation.
@@ -44,7 +46,7 @@ pub trait OperationValue {
 sp_api::decl_runtime_apis! {
 	/// Runtime API to compute the collateral for a given amount and pool ID
 	/// and to query all pool IDs where the given account is the manager or owner.
-	#[api_version(2)]
+	#[api_version(6)]
 	pub trait BondedCurrency<Balance, PoolId, Operation, AccountId, BondedAssetId, CollateralAssetId, BitType, Error> where
 		Balance: Codec,
 		PoolId: Codec,
@@ -58,6 +60,8 @@ sp_api::decl_runtime_apis! {
 			/// Calculates the collateral for the given amount.
 			/// The operation is determining whether the amount is minted or burned.
 			/// The calculated collateral amount is based on the current state of the pool.
+			/// It includes the fees of the pool and the protocol: they are added to the costs of
+			/// minting and deducted from the collateral returned by burning.
 			fn quote_for(
 				pool_id: PoolId,
 				currency_idx: u8,
@@ -91,9 +95,60 @@ sp_api::decl_runtime_apis! {
 			fn decode_curve_coefficient(bit_representation: BitType) -> Result<String, Error>;
 
 			/// Query the pool status in a human readable format.
+			/// Since version 5, the details include the metadata of the pool and the identity of its owner.
 			fn pool_info(pool_id: PoolId) -> Result<PoolDetailsOf<AccountId, Balance, BondedAssetId, CollateralAssetId>, Error>;
 
 			/// Query the pools status in a human readable format.
 			fn pool_infos(pool_ids: Vec<PoolId>) -> Result<Vec<PoolDetailsOf<AccountId, Balance, BondedAssetId, CollateralAssetId>>, Error>;
+
+			/// Calculates the amount of bonded tokens received for swapping `amount_in` tokens of the
+			/// currency at `from_idx` for the currency at `to_idx` of the same pool.
+			/// The calculated amount is based on the current state of the pool and includes the burn
+			/// fee of the pool and the protocol fee.
+			#[api_version(3)]
+			fn quote_swap_within_pool(
+				pool_id: PoolId,
+				from_idx: u8,
+				to_idx: u8,
+				amount_in: Balance,
+			) -> Result<Balance, Error>;
+
+			/// Calculates the time-weighted average price of the currency at `currency_idx` over at
+			/// least the last `window` blocks. The price is denominated in the smallest unit of the
+			/// collateral currency per whole coin of the bonded currency.
+			#[api_version(4)]
+			fn twap(
+				pool_id: PoolId,
+				currency_idx: u8,
+				window: u32,
+			) -> Result<Balance, Error>;
+
+			/// Lists the pools, optionally filtered by their status. The listing starts after the pool
+			/// `start` and contains at most `limit` pools. The number of pools returned and read per
+			/// call is bounded by the runtime, so a page can contain fewer pools than requested even
+			/// if `next` is set.
+			#[api_version(6)]
+			fn list_pools(
+				start: Option<PoolId>,
+				limit: u32,
+				status: Option<PoolStatusFilter>,
+			) -> Result<PoolsPage<PoolId, PoolDetailsOf<AccountId, Balance, BondedAssetId, CollateralAssetId>>, Error>;
+
+			/// Query the market data of a pool: the collateral locked in the pool and the supply and spot
+			/// price of each of its currencies.
+			#[api_version(6)]
+			fn pool_analytics(pool_id: PoolId) -> Result<PoolAnalytics<BondedAssetId, Balance>, Error>;
+
+			/// Query the at most `limit` largest holders of the currency at `currency_idx` among the
+			/// accounts after the account `start`. The number of accounts considered per call is bounded
+			/// by the runtime, so the largest holders overall are found by following `next` until it is
+			/// `None` and merging the holders of all pages.
+			#[api_version(6)]
+			fn top_holders(
+				pool_id: PoolId,
+				currency_idx: u8,
+				start: Option<AccountId>,
+				limit: u32,
+			) -> Result<TopHolders<AccountId, Balance>, Error>;
 		}
 }
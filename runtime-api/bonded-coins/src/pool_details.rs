@@ -36,6 +36,8 @@ pub type PoolDetailsOf<AccountId, Balance, AssetId, CollateralAssetId> = PoolDet
 	CollateralDetails<CollateralAssetId>,
 	Balance,
 	Balance,
+	Option<PoolMetadataDetails>,
+	Option<IssuerDetails<AccountId>>,
 >;
 
 #[derive(Default, Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug)]
@@ -47,6 +49,8 @@ pub struct PoolDetails<
 	BaseCurrencyId,
 	DepositBalance,
 	FungiblesBalance,
+	Metadata,
+	Issuer,
 > {
 	/// The ID of the pool.
 	pub id: PoolId,
@@ -66,6 +70,10 @@ pub struct PoolDetails<
 	pub currencies_settings: BondedCurrenciesSettings<FungiblesBalance>,
 	/// The deposit to be returned upon destruction of this pool.
 	pub deposit: DepositBalance,
+	/// Descriptive metadata of the pool.
+	pub metadata: Metadata,
+	/// The identity of the owner of the pool.
+	pub issuer: Issuer,
 }
 /// Collateral currency details used for the runtime API.
 #[derive(Decode, Encode, TypeInfo)]
@@ -84,3 +92,21 @@ pub struct BondedCurrencyDetails<AssetId, Balance> {
 	pub symbol: String,
 	pub supply: Balance,
 }
+
+/// Pool metadata details used for the runtime API.
+#[derive(Decode, Encode, TypeInfo)]
+pub struct PoolMetadataDetails {
+	pub description: Option<String>,
+	pub uri: Option<String>,
+	pub logo_hash: Option<Vec<u8>>,
+}
+
+/// Details of the identity of a pool owner used for the runtime API.
+#[derive(Decode, Encode, TypeInfo)]
+pub struct IssuerDetails<DidIdentifier> {
+	pub did: DidIdentifier,
+	pub credential_id: Vec<u8>,
+	/// Whether the owner still controls the DID and the credential is still
+	/// valid. Credentials can be revoked after the pool has been linked.
+	pub verified: bool,
+}
@@ -18,6 +18,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use pallet_did_lookup::{AccountPurpose, LinkMetadata};
 use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::vec::Vec;
@@ -48,11 +49,62 @@ pub struct DidLinkedInfo<
 	pub details: DidDetails<Key, BlockNumber, AccountId, Balance>,
 }
 
-/// The DidLinkedInfo with a Web3Name represented as a byte array.
+/// An account linked to a DID, together with the metadata the DID attached
+/// to it.
+#[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
+pub struct LinkedAccount<LinkableAccountId, Label> {
+	pub account: LinkableAccountId,
+	pub primary: bool,
+	pub label: Option<Label>,
+	pub purpose: Option<AccountPurpose>,
+}
+
+/// The LinkedAccount with a label represented as a byte array.
+pub type RawLinkedAccount<LinkableAccountId> = LinkedAccount<LinkableAccountId, Vec<u8>>;
+
+impl<LinkableAccountId, Label: Into<Vec<u8>>> From<(LinkableAccountId, Option<LinkMetadata<Label>>)>
+	for RawLinkedAccount<LinkableAccountId>
+{
+	fn from((account, metadata): (LinkableAccountId, Option<LinkMetadata<Label>>)) -> Self {
+		let LinkMetadata {
+			primary,
+			label,
+			purpose,
+		} = metadata.unwrap_or(LinkMetadata {
+			primary: false,
+			label: None,
+			purpose: None,
+		});
+		Self {
+			account,
+			primary,
+			label: label.map(Into::into),
+			purpose,
+		}
+	}
+}
+
+/// The DidLinkedInfo with a Web3Name represented as a byte array and the
+/// linked accounts including their metadata.
 ///
 /// This will be returned by the runtime and processed by the client side RPC
 /// implementation.
 pub type RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber> = DidLinkedInfo<
+	DidIdentifier,
+	AccountId,
+	RawLinkedAccount<LinkableAccountId>,
+	Vec<u8>,
+	Vec<u8>,
+	Vec<u8>,
+	Vec<u8>,
+	Balance,
+	Key,
+	BlockNumber,
+>;
+
+/// The RawDidLinkedInfo returned before version 5 of the runtime API, which
+/// does not include the metadata of linked accounts.
+pub type RawDidLinkedInfoV4<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber> = DidLinkedInfo<
 	DidIdentifier,
 	AccountId,
 	LinkableAccountId,
@@ -66,7 +118,7 @@ pub type RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance,
 >;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(4)]
+	#[api_version(5)]
 	pub trait Did<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber, LinkedResource, RuntimeCall> where
 		DidIdentifier: Codec,
 		AccountId: Codec,
@@ -84,9 +136,14 @@ sp_api::decl_runtime_apis! {
 		/// * associated accounts
 		/// * service endpoints
 		#[changed_in(2)]
-		fn query_by_web3_name(name: Vec<u8>) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		fn query_by_web3_name(name: Vec<u8>) -> Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		#[changed_in(5)]
+		fn query_by_web3_name(name: Vec<u8>) -> Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		fn query_by_web3_name(name: Vec<u8>) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		/// Allows for batching multiple `query_by_web3_name` requests into one. For each requested name, the corresponding vector entry contains either `Some` or `None` depending on the result of each query.
+		#[changed_in(5)]
+		#[allow(clippy::type_complexity)]
+		fn batch_query_by_web3_name(names: Vec<Vec<u8>>) -> Vec<Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
 		#[allow(clippy::type_complexity)]
 		fn batch_query_by_web3_name(names: Vec<Vec<u8>>) -> Vec<Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
 		/// Given an account address this returns:
@@ -96,9 +153,14 @@ sp_api::decl_runtime_apis! {
 		/// * associated accounts
 		/// * service endpoints
 		#[changed_in(2)]
-		fn query_by_account(account: AccountId) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		fn query_by_account(account: AccountId) -> Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		#[changed_in(5)]
+		fn query_by_account(account: LinkableAccountId) -> Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		fn query_by_account(account: LinkableAccountId) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		/// Allows for batching multiple `query_by_account` requests into one. For each requested name, the corresponding vector entry contains either `Some` or `None` depending on the result of each query.
+		#[changed_in(5)]
+		#[allow(clippy::type_complexity)]
+		fn batch_query_by_account(accounts: Vec<LinkableAccountId>) -> Vec<Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
 		#[allow(clippy::type_complexity)]
 		fn batch_query_by_account(accounts: Vec<LinkableAccountId>) -> Vec<Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
 		/// Given a did this returns:
@@ -108,9 +170,14 @@ sp_api::decl_runtime_apis! {
 		/// * associated accounts
 		/// * service endpoints
 		#[changed_in(2)]
-		fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		fn query(did: DidIdentifier) -> Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, AccountId, Balance, Key, BlockNumber>>;
+		#[changed_in(5)]
+		fn query(did: DidIdentifier) -> Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>;
 		/// Allows for batching multiple `query` requests into one. For each requested name, the corresponding vector entry contains either `Some` or `None` depending on the result of each query.
+		#[changed_in(5)]
+		#[allow(clippy::type_complexity)]
+		fn batch_query(dids: Vec<DidIdentifier>) -> Vec<Option<RawDidLinkedInfoV4<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
 		#[allow(clippy::type_complexity)]
 		fn batch_query(dids: Vec<DidIdentifier>) -> Vec<Option<RawDidLinkedInfo<DidIdentifier, AccountId, LinkableAccountId, Balance, Key, BlockNumber>>>;
 
@@ -119,4 +186,13 @@ sp_api::decl_runtime_apis! {
 		/// Returns the list of calls that must be executed to delete the linked resources of a given DID, before deleting the DID itself.
 		fn linked_resources_deletion_calls(did: DidIdentifier) -> Vec<RuntimeCall>;
 	}
+
+	/// Lightweight reverse resolution of accounts to web3names.
+	pub trait Web3NameLookup<LinkableAccountId> where
+		LinkableAccountId: Codec,
+	{
+		/// For each requested account, the corresponding vector entry contains the web3name of the DID the account is linked to, or `None` if the account is not linked or the DID has no web3name.
+		/// Unlike `batch_query_by_account`, neither the DID details, nor the linked accounts, nor the service endpoints are read.
+		fn batch_query_web3_name_by_account(accounts: Vec<LinkableAccountId>) -> Vec<Option<Vec<u8>>>;
+	}
 }
@@ -20,7 +20,8 @@
 
 use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::Perquintill;
+use sp_runtime::{FixedU128, Perbill, Perquintill};
+use sp_std::vec::Vec;
 
 #[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
 pub struct StakingRates {
@@ -30,8 +31,48 @@ pub struct StakingRates {
 	pub delegator_reward_rate: Perquintill,
 }
 
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+pub struct CollatorCommission {
+	/// The commission charged on the rewards of the delegators.
+	pub commission: Perbill,
+	/// The maximum commission a collator candidate can charge.
+	pub max_commission: Perbill,
+	/// The scheduled commission and the round from which on it can be
+	/// applied.
+	pub scheduled: Option<(Perbill, u32)>,
+}
+
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+pub struct CollatorRoundStats<Balance> {
+	/// The index of the round.
+	pub round: u32,
+	/// The number of blocks authored by the collator.
+	pub blocks_authored: u32,
+	/// The own stake of the collator at the start of the round.
+	pub stake: Balance,
+	/// The sum of the stakes of the delegators at the start of the round.
+	pub delegators_stake: Balance,
+	/// The commission charged by the collator at the start of the round.
+	pub commission: Perbill,
+	/// The rewards credited to the collator during the round including the
+	/// commission.
+	pub collator_rewards: Balance,
+	/// The rewards credited to the delegators during the round after
+	/// deducting the commission.
+	pub delegator_rewards: Balance,
+}
+
+#[derive(Decode, Encode, TypeInfo, PartialEq, Eq, Debug)]
+pub struct CandidateIdentity<DidIdentifier> {
+	/// The DID the collator candidate is linked to.
+	pub did: DidIdentifier,
+	/// The web3name of the DID, if any.
+	pub web3_name: Option<Vec<u8>>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// The API to query staking and reward rates.
+	#[api_version(6)]
 	pub trait Staking<AccountId, Balance>
 	where
 		AccountId: Codec,
@@ -42,5 +83,31 @@ sp_api::decl_runtime_apis! {
 		/// Returns the current staking and reward rates for collators and
 		/// delegators.
 		fn get_staking_rates() -> StakingRates;
+		/// Returns the commission of a collator candidate or `None` if the
+		/// account is not a candidate.
+		#[api_version(2)]
+		fn get_collator_commission(collator: &AccountId) -> Option<CollatorCommission>;
+		/// Returns the share of rewards which is automatically staked for a
+		/// given account address.
+		#[api_version(3)]
+		fn get_auto_compound(account: &AccountId) -> Perbill;
+		/// Returns the statistics of a collator for the last `rounds`
+		/// completed rounds.
+		#[api_version(4)]
+		fn get_collator_stats(collator: &AccountId, rounds: u32) -> Vec<CollatorRoundStats<Balance>>;
+		/// Returns the effective annual yield of the delegators of a collator
+		/// over the last `rounds` completed rounds or `None` if there are no
+		/// statistics for these rounds.
+		#[changed_in(6)]
+		fn get_delegator_apy(collator: &AccountId, rounds: u32) -> Option<Perquintill>;
+		/// Returns the effective annual yield of the delegators of a collator
+		/// over the last `rounds` completed rounds as a ratio, which can
+		/// exceed one, or `None` if there are no statistics for these rounds.
+		#[api_version(4)]
+		fn get_delegator_apy(collator: &AccountId, rounds: u32) -> Option<FixedU128>;
+		/// Returns all collator candidates together with the DID and web3name
+		/// they are linked to, if any.
+		#[api_version(5)]
+		fn get_candidate_identities() -> Vec<(AccountId, Option<CandidateIdentity<AccountId>>)>;
 	}
 }
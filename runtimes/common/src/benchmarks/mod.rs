@@ -17,6 +17,7 @@
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
 pub mod treasury;
+pub mod web3_name_lookup;
 pub mod xcm;
 
 use parity_scale_codec::{Decode, Encode};
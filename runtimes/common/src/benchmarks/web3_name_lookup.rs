@@ -0,0 +1,128 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! Benchmarks comparing the cost of resolving accounts to web3names via the
+//! lightweight reverse lookup against resolving the whole DID linked
+//! information, as done by the `Did::batch_query_by_account` runtime API.
+
+use core::hint::black_box;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::Get;
+use kilt_support::Deposit;
+use pallet_did_lookup::{
+	linkable_account::LinkableAccountId, ConnectedAccountCount, ConnectedAccounts, ConnectedDids, ConnectedNames,
+	ConnectionRecord,
+};
+use pallet_web3_names::{BenchmarkHelper, Names, Web3NameOf};
+use parity_scale_codec::Encode;
+use sp_core::sr25519;
+use sp_io::hashing::blake2_256;
+use sp_runtime::{traits::Zero, SaturatedConversion};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use did::{
+	did_details::{DidDetails, DidVerificationKey},
+	DidIdentifierOf,
+};
+
+use crate::did::{web3_name_by_account, LinkedWeb3Names};
+
+const SEED: u32 = 0;
+const MAX_ACCOUNTS: u32 = 50;
+
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+pub trait Config:
+	did::Config
+	+ pallet_did_lookup::Config<DidIdentifier = DidIdentifierOf<Self>, NameProvider = LinkedWeb3Names<Self>>
+	+ pallet_web3_names::Config<Web3NameOwner = DidIdentifierOf<Self>>
+{
+}
+
+/// Creates `n` accounts, each linked to a different DID with a web3name.
+fn setup_linked_accounts<T: Config>(n: u32) -> Vec<LinkableAccountId> {
+	let name_input = <T as pallet_web3_names::Config>::BenchmarkHelper::generate_name_input_with_length(
+		<T as pallet_web3_names::Config>::MaxNameLength::get().saturated_into(),
+	);
+	let name = Web3NameOf::<T>::try_from(name_input).expect("Web3name creation should not fail.");
+
+	(0..n)
+		.map(|i| {
+			let did: DidIdentifierOf<T> = account("did", i, SEED);
+			let deposit_owner: T::AccountId = account("owner", i, SEED);
+			let linked_account = LinkableAccountId::from(blake2_256(&(b"linked", i).encode()));
+
+			let auth_key = DidVerificationKey::from(sr25519::Public::from_raw(blake2_256(&(b"auth", i).encode())));
+			let details = DidDetails::<T>::new(
+				auth_key,
+				frame_system::Pallet::<T>::block_number(),
+				deposit_owner.clone(),
+			)
+			.expect("Failed to generate DID details.");
+			did::Did::<T>::insert(&did, details);
+
+			ConnectedDids::<T>::insert(
+				&linked_account,
+				ConnectionRecord {
+					did: did.clone(),
+					deposit: Deposit {
+						owner: deposit_owner,
+						amount: Zero::zero(),
+					},
+				},
+			);
+			ConnectedAccounts::<T>::insert(&did, &linked_account, ());
+			ConnectedAccountCount::<T>::insert(&did, 1);
+			ConnectedNames::<T>::insert(&linked_account, name.clone());
+			Names::<T>::insert(&did, name.clone());
+
+			linked_account
+		})
+		.collect()
+}
+
+benchmarks! {
+	batch_query_by_account {
+		let n in 1 .. MAX_ACCOUNTS;
+		let accounts = setup_linked_accounts::<T>(n);
+	}: {
+		// Mirrors the storage accesses of `Did::query_by_account` for each account.
+		for account in &accounts {
+			let Some(connection_record) = ConnectedDids::<T>::get(account) else {
+				continue;
+			};
+			let details = did::Did::<T>::get(&connection_record.did);
+			let w3n = Names::<T>::get(&connection_record.did);
+			let linked_accounts: Vec<_> = pallet_did_lookup::Pallet::<T>::linked_accounts(&connection_record.did).collect();
+			let service_endpoints: Vec<_> = did::ServiceEndpoints::<T>::iter_prefix(&connection_record.did).collect();
+			black_box((details, w3n, linked_accounts, service_endpoints));
+		}
+	}
+
+	batch_query_web3_name_by_account {
+		let n in 1 .. MAX_ACCOUNTS;
+		let accounts = setup_linked_accounts::<T>(n);
+	}: {
+		for account in &accounts {
+			black_box(web3_name_by_account::<T>(account));
+		}
+	}
+	verify {
+		assert!(accounts.iter().all(|account| web3_name_by_account::<T>(account).is_some()));
+	}
+}
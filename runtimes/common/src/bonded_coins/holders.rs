@@ -0,0 +1,84 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use frame_support::{
+	pallet_prelude::{OptionQuery, PalletInfoAccess},
+	storage_alias, Blake2_128Concat,
+};
+use pallet_assets::AssetAccount;
+use sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+
+use crate::{bonded_coins::AssetId, AccountId, Balance};
+
+/// The accounts of the `pallet_assets` instance holding the bonded
+/// currencies. The storage item of the pallet is not public.
+#[storage_alias]
+pub type Account<BondedFungibles: PalletInfoAccess> = StorageDoubleMap<
+	BondedFungibles,
+	Blake2_128Concat,
+	AssetId,
+	Blake2_128Concat,
+	AccountId,
+	AssetAccount<Balance, Balance, (), AccountId>,
+	OptionQuery,
+>;
+
+/// Returns the `limit` accounts with the largest balance of a bonded
+/// currency, sorted by balance in descending order.
+///
+/// The accounts are read in storage order, starting after the account
+/// `start`, and at most `max_scanned` accounts are read. Along with the
+/// holders, the last account read is returned if there are accounts left to
+/// read, which is the `start` of the next call. The overall largest holders
+/// are the largest of all holders returned until no account is left.
+pub fn top_holders<BondedFungibles>(
+	asset_id: AssetId,
+	start: Option<AccountId>,
+	limit: usize,
+	max_scanned: usize,
+) -> (Vec<(AccountId, Balance)>, Option<AccountId>)
+where
+	BondedFungibles: PalletInfoAccess,
+{
+	let mut holders: Vec<(AccountId, Balance)> = Vec::new();
+	let mut accounts = match start {
+		Some(start) => Account::<BondedFungibles>::iter_prefix_from(
+			asset_id,
+			Account::<BondedFungibles>::hashed_key_for(asset_id, start),
+		),
+		None => Account::<BondedFungibles>::iter_prefix(asset_id),
+	};
+	let mut last_scanned = None;
+
+	for (account, details) in accounts.by_ref().take(max_scanned) {
+		last_scanned = Some(account.clone());
+		if details.balance.is_zero() {
+			continue;
+		}
+		let position = holders.partition_point(|(_, other)| *other >= details.balance);
+		if position < limit {
+			holders.insert(position, (account, details.balance));
+			holders.truncate(limit);
+		}
+	}
+
+	let next = accounts.next().and(last_scanned);
+
+	(holders, next)
+}
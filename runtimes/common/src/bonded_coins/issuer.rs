@@ -0,0 +1,143 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::traits::{Get, SortedMembers};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_bonded_coins::traits::VerifyIssuer;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use parity_scale_codec::Encode;
+use public_credentials::{CredentialIdOf, CredentialSubjects, Credentials};
+use sp_core::hexdisplay::HexDisplay;
+use sp_runtime::traits::Zero;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use crate::{AccountId, DidIdentifier};
+
+/// Returns the subject a credential must have to back the issuer of the pool
+/// `pool_id`, i.e., the asset DID
+/// `did:asset:polkadot:<genesis hash>.bonded:<pool id>` with both the first 16
+/// bytes of the genesis hash and the encoded pool ID in hex.
+pub fn pool_subject<Runtime, PoolId>(pool_id: &PoolId) -> Option<<Runtime as public_credentials::Config>::SubjectId>
+where
+	Runtime: public_credentials::Config,
+	PoolId: Encode,
+{
+	let genesis_hash = frame_system::Pallet::<Runtime>::block_hash(BlockNumberFor::<Runtime>::zero());
+	let genesis_reference = genesis_hash.as_ref().get(..16)?;
+	let subject = parity_scale_codec::alloc::format!(
+		"did:asset:polkadot:{}.bonded:{}",
+		HexDisplay::from(&genesis_reference),
+		HexDisplay::from(&pool_id.encode())
+	);
+	<Runtime as public_credentials::Config>::SubjectId::try_from(Vec::from(subject.as_bytes())).ok()
+}
+
+/// Verifies the link of a bonded coin pool to the DID of its owner. The
+/// owner's account must be linked to the DID via `pallet_did_lookup`, and the
+/// referenced public credential must
+/// - have the pool as subject, as returned by [`pool_subject`],
+/// - be of the CType `IssuerCtype`,
+/// - be attested by a member of `TrustedAttesters` other than the DID itself,
+/// - not be revoked.
+pub struct PublicCredentialIssuer<Runtime, TrustedAttesters, IssuerCtype>(
+	PhantomData<(Runtime, TrustedAttesters, IssuerCtype)>,
+);
+
+impl<Runtime, TrustedAttesters, IssuerCtype, PoolId> VerifyIssuer<AccountId, PoolId>
+	for PublicCredentialIssuer<Runtime, TrustedAttesters, IssuerCtype>
+where
+	Runtime: frame_system::Config<AccountId = AccountId>
+		+ pallet_did_lookup::Config<DidIdentifier = DidIdentifier>
+		+ public_credentials::Config<AttesterId = DidIdentifier>,
+	TrustedAttesters: SortedMembers<DidIdentifier>,
+	IssuerCtype: Get<<Runtime as frame_system::Config>::Hash>,
+	PoolId: Encode,
+{
+	type Identifier = DidIdentifier;
+	type CredentialId = CredentialIdOf<Runtime>;
+
+	fn is_verified(
+		who: &AccountId,
+		pool_id: &PoolId,
+		did: &DidIdentifier,
+		credential_id: &CredentialIdOf<Runtime>,
+	) -> bool {
+		let is_linked = pallet_did_lookup::ConnectedDids::<Runtime>::get(LinkableAccountId::from(who.to_owned()))
+			.is_some_and(|connection| &connection.did == did);
+		if !is_linked {
+			return false;
+		}
+
+		let Some(subject_id) = CredentialSubjects::<Runtime>::get(credential_id) else {
+			return false;
+		};
+		if pool_subject::<Runtime, PoolId>(pool_id).as_ref() != Some(&subject_id) {
+			return false;
+		}
+
+		Credentials::<Runtime>::get(subject_id, credential_id).is_some_and(|credential| {
+			!credential.revoked
+				&& credential.ctype_hash == IssuerCtype::get()
+				&& &credential.attester != did
+				&& TrustedAttesters::contains(&credential.attester)
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_verified_issuer(who: &AccountId, pool_id: &PoolId) -> (DidIdentifier, CredentialIdOf<Runtime>) {
+		use kilt_support::Deposit;
+		use pallet_did_lookup::ConnectionRecord;
+		use public_credentials::CredentialEntry;
+		use sp_runtime::traits::Hash;
+
+		let did: DidIdentifier = who.to_owned();
+		pallet_did_lookup::ConnectedDids::<Runtime>::insert(
+			LinkableAccountId::from(who.to_owned()),
+			ConnectionRecord {
+				did: did.clone(),
+				deposit: Deposit {
+					owner: who.to_owned(),
+					amount: Zero::zero(),
+				},
+			},
+		);
+
+		let attester = DidIdentifier::new([0xa7; 32]);
+		TrustedAttesters::add(&attester);
+
+		let subject_id =
+			pool_subject::<Runtime, PoolId>(pool_id).expect("Benchmark subject should be a valid asset DID.");
+		let credential_id = <Runtime as public_credentials::Config>::CredentialHash::hash(&who.encode());
+		Credentials::<Runtime>::insert(
+			&subject_id,
+			credential_id,
+			CredentialEntry {
+				ctype_hash: IssuerCtype::get(),
+				attester,
+				revoked: false,
+				block_number: frame_system::Pallet::<Runtime>::block_number(),
+				deposit: Deposit {
+					owner: who.to_owned(),
+					amount: Zero::zero(),
+				},
+				authorization_id: None,
+			},
+		);
+		CredentialSubjects::<Runtime>::insert(credential_id, subject_id);
+
+		(did, credential_id)
+	}
+}
@@ -16,6 +16,7 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
+use did::DidRawOrigin;
 /// Wrapper struct for [UnionOf] to implement the [metadata::Inspect] trait,
 /// needed for the pallet_bonded_coins module.
 use frame_support::{
@@ -32,7 +33,7 @@ use frame_support::{
 };
 use kilt_support::traits::InspectMetadata;
 use sp_runtime::{
-	traits::Convert,
+	traits::{Convert, Morph},
 	DispatchError, Either,
 	Either::{Left, Right},
 };
@@ -44,8 +45,14 @@ use substrate_fixed::{
 
 pub mod hooks;
 
+pub mod holders;
+
 pub mod runtime_api;
 
+pub mod remote_collateral;
+
+pub mod issuer;
+
 /// The AssetId for bonded assets.
 pub type AssetId = u32;
 
@@ -58,6 +65,18 @@ pub type FixedPoint = I75F53;
 /// For a I75F53, the underlying type is a i128.
 pub type FixedPointUnderlyingType = <FixedPoint as Fixed>::Bits;
 
+/// Maps a DID origin to its subject, so that a DID can act as the resolver of
+/// a bonded pool.
+pub struct DidSubject<DidIdentifier, AccountId>(PhantomData<(DidIdentifier, AccountId)>);
+
+impl<DidIdentifier, AccountId> Morph<DidRawOrigin<DidIdentifier, AccountId>> for DidSubject<DidIdentifier, AccountId> {
+	type Outcome = DidIdentifier;
+
+	fn morph(origin: DidRawOrigin<DidIdentifier, AccountId>) -> Self::Outcome {
+		origin.id
+	}
+}
+
 /// Struct to implement the desired [Convert] trait needed for the
 /// [NativeAndForeignAssets] type.
 /// The generic type Target is used to determine the type of the asset id
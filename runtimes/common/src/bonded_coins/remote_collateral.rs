@@ -0,0 +1,62 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_system::RawOrigin;
+use pallet_bonded_coins::traits::RemoteCollateralTransfer;
+use sp_runtime::{DispatchError, DispatchResult};
+use sp_std::{boxed::Box, marker::PhantomData};
+use xcm::{
+	v4::{Asset, Assets, Location, WeightLimit},
+	VersionedAssets, VersionedLocation,
+};
+
+use crate::{xcm_config::sibling_reserve_of, AccountId, Balance};
+
+/// Sends the collateral of bonded coin pools back to its reserve chain with a
+/// reserve transfer via `pallet_xcm`. The XCM fees on the reserve chain are
+/// paid with the transferred collateral.
+///
+/// Only collaterals which are native to a sibling parachain, e.g., USDT on
+/// Asset Hub, can be sent. The beneficiary is the location of the recipient
+/// as seen from the reserve chain.
+pub struct XcmCollateralTransfer<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> RemoteCollateralTransfer<AccountId, Location, Balance> for XcmCollateralTransfer<Runtime>
+where
+	Runtime: pallet_xcm::Config + frame_system::Config<AccountId = AccountId>,
+{
+	type Beneficiary = VersionedLocation;
+
+	fn transfer(
+		who: &AccountId,
+		asset_id: Location,
+		amount: Balance,
+		beneficiary: VersionedLocation,
+	) -> DispatchResult {
+		let destination =
+			sibling_reserve_of(&asset_id).ok_or(DispatchError::Other("Collateral is not a reserve asset."))?;
+		let asset: Asset = (asset_id, amount).into();
+
+		pallet_xcm::Pallet::<Runtime>::transfer_assets(
+			RawOrigin::Signed(who.to_owned()).into(),
+			Box::new(VersionedLocation::from(destination)),
+			Box::new(beneficiary),
+			Box::new(VersionedAssets::from(Assets::from(asset))),
+			0,
+			WeightLimit::Unlimited,
+		)
+	}
+}
@@ -27,6 +27,7 @@ pub enum Error {
 	CalculationError,
 	Internal,
 	InvalidInput,
+	InsufficientHistory,
 }
 
 /// Enum to represent the operation of minting or burning tokens.
@@ -162,12 +162,47 @@ pub mod bonded_coins {
 	use super::*;
 
 	/// The size is checked in the runtime by a test.
-	pub const MAX_POOL_BYTE_LENGTH: u32 = 987;
+	pub const MAX_POOL_BYTE_LENGTH: u32 = 1196;
 	pub const BASE_DEPOSIT: Balance = 0;
 	pub const DEPOSIT_PER_CURRENCY: Balance = 0;
 	pub const MAX_CURRENCIES: u32 = 50;
 	pub const MAX_DENOMINATION: u8 = 15;
 	pub const MAX_STRING_LENGTH: u32 = crate::constants::bonded_assets::STRING_LIMIT;
+	/// The maximum mint and burn fee a pool can charge.
+	pub const MAX_POOL_FEE: Perbill = Perbill::from_percent(10);
+	/// The share of the collateral of each mint and burn paid to the treasury.
+	pub const PROTOCOL_FEE: Perbill = Perbill::from_parts(5_000_000);
+	/// The share of each swap in the AMM of a graduated pool retained for
+	/// liquidity providers.
+	pub const SWAP_FEE: Perbill = Perbill::from_parts(3_000_000);
+	/// The maximum number of vesting schedules of an account per bonded
+	/// currency.
+	pub const MAX_VESTING_SCHEDULES: u32 = 10;
+	/// The number of price observations kept per pool, which covers two days
+	/// of price history.
+	pub const MAX_PRICE_OBSERVATIONS: u32 = 48;
+	/// The minimum number of blocks between two price observations of a pool.
+	pub const PRICE_OBSERVATION_PERIOD: BlockNumber = HOURS;
+	/// The maximum length of the description of a pool.
+	pub const MAX_DESCRIPTION_LENGTH: u32 = 512;
+	/// The maximum length of the URI of a pool.
+	pub const MAX_URI_LENGTH: u32 = 256;
+	/// The size is checked in the runtime by a test.
+	pub const MAX_POOL_METADATA_BYTE_LENGTH: u32 = 839;
+	/// The deposit for the metadata of a pool and for the link of a pool to
+	/// the DID of its owner, each.
+	pub const METADATA_DEPOSIT: Balance = deposit(1, MAX_POOL_METADATA_BYTE_LENGTH);
+	/// The maximum number of attesters trusted to back the link of a pool to
+	/// the DID of its owner.
+	pub const MAX_TRUSTED_ATTESTERS: u32 = 20;
+	/// The maximum number of pools listed per page by the runtime API.
+	pub const MAX_POOLS_PER_PAGE: u32 = 100;
+	/// The maximum number of holders of a bonded currency returned by the
+	/// runtime API.
+	pub const MAX_TOP_HOLDERS: u32 = 100;
+	/// The maximum number of storage entries read by a single listing of the
+	/// runtime API, which bounds the execution time of a query.
+	pub const MAX_SCANNED_ENTRIES: u32 = 10_000;
 }
 
 pub mod bonded_assets {
@@ -180,6 +215,27 @@ pub mod bonded_assets {
 	pub const STRING_LIMIT: u32 = 50;
 }
 
+pub mod liquid_staking {
+	use super::*;
+
+	/// The pallet account backs as many collators as a single delegator can.
+	pub const MAX_COLLATORS: u32 = super::staking::MAX_COLLATORS_PER_DELEGATOR;
+	pub const MAX_UNBONDING_REQUESTS: u32 = 10;
+	pub const MIN_DEPOSIT: Balance = KILT;
+	/// Redemptions are unstaked every other day. As unlocked batches free
+	/// their unstaking requests, at most four batches are pending within the
+	/// stake duration of 7 days, which leaves room for the revocations of
+	/// `set_collators` within the limit of unstaking requests of the staking
+	/// pallet.
+	#[cfg(feature = "fast-gov")]
+	pub const BATCH_PERIOD: BlockNumber = 15;
+	#[cfg(not(feature = "fast-gov"))]
+	pub const BATCH_PERIOD: BlockNumber = 2 * DAYS;
+	pub const UNBONDING_PERIOD: BlockNumber = super::staking::STAKE_DURATION;
+	pub const DECIMALS: u8 = 15;
+	pub const MAX_STRING_LENGTH: u32 = crate::constants::bonded_assets::STRING_LIMIT;
+}
+
 pub mod assets {
 	use super::*;
 
@@ -271,6 +327,7 @@ pub mod staking {
 	pub const MAX_CANDIDATES: u32 = 75;
 
 	pub const MAX_DELEGATORS_PER_COLLATOR: u32 = 35;
+	pub const MAX_COLLATORS_PER_DELEGATOR: u32 = 4;
 	pub const MIN_DELEGATOR_STAKE: Balance = 20 * KILT;
 
 	pub const NETWORK_REWARD_RATE: Perquintill = Perquintill::from_percent(10);
@@ -286,6 +343,20 @@ pub mod staking {
 		pub const StakeDuration: BlockNumber = STAKE_DURATION;
 		/// Collator exit requests are delayed by 4 hours (2 rounds/sessions)
 		pub const ExitQueueDelay: u32 = 2;
+		/// Commission changes are delayed by 24 hours (12 rounds/sessions)
+		pub const CommissionChangeDelay: u32 = 12;
+		/// Slashes are deferred by 48 hours (24 rounds/sessions)
+		pub const SlashDeferDuration: u32 = 24;
+		/// Staking statistics are kept for 30 days (360 rounds/sessions)
+		pub const MaxRoundHistory: u32 = 360;
+		/// Up to 10 upcoming changes of the inflation configuration can be scheduled
+		pub const MaxInflationSteps: u32 = 10;
+		/// Up to 5 collator candidates can be linked to the same identity
+		pub const MaxCandidatesPerIdentity: u32 = 5;
+		/// The rewards of up to 10 accounts are compounded per block
+		pub const MaxAutoCompoundsPerBlock: u32 = 10;
+		/// The rewards of up to 5 candidates and their delegators are incremented per block when applying an inflation step
+		pub const MaxInflationUpdatesPerBlock: u32 = 5;
 		/// Minimum 16 collators selected per round, default at genesis and minimum forever after
 		pub const MinCollators: u32 = MIN_COLLATORS;
 		/// At least 4 candidates which cannot leave the network if there are no other candidates.
@@ -295,6 +366,9 @@ pub mod staking {
 		/// Maximum 25 delegators per collator at launch, might be increased later
 		#[derive(Debug, Eq, PartialEq)]
 		pub const MaxDelegatorsPerCollator: u32 = MAX_DELEGATORS_PER_COLLATOR;
+		/// Maximum 4 collators can be backed by a single delegator
+		#[derive(Debug, Eq, PartialEq)]
+		pub const MaxCollatorsPerDelegator: u32 = MAX_COLLATORS_PER_DELEGATOR;
 		/// Minimum stake required to be reserved to be a collator is 10_000
 		pub const MinCollatorStake: Balance = 10_000 * KILT;
 		/// Minimum stake required to be reserved to be a delegator is 1000
@@ -304,6 +378,8 @@ pub mod staking {
 		pub const MaxCollatorCandidates: u32 = MAX_CANDIDATES;
 		/// Maximum number of concurrent requests to unlock unstaked balance
 		pub const MaxUnstakeRequests: u32 = 10;
+		/// Minimum amount of funds to join a staking pool is 1 KILT
+		pub const MinPoolJoin: Balance = KILT;
 		/// The starting block number for the network rewards
 		pub const NetworkRewardStart: BlockNumber = super::treasury::INITIAL_PERIOD_LENGTH;
 		/// The rate in percent for the network rewards
@@ -486,9 +562,27 @@ pub mod did_lookup {
 	/// The size is checked in the runtime by a test.
 	pub const MAX_CONNECTION_BYTE_LENGTH: u32 = 80;
 	pub const DID_CONNECTION_DEPOSIT: Balance = 0;
+	/// The maximum length of the label of a linked account.
+	pub const MAX_LABEL_LENGTH: u32 = 32;
+	/// The size is checked in the runtime by a test.
+	pub const MAX_LINK_METADATA_BYTE_LENGTH: u32 = 37;
+	pub const DID_CONNECTION_METADATA_BYTE_DEPOSIT: Balance = deposit(0, 1);
+	/// The maximum number of accounts that can be (un)associated in a single
+	/// call.
+	pub const MAX_ASSOCIATIONS_PER_BATCH: u32 = 50;
+	/// The maximum number of accounts that can be linked to a single DID.
+	pub const MAX_LINKED_ACCOUNTS: u32 = 50;
+	/// The chain ID of the EIP-712 domain for typed data signatures. It
+	/// matches the KILT parachain ID.
+	pub const EIP712_CHAIN_ID: u64 = 2086;
 
 	parameter_types! {
 		pub const DidLookupDeposit: Balance = DID_CONNECTION_DEPOSIT;
+		pub const DidLookupMetadataByteDeposit: Balance = DID_CONNECTION_METADATA_BYTE_DEPOSIT;
+		pub const MaxLabelLength: u32 = MAX_LABEL_LENGTH;
+		pub const MaxAssociationsPerBatch: u32 = MAX_ASSOCIATIONS_PER_BATCH;
+		pub const MaxLinkedAccounts: u32 = MAX_LINKED_ACCOUNTS;
+		pub const Eip712ChainId: u64 = EIP712_CHAIN_ID;
 	}
 }
 
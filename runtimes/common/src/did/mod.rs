@@ -16,4 +16,95 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
+use frame_support::{traits::Get, weights::Weight};
+use pallet_did_lookup::{linkable_account::LinkableAccountId, traits::DidNameProvider};
+use pallet_web3_names::{traits::Web3NameChangeHook, Web3NameOf};
+use parachain_staking::traits::CollatorIdentityProvider;
+use sp_runtime::AccountId32;
+use sp_std::marker::PhantomData;
+
 pub mod runtime_apis;
+
+#[cfg(test)]
+mod tests;
+
+/// Returns the web3name of the DID the given account is linked to, if any.
+///
+/// This is a single read of the name stored for the account by did-lookup,
+/// which makes it considerably cheaper than resolving the whole DID linked
+/// information when only the name is needed, e.g., to render address books.
+pub fn web3_name_by_account<T>(account: &LinkableAccountId) -> Option<Web3NameOf<T>>
+where
+	T: pallet_did_lookup::Config<NameProvider = LinkedWeb3Names<T>>
+		+ pallet_web3_names::Config<Web3NameOwner = <T as pallet_did_lookup::Config>::DidIdentifier>,
+{
+	pallet_did_lookup::ConnectedNames::<T>::get(account)
+}
+
+/// Keeps the web3names stored by did-lookup for linked accounts in sync with
+/// the web3names pallet.
+pub struct LinkedWeb3Names<T>(PhantomData<T>);
+
+impl<T> DidNameProvider<<T as pallet_did_lookup::Config>::DidIdentifier> for LinkedWeb3Names<T>
+where
+	T: pallet_did_lookup::Config
+		+ pallet_web3_names::Config<Web3NameOwner = <T as pallet_did_lookup::Config>::DidIdentifier>,
+{
+	type Name = Web3NameOf<T>;
+
+	fn name_of(did: &<T as pallet_did_lookup::Config>::DidIdentifier) -> Option<Self::Name> {
+		pallet_web3_names::Names::<T>::get(did)
+	}
+}
+
+impl<T> Web3NameChangeHook<<T as pallet_did_lookup::Config>::DidIdentifier, Web3NameOf<T>> for LinkedWeb3Names<T>
+where
+	T: pallet_did_lookup::Config<NameProvider = LinkedWeb3Names<T>>
+		+ pallet_web3_names::Config<Web3NameOwner = <T as pallet_did_lookup::Config>::DidIdentifier>,
+{
+	fn on_name_changed(owner: &<T as pallet_did_lookup::Config>::DidIdentifier, name: Option<&Web3NameOf<T>>) {
+		pallet_did_lookup::Pallet::<T>::update_linked_names(owner, name);
+	}
+
+	fn on_name_changed_weight() -> Weight {
+		// One read of the linked accounts more than the maximum, to detect the end of
+		// the iteration, and one write per linked account.
+		let max_linked_accounts = u64::from(<T as pallet_did_lookup::Config>::MaxLinkedAccounts::get());
+		<T as frame_system::Config>::DbWeight::get()
+			.reads_writes(max_linked_accounts.saturating_add(1), max_linked_accounts)
+	}
+}
+
+/// Provides the DID an account is linked to as the identity of collator
+/// candidates.
+pub struct LinkedDidIdentity<T>(PhantomData<T>);
+
+impl<T> CollatorIdentityProvider<AccountId32> for LinkedDidIdentity<T>
+where
+	T: pallet_did_lookup::Config<DidIdentifier = AccountId32> + frame_system::Config<AccountId = AccountId32>,
+{
+	type Identity = AccountId32;
+
+	fn identity_of(who: &AccountId32) -> Option<AccountId32> {
+		pallet_did_lookup::ConnectedDids::<T>::get(LinkableAccountId::from(who.clone()))
+			.map(|connection_record| connection_record.did)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn link_identity(who: &AccountId32) -> AccountId32 {
+		let account = LinkableAccountId::from(who.clone());
+		pallet_did_lookup::ConnectedDids::<T>::insert(
+			&account,
+			pallet_did_lookup::ConnectionRecord {
+				did: who.clone(),
+				deposit: kilt_support::Deposit {
+					owner: who.clone(),
+					amount: Default::default(),
+				},
+			},
+		);
+		pallet_did_lookup::ConnectedAccounts::<T>::insert(who, account, ());
+		pallet_did_lookup::ConnectedAccountCount::<T>::mutate(who, |count| *count = count.saturating_add(1));
+		who.clone()
+	}
+}
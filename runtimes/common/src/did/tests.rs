@@ -0,0 +1,133 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+use did::{did_details::DidVerificationKey, mock_utils::generate_base_did_details};
+use frame_support::assert_ok;
+use pallet_did_lookup::linkable_account::LinkableAccountId;
+use pallet_web3_names::Web3NameOf;
+use parachain_staking::traits::CollatorIdentityProvider;
+use sp_runtime::AccountId32;
+
+use crate::{
+	did::{web3_name_by_account, LinkedDidIdentity},
+	dip::mock::{ExtBuilder, RuntimeOrigin, TestRuntime, Web3Names, ACCOUNT, DID_IDENTIFIER, SUBMITTER},
+};
+
+#[test]
+fn web3_name_by_account_successful() {
+	let linked_account = LinkableAccountId::AccountId32(ACCOUNT);
+	let web3_name: Web3NameOf<TestRuntime> = b"john_doe".to_vec().try_into().unwrap();
+
+	ExtBuilder::default()
+		.with_dids(vec![(
+			DID_IDENTIFIER,
+			generate_base_did_details(DidVerificationKey::Account(ACCOUNT), Some(SUBMITTER)),
+			Some(web3_name.clone()),
+			vec![linked_account.clone()],
+			SUBMITTER,
+		)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(web3_name_by_account::<TestRuntime>(&linked_account), Some(web3_name));
+		});
+}
+
+#[test]
+fn web3_name_by_account_no_web3_name() {
+	let linked_account = LinkableAccountId::AccountId32(ACCOUNT);
+
+	ExtBuilder::default()
+		.with_dids(vec![(
+			DID_IDENTIFIER,
+			generate_base_did_details(DidVerificationKey::Account(ACCOUNT), Some(SUBMITTER)),
+			None,
+			vec![linked_account.clone()],
+			SUBMITTER,
+		)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(web3_name_by_account::<TestRuntime>(&linked_account), None);
+		});
+}
+
+#[test]
+fn web3_name_by_account_not_linked() {
+	let web3_name: Web3NameOf<TestRuntime> = b"john_doe".to_vec().try_into().unwrap();
+
+	ExtBuilder::default()
+		.with_dids(vec![(
+			DID_IDENTIFIER,
+			generate_base_did_details(DidVerificationKey::Account(ACCOUNT), Some(SUBMITTER)),
+			Some(web3_name),
+			vec![LinkableAccountId::AccountId32(ACCOUNT)],
+			SUBMITTER,
+		)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				web3_name_by_account::<TestRuntime>(&LinkableAccountId::AccountId32(AccountId32::new([1u8; 32]))),
+				None
+			);
+		});
+}
+
+#[test]
+fn web3_name_by_account_follows_name_changes() {
+	let linked_account = LinkableAccountId::AccountId32(ACCOUNT);
+	let web3_name: Web3NameOf<TestRuntime> = b"john_doe".to_vec().try_into().unwrap();
+
+	ExtBuilder::default()
+		.with_dids(vec![(
+			DID_IDENTIFIER,
+			generate_base_did_details(DidVerificationKey::Account(ACCOUNT), Some(SUBMITTER)),
+			None,
+			vec![linked_account.clone()],
+			SUBMITTER,
+		)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Web3Names::register_name(web3_name.clone(), DID_IDENTIFIER, SUBMITTER));
+			assert_eq!(web3_name_by_account::<TestRuntime>(&linked_account), Some(web3_name));
+
+			assert_ok!(Web3Names::release_by_owner(RuntimeOrigin::signed(DID_IDENTIFIER)));
+			assert_eq!(web3_name_by_account::<TestRuntime>(&linked_account), None);
+		});
+}
+
+#[test]
+fn linked_did_identity() {
+	ExtBuilder::default()
+		.with_dids(vec![(
+			DID_IDENTIFIER,
+			generate_base_did_details(DidVerificationKey::Account(ACCOUNT), Some(SUBMITTER)),
+			None,
+			vec![LinkableAccountId::AccountId32(ACCOUNT)],
+			SUBMITTER,
+		)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				LinkedDidIdentity::<TestRuntime>::identity_of(&ACCOUNT),
+				Some(DID_IDENTIFIER)
+			);
+			assert_eq!(
+				LinkedDidIdentity::<TestRuntime>::identity_of(&AccountId32::new([1u8; 32])),
+				None
+			);
+		});
+}
@@ -42,8 +42,9 @@ use crate::{
 		},
 		dip_provider::MAX_LINKED_ACCOUNTS,
 		web3_names::{MaxNameLength, MinNameLength},
-		KILT,
+		KILT, MICRO_KILT,
 	},
+	did::LinkedWeb3Names,
 	dip::{
 		did::{LinkedDidInfoOf, LinkedDidInfoProvider},
 		merkle::DidMerkleRootGenerator,
@@ -158,6 +159,7 @@ impl pallet_web3_names::Config for TestRuntime {
 	type Deposit = ConstU128<KILT>;
 	type MaxNameLength = MaxNameLength;
 	type MinNameLength = MinNameLength;
+	type NameChangeHook = LinkedWeb3Names<Self>;
 	type OriginSuccess = AccountId;
 	type OwnerOrigin = EnsureSigned<AccountId>;
 	type RuntimeEvent = RuntimeEvent;
@@ -176,7 +178,13 @@ impl pallet_did_lookup::Config for TestRuntime {
 	type Currency = Balances;
 	type Deposit = ConstU128<KILT>;
 	type DidIdentifier = DidIdentifier;
+	type Eip712ChainId = ConstU64<0>;
 	type EnsureOrigin = EnsureSigned<AccountId>;
+	type MaxAssociationsPerBatch = ConstU32<50>;
+	type MaxLabelLength = ConstU32<32>;
+	type MaxLinkedAccounts = ConstU32<MAX_LINKED_ACCOUNTS>;
+	type MetadataByteDeposit = ConstU128<MICRO_KILT>;
+	type NameProvider = LinkedWeb3Names<Self>;
 	type OriginSuccess = AccountId;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -24,4 +24,6 @@ parameter_types! {
 	pub const Treasury: PalletId = PalletId(*b"kilt/tsy");
 	pub const Launch: PalletId = PalletId(*b"kilt/lch");
 	pub const Gratitude: PalletId = PalletId(*b"kilt/thx");
+	pub const StakingPools: PalletId = PalletId(*b"kilt/spl");
+	pub const LiquidStaking: PalletId = PalletId(*b"kilt/lst");
 }
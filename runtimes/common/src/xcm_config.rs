@@ -18,12 +18,19 @@
 
 use core::{marker::PhantomData, ops::ControlFlow};
 use cumulus_primitives_core::AggregateMessageOrigin;
-use frame_support::{match_types, parameter_types, traits::ProcessMessageError, weights::Weight};
+use frame_support::{
+	match_types, parameter_types,
+	traits::{fungibles, ContainsPair, ProcessMessageError},
+	weights::Weight,
+};
 use polkadot_parachain::primitives::Sibling;
 use sp_runtime::Perbill;
 use xcm::v4::prelude::*;
-use xcm_builder::{AccountId32Aliases, FungibleAdapter, IsConcrete, ParentIsPreset, SiblingParachainConvertsVia};
-use xcm_executor::traits::{Properties, ShouldExecute};
+use xcm_builder::{
+	AccountId32Aliases, DescribeAllTerminal, DescribeFamily, FungibleAdapter, FungiblesAdapter, HashedDescription,
+	IsConcrete, NoChecking, ParentIsPreset, SiblingParachainConvertsVia,
+};
+use xcm_executor::traits::{Error as MatchError, MatchesFungibles, Properties, ShouldExecute};
 
 use crate::{AccountId, BlockWeights};
 
@@ -174,3 +181,78 @@ pub type LocalAssetTransactor<Currency, NetworkId> = FungibleAdapter<
 	// We don't track any teleports.
 	(),
 >;
+
+/// Type for specifying how a `Location` can be converted into an `AccountId`,
+/// which in addition to [LocationToAccountId] derives local accounts for
+/// accounts on other chains, e.g., users on Asset Hub. Those accounts can hold
+/// assets received via XCM and dispatch calls via XCM `Transact`.
+pub type LocationToAccountIdWithRemoteAccounts<NetworkId> = (
+	LocationToAccountId<NetworkId>,
+	// Foreign locations alias into accounts according to a hash of their standard description.
+	HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
+);
+
+/// Returns the location of the chain which is the reserve of an asset, if the
+/// asset is native to a sibling parachain, e.g., USDT on Asset Hub.
+pub fn sibling_reserve_of(asset_location: &Location) -> Option<Location> {
+	match asset_location.unpack() {
+		(1, [Parachain(para_id), ..]) => Some(Location::new(1, [Parachain(*para_id)])),
+		_ => None,
+	}
+}
+
+/// Matches fungible assets of sibling parachains which have been registered
+/// in `Fungibles`, using their location as asset ID.
+pub struct MatchesForeignFungibles<Fungibles>(PhantomData<Fungibles>);
+
+impl<Fungibles> MatchesFungibles<Location, u128> for MatchesForeignFungibles<Fungibles>
+where
+	Fungibles: fungibles::Inspect<AccountId, AssetId = Location>,
+{
+	fn matches_fungibles(asset: &Asset) -> Result<(Location, u128), MatchError> {
+		let Asset {
+			id: AssetId(location),
+			fun: Fungible(amount),
+		} = asset
+		else {
+			return Err(MatchError::AssetNotHandled);
+		};
+		if sibling_reserve_of(location).is_none() || !Fungibles::asset_exists(location.clone()) {
+			return Err(MatchError::AssetNotHandled);
+		}
+		Ok((location.clone(), *amount))
+	}
+}
+
+/// Accepts assets registered in `Fungibles` as reserve assets if they are sent
+/// by their reserve chain, e.g., USDT sent by Asset Hub.
+pub struct IsForeignReserveAsset<Fungibles>(PhantomData<Fungibles>);
+
+impl<Fungibles> ContainsPair<Asset, Location> for IsForeignReserveAsset<Fungibles>
+where
+	Fungibles: fungibles::Inspect<AccountId, AssetId = Location>,
+{
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		let Ok((location, _)) = MatchesForeignFungibles::<Fungibles>::matches_fungibles(asset) else {
+			return false;
+		};
+		sibling_reserve_of(&location).as_ref() == Some(origin)
+	}
+}
+
+/// Means for transacting the foreign assets registered in `Fungibles`, e.g.,
+/// to deposit the collateral of bonded coin pools sent from other chains.
+pub type ForeignAssetTransactor<Fungibles, NetworkId, CheckingAccount> = FungiblesAdapter<
+	// Use this fungibles implementation:
+	Fungibles,
+	// Use this matcher for assets of sibling chains registered in the fungibles implementation:
+	MatchesForeignFungibles<Fungibles>,
+	// Convert local and remote account locations into local account IDs:
+	LocationToAccountIdWithRemoteAccounts<NetworkId>,
+	// Our chain's account ID type:
+	AccountId,
+	// We don't track any teleports.
+	NoChecking,
+	// The account used to track teleports. Not used.
+	CheckingAccount,
+>;
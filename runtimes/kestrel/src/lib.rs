@@ -442,6 +442,10 @@ impl pallet_did_lookup::Config for Runtime {
 
 	type Currency = Balances;
 	type Deposit = constants::did_lookup::DidLookupDeposit;
+	type MetadataByteDeposit = constants::did_lookup::DidLookupMetadataByteDeposit;
+	type MaxLabelLength = constants::did_lookup::MaxLabelLength;
+	type MaxAssociationsPerBatch = constants::did_lookup::MaxAssociationsPerBatch;
+	type MaxLinkedAccounts = constants::did_lookup::MaxLinkedAccounts;
 
 	type AssociateOrigin = Self::EnsureOrigin;
 	type EnsureOrigin = did::EnsureDidOrigin<DidIdentifier, AccountId>;
@@ -451,6 +455,8 @@ impl pallet_did_lookup::Config for Runtime {
 	// Do not change the below flag to `true` without also deploying a runtime
 	// migration which removes any links that point to the same DID!
 	type UniqueLinkingEnabled = ConstBool<false>;
+	type Eip712ChainId = constants::did_lookup::Eip712ChainId;
+	type NameProvider = runtime_common::did::LinkedWeb3Names<Runtime>;
 }
 
 impl pallet_web3_names::Config for Runtime {
@@ -467,6 +473,7 @@ impl pallet_web3_names::Config for Runtime {
 	type Web3Name = Web3Name<{ Self::MinNameLength::get() }, { Self::MaxNameLength::get() }>;
 	type Web3NameOwner = DidIdentifier;
 	type WeightInfo = ();
+	type NameChangeHook = runtime_common::did::LinkedWeb3Names<Runtime>;
 	type BalanceMigrationManager = ();
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1037,9 +1044,9 @@ impl_runtime_apis! {
 					did::Did::<Runtime>::get(&owner_info.owner).map(|details| (owner_info, details))
 				})
 				.map(|(owner_info, details)| {
-					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(
+					let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(
 						&owner_info.owner,
-					).collect();
+					).map(Into::into).collect();
 					let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
 
 					kilt_runtime_api_did::RawDidLinkedInfo{
@@ -1080,7 +1087,7 @@ impl_runtime_apis! {
 				})
 				.map(|(connection_record, details)| {
 					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did).map(Into::into);
-					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&connection_record.did).collect();
+					let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(&connection_record.did).map(Into::into).collect();
 					let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&connection_record.did).map(|e| From::from(e.1)).collect();
 
 					kilt_runtime_api_did::RawDidLinkedInfo {
@@ -1118,7 +1125,7 @@ impl_runtime_apis! {
 		> {
 			let details = did::Did::<Runtime>::get(&did)?;
 			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
-			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
+			let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(&did).map(Into::into).collect();
 			let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
 
 			Some(kilt_runtime_api_did::RawDidLinkedInfo {
@@ -1154,6 +1161,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl kilt_runtime_api_did::Web3NameLookup<Block, LinkableAccountId> for Runtime {
+		fn batch_query_web3_name_by_account(accounts: Vec<LinkableAccountId>) -> Vec<Option<Vec<u8>>> {
+			accounts.iter().map(|account| runtime_common::did::web3_name_by_account::<Runtime>(account).map(Into::into)).collect()
+		}
+	}
+
 	impl kilt_runtime_api_public_credentials::PublicCredentials<Block, Vec<u8>, Hash, public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>, PublicCredentialsFilter<Hash, AccountId>, PublicCredentialsApiError> for Runtime {
 		fn get_by_id(credential_id: Hash) -> Option<public_credentials::CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>> {
 			let subject = public_credentials::CredentialSubjects::<Runtime>::get(credential_id)?;
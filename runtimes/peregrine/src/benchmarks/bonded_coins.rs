@@ -17,11 +17,15 @@
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
 use frame_support::traits::fungible::Mutate;
-use pallet_bonded_coins::{BenchmarkHelper, CollateralAssetIdOf, Config, FungiblesAssetIdOf};
+use pallet_bonded_coins::{BenchmarkHelper, CollateralAssetIdOf, Config, FungiblesAssetIdOf, RemoteBeneficiaryOf};
+use runtime_common::benchmarks::xcm::RandomParaId;
 use sp_runtime::SaturatedConversion;
-use xcm::v4::{Junction, Junctions, Location};
+use xcm::{
+	v4::{Junction, Junctions, Location},
+	VersionedLocation,
+};
 
-use crate::kilt::BondedFungiblesInstance;
+use crate::{kilt::BondedFungiblesInstance, ParachainSystem};
 pub struct BondedFungiblesBenchmarkHelper<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config + pallet_assets::Config + pallet_assets::Config<BondedFungiblesInstance> + pallet_balances::Config>
@@ -29,6 +33,7 @@ impl<T: Config + pallet_assets::Config + pallet_assets::Config<BondedFungiblesIn
 where
 	FungiblesAssetIdOf<T>: From<u32>,
 	CollateralAssetIdOf<T>: From<Location>,
+	RemoteBeneficiaryOf<T>: From<VersionedLocation>,
 {
 	fn calculate_bonded_asset_id(seed: u32) -> FungiblesAssetIdOf<T> {
 		FungiblesAssetIdOf::<T>::from(seed)
@@ -44,4 +49,26 @@ where
 	fn set_native_balance(who: &<T as frame_system::Config>::AccountId, amount: u128) {
 		pallet_balances::Pallet::<T>::set_balance(who, amount.saturated_into());
 	}
+
+	fn calculate_remote_collateral_asset_id(seed: u32) -> CollateralAssetIdOf<T> {
+		CollateralAssetIdOf::<T>::from(Location::new(
+			1,
+			[
+				Junction::Parachain(RandomParaId::get().into()),
+				Junction::GeneralIndex(seed.into()),
+			],
+		))
+	}
+
+	fn prepare_remote_transfer(seed: u32) -> RemoteBeneficiaryOf<T> {
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(RandomParaId::get());
+		let beneficiary = Location::new(
+			0,
+			[Junction::AccountId32 {
+				network: None,
+				id: sp_core::blake2_256(&seed.to_le_bytes()),
+			}],
+		);
+		RemoteBeneficiaryOf::<T>::from(VersionedLocation::from(beneficiary))
+	}
 }
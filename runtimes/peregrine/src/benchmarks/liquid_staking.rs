@@ -0,0 +1,55 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+use frame_benchmarking::account;
+use frame_support::{
+	pallet_prelude::{Twox64Concat, ValueQuery},
+	storage_alias,
+	traits::{fungible::Mutate, Get},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_liquid_staking::{traits::DelegateStake, BenchmarkHelper, FungiblesAssetIdOf};
+use runtime_common::{bonded_coins::AssetId, AccountId};
+
+use crate::{Balances, ParachainStaking, Runtime};
+
+/// The number of authored blocks for collators in the staking pallet.
+#[storage_alias]
+type BlocksAuthored = StorageMap<ParachainStaking, Twox64Concat, AccountId, BlockNumberFor<Runtime>, ValueQuery>;
+
+pub struct LiquidStakingBenchmarkHelper;
+
+impl BenchmarkHelper<Runtime> for LiquidStakingBenchmarkHelper {
+	fn derivative_asset_id() -> FungiblesAssetIdOf<Runtime> {
+		AssetId::MAX
+	}
+
+	fn setup_collator(seed: u32) -> AccountId {
+		let collator: AccountId = account("collator", seed, 0);
+		let stake = <Runtime as parachain_staking::Config>::MinCollatorStake::get();
+		Balances::set_balance(&collator, stake.saturating_mul(2));
+		ParachainStaking::join_candidates(RawOrigin::Signed(collator.clone()).into(), stake)
+			.expect("Joining the collator candidates should work");
+		collator
+	}
+
+	fn accrue_rewards(delegator: &AccountId) {
+		for (collator, _) in ParachainStaking::delegations(delegator) {
+			BlocksAuthored::mutate(&collator, |authored| *authored = authored.saturating_add(100));
+		}
+	}
+}
@@ -39,14 +39,15 @@ use xcm::v4::{Asset, Assets, Fungibility, Location};
 use crate::{
 	xcm::XcmConfig, AllPalletsWithSystem, AssetSwitchPool1, Attestation, Balances, BondedCurrencies, BondedFungibles,
 	Collators, Council, Ctype, Delegation, Democracy, DepositStorage, Did, DidLookup, DipProvider, Fungibles, Indices,
-	IsmpParachain, MessageQueue, Migration, Multisig, ParachainStaking, ParachainSystem, Preimage, Proxy,
-	PublicCredentials, Runtime, RuntimeEvent, Scheduler, Sudo, System, TechnicalCommittee, TechnicalMembership,
+	IsmpParachain, LiquidStaking, MessageQueue, Migration, Multisig, ParachainStaking, ParachainSystem, Preimage,
+	Proxy, PublicCredentials, Runtime, RuntimeEvent, Scheduler, Sudo, System, TechnicalCommittee, TechnicalMembership,
 	Timestamp, Tips, TipsMembership, TokenGateway, Treasury, Utility, Vesting, Web3Names,
 };
 
 pub(crate) mod asset_switch;
 pub(crate) mod bonded_coins;
 pub(crate) mod governance;
+pub(crate) mod liquid_staking;
 pub(crate) mod web3_names;
 
 /// Workaround for a bug in the benchmarking code around instances.
@@ -89,8 +90,10 @@ define_benchmarks!(
 	[pallet_asset_switch, AssetSwitchPool1]
 	[pallet_message_queue, MessageQueue]
 	[pallet_bonded_coins, BondedCurrencies]
+	[pallet_liquid_staking, LiquidStaking]
 	[cumulus_pallet_parachain_system, ParachainSystem]
 	[frame_benchmarking::baseline, frame_benchmarking::baseline::Pallet::<Runtime>]
+	[kilt_runtime_api_did, runtime_common::benchmarks::web3_name_lookup::Pallet::<Runtime>]
 	// pallet_collective instances
 	[pallet_collective, Council]
 	[pallet_technical_committee_collective, TechnicalCommittee]
@@ -167,6 +170,7 @@ impl frame_system_benchmarking::Config for Runtime {
 }
 
 impl cumulus_pallet_session_benchmarking::Config for Runtime {}
+impl runtime_common::benchmarks::web3_name_lookup::Config for Runtime {}
 impl frame_benchmarking::baseline::Config for Runtime {}
 
 pub(crate) fn benchmark_metadata(extra: bool) -> (Vec<BenchmarkList>, Vec<StorageInfo>) {
@@ -16,7 +16,11 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
-use frame_support::{parameter_types, traits::AsEnsureOriginWithArg};
+use did::EnsureDidOrigin;
+use frame_support::{
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, EitherOf, MapSuccess},
+};
 use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot, EnsureSigned};
 use kilt_support::traits::InspectMetadata;
 use pallet_asset_switch::xcm::{AccountId32ToAccountId32JunctionConverter, MatchesSwitchPairXcmFeeFungibleAsset};
@@ -24,23 +28,26 @@ use pallet_deposit_storage::{DepositKeyOf, PalletDepositStorageReason};
 use runtime_common::{
 	asset_switch::{hooks::RestrictSwitchDestinationToSelf, EnsureRootAsTreasury},
 	bonded_coins::{
-		hooks::NextAssetIdGenerator, AssetId, FixedPoint, FixedPointInput,
-		NativeAndForeignAssets as NativeAndForeignAssetsType, TargetFromLeft,
+		hooks::NextAssetIdGenerator, issuer::PublicCredentialIssuer, remote_collateral::XcmCollateralTransfer, AssetId,
+		DidSubject, FixedPoint, FixedPointInput, NativeAndForeignAssets as NativeAndForeignAssetsType, TargetFromLeft,
 	},
 	deposits::DepositNamespace,
-	AccountId, Balance,
+	did::LinkedDidIdentity,
+	pallet_id, AccountId, Balance, DidIdentifier, Hash, SendDustAndFeesToTreasury,
 };
-use sp_core::{crypto::ByteArray, ConstU128, ConstU32, ConstU8};
-use sp_runtime::AccountId32;
+use sp_core::{crypto::ByteArray, ConstU128, ConstU32, ConstU64, ConstU8};
+use sp_runtime::{AccountId32, Perbill};
 use sp_std::vec::Vec;
 use xcm::v4::{Junctions, Location};
 use xcm_builder::{FungiblesAdapter, NoChecking};
 
 use crate::{
-	constants, weights,
+	constants,
+	governance::{RootOrMoreThanHalfCouncil, TreasuryAccount},
+	weights,
 	xcm::{LocationToAccountIdConverter, UniversalLocation, XcmRouter},
-	Balances, BondedCurrencies, BondedFungibles, DepositStorage, Fungibles, PolkadotXcm, Runtime, RuntimeEvent,
-	RuntimeFreezeReason, RuntimeHoldReason,
+	Balances, BondedCoinsAttesters, BondedCurrencies, BondedFungibles, DepositStorage, Fungibles, ParachainStaking,
+	PolkadotXcm, Runtime, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
 };
 
 pub(crate) mod credential;
@@ -58,18 +65,30 @@ impl parachain_staking::Config for Runtime {
 	type DefaultBlocksPerRound = constants::staking::DefaultBlocksPerRound;
 	type StakeDuration = constants::staking::StakeDuration;
 	type ExitQueueDelay = constants::staking::ExitQueueDelay;
+	type CommissionChangeDelay = constants::staking::CommissionChangeDelay;
+	type SlashDeferDuration = constants::staking::SlashDeferDuration;
+	type MaxRoundHistory = constants::staking::MaxRoundHistory;
+	type MaxInflationSteps = constants::staking::MaxInflationSteps;
+	type MaxCandidatesPerIdentity = constants::staking::MaxCandidatesPerIdentity;
+	type MaxAutoCompoundsPerBlock = constants::staking::MaxAutoCompoundsPerBlock;
+	type MaxInflationUpdatesPerBlock = constants::staking::MaxInflationUpdatesPerBlock;
 	type MinCollators = constants::staking::MinCollators;
 	type MinRequiredCollators = constants::staking::MinRequiredCollators;
 	type MaxDelegationsPerRound = constants::staking::MaxDelegationsPerRound;
 	type MaxDelegatorsPerCollator = constants::staking::MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = constants::staking::MaxCollatorsPerDelegator;
 	type MinCollatorStake = constants::staking::MinCollatorStake;
 	type MinCollatorCandidateStake = constants::staking::MinCollatorStake;
 	type MaxTopCandidates = constants::staking::MaxCollatorCandidates;
 	type MinDelegatorStake = constants::staking::MinDelegatorStake;
 	type MaxUnstakeRequests = constants::staking::MaxUnstakeRequests;
+	type PoolPalletId = pallet_id::StakingPools;
+	type MinPoolJoin = constants::staking::MinPoolJoin;
 	type NetworkRewardRate = constants::staking::NetworkRewardRate;
 	type NetworkRewardStart = constants::staking::NetworkRewardStart;
 	type NetworkRewardBeneficiary = ();
+	type Slash = SendDustAndFeesToTreasury<Runtime>;
+	type IdentityProvider = LinkedDidIdentity<Runtime>;
 	type WeightInfo = weights::parachain_staking::WeightInfo<Runtime>;
 
 	const BLOCKS_PER_YEAR: BlockNumberFor<Self> = constants::BLOCKS_PER_YEAR;
@@ -160,6 +179,32 @@ impl From<LocalHoldReason> for PalletDepositStorageReason<DepositNamespace, Depo
 	}
 }
 
+/// The attesters trusted to back the link of a bonded coin pool to the DID of
+/// its owner.
+type BondedCoinsAttestersProvider = pallet_membership::Instance4;
+impl pallet_membership::Config<BondedCoinsAttestersProvider> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = RootOrMoreThanHalfCouncil;
+	type RemoveOrigin = RootOrMoreThanHalfCouncil;
+	type SwapOrigin = RootOrMoreThanHalfCouncil;
+	type ResetOrigin = RootOrMoreThanHalfCouncil;
+	type PrimeOrigin = RootOrMoreThanHalfCouncil;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = ConstU32<{ constants::bonded_coins::MAX_TRUSTED_ATTESTERS }>;
+	type WeightInfo = weights::pallet_membership::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	/// The CType of the credentials linking a pool to the DID of its owner.
+	/// No credential is accepted until governance sets it via
+	/// `system.set_storage`.
+	pub storage BondedCoinsIssuerCtype: Hash = Hash::zero();
+	pub const BondedCoinsMaxPoolFee: Perbill = constants::bonded_coins::MAX_POOL_FEE;
+	pub const BondedCoinsProtocolFee: Perbill = constants::bonded_coins::PROTOCOL_FEE;
+	pub const BondedCoinsSwapFee: Perbill = constants::bonded_coins::SWAP_FEE;
+}
+
 impl pallet_bonded_coins::Config for Runtime {
 	type BaseDeposit = ConstU128<{ constants::bonded_coins::BASE_DEPOSIT }>;
 	type Collaterals = NativeAndForeignAssets;
@@ -170,21 +215,57 @@ impl pallet_bonded_coins::Config for Runtime {
 	type DepositPerCurrency = ConstU128<{ constants::bonded_coins::DEPOSIT_PER_CURRENCY }>;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type Fungibles = BondedFungibles;
+	type IssuerVerification = PublicCredentialIssuer<Runtime, BondedCoinsAttesters, BondedCoinsIssuerCtype>;
 	type MaxCurrenciesPerPool = ConstU32<{ constants::bonded_coins::MAX_CURRENCIES }>;
 	type MaxDenomination = ConstU8<{ constants::bonded_coins::MAX_DENOMINATION }>;
+	type MaxDescriptionLength = ConstU32<{ constants::bonded_coins::MAX_DESCRIPTION_LENGTH }>;
+	type MaxPoolFee = BondedCoinsMaxPoolFee;
+	type MaxPriceObservations = ConstU32<{ constants::bonded_coins::MAX_PRICE_OBSERVATIONS }>;
 	type MaxStringInputLength = ConstU32<{ constants::bonded_coins::MAX_STRING_LENGTH }>;
+	type MaxUriLength = ConstU32<{ constants::bonded_coins::MAX_URI_LENGTH }>;
+	type MaxVestingSchedules = ConstU32<{ constants::bonded_coins::MAX_VESTING_SCHEDULES }>;
+	type MetadataDeposit = ConstU128<{ constants::bonded_coins::METADATA_DEPOSIT }>;
 	type NextAssetIds = NextAssetIdGenerator<BondedCurrencies>;
 	type PoolCreateOrigin = EnsureSigned<AccountId>;
 	type PoolId = AccountId;
+	type PriceObservationPeriod = ConstU64<{ constants::bonded_coins::PRICE_OBSERVATION_PERIOD }>;
+	type ProtocolFee = BondedCoinsProtocolFee;
+	type ProtocolFeeDestination = TreasuryAccount;
+	type RemoteCollaterals = XcmCollateralTransfer<Runtime>;
+	type ResolveOrigin = EitherOf<
+		EnsureSigned<AccountId>,
+		MapSuccess<EnsureDidOrigin<DidIdentifier, AccountId>, DidSubject<DidIdentifier, AccountId>>,
+	>;
 	type RuntimeEvent = RuntimeEvent;
 	type HoldReason = LocalHoldReason;
 	type RuntimeHoldReason = PalletDepositStorageReason<DepositNamespace, DepositKeyOf<Runtime>>;
+	type SwapFee = BondedCoinsSwapFee;
 	type WeightInfo = weights::pallet_bonded_coins::WeightInfo<Runtime>;
 
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = crate::benchmarks::bonded_coins::BondedFungiblesBenchmarkHelper<Runtime>;
 }
 
+impl pallet_liquid_staking::Config for Runtime {
+	type BatchPeriod = ConstU32<{ constants::liquid_staking::BATCH_PERIOD }>;
+	type Currency = Balances;
+	type Decimals = ConstU8<{ constants::liquid_staking::DECIMALS }>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Fungibles = BondedFungibles;
+	type MaxCollators = ConstU32<{ constants::liquid_staking::MAX_COLLATORS }>;
+	type MaxStringInputLength = ConstU32<{ constants::liquid_staking::MAX_STRING_LENGTH }>;
+	type MaxUnbondingRequests = ConstU32<{ constants::liquid_staking::MAX_UNBONDING_REQUESTS }>;
+	type MinDeposit = ConstU128<{ constants::liquid_staking::MIN_DEPOSIT }>;
+	type PalletId = pallet_id::LiquidStaking;
+	type RuntimeEvent = RuntimeEvent;
+	type Staking = ParachainStaking;
+	type UnbondingPeriod = ConstU32<{ constants::liquid_staking::UNBONDING_PERIOD }>;
+	type WeightInfo = weights::pallet_liquid_staking::WeightInfo<Runtime>;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = crate::benchmarks::liquid_staking::LiquidStakingBenchmarkHelper;
+}
+
 pub(crate) type BondedFungiblesInstance = pallet_assets::Instance2;
 impl pallet_assets::Config<BondedFungiblesInstance> for Runtime {
 	type ApprovalDeposit = ConstU128<{ constants::bonded_assets::APPROVAL_DEPOSIT }>;
@@ -198,7 +279,7 @@ impl pallet_assets::Config<BondedFungiblesInstance> for Runtime {
 	type Currency = Balances;
 	type Extra = ();
 	type ForceOrigin = EnsureRoot<AccountId>;
-	type Freezer = ();
+	type Freezer = BondedCurrencies;
 	type MetadataDepositBase = ConstU128<{ constants::bonded_assets::META_DEPOSIT_BASE }>;
 	type MetadataDepositPerByte = ConstU128<{ constants::bonded_assets::META_DEPOSIT_PER_BYTE }>;
 	type RemoveItemsLimit = ConstU32<{ constants::bonded_assets::REMOVE_ITEMS_LIMIT }>;
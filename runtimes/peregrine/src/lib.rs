@@ -82,7 +82,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 11600,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSION,
-	transaction_version: 11,
+	transaction_version: 13,
 	state_version: 0,
 };
 
@@ -169,6 +169,8 @@ construct_runtime! {
 		// DELETED: DotNames: pallet_web3_names::<Instance2> = 73,
 		// DELETED: UniqueLinking: pallet_did_lookup::<Instance2> = 74,
 		BondedCurrencies: pallet_bonded_coins = 75,
+		LiquidStaking: pallet_liquid_staking = 76,
+		BondedCoinsAttesters: pallet_membership::<Instance4> = 77,
 
 		// Parachains pallets. Start indices at 80 to leave room.
 
@@ -34,6 +34,8 @@ pub type RuntimeMigrations = (
 	frame_support::migrations::RemovePallet<Inflation, <Runtime as frame_system::Config>::DbWeight>,
 	pallet_bonded_coins::migrations::v1::MigrateV0ToV1<Runtime>,
 	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+	parachain_staking::migrations::v10::MigrateV9ToV10<Runtime>,
+	pallet_did_lookup::migrations::v5::MigrateV4ToV5<Runtime>,
 );
 
 impl pallet_migration::Config for Runtime {
@@ -25,14 +25,23 @@ use kilt_runtime_api_did::RawDidLinkedInfo;
 use kilt_support::traits::ItemFilter;
 use pallet_asset_switch::xcm::AccountId32ToAccountId32JunctionConverter;
 use pallet_bonded_coins::{
+	amm,
 	curves::{
-		balance_to_fixed, fixed_to_balance, lmsr::LMSRParameters, polynomial::PolynomialParameters,
-		square_root::SquareRootParameters, BondingFunction, Curve,
+		balance_to_fixed,
+		exponential::ExponentialParameters,
+		fixed_to_balance,
+		lmsr::LMSRParameters,
+		piecewise_linear::{ControlPoint, PiecewiseLinearParameters},
+		polynomial::PolynomialParameters,
+		square_root::SquareRootParameters,
+		BondingFunction, Curve,
 	},
-	PoolDetailsOf, Pools, Round,
+	traits::VerifyIssuer,
+	AmmPools, Error as BondedCoinsError, PoolDetailsOf, PoolFeeSettings, PoolStatus, Pools, Round,
 };
 use pallet_bonded_coins_runtime_api::{
-	BondedCurrencyDetails, Coefficient, CollateralDetails, PoolDetailsOf as PoolDetails,
+	BondedCurrencyDetails, Coefficient, CollateralDetails, CurrencyAnalytics, Holder, IssuerDetails, PoolAnalytics,
+	PoolDetailsOf as PoolDetails, PoolMetadataDetails, PoolStatusFilter, PoolsPage, TopHolders,
 };
 use pallet_did_lookup::linkable_account::LinkableAccountId;
 use pallet_dip_provider::traits::IdentityProvider;
@@ -45,10 +54,14 @@ use runtime_common::{
 	assets::{AssetDid, PublicCredentialsFilter},
 	authorization::AuthorizationId,
 	bonded_coins::{
+		holders,
 		runtime_api::{Error as BondedCurrencyError, Operation},
 		AssetId as BondedAssetId, FixedPoint, FixedPointUnderlyingType,
 	},
-	constants::SLOT_DURATION,
+	constants::{
+		bonded_coins::{MAX_POOLS_PER_PAGE, MAX_SCANNED_ENTRIES, MAX_TOP_HOLDERS},
+		SLOT_DURATION,
+	},
 	did::runtime_apis::LinkedDidResource,
 	dip::merkle::{CompleteMerkleProof, DidMerkleProofOf, DidMerkleRootGenerator},
 	errors::PublicCredentialsApiError,
@@ -285,9 +298,9 @@ impl_runtime_apis! {
 					did::Did::<Runtime>::get(&owner_info.owner).map(|details| (owner_info, details))
 				})
 				.map(|(owner_info, details)| {
-					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(
+					let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(
 						&owner_info.owner,
-					).collect();
+					).map(Into::into).collect();
 					let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
 
 					RawDidLinkedInfo{
@@ -328,7 +341,7 @@ impl_runtime_apis! {
 				})
 				.map(|(connection_record, details)| {
 					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did).map(Into::into);
-					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&connection_record.did).collect();
+					let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(&connection_record.did).map(Into::into).collect();
 					let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&connection_record.did).map(|e| From::from(e.1)).collect();
 
 					RawDidLinkedInfo {
@@ -366,7 +379,7 @@ impl_runtime_apis! {
 		> {
 			let details = did::Did::<Runtime>::get(&did)?;
 			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
-			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
+			let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(&did).map(Into::into).collect();
 			let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
 
 			Some(RawDidLinkedInfo {
@@ -401,6 +414,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl kilt_runtime_api_did::Web3NameLookup<Block, LinkableAccountId> for Runtime {
+		fn batch_query_web3_name_by_account(accounts: Vec<LinkableAccountId>) -> Vec<Option<Vec<u8>>> {
+			accounts.iter().map(|account| runtime_common::did::web3_name_by_account::<Runtime>(account).map(Into::into)).collect()
+		}
+	}
+
 	impl kilt_runtime_api_public_credentials::PublicCredentials<Block, Vec<u8>, Hash, CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>, PublicCredentialsFilter<Hash, AccountId>, PublicCredentialsApiError> for Runtime {
 		fn get_by_id(credential_id: Hash) -> Option<CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>> {
 			let subject = public_credentials::CredentialSubjects::<Runtime>::get(credential_id)?;
@@ -426,6 +445,35 @@ impl_runtime_apis! {
 		fn get_staking_rates() -> kilt_runtime_api_staking::StakingRates {
 			ParachainStaking::get_staking_rates()
 		}
+
+		fn get_collator_commission(collator: &AccountId) -> Option<kilt_runtime_api_staking::CollatorCommission> {
+			ParachainStaking::get_collator_commission(collator)
+		}
+
+		fn get_auto_compound(account: &AccountId) -> sp_runtime::Perbill {
+			ParachainStaking::get_auto_compound(account)
+		}
+
+		fn get_collator_stats(collator: &AccountId, rounds: u32) -> Vec<kilt_runtime_api_staking::CollatorRoundStats<Balance>> {
+			ParachainStaking::get_collator_stats(collator, rounds)
+		}
+
+		fn get_delegator_apy(collator: &AccountId, rounds: u32) -> Option<sp_runtime::FixedU128> {
+			ParachainStaking::get_delegator_apy(collator, rounds)
+		}
+
+		fn get_candidate_identities() -> Vec<(AccountId, Option<kilt_runtime_api_staking::CandidateIdentity<AccountId>>)> {
+			ParachainStaking::get_candidate_identities()
+				.into_iter()
+				.map(|(collator, did)| {
+					let identity = did.map(|did| kilt_runtime_api_staking::CandidateIdentity {
+						web3_name: pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into),
+						did,
+					});
+					(collator, identity)
+				})
+				.collect()
+		}
 	}
 
 	impl kilt_runtime_api_dip_provider::DipProvider<Block, DipProofRequest, CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, DipProofError> for Runtime {
@@ -486,18 +534,27 @@ impl_runtime_apis! {
 			operation: Operation<Balance>,
 		) -> Result<Balance, BondedCurrencyError> {
 			let pool = Pools::<Runtime>::get(&pool_id).ok_or(BondedCurrencyError::PoolNotFound)?;
-			let PoolDetailsOf::<Runtime> { bonded_currencies, .. } = pool;
+			let PoolDetailsOf::<Runtime> { bonded_currencies, collateral, .. } = pool;
 			let currency_id = bonded_currencies.get(currency_idx.saturated_into::<usize>()).ok_or(BondedCurrencyError::CurrencyNotFound)?;
 
 
 			let currency_supply = BondedFungibles::total_issuance(currency_id.to_owned());
 
-			let (low, high, rounding) = match operation {
-				Operation::Mint(amount) => (currency_supply, currency_supply.saturating_add(amount), Round::Up),
-				Operation::Burn(amount) => (currency_supply.saturating_sub(amount), currency_supply, Round::Down),
+			let fees = PoolFeeSettings::<Runtime>::get(&pool_id);
+
+			let (low, high, rounding, fee_rate) = match operation {
+				Operation::Mint(amount) => (currency_supply, currency_supply.saturating_add(amount), Round::Up, fees.mint),
+				Operation::Burn(amount) => (currency_supply.saturating_sub(amount), currency_supply, Round::Down, fees.burn),
 			};
 
-			Self::quote_for_low_and_high_bounds(pool_id, currency_idx, low, high, rounding)
+			let collateral_amount = Self::quote_for_low_and_high_bounds(pool_id, currency_idx, low, high, rounding)?;
+			let (pool_fee, protocol_fee) = BondedCurrencies::calculate_fees(fee_rate, collateral, collateral_amount);
+
+			// Fees are paid on top of the minting costs and withheld from the collateral returned by burning.
+			match rounding {
+				Round::Up => Ok(collateral_amount.saturating_add(pool_fee).saturating_add(protocol_fee)),
+				Round::Down => Ok(collateral_amount.saturating_sub(pool_fee).saturating_sub(protocol_fee)),
+			}
 		}
 
 		fn quote_for_low_and_high_bounds(
@@ -560,6 +617,22 @@ impl_runtime_apis! {
 
 		fn pool_info(pool_id: AccountId) -> Result<PoolDetails<AccountId, Balance, BondedAssetId, AssetId>, BondedCurrencyError> {
 			let pool = Pools::<Runtime>::get(&pool_id).ok_or(BondedCurrencyError::PoolNotFound)?;
+			// The metadata is provided by the owner and is not guaranteed to be valid UTF-8.
+			let metadata = BondedCurrencies::pool_metadata(&pool_id).map(|metadata| PoolMetadataDetails {
+				description: metadata.description.map(|description| String::from_utf8_lossy(&description).into_owned()),
+				uri: metadata.uri.map(|uri| String::from_utf8_lossy(&uri).into_owned()),
+				logo_hash: metadata.logo_hash.map(|logo_hash| logo_hash.as_bytes().to_vec()),
+			});
+			let issuer = BondedCurrencies::pool_issuer(&pool_id).map(|issuer| IssuerDetails {
+				verified: <Runtime as pallet_bonded_coins::Config>::IssuerVerification::is_verified(
+					&pool.owner,
+					&pool_id,
+					&issuer.identifier,
+					&issuer.credential_id,
+				),
+				did: issuer.identifier,
+				credential_id: issuer.credential_id.as_bytes().to_vec(),
+			});
 			let PoolDetailsOf::<Runtime> {
 				curve,
 				currencies_settings,
@@ -604,6 +677,17 @@ impl_runtime_apis! {
 				Curve::Lmsr(params) => Curve::Lmsr(LMSRParameters {
 					m: params.m.to_string(),
 				}),
+				Curve::PiecewiseLinear(params) => Curve::PiecewiseLinear(PiecewiseLinearParameters {
+					points: params.points.iter().map(|point| ControlPoint {
+						supply: point.supply.to_string(),
+						price: point.price.to_string(),
+					}).collect::<Vec<_>>().try_into().map_err(|_| BondedCurrencyError::Internal)?,
+				}),
+				Curve::Exponential(params) => Curve::Exponential(ExponentialParameters {
+					m: params.m.to_string(),
+					k: params.k.to_string(),
+					cap: params.cap.to_string(),
+				}),
 			};
 
 			Ok(PoolDetails {
@@ -615,13 +699,152 @@ impl_runtime_apis! {
 				deposit,
 				manager,
 				state,
-				currencies_settings
+				currencies_settings,
+				metadata,
+				issuer,
 			})
 		}
 
 		fn pool_infos(ids: Vec<AccountId>) -> Result<Vec<PoolDetails<AccountId, Balance, BondedAssetId, AssetId>>, BondedCurrencyError> {
 			ids.into_iter().map(Self::pool_info).collect()
 		}
+
+		fn quote_swap_within_pool(
+			pool_id: AccountId,
+			from_idx: u8,
+			to_idx: u8,
+			amount_in: Balance,
+		) -> Result<Balance, BondedCurrencyError> {
+			let pool = Pools::<Runtime>::get(&pool_id).ok_or(BondedCurrencyError::PoolNotFound)?;
+			let currencies_number = pool.bonded_currencies.len();
+			let from_idx = from_idx.saturated_into::<usize>();
+			let to_idx = to_idx.saturated_into::<usize>();
+
+			if from_idx >= currencies_number || to_idx >= currencies_number {
+				return Err(BondedCurrencyError::CurrencyNotFound);
+			}
+			if from_idx == to_idx {
+				return Err(BondedCurrencyError::InvalidInput);
+			}
+
+			let (amount_out, _, _) = BondedCurrencies::calculate_swap_within_pool(&pool_id, &pool, from_idx, to_idx, amount_in)
+				.map_err(|_| BondedCurrencyError::CalculationError)?;
+
+			Ok(amount_out)
+		}
+
+		fn twap(
+			pool_id: AccountId,
+			currency_idx: u8,
+			window: u32,
+		) -> Result<Balance, BondedCurrencyError> {
+			BondedCurrencies::calculate_twap(&pool_id, currency_idx.saturated_into(), window.into()).map_err(|error| {
+				if error == BondedCoinsError::<Runtime>::PoolUnknown.into() {
+					BondedCurrencyError::PoolNotFound
+				} else if error == BondedCoinsError::<Runtime>::IndexOutOfBounds.into() {
+					BondedCurrencyError::CurrencyNotFound
+				} else if error == BondedCoinsError::<Runtime>::InsufficientPriceHistory.into() {
+					BondedCurrencyError::InsufficientHistory
+				} else if error == BondedCoinsError::<Runtime>::InvalidInput.into()
+					|| error == BondedCoinsError::<Runtime>::PoolNotLive.into()
+				{
+					BondedCurrencyError::InvalidInput
+				} else {
+					BondedCurrencyError::CalculationError
+				}
+			})
+		}
+
+		fn list_pools(
+			start: Option<AccountId>,
+			limit: u32,
+			status: Option<PoolStatusFilter>,
+		) -> Result<PoolsPage<AccountId, PoolDetails<AccountId, Balance, BondedAssetId, AssetId>>, BondedCurrencyError> {
+			if limit == 0 {
+				return Err(BondedCurrencyError::InvalidInput);
+			}
+			let limit = limit.min(MAX_POOLS_PER_PAGE).saturated_into::<usize>();
+
+			let mut entries = match start {
+				Some(start) => Pools::<Runtime>::iter_from(Pools::<Runtime>::hashed_key_for(start)),
+				None => Pools::<Runtime>::iter(),
+			};
+
+			let mut pools = Vec::new();
+			let mut last_scanned = None;
+			let mut scanned: u32 = 0;
+
+			// The listing stops once the page is full or the maximum number of entries has been read.
+			let next = loop {
+				if pools.len() >= limit || scanned >= MAX_SCANNED_ENTRIES {
+					break last_scanned;
+				}
+				let Some((pool_id, pool)) = entries.next() else {
+					break None;
+				};
+				scanned = scanned.saturating_add(1);
+				if status.map_or(true, |status| status.matches(&pool.state)) {
+					pools.push(Self::pool_info(pool_id.clone())?);
+				}
+				last_scanned = Some(pool_id);
+			};
+
+			Ok(PoolsPage { pools, next })
+		}
+
+		fn pool_analytics(pool_id: AccountId) -> Result<PoolAnalytics<BondedAssetId, Balance>, BondedCurrencyError> {
+			let pool = Pools::<Runtime>::get(&pool_id).ok_or(BondedCurrencyError::PoolNotFound)?;
+			let denomination = pool.currencies_settings.denomination;
+
+			let spot_prices = match pool.state {
+				PoolStatus::Active | PoolStatus::Locked(_) => BondedCurrencies::calculate_spot_prices(&pool.curve, &pool.bonded_currencies, denomination, pool.collateral.clone()),
+				// Graduated pools trade against the reserves of their AMM.
+				PoolStatus::Graduated => {
+					let amm_price = AmmPools::<Runtime>::get(&pool_id).and_then(|amm_pool| {
+						let one_coin = 10u128.checked_pow(denomination.into())?;
+						amm::mul_div::<_, _, _, Balance>(amm_pool.collateral_reserve, one_coin, amm_pool.bonded_reserve, Round::Down).ok()
+					});
+					pool.bonded_currencies.iter().map(|_| amm_price).collect()
+				},
+				PoolStatus::Refunding | PoolStatus::Destroying => pool.bonded_currencies.iter().map(|_| None).collect(),
+			};
+
+			let currencies = pool.bonded_currencies.iter().zip(spot_prices).map(|(currency_id, spot_price)| CurrencyAnalytics {
+				id: currency_id.to_owned(),
+				supply: BondedFungibles::total_issuance(currency_id.to_owned()),
+				spot_price,
+			}).collect();
+
+			Ok(PoolAnalytics {
+				collateral_locked: BondedCurrencies::collateral_reserves(&pool_id, pool.collateral),
+				currencies,
+			})
+		}
+
+		fn top_holders(
+			pool_id: AccountId,
+			currency_idx: u8,
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Result<TopHolders<AccountId, Balance>, BondedCurrencyError> {
+			if limit == 0 {
+				return Err(BondedCurrencyError::InvalidInput);
+			}
+			let pool = Pools::<Runtime>::get(&pool_id).ok_or(BondedCurrencyError::PoolNotFound)?;
+			let currency_id = pool.bonded_currencies.get(currency_idx.saturated_into::<usize>()).ok_or(BondedCurrencyError::CurrencyNotFound)?;
+
+			let (holders, next) = holders::top_holders::<BondedFungibles>(
+				currency_id.to_owned(),
+				start,
+				limit.min(MAX_TOP_HOLDERS).saturated_into(),
+				MAX_SCANNED_ENTRIES.saturated_into(),
+			);
+
+			Ok(TopHolders {
+				holders: holders.into_iter().map(|(account, balance)| Holder { account, balance }).collect(),
+				next,
+			})
+		}
 	}
 
 	impl pallet_ismp_runtime_api::IsmpRuntimeApi<Block, <Block as BlockT>::Hash> for Runtime {
@@ -90,6 +90,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					| RuntimeCall::TechnicalCommittee(..)
 					| RuntimeCall::TechnicalMembership(..)
 					| RuntimeCall::TipsMembership(..)
+					| RuntimeCall::BondedCoinsAttesters(..)
 					| RuntimeCall::Timestamp(..)
 					| RuntimeCall::Treasury(..)
 					| RuntimeCall::Utility(..)
@@ -100,17 +101,29 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					)
 					| RuntimeCall::Web3Names(..)
 					| RuntimeCall::BondedCurrencies(
-						// Excludes `mint_into`, `burn_into`, 
+						// Excludes `mint_into`, `burn_into`, `claim_fees`, and the AMM swap and liquidity calls
 						pallet_bonded_coins::Call::create_pool { .. }
 							| pallet_bonded_coins::Call::reset_team { .. }
 							| pallet_bonded_coins::Call::reset_manager { .. }
 							| pallet_bonded_coins::Call::set_lock { .. }
 							| pallet_bonded_coins::Call::unlock { .. }
+							| pallet_bonded_coins::Call::set_graduation { .. }
+							| pallet_bonded_coins::Call::graduate { .. }
+							| pallet_bonded_coins::Call::set_vesting { .. }
+							| pallet_bonded_coins::Call::claim_vested { .. }
+							| pallet_bonded_coins::Call::set_resolver { .. }
+							| pallet_bonded_coins::Call::resolve { .. }
 							| pallet_bonded_coins::Call::start_refund { .. }
 							| pallet_bonded_coins::Call::refund_account { .. }
 							| pallet_bonded_coins::Call::start_destroy { .. }
 							| pallet_bonded_coins::Call::finish_destroy { .. }
 					)
+					| RuntimeCall::LiquidStaking(
+						// Excludes `deposit`, `redeem`, and `withdraw_unbonded`
+						pallet_liquid_staking::Call::initialize { .. }
+							| pallet_liquid_staking::Call::set_collators { .. }
+							| pallet_liquid_staking::Call::compound { .. }
+					)
 			),
 			ProxyType::NonDepositClaiming => matches!(
 				c,
@@ -187,6 +200,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					| RuntimeCall::TechnicalCommittee(..)
 					| RuntimeCall::TechnicalMembership(..)
 					| RuntimeCall::TipsMembership(..)
+					| RuntimeCall::BondedCoinsAttesters(..)
 					| RuntimeCall::Timestamp(..)
 					| RuntimeCall::Treasury(..)
 					| RuntimeCall::Utility(..)
@@ -200,6 +214,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 							| pallet_web3_names::Call::change_deposit_owner { .. }
 					)
 					| RuntimeCall::BondedCurrencies(..)
+					| RuntimeCall::LiquidStaking(..)
 			),
 			ProxyType::Governance => matches!(
 				c,
@@ -208,6 +223,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					| RuntimeCall::TechnicalCommittee(..)
 					| RuntimeCall::TechnicalMembership(..)
 					| RuntimeCall::TipsMembership(..)
+					| RuntimeCall::BondedCoinsAttesters(..)
 					| RuntimeCall::Treasury(..)
 					| RuntimeCall::Utility(..)
 			),
@@ -16,30 +16,56 @@
 
 // If you feel like getting in touch with us, you can do so at <hello@kilt.io>
 
-use frame_support::{traits::Currency, BoundedVec};
+use cumulus_primitives_utility::ChargeWeightInFungibles;
+use frame_support::{
+	traits::{
+		fungibles::{Create, Inspect, Mutate},
+		Currency,
+	},
+	weights::{Weight, WeightToFee as WeightToFeeT},
+	BoundedVec,
+};
+use kilt_support::Deposit;
+use pallet_bonded_coins::{
+	curves::{polynomial::PolynomialParameters, Curve},
+	traits::VerifyIssuer,
+	Locks, PoolDetailsOf, PoolStatus, Pools,
+};
+use pallet_bonded_coins_runtime_api::{runtime_decl_for_bonded_currency::BondedCurrencyV6, PoolStatusFilter};
 use pallet_dip_provider::IdentityCommitmentOf;
 use parity_scale_codec::MaxEncodedLen;
 
 use did::DeriveDidCallAuthorizationVerificationKeyRelationship;
-use pallet_did_lookup::associate_account_request::AssociateAccountRequest;
+use pallet_did_lookup::{
+	associate_account_request::AssociateAccountRequest, linkable_account::LinkableAccountId, ConnectionRecord,
+};
 use pallet_treasury::BalanceOf;
 use pallet_web3_names::{Web3NameOf, Web3OwnershipOf};
+use public_credentials::{CredentialEntry, CredentialSubjects, Credentials};
 use runtime_common::{
+	bonded_coins::{
+		holders, issuer::pool_subject, runtime_api::Error as BondedCurrencyError, AssetId as BondedAssetId, FixedPoint,
+	},
 	constants::{
 		attestation::MAX_ATTESTATION_BYTE_LENGTH,
-		bonded_coins::MAX_POOL_BYTE_LENGTH,
+		bonded_coins::{MAX_POOL_BYTE_LENGTH, MAX_POOL_METADATA_BYTE_LENGTH},
 		deposit_storage::MAX_DEPOSIT_PALLET_KEY_LENGTH,
 		did::{MAX_KEY_LENGTH, MAX_SERVICE_ENDPOINT_BYTE_LENGTH},
-		did_lookup::MAX_CONNECTION_BYTE_LENGTH,
+		did_lookup::{MAX_CONNECTION_BYTE_LENGTH, MAX_LINK_METADATA_BYTE_LENGTH},
 		dip_provider::MAX_COMMITMENT_BYTE_LENGTH,
 		public_credentials::MAX_PUBLIC_CREDENTIAL_STORAGE_LENGTH,
-		MAX_INDICES_BYTE_LENGTH,
+		EXISTENTIAL_DEPOSIT, MAX_INDICES_BYTE_LENGTH,
 	},
 	deposits::DepositKey,
-	AccountId, BlockNumber,
+	AccountId, Balance, BlockNumber, Hash,
 };
 
-use super::{Runtime, RuntimeCall};
+use xcm::v4::prelude::*;
+
+use super::{
+	kilt::BondedCoinsIssuerCtype, xcm::ForeignFungiblesFeeCharger, BondedCoinsAttesters, BondedFungibles, Fungibles,
+	Runtime, RuntimeCall, RuntimeOrigin, WeightToFee,
+};
 
 // TODO: Uncomment if pallet_assets implements measures to reduce their `Call`
 // space footprint.
@@ -92,7 +118,11 @@ fn did_lookup_storage_sizes() {
 
 	let did_connection_size = DidConnection::max_encoded_len();
 
-	assert_eq!(did_connection_size, MAX_CONNECTION_BYTE_LENGTH as usize)
+	assert_eq!(did_connection_size, MAX_CONNECTION_BYTE_LENGTH as usize);
+
+	let link_metadata_size = pallet_did_lookup::LinkMetadataOf::<Runtime, ()>::max_encoded_len();
+
+	assert_eq!(link_metadata_size, MAX_LINK_METADATA_BYTE_LENGTH as usize)
 }
 
 #[test]
@@ -114,6 +144,14 @@ fn test_bonded_coins_pool_max_length() {
 	assert_eq!(id + value, MAX_POOL_BYTE_LENGTH as usize)
 }
 
+#[test]
+fn test_bonded_coins_pool_metadata_max_length() {
+	let value = pallet_bonded_coins::PoolMetadataOf::<Runtime>::max_encoded_len();
+	let id = <Runtime as pallet_bonded_coins::Config>::PoolId::max_encoded_len();
+
+	assert_eq!(id + value, MAX_POOL_METADATA_BYTE_LENGTH as usize)
+}
+
 #[test]
 fn indices_storage_sizes() {
 	type Indices = (<Runtime as frame_system::Config>::AccountId, BalanceOf<Runtime>, bool);
@@ -326,3 +364,307 @@ fn test_derive_did_verification_relation_empty() {
 		Err(did::RelationshipDeriveError::InvalidCallParameter)
 	);
 }
+
+#[test]
+fn foreign_fungibles_fee_is_scaled_by_existential_deposits() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let usdt = Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]);
+		let usdt_min_balance = 10_000;
+		assert!(<Fungibles as Create<AccountId>>::create(
+			usdt.clone(),
+			AccountId::new([0u8; 32]),
+			true,
+			usdt_min_balance
+		)
+		.is_ok());
+
+		let weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+		let native_fee = WeightToFee::<Runtime>::weight_to_fee(&weight);
+		let expected_fee = native_fee * usdt_min_balance / EXISTENTIAL_DEPOSIT;
+		assert!(expected_fee > 0);
+
+		assert_eq!(
+			ForeignFungiblesFeeCharger::charge_weight_in_fungibles(usdt, weight),
+			Ok(expected_fee)
+		);
+	});
+}
+
+#[test]
+fn foreign_fungibles_fee_requires_sufficient_asset() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let usdt = Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]);
+		assert!(
+			<Fungibles as Create<AccountId>>::create(usdt.clone(), AccountId::new([0u8; 32]), false, 10_000).is_ok()
+		);
+
+		assert_eq!(
+			ForeignFungiblesFeeCharger::charge_weight_in_fungibles(usdt, Weight::from_parts(1_000_000_000, 0)),
+			Err(XcmError::TooExpensive)
+		);
+	});
+}
+
+#[test]
+fn bonded_coins_issuer_requires_credential_of_trusted_attester() {
+	type IssuerVerification = <Runtime as pallet_bonded_coins::Config>::IssuerVerification;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		let owner = AccountId::new([1u8; 32]);
+		let attester = AccountId::new([2u8; 32]);
+		let pool_id = AccountId::new([3u8; 32]);
+		let credential_id = Hash::repeat_byte(4);
+		let ctype_hash = Hash::repeat_byte(5);
+		BondedCoinsIssuerCtype::set(&ctype_hash);
+
+		for account in [&owner, &attester] {
+			pallet_did_lookup::ConnectedDids::<Runtime>::insert(
+				LinkableAccountId::from(account.clone()),
+				ConnectionRecord {
+					did: account.clone(),
+					deposit: Deposit {
+						owner: account.clone(),
+						amount: 0,
+					},
+				},
+			);
+		}
+
+		let subject_id =
+			pool_subject::<Runtime, AccountId>(&pool_id).expect("The subject of a pool should be a valid asset DID.");
+		let credential = CredentialEntry {
+			ctype_hash,
+			attester: attester.clone(),
+			revoked: false,
+			block_number: 0,
+			deposit: Deposit {
+				owner: attester.clone(),
+				amount: 0,
+			},
+			authorization_id: None,
+		};
+		Credentials::<Runtime>::insert(&subject_id, credential_id, credential.clone());
+		CredentialSubjects::<Runtime>::insert(credential_id, subject_id.clone());
+
+		// The attester is not trusted yet.
+		assert!(!IssuerVerification::is_verified(
+			&owner,
+			&pool_id,
+			&owner,
+			&credential_id
+		));
+
+		assert!(BondedCoinsAttesters::add_member(RuntimeOrigin::root(), attester.clone().into()).is_ok());
+		assert!(IssuerVerification::is_verified(
+			&owner,
+			&pool_id,
+			&owner,
+			&credential_id
+		));
+
+		// The credential is about another pool.
+		assert!(!IssuerVerification::is_verified(
+			&owner,
+			&AccountId::new([6u8; 32]),
+			&owner,
+			&credential_id
+		));
+
+		// Trusted attesters cannot vouch for themselves.
+		assert!(!IssuerVerification::is_verified(
+			&attester,
+			&pool_id,
+			&attester,
+			&credential_id
+		));
+
+		// The credential is of another CType.
+		BondedCoinsIssuerCtype::set(&Hash::repeat_byte(7));
+		assert!(!IssuerVerification::is_verified(
+			&owner,
+			&pool_id,
+			&owner,
+			&credential_id
+		));
+		BondedCoinsIssuerCtype::set(&ctype_hash);
+
+		// The credential was revoked.
+		Credentials::<Runtime>::insert(
+			&subject_id,
+			credential_id,
+			CredentialEntry {
+				revoked: true,
+				..credential
+			},
+		);
+		assert!(!IssuerVerification::is_verified(
+			&owner,
+			&pool_id,
+			&owner,
+			&credential_id
+		));
+	});
+}
+
+fn bonded_coins_collateral() -> Location {
+	Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])
+}
+
+/// Creates a pool issuing a single currency at a constant price.
+fn create_bonded_coins_pool(pool_id: &AccountId, currency_id: BondedAssetId, state: PoolStatus<Locks>) {
+	if !<Fungibles as Inspect<AccountId>>::asset_exists(bonded_coins_collateral()) {
+		assert!(<Fungibles as Create<AccountId>>::create(bonded_coins_collateral(), pool_id.clone(), true, 1).is_ok());
+	}
+	assert!(<BondedFungibles as Create<AccountId>>::create(currency_id, pool_id.clone(), true, 1).is_ok());
+
+	let curve = Curve::Polynomial(PolynomialParameters {
+		m: FixedPoint::from_num(0),
+		n: FixedPoint::from_num(0),
+		o: FixedPoint::from_num(1),
+	});
+	let mut pool_details = PoolDetailsOf::<Runtime>::new(
+		pool_id.clone(),
+		curve,
+		bonded_coins_collateral(),
+		vec![currency_id].try_into().expect("One currency is within bounds."),
+		true,
+		false,
+		0,
+		1,
+		0,
+	);
+	pool_details.state = state;
+	Pools::<Runtime>::insert(pool_id, pool_details);
+}
+
+#[test]
+fn bonded_coins_list_pools_pages_through_pools() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let pool_ids = [
+			AccountId::new([1u8; 32]),
+			AccountId::new([2u8; 32]),
+			AccountId::new([3u8; 32]),
+		];
+		create_bonded_coins_pool(&pool_ids[0], 0, PoolStatus::Active);
+		create_bonded_coins_pool(&pool_ids[1], 1, PoolStatus::Active);
+		create_bonded_coins_pool(&pool_ids[2], 2, PoolStatus::Refunding);
+
+		let mut listed = Vec::new();
+		let mut start = None;
+		loop {
+			let page = Runtime::list_pools(start, 2, None).expect("Listing pools should not fail.");
+			assert!(page.pools.len() <= 2);
+			listed.extend(page.pools.into_iter().map(|pool| pool.id));
+			start = page.next;
+			if start.is_none() {
+				break;
+			}
+		}
+		listed.sort();
+		assert_eq!(listed, pool_ids.to_vec());
+
+		let refunding =
+			Runtime::list_pools(None, 10, Some(PoolStatusFilter::Refunding)).expect("Listing pools should not fail.");
+		assert_eq!(
+			refunding.pools.into_iter().map(|pool| pool.id).collect::<Vec<_>>(),
+			vec![pool_ids[2].clone()]
+		);
+		assert!(refunding.next.is_none());
+
+		assert!(matches!(
+			Runtime::list_pools(None, 0, None),
+			Err(BondedCurrencyError::InvalidInput)
+		));
+	});
+}
+
+#[test]
+fn bonded_coins_pool_analytics_reports_supply_and_collateral() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let active_pool = AccountId::new([1u8; 32]);
+		let refunding_pool = AccountId::new([2u8; 32]);
+		create_bonded_coins_pool(&active_pool, 0, PoolStatus::Active);
+		create_bonded_coins_pool(&refunding_pool, 1, PoolStatus::Refunding);
+
+		for (pool_id, currency_id) in [(&active_pool, 0), (&refunding_pool, 1)] {
+			assert!(<Fungibles as Mutate<AccountId>>::mint_into(bonded_coins_collateral(), pool_id, 5_000).is_ok());
+			assert!(<BondedFungibles as Mutate<AccountId>>::mint_into(currency_id, pool_id, 1_000).is_ok());
+		}
+
+		let analytics = Runtime::pool_analytics(active_pool).expect("Analytics of an existing pool should not fail.");
+		assert_eq!(analytics.collateral_locked, 5_000);
+		assert_eq!(analytics.currencies.len(), 1);
+		assert_eq!(analytics.currencies[0].id, 0);
+		assert_eq!(analytics.currencies[0].supply, 1_000);
+		assert!(analytics.currencies[0].spot_price.is_some());
+
+		// Refunding pools are not trading.
+		let analytics =
+			Runtime::pool_analytics(refunding_pool).expect("Analytics of an existing pool should not fail.");
+		assert_eq!(analytics.currencies[0].supply, 1_000);
+		assert!(analytics.currencies[0].spot_price.is_none());
+
+		assert!(matches!(
+			Runtime::pool_analytics(AccountId::new([3u8; 32])),
+			Err(BondedCurrencyError::PoolNotFound)
+		));
+	});
+}
+
+#[test]
+fn bonded_coins_top_holders_pages_through_holders() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let pool_id = AccountId::new([0u8; 32]);
+		create_bonded_coins_pool(&pool_id, 0, PoolStatus::Active);
+
+		let balances: Vec<(AccountId, Balance)> = (1u8..=5)
+			.map(|seed| (AccountId::new([seed; 32]), Balance::from(seed) * 100))
+			.collect();
+		for (account, balance) in balances.iter() {
+			assert!(<BondedFungibles as Mutate<AccountId>>::mint_into(0, account, *balance).is_ok());
+		}
+
+		let top_holders = Runtime::top_holders(pool_id.clone(), 0, None, 2).expect("Querying holders should not fail.");
+		assert_eq!(
+			top_holders
+				.holders
+				.into_iter()
+				.map(|holder| (holder.account, holder.balance))
+				.collect::<Vec<_>>(),
+			vec![balances[4].clone(), balances[3].clone()]
+		);
+		assert!(top_holders.next.is_none());
+
+		// Reading two accounts per call takes three pages, which together contain
+		// all holders.
+		let mut pages = 0;
+		let mut found = Vec::new();
+		let mut start = None;
+		loop {
+			let (page, next) = holders::top_holders::<BondedFungibles>(0, start, 5, 2);
+			assert!(page.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+			found.extend(page);
+			pages += 1;
+			start = next;
+			if start.is_none() {
+				break;
+			}
+		}
+		assert_eq!(pages, 3);
+		found.sort();
+		assert_eq!(found, balances);
+
+		assert!(matches!(
+			Runtime::top_holders(pool_id.clone(), 0, None, 0),
+			Err(BondedCurrencyError::InvalidInput)
+		));
+		assert!(matches!(
+			Runtime::top_holders(pool_id, 1, None, 2),
+			Err(BondedCurrencyError::CurrencyNotFound)
+		));
+		assert!(matches!(
+			Runtime::top_holders(AccountId::new([9u8; 32]), 0, None, 2),
+			Err(BondedCurrencyError::PoolNotFound)
+		));
+	});
+}
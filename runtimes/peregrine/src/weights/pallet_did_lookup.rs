@@ -126,6 +126,46 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like the
+	/// Ethereum association, the Bitcoin association verifies a secp256k1
+	/// signature over a hashed message.
+	fn associate_bitcoin_account() -> Weight {
+		<Self as pallet_did_lookup::WeightInfo>::associate_eth_account()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like the
+	/// ed25519 account association, the Solana association verifies an ed25519
+	/// signature.
+	fn associate_solana_account() -> Weight {
+		<Self as pallet_did_lookup::WeightInfo>::associate_account_multisig_ed25519()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like the
+	/// Ethereum association, the Cosmos association verifies a secp256k1
+	/// signature over a hashed message.
+	fn associate_cosmos_account() -> Weight {
+		<Self as pallet_did_lookup::WeightInfo>::associate_eth_account()
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(301), added: 2776, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:2)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:0 w:1)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn associate_eth_typed_data_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `449`
+		//  Estimated: `3766`
+		// Minimum execution time: 138_238_000 picoseconds.
+		Weight::from_parts(140_083_000, 0)
+			.saturating_add(Weight::from_parts(0, 3766))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
@@ -222,6 +262,86 @@ impl<T: frame_system::Config> pallet_did_lookup::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `DidLookup::ConnectedDids` (r:1 w:1)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::PrimaryAccounts` (r:1 w:1)
+	/// Proof: `DidLookup::PrimaryAccounts` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccountsMetadata` (r:2 w:2)
+	/// Proof: `DidLookup::ConnectedAccountsMetadata` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:1 w:0)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(301), added: 2776, mode: `MaxEncodedLen`)
+	fn update_account_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `647`
+		//  Estimated: `6542`
+		// Minimum execution time: 117_587_000 picoseconds.
+		Weight::from_parts(118_729_000, 0)
+			.saturating_add(Weight::from_parts(0, 6542))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `System::Account` (r:51 w:51)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:51 w:51)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(301), added: 2776, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedDids` (r:50 w:50)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::PrimaryAccounts` (r:50 w:0)
+	/// Proof: `DidLookup::PrimaryAccounts` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccountsMetadata` (r:0 w:50)
+	/// Proof: `DidLookup::ConnectedAccountsMetadata` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:100)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:0 w:50)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn associate_accounts(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462 + n * (212 ±0)`
+		//  Estimated: `3766 + n * (2776 ±0)`
+		// Minimum execution time: 153_486_000 picoseconds.
+		Weight::from_parts(38_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 3766))
+			// Standard Error: 1_218_317
+			.saturating_add(Weight::from_parts(118_207_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2776).saturating_mul(n.into()))
+	}
+	/// Storage: `DidLookup::ConnectedDids` (r:50 w:50)
+	/// Proof: `DidLookup::ConnectedDids` (`max_values`: None, `max_size`: Some(129), added: 2604, mode: `MaxEncodedLen`)
+	/// Storage: `Migration::MigratedKeys` (r:50 w:0)
+	/// Proof: `Migration::MigratedKeys` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:50 w:50)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:50 w:50)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(301), added: 2776, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::PrimaryAccounts` (r:50 w:0)
+	/// Proof: `DidLookup::PrimaryAccounts` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccountsMetadata` (r:0 w:50)
+	/// Proof: `DidLookup::ConnectedAccountsMetadata` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `DidLookup::ConnectedAccounts` (r:0 w:50)
+	/// Proof: `DidLookup::ConnectedAccounts` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn remove_account_associations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160 + n * (380 ±0)`
+		//  Estimated: `990 + n * (2776 ±0)`
+		// Minimum execution time: 55_311_000 picoseconds.
+		Weight::from_parts(11_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			// Standard Error: 462_317
+			.saturating_add(Weight::from_parts(44_910_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2776).saturating_mul(n.into()))
+	}
 }
 
 #[cfg(test)]
@@ -275,6 +395,18 @@ mod tests {
 		);
 	}
 	#[test]
+	fn test_associate_eth_typed_data_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3766
+		);
+	}
+	#[test]
 	fn test_associate_sender() {
 		assert!(
 			<crate::Runtime as frame_system::Config>::BlockWeights::get()
@@ -334,4 +466,76 @@ mod tests {
 				> 3766
 		);
 	}
+	#[test]
+	fn test_update_account_metadata() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6542
+		);
+	}
+	#[test]
+	fn test_associate_accounts() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3766
+		);
+	}
+	#[test]
+	fn test_remove_account_associations() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 990
+		);
+	}
+	#[test]
+	fn test_associate_bitcoin_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as pallet_did_lookup::WeightInfo>::associate_bitcoin_account().proof_size()
+		);
+	}
+	#[test]
+	fn test_associate_solana_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as pallet_did_lookup::WeightInfo>::associate_solana_account().proof_size()
+		);
+	}
+	#[test]
+	fn test_associate_cosmos_account() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as pallet_did_lookup::WeightInfo>::associate_cosmos_account().proof_size()
+		);
+	}
 }
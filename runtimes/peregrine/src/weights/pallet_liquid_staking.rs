@@ -0,0 +1,393 @@
+// KILT Blockchain – <https://kilt.io>
+// Copyright (C) 2025, KILT Foundation
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at <hello@kilt.io>
+
+//! Autogenerated weights for `pallet_liquid_staking`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 33.0.0
+//! DATE: 2025-02-12, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `rust-2`, CPU: `12th Gen Intel(R) Core(TM) i9-12900K`
+//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/kilt-parachain
+// benchmark
+// pallet
+// --heap-pages=4096
+// --chain=dev
+// --pallet=pallet-liquid-staking
+// --extrinsic=*
+// --header=HEADER-GPL
+// --template=.maintain/runtime-weight-template.hbs
+// --output=./runtimes/peregrine/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_imports)]
+#![allow(clippy::as_conversions)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_liquid_staking`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T> {
+	/// Storage: `LiquidStaking::DerivativeAsset` (r:1 w:1)
+	/// Proof: `LiquidStaking::DerivativeAsset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BondedFungibles::Asset` (r:1 w:1)
+	/// Proof: `BondedFungibles::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedFungibles::Metadata` (r:1 w:1)
+	/// Proof: `BondedFungibles::Metadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn initialize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3675`
+		// Minimum execution time: 31_204_000 picoseconds.
+		Weight::from_parts(32_145_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: Some(833), added: 3308, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:4 w:4)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: None, `max_size`: Some(1790), added: 4265, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopCandidates` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopCandidates` (`max_values`: Some(1), `max_size`: Some(3602), added: 4097, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:1)
+	/// Proof: `ParachainStaking::TotalCollatorStake` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Unstaking` (r:1 w:1)
+	/// Proof: `ParachainStaking::Unstaking` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AutoCompound` (r:0 w:1)
+	/// Proof: `ParachainStaking::AutoCompound` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::Collators` (r:0 w:1)
+	/// Proof: `LiquidStaking::Collators` (`max_values`: Some(1), `max_size`: Some(129), added: 624, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 4]`.
+	fn set_collators(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1_214 + n * (171 ±0)`
+		//  Estimated: `5087 + n * (4265 ±0)`
+		// Minimum execution time: 61_312_000 picoseconds.
+		Weight::from_parts(58_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 5087))
+			// Standard Error: 1_078_332
+			.saturating_add(Weight::from_parts(21_538_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4265).saturating_mul(n.into()))
+	}
+	/// Storage: `LiquidStaking::DerivativeAsset` (r:1 w:0)
+	/// Proof: `LiquidStaking::DerivativeAsset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: Some(833), added: 3308, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BlocksAuthored` (r:4 w:0)
+	/// Proof: `ParachainStaking::BlocksAuthored` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BlocksRewarded` (r:4 w:4)
+	/// Proof: `ParachainStaking::BlocksRewarded` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Rewards` (r:1 w:1)
+	/// Proof: `ParachainStaking::Rewards` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::TotalPooled` (r:1 w:1)
+	/// Proof: `LiquidStaking::TotalPooled` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::QueuedUnbond` (r:1 w:0)
+	/// Proof: `LiquidStaking::QueuedUnbond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::TotalUnbonding` (r:1 w:0)
+	/// Proof: `LiquidStaking::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedFungibles::Asset` (r:1 w:1)
+	/// Proof: `BondedFungibles::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedFungibles::Account` (r:1 w:1)
+	/// Proof: `BondedFungibles::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::Collators` (r:1 w:0)
+	/// Proof: `LiquidStaking::Collators` (`max_values`: Some(1), `max_size`: Some(129), added: 624, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: None, `max_size`: Some(1790), added: 4265, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopCandidates` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopCandidates` (`max_values`: Some(1), `max_size`: Some(3602), added: 4097, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:1)
+	/// Proof: `ParachainStaking::TotalCollatorStake` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Unstaking` (r:1 w:1)
+	/// Proof: `ParachainStaking::Unstaking` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 4]`.
+	fn deposit(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2_315 + n * (152 ±0)`
+		//  Estimated: `5087 + n * (2523 ±0)`
+		// Minimum execution time: 182_455_000 picoseconds.
+		Weight::from_parts(170_117_000, 0)
+			.saturating_add(Weight::from_parts(0, 5087))
+			// Standard Error: 472_032
+			.saturating_add(Weight::from_parts(9_412_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(12))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: `LiquidStaking::DerivativeAsset` (r:1 w:0)
+	/// Proof: `LiquidStaking::DerivativeAsset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: Some(833), added: 3308, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BlocksAuthored` (r:4 w:0)
+	/// Proof: `ParachainStaking::BlocksAuthored` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BlocksRewarded` (r:4 w:4)
+	/// Proof: `ParachainStaking::BlocksRewarded` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Rewards` (r:1 w:1)
+	/// Proof: `ParachainStaking::Rewards` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::TotalPooled` (r:1 w:1)
+	/// Proof: `LiquidStaking::TotalPooled` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::QueuedUnbond` (r:1 w:1)
+	/// Proof: `LiquidStaking::QueuedUnbond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::TotalUnbonding` (r:1 w:0)
+	/// Proof: `LiquidStaking::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `BondedFungibles::Asset` (r:1 w:1)
+	/// Proof: `BondedFungibles::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `BondedFungibles::Account` (r:1 w:1)
+	/// Proof: `BondedFungibles::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::CurrentBatch` (r:1 w:0)
+	/// Proof: `LiquidStaking::CurrentBatch` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::Unbonding` (r:1 w:1)
+	/// Proof: `LiquidStaking::Unbonding` (`max_values`: None, `max_size`: Some(241), added: 2716, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 4]`.
+	fn redeem(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2_108 + n * (152 ±0)`
+		//  Estimated: `4298 + n * (2523 ±0)`
+		// Minimum execution time: 141_871_000 picoseconds.
+		Weight::from_parts(132_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 4298))
+			// Standard Error: 453_732
+			.saturating_add(Weight::from_parts(9_046_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: `LiquidStaking::Unbonding` (r:1 w:1)
+	/// Proof: `LiquidStaking::Unbonding` (`max_values`: None, `max_size`: Some(241), added: 2716, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::UnbondingBatches` (r:10 w:10)
+	/// Proof: `LiquidStaking::UnbondingBatches` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Unstaking` (r:1 w:1)
+	/// Proof: `ParachainStaking::Unstaking` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::TotalUnbonding` (r:1 w:1)
+	/// Proof: `LiquidStaking::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 10]`.
+	fn withdraw_unbonded(u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1_095 + u * (32 ±0)`
+		//  Estimated: `3746 + u * (2507 ±0)`
+		// Minimum execution time: 72_380_000 picoseconds.
+		Weight::from_parts(69_131_000, 0)
+			.saturating_add(Weight::from_parts(0, 3746))
+			// Standard Error: 157_782
+			.saturating_add(Weight::from_parts(3_127_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(u.into()))
+	}
+	/// Storage: `LiquidStaking::DerivativeAsset` (r:1 w:0)
+	/// Proof: `LiquidStaking::DerivativeAsset` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: Some(833), added: 3308, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BlocksAuthored` (r:4 w:0)
+	/// Proof: `ParachainStaking::BlocksAuthored` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BlocksRewarded` (r:4 w:4)
+	/// Proof: `ParachainStaking::BlocksRewarded` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Rewards` (r:1 w:1)
+	/// Proof: `ParachainStaking::Rewards` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::TotalPooled` (r:1 w:1)
+	/// Proof: `LiquidStaking::TotalPooled` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::QueuedUnbond` (r:1 w:0)
+	/// Proof: `LiquidStaking::QueuedUnbond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::TotalUnbonding` (r:1 w:0)
+	/// Proof: `LiquidStaking::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::Collators` (r:1 w:0)
+	/// Proof: `LiquidStaking::Collators` (`max_values`: Some(1), `max_size`: Some(129), added: 624, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: None, `max_size`: Some(1790), added: 4265, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopCandidates` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopCandidates` (`max_values`: Some(1), `max_size`: Some(3602), added: 4097, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:1)
+	/// Proof: `ParachainStaking::TotalCollatorStake` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Unstaking` (r:1 w:1)
+	/// Proof: `ParachainStaking::Unstaking` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 4]`.
+	fn compound(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2_214 + n * (152 ±0)`
+		//  Estimated: `5087 + n * (2523 ±0)`
+		// Minimum execution time: 151_903_000 picoseconds.
+		Weight::from_parts(141_076_000, 0)
+			.saturating_add(Weight::from_parts(0, 5087))
+			// Standard Error: 458_332
+			.saturating_add(Weight::from_parts(9_138_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: `LiquidStaking::QueuedUnbond` (r:1 w:1)
+	/// Proof: `LiquidStaking::QueuedUnbond` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: Some(833), added: 3308, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:4 w:4)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: None, `max_size`: Some(1790), added: 4265, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BlocksAuthored` (r:4 w:0)
+	/// Proof: `ParachainStaking::BlocksAuthored` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::BlocksRewarded` (r:4 w:4)
+	/// Proof: `ParachainStaking::BlocksRewarded` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Rewards` (r:1 w:1)
+	/// Proof: `ParachainStaking::Rewards` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopCandidates` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopCandidates` (`max_values`: Some(1), `max_size`: Some(3602), added: 4097, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TotalCollatorStake` (r:1 w:1)
+	/// Proof: `ParachainStaking::TotalCollatorStake` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Unstaking` (r:1 w:1)
+	/// Proof: `ParachainStaking::Unstaking` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::CurrentBatch` (r:1 w:1)
+	/// Proof: `LiquidStaking::CurrentBatch` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::TotalUnbonding` (r:1 w:1)
+	/// Proof: `LiquidStaking::TotalUnbonding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidStaking::UnbondingBatches` (r:0 w:1)
+	/// Proof: `LiquidStaking::UnbondingBatches` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 4]`.
+	fn on_initialize_unbond_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1_632 + n * (171 ±0)`
+		//  Estimated: `5087 + n * (4265 ±0)`
+		// Minimum execution time: 95_604_000 picoseconds.
+		Weight::from_parts(71_243_000, 0)
+			.saturating_add(Weight::from_parts(0, 5087))
+			// Standard Error: 1_354_132
+			.saturating_add(Weight::from_parts(27_054_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4265).saturating_mul(n.into()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn test_initialize() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3675
+		);
+	}
+	#[test]
+	fn test_set_collators() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5087
+		);
+	}
+	#[test]
+	fn test_deposit() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5087
+		);
+	}
+	#[test]
+	fn test_redeem() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4298
+		);
+	}
+	#[test]
+	fn test_withdraw_unbonded() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3746
+		);
+	}
+	#[test]
+	fn test_compound() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5087
+		);
+	}
+	#[test]
+	fn test_on_initialize_unbond_batch() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5087
+		);
+	}
+}
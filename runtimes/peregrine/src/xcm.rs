@@ -34,9 +34,13 @@ use pallet_asset_switch::xcm::{
 	SwitchPairRemoteAssetTransactor, UsingComponentsForSwitchPairRemoteAsset, UsingComponentsForXcmFeeAsset,
 };
 use pallet_xcm::XcmPassthrough;
-use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
+use parachains_common::{
+	message_queue::{NarrowOriginToSibling, ParaIdToSibling},
+	xcm_config::AssetFeeAsExistentialDepositMultiplier,
+};
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use sp_core::ConstU32;
+use sp_runtime::traits::ConvertInto;
 use sp_std::prelude::ToOwned;
 use xcm::v4::prelude::*;
 use xcm_builder::{
@@ -50,8 +54,9 @@ use xcm_executor::{traits::WithOriginFilter, XcmExecutor};
 use runtime_common::{
 	constants,
 	xcm_config::{
-		DenyReserveTransferToRelayChain, DenyThenTry, HeapSize, HereLocation, LocalAssetTransactor,
-		LocationToAccountId, MaxAssetsIntoHolding, MaxInstructions, MaxStale, ParentLocation, ParentOrSiblings,
+		DenyReserveTransferToRelayChain, DenyThenTry, ForeignAssetTransactor, HeapSize, HereLocation,
+		IsForeignReserveAsset, LocalAssetTransactor, LocationToAccountId, LocationToAccountIdWithRemoteAccounts,
+		MatchesForeignFungibles, MaxAssetsIntoHolding, MaxInstructions, MaxStale, ParentLocation, ParentOrSiblings,
 		ServiceWeight, UnitWeightCost,
 	},
 	AccountId, SendDustAndFeesToTreasury,
@@ -72,16 +77,23 @@ parameter_types! {
 /// `Transact` operations.
 pub type LocationToAccountIdConverter = LocationToAccountId<RelayNetworkId>;
 
+/// In addition to [LocationToAccountIdConverter], maps accounts on other
+/// chains to local accounts, so that they can fund bonded coin pools
+/// cross-chain. Only used for foreign assets and XCM `Transact`, so that
+/// sovereign accounts and the asset switch transactor are unaffected.
+pub type LocationToAccountIdWithRemoteAccountsConverter = LocationToAccountIdWithRemoteAccounts<RelayNetworkId>;
+
 /// This is the type we use to convert an (incoming) XCM origin into a local
 /// `Origin` instance, ready for dispatching a transaction with Xcm's
 /// `Transact`. There is an `OriginKind` which can bias the kind of local
 /// `Origin` it will become.
 pub type XcmOriginToTransactDispatchOrigin = (
 	// Sovereign account converter; this attempts to derive an `AccountId` from the origin location
-	// using `LocationToAccountIdConverter` and then turn that into the usual `Signed` origin. Useful for
-	// foreign chains who want to have a local sovereign account on this chain which they control.
+	// using `LocationToAccountIdWithRemoteAccountsConverter` and then turn that into the usual `Signed`
+	// origin. Useful for foreign chains who want to have a local sovereign account on this chain which
+	// they control, and for accounts on other chains minting and burning bonded coins.
 	// In contrast to Spiritnet, it's fine to include this on peregrine for testing.
-	SovereignSignedViaLocation<LocationToAccountIdConverter, RuntimeOrigin>,
+	SovereignSignedViaLocation<LocationToAccountIdWithRemoteAccountsConverter, RuntimeOrigin>,
 	// Native converter for Relay-chain (Parent) location which converts to a `Relay` origin when
 	// recognized.
 	RelayChainAsNative<RelayChainOrigin, RuntimeOrigin>,
@@ -141,6 +153,13 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 				| RuntimeCall::Web3Names { .. }
 				| RuntimeCall::PublicCredentials { .. }
 				| RuntimeCall::Attestation { .. }
+				// Bonded coins can be minted and burned by accounts on other chains, which
+				// send the collateral via XCM.
+				| RuntimeCall::BondedCurrencies (
+							pallet_bonded_coins::Call::mint_into { .. }
+							| pallet_bonded_coins::Call::burn_into { .. }
+							| pallet_bonded_coins::Call::burn_into_remote { .. }
+						)
 				// we exclude here [dispatch_as] and [submit_did_call]
 				| RuntimeCall::Did (
 							did::Call::add_key_agreement_key { .. }
@@ -178,8 +197,23 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 
 parameter_types! {
 	pub TreasuryAccountId: AccountId = Treasury::account_id();
+	pub XcmForeignAssetFeesReceiver: Option<AccountId> = Some(Treasury::account_id());
 }
 
+/// Means for transacting the foreign assets registered in [Fungibles], such as
+/// the collateral of bonded coin pools.
+pub type ForeignFungiblesTransactor = ForeignAssetTransactor<Fungibles, RelayNetworkId, CheckingAccount>;
+
+/// Charges XCM fees in foreign assets registered in [Fungibles]. The fee is
+/// converted based on the ratio of the existential deposits of the native
+/// currency and the asset, which requires the asset to be sufficient.
+pub type ForeignFungiblesFeeCharger = AssetFeeAsExistentialDepositMultiplier<
+	Runtime,
+	WeightToFee<Runtime>,
+	pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+	(),
+>;
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
@@ -201,6 +235,9 @@ impl xcm_executor::Config for XcmConfig {
 			NoChecking,
 			CheckingAccount,
 		>,
+		// Allow foreign assets registered in the fungibles pallet, e.g., the collateral of bonded coin
+		// pools, to be deposited into and withdrawn from the current system.
+		ForeignFungiblesTransactor,
 		// Transactor for fungibles matching the "Here" location.
 		LocalAssetTransactor<Balances, RelayNetworkId>,
 	);
@@ -209,6 +246,8 @@ impl xcm_executor::Config for XcmConfig {
 		NativeAsset,
 		IsSwitchPairRemoteAsset<Runtime, KiltToEKiltSwitchPallet>,
 		IsSwitchPairXcmFeeAsset<Runtime, KiltToEKiltSwitchPallet>,
+		// Accept foreign assets registered in the fungibles pallet from their reserve chain.
+		IsForeignReserveAsset<Fungibles>,
 	);
 
 	// Teleporting is disabled.
@@ -233,6 +272,18 @@ impl xcm_executor::Config for XcmConfig {
 			WeightToFee<Runtime>,
 			TreasuryAccountId,
 		>,
+		// Can pay with the foreign assets registered in the fungibles pallet.
+		cumulus_primitives_utility::TakeFirstAssetTrader<
+			AccountId,
+			ForeignFungiblesFeeCharger,
+			MatchesForeignFungibles<Fungibles>,
+			Fungibles,
+			cumulus_primitives_utility::XcmFeesTo32ByteAccount<
+				ForeignFungiblesTransactor,
+				AccountId,
+				XcmForeignAssetFeesReceiver,
+			>,
+		>,
 		// Can pay with the fungible that matches the "Here" location.
 		UsingComponents<WeightToFee<Runtime>, HereLocation, AccountId, Balances, SendDustAndFeesToTreasury<Runtime>>,
 	);
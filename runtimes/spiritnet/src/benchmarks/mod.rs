@@ -89,6 +89,7 @@ define_benchmarks!(
 	[pallet_message_queue, MessageQueue]
 	[cumulus_pallet_parachain_system, ParachainSystem]
 	[frame_benchmarking::baseline, frame_benchmarking::baseline::Pallet::<Runtime>]
+	[kilt_runtime_api_did, runtime_common::benchmarks::web3_name_lookup::Pallet::<Runtime>]
 	// pallet_collective instances
 	[pallet_collective, Council]
 	[pallet_technical_committee_collective, TechnicalCommittee]
@@ -161,6 +162,7 @@ impl frame_system_benchmarking::Config for Runtime {
 }
 
 impl cumulus_pallet_session_benchmarking::Config for Runtime {}
+impl runtime_common::benchmarks::web3_name_lookup::Config for Runtime {}
 impl frame_benchmarking::baseline::Config for Runtime {}
 
 pub(crate) fn benchmark_metadata(extra: bool) -> (Vec<BenchmarkList>, Vec<StorageInfo>) {
@@ -20,13 +20,14 @@ use did::{
 	DeriveDidCallAuthorizationVerificationKeyRelationship, DeriveDidCallKeyRelationshipResult, DidRawOrigin,
 	DidVerificationKeyRelationship, EnsureDidOrigin, RelationshipDeriveError,
 };
+use frame_support::weights::Weight;
 use frame_system::EnsureRoot;
 use runtime_common::{constants, AccountId, DidIdentifier, SendDustAndFeesToTreasury};
 use sp_core::ConstBool;
 
 use crate::{
 	weights::{self},
-	Balances, Migration, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin,
+	Balances, Migration, ParachainStaking, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin,
 };
 
 impl DeriveDidCallAuthorizationVerificationKeyRelationship for RuntimeCall {
@@ -105,6 +106,17 @@ impl did::traits::DidDeletionHook<Runtime> for EnsureNoNamesAndNoLinkedAccountsO
 
 		true
 	}
+
+	fn on_deleted(did: &did::DidIdentifierOf<Runtime>) {
+		// Collator candidates cannot remain linked to a deleted DID
+		ParachainStaking::unlink_identity_candidates(did);
+	}
+
+	fn on_deleted_weight() -> Weight {
+		// every linked collator candidate is unlinked like via `unlink_identity`
+		<weights::parachain_staking::WeightInfo<Runtime> as parachain_staking::WeightInfo>::unlink_identity()
+			.saturating_mul(constants::staking::MaxCandidatesPerIdentity::get().into())
+	}
 }
 
 impl did::Config for Runtime {
@@ -153,6 +165,10 @@ impl pallet_did_lookup::Config for Runtime {
 
 	type Currency = Balances;
 	type Deposit = constants::did_lookup::DidLookupDeposit;
+	type MetadataByteDeposit = constants::did_lookup::DidLookupMetadataByteDeposit;
+	type MaxLabelLength = constants::did_lookup::MaxLabelLength;
+	type MaxAssociationsPerBatch = constants::did_lookup::MaxAssociationsPerBatch;
+	type MaxLinkedAccounts = constants::did_lookup::MaxLinkedAccounts;
 
 	type EnsureOrigin = EnsureDidOrigin<DidIdentifier, AccountId>;
 	type AssociateOrigin = Self::EnsureOrigin;
@@ -163,6 +179,8 @@ impl pallet_did_lookup::Config for Runtime {
 	// Do not change the below flag to `true` without also deploying a runtime
 	// migration which removes any links that point to the same DID!
 	type UniqueLinkingEnabled = ConstBool<false>;
+	type Eip712ChainId = constants::did_lookup::Eip712ChainId;
+	type NameProvider = runtime_common::did::LinkedWeb3Names<Runtime>;
 }
 
 pub type Web3Name =
@@ -181,6 +199,7 @@ impl pallet_web3_names::Config for Runtime {
 	type Web3Name = Web3Name;
 	type Web3NameOwner = DidIdentifier;
 	type WeightInfo = weights::pallet_web3_names::WeightInfo<Runtime>;
+	type NameChangeHook = runtime_common::did::LinkedWeb3Names<Runtime>;
 	type BalanceMigrationManager = Migration;
 
 	#[cfg(feature = "runtime-benchmarks")]
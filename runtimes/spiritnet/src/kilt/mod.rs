@@ -19,7 +19,10 @@
 use frame_support::parameter_types;
 use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot, EnsureSigned};
 use pallet_asset_switch::xcm::{AccountId32ToAccountId32JunctionConverter, MatchesSwitchPairXcmFeeFungibleAsset};
-use runtime_common::{asset_switch::hooks::RestrictSwitchDestinationToSelf, AccountId, Balance};
+use runtime_common::{
+	asset_switch::hooks::RestrictSwitchDestinationToSelf, did::LinkedDidIdentity, pallet_id, AccountId, Balance,
+	SendDustAndFeesToTreasury,
+};
 use xcm_builder::{FungiblesAdapter, NoChecking};
 
 use crate::{
@@ -43,18 +46,30 @@ impl parachain_staking::Config for Runtime {
 	type DefaultBlocksPerRound = constants::staking::DefaultBlocksPerRound;
 	type StakeDuration = constants::staking::StakeDuration;
 	type ExitQueueDelay = constants::staking::ExitQueueDelay;
+	type CommissionChangeDelay = constants::staking::CommissionChangeDelay;
+	type SlashDeferDuration = constants::staking::SlashDeferDuration;
+	type MaxRoundHistory = constants::staking::MaxRoundHistory;
+	type MaxInflationSteps = constants::staking::MaxInflationSteps;
+	type MaxCandidatesPerIdentity = constants::staking::MaxCandidatesPerIdentity;
+	type MaxAutoCompoundsPerBlock = constants::staking::MaxAutoCompoundsPerBlock;
+	type MaxInflationUpdatesPerBlock = constants::staking::MaxInflationUpdatesPerBlock;
 	type MinCollators = constants::staking::MinCollators;
 	type MinRequiredCollators = constants::staking::MinRequiredCollators;
 	type MaxDelegationsPerRound = constants::staking::MaxDelegationsPerRound;
 	type MaxDelegatorsPerCollator = constants::staking::MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = constants::staking::MaxCollatorsPerDelegator;
 	type MinCollatorStake = constants::staking::MinCollatorStake;
 	type MinCollatorCandidateStake = constants::staking::MinCollatorStake;
 	type MaxTopCandidates = constants::staking::MaxCollatorCandidates;
 	type MinDelegatorStake = constants::staking::MinDelegatorStake;
 	type MaxUnstakeRequests = constants::staking::MaxUnstakeRequests;
+	type PoolPalletId = pallet_id::StakingPools;
+	type MinPoolJoin = constants::staking::MinPoolJoin;
 	type NetworkRewardRate = constants::staking::NetworkRewardRate;
 	type NetworkRewardStart = constants::staking::NetworkRewardStart;
 	type NetworkRewardBeneficiary = ();
+	type Slash = SendDustAndFeesToTreasury<Runtime>;
+	type IdentityProvider = LinkedDidIdentity<Runtime>;
 	type WeightInfo = weights::parachain_staking::WeightInfo<Runtime>;
 
 	const BLOCKS_PER_YEAR: BlockNumberFor<Self> = constants::BLOCKS_PER_YEAR;
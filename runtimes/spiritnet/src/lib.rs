@@ -81,7 +81,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 11600,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSION,
-	transaction_version: 11,
+	transaction_version: 12,
 	state_version: 0,
 };
 
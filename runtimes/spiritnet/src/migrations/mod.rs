@@ -34,6 +34,8 @@ pub type RuntimeMigrations = (
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	frame_support::migrations::RemovePallet<DmpPalletName, <Runtime as frame_system::Config>::DbWeight>,
 	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+	parachain_staking::migrations::v10::MigrateV9ToV10<Runtime>,
+	pallet_did_lookup::migrations::v5::MigrateV4ToV5<Runtime>,
 	frame_support::migrations::RemovePallet<Inflation, <Runtime as frame_system::Config>::DbWeight>,
 );
 
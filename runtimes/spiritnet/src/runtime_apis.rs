@@ -266,9 +266,9 @@ impl_runtime_apis! {
 					did::Did::<Runtime>::get(&owner_info.owner).map(|details| (owner_info, details))
 				})
 				.map(|(owner_info, details)| {
-					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(
+					let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(
 						&owner_info.owner,
-					).collect();
+					).map(Into::into).collect();
 					let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
 
 					RawDidLinkedInfo{
@@ -309,7 +309,7 @@ impl_runtime_apis! {
 				})
 				.map(|(connection_record, details)| {
 					let w3n = pallet_web3_names::Names::<Runtime>::get(&connection_record.did).map(Into::into);
-					let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&connection_record.did).collect();
+					let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(&connection_record.did).map(Into::into).collect();
 					let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&connection_record.did).map(|e| From::from(e.1)).collect();
 
 					RawDidLinkedInfo {
@@ -347,7 +347,7 @@ impl_runtime_apis! {
 		> {
 			let details = did::Did::<Runtime>::get(&did)?;
 			let w3n = pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into);
-			let accounts = pallet_did_lookup::ConnectedAccounts::<Runtime>::iter_key_prefix(&did).collect();
+			let accounts = pallet_did_lookup::Pallet::<Runtime>::linked_accounts(&did).map(Into::into).collect();
 			let service_endpoints = did::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
 
 			Some(RawDidLinkedInfo {
@@ -382,6 +382,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl kilt_runtime_api_did::Web3NameLookup<Block, LinkableAccountId> for Runtime {
+		fn batch_query_web3_name_by_account(accounts: Vec<LinkableAccountId>) -> Vec<Option<Vec<u8>>> {
+			accounts.iter().map(|account| runtime_common::did::web3_name_by_account::<Runtime>(account).map(Into::into)).collect()
+		}
+	}
+
 	impl kilt_runtime_api_public_credentials::PublicCredentials<Block, Vec<u8>, Hash, CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>, PublicCredentialsFilter<Hash, AccountId>, PublicCredentialsApiError> for Runtime {
 		fn get_by_id(credential_id: Hash) -> Option<CredentialEntry<Hash, DidIdentifier, BlockNumber, AccountId, Balance, AuthorizationId<<Runtime as delegation::Config>::DelegationNodeId>>> {
 			let subject = public_credentials::CredentialSubjects::<Runtime>::get(credential_id)?;
@@ -407,6 +413,35 @@ impl_runtime_apis! {
 		fn get_staking_rates() -> kilt_runtime_api_staking::StakingRates {
 			ParachainStaking::get_staking_rates()
 		}
+
+		fn get_collator_commission(collator: &AccountId) -> Option<kilt_runtime_api_staking::CollatorCommission> {
+			ParachainStaking::get_collator_commission(collator)
+		}
+
+		fn get_auto_compound(account: &AccountId) -> sp_runtime::Perbill {
+			ParachainStaking::get_auto_compound(account)
+		}
+
+		fn get_collator_stats(collator: &AccountId, rounds: u32) -> Vec<kilt_runtime_api_staking::CollatorRoundStats<Balance>> {
+			ParachainStaking::get_collator_stats(collator, rounds)
+		}
+
+		fn get_delegator_apy(collator: &AccountId, rounds: u32) -> Option<sp_runtime::FixedU128> {
+			ParachainStaking::get_delegator_apy(collator, rounds)
+		}
+
+		fn get_candidate_identities() -> Vec<(AccountId, Option<kilt_runtime_api_staking::CandidateIdentity<AccountId>>)> {
+			ParachainStaking::get_candidate_identities()
+				.into_iter()
+				.map(|(collator, did)| {
+					let identity = did.map(|did| kilt_runtime_api_staking::CandidateIdentity {
+						web3_name: pallet_web3_names::Names::<Runtime>::get(&did).map(Into::into),
+						did,
+					});
+					(collator, identity)
+				})
+				.collect()
+		}
 	}
 
 	impl kilt_runtime_api_dip_provider::DipProvider<Block, DipProofRequest, CompleteMerkleProof<Hash, DidMerkleProofOf<Runtime>>, DipProofError> for Runtime {
@@ -29,7 +29,7 @@ use runtime_common::{
 		attestation::MAX_ATTESTATION_BYTE_LENGTH,
 		deposit_storage::MAX_DEPOSIT_PALLET_KEY_LENGTH,
 		did::{MAX_KEY_LENGTH, MAX_SERVICE_ENDPOINT_BYTE_LENGTH},
-		did_lookup::MAX_CONNECTION_BYTE_LENGTH,
+		did_lookup::{MAX_CONNECTION_BYTE_LENGTH, MAX_LINK_METADATA_BYTE_LENGTH},
 		dip_provider::MAX_COMMITMENT_BYTE_LENGTH,
 		public_credentials::MAX_PUBLIC_CREDENTIAL_STORAGE_LENGTH,
 		MAX_INDICES_BYTE_LENGTH,
@@ -88,7 +88,11 @@ fn did_lookup_storage_sizes() {
 
 	let did_connection_size = DidConnection::max_encoded_len();
 
-	assert_eq!(did_connection_size, MAX_CONNECTION_BYTE_LENGTH as usize)
+	assert_eq!(did_connection_size, MAX_CONNECTION_BYTE_LENGTH as usize);
+
+	let link_metadata_size = pallet_did_lookup::LinkMetadataOf::<Runtime, ()>::max_encoded_len();
+
+	assert_eq!(link_metadata_size, MAX_LINK_METADATA_BYTE_LENGTH as usize)
 }
 
 #[test]
@@ -472,6 +472,18 @@ impl<T: frame_system::Config> parachain_staking::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(10 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `join_delegators`, this adds a delegation to a candidate, locks the
+	/// additional stake and updates the top candidates.
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::join_delegators(n, m)
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `leave_delegators`, this increments the rewards of the delegation,
+	/// removes it from the candidate and unstakes it.
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::leave_delegators(n, m)
+	}
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
 	// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(281), added: 2756, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
@@ -570,6 +582,171 @@ impl<T: frame_system::Config> parachain_staking::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 64823).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 31965).saturating_mul(n.into()))
 	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the candidate, the maximum commission and the current round before
+	/// scheduling the change.
+	fn set_commission() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. The
+	/// rewards of the collator and of each of its `m` delegators are
+	/// incremented at the previous commission before the new one applies.
+	fn execute_commission_change(m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::increment_collator_rewards()
+			.saturating_add(<Self as parachain_staking::WeightInfo>::increment_delegator_rewards().saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_max_commission() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the delegation of the caller before storing the setting.
+	fn set_auto_compound() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `candidate_stake_less`, this lowers the stake of the candidate and
+	/// updates the top candidates, and additionally stores the deferred slash.
+	fn report_equivocation(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::candidate_stake_less(n, m)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `force_remove_candidate`, this touches the stake, freeze and rewards of
+	/// the candidate and of each of its delegators.
+	fn apply_deferred_slash(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::force_remove_candidate(n, m)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Removes
+	/// a single deferred slash.
+	fn cancel_deferred_slash() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `cancel_leave_candidates`, this inserts the candidate into the top
+	/// candidates again.
+	fn resume_candidate(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::cancel_leave_candidates(n, m)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_slashing_config() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `join_delegators`, this delegates the initial bond to a candidate, and
+	/// additionally transfers the bond to the pool account and stores the pool
+	/// and its first member.
+	fn create_pool(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::join_delegators(n, m)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `delegator_stake_more`, this increases the delegation of the pool after
+	/// incrementing its rewards, and additionally transfers the bond and updates
+	/// the pool and the member.
+	fn join_pool(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::delegator_stake_more(n, m, 1)
+			.saturating_add(<Self as parachain_staking::WeightInfo>::increment_delegator_rewards())
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_rewards`, this pays out rewards after incrementing those of the
+	/// pool, and additionally updates the pool and the member.
+	fn claim_pool_rewards() -> Weight {
+		<Self as parachain_staking::WeightInfo>::increment_delegator_rewards()
+			.saturating_add(<Self as parachain_staking::WeightInfo>::claim_rewards())
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `delegator_stake_less`, this lowers the delegation of the pool after
+	/// incrementing its rewards, and additionally records the unbonding of the
+	/// member.
+	fn unbond_from_pool(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::delegator_stake_less(n, m)
+			.saturating_add(<Self as parachain_staking::WeightInfo>::increment_delegator_rewards())
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `unlock_unstaked`, this unlocks the unstaked bonds of the pool, and
+	/// additionally transfers them to the member.
+	fn withdraw_from_pool(u: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::unlock_unstaked(u)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_pool_rewards`, this harvests the rewards of the pool before
+	/// updating it.
+	fn set_pool_roles() -> Weight {
+		<Self as parachain_staking::WeightInfo>::claim_pool_rewards()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `claim_pool_rewards`, this harvests the rewards of the pool before
+	/// updating it, and additionally checks the maximum commission.
+	fn set_pool_commission() -> Weight {
+		<Self as parachain_staking::WeightInfo>::claim_pool_rewards()
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the roles of the caller before updating the pool.
+	fn set_pool_state() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// the candidate and the identity of the caller before replacing the link
+	/// and the candidate counts of the previous and the new identity.
+	fn link_identity() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Checks
+	/// whether identities are required before removing the link and
+	/// decrementing the candidate count of the identity.
+	fn unlink_identity() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value.
+	fn set_identity_requirement() -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `revoke_delegation`, this removes the delegation, and additionally
+	/// unlocks the stake right away like `unlock_unstaked`. Checking the
+	/// collator history reads at most one entry per round of the
+	/// `MaxRoundHistory` of 360 rounds.
+	fn fast_unstake(n: u32, m: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::revoke_delegation(n, m)
+			.saturating_add(<Self as parachain_staking::WeightInfo>::unlock_unstaked(1))
+			.saturating_add(T::DbWeight::get().reads(363 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	/// Not benchmarked yet, to be replaced by the benchmark output. Like
+	/// `set_max_candidate_stake`, this writes a single storage value of at most
+	/// `s` steps, and additionally removes a pending step for an empty schedule.
+	fn set_inflation_schedule(_s: u32, ) -> Weight {
+		<Self as parachain_staking::WeightInfo>::set_max_candidate_stake()
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 #[cfg(test)]
@@ -779,6 +956,30 @@ mod tests {
 		);
 	}
 	#[test]
+	fn test_delegate_another_candidate() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::delegate_another_candidate(75, 34).proof_size()
+		);
+	}
+	#[test]
+	fn test_revoke_delegation() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::revoke_delegation(75, 34).proof_size()
+		);
+	}
+	#[test]
 	fn test_unlock_unstaked() {
 		assert!(
 			<crate::Runtime as frame_system::Config>::BlockWeights::get()
@@ -838,4 +1039,268 @@ mod tests {
 				> 183222
 		);
 	}
+	#[test]
+	fn test_set_commission() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_commission().proof_size()
+		);
+	}
+	#[test]
+	fn test_execute_commission_change() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::execute_commission_change(35).proof_size()
+		);
+	}
+	#[test]
+	fn test_set_max_commission() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_max_commission().proof_size()
+		);
+	}
+	#[test]
+	fn test_set_auto_compound() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_auto_compound().proof_size()
+		);
+	}
+	#[test]
+	fn test_report_equivocation() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::report_equivocation(74, 35).proof_size()
+		);
+	}
+	#[test]
+	fn test_apply_deferred_slash() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::apply_deferred_slash(74, 35).proof_size()
+		);
+	}
+	#[test]
+	fn test_cancel_deferred_slash() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::cancel_deferred_slash().proof_size()
+		);
+	}
+	#[test]
+	fn test_resume_candidate() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::resume_candidate(74, 35).proof_size()
+		);
+	}
+	#[test]
+	fn test_set_slashing_config() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_slashing_config().proof_size()
+		);
+	}
+	#[test]
+	fn test_create_pool() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::create_pool(75, 34).proof_size()
+		);
+	}
+	#[test]
+	fn test_join_pool() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::join_pool(75, 34).proof_size()
+		);
+	}
+	#[test]
+	fn test_claim_pool_rewards() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::claim_pool_rewards().proof_size()
+		);
+	}
+	#[test]
+	fn test_unbond_from_pool() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::unbond_from_pool(75, 34).proof_size()
+		);
+	}
+	#[test]
+	fn test_withdraw_from_pool() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::withdraw_from_pool(9).proof_size()
+		);
+	}
+	#[test]
+	fn test_set_pool_roles() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_pool_roles().proof_size()
+		);
+	}
+	#[test]
+	fn test_set_pool_commission() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_pool_commission().proof_size()
+		);
+	}
+	#[test]
+	fn test_set_pool_state() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_pool_state().proof_size()
+		);
+	}
+	#[test]
+	fn test_link_identity() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::link_identity().proof_size()
+		);
+	}
+	#[test]
+	fn test_unlink_identity() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::unlink_identity().proof_size()
+		);
+	}
+	#[test]
+	fn test_set_identity_requirement() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_identity_requirement().proof_size()
+		);
+	}
+	#[test]
+	fn test_fast_unstake() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::fast_unstake(75, 34).proof_size()
+		);
+	}
+	#[test]
+	fn test_set_inflation_schedule() {
+		assert!(
+			<crate::Runtime as frame_system::Config>::BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<sp_weights::Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> <super::WeightInfo<crate::Runtime> as parachain_staking::WeightInfo>::set_inflation_schedule(10).proof_size()
+		);
+	}
 }